@@ -0,0 +1,23 @@
+//! FHIR specifications parser
+//!
+//! Parse the FHIR R5 specification JSON files, and generate Rust source code
+//! from their StructureDefinitions.
+//!
+//! The binary regenerates the committed files in `./tmp/out`. A build
+//! script of another crate can generate selected types into its `OUT_DIR`;
+//! see `r5::parse::profiles_types::CodegenBuild`.
+
+pub mod r5;
+pub mod util;
+
+pub static DEFINITIONS_DIR: std::sync::LazyLock<std::path::PathBuf> =
+    std::sync::LazyLock::new(|| {
+        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("doc")
+            .join("fhir-specifications")
+            .join("r5")
+            .join("fhir-definitions-json")
+    });
+
+/// Literate programming.
+pub type SourceCodeString = String;
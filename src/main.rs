@@ -1,36 +1,69 @@
-pub mod r5;
-pub mod util;
-
-use crate::r5::parse;
+use ::fhir_specifications_parser::r5::parse;
 use std::fs::File;
 use std::io::BufReader;
 
-pub static DEFINITIONS_DIR: std::sync::LazyLock<std::path::PathBuf> =
-    std::sync::LazyLock::new(|| {
-        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("doc")
-            .join("fhir-specifications")
-            .join("r5")
-            .join("fhir-definitions-json")
-    });
+/// Parse the resource StructureDefinitions, such as Patient, when the
+/// profiles-resources.json file is present.
+fn parse_profiles_resources() -> Vec<parse::profiles_types::Resource> {
+    let Ok(file) = File::open(&*parse::profiles_resources::DEFINITIONS_FILE) else {
+        return vec![];
+    };
+    let reader = BufReader::new(file);
+    let bundle: parse::profiles_resources::Bundle = ::serde_json::from_reader(reader).unwrap();
+    bundle
+        .entry
+        .iter()
+        .filter_map(|entry| {
+            parse::profiles_resources::resource_into_structure_definition(&entry.resource)
+                .unwrap_or_else(|error| {
+                    panic!("StructureDefinition/{}: {error}", entry.resource.id)
+                })
+        })
+        .collect()
+}
 
-fn parse_profiles_types() {
-    let path = crate::DEFINITIONS_DIR.join("profiles-types.json");
-    let file = File::open(path).expect("open");
+/// Parse the CodeSystems, such as PublicationStatus, when the valuesets.json
+/// file is present.
+fn parse_code_systems() -> Vec<parse::value_sets::CodeSystem> {
+    let Ok(file) = File::open(&*parse::value_sets::DEFINITIONS_FILE) else {
+        return vec![];
+    };
     let reader = BufReader::new(file);
-    let bundle: parse::profiles_types::Bundle = ::serde_json::from_reader(reader).unwrap();
+    let bundle: parse::value_sets::Bundle = ::serde_json::from_reader(reader).unwrap();
     bundle
         .entry
-        .into_iter()
-        .map(|resource_head| resource_head.resource)
-        .for_each(|resource| {
-            parse::profiles_types::resource_into_rust(&resource).expect("resource_into_rust");
-        });
+        .iter()
+        .filter_map(|entry| parse::value_sets::resource_into_code_system(&entry.resource))
+        .collect()
 }
 
-/// Literate programming.
-pub type SourceCodeString = String;
+/// Parse the SearchParameters, such as Patient's `name`.
+fn parse_search_parameters() -> Vec<parse::search_parameters::Resource> {
+    let file = File::open(&*parse::search_parameters::DEFINITIONS_FILE).unwrap();
+    let reader = BufReader::new(file);
+    let bundle: parse::search_parameters::Bundle = ::serde_json::from_reader(reader).unwrap();
+    bundle.entry.into_iter().map(|entry| entry.resource).collect()
+}
+
+fn generate() {
+    let mut resources = parse::profiles_types::load_profiles_types().expect("load_profiles_types");
+    resources.extend(parse_profiles_resources());
+    resources.retain(|resource| !parse::profiles_types::resource_is_abstract_resource(resource));
+    let context = parse::profiles_types::golden_context(&resources);
+    parse::profiles_types::resources_into_rust(&resources, &context).expect("resources_into_rust");
+    parse::search_parameters::resources_into_rust(
+        &parse_search_parameters(),
+        &parse::profiles_types::SEARCH_PARAMETERS_GOLDEN_DIR,
+    )
+    .expect("search_parameters::resources_into_rust");
+    let code_systems = parse_code_systems();
+    if !code_systems.is_empty() {
+        let dir = context.config.output_dir.with_file_name("code_systems");
+        parse::value_sets::code_systems_into_rust(&code_systems, &dir)
+            .expect("code_systems_into_rust");
+    }
+}
 
 fn main() {
-    parse_profiles_types();
+    generate();
 }
@@ -13,3 +13,12 @@ pub mod todo;
 
 /// FHIR R5 datatypes
 pub mod types;
+
+/// Registries of loaded FHIR R5 definitions, indexed by canonical URL.
+pub mod registry;
+
+/// Integrity checks over loaded FHIR R5 definitions.
+pub mod check;
+
+/// Summary, mustSupport, and isModifier views of StructureDefinitions.
+pub mod summary;
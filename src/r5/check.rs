@@ -0,0 +1,16 @@
+//! Integrity checks over loaded FHIR R5 definitions.
+//!
+//! Each check reads the registries and returns a list of issues, rather than
+//! stopping at the first problem, so one run can report a whole bundle.
+
+pub mod canonical_integrity;
+pub mod constraint_integrity;
+pub mod extension_usage;
+
+pub use canonical_integrity::{
+    CanonicalBundle, CanonicalIntegrityChecker, CanonicalReference, CanonicalReport,
+};
+pub use constraint_integrity::{
+    ConstraintIntegrityChecker, ConstraintIssue, ConstraintIssueKind, ConstraintReport,
+};
+pub use extension_usage::{ExtensionUsageChecker, ExtensionUsageIssue, ExtensionUsageIssueKind};
@@ -0,0 +1,500 @@
+//! Canonical reference integrity check
+//!
+//! Definitions refer to each other by canonical URL, in places such as:
+//!
+//! - StructureDefinition `baseDefinition`
+//! - ElementDefinition `binding.valueSet` and `binding.additional.valueSet`
+//! - ElementDefinition `type.profile` and `type.targetProfile`
+//! - SearchParameter `component.definition`
+//! - ConceptMap `sourceScopeCanonical`, `targetScopeCanonical`, and groups
+//! - ValueSet `compose.include.valueSet` and `relatedArtifact.resource`
+//!
+//! This check resolves each reference, with any `|version` suffix, against
+//! every loaded bundle, and lists the dangling references by source resource
+//! and JSON path. A dangling reference means the bundles are incomplete.
+//!
+//! References to contained resources, which start with `#`, are skipped.
+//!
+//! Like the other checks, [`CanonicalIntegrityChecker::check_all`] returns the
+//! issues, here the dangling references; [`CanonicalIntegrityChecker::report`]
+//! also counts the references that were checked.
+
+use crate::r5::parse::all::{Binding, ElementType, RelatedArtifact};
+use crate::r5::parse::{
+    concept_maps, data_elements, profiles_others, profiles_resources, profiles_types,
+    search_parameters, value_sets,
+};
+use crate::r5::registry::canonical_registry::{CanonicalEntry, CanonicalRegistry};
+use std::borrow::Cow;
+
+/// One canonical reference, found at a JSON path within a source resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonicalReference {
+    /// The source resource, as `ResourceType/id`.
+    pub resource: String,
+
+    /// The JSON path of the reference within the source resource.
+    pub path: String,
+
+    pub canonical: String,
+}
+
+/// The outcome of a canonical reference integrity check.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CanonicalReport {
+    pub references_checked: usize,
+    pub dangling: Vec<CanonicalReference>,
+}
+
+impl CanonicalReport {
+    pub fn is_ok(&self) -> bool {
+        self.dangling.is_empty()
+    }
+}
+
+/// A parsed bundle of definitions, which provides canonical resources and
+/// refers to others.
+pub trait CanonicalBundle {
+    /// The canonical resources that the bundle provides.
+    fn canonical_entries(&self) -> Vec<CanonicalEntry>;
+
+    /// The canonical references that the bundle makes.
+    fn canonical_references(&self) -> Vec<CanonicalReference>;
+}
+
+pub struct CanonicalIntegrityChecker<'a> {
+    bundles: &'a [&'a dyn CanonicalBundle],
+    registry: Cow<'a, CanonicalRegistry>,
+}
+
+impl<'a> CanonicalIntegrityChecker<'a> {
+    /// Resolve against the entries of every bundle.
+    pub fn new(bundles: &'a [&'a dyn CanonicalBundle]) -> Self {
+        let registry = bundles
+            .iter()
+            .flat_map(|bundle| bundle.canonical_entries())
+            .collect();
+        Self {
+            bundles,
+            registry: Cow::Owned(registry),
+        }
+    }
+
+    /// Resolve against a given registry instead.
+    pub fn with_registry(
+        bundles: &'a [&'a dyn CanonicalBundle],
+        registry: &'a CanonicalRegistry,
+    ) -> Self {
+        Self {
+            bundles,
+            registry: Cow::Borrowed(registry),
+        }
+    }
+
+    /// The dangling references of every bundle.
+    pub fn check_all(&self) -> Vec<CanonicalReference> {
+        self.report().dangling
+    }
+
+    /// The dangling references of every bundle, with the number checked.
+    pub fn report(&self) -> CanonicalReport {
+        let mut report = CanonicalReport::default();
+        for reference in self
+            .bundles
+            .iter()
+            .flat_map(|bundle| bundle.canonical_references())
+        {
+            if reference.canonical.starts_with('#') {
+                continue;
+            }
+            report.references_checked += 1;
+            if self.registry.resolve(&reference.canonical).is_none() {
+                report.dangling.push(reference);
+            }
+        }
+        report
+    }
+}
+
+/// Collects references for one source resource.
+struct References {
+    resource: String,
+    references: Vec<CanonicalReference>,
+}
+
+impl References {
+    fn new(resource_type: &str, id: &str) -> Self {
+        Self {
+            resource: format!("{resource_type}/{id}"),
+            references: vec![],
+        }
+    }
+
+    fn push(&mut self, path: impl Into<String>, canonical: &str) {
+        self.references.push(CanonicalReference {
+            resource: self.resource.clone(),
+            path: path.into(),
+            canonical: canonical.into(),
+        });
+    }
+
+    fn push_option(&mut self, path: &str, canonical: Option<&String>) {
+        if let Some(canonical) = canonical {
+            self.push(path, canonical);
+        }
+    }
+
+    fn push_elements<'a>(
+        &mut self,
+        label: &str,
+        elements: impl Iterator<Item = (Option<&'a Binding>, Option<&'a Vec<ElementType>>)>,
+    ) {
+        for (index, (binding, types)) in elements.enumerate() {
+            let path = format!("{label}.element[{index}]");
+            if let Some(binding) = binding {
+                self.push_option(
+                    &format!("{path}.binding.valueSet"),
+                    binding.value_set.as_ref(),
+                );
+                for (i, additional) in binding.additional.iter().flatten().enumerate() {
+                    self.push(
+                        format!("{path}.binding.additional[{i}].valueSet"),
+                        &additional.value_set,
+                    );
+                }
+            }
+            for (i, element_type) in types.into_iter().flatten().enumerate() {
+                for (j, profile) in element_type.profile.iter().flatten().enumerate() {
+                    self.push(format!("{path}.type[{i}].profile[{j}]"), profile);
+                }
+                for (j, profile) in element_type.target_profile.iter().flatten().enumerate() {
+                    self.push(format!("{path}.type[{i}].targetProfile[{j}]"), profile);
+                }
+            }
+        }
+    }
+
+    fn push_related_artifacts(&mut self, related_artifacts: Option<&Vec<RelatedArtifact>>) {
+        for (i, related_artifact) in related_artifacts.into_iter().flatten().enumerate() {
+            self.push(
+                format!("relatedArtifact[{i}].resource"),
+                &related_artifact.resource,
+            );
+        }
+    }
+
+    /// Push each string in `value[index].key`, which may be a string or an
+    /// array of strings, for a JSON array value.
+    fn push_json(&mut self, label: &str, value: Option<&::serde_json::Value>, key: &str) {
+        let Some(::serde_json::Value::Array(array)) = value else {
+            return;
+        };
+        for (index, item) in array.iter().enumerate() {
+            match &item[key] {
+                ::serde_json::Value::String(canonical) => {
+                    self.push(format!("{label}[{index}].{key}"), canonical)
+                }
+                ::serde_json::Value::Array(canonicals) => {
+                    for (i, canonical) in canonicals.iter().enumerate() {
+                        if let Some(canonical) = canonical.as_str() {
+                            self.push(format!("{label}[{index}].{key}[{i}]"), canonical);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn entry(resource_type: &str, id: &str, url: &str, version: &str) -> CanonicalEntry {
+    CanonicalEntry {
+        url: url.into(),
+        version: Some(version.into()).filter(|x: &String| !x.is_empty()),
+        resource_type: resource_type.into(),
+        id: id.into(),
+    }
+}
+
+/// Implement [`CanonicalBundle`] for a bundle of StructureDefinitions. The
+/// profiles bundles are parsed into separate, identically shaped types.
+macro_rules! impl_canonical_bundle_for_structure_definitions {
+    ($($bundle:ty),+ $(,)?) => {$(
+        impl CanonicalBundle for $bundle {
+            fn canonical_entries(&self) -> Vec<CanonicalEntry> {
+                self.entry
+                    .iter()
+                    .map(|x| &x.resource)
+                    .map(|x| entry(&x.resource_type, &x.id, &x.url, &x.version))
+                    .collect()
+            }
+
+            fn canonical_references(&self) -> Vec<CanonicalReference> {
+                let mut all = vec![];
+                for resource in self.entry.iter().map(|x| &x.resource) {
+                    let mut references = References::new(&resource.resource_type, &resource.id);
+                    references.push_option("baseDefinition", resource.base_definition.as_ref());
+                    if let Some(snapshot) = resource.snapshot.as_ref() {
+                        references.push_elements(
+                            "snapshot",
+                            snapshot
+                                .element
+                                .iter()
+                                .map(|x| (x.binding.as_ref(), x.r#type.as_ref())),
+                        );
+                    }
+                    if let Some(differential) = resource.differential.as_ref() {
+                        references.push_elements(
+                            "differential",
+                            differential
+                                .element
+                                .iter()
+                                .map(|x| (x.binding.as_ref(), x.r#type.as_ref())),
+                        );
+                    }
+                    all.extend(references.references);
+                }
+                all
+            }
+        }
+    )+};
+}
+
+impl_canonical_bundle_for_structure_definitions!(
+    profiles_types::Bundle,
+    profiles_resources::Bundle,
+    profiles_others::Bundle,
+);
+
+impl CanonicalBundle for data_elements::Bundle {
+    fn canonical_entries(&self) -> Vec<CanonicalEntry> {
+        self.entry
+            .iter()
+            .map(|x| &x.resource)
+            .map(|x| entry(&x.resource_type, &x.id, &x.url, &x.version))
+            .collect()
+    }
+
+    fn canonical_references(&self) -> Vec<CanonicalReference> {
+        let mut all = vec![];
+        for resource in self.entry.iter().map(|x| &x.resource) {
+            let mut references = References::new(&resource.resource_type, &resource.id);
+            references.push_option("baseDefinition", resource.base_definition.as_ref());
+            references.push_elements(
+                "snapshot",
+                resource
+                    .snapshot
+                    .element
+                    .iter()
+                    .map(|x| (x.binding.as_ref(), x.r#type.as_ref())),
+            );
+            all.extend(references.references);
+        }
+        all
+    }
+}
+
+impl CanonicalBundle for search_parameters::Bundle {
+    fn canonical_entries(&self) -> Vec<CanonicalEntry> {
+        self.entry
+            .iter()
+            .map(|x| &x.resource)
+            .map(|x| entry(&x.resource_type, &x.id, &x.url, &x.version))
+            .collect()
+    }
+
+    fn canonical_references(&self) -> Vec<CanonicalReference> {
+        let mut all = vec![];
+        for resource in self.entry.iter().map(|x| &x.resource) {
+            let mut references = References::new(&resource.resource_type, &resource.id);
+            for (i, component) in resource.component.iter().flatten().enumerate() {
+                references.push(format!("component[{i}].definition"), &component.definition);
+            }
+            all.extend(references.references);
+        }
+        all
+    }
+}
+
+impl CanonicalBundle for value_sets::Bundle {
+    fn canonical_entries(&self) -> Vec<CanonicalEntry> {
+        self.entry
+            .iter()
+            .map(|x| &x.resource)
+            .filter_map(|x| {
+                let url = x.url.as_deref()?;
+                let version = x.version.as_deref().unwrap_or_default();
+                Some(entry(&x.resource_type, &x.id, url, version))
+            })
+            .collect()
+    }
+
+    fn canonical_references(&self) -> Vec<CanonicalReference> {
+        let mut all = vec![];
+        for resource in self.entry.iter().map(|x| &x.resource) {
+            let mut references = References::new(&resource.resource_type, &resource.id);
+            references.push_option("valueSet", resource.value_set.as_ref());
+            references.push_option("supplements", resource.supplements.as_ref());
+            if let Some(compose) = resource.compose.as_ref() {
+                let include = ::serde_json::Value::Array(compose.include.clone());
+                references.push_json("compose.include", Some(&include), "valueSet");
+                let exclude = compose.exclude.clone().map(::serde_json::Value::Array);
+                references.push_json("compose.exclude", exclude.as_ref(), "valueSet");
+            }
+            references.push_related_artifacts(resource.related_artifact.as_ref());
+            all.extend(references.references);
+        }
+        all
+    }
+}
+
+impl CanonicalBundle for concept_maps::Bundle {
+    fn canonical_entries(&self) -> Vec<CanonicalEntry> {
+        self.entry
+            .iter()
+            .map(|x| &x.resource)
+            .map(|x| entry(&x.resource_type, &x.id, &x.url, &x.version))
+            .collect()
+    }
+
+    fn canonical_references(&self) -> Vec<CanonicalReference> {
+        let mut all = vec![];
+        for resource in self.entry.iter().map(|x| &x.resource) {
+            let mut references = References::new(&resource.resource_type, &resource.id);
+            references.push_option(
+                "sourceScopeCanonical",
+                resource.source_scope_canonical.as_ref(),
+            );
+            references.push_option(
+                "targetScopeCanonical",
+                resource.target_scope_canonical.as_ref(),
+            );
+            references.push_json("group", Some(&resource.group), "source");
+            references.push_json("group", Some(&resource.group), "target");
+            references.push_related_artifacts(resource.related_artifact.as_ref());
+            all.extend(references.references);
+        }
+        all
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde_json::json;
+
+    fn profiles_types_bundle() -> profiles_types::Bundle {
+        ::serde_json::from_value(json!({
+            "resourceType": "Bundle",
+            "id": "types",
+            "type": "collection",
+            "meta": {},
+            "entry": [
+                {
+                    "fullUrl": "http://hl7.org/fhir/StructureDefinition/Alfa",
+                    "resource": {
+                        "resourceType": "StructureDefinition",
+                        "id": "Alfa",
+                        "meta": {},
+                        "url": "http://hl7.org/fhir/StructureDefinition/Alfa",
+                        "version": "5.0.0",
+                        "status": "active",
+                        "experimental": false,
+                        "baseDefinition": "http://hl7.org/fhir/StructureDefinition/Bravo|5.0.0",
+                        "snapshot": {
+                            "element": [
+                                { "id": "Alfa", "path": "Alfa" },
+                                {
+                                    "id": "Alfa.charlie",
+                                    "path": "Alfa.charlie",
+                                    "type": [{
+                                        "code": "Reference",
+                                        "targetProfile": ["http://hl7.org/fhir/StructureDefinition/Alfa|4.0.1"]
+                                    }],
+                                    "binding": {
+                                        "strength": "required",
+                                        "valueSet": "http://hl7.org/fhir/ValueSet/delta|5.0.0"
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                },
+                {
+                    "fullUrl": "http://hl7.org/fhir/StructureDefinition/Bravo",
+                    "resource": {
+                        "resourceType": "StructureDefinition",
+                        "id": "Bravo",
+                        "meta": {},
+                        "url": "http://hl7.org/fhir/StructureDefinition/Bravo",
+                        "version": "5.0.0",
+                        "status": "active",
+                        "experimental": false
+                    }
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_canonical_integrity_checker_report() {
+        let bundle = profiles_types_bundle();
+        let bundles: [&dyn CanonicalBundle; 1] = [&bundle];
+        let actual = CanonicalIntegrityChecker::new(&bundles).report();
+        let reference = |path: &str, canonical: &str| CanonicalReference {
+            resource: String::from("StructureDefinition/Alfa"),
+            path: path.into(),
+            canonical: canonical.into(),
+        };
+        let expect = CanonicalReport {
+            references_checked: 3,
+            dangling: vec![
+                reference(
+                    "snapshot.element[1].binding.valueSet",
+                    "http://hl7.org/fhir/ValueSet/delta|5.0.0",
+                ),
+                reference(
+                    "snapshot.element[1].type[0].targetProfile[0]",
+                    "http://hl7.org/fhir/StructureDefinition/Alfa|4.0.1",
+                ),
+            ],
+        };
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_canonical_integrity_checker_check_all() {
+        let bundle = profiles_types_bundle();
+        let bundles: [&dyn CanonicalBundle; 1] = [&bundle];
+        let actual: Vec<String> = CanonicalIntegrityChecker::new(&bundles)
+            .check_all()
+            .into_iter()
+            .map(|x| x.path)
+            .collect();
+        let expect = vec![
+            "snapshot.element[1].binding.valueSet",
+            "snapshot.element[1].type[0].targetProfile[0]",
+        ];
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_canonical_integrity_checker_with_definitions_files() {
+        let open =
+            |path: &std::path::Path| std::io::BufReader::new(std::fs::File::open(path).unwrap());
+        let types: profiles_types::Bundle =
+            ::serde_json::from_reader(open(&profiles_types::DEFINITIONS_FILE)).unwrap();
+        let search: search_parameters::Bundle =
+            ::serde_json::from_reader(open(&search_parameters::DEFINITIONS_FILE)).unwrap();
+        let bundles: [&dyn CanonicalBundle; 2] = [&types, &search];
+        let actual = CanonicalIntegrityChecker::new(&bundles).report();
+        assert!(actual.references_checked > 0);
+        let dangling_base_definitions: Vec<&CanonicalReference> = actual
+            .dangling
+            .iter()
+            .filter(|x| x.path == "baseDefinition")
+            .collect();
+        assert_eq!(dangling_base_definitions, Vec::<&CanonicalReference>::new());
+    }
+}
@@ -0,0 +1,301 @@
+//! Constraint integrity check
+//!
+//! Elements refer to invariants by key in `condition`, and define them in
+//! `constraint`. This check reports, for each StructureDefinition snapshot:
+//!
+//! - a condition key that is not defined on the element or an ancestor
+//! - a key that is defined twice on one element
+//! - a key that is defined again elsewhere with a different expression
+//! - a severity that is not `error` or `warning`
+//! - a source that is not a loaded StructureDefinition
+//!
+//! Snapshots repeat inherited constraints on every element, such as `ele-1`,
+//! so a key that is defined again with the same expression is not an issue.
+
+use crate::r5::parse::profiles_types::{Element, Resource};
+use crate::r5::registry::StructureDefinitionRegistry;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintIssue {
+    /// Canonical URL of the StructureDefinition.
+    pub resource: String,
+
+    /// Id of the element with the issue.
+    pub element: String,
+
+    /// The constraint key, or the condition key.
+    pub key: String,
+
+    pub kind: ConstraintIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConstraintIssueKind {
+    /// A condition names a key that the element and its ancestors lack.
+    UndefinedCondition,
+
+    /// One element defines the key more than once.
+    DuplicateKey,
+
+    /// Another element defines the key with a different expression.
+    ConflictingKey { other_element: String },
+
+    /// The severity is neither `error` nor `warning`.
+    InvalidSeverity { severity: String },
+
+    /// The source is not a loaded StructureDefinition.
+    UnknownSource { source: String },
+}
+
+/// The outcome of a constraint integrity check.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConstraintReport {
+    pub resources_checked: usize,
+    pub constraints_checked: usize,
+    pub issues: Vec<ConstraintIssue>,
+}
+
+impl ConstraintReport {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// The issues of one kind, matched by variant.
+    pub fn issues_where(
+        &self,
+        predicate: impl Fn(&ConstraintIssueKind) -> bool,
+    ) -> Vec<&ConstraintIssue> {
+        self.issues.iter().filter(|x| predicate(&x.kind)).collect()
+    }
+}
+
+pub struct ConstraintIntegrityChecker<'a> {
+    structure_definitions: &'a StructureDefinitionRegistry,
+}
+
+impl<'a> ConstraintIntegrityChecker<'a> {
+    pub fn new(structure_definitions: &'a StructureDefinitionRegistry) -> Self {
+        Self {
+            structure_definitions,
+        }
+    }
+
+    /// Check every StructureDefinition in the registry.
+    pub fn check_all(&self) -> ConstraintReport {
+        let mut report = ConstraintReport::default();
+        for resource in self.structure_definitions.iter() {
+            self.check_resource_into(resource, &mut report);
+        }
+        report
+    }
+
+    /// Check the snapshot of one StructureDefinition.
+    pub fn check_resource(&self, resource: &Resource) -> ConstraintReport {
+        let mut report = ConstraintReport::default();
+        self.check_resource_into(resource, &mut report);
+        report
+    }
+
+    fn check_resource_into(&self, resource: &Resource, report: &mut ConstraintReport) {
+        let elements: &[Element] = resource
+            .snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.element.as_slice())
+            .unwrap_or_default();
+        let issue = |element: &Element, key: &str, kind| ConstraintIssue {
+            resource: resource.url.clone(),
+            element: element.id.clone(),
+            key: key.into(),
+            kind,
+        };
+        report.resources_checked += 1;
+
+        // Key => (element id, expression) of its first definition.
+        let mut definitions: BTreeMap<&str, (&str, Option<&str>)> = BTreeMap::new();
+        for element in elements {
+            let constraints = element.constraint.as_deref().unwrap_or_default();
+            for (index, constraint) in constraints.iter().enumerate() {
+                report.constraints_checked += 1;
+                let key = constraint.key.as_str();
+                if constraints[..index].iter().any(|x| x.key == key) {
+                    report
+                        .issues
+                        .push(issue(element, key, ConstraintIssueKind::DuplicateKey));
+                } else if let Some((other_element, expression)) = definitions.get(key) {
+                    if *expression != constraint.expression.as_deref() {
+                        report.issues.push(issue(
+                            element,
+                            key,
+                            ConstraintIssueKind::ConflictingKey {
+                                other_element: String::from(*other_element),
+                            },
+                        ));
+                    }
+                } else {
+                    definitions.insert(key, (&element.id, constraint.expression.as_deref()));
+                }
+                if !matches!(constraint.severity.as_str(), "error" | "warning") {
+                    report.issues.push(issue(
+                        element,
+                        key,
+                        ConstraintIssueKind::InvalidSeverity {
+                            severity: constraint.severity.clone(),
+                        },
+                    ));
+                }
+                if let Some(source) = constraint.source.as_deref()
+                    && self.structure_definitions.get(source).is_none()
+                {
+                    report.issues.push(issue(
+                        element,
+                        key,
+                        ConstraintIssueKind::UnknownSource {
+                            source: String::from(source),
+                        },
+                    ));
+                }
+            }
+        }
+
+        for element in elements {
+            for key in element.condition.iter().flatten() {
+                let is_defined = elements
+                    .iter()
+                    .filter(|x| is_self_or_ancestor(&x.path, &element.path))
+                    .flat_map(|x| x.constraint.iter().flatten())
+                    .any(|x| x.key == *key);
+                if !is_defined {
+                    report.issues.push(issue(
+                        element,
+                        key,
+                        ConstraintIssueKind::UndefinedCondition,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Is the element at `path` the element at `other` or one of its ancestors?
+fn is_self_or_ancestor(path: &str, other: &str) -> bool {
+    other
+        .strip_prefix(path)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde_json::json;
+
+    fn resource() -> Resource {
+        ::serde_json::from_value(json!({
+            "resourceType": "StructureDefinition",
+            "id": "Alfa",
+            "meta": {},
+            "url": "http://example.com/StructureDefinition/Alfa",
+            "version": "5.0.0",
+            "status": "active",
+            "experimental": false,
+            "snapshot": {
+                "element": [
+                    {
+                        "id": "Alfa",
+                        "path": "Alfa",
+                        "constraint": [
+                            { "key": "alf-1", "severity": "error", "human": "One", "expression": "a.exists()" },
+                            { "key": "alf-1", "severity": "error", "human": "One again", "expression": "a.exists()" }
+                        ]
+                    },
+                    {
+                        "id": "Alfa.bravo",
+                        "path": "Alfa.bravo",
+                        "condition": ["alf-1", "alf-9"],
+                        "constraint": [
+                            {
+                                "key": "alf-2",
+                                "severity": "information",
+                                "human": "Two",
+                                "expression": "b.exists()",
+                                "source": "http://example.com/StructureDefinition/Missing"
+                            }
+                        ]
+                    },
+                    {
+                        "id": "Alfa.charlie",
+                        "path": "Alfa.charlie",
+                        "condition": ["alf-2"],
+                        "constraint": [
+                            { "key": "alf-2", "severity": "warning", "human": "Two", "expression": "c.exists()" }
+                        ]
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_resource() {
+        let registry = StructureDefinitionRegistry::new();
+        let actual = ConstraintIntegrityChecker::new(&registry).check_resource(&resource());
+        let issue = |element: &str, key: &str, kind| ConstraintIssue {
+            resource: String::from("http://example.com/StructureDefinition/Alfa"),
+            element: element.into(),
+            key: key.into(),
+            kind,
+        };
+        let expect = ConstraintReport {
+            resources_checked: 1,
+            constraints_checked: 4,
+            issues: vec![
+                issue("Alfa", "alf-1", ConstraintIssueKind::DuplicateKey),
+                issue(
+                    "Alfa.bravo",
+                    "alf-2",
+                    ConstraintIssueKind::InvalidSeverity {
+                        severity: String::from("information"),
+                    },
+                ),
+                issue(
+                    "Alfa.bravo",
+                    "alf-2",
+                    ConstraintIssueKind::UnknownSource {
+                        source: String::from("http://example.com/StructureDefinition/Missing"),
+                    },
+                ),
+                issue(
+                    "Alfa.charlie",
+                    "alf-2",
+                    ConstraintIssueKind::ConflictingKey {
+                        other_element: String::from("Alfa.bravo"),
+                    },
+                ),
+                issue(
+                    "Alfa.bravo",
+                    "alf-9",
+                    ConstraintIssueKind::UndefinedCondition,
+                ),
+            ],
+        };
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_is_self_or_ancestor() {
+        assert!(is_self_or_ancestor("Alfa", "Alfa"));
+        assert!(is_self_or_ancestor("Alfa", "Alfa.bravo"));
+        assert!(!is_self_or_ancestor("Alfa.bravo", "Alfa.bravocharlie"));
+        assert!(!is_self_or_ancestor("Alfa.bravo", "Alfa"));
+    }
+
+    #[test]
+    fn test_check_all_with_definitions_file() {
+        let registry = StructureDefinitionRegistry::from_definitions_file().unwrap();
+        let actual = ConstraintIntegrityChecker::new(&registry).check_all();
+        assert_eq!(actual.resources_checked, registry.len());
+        assert!(actual.constraints_checked > 0);
+        assert!(actual.is_ok(), "{:?}", actual.issues);
+    }
+}
@@ -0,0 +1,442 @@
+//! Extension usage check
+//!
+//! Report extensions that are used in a place their definition's context
+//! does not allow, or whose `value[x]` type is not one the definition allows.
+//!
+//! The check walks the parsed StructureDefinitions, where the places of use
+//! are known: the resource root, each ElementDefinition, and each element's
+//! binding, types, and constraints. Extensions without a loaded definition
+//! are skipped.
+//!
+//! The value type is read from the extension's JSON `value[x]` key, such as
+//! `valueCoding`, so it covers every type and not only the fields that the
+//! parsed `Extension` struct declares. A root extension that is not an object
+//! with a `url` is reported, rather than dropped.
+
+use crate::r5::parse::all::Extension;
+use crate::r5::parse::profiles_types::{Element, Resource};
+use crate::r5::registry::{ExtensionRegistry, StructureDefinitionRegistry};
+
+/// Ancestors of a StructureDefinition resource, which is defined in
+/// profiles-resources.json and so is usually absent from the registry.
+const STRUCTURE_DEFINITION_ANCESTORS: [&str; 3] = ["DomainResource", "Resource", "Base"];
+
+/// The FHIR R5 primitive type codes, which start with a lowercase letter,
+/// unlike the `value[x]` key suffix.
+const PRIMITIVE_TYPES: [&str; 21] = [
+    "base64Binary",
+    "boolean",
+    "canonical",
+    "code",
+    "date",
+    "dateTime",
+    "decimal",
+    "id",
+    "instant",
+    "integer",
+    "integer64",
+    "markdown",
+    "oid",
+    "positiveInt",
+    "string",
+    "time",
+    "unsignedInt",
+    "uri",
+    "url",
+    "uuid",
+    "xhtml",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionUsageIssue {
+    /// Canonical URL of the resource that uses the extension.
+    pub resource: String,
+
+    /// Location of the extension within the resource.
+    pub path: String,
+
+    /// URL of the extension.
+    pub url: String,
+
+    pub kind: ExtensionUsageIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtensionUsageIssueKind {
+    /// The place of use matches none of the definition's contexts.
+    ContextNotAllowed { contexts: Vec<String> },
+
+    /// The value type is not allowed; `found` is none when there is no value.
+    ValueTypeMismatch {
+        expected: Vec<String>,
+        found: Option<String>,
+    },
+
+    /// The extension could not be read; `url` is empty when it has none.
+    Invalid { error: String },
+}
+
+pub struct ExtensionUsageChecker<'a> {
+    structure_definitions: &'a StructureDefinitionRegistry,
+    extensions: &'a ExtensionRegistry,
+}
+
+impl<'a> ExtensionUsageChecker<'a> {
+    pub fn new(
+        structure_definitions: &'a StructureDefinitionRegistry,
+        extensions: &'a ExtensionRegistry,
+    ) -> Self {
+        Self {
+            structure_definitions,
+            extensions,
+        }
+    }
+
+    /// Check every StructureDefinition in the registry.
+    pub fn check_all(&self) -> Vec<ExtensionUsageIssue> {
+        self.structure_definitions
+            .iter()
+            .flat_map(|resource| self.check_resource(resource))
+            .collect()
+    }
+
+    /// Check the extensions used throughout one StructureDefinition.
+    pub fn check_resource(&self, resource: &Resource) -> Vec<ExtensionUsageIssue> {
+        let mut issues = vec![];
+        let mut names = self.context_names("StructureDefinition", "StructureDefinition");
+        names.extend(STRUCTURE_DEFINITION_ANCESTORS.map(String::from));
+        let extensions: &[::serde_json::Value] = match resource.extension.as_ref() {
+            None => &[],
+            Some(::serde_json::Value::Array(extensions)) => extensions,
+            Some(_) => {
+                issues.push(ExtensionUsageIssue {
+                    resource: resource.url.clone(),
+                    path: String::from("StructureDefinition"),
+                    url: String::new(),
+                    kind: ExtensionUsageIssueKind::Invalid {
+                        error: String::from("extension is not an array"),
+                    },
+                });
+                &[]
+            }
+        };
+        for extension in extensions {
+            issues.extend(self.check_extension_json(
+                resource,
+                "StructureDefinition",
+                &names,
+                extension,
+            ));
+        }
+        if let Some(snapshot) = resource.snapshot.as_ref() {
+            issues.extend(self.check_elements(resource, "snapshot", &snapshot.element));
+        }
+        if let Some(differential) = resource.differential.as_ref() {
+            issues.extend(self.check_elements(resource, "differential", &differential.element));
+        }
+        issues
+    }
+
+    fn check_elements(
+        &self,
+        resource: &Resource,
+        label: &str,
+        elements: &[Element],
+    ) -> Vec<ExtensionUsageIssue> {
+        let element_names = self.context_names("ElementDefinition", "ElementDefinition");
+        let binding_names = self.context_names("ElementDefinition.binding", "Element");
+        let type_names = self.context_names("ElementDefinition.type", "Element");
+        let constraint_names = self.context_names("ElementDefinition.constraint", "Element");
+        let mut issues = vec![];
+        for element in elements {
+            let path = format!("StructureDefinition.{label}.element[{}]", element.id);
+            for extension in element.extension.iter().flatten() {
+                issues.extend(self.check_extension(resource, &path, &element_names, extension));
+            }
+            if let Some(binding) = element.binding.as_ref() {
+                let path = format!("{path}.binding");
+                for extension in binding.extension.iter().flatten() {
+                    issues.extend(self.check_extension(resource, &path, &binding_names, extension));
+                }
+            }
+            for element_type in element.r#type.iter().flatten() {
+                let path = format!("{path}.type[{}]", element_type.code);
+                for extension in element_type.extension.iter().flatten() {
+                    issues.extend(self.check_extension(resource, &path, &type_names, extension));
+                }
+            }
+            for constraint in element.constraint.iter().flatten() {
+                let path = format!("{path}.constraint[{}]", constraint.key);
+                for extension in constraint.extension.iter().flatten() {
+                    issues.extend(self.check_extension(
+                        resource,
+                        &path,
+                        &constraint_names,
+                        extension,
+                    ));
+                }
+            }
+        }
+        issues
+    }
+
+    /// Check one extension against its definition, given the names that
+    /// describe its place of use.
+    pub fn check_extension(
+        &self,
+        resource: &Resource,
+        path: &str,
+        names: &[String],
+        extension: &Extension,
+    ) -> Vec<ExtensionUsageIssue> {
+        let value = ::serde_json::to_value(extension).unwrap_or_default();
+        self.check_extension_json(resource, path, names, &value)
+    }
+
+    /// Check one extension, as JSON, against its definition.
+    pub fn check_extension_json(
+        &self,
+        resource: &Resource,
+        path: &str,
+        names: &[String],
+        extension: &::serde_json::Value,
+    ) -> Vec<ExtensionUsageIssue> {
+        let url = extension["url"].as_str();
+        let issue = |kind| ExtensionUsageIssue {
+            resource: resource.url.clone(),
+            path: path.into(),
+            url: url.unwrap_or_default().into(),
+            kind,
+        };
+        let Some(url) = url else {
+            let error = if extension.is_object() {
+                "extension has no url"
+            } else {
+                "extension is not an object"
+            };
+            return vec![issue(ExtensionUsageIssueKind::Invalid {
+                error: error.into(),
+            })];
+        };
+        let Some(definition) = self.extensions.get(url) else {
+            return vec![];
+        };
+        let mut issues = vec![];
+        if !definition.allows_context(names) {
+            issues.push(issue(ExtensionUsageIssueKind::ContextNotAllowed {
+                contexts: definition
+                    .contexts
+                    .iter()
+                    .map(|context| context.expression.clone())
+                    .collect(),
+            }));
+        }
+        let found = extension_value_type(extension);
+        let allowed = match found.as_deref() {
+            Some(code) => definition.allows_value_type(code),
+            None => definition.is_complex(),
+        };
+        if !allowed {
+            issues.push(issue(ExtensionUsageIssueKind::ValueTypeMismatch {
+                expected: definition.value_types.clone(),
+                found,
+            }));
+        }
+        issues
+    }
+
+    /// The names a context expression may use for a place: its element path,
+    /// its type, and the type's ancestors.
+    fn context_names(&self, path: &str, type_name: &str) -> Vec<String> {
+        let mut names = vec![String::from(path), String::from(type_name)];
+        if let Some(resource) = self.structure_definitions.get_type(type_name) {
+            names.extend(
+                self.structure_definitions
+                    .ancestors(resource)
+                    .iter()
+                    .map(|x| x.id.clone()),
+            );
+        }
+        if path.contains('.') {
+            names.extend([String::from("Element"), String::from("Base")]);
+        }
+        names.dedup();
+        names
+    }
+}
+
+/// Given an extension as JSON, return the FHIR type code of its value, if
+/// any, from its `value[x]` key: `valueCoding` is a Coding, and `valueString`
+/// is a string.
+fn extension_value_type(extension: &::serde_json::Value) -> Option<String> {
+    let suffix = extension
+        .as_object()?
+        .keys()
+        .filter_map(|key| key.strip_prefix("value"))
+        .find(|suffix| suffix.starts_with(|c: char| c.is_ascii_uppercase()))?;
+    let lowercase = format!("{}{}", suffix[..1].to_ascii_lowercase(), &suffix[1..]);
+    if PRIMITIVE_TYPES.contains(&lowercase.as_str()) {
+        Some(lowercase)
+    } else {
+        Some(suffix.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::Context;
+    use crate::r5::registry::ExtensionDefinition;
+    use ::serde_json::json;
+
+    const BINDING_NAME: &str =
+        "http://hl7.org/fhir/StructureDefinition/elementdefinition-bindingName";
+
+    fn extensions() -> ExtensionRegistry {
+        [ExtensionDefinition {
+            url: String::from(BINDING_NAME),
+            name: Some(String::from("BindingName")),
+            contexts: vec![Context {
+                r#type: String::from("element"),
+                expression: String::from("ElementDefinition.binding"),
+            }],
+            value_types: vec![String::from("string")],
+        }]
+        .into_iter()
+        .collect()
+    }
+
+    fn resource(binding_extension: ::serde_json::Value) -> Resource {
+        ::serde_json::from_value(json!({
+            "resourceType": "StructureDefinition",
+            "id": "Address",
+            "meta": {},
+            "url": "http://hl7.org/fhir/StructureDefinition/Address",
+            "version": "5.0.0",
+            "status": "active",
+            "experimental": false,
+            "extension": [{ "url": BINDING_NAME, "valueString": "AddressType" }],
+            "snapshot": {
+                "element": [{
+                    "id": "Address.type",
+                    "path": "Address.type",
+                    "binding": {
+                        "strength": "required",
+                        "extension": [binding_extension]
+                    }
+                }]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_resource_with_context_not_allowed() {
+        let structure_definitions = StructureDefinitionRegistry::new();
+        let extensions = extensions();
+        let checker = ExtensionUsageChecker::new(&structure_definitions, &extensions);
+        let resource = resource(json!({ "url": BINDING_NAME, "valueString": "AddressType" }));
+        let actual = checker.check_resource(&resource);
+        let expect = vec![ExtensionUsageIssue {
+            resource: String::from("http://hl7.org/fhir/StructureDefinition/Address"),
+            path: String::from("StructureDefinition"),
+            url: String::from(BINDING_NAME),
+            kind: ExtensionUsageIssueKind::ContextNotAllowed {
+                contexts: vec![String::from("ElementDefinition.binding")],
+            },
+        }];
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_check_resource_with_value_type_mismatch() {
+        let structure_definitions = StructureDefinitionRegistry::new();
+        let extensions = extensions();
+        let checker = ExtensionUsageChecker::new(&structure_definitions, &extensions);
+        let resource = resource(json!({ "url": BINDING_NAME, "valueCode": "AddressType" }));
+        let actual = checker.check_resource(&resource);
+        assert_eq!(actual.len(), 2);
+        assert_eq!(
+            actual[1].path,
+            "StructureDefinition.snapshot.element[Address.type].binding"
+        );
+        assert_eq!(
+            actual[1].kind,
+            ExtensionUsageIssueKind::ValueTypeMismatch {
+                expected: vec![String::from("string")],
+                found: Some(String::from("code")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_resource_with_complex_value_type() {
+        let structure_definitions = StructureDefinitionRegistry::new();
+        let extensions = extensions();
+        let checker = ExtensionUsageChecker::new(&structure_definitions, &extensions);
+        let mut resource = resource(json!({ "url": BINDING_NAME, "valueString": "AddressType" }));
+        resource.extension = Some(json!([{
+            "url": BINDING_NAME,
+            "valueCoding": { "system": "http://example.org", "code": "a" }
+        }]));
+        let actual = checker.check_resource(&resource);
+        assert_eq!(
+            actual[1].kind,
+            ExtensionUsageIssueKind::ValueTypeMismatch {
+                expected: vec![String::from("string")],
+                found: Some(String::from("Coding")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_resource_with_invalid_extension() {
+        let structure_definitions = StructureDefinitionRegistry::new();
+        let extensions = extensions();
+        let checker = ExtensionUsageChecker::new(&structure_definitions, &extensions);
+        let mut resource = resource(json!({ "url": BINDING_NAME, "valueString": "AddressType" }));
+        resource.extension = Some(json!([{ "valueString": "a" }, { "url": BINDING_NAME }]));
+        let actual = checker.check_resource(&resource);
+        assert_eq!(actual.len(), 3);
+        assert_eq!(
+            actual[0].kind,
+            ExtensionUsageIssueKind::Invalid {
+                error: String::from("extension has no url"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_extension_value_type() {
+        let code = |value| extension_value_type(&value);
+        assert_eq!(code(json!({ "url": "x" })), None);
+        assert_eq!(
+            code(json!({ "valueString": "a" })).as_deref(),
+            Some("string")
+        );
+        assert_eq!(
+            code(json!({ "valueDateTime": "2020" })).as_deref(),
+            Some("dateTime")
+        );
+        assert_eq!(
+            code(json!({ "valueInteger64": "1" })).as_deref(),
+            Some("integer64")
+        );
+        assert_eq!(
+            code(json!({ "valueCodeableConcept": {} })).as_deref(),
+            Some("CodeableConcept")
+        );
+        assert_eq!(
+            code(json!({ "valueReference": {} })).as_deref(),
+            Some("Reference")
+        );
+    }
+
+    #[test]
+    fn test_check_all_with_definitions_file() {
+        let structure_definitions = StructureDefinitionRegistry::from_definitions_file().unwrap();
+        let extensions = extensions();
+        let checker = ExtensionUsageChecker::new(&structure_definitions, &extensions);
+        assert_eq!(checker.check_all(), vec![]);
+    }
+}
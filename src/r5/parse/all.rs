@@ -43,6 +43,11 @@ pub mod contact {
 }
 pub use contact::contact::Contact;
 
+pub mod context {
+    pub mod context;
+}
+pub use context::context::Context;
+
 pub mod depends_on {
     pub mod depends_on;
 }
@@ -0,0 +1,85 @@
+//! # context
+//!
+//! ## Description
+//!
+//! The `context` property identifies the places where an extension
+//! StructureDefinition may be used. Each context pairs a `type` that says how
+//! to read the expression with an `expression` that names the place.
+//!
+//! ## Purpose
+//!
+//! - Restrict where an extension may appear in a resource or datatype
+//! - Let validators reject extensions that are used out of place
+//! - Document the intended scope of an extension definition
+//!
+//! ## Usage
+//!
+//! The `context` property is used within StructureDefinition resources whose
+//! `type` is `Extension`. It is absent on other StructureDefinitions.
+//!
+//! ## Data Type
+//!
+//! **BackboneElement** - A complex structure containing:
+//!
+//! - **type** (code) - fhirpath, element, or extension
+//! - **expression** (string) - Where the extension can be used
+//!
+//! ## Constraints
+//!
+//! - **Required**: Yes for extension definitions
+//! - **Cardinality**: 0..* (zero to many contexts)
+//! - **Type Values**: Must be one of: fhirpath, element, extension
+//!
+//! ## Examples
+//!
+//! ```json
+//! {
+//!   "context": [
+//!     {
+//!       "type": "element",
+//!       "expression": "ElementDefinition"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! ## Related Keys
+//!
+//! - `contextInvariant` - FHIRPath invariants that apply to the context
+//! - `type` - Always `Extension` when `context` is present
+//!
+//! ## Specification Reference
+//!
+//! FHIR R5 StructureDefinition:
+//! [context](http://hl7.org/fhir/R5/structuredefinition-definitions.html#StructureDefinition.context)
+
+use ::serde::{Deserialize, Serialize};
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct Context {
+    /// Example: "element"
+    pub r#type: String,
+
+    /// Example: "ElementDefinition"
+    pub expression: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    type T = Context;
+
+    #[test]
+    fn test_serde_json_from_reader() {
+        let path = crate::r5::parse::all::DIR
+            .join("context")
+            .join("context.json");
+        let file = std::fs::File::open(path).expect("open");
+        let reader = std::io::BufReader::new(file);
+        let actual: T = ::serde_json::from_reader(reader).unwrap();
+        assert_eq!(actual.r#type, "element");
+    }
+}
@@ -179,6 +179,8 @@ mod tests {
         let resource = Resource::default();
         let actual = resource_into_rust(&resource);
         assert!(actual.is_ok());
+        // Keep the golden files of `./tmp/out` free of the test file.
+        let _ = std::fs::remove_file(resource_into_rust_struct_path(&resource));
     }
 
     #[test]
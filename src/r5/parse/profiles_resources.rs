@@ -55,7 +55,7 @@ pub mod resource {
     pub mod resource_into_rust;
 }
 pub use resource::resource::Resource;
-pub use resource::resource_into_rust::resource_into_rust;
+pub use resource::resource_into_rust::{resource_into_rust, resource_into_structure_definition};
 
 pub mod resource_inner {
     pub mod resource_inner;
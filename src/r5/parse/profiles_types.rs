@@ -28,12 +28,74 @@ pub mod discriminator {
 }
 pub use discriminator::discriminator::Discriminator;
 
+pub mod cargo_features {
+    pub mod cargo_features;
+}
+pub use cargo_features::cargo_features::{
+    FEATURES_FILE_NAME, FULL_FEATURE, STRUCTUREDEFINITION_CATEGORY_URL, features_cfg_attribute,
+    resource_category_feature_name, resource_enum_features, resource_feature_name,
+    resources_into_cargo_features, resources_into_cargo_features_block,
+};
+
+pub mod codegen_build {
+    pub mod codegen_build;
+}
+pub use codegen_build::codegen_build::{CodegenBuild, OUT_DIR_FILE_NAME};
+
+#[cfg(test)]
+pub mod codegen_check {
+    pub mod codegen_check;
+}
+
+pub mod codegen_golden {
+    pub mod codegen_golden;
+}
+pub use codegen_golden::codegen_golden::{
+    GOLDEN_DIR, SEARCH_PARAMETERS_GOLDEN_DIR, golden_context, load_profiles_types,
+    load_search_parameters,
+};
+
+pub mod codegen_config {
+    pub mod codegen_config;
+}
+pub use codegen_config::codegen_config::{
+    CodegenConfig, DEFAULT_HEADER_TEMPLATE, ModuleLayout, resource_kind_name,
+};
+
+pub mod codegen_context {
+    pub mod codegen_context;
+}
+pub use codegen_context::codegen_context::{BINDING_NAME_URL, CodeEnum, CodegenContext};
+
 pub mod element {
     pub mod element;
+    pub mod element_into_rust_choice_enum;
+    pub mod element_into_rust_code_enum;
+    pub mod element_into_rust_doc;
     pub mod element_into_rust_struct_attribute;
+    pub mod element_into_rust_type;
 }
 pub use element::element::Element;
+pub use element::element_into_rust_choice_enum::{
+    CHOICE_BLOCK, CHOICE_MODULE, UNKNOWN_FIELDS_ATTRIBUTE, UNKNOWN_FIELDS_BLOCK,
+    UNKNOWN_FIELDS_MODULE, choice_json_key, choice_sibling_codes, choice_sibling_enum_name,
+    element_has_choice_sibling, element_into_rust_choice_enum,
+    element_into_rust_choice_sibling_enum, element_is_choice, element_is_flattened_choice,
+};
+pub use element::element_into_rust_code_enum::{
+    code_enum_into_rust_enum, code_enum_into_rust_variants, code_into_rust_variant_name,
+};
+pub use element::element_into_rust_doc::{
+    FHIR_SPEC_BASE_URL, element_into_rust_doc_comment, element_into_rust_doc_paragraphs,
+    markdown_into_rustdoc,
+};
 pub use element::element_into_rust_struct_attribute::element_into_rust_struct_attribute;
+pub use element::element_into_rust_type::{
+    Cardinality, element_has_primitive_sibling, element_into_json_name,
+    element_into_rust_field_type, element_into_rust_item_type, element_into_rust_type,
+    element_is_inline_struct, json_name_into_rust_field_name, json_name_needs_serde_rename,
+    path_into_rust_struct_name, type_code_into_rust_type, type_code_is_primitive,
+};
 
 pub mod entry {
     pub mod entry;
@@ -46,11 +108,79 @@ pub mod mapping {
 pub use mapping::mapping::Mapping;
 
 pub mod resource {
+    pub mod elements_into_rust_builder;
+    pub mod elements_into_rust_extensions;
+    pub mod elements_into_rust_reflect;
+    pub mod elements_into_rust_validate;
+    pub mod elements_into_rust_visit;
     pub mod resource;
     pub mod resource_into_rust;
+    pub mod resource_into_rust_primitive;
+    pub mod resource_into_rust_profile;
+    pub mod resources_into_rust;
+    pub mod resources_into_rust_enum;
+    pub mod resources_into_rust_mod;
+    pub mod resources_into_rust_single_file;
 }
+pub use resource::elements_into_rust_builder::{
+    BUILDER_ERROR_BLOCK, BUILDER_MODULE, elements_into_rust_builder_block,
+};
+pub use resource::elements_into_rust_extensions::{
+    CORE_EXTENSION_URL_PREFIX, EXTENSIONS_MODULE, HAS_EXTENSIONS_BLOCK,
+    elements_into_rust_has_extensions_block, extension_accessor_name,
+    resource_into_rust_has_extensions_profile_block, resources_into_rust_extensions_block,
+};
+pub use resource::elements_into_rust_reflect::{
+    REFLECT_BLOCK, REFLECT_MODULE, code_enum_into_rust_reflect_block,
+    element_into_rust_element_info, element_into_rust_reflect_choice_block,
+    elements_into_rust_reflect_block, resource_into_rust_reflect_primitive_block,
+    resource_into_rust_reflect_profile_block, resources_into_rust_enum_reflect_block,
+};
+pub use resource::elements_into_rust_validate::{
+    VALIDATE_BLOCK, VALIDATE_MODULE, code_enum_into_rust_validate_block,
+    element_into_rust_validate_choice_block, element_into_rust_validate_choice_sibling_block,
+    elements_into_rust_validate_block, resource_into_rust_validate_primitive_block,
+    resource_into_rust_validate_profile_block, resources_into_rust_enum_validate_block,
+};
+pub use resource::elements_into_rust_visit::{
+    VISIT_MODULE, WALK_BLOCK, code_enum_into_rust_walk_block, element_into_rust_walk_choice_block,
+    element_into_rust_walk_choice_sibling_block, elements_into_rust_walk_block,
+    resource_into_rust_walk_primitive_block, resource_into_rust_walk_profile_block,
+    resources_into_rust_enum_walk_block, resources_into_rust_visit_block, visit_method_name,
+};
 pub use resource::resource::Resource;
 pub use resource::resource_into_rust::resource_into_rust;
+pub use resource::resource_into_rust_primitive::{
+    primitive_into_rust_value_type, resource_into_rust_primitive_block,
+};
+pub use resource::resource_into_rust_profile::{
+    PROFILE_MODULE, PROFILE_RULES_BLOCK, element_fixed_value, element_pattern_value,
+    resource_into_rust_profile_block, resource_into_rust_profile_tests_block,
+    resource_is_constraint_profile, resource_profile_rules,
+};
+pub use resource::resources_into_rust::resources_into_rust;
+pub use resource::resources_into_rust_enum::{
+    RESOURCE_ENUM_MODULE, resource_is_abstract_resource, resource_is_concrete_resource,
+    resources_into_rust_enum, resources_into_rust_enum_block,
+};
+pub use resource::resources_into_rust_mod::{
+    resources_into_rust_mod, resources_into_rust_mod_block,
+};
+pub use resource::resources_into_rust_single_file::{
+    resources_into_rust_single_file, resources_into_rust_single_file_block,
+};
+
+pub mod type_dependencies {
+    pub mod type_dependencies;
+}
+pub use type_dependencies::type_dependencies::{
+    resource_type_dependencies, resources_type_closure,
+};
+
+pub mod type_graph {
+    pub mod type_graph;
+}
+pub use type_graph::type_graph::{TypeEdge, TypeGraph};
 
 pub mod slicing {
     pub mod slicing;
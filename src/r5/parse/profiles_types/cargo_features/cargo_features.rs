@@ -0,0 +1,285 @@
+//! Cargo features
+//!
+//! A crate of every generated type compiles slowly, and a service may use a
+//! few resources. With features in the config, each generated module has a
+//! cargo feature, named by its type, such as `Patient`, that enables the
+//! features of the types that it depends on; see `resource_type_dependencies`.
+//!
+//! Each resource category has a feature, such as `base_individuals`, that
+//! enables the features of its resources. The category comes from the
+//! `structuredefinition-category` extension, such as `Base.Individuals`; a
+//! type without one, such as `Period`, is in the feature of its
+//! StructureDefinition kind, such as `complex_types`. The `full` feature
+//! enables every category, and is the default, so that a crate can opt in:
+//!
+//! ```toml
+//! my-fhir-types = { default-features = false, features = ["Patient", "Observation"] }
+//! ```
+//!
+//! The generator writes the `[features]` table into `features.toml`, in the
+//! output directory, to copy into the Cargo.toml of the crate.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::*;
+use crate::util::write_if_changed;
+use ::convert_case::{Case, Casing};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The feature that enables every generated type.
+pub const FULL_FEATURE: &str = "full";
+
+/// The file name of the `[features]` table in the output directory.
+pub const FEATURES_FILE_NAME: &str = "features.toml";
+
+/// The url of the extension that gives the category of a resource.
+pub const STRUCTUREDEFINITION_CATEGORY_URL: &str =
+    "http://hl7.org/fhir/StructureDefinition/structuredefinition-category";
+
+/// Given one resource, return the name of its cargo feature, such as
+/// `Patient` or `DateTime`.
+pub fn resource_feature_name(resource: &Resource) -> SourceCodeString {
+    resource.id.to_case(Case::Pascal)
+}
+
+/// Given one resource, return the name of its category feature, such as
+/// `base_individuals` for the category `Base.Individuals`, or the name of
+/// its StructureDefinition kind when it has no category, such as
+/// `complex_types`.
+pub fn resource_category_feature_name(resource: &Resource) -> SourceCodeString {
+    resource
+        .extension
+        .as_ref()
+        .and_then(|x| x.as_array())
+        .into_iter()
+        .flatten()
+        .find(|x| x["url"] == STRUCTUREDEFINITION_CATEGORY_URL)
+        .and_then(|x| x["valueString"].as_str())
+        .map(|x| x.replace('.', "_").to_case(Case::Snake))
+        .unwrap_or_else(|| String::from(resource_kind_name(resource)))
+}
+
+/// Given the features that enable an item, return the `cfg` attribute line
+/// that gates the item, or nothing when the config has no features.
+///
+/// Example:
+///
+/// ```no_run
+/// features_cfg_attribute(&config, &["Patient"]) => "#[cfg(feature = \"Patient\")]\n"
+/// features_cfg_attribute(&config, &["Bundle", "Patient"]) => "#[cfg(any(feature = \"Bundle\", feature = \"Patient\"))]\n"
+/// ```
+///
+pub fn features_cfg_attribute<S: AsRef<str>>(
+    config: &CodegenConfig,
+    features: &[S],
+) -> SourceCodeString {
+    if !config.features {
+        return String::new();
+    }
+    match features {
+        [] => String::new(),
+        [feature] => format!("#[cfg(feature = \"{}\")]\n", feature.as_ref()),
+        _ => format!(
+            "#[cfg(any({}))]\n",
+            features
+                .iter()
+                .map(|x| format!("feature = \"{}\"", x.as_ref()))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// The features that enable the `Resource` enum: one per concrete resource.
+pub fn resource_enum_features(resources: &[Resource]) -> Vec<SourceCodeString> {
+    let features: BTreeSet<String> = resources
+        .iter()
+        .filter(|x| resource_is_concrete_resource(x))
+        .map(resource_feature_name)
+        .collect();
+    features.into_iter().collect()
+}
+
+/// Given one feature name and the features that it enables, return its TOML
+/// line.
+fn feature_line<'a>(name: &str, features: impl IntoIterator<Item = &'a String>) -> String {
+    format!(
+        "{name} = [{}]\n",
+        features
+            .into_iter()
+            .map(|x| format!("{x:?}"))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+/// FHIR resources => the `[features]` table of a Cargo.toml.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. resource ids dateTime, Period, Patient.
+/// resources_into_cargo_features_block(&resources)
+/// ```
+///
+/// Output:
+///
+/// ```toml
+/// [features]
+/// default = ["full"]
+/// full = ["base_individuals", "complex_types", "primitive_types"]
+/// base_individuals = ["Patient"]
+/// complex_types = ["Period"]
+/// primitive_types = ["DateTime"]
+/// DateTime = []
+/// Patient = ["Period"]
+/// Period = ["DateTime"]
+/// ```
+///
+/// A dependency that is not a generated type, such as the `Resource` enum,
+/// has no feature.
+///
+#[allow(dead_code)]
+pub fn resources_into_cargo_features_block(resources: &[Resource]) -> SourceCodeString {
+    let resources: Vec<&Resource> = resources
+        .iter()
+        .filter(|x| !x.id.is_empty() && !resource_is_abstract_resource(x))
+        .collect();
+    let names: BTreeSet<String> = resources.iter().map(|x| resource_feature_name(x)).collect();
+    let mut categories: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut features: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for resource in resources {
+        let name = resource_feature_name(resource);
+        categories
+            .entry(resource_category_feature_name(resource))
+            .or_default()
+            .insert(name.clone());
+        features.entry(name).or_default().extend(
+            resource_type_dependencies(resource)
+                .into_iter()
+                .filter(|x| names.contains(x)),
+        );
+    }
+    let mut block = String::from("[features]\n");
+    block.push_str(&feature_line("default", &[String::from(FULL_FEATURE)]));
+    block.push_str(&feature_line(FULL_FEATURE, categories.keys()));
+    for (category, names) in &categories {
+        block.push_str(&feature_line(category, names));
+    }
+    for (name, dependencies) in &features {
+        block.push_str(&feature_line(name, dependencies));
+    }
+    block
+}
+
+/// FHIR resources => `features.toml` in the output directory of the config.
+#[allow(dead_code)]
+pub fn resources_into_cargo_features(
+    resources: &[Resource],
+    context: &CodegenContext,
+) -> std::io::Result<()> {
+    write_if_changed(
+        &context.config.output_dir.join(FEATURES_FILE_NAME),
+        resources_into_cargo_features_block(resources),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn resource(id: &str, kind: &str, codes: &[&str]) -> Resource {
+        let element = |code: &&str| Element {
+            id: format!("{id}.{code}"),
+            path: format!("{id}.{code}"),
+            r#type: Some(vec![ElementType {
+                code: String::from(*code),
+                ..ElementType::default()
+            }]),
+            ..Element::default()
+        };
+        Resource {
+            id: id.into(),
+            kind: Some(String::from(kind)),
+            snapshot: Some(Snapshot {
+                element: codes.iter().map(element).collect(),
+                ..Snapshot::default()
+            }),
+            ..Resource::default()
+        }
+    }
+
+    fn resource_with_category(id: &str, category: &str, codes: &[&str]) -> Resource {
+        Resource {
+            extension: Some(::serde_json::json!([{
+                "url": STRUCTUREDEFINITION_CATEGORY_URL,
+                "valueString": category,
+            }])),
+            ..resource(id, "resource", codes)
+        }
+    }
+
+    #[test]
+    fn test_features_cfg_attribute() {
+        let config = CodegenConfig {
+            features: true,
+            ..CodegenConfig::new()
+        };
+        assert_eq!(
+            features_cfg_attribute(&config, &["Patient"]),
+            "#[cfg(feature = \"Patient\")]\n"
+        );
+        assert_eq!(
+            features_cfg_attribute(&config, &["Bundle", "Patient"]),
+            "#[cfg(any(feature = \"Bundle\", feature = \"Patient\"))]\n"
+        );
+        assert_eq!(
+            features_cfg_attribute(&CodegenConfig::new(), &["Patient"]),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_resource_category_feature_name() {
+        assert_eq!(
+            resource_category_feature_name(&resource_with_category(
+                "Patient",
+                "Base.Individuals",
+                &[]
+            )),
+            "base_individuals"
+        );
+        assert_eq!(
+            resource_category_feature_name(&resource("Period", "complex-type", &[])),
+            "complex_types"
+        );
+    }
+
+    #[test]
+    fn test_resources_into_cargo_features_block() {
+        let resources = vec![
+            resource("dateTime", "primitive-type", &[]),
+            resource("Period", "complex-type", &["dateTime"]),
+            resource_with_category("Patient", "Base.Individuals", &["Period", "Resource"]),
+            resource_with_category("Observation", "Clinical.Diagnostics", &["Patient"]),
+            resource_with_category("Group", "Base.Individuals", &[]),
+        ];
+        let actual = resources_into_cargo_features_block(&resources);
+        let expect = concat!(
+            "[features]\n",
+            "default = [\"full\"]\n",
+            "full = [\"base_individuals\", \"clinical_diagnostics\", \"complex_types\", \"primitive_types\"]\n",
+            "base_individuals = [\"Group\", \"Patient\"]\n",
+            "clinical_diagnostics = [\"Observation\"]\n",
+            "complex_types = [\"Period\"]\n",
+            "primitive_types = [\"DateTime\"]\n",
+            "DateTime = []\n",
+            "Group = []\n",
+            "Observation = [\"Patient\"]\n",
+            "Patient = [\"Period\"]\n",
+            "Period = [\"DateTime\"]\n",
+        );
+        assert_eq!(actual, expect);
+    }
+}
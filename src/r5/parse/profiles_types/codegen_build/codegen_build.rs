@@ -0,0 +1,225 @@
+//! Codegen build
+//!
+//! Generate selected FHIR types at build time, from a build script of
+//! another crate, so that the crate always matches the FHIR specification
+//! files that it pins.
+//!
+//! Example `build.rs`:
+//!
+//! ```text
+//! fn main() {
+//!     ::fhir_specifications_parser::r5::parse::profiles_types::CodegenBuild::new()
+//!         .with_include(["Patient", "Observation"])
+//!         .generate_into_out_dir()
+//!         .expect("generate FHIR types");
+//! }
+//! ```
+//!
+//! Example `lib.rs`:
+//!
+//! ```no_run
+//! pub mod fhir {
+//!     include!(concat!(env!("OUT_DIR"), "/fhir.rs"));
+//! }
+//! ```
+//!
+//! The build generates each included type, and each type that it depends
+//! on; see `resources_type_closure`.
+//!
+//! The crate needs the serde_json `arbitrary_precision` feature, so that a
+//! `decimal` keeps its exact digits, such as `1.50`:
+//!
+//! ```text
+//! serde_json = { version = "1", features = ["arbitrary_precision"] }
+//! ```
+
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use std::io::BufReader;
+use std::path::PathBuf;
+
+/// The file name of the generated types in `OUT_DIR`.
+pub const OUT_DIR_FILE_NAME: &str = "fhir.rs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodegenBuild {
+    /// The FHIR Bundle JSON files of StructureDefinitions, such as
+    /// `profiles-types.json` and `profiles-resources.json`.
+    pub definitions_files: Vec<PathBuf>,
+
+    /// The names of the included types, such as `Patient`, or empty to
+    /// include every type.
+    pub include: Vec<String>,
+
+    pub config: CodegenConfig,
+}
+
+impl Default for CodegenBuild {
+    fn default() -> Self {
+        Self {
+            definitions_files: vec![DEFINITIONS_FILE.clone()],
+            include: vec![],
+            config: CodegenConfig {
+                layout: ModuleLayout::SingleFile,
+                crate_path: String::from("crate::fhir"),
+                ..CodegenConfig::new()
+            },
+        }
+    }
+}
+
+impl CodegenBuild {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the StructureDefinitions of these files, instead of the default
+    /// `profiles-types.json`.
+    pub fn with_definitions_files<I, P>(mut self, definitions_files: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        self.definitions_files = definitions_files.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Include these types, such as `Patient`, and their dependencies.
+    pub fn with_include<I, S>(mut self, include: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.include = include.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_config(mut self, config: CodegenConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Load the StructureDefinitions of the definitions files, without the
+    /// abstract ones, and keep the included types and their dependencies.
+    ///
+    /// An included name that matches no StructureDefinition is an error, so
+    /// that a typo fails the build.
+    pub fn load_resources(&self) -> std::io::Result<Vec<Resource>> {
+        let mut resources: Vec<Resource> = vec![];
+        for path in &self.definitions_files {
+            let file = std::fs::File::open(path)?;
+            let bundle: ::serde_json::Value = ::serde_json::from_reader(BufReader::new(file))?;
+            for entry in bundle["entry"].as_array().into_iter().flatten() {
+                if entry["resource"]["resourceType"] == "StructureDefinition" {
+                    resources.push(::serde_json::from_value(entry["resource"].clone())?);
+                }
+            }
+        }
+        resources.retain(|x| !resource_is_abstract_resource(x));
+        if self.include.is_empty() {
+            return Ok(resources);
+        }
+        if let Some(name) = self.include.iter().find(|name| {
+            !resources
+                .iter()
+                .any(|x| x.id.to_case(Case::Pascal) == name.to_case(Case::Pascal))
+        }) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("no StructureDefinition for the included type {name}"),
+            ));
+        }
+        let closure = resources_type_closure(&resources, &self.include);
+        resources.retain(|x| closure.contains(&x.id.to_case(Case::Pascal)));
+        Ok(resources)
+    }
+
+    /// Generate the types, by the config.
+    pub fn generate(&self) -> std::io::Result<()> {
+        let resources = self.load_resources()?;
+        let context = CodegenContext {
+            config: self.config.clone(),
+            ..CodegenContext::from_definitions_files().with_boxed_paths(&resources)
+        };
+        resources_into_rust(&resources, &context)
+    }
+
+    /// Generate the types into one file of the `OUT_DIR` of a build script,
+    /// and return its path, such as `$OUT_DIR/fhir.rs`.
+    ///
+    /// Tell cargo to rerun the build script when a definitions file changes.
+    pub fn generate_into_out_dir(mut self) -> std::io::Result<PathBuf> {
+        let out_dir = std::env::var_os("OUT_DIR")
+            .map(PathBuf::from)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "OUT_DIR is not set; generate from a build script",
+                )
+            })?;
+        self.config.layout = ModuleLayout::SingleFile;
+        self.config.mod_file = out_dir.join(OUT_DIR_FILE_NAME);
+        self.config.output_dir = out_dir;
+        for path in &self.definitions_files {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+        self.generate()?;
+        Ok(self.config.mod_file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_resources_with_include() {
+        let resources = CodegenBuild::new()
+            .with_include(["Dosage"])
+            .load_resources()
+            .unwrap();
+        let ids: Vec<&str> = resources.iter().map(|x| x.id.as_str()).collect();
+        for id in [
+            "Dosage",
+            "Timing",
+            "Quantity",
+            "Element",
+            "Extension",
+            "dateTime",
+        ] {
+            assert!(ids.contains(&id), "{id} {:?}", ids);
+        }
+        assert!(!ids.contains(&"MoneyQuantity"), "{:?}", ids);
+    }
+
+    #[test]
+    fn test_load_resources_with_unknown_include() {
+        let actual = CodegenBuild::new()
+            .with_include(["Alfa"])
+            .load_resources()
+            .unwrap_err();
+        assert_eq!(actual.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_generate() {
+        let dir = std::env::temp_dir().join(format!("codegen_build_{}", std::process::id()));
+        let build = CodegenBuild::new().with_include(["Period"]);
+        let build = CodegenBuild {
+            config: CodegenConfig {
+                mod_file: dir.join(OUT_DIR_FILE_NAME),
+                ..build.config.clone()
+            },
+            ..build
+        };
+        build.generate().unwrap();
+        let actual = std::fs::read_to_string(dir.join(OUT_DIR_FILE_NAME)).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(
+            actual.contains("pub mod period {\n    //! Period\n"),
+            "{}",
+            actual
+        );
+        assert!(!actual.contains("pub mod money_quantity {"), "{}", actual);
+    }
+}
@@ -0,0 +1,38 @@
+//! Builders
+//!
+//! A builder takes literals for primitive attributes, such as a `&str` for a
+//! `string` or an integer for a `decimal`, and its `build()` lists every
+//! required element that is not set.
+
+use ::fhir_codegen_check::types::{self, Narrative, Quantity};
+use ::serde_json::json;
+
+#[test]
+fn test_build() {
+    let quantity = Quantity::builder().value(1).unit("mg").build().unwrap();
+    assert_eq!(
+        ::serde_json::to_value(&quantity).unwrap(),
+        json!({ "value": 1, "unit": "mg" })
+    );
+}
+
+#[test]
+fn test_build_with_missing_elements() {
+    let actual = Narrative::builder()
+        .div(types::Xhtml::from("<div/>"))
+        .build();
+    assert_eq!(
+        actual,
+        Err(types::MissingElements {
+            paths: vec!["Narrative.status"],
+        })
+    );
+}
+
+#[test]
+fn test_from_literals() {
+    assert_eq!(types::String::from("a").value, "a");
+    assert_eq!(types::Code::from("final").value, "final");
+    assert_eq!(types::Decimal::from(2_i64).value, 2.into());
+    assert_eq!(types::Decimal::from(3_u32).value, 3.into());
+}
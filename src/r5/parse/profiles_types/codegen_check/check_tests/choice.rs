@@ -0,0 +1,78 @@
+//! Choice elements
+//!
+//! A choice element, such as `Extension.value[x]`, reads and writes keys
+//! such as `valueString`, and the id and extensions of a primitive value
+//! in a sibling key, such as `_valueString`. A value of the wrong type fails,
+//! rather than reading as none. Its struct rejects unknown fields, like a
+//! struct without a choice.
+
+use ::fhir_codegen_check::types::extension::{ExtensionValue, ExtensionValueElement};
+use ::fhir_codegen_check::types::{self, Extension};
+use ::serde_json::json;
+
+#[test]
+fn test_serde_json_round_trip_with_sibling() {
+    let value = json!({ "url": "x", "valueString": "a", "_valueString": { "id": "q" } });
+    let extension: Extension = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(
+        extension.value,
+        Some(ExtensionValue::String(types::String::from("a")))
+    );
+    assert_eq!(
+        extension.value_element,
+        Some(ExtensionValueElement::String(types::Element {
+            id: Some(String::from("q")),
+            ..Default::default()
+        }))
+    );
+    assert_eq!(::serde_json::to_value(&extension).unwrap(), value);
+}
+
+#[test]
+fn test_serde_json_round_trip_without_sibling() {
+    let value = json!({ "url": "x", "valueString": "a" });
+    let extension: Extension = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(extension.value_element, None);
+    assert_eq!(::serde_json::to_value(&extension).unwrap(), value);
+}
+
+#[test]
+fn test_serde_json_round_trip_with_sibling_only() {
+    let value =
+        json!({ "url": "x", "_valueBoolean": { "extension": [{ "url": "y", "valueCode": "z" }] } });
+    let extension: Extension = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(extension.value, None);
+    assert!(matches!(
+        extension.value_element,
+        Some(ExtensionValueElement::Boolean(_))
+    ));
+    assert_eq!(::serde_json::to_value(&extension).unwrap(), value);
+}
+
+#[test]
+fn test_unknown_fields() {
+    let error =
+        ::serde_json::from_value::<Extension>(json!({ "url": "x", "valueString": "a", "foo": 1 }))
+            .unwrap_err();
+    assert!(error.to_string().contains("unknown field `foo`"), "{error}");
+    assert!(::serde_json::from_value::<types::Coding>(json!({ "code": "a", "foo": 1 })).is_err());
+}
+
+#[test]
+fn test_wrong_type() {
+    let error =
+        ::serde_json::from_value::<Extension>(json!({ "url": "http://x", "valueString": 5 }))
+            .unwrap_err();
+    assert!(error.to_string().contains("invalid type"), "{error}");
+    let value = json!({ "url": "x", "valueQuantity": { "value": "notanumber" } });
+    assert!(::serde_json::from_value::<Extension>(value).is_err());
+    let value = json!({ "url": "x", "valueString": "a", "_valueString": 5 });
+    assert!(::serde_json::from_value::<Extension>(value).is_err());
+}
+
+#[test]
+fn test_more_than_one_type() {
+    let value = json!({ "url": "x", "valueString": "a", "valueBoolean": true });
+    let error = ::serde_json::from_value::<Extension>(value).unwrap_err();
+    assert!(error.to_string().contains("more than one"), "{error}");
+}
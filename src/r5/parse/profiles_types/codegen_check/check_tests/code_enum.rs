@@ -0,0 +1,46 @@
+//! Code enums
+//!
+//! A `code` element with a required binding is a Rust enum of the codes of
+//! its ValueSet, such as `NarrativeStatus`. A 1..1 field defaults to the first
+//! code, and a 0..1 field is an `Option`.
+
+use ::fhir_codegen_check::types::narrative::NarrativeStatus;
+use ::fhir_codegen_check::types::quantity::QuantityComparator;
+use ::fhir_codegen_check::types::{Narrative, Quantity};
+use ::serde_json::json;
+
+#[test]
+fn test_required_field_default() {
+    let narrative = Narrative::default();
+    assert_eq!(narrative.status, NarrativeStatus::Generated);
+    assert_eq!(
+        ::serde_json::to_value(&narrative).unwrap()["status"],
+        json!("generated")
+    );
+}
+
+#[test]
+fn test_serde_json_round_trip() {
+    let value = json!({ "status": "additional", "div": "<div/>" });
+    let narrative: Narrative = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(narrative.status, NarrativeStatus::Additional);
+    assert_eq!(::serde_json::to_value(&narrative).unwrap(), value);
+
+    let value = json!({ "value": 1.5, "comparator": "<=" });
+    let quantity: Quantity = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(quantity.comparator, Some(QuantityComparator::LessOrEqualTo));
+    assert_eq!(::serde_json::to_value(&quantity).unwrap(), value);
+}
+
+#[test]
+fn test_unknown_code() {
+    let value = json!({ "status": "draft", "div": "<div/>" });
+    assert!(::serde_json::from_value::<Narrative>(value).is_err());
+    assert!("draft".parse::<NarrativeStatus>().is_err());
+}
+
+#[test]
+fn test_display_and_from_str() {
+    assert_eq!(QuantityComparator::GreaterOrEqualTo.to_string(), ">=");
+    assert_eq!("ad".parse(), Ok(QuantityComparator::Ad));
+}
@@ -0,0 +1,27 @@
+//! Decimals
+//!
+//! A `decimal` keeps its exact digits, such as `1.50`, with the serde_json
+//! `arbitrary_precision` feature, both as a field, such as `Quantity.value`,
+//! and as a choice, such as `Extension.valueDecimal`.
+
+use ::fhir_codegen_check::types::{Extension, Quantity};
+
+#[test]
+fn test_serde_json_round_trip() {
+    let json = r#"{"value":1.50,"unit":"mg"}"#;
+    let quantity: Quantity = ::serde_json::from_str(json).unwrap();
+    assert_eq!(quantity.value.as_ref().unwrap().value.to_string(), "1.50");
+    assert_eq!(::serde_json::to_string(&quantity).unwrap(), json);
+}
+
+#[test]
+fn test_serde_json_round_trip_in_choice() {
+    for json in [
+        r#"{"url":"x","valueDecimal":1.50}"#,
+        r#"{"url":"x","valueInteger":5}"#,
+        r#"{"url":"x","valueQuantity":{"value":0.010}}"#,
+    ] {
+        let extension: Extension = ::serde_json::from_str(json).unwrap();
+        assert_eq!(::serde_json::to_string(&extension).unwrap(), json);
+    }
+}
@@ -0,0 +1,38 @@
+//! Repeated primitives
+//!
+//! FHIR JSON pads the values of a repeated primitive with `null`, to line up
+//! with the ids and extensions of its sibling key, such as `_line`.
+
+use ::fhir_codegen_check::types::{self, Address};
+use ::serde_json::json;
+
+#[test]
+fn test_serde_json_round_trip_with_null_items() {
+    let value = json!({ "line": ["a", null], "_line": [null, { "id": "l" }] });
+    let address: Address = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(address.line, vec![Some(types::String::from("a")), None]);
+    assert_eq!(
+        address.line_element,
+        vec![
+            None,
+            Some(types::Element {
+                id: Some(String::from("l")),
+                ..Default::default()
+            })
+        ]
+    );
+    assert_eq!(::serde_json::to_value(&address).unwrap(), value);
+}
+
+#[test]
+fn test_builder() {
+    let address = Address::builder()
+        .line(["a", "b"])
+        .push_line("c")
+        .build()
+        .unwrap();
+    assert_eq!(
+        ::serde_json::to_value(&address).unwrap(),
+        json!({ "line": ["a", "b", "c"] })
+    );
+}
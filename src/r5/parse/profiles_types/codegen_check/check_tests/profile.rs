@@ -0,0 +1,76 @@
+//! Profile rules
+//!
+//! The generated profile rules check a value against the rules of a
+//! constraint profile, such as the slice of the vital signs category.
+
+use ::fhir_codegen_check::types::profile::*;
+use ::serde_json::json;
+
+const RULES: &[ProfileRule] = &[
+    ProfileRule::Cardinality {
+        path: "Observation.category",
+        min: 1,
+        max: None,
+    },
+    ProfileRule::Slice {
+        path: "Observation.category",
+        name: "VSCat",
+        discriminators: &[("coding.code", "\"vital-signs\"")],
+        min: 1,
+        max: Some(1),
+    },
+    ProfileRule::Fixed {
+        path: "Observation.status",
+        value: "\"final\"",
+    },
+    ProfileRule::Pattern {
+        path: "Observation.value[x]",
+        value: "{\"unit\":\"mmHg\"}",
+    },
+];
+
+#[test]
+fn test_check_profile_rules() {
+    let value = json!({
+        "status": "final",
+        "category": [{ "coding": [{ "system": "alfa", "code": "vital-signs" }] }],
+        "valueQuantity": { "value": 120, "unit": "mmHg" }
+    });
+    assert_eq!(check_profile_rules("alfa", &value, RULES), Ok(()));
+}
+
+#[test]
+fn test_check_profile_rules_with_issues() {
+    let value = json!({
+        "status": "draft",
+        "category": [{ "coding": [{ "code": "laboratory" }] }],
+        "valueQuantity": { "value": 120, "unit": "kg" }
+    });
+    let actual = check_profile_rules("alfa", &value, RULES).unwrap_err();
+    let paths: Vec<&str> = actual.issues.iter().map(|x| x.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "Observation.category",
+            "Observation.status",
+            "Observation.value[x]"
+        ]
+    );
+    assert_eq!(
+        actual.issues[0].message,
+        "has 0 values of slice VSCat, but allows 1..1"
+    );
+}
+
+#[test]
+fn test_slice_matches() {
+    let discriminators = &[("coding.code", "\"vital-signs\"")];
+    assert!(slice_matches(
+        &json!({ "coding": [{ "code": "vital-signs" }] }),
+        discriminators
+    ));
+    assert!(!slice_matches(
+        &json!({ "coding": [{ "code": "laboratory" }] }),
+        discriminators
+    ));
+}
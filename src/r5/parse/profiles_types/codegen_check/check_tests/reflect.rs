@@ -0,0 +1,22 @@
+//! Reflect
+//!
+//! The generated reflection views each value of a child element as a
+//! borrowed `ValueRef`.
+
+use ::fhir_codegen_check::types::{AsValueRef, ValueRef};
+
+#[test]
+fn test_as_value_ref() {
+    assert!(None::<bool>.as_value_ref().is_none());
+    assert!(Vec::<i32>::new().as_value_ref().is_none());
+    match vec![1_i32, 2].as_value_ref() {
+        Some(ValueRef::List(values)) => {
+            assert!(matches!(values.as_slice(), [ValueRef::Integer(1), ValueRef::Integer(2)]))
+        }
+        other => panic!("{other:?}"),
+    }
+    assert!(matches!(
+        Some(String::from("alfa")).as_value_ref(),
+        Some(ValueRef::String("alfa"))
+    ));
+}
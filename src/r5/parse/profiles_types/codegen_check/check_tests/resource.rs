@@ -0,0 +1,80 @@
+//! Resources
+//!
+//! A resource struct, such as `Patient`, reads and writes its `resourceType`
+//! JSON key, and rejects any other resource type. The `Resource` enum
+//! dispatches on that key to the struct, and writes the same JSON, also for
+//! a resource within a resource, such as `Patient.contained`.
+
+use ::fhir_codegen_check::types::{self, Patient, Resource};
+use ::serde_json::json;
+
+#[test]
+fn test_serde_json_round_trip() {
+    let value = json!({ "resourceType": "Patient" });
+    let patient: Patient = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(patient, Patient::default());
+    assert_eq!(::serde_json::to_value(&patient).unwrap(), value);
+
+    let value = json!({
+        "resourceType": "Patient",
+        "active": true,
+        "name": [{ "family": "Doe" }],
+        "deceasedDateTime": "2020-01-01"
+    });
+    let patient: Patient = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(patient.active, Some(types::Boolean::from(true)));
+    assert_eq!(::serde_json::to_value(&patient).unwrap(), value);
+}
+
+#[test]
+fn test_resource_type() {
+    let error =
+        ::serde_json::from_value::<Patient>(json!({ "resourceType": "Observation" })).unwrap_err();
+    assert!(error.to_string().contains("Observation"), "{error}");
+    assert!(::serde_json::from_value::<Patient>(json!({ "active": true })).is_err());
+}
+
+#[test]
+fn test_resource_enum_round_trip() {
+    let value = json!({ "resourceType": "Patient", "active": false });
+    let resource: Resource = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(resource.resource_type(), "Patient");
+    assert!(matches!(&resource, Resource::Patient(patient) if patient.active.is_some()));
+    assert_eq!(::serde_json::to_value(&resource).unwrap(), value);
+    assert_eq!(
+        ::serde_json::to_value(Resource::from(Patient::default())).unwrap(),
+        json!({ "resourceType": "Patient" })
+    );
+}
+
+#[test]
+fn test_resource_enum_unknown_resource_type() {
+    let error = ::serde_json::from_value::<Resource>(json!({ "resourceType": "Foo" })).unwrap_err();
+    assert!(
+        error.to_string().contains("unknown resourceType `Foo`"),
+        "{error}"
+    );
+    assert!(::serde_json::from_value::<Resource>(json!({ "active": true })).is_err());
+}
+
+#[test]
+fn test_serde_json_round_trip_with_contained_and_backbone_element() {
+    let value = json!({
+        "resourceType": "Patient",
+        "contained": [{ "resourceType": "Patient", "active": true }],
+        "contact": [{ "name": { "family": "Doe" } }]
+    });
+    let patient: Patient = ::serde_json::from_value(value.clone()).unwrap();
+    assert!(matches!(
+        patient.contained.as_slice(),
+        [Resource::Patient(contained)] if contained.active == Some(types::Boolean::from(true))
+    ));
+    assert_eq!(patient.contact.len(), 1);
+    assert_eq!(::serde_json::to_value(&patient).unwrap(), value);
+
+    let resource: Resource = ::serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(::serde_json::to_value(&resource).unwrap(), value);
+
+    let value = json!({ "resourceType": "Patient", "contained": [{ "resourceType": "Foo" }] });
+    assert!(::serde_json::from_value::<Patient>(value).is_err());
+}
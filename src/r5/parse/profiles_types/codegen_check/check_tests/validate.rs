@@ -0,0 +1,106 @@
+//! Validate
+//!
+//! The generated validation checks the cardinality, the bounds, the fixed and
+//! pattern values, and the required bindings of a value.
+
+use ::fhir_codegen_check::types::validate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AddressUse {
+    Home,
+}
+
+impl std::str::FromStr for AddressUse {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "home" => Ok(Self::Home),
+            _ => Err(()),
+        }
+    }
+}
+
+struct Foo {
+    bravo: Vec<Option<i32>>,
+}
+
+impl Validate for Foo {
+    const PATH: &'static str = "Foo";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>) {
+        let path = &format!("{path}.bravo");
+        check_count(path, self.bravo.len(), 1, Some(2), issues);
+        for (index, value) in self.bravo.iter().enumerate() {
+            if let Some(value) = value {
+                let path = &format!("{path}[{index}]");
+                check_range(path, i64::from(*value), Some(0), None, issues);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_validate() {
+    let actual = Foo {
+        bravo: vec![Some(1), None, Some(-1)],
+    }
+    .validate();
+    let expect = vec![
+        ValidationIssue {
+            path: String::from("Foo.bravo"),
+            message: String::from("has 3 values, but allows 1..2"),
+        },
+        ValidationIssue {
+            path: String::from("Foo.bravo[2]"),
+            message: String::from("is -1, but allows 0.."),
+        },
+    ];
+    assert_eq!(actual, expect);
+    assert!(Foo { bravo: vec![None] }.validate().is_empty());
+}
+
+#[test]
+fn test_check_max_length() {
+    let mut issues = vec![];
+    check_max_length("Foo.bravo", "alfa", 4, &mut issues);
+    assert!(issues.is_empty());
+    check_max_length("Foo.bravo", "alfa", 3, &mut issues);
+    assert_eq!(
+        issues[0].to_string(),
+        "Foo.bravo has 4 characters, but allows at most 3"
+    );
+}
+
+#[test]
+fn test_check_fixed_and_pattern() {
+    let mut issues = vec![];
+    let value = ::serde_json::json!({ "system": "alfa", "code": "bravo" });
+    check_fixed("Foo.bravo", &"final", "\"final\"", &mut issues);
+    check_pattern("Foo.bravo", &value, "{\"code\":\"bravo\"}", &mut issues);
+    assert!(issues.is_empty());
+    check_fixed("Foo.bravo", &value, "{\"code\":\"bravo\"}", &mut issues);
+    check_pattern("Foo.bravo", &value, "{\"code\":\"charlie\"}", &mut issues);
+    let messages: Vec<&str> = issues.iter().map(|x| x.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "is not the fixed value {\"code\":\"bravo\"}",
+            "does not match the pattern {\"code\":\"charlie\"}",
+        ]
+    );
+}
+
+#[test]
+fn test_check_codes() {
+    let mut issues = vec![];
+    let value_set = "http://hl7.org/fhir/ValueSet/address-use";
+    check_codes::<AddressUse>("Foo.bravo", &[], value_set, &mut issues);
+    check_codes::<AddressUse>("Foo.bravo", &["work", "home"], value_set, &mut issues);
+    assert!(issues.is_empty());
+    check_codes::<AddressUse>("Foo.bravo", &["work"], value_set, &mut issues);
+    assert_eq!(
+        issues[0].message,
+        "has no code of the required ValueSet http://hl7.org/fhir/ValueSet/address-use"
+    );
+}
@@ -0,0 +1,562 @@
+//! Codegen check
+//!
+//! Check code generation end to end, with every type of profiles-types.json:
+//!
+//! - Golden files: the generated files must equal the committed files in
+//!   `./tmp/out`, so that each change of generated code shows in review. Run
+//!   `cargo run` to regenerate them.
+//!
+//! - Compile check: the generated files must compile, as the `types` module
+//!   of a temporary crate, checked by `cargo check --offline`, with the
+//!   dependency versions of this crate's Cargo.lock.
+//!
+//! - Doc check: the generated doc comments must build with `cargo doc
+//!   --offline`, without warnings, such as a bare URL.
+//!
+//! - Search parameters: the generated search parameters must equal the
+//!   committed files in `./tmp/search_parameters`, and must compile.
+//!
+//! - Build script check: a temporary crate with a build script that calls
+//!   `CodegenBuild` must compile, with the generated types in its `OUT_DIR`.
+//!
+//! - Runtime check: the generated tests of each type must pass, and the
+//!   generated runtime modules, such as the profile rules, must pass the tests
+//!   in `./check_tests`, as integration tests of the temporary crate, run by
+//!   `cargo test --offline`.
+//!
+//! - Code enum check: valuesets.json is not committed, so the code enums of
+//!   required bindings are checked with a small in-memory ValueSet and
+//!   CodeSystem, on the types that use them.
+//!
+//! - Resource check: profiles-resources.json is not committed, so the
+//!   `resourceType` of a resource struct and the `Resource` enum are checked
+//!   with a small synthetic `Patient`.
+//!
+//! The harness is test only. The golden files themselves, and the context
+//! that generates them, are in `codegen_golden`.
+
+use crate::r5::parse::profiles_types::*;
+use std::path::{Path, PathBuf};
+
+/// The Cargo.toml of the temporary crate of the compile check, with the
+/// dependencies that generated code uses.
+const CHECK_CRATE_MANIFEST: &str = r#"[package]
+name = "fhir-codegen-check"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+serde = { version = "~1.0.219", features = ["derive"] }
+serde_json = { version = "~1.0.140", features = ["arbitrary_precision"] }
+serde_with = { version = "3.14" }
+"#;
+
+/// The integration tests of the generated runtime modules, by file name,
+/// such as `profile` for `tests/profile.rs`; see `write_check_tests`.
+pub const CHECK_TESTS: &[(&str, &str)] = &[
+    ("builder", include_str!("check_tests/builder.rs")),
+    ("choice", include_str!("check_tests/choice.rs")),
+    ("decimal", include_str!("check_tests/decimal.rs")),
+    ("primitive", include_str!("check_tests/primitive.rs")),
+    ("profile", include_str!("check_tests/profile.rs")),
+    ("reflect", include_str!("check_tests/reflect.rs")),
+    ("validate", include_str!("check_tests/validate.rs")),
+];
+
+/// Compare two directories of generated files, and return the name of each
+/// file that differs, or that only one directory has, sorted.
+pub fn compare_dirs(actual: &Path, expect: &Path) -> std::io::Result<Vec<String>> {
+    let mut names = std::collections::BTreeSet::new();
+    for dir in [actual, expect] {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                names.insert(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+    }
+    Ok(names
+        .into_iter()
+        .filter(|name| {
+            std::fs::read(actual.join(name)).ok() != std::fs::read(expect.join(name)).ok()
+        })
+        .collect())
+}
+
+/// Write a temporary crate, with the generated files as its `types` module.
+///
+/// The generated files refer to each other as `crate::types`. When the config
+/// has features, the Cargo.toml has the `[features]` table.
+pub fn write_check_crate(
+    dir: &Path,
+    resources: &[Resource],
+    context: &CodegenContext,
+) -> std::io::Result<()> {
+    let src = dir.join("src");
+    let mut context = context.clone();
+    context.config = CodegenConfig {
+        crate_path: String::from("crate::types"),
+        ..context.config.with_output_dir(src.join("types"))
+    };
+    resources_into_rust(resources, &context)?;
+    let manifest = if context.config.features {
+        format!(
+            "{CHECK_CRATE_MANIFEST}\n{}",
+            resources_into_cargo_features_block(resources)
+        )
+    } else {
+        String::from(CHECK_CRATE_MANIFEST)
+    };
+    std::fs::write(dir.join("Cargo.toml"), manifest)?;
+    std::fs::copy(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.lock"),
+        dir.join("Cargo.lock"),
+    )?;
+    std::fs::write(
+        src.join("lib.rs"),
+        "//! The generated FHIR R5 types.\n\npub mod types;\n",
+    )
+}
+
+/// Write a temporary crate, with the generated search parameters as its
+/// `search_parameters` module.
+pub fn write_search_parameters_check_crate(
+    dir: &Path,
+    resources: &[crate::r5::parse::search_parameters::Resource],
+) -> std::io::Result<()> {
+    let src = dir.join("src");
+    crate::r5::parse::search_parameters::resources_into_rust(
+        resources,
+        &src.join("search_parameters"),
+    )?;
+    std::fs::write(dir.join("Cargo.toml"), CHECK_CRATE_MANIFEST)?;
+    std::fs::copy(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.lock"),
+        dir.join("Cargo.lock"),
+    )?;
+    std::fs::write(
+        src.join("lib.rs"),
+        "//! The generated FHIR R5 search parameters.\n\npub mod search_parameters;\n",
+    )
+}
+
+/// Write a temporary crate, with a build script that generates the included
+/// types into its `OUT_DIR`, as its `fhir` module.
+pub fn write_build_script_crate(dir: &Path, include: &[&str]) -> std::io::Result<()> {
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src)?;
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "{CHECK_CRATE_MANIFEST}\n[build-dependencies]\nfhir-specifications-parser = {{ path = {:?} }}\n",
+            env!("CARGO_MANIFEST_DIR")
+        ),
+    )?;
+    std::fs::copy(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.lock"),
+        dir.join("Cargo.lock"),
+    )?;
+    std::fs::write(
+        dir.join("build.rs"),
+        format!(
+            "fn main() {{\n    ::fhir_specifications_parser::r5::parse::profiles_types::CodegenBuild::new()\n        .with_include({include:?})\n        .generate_into_out_dir()\n        .expect(\"generate FHIR types\");\n}}\n"
+        ),
+    )?;
+    std::fs::write(
+        src.join("lib.rs"),
+        "//! The generated FHIR R5 types.\n\npub mod fhir {\n    include!(concat!(env!(\"OUT_DIR\"), \"/fhir.rs\"));\n}\n",
+    )
+}
+
+/// Write the integration tests of a temporary crate, such as
+/// `tests/profile.rs`, which use the generated types as
+/// `fhir_codegen_check::types`.
+pub fn write_check_tests(dir: &Path, tests: &[(&str, &str)]) -> std::io::Result<()> {
+    let tests_dir = dir.join("tests");
+    std::fs::create_dir_all(&tests_dir)?;
+    for (name, source) in tests {
+        std::fs::write(tests_dir.join(format!("{name}.rs")), source)?;
+    }
+    Ok(())
+}
+
+/// Run `cargo check --offline` on a temporary crate, with its tests and the
+/// extra arguments, such as `--no-default-features`, and return the output.
+///
+/// The build shares a target directory inside this crate's, so that each
+/// check after the first one builds only the generated code.
+pub fn cargo_check(dir: &Path, args: &[&str]) -> std::io::Result<std::process::Output> {
+    cargo(dir, &["check", "--offline", "--tests", "--quiet"], args)
+}
+
+/// Run `cargo test --offline` on a temporary crate, with its unit and
+/// integration tests, without doc tests, and return the output.
+pub fn cargo_test(dir: &Path, args: &[&str]) -> std::io::Result<std::process::Output> {
+    cargo(dir, &["test", "--offline", "--tests", "--quiet"], args)
+}
+
+/// Run `cargo doc --offline` on a temporary crate, without its dependencies,
+/// and with every rustdoc warning as an error, and return the output.
+pub fn cargo_doc(dir: &Path) -> std::io::Result<std::process::Output> {
+    cargo(dir, &["doc", "--offline", "--no-deps", "--quiet"], &[])
+}
+
+/// Run cargo on a temporary crate, in the shared target directory.
+fn cargo(dir: &Path, command: &[&str], args: &[&str]) -> std::io::Result<std::process::Output> {
+    std::process::Command::new(std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo")))
+        .args(command)
+        .args(args)
+        .current_dir(dir)
+        .env("RUSTDOCFLAGS", "-D warnings")
+        .env(
+            "CARGO_TARGET_DIR",
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("target")
+                .join("codegen-check"),
+        )
+        .output()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_golden_files() {
+        let resources = load_profiles_types().unwrap();
+        let dir = temp_dir("codegen_check_golden");
+        let mut context = golden_context(&resources);
+        context.config = context.config.with_output_dir(&dir);
+        resources_into_rust(&resources, &context).unwrap();
+        let actual = compare_dirs(&dir, &GOLDEN_DIR).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(
+            actual.is_empty(),
+            "generated files differ from the golden files in ./tmp/out; run `cargo run` to regenerate them: {:?}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_cargo_check() {
+        let resources = load_profiles_types().unwrap();
+        let dir = temp_dir("codegen_check_crate");
+        write_check_crate(&dir, &resources, &golden_context(&resources)).unwrap();
+        let output = cargo_check(&dir, &[]).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cargo_doc() {
+        let resources = load_profiles_types().unwrap();
+        let dir = temp_dir("codegen_check_doc");
+        write_check_crate(&dir, &resources, &golden_context(&resources)).unwrap();
+        let output = cargo_doc(&dir).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cargo_check_with_features() {
+        let resources = load_profiles_types().unwrap();
+        let dir = temp_dir("codegen_check_features");
+        let mut context = golden_context(&resources);
+        context.config.features = true;
+        write_check_crate(&dir, &resources, &context).unwrap();
+        let output = cargo_check(&dir, &["--no-default-features", "--features", "Dosage"]).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cargo_check_with_extension_definitions() {
+        let resources = load_profiles_types().unwrap();
+        let closure = resources_type_closure(&resources, ["Extension"]);
+        let resources: Vec<Resource> = resources
+            .into_iter()
+            .filter(|x| closure.contains(&resource_feature_name(x)))
+            .collect();
+        let dir = temp_dir("codegen_check_extensions");
+        let mut context = golden_context(&resources);
+        context.extensions = [
+            ("structuredefinition-fmm", vec!["integer"]),
+            ("structuredefinition-standards-status", vec!["code"]),
+            ("patient-birthTime", vec!["dateTime", "instant"]),
+            ("patient-nationality", vec![]),
+        ]
+        .into_iter()
+        .map(
+            |(name, value_types)| crate::r5::registry::ExtensionDefinition {
+                url: format!("{CORE_EXTENSION_URL_PREFIX}{name}"),
+                value_types: value_types.into_iter().map(String::from).collect(),
+                ..Default::default()
+            },
+        )
+        .collect();
+        write_check_crate(&dir, &resources, &context).unwrap();
+        std::fs::write(
+            dir.join("src").join("lib.rs"),
+            concat!(
+                "//! The generated FHIR R5 types.\n\npub mod types;\n\n",
+                "#[cfg(test)]\nmod tests {\n",
+                "    use crate::types::{self, CoreExtensions, HasExtensions};\n\n",
+                "    #[test]\n    fn test_extensions() {\n",
+                "        let mut element = types::Element::default();\n",
+                "        element.set_structuredefinition_fmm(types::Integer::default());\n",
+                "        let _: Option<&types::Integer> = element.structuredefinition_fmm();\n",
+                "        let _: Option<&types::Code> = element.get_extension_value(types::extensions::STRUCTUREDEFINITION_STANDARDS_STATUS_URL);\n",
+                "        let _: Option<&types::extension::ExtensionValue> = element.patient_birth_time();\n",
+                "        let _: Option<&types::Extension> = element.patient_nationality().and_then(|x| x.get_extension(\"code\"));\n",
+                "        let _: Vec<types::Extension> = element.remove_extensions(types::extensions::STRUCTUREDEFINITION_FMM_URL);\n",
+                "    }\n}\n",
+            ),
+        )
+        .unwrap();
+        let output = cargo_check(&dir, &[]).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cargo_test() {
+        let resources = load_profiles_types().unwrap();
+        let dir = temp_dir("codegen_check_tests");
+        write_check_crate(&dir, &resources, &golden_context(&resources)).unwrap();
+        write_check_tests(&dir, CHECK_TESTS).unwrap();
+        let output = cargo_test(&dir, &[]).unwrap();
+        assert!(
+            output.status.success(),
+            "{}\n{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// The ValueSets of two required bindings, `Narrative.status`, which is
+    /// 1..1, and `Quantity.comparator`, which is 0..1, with their CodeSystems.
+    fn code_enum_value_sets() -> crate::r5::registry::ValueSetRegistry {
+        let resources: Vec<crate::r5::parse::value_sets::Resource> =
+            ::serde_json::from_value(::serde_json::json!([
+                {
+                    "resourceType": "CodeSystem",
+                    "id": "narrative-status",
+                    "url": "http://hl7.org/fhir/narrative-status",
+                    "status": "active",
+                    "content": "complete",
+                    "concept": [
+                        { "code": "generated", "display": "Generated" },
+                        { "code": "extensions", "display": "Extensions" },
+                        { "code": "additional", "display": "Additional" },
+                        { "code": "empty", "display": "Empty" }
+                    ]
+                },
+                {
+                    "resourceType": "ValueSet",
+                    "id": "narrative-status",
+                    "url": "http://hl7.org/fhir/ValueSet/narrative-status",
+                    "status": "active",
+                    "compose": { "include": [{ "system": "http://hl7.org/fhir/narrative-status" }] }
+                },
+                {
+                    "resourceType": "CodeSystem",
+                    "id": "quantity-comparator",
+                    "url": "http://hl7.org/fhir/quantity-comparator",
+                    "status": "active",
+                    "content": "complete",
+                    "concept": [
+                        { "code": "<", "display": "Less than" },
+                        { "code": "<=", "display": "Less or Equal to" },
+                        { "code": ">=", "display": "Greater or Equal to" },
+                        { "code": ">", "display": "Greater than" },
+                        { "code": "ad", "display": "Sufficient to achieve this total quantity" }
+                    ]
+                },
+                {
+                    "resourceType": "ValueSet",
+                    "id": "quantity-comparator",
+                    "url": "http://hl7.org/fhir/ValueSet/quantity-comparator",
+                    "status": "active",
+                    "compose": { "include": [{ "system": "http://hl7.org/fhir/quantity-comparator" }] }
+                }
+            ]))
+            .unwrap();
+        resources.into_iter().collect()
+    }
+
+    #[test]
+    fn test_cargo_test_with_value_sets() {
+        let resources = load_profiles_types().unwrap();
+        let closure = resources_type_closure(&resources, ["Narrative", "Quantity"]);
+        let resources: Vec<Resource> = resources
+            .into_iter()
+            .filter(|x| closure.contains(&resource_feature_name(x)))
+            .collect();
+        let dir = temp_dir("codegen_check_value_sets");
+        let mut context = golden_context(&resources);
+        context.value_sets = code_enum_value_sets();
+        write_check_crate(&dir, &resources, &context).unwrap();
+        write_check_tests(
+            &dir,
+            &[("code_enum", include_str!("check_tests/code_enum.rs"))],
+        )
+        .unwrap();
+        let output = cargo_test(&dir, &[]).unwrap();
+        assert!(
+            output.status.success(),
+            "{}\n{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// A small concrete resource, `Patient`, because profiles-types.json has
+    /// no resource, with a primitive, a repeated complex type, a choice, the
+    /// contained resources, and a BackboneElement.
+    fn synthetic_patient() -> Resource {
+        let element = |path: &str, min: u32, max: &str, codes: &[&str]| Element {
+            id: path.into(),
+            path: path.into(),
+            short: Some(format!("Short description of {path}")),
+            min: Some(min),
+            max: Some(max.into()),
+            r#type: (!codes.is_empty()).then(|| {
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect()
+            }),
+            ..Element::default()
+        };
+        Resource {
+            id: "Patient".into(),
+            kind: Some(String::from("resource")),
+            r#abstract: Some(false),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Patient", 0, "*", &[]),
+                    element("Patient.contained", 0, "*", &["Resource"]),
+                    element("Patient.active", 0, "1", &["boolean"]),
+                    element("Patient.name", 0, "*", &["HumanName"]),
+                    element("Patient.deceased[x]", 0, "1", &["boolean", "dateTime"]),
+                    element("Patient.contact", 0, "*", &["BackboneElement"]),
+                    element("Patient.contact.name", 0, "1", &["HumanName"]),
+                ],
+            }),
+            ..Resource::default()
+        }
+    }
+
+    #[test]
+    fn test_cargo_test_with_resource() {
+        let resources = load_profiles_types().unwrap();
+        let closure = resources_type_closure(&resources, ["HumanName", "Boolean", "DateTime"]);
+        let mut resources: Vec<Resource> = resources
+            .into_iter()
+            .filter(|x| closure.contains(&resource_feature_name(x)))
+            .collect();
+        resources.push(synthetic_patient());
+        let dir = temp_dir("codegen_check_resource");
+        write_check_crate(&dir, &resources, &golden_context(&resources)).unwrap();
+        write_check_tests(
+            &dir,
+            &[("resource", include_str!("check_tests/resource.rs"))],
+        )
+        .unwrap();
+        let output = cargo_test(&dir, &[]).unwrap();
+        assert!(
+            output.status.success(),
+            "{}\n{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_search_parameters_golden_files() {
+        let dir = temp_dir("codegen_check_search_parameters_golden");
+        crate::r5::parse::search_parameters::resources_into_rust(
+            &load_search_parameters().unwrap(),
+            &dir,
+        )
+        .unwrap();
+        let actual = compare_dirs(&dir, &SEARCH_PARAMETERS_GOLDEN_DIR).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(
+            actual.is_empty(),
+            "generated files differ from the golden files in ./tmp/search_parameters; run `cargo run` to regenerate them: {:?}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_cargo_check_search_parameters() {
+        let dir = temp_dir("codegen_check_search_parameters");
+        write_search_parameters_check_crate(&dir, &load_search_parameters().unwrap()).unwrap();
+        let output = cargo_check(&dir, &[]).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cargo_check_build_script() {
+        let dir = temp_dir("codegen_check_build_script");
+        write_build_script_crate(&dir, &["Dosage"]).unwrap();
+        let output = cargo_check(&dir, &[]).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_compare_dirs() {
+        let dir = temp_dir("codegen_check_compare");
+        let (actual, expect) = (dir.join("actual"), dir.join("expect"));
+        std::fs::create_dir_all(&actual).unwrap();
+        std::fs::create_dir_all(&expect).unwrap();
+        std::fs::write(actual.join("alfa.rs"), "alfa").unwrap();
+        std::fs::write(expect.join("alfa.rs"), "alfa").unwrap();
+        std::fs::write(actual.join("bravo.rs"), "bravo").unwrap();
+        std::fs::write(expect.join("bravo.rs"), "charlie").unwrap();
+        std::fs::write(expect.join("delta.rs"), "delta").unwrap();
+        let actual = compare_dirs(&actual, &expect).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(actual, vec!["bravo.rs", "delta.rs"]);
+    }
+}
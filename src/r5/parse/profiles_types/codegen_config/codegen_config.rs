@@ -0,0 +1,260 @@
+//! Codegen config
+//!
+//! Where and how code generation writes its Rust source code files: the
+//! output directory, the module layout, the crate path of the FHIR types, and
+//! the header of each file.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use std::path::PathBuf;
+
+/// The default header of each generated file.
+///
+/// The placeholders are `{name}`, `{url}`, `{version}`, and `{description}`.
+pub const DEFAULT_HEADER_TEMPLATE: &str = "//! {name}
+//!
+//! URL: <{url}>
+//!
+//! Version: {version}
+//!
+//! {description}
+//!
+//! FHIR: <https://build.fhir.org/>
+//!
+//! UML: <https://build.fhir.org/uml.html>
+";
+
+/// How generated files are arranged in modules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleLayout {
+    /// Every file in the output directory, such as `address.rs`.
+    #[default]
+    Flat,
+
+    /// Every file in a directory for its StructureDefinition kind, such as
+    /// `complex_types/address.rs` and `primitive_types/boolean.rs`.
+    GroupedByKind,
+
+    /// Every module inline in the module file, such as `pub mod address {
+    /// … }`, so that a crate can `include!` the file from its `OUT_DIR`,
+    /// where a `mod address;` declaration would not find `address.rs`.
+    SingleFile,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodegenConfig {
+    /// The directory of the generated files, such as `./tmp/out`.
+    pub output_dir: PathBuf,
+
+    /// The module file of the output directory, such as `./tmp/out/mod.rs`,
+    /// or a sibling such as `./src/types.rs` for the directory `./src/types`.
+    pub mod_file: PathBuf,
+
+    pub layout: ModuleLayout,
+
+    /// The Rust path of the module of FHIR types, that generated fields such
+    /// as `types::Quantity` refer to, such as `crate::r5::types`.
+    pub crate_path: String,
+
+    /// The header of each generated file; see `DEFAULT_HEADER_TEMPLATE`.
+    pub header_template: String,
+
+    /// Generate a builder for each struct, such as `QuantityBuilder`.
+    pub builders: bool,
+
+    /// Generate a `Validate` impl for each type, that checks the rules of
+    /// its StructureDefinition; see `elements_into_rust_validate_block`.
+    pub validation: bool,
+
+    /// Generate the `Visit` and `VisitMut` traits, and a `Walk` impl for
+    /// each type; see `resources_into_rust_visit_block`.
+    pub visitors: bool,
+
+    /// Generate an `ELEMENTS` table of `ElementInfo` and a `Reflect` impl
+    /// for each type; see `elements_into_rust_reflect_block`.
+    pub reflection: bool,
+
+    /// Generate the `HasExtensions` trait, with typed extension values, and
+    /// an impl for each struct with extensions; see
+    /// `resources_into_rust_extensions_block`.
+    pub extensions: bool,
+
+    /// Gate each generated module on a cargo feature, such as `Patient`,
+    /// and write the `[features]` table; see `resources_into_cargo_features`.
+    pub features: bool,
+}
+
+impl Default for CodegenConfig {
+    fn default() -> Self {
+        let output_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tmp")
+            .join("out");
+        Self {
+            mod_file: output_dir.join("mod.rs"),
+            output_dir,
+            layout: ModuleLayout::default(),
+            crate_path: String::from("crate::r5::types"),
+            header_template: String::from(DEFAULT_HEADER_TEMPLATE),
+            builders: false,
+            validation: false,
+            visitors: false,
+            reflection: false,
+            extensions: false,
+            features: false,
+        }
+    }
+}
+
+impl CodegenConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write into the output directory, with its `mod.rs` module file.
+    pub fn with_output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = output_dir.into();
+        self.mod_file = self.output_dir.join("mod.rs");
+        self
+    }
+
+    /// Given one resource, return its Rust module name, such as `address`.
+    pub fn module_name(&self, resource: &Resource) -> SourceCodeString {
+        resource.id.from_case(Case::Pascal).to_case(Case::Snake)
+    }
+
+    /// Given one resource, return its group module name, such as
+    /// `complex_types`, or none for the flat layout.
+    pub fn group_name(&self, resource: &Resource) -> Option<&'static str> {
+        match self.layout {
+            ModuleLayout::Flat | ModuleLayout::SingleFile => None,
+            ModuleLayout::GroupedByKind => Some(resource_kind_name(resource)),
+        }
+    }
+
+    /// Given one resource, return the path of its generated file.
+    ///
+    /// Example:
+    ///
+    /// ```no_run
+    /// let resource = … // e.g. resource id AlfaBravo.
+    /// config.file_path(&resource) => "./tmp/out/alfa_bravo.rs"
+    /// ```
+    ///
+    pub fn file_path(&self, resource: &Resource) -> PathBuf {
+        let dir = match self.group_name(resource) {
+            Some(group) => self.output_dir.join(group),
+            None => self.output_dir.clone(),
+        };
+        dir.join(format!("{}.rs", self.module_name(resource)))
+    }
+
+    /// The Rust `use` line that imports the FHIR types as `types`.
+    pub fn use_types_line(&self) -> SourceCodeString {
+        if self.crate_path == "types" || self.crate_path.ends_with("::types") {
+            format!("use {};", self.crate_path)
+        } else {
+            format!("use {} as types;", self.crate_path)
+        }
+    }
+
+    /// Given one resource, fill in the header template.
+    ///
+    /// The description is markdown that may span lines, so each line after
+    /// the first continues the `//!` comment.
+    pub fn header(&self, resource: &Resource) -> SourceCodeString {
+        let description = markdown_into_rustdoc(resource.description.as_deref().unwrap_or("?"))
+            .lines()
+            .map(|line| format!("//! {line}").trim_end().to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+        self.header_template
+            .replace("{name}", &resource.id.to_case(Case::Pascal))
+            .replace("{url}", &resource.url)
+            .replace("{version}", &resource.version)
+            .replace("//! {description}", &description)
+            .replace("{description}", description.trim_start_matches("//! "))
+    }
+}
+
+/// Given one resource, return the name of its StructureDefinition kind, as
+/// a group module, such as `complex_types`, or as the cargo feature of a
+/// type without a category; see `resource_category_feature_name`.
+pub fn resource_kind_name(resource: &Resource) -> &'static str {
+    match resource.kind.as_deref() {
+        Some("primitive-type") => "primitive_types",
+        Some("complex-type") => "complex_types",
+        Some("resource") => "resources",
+        Some("logical") => "logical_models",
+        _ => "others",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(id: &str, kind: &str) -> Resource {
+        Resource {
+            id: id.into(),
+            kind: Some(String::from(kind)),
+            ..Resource::default()
+        }
+    }
+
+    #[test]
+    fn test_file_path() {
+        let config = CodegenConfig::new().with_output_dir("/alfa");
+        assert_eq!(
+            config.file_path(&resource("AlfaBravo", "complex-type")),
+            PathBuf::from("/alfa/alfa_bravo.rs")
+        );
+        assert_eq!(config.mod_file, PathBuf::from("/alfa/mod.rs"));
+        let config = CodegenConfig {
+            layout: ModuleLayout::GroupedByKind,
+            ..config
+        };
+        assert_eq!(
+            config.file_path(&resource("dateTime", "primitive-type")),
+            PathBuf::from("/alfa/primitive_types/date_time.rs")
+        );
+    }
+
+    #[test]
+    fn test_use_types_line() {
+        let mut config = CodegenConfig::new();
+        assert_eq!(config.use_types_line(), "use crate::r5::types;");
+        config.crate_path = String::from("::fhir::r5");
+        assert_eq!(config.use_types_line(), "use ::fhir::r5 as types;");
+    }
+
+    #[test]
+    fn test_header() {
+        let config = CodegenConfig {
+            header_template: String::from("//! {name} {version}\n"),
+            ..CodegenConfig::default()
+        };
+        let resource = Resource {
+            version: "5.0.0".into(),
+            ..resource("dateTime", "primitive-type")
+        };
+        assert_eq!(config.header(&resource), "//! DateTime 5.0.0\n");
+    }
+
+    #[test]
+    fn test_header_with_multiline_description() {
+        let config = CodegenConfig {
+            header_template: String::from("//! {name}\n//!\n//! {description}\n"),
+            ..CodegenConfig::new()
+        };
+        let resource = Resource {
+            id: String::from("address"),
+            description: Some(String::from("Alfa.\r\n\r\nBravo <charlie>.")),
+            ..Resource::default()
+        };
+        assert_eq!(
+            config.header(&resource),
+            "//! Address\n//!\n//! Alfa.\n//!\n//! Bravo &lt;charlie&gt;.\n"
+        );
+    }
+}
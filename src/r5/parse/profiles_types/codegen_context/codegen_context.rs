@@ -0,0 +1,118 @@
+//! Codegen context
+//!
+//! The definitions that code generation reads beyond one StructureDefinition,
+//! such as the ValueSets that required bindings expand into Rust enums, and
+//! the config of where and how to write it.
+
+use crate::r5::parse::all::Binding;
+use crate::r5::parse::profiles_types::*;
+use crate::r5::registry::{CodeConcept, ExtensionRegistry, ValueSetRegistry};
+use std::collections::BTreeSet;
+
+/// The URL of the extension that names a binding, such as `AddressUse`.
+pub const BINDING_NAME_URL: &str =
+    "http://hl7.org/fhir/StructureDefinition/elementdefinition-bindingName";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CodegenContext {
+    pub value_sets: ValueSetRegistry,
+
+    /// The extension definitions that get typed accessors, such as
+    /// `structuredefinition-fmm`; see `resources_into_rust_extensions_block`.
+    pub extensions: ExtensionRegistry,
+
+    /// The element paths of the fields and choice variants that need a `Box`
+    /// to break a recursive type, from the `TypeGraph`.
+    pub boxed_paths: BTreeSet<String>,
+
+    pub config: CodegenConfig,
+}
+
+/// A Rust enum for the codes of a required binding.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CodeEnum {
+    /// The Rust enum name, such as `AddressUse`.
+    pub name: String,
+
+    /// The canonical URL of the bound ValueSet.
+    pub value_set: String,
+
+    pub concepts: Vec<CodeConcept>,
+}
+
+impl CodegenContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the context from the definitions files.
+    ///
+    /// A missing ValueSet file gives an empty registry, so every binding
+    /// stays a `types::Code`. A missing extension definitions file gives an
+    /// empty registry, so there are no typed extension accessors.
+    pub fn from_definitions_files() -> Self {
+        Self {
+            value_sets: ValueSetRegistry::from_definitions_file().unwrap_or_default(),
+            extensions: ExtensionRegistry::from_definitions_file().unwrap_or_default(),
+            ..Self::default()
+        }
+    }
+
+    /// Find the recursive types among the resources, and box the fields that
+    /// break each cycle.
+    pub fn with_boxed_paths(mut self, resources: &[Resource]) -> Self {
+        self.boxed_paths = TypeGraph::from_resources(resources, &self).boxed_paths();
+        self
+    }
+
+    /// Does the field or choice variant at the element path need a `Box`?
+    pub fn is_boxed(&self, path: &str) -> bool {
+        self.boxed_paths.contains(path)
+    }
+
+    /// Given one element, return the Rust enum for its codes, when the
+    /// element is a `code` with a required binding to a ValueSet that
+    /// expands from the loaded CodeSystems.
+    pub fn code_enum(&self, element: &Element) -> Option<CodeEnum> {
+        match element.r#type.as_deref() {
+            Some([element_type]) if element_type.code == "code" => {}
+            _ => return None,
+        }
+        self.binding_enum(element)
+    }
+
+    /// Given one element, return the Rust enum for the codes of its required
+    /// binding, whatever its type, such as a `CodeableConcept`, when the
+    /// ValueSet expands from the loaded CodeSystems.
+    ///
+    /// The enum name is the binding name, which is not unique to the
+    /// ValueSet, so the enum keeps the ValueSet URL to tell two apart.
+    pub fn binding_enum(&self, element: &Element) -> Option<CodeEnum> {
+        let binding = element.binding.as_ref()?;
+        if binding.strength != "required" {
+            return None;
+        }
+        let value_set = binding.value_set.as_ref()?;
+        let concepts = self.value_sets.expand(value_set)?;
+        if concepts.is_empty() {
+            return None;
+        }
+        Some(CodeEnum {
+            name: binding_name(binding)
+                .map(String::from)
+                .unwrap_or_else(|| path_into_rust_struct_name(&element.path)),
+            value_set: value_set.clone(),
+            concepts,
+        })
+    }
+}
+
+/// The name of a binding, from its `elementdefinition-bindingName` extension.
+fn binding_name(binding: &Binding) -> Option<&str> {
+    binding
+        .extension
+        .iter()
+        .flatten()
+        .find(|extension| extension.url == BINDING_NAME_URL)
+        .and_then(|extension| extension.value_string.as_deref())
+}
@@ -0,0 +1,60 @@
+//! Codegen golden
+//!
+//! The golden files are the generated files that are committed, so that each
+//! change of generated code shows in review: the types of profiles-types.json
+//! in `./tmp/out`, and the search parameters in `./tmp/search_parameters`.
+//! `cargo run` writes them, and the codegen check compares them.
+
+use crate::r5::parse::profiles_types::*;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+/// The directory of the committed golden files.
+pub static GOLDEN_DIR: std::sync::LazyLock<PathBuf> = std::sync::LazyLock::new(|| {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tmp")
+        .join("out")
+});
+
+/// The directory of the committed search parameters.
+pub static SEARCH_PARAMETERS_GOLDEN_DIR: std::sync::LazyLock<PathBuf> =
+    std::sync::LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tmp")
+            .join("search_parameters")
+    });
+
+/// Load every resource of profiles-types.json.
+pub fn load_profiles_types() -> std::io::Result<Vec<Resource>> {
+    let file = std::fs::File::open(&*DEFINITIONS_FILE)?;
+    let bundle: Bundle = ::serde_json::from_reader(BufReader::new(file))?;
+    Ok(bundle
+        .entry
+        .into_iter()
+        .map(|entry| entry.resource)
+        .collect())
+}
+
+/// Load every SearchParameter of search-parameters.json.
+pub fn load_search_parameters()
+-> std::io::Result<Vec<crate::r5::parse::search_parameters::Resource>> {
+    let file = std::fs::File::open(&*crate::r5::parse::search_parameters::DEFINITIONS_FILE)?;
+    let bundle: crate::r5::parse::search_parameters::Bundle =
+        ::serde_json::from_reader(BufReader::new(file))?;
+    Ok(bundle
+        .entry
+        .into_iter()
+        .map(|entry| entry.resource)
+        .collect())
+}
+
+/// The context that generates the golden files.
+pub fn golden_context(resources: &[Resource]) -> CodegenContext {
+    let mut context = CodegenContext::from_definitions_files().with_boxed_paths(resources);
+    context.config.builders = true;
+    context.config.validation = true;
+    context.config.visitors = true;
+    context.config.reflection = true;
+    context.config.extensions = true;
+    context
+}
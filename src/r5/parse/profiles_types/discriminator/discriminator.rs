@@ -10,10 +10,10 @@ use ::serde::{Deserialize, Serialize};
 #[serde(deny_unknown_fields)]
 pub struct Discriminator {
     /// Example: "value"
-    r#type: String,
+    pub r#type: String,
 
     /// Example: "url"
-    path: String,
+    pub path: String,
 }
 
 #[cfg(test)]
@@ -84,6 +84,9 @@ pub struct Element {
     /// Example: "Narrative.id"
     pub path: String,
 
+    /// Example: "VSCat", for the element id "Observation.category:VSCat"
+    pub slice_name: Option<String>,
+
     /// Example: ["xmlAttr"]
     pub representation: Option<Vec<String>>,
 
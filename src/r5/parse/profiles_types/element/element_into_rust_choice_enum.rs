@@ -0,0 +1,450 @@
+//! Element into Rust choice enum
+//!
+//! A choice element, such as `Extension.value[x]`, allows one of many types.
+//! Its JSON key names the type, such as `valueQuantity` or `valueString`.
+//!
+//! The Rust enum has one variant per type, and each variant renames to its
+//! JSON key, so the parent struct flattens the enum into its own keys.
+//!
+//! A primitive type, such as `valueString`, can carry an id and extensions in
+//! a sibling JSON key, such as `_valueString`. The sibling enum has one
+//! variant per primitive type, each renamed to its sibling key, and the
+//! parent struct flattens it as an option beside the choice.
+//!
+//! Serde reads a flattened `Option` as none when its value fails, which would
+//! drop a choice value of the wrong type, such as `"valueString": 5`. So each
+//! enum implements `types::Choice`, with its JSON keys, and the parent struct
+//! reads it with `types::Choice::deserialize_option`, which reads only those
+//! keys, and fails when a value fails.
+//!
+//! Serde ignores `#[serde(deny_unknown_fields)]` on a struct that flattens a
+//! field, so such a struct flattens `types::DenyUnknownFields` last instead,
+//! which rejects any key that no other field reads. So every struct rejects
+//! unknown fields alike, whether or not it has a choice.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+
+/// Indent the start of each Rust variant line of source code
+const RUST_VARIANT_INDENT: &str = "    ";
+
+/// The module name of the generated unknown fields check.
+pub const UNKNOWN_FIELDS_MODULE: &str = "unknown_fields";
+
+/// The Rust source code of the generated unknown fields check module.
+pub const UNKNOWN_FIELDS_BLOCK: &str = r#"//! Unknown fields
+//!
+//! Serde ignores `#[serde(deny_unknown_fields)]` on a struct that flattens a
+//! field, such as a choice of types. Such a struct flattens this type last,
+//! which reads the keys that no other field reads, and rejects the first.
+
+use ::serde::de::{Error, IgnoredAny};
+use ::serde::ser::SerializeMap;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// Reject any key that no other field of the struct reads, and write none.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DenyUnknownFields;
+
+impl<'de> Deserialize<'de> for DenyUnknownFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let unknown: BTreeMap<String, IgnoredAny> =
+            Deserialize::deserialize(deserializer)?;
+        match unknown.into_keys().next() {
+            Some(key) => Err(D::Error::custom(format!("unknown field `{key}`"))),
+            None => Ok(Self),
+        }
+    }
+}
+
+impl Serialize for DenyUnknownFields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+"#;
+
+/// The module name of the generated choice trait.
+pub const CHOICE_MODULE: &str = "choice";
+
+/// The Rust source code of the generated choice trait module.
+pub const CHOICE_BLOCK: &str = r#"//! Choice
+//!
+//! A choice enum, such as `ExtensionValue`, is flattened into its parent
+//! struct, and reads one of its JSON keys, such as `valueString`. Serde reads
+//! a flattened `Option` as none when its value fails, so a parent struct
+//! reads a choice with `Choice::deserialize_option`, which fails instead.
+
+use ::serde::de::{DeserializeOwned, Error, MapAccess, Visitor};
+use ::serde::Deserializer;
+use std::marker::PhantomData;
+
+/// An enum that is flattened into its parent struct, with one JSON key per
+/// variant.
+pub trait Choice: DeserializeOwned {
+    /// The JSON key of each variant, such as `valueString`.
+    const KEYS: &'static [&'static str];
+
+    /// Read the one key of the choice, or none, and fail when its value
+    /// fails, or when there is more than one key.
+    fn deserialize_option<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Self>, D::Error> {
+        let visitor = ChoiceVisitor(PhantomData);
+        deserializer.deserialize_struct("Choice", Self::KEYS, visitor)
+    }
+
+    /// Read the one key of a required choice, and fail when there is none.
+    fn deserialize_required<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize_option(deserializer)?.ok_or_else(|| {
+            D::Error::custom(format!("missing one of the fields {:?}", Self::KEYS))
+        })
+    }
+}
+
+/// Read the keys of a choice, as a JSON value each, so that a number keeps
+/// its digits, then read the enum from the one key.
+struct ChoiceVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Choice> Visitor<'de> for ChoiceVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "one of the fields {:?}", T::KEYS)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut choice = None;
+        while let Some(key) = map.next_key::<String>()? {
+            let value: ::serde_json::Value = map.next_value()?;
+            let object = ::serde_json::Value::Object([(key.clone(), value)].into_iter().collect());
+            let variant = T::deserialize(object).map_err(A::Error::custom)?;
+            if choice.replace(variant).is_some() {
+                return Err(A::Error::custom(format!(
+                    "more than one of the fields {:?}, such as `{key}`",
+                    T::KEYS
+                )));
+            }
+        }
+        Ok(choice)
+    }
+}
+"#;
+
+/// The Rust struct attribute that rejects unknown fields, for a struct that
+/// flattens a choice.
+pub const UNKNOWN_FIELDS_ATTRIBUTE: &str = "    /// Reject unknown fields, which serde can not deny in a struct that\n    /// flattens a choice.\n    #[serde(flatten)]\n    pub unknown_fields: types::DenyUnknownFields,\n";
+
+/// Is the element a choice element, such as `value[x]`?
+#[allow(dead_code)]
+pub fn element_is_choice(element: &Element) -> bool {
+    element.path.ends_with("[x]")
+}
+
+/// Does the element flatten a choice enum into its parent struct? A
+/// prohibited choice, with cardinality 0..0, has no attribute to flatten.
+#[allow(dead_code)]
+pub fn element_is_flattened_choice(element: &Element) -> bool {
+    element_is_choice(element) && Cardinality::from_element(element) != Cardinality::Prohibited
+}
+
+/// Given one choice element and one of its type codes, return its JSON key.
+///
+/// Example:
+///
+/// ```no_run
+/// choice_json_key("value", "dateTime") => "valueDateTime"
+/// ```
+///
+#[allow(dead_code)]
+pub fn choice_json_key(json_name: &str, code: &str) -> SourceCodeString {
+    let mut chars = code.chars();
+    match chars.next() {
+        Some(first) => format!("{json_name}{}{}", first.to_uppercase(), chars.as_str()),
+        None => String::from(json_name),
+    }
+}
+
+/// Given one choice element, return the primitive type codes that can carry
+/// an id and extensions in a sibling JSON key, such as `_valueString`.
+///
+/// The `xhtml` type can not carry extensions.
+#[allow(dead_code)]
+pub fn choice_sibling_codes(element: &Element) -> Vec<&str> {
+    element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| element_type.code.as_str())
+        .filter(|code| type_code_is_primitive(code) && *code != "xhtml")
+        .collect()
+}
+
+/// Is the element a choice element with a primitive type, and so a sibling
+/// enum for its id and extensions?
+#[allow(dead_code)]
+pub fn element_has_choice_sibling(element: &Element) -> bool {
+    element_is_choice(element) && !choice_sibling_codes(element).is_empty()
+}
+
+/// Given one choice element, return the Rust name of its sibling enum.
+///
+/// Example:
+///
+/// ```no_run
+/// choice_sibling_enum_name(&element) => "ExtensionValueElement"
+/// ```
+///
+#[allow(dead_code)]
+pub fn choice_sibling_enum_name(element: &Element) -> SourceCodeString {
+    format!(
+        "{}Element",
+        path_into_rust_struct_name(element.path.trim_end_matches("[x]"))
+    )
+}
+
+/// Given one choice element, generate its Rust enum source code.
+///
+/// Example:
+///
+/// ```no_run
+/// let element = … // e.g. path "Foo.value[x]", type codes "Quantity", "string".
+/// element_into_rust_choice_enum(&element, &context)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// pub enum FooValue {
+///     #[serde(rename = "valueQuantity")]
+///     Quantity(types::Quantity),
+///     #[serde(rename = "valueString")]
+///     String(types::String),
+/// }
+/// ```
+///
+/// A variant that the context boxes, such as `Extension.valueExtension`,
+/// holds a `Box`, such as `Extension(Box<types::Extension>)`.
+///
+/// The default is the first variant with its default value, so that a
+/// parent struct with a required choice can derive its own default.
+///
+#[allow(dead_code)]
+pub fn element_into_rust_choice_enum(
+    element: &Element,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let path = element.path.trim_end_matches("[x]");
+    let name = path_into_rust_struct_name(path);
+    let json_name = element_into_json_name(element);
+    let codes: Vec<&str> = element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| element_type.code.as_str())
+        .collect();
+    let variant_block = codes
+        .iter()
+        .map(|code| {
+            let rust_type = type_code_into_rust_type(code);
+            let rust_type = if context.is_boxed(&choice_json_key(path, code)) {
+                format!("Box<{rust_type}>")
+            } else {
+                rust_type
+            };
+            format!(
+                "{indent}#[serde(rename = \"{key}\")]\n{indent}{variant}({rust_type}),\n",
+                indent = RUST_VARIANT_INDENT,
+                key = choice_json_key(json_name, code),
+                variant = code.to_case(Case::Pascal),
+                rust_type = rust_type,
+            )
+        })
+        .collect::<String>();
+    let default_variant = codes
+        .first()
+        .map(|code| code.to_case(Case::Pascal))
+        .unwrap_or_default();
+    let keys = choice_keys_block(codes.iter().map(|code| choice_json_key(json_name, code)));
+    formatdoc!(
+        r#"
+        /// Choice of types for `{path}`.
+        #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+        pub enum {name} {{
+        {variant_block}}}
+
+        impl Default for {name} {{
+            fn default() -> Self {{
+                Self::{default_variant}(Default::default())
+            }}
+        }}
+
+        impl types::Choice for {name} {{
+            const KEYS: &'static [&'static str] = &[{keys}];
+        }}
+        "#,
+        path = element.path,
+    )
+}
+
+/// JSON keys => the items of a Rust `&[&str]`, such as `"valueString", "valueUri"`.
+fn choice_keys_block(keys: impl Iterator<Item = String>) -> SourceCodeString {
+    keys.map(|key| format!("{key:?}"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Given one choice element, generate the Rust enum source code of the id
+/// and extensions of its primitive types, or nothing when it has none.
+///
+/// Output is approximately like this, for type codes "Quantity", "string":
+///
+/// ```no_run
+/// pub enum FooValueElement {
+///     #[serde(rename = "_valueString")]
+///     String(types::Element),
+/// }
+/// ```
+///
+#[allow(dead_code)]
+pub fn element_into_rust_choice_sibling_enum(element: &Element) -> SourceCodeString {
+    let codes = choice_sibling_codes(element);
+    if codes.is_empty() {
+        return String::new();
+    }
+    let json_name = element_into_json_name(element);
+    let variant_block = codes
+        .iter()
+        .map(|code| {
+            format!(
+                "{indent}#[serde(rename = \"_{key}\")]\n{indent}{variant}(types::Element),\n",
+                indent = RUST_VARIANT_INDENT,
+                key = choice_json_key(json_name, code),
+                variant = code.to_case(Case::Pascal),
+            )
+        })
+        .collect::<String>();
+    let keys = choice_keys_block(
+        codes
+            .iter()
+            .map(|code| format!("_{}", choice_json_key(json_name, code))),
+    );
+    formatdoc!(
+        r#"
+        /// Id and extensions of `{path}`, when its type is a primitive.
+        #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+        pub enum {name} {{
+        {variant_block}}}
+
+        impl types::Choice for {name} {{
+            const KEYS: &'static [&'static str] = &[{keys}];
+        }}
+        "#,
+        path = element.path,
+        name = choice_sibling_enum_name(element),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn element() -> Element {
+        Element {
+            id: "Foo.value[x]".into(),
+            path: "Foo.value[x]".into(),
+            r#type: Some(
+                ["Quantity", "dateTime"]
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    #[test]
+    fn test_element_is_choice() {
+        assert!(element_is_choice(&element()));
+        assert!(!element_is_choice(&Element {
+            path: "Foo.bravo".into(),
+            ..Element::default()
+        }));
+    }
+
+    #[test]
+    fn test_choice_json_key() {
+        assert_eq!(choice_json_key("value", "dateTime"), "valueDateTime");
+        assert_eq!(choice_json_key("value", "Quantity"), "valueQuantity");
+    }
+
+    #[test]
+    fn test_element_into_rust_choice_enum() {
+        let actual = element_into_rust_choice_enum(&element(), &CodegenContext::new());
+        let expect = concat!(
+            "/// Choice of types for `Foo.value[x]`.\n",
+            "#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]\n",
+            "pub enum FooValue {\n",
+            "    #[serde(rename = \"valueQuantity\")]\n",
+            "    Quantity(types::Quantity),\n",
+            "    #[serde(rename = \"valueDateTime\")]\n",
+            "    DateTime(types::DateTime),\n",
+            "}\n",
+            "\n",
+            "impl Default for FooValue {\n",
+            "    fn default() -> Self {\n",
+            "        Self::Quantity(Default::default())\n",
+            "    }\n",
+            "}\n",
+            "\n",
+            "impl types::Choice for FooValue {\n",
+            "    const KEYS: &'static [&'static str] = &[\"valueQuantity\", \"valueDateTime\"];\n",
+            "}\n",
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_element_into_rust_choice_sibling_enum() {
+        let actual = element_into_rust_choice_sibling_enum(&element());
+        let expect = concat!(
+            "/// Id and extensions of `Foo.value[x]`, when its type is a primitive.\n",
+            "#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]\n",
+            "pub enum FooValueElement {\n",
+            "    #[serde(rename = \"_valueDateTime\")]\n",
+            "    DateTime(types::Element),\n",
+            "}\n",
+            "\n",
+            "impl types::Choice for FooValueElement {\n",
+            "    const KEYS: &'static [&'static str] = &[\"_valueDateTime\"];\n",
+            "}\n",
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_element_into_rust_choice_enum_with_boxed_paths() {
+        let context = CodegenContext {
+            boxed_paths: [String::from("Foo.valueQuantity")].into(),
+            ..CodegenContext::default()
+        };
+        let actual = element_into_rust_choice_enum(&element(), &context);
+        assert!(
+            actual.contains("    Quantity(Box<types::Quantity>),\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("    DateTime(types::DateTime),\n"),
+            "{}",
+            actual
+        );
+    }
+}
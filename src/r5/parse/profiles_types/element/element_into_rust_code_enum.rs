@@ -0,0 +1,239 @@
+//! Element into Rust code enum
+//!
+//! An element with a required binding, such as `Address.use`, allows only
+//! the codes of its ValueSet. The Rust enum has one variant per code, and
+//! each variant renames to its exact code, with Display and FromStr.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+
+/// Indent the start of each Rust variant line of source code
+const RUST_VARIANT_INDENT: &str = "    ";
+
+/// Given one code and its display, return a Rust enum variant name.
+///
+/// Example:
+///
+/// ```no_run
+/// code_into_rust_variant_name("home", None) => "Home"
+/// code_into_rust_variant_name("entered-in-error", None) => "EnteredInError"
+/// code_into_rust_variant_name("<=", Some("Less or Equal to")) => "LessOrEqualTo"
+/// ```
+///
+#[allow(dead_code)]
+pub fn code_into_rust_variant_name(code: &str, display: Option<&str>) -> SourceCodeString {
+    let is_word = |s: &str| {
+        s.chars().any(|c| c.is_ascii_alphanumeric())
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ' '))
+    };
+    let name = if is_word(code) {
+        code.replace('.', " ").to_case(Case::Pascal)
+    } else {
+        display
+            .map(|display| {
+                display
+                    .chars()
+                    .filter(|c| c.is_ascii_alphanumeric() || *c == ' ')
+                    .collect::<String>()
+                    .to_case(Case::Pascal)
+            })
+            .unwrap_or_default()
+    };
+    match name.chars().next() {
+        None => String::from("Code"),
+        Some(first) if first.is_ascii_digit() => format!("V{name}"),
+        Some(_) => name,
+    }
+}
+
+/// Given one code enum, return the Rust variant name and the code of each
+/// concept.
+///
+/// A variant name that is already taken gets its index as a suffix, so that
+/// each variant is unique.
+#[allow(dead_code)]
+pub fn code_enum_into_rust_variants(code_enum: &CodeEnum) -> Vec<(SourceCodeString, &str)> {
+    let mut variants: Vec<(String, &str)> = vec![];
+    for concept in &code_enum.concepts {
+        let mut variant = code_into_rust_variant_name(&concept.code, concept.display.as_deref());
+        if variants.iter().any(|(x, _)| *x == variant) {
+            variant = format!("{variant}{}", variants.len());
+        }
+        variants.push((variant, &concept.code));
+    }
+    variants
+}
+
+/// Given one code enum, generate its Rust enum source code, with Display
+/// and FromStr that use the exact codes.
+///
+/// Example:
+///
+/// ```no_run
+/// let code_enum = … // e.g. name "AddressUse", codes "home", "work".
+/// code_enum_into_rust_enum(&code_enum)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// pub enum AddressUse {
+///     /// Home: A communication address at a home.
+///     #[default]
+///     #[serde(rename = "home")]
+///     Home,
+///     /// Work: An office address.
+///     #[serde(rename = "work")]
+///     Work,
+/// }
+/// ```
+///
+#[allow(dead_code)]
+pub fn code_enum_into_rust_enum(code_enum: &CodeEnum) -> SourceCodeString {
+    let variants = code_enum_into_rust_variants(code_enum);
+    let variant_block = code_enum
+        .concepts
+        .iter()
+        .zip(&variants)
+        .enumerate()
+        .map(|(index, (concept, (variant, code)))| {
+            let doc = match (concept.display.as_deref(), concept.definition.as_deref()) {
+                (Some(display), Some(definition)) => format!("{display}: {definition}"),
+                (Some(text), None) | (None, Some(text)) => String::from(text),
+                (None, None) => String::from(*code),
+            };
+            format!(
+                "{docs}{default}{indent}#[serde(rename = \"{code}\")]\n{indent}{variant},\n",
+                docs = doc
+                    .lines()
+                    .map(|line| format!("{RUST_VARIANT_INDENT}/// {line}\n")
+                        .replace("/// \n", "///\n"))
+                    .collect::<String>(),
+                default = if index == 0 {
+                    format!("{RUST_VARIANT_INDENT}#[default]\n")
+                } else {
+                    String::new()
+                },
+                indent = RUST_VARIANT_INDENT,
+                code = code.escape_default(),
+            )
+        })
+        .collect::<String>();
+    let display_block = variants
+        .iter()
+        .map(|(variant, code)| {
+            format!(
+                "            Self::{variant} => \"{}\",\n",
+                code.escape_default()
+            )
+        })
+        .collect::<String>();
+    let from_str_block = variants
+        .iter()
+        .map(|(variant, code)| {
+            format!(
+                "            \"{}\" => Ok(Self::{variant}),\n",
+                code.escape_default()
+            )
+        })
+        .collect::<String>();
+    let name = &code_enum.name;
+    formatdoc!(
+        r#"
+        /// Codes of the required ValueSet <{value_set}>.
+        #[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+        pub enum {name} {{
+        {variant_block}}}
+
+        impl std::fmt::Display for {name} {{
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                f.write_str(match self {{
+        {display_block}        }})
+            }}
+        }}
+
+        impl std::str::FromStr for {name} {{
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {{
+                match s {{
+        {from_str_block}            _ => Err(format!("unknown {name} code: {{s}}")),
+                }}
+            }}
+        }}
+        "#,
+        value_set = code_enum.value_set,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::registry::CodeConcept;
+
+    #[test]
+    fn test_code_into_rust_variant_name() {
+        let f = code_into_rust_variant_name;
+        assert_eq!(f("home", None), "Home");
+        assert_eq!(f("entered-in-error", None), "EnteredInError");
+        assert_eq!(f("<=", Some("Less or Equal to")), "LessOrEqualTo");
+        assert_eq!(f("1", None), "V1");
+    }
+
+    #[test]
+    fn test_code_enum_into_rust_enum() {
+        let concept = |code: &str, display: &str| CodeConcept {
+            system: String::from("http://hl7.org/fhir/quantity-comparator"),
+            code: code.into(),
+            display: Some(display.into()),
+            definition: None,
+        };
+        let code_enum = CodeEnum {
+            name: String::from("QuantityComparator"),
+            value_set: String::from("http://hl7.org/fhir/ValueSet/quantity-comparator|5.0.0"),
+            concepts: vec![
+                concept("<", "Less than"),
+                concept(">=", "Greater or Equal to"),
+            ],
+        };
+        let actual = code_enum_into_rust_enum(&code_enum);
+        let expect = concat!(
+            "/// Codes of the required ValueSet <http://hl7.org/fhir/ValueSet/quantity-comparator|5.0.0>.\n",
+            "#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]\n",
+            "pub enum QuantityComparator {\n",
+            "    /// Less than\n",
+            "    #[default]\n",
+            "    #[serde(rename = \"<\")]\n",
+            "    LessThan,\n",
+            "    /// Greater or Equal to\n",
+            "    #[serde(rename = \">=\")]\n",
+            "    GreaterOrEqualTo,\n",
+            "}\n",
+            "\n",
+            "impl std::fmt::Display for QuantityComparator {\n",
+            "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n",
+            "        f.write_str(match self {\n",
+            "            Self::LessThan => \"<\",\n",
+            "            Self::GreaterOrEqualTo => \">=\",\n",
+            "        })\n",
+            "    }\n",
+            "}\n",
+            "\n",
+            "impl std::str::FromStr for QuantityComparator {\n",
+            "    type Err = String;\n",
+            "\n",
+            "    fn from_str(s: &str) -> Result<Self, Self::Err> {\n",
+            "        match s {\n",
+            "            \"<\" => Ok(Self::LessThan),\n",
+            "            \">=\" => Ok(Self::GreaterOrEqualTo),\n",
+            "            _ => Err(format!(\"unknown QuantityComparator code: {s}\")),\n",
+            "        }\n",
+            "    }\n",
+            "}\n",
+        );
+        assert_eq!(actual, expect);
+    }
+}
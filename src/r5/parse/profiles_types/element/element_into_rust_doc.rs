@@ -0,0 +1,348 @@
+//! Element into Rust doc
+//!
+//! Generate the doc comment of each element from its definition, such as:
+//!
+//! ```text
+//! /// Postal code for area
+//! ///
+//! /// A postal code designating a region defined by the postal service.
+//! ///
+//! /// Cardinality: 0..1
+//! ///
+//! /// Types: `string`
+//! ```
+//!
+//! FHIR text is markdown for the FHIR website, so it can have links relative
+//! to the spec, bare URLs, HTML-like text such as `<5ug/L`, and brackets such
+//! as `value[x]`. Each of these becomes valid rustdoc, so that `cargo doc` on
+//! generated code builds without warnings.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::element::element_into_rust_type::FHIRPATH_SYSTEM_PREFIX;
+use crate::r5::parse::profiles_types::*;
+
+/// The base URL of the FHIR specification pages, for relative links.
+pub const FHIR_SPEC_BASE_URL: &str = "https://build.fhir.org/";
+
+/// The constraints that every element has, such as `ele-1`, come from the
+/// Element definition, so the doc comment of each element skips them.
+const ELEMENT_CONSTRAINT_SOURCE: &str = "http://hl7.org/fhir/StructureDefinition/Element";
+
+/// Given FHIR markdown, return rustdoc markdown.
+///
+/// Example:
+///
+/// ```no_run
+/// markdown_into_rustdoc("See [Duration](datatypes.html#Duration) for value[x]")
+/// => "See [Duration](https://build.fhir.org/datatypes.html#Duration) for value\[x\]"
+/// ```
+///
+/// Each line loses its leading spaces, so that rustdoc never reads it as an
+/// indented code block, and each fenced code block is text, so that rustdoc
+/// never runs it as a doctest.
+///
+#[allow(dead_code)]
+pub fn markdown_into_rustdoc(markdown: &str) -> SourceCodeString {
+    let mut in_fence = false;
+    // FHIR markdown may break lines with a bare carriage return, which
+    // rustdoc rejects.
+    markdown
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            if line.starts_with("```") {
+                in_fence = !in_fence;
+                return String::from(if in_fence { "```text" } else { "```" });
+            }
+            if in_fence {
+                String::from(line)
+            } else {
+                markdown_line_into_rustdoc(line)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Given one line of FHIR markdown outside a fenced code block, return it
+/// as rustdoc, with absolute links and escaped text.
+fn markdown_line_into_rustdoc(line: &str) -> String {
+    let mut output = String::new();
+    let mut in_code = false;
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        if c == '`' {
+            in_code = !in_code;
+        } else if !in_code {
+            if let Some((link, after)) = markdown_link(rest) {
+                output.push_str(&link);
+                rest = after;
+                continue;
+            }
+            if rest.starts_with("http://") || rest.starts_with("https://") {
+                let end = rest
+                    .find(|x: char| x.is_whitespace() || matches!(x, ')' | '"' | '<' | '>'))
+                    .unwrap_or(rest.len());
+                let url = rest[..end].trim_end_matches(['.', ',', ';', ':']);
+                output.push_str(&format!("<{url}>"));
+                rest = &rest[url.len()..];
+                continue;
+            }
+            match c {
+                '[' => output.push_str("\\["),
+                ']' => output.push_str("\\]"),
+                '<' => output.push_str("&lt;"),
+                '>' => output.push_str("&gt;"),
+                _ => output.push(c),
+            }
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        output.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    output
+}
+
+/// Given text that starts with a markdown link, such as `[a](b.html) …`,
+/// return the link with an absolute URL, and the text after it.
+fn markdown_link(text: &str) -> Option<(String, &str)> {
+    let inner = text.strip_prefix('[')?;
+    let close = inner.find(']')?;
+    let (label, after) = (&inner[..close], &inner[close + 1..]);
+    let after = after.strip_prefix('(')?;
+    let end = after.find(')')?;
+    let url = &after[..end];
+    if label.contains('[') || url.contains(char::is_whitespace) {
+        return None;
+    }
+    let url = if url.contains("://") || url.starts_with('#') {
+        String::from(url)
+    } else {
+        format!("{FHIR_SPEC_BASE_URL}{url}")
+    };
+    let label = label.replace('<', "&lt;").replace('>', "&gt;");
+    Some((format!("[{label}]({url})"), &after[end + 1..]))
+}
+
+/// Given one element, return the paragraphs of its doc comment, in rustdoc
+/// markdown: short, definition, comment, requirements, cardinality, types,
+/// binding, constraints, and the reason that it is a modifier.
+///
+#[allow(dead_code)]
+pub fn element_into_rust_doc_paragraphs(element: &Element) -> Vec<String> {
+    let mut paragraphs = vec![
+        element
+            .short
+            .as_deref()
+            .map(markdown_into_rustdoc)
+            .unwrap_or_else(|| String::from("Short description goes here.")),
+    ];
+    if let Some(definition) = element.definition.as_deref()
+        && Some(definition) != element.short.as_deref()
+    {
+        paragraphs.push(markdown_into_rustdoc(definition));
+    }
+    if let Some(comment) = element.comment.as_deref() {
+        paragraphs.push(format!("Comment: {}", markdown_into_rustdoc(comment)));
+    }
+    if let Some(requirements) = element.requirements.as_deref() {
+        paragraphs.push(format!(
+            "Requirements: {}",
+            markdown_into_rustdoc(requirements)
+        ));
+    }
+    if let (Some(min), Some(max)) = (element.min, element.max.as_deref()) {
+        paragraphs.push(format!("Cardinality: {min}..{max}"));
+    }
+    let types = element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| {
+            let code = element_type
+                .code
+                .strip_prefix(FHIRPATH_SYSTEM_PREFIX)
+                .unwrap_or(&element_type.code);
+            let targets = element_type
+                .target_profile
+                .iter()
+                .flatten()
+                .map(|x| x.rsplit('/').next().unwrap_or(x))
+                .collect::<Vec<&str>>();
+            if targets.is_empty() {
+                format!("`{code}`")
+            } else {
+                format!("`{code}({})`", targets.join(" | "))
+            }
+        })
+        .collect::<Vec<String>>();
+    if !types.is_empty() {
+        paragraphs.push(format!("Types: {}", types.join(", ")));
+    }
+    if let Some(binding) = element.binding.as_ref() {
+        let mut paragraph = format!("Binding: {}", binding.strength);
+        if let Some(value_set) = binding.value_set.as_deref() {
+            let (url, _) = crate::util::split_canonical(value_set);
+            paragraph.push_str(&format!(" to [`{value_set}`]({url})"));
+        }
+        if let Some(description) = binding.description.as_deref() {
+            paragraph.push_str(&format!("\n\n{}", markdown_into_rustdoc(description)));
+        }
+        paragraphs.push(paragraph);
+    }
+    let constraints = element
+        .constraint
+        .iter()
+        .flatten()
+        .filter(|x| x.source.as_deref() != Some(ELEMENT_CONSTRAINT_SOURCE))
+        .map(|x| format!("- `{}`: {}", x.key, markdown_into_rustdoc(&x.human)))
+        .collect::<Vec<String>>();
+    if !constraints.is_empty() {
+        paragraphs.push(format!("Constraints:\n\n{}", constraints.join("\n")));
+    }
+    if let Some(reason) = element.is_modifier_reason.as_deref() {
+        paragraphs.push(format!("Modifier: {}", markdown_into_rustdoc(reason)));
+    }
+    paragraphs
+}
+
+/// Given one element, generate its Rust doc comment source code, with each
+/// line at the indent.
+///
+/// Example:
+///
+/// ```no_run
+/// element_into_rust_doc_comment(&element, "    ")
+/// => "    /// Postal code for area\n    ///\n    /// Cardinality: 0..1\n"
+/// ```
+///
+#[allow(dead_code)]
+pub fn element_into_rust_doc_comment(element: &Element, indent: &str) -> SourceCodeString {
+    element_into_rust_doc_paragraphs(element)
+        .join("\n\n")
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{indent}///\n")
+            } else {
+                format!("{indent}/// {line}\n")
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::{Binding, Constraint, ElementType};
+
+    #[test]
+    fn test_markdown_into_rustdoc() {
+        let f = markdown_into_rustdoc;
+        assert_eq!(
+            f("See [Duration](datatypes.html#Duration)."),
+            "See [Duration](https://build.fhir.org/datatypes.html#Duration)."
+        );
+        assert_eq!(
+            f("See [it](http://hl7.org/fhir/extensions/x.html)."),
+            "See [it](http://hl7.org/fhir/extensions/x.html)."
+        );
+        assert_eq!(
+            f("Use value[x] and `a[0]`."),
+            "Use value\\[x\\] and `a[0]`."
+        );
+        assert_eq!(f("If <5ug/L or >400mg/L"), "If &lt;5ug/L or &gt;400mg/L");
+        assert_eq!(
+            f("At http://hl7.org/fhir/ValueSet/x, or not."),
+            "At <http://hl7.org/fhir/ValueSet/x>, or not."
+        );
+        assert_eq!(f("Alfa\r\n    bravo"), "Alfa\nbravo");
+        assert_eq!(f("```\nfn x()\n```"), "```text\nfn x()\n```");
+    }
+
+    #[test]
+    fn test_element_into_rust_doc_comment() {
+        let element = Element {
+            id: "Quantity.comparator".into(),
+            path: "Quantity.comparator".into(),
+            short: Some(String::from("< | <= | >= | > | ad")),
+            definition: Some(String::from("How the value should be understood.")),
+            requirements: Some(String::from("Need a framework.")),
+            min: Some(0),
+            max: Some(String::from("1")),
+            r#type: Some(vec![ElementType {
+                code: String::from("code"),
+                ..ElementType::default()
+            }]),
+            binding: Some(Binding {
+                strength: String::from("required"),
+                value_set: Some(String::from(
+                    "http://hl7.org/fhir/ValueSet/quantity-comparator|5.0.0",
+                )),
+                ..Binding::default()
+            }),
+            constraint: Some(vec![
+                Constraint {
+                    key: String::from("ele-1"),
+                    human: String::from("All FHIR elements must have a @value or children"),
+                    source: Some(String::from(ELEMENT_CONSTRAINT_SOURCE)),
+                    ..Constraint::default()
+                },
+                Constraint {
+                    key: String::from("qty-3"),
+                    human: String::from("If a code is present, the system SHALL be present"),
+                    ..Constraint::default()
+                },
+            ]),
+            is_modifier_reason: Some(String::from("It modifies the value.")),
+            ..Element::default()
+        };
+        let actual = element_into_rust_doc_comment(&element, "    ");
+        let expect = concat!(
+            "    /// &lt; | &lt;= | &gt;= | &gt; | ad\n",
+            "    ///\n",
+            "    /// How the value should be understood.\n",
+            "    ///\n",
+            "    /// Requirements: Need a framework.\n",
+            "    ///\n",
+            "    /// Cardinality: 0..1\n",
+            "    ///\n",
+            "    /// Types: `code`\n",
+            "    ///\n",
+            "    /// Binding: required to [`http://hl7.org/fhir/ValueSet/quantity-comparator|5.0.0`](http://hl7.org/fhir/ValueSet/quantity-comparator)\n",
+            "    ///\n",
+            "    /// Constraints:\n",
+            "    ///\n",
+            "    /// - `qty-3`: If a code is present, the system SHALL be present\n",
+            "    ///\n",
+            "    /// Modifier: It modifies the value.\n",
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_element_into_rust_doc_comment_with_reference() {
+        let element = Element {
+            id: "Alfa.bravo".into(),
+            path: "Alfa.bravo".into(),
+            r#type: Some(vec![ElementType {
+                code: String::from("Reference"),
+                target_profile: Some(vec![
+                    String::from("http://hl7.org/fhir/StructureDefinition/Patient"),
+                    String::from("http://hl7.org/fhir/StructureDefinition/Group"),
+                ]),
+                ..ElementType::default()
+            }]),
+            ..Element::default()
+        };
+        let actual = element_into_rust_doc_comment(&element, "");
+        assert!(
+            actual.ends_with("/// Types: `Reference(Patient | Group)`\n"),
+            "{}",
+            actual
+        );
+    }
+}
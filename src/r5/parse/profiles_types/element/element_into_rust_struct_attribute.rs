@@ -2,44 +2,308 @@
 
 use crate::SourceCodeString;
 use crate::r5::parse::profiles_types::*;
-use crate::util::*;
 
 /// Indent the start of each Rust attribute line of source code
 const RUST_ATTRIBUTE_INDENT: &str = "    ";
 
 /// Given one element, generate Rust struct attribute source code.
+///
+/// Example:
+///
+/// ```no_run
+/// let element = … // e.g. path "Address.postalCode", type code "string", 0..1.
+/// element_into_rust_struct_attribute(&element, &context)
+/// ```
+///
+/// Output:
+///
+/// ```no_run
+///     /// Postal code for area
+///     ///
+///     /// Cardinality: 0..1
+///     ///
+///     /// Types: `string`
+///     pub postal_code: Option<types::String>,
+/// ```
+///
+/// The doc comment has the rich text of the element definition; see
+/// `element_into_rust_doc_comment`.
+///
+/// A repeated element, with cardinality 0..* or 1..*, is a `Vec` that is
+/// skipped when empty. A repeated primitive element has an option per item,
+/// such as `Vec<Option<types::String>>`, for the `null` items of its values. A prohibited element, with cardinality 0..0, has no
+/// attribute.
+///
+/// A choice element is flattened into the struct, so that its enum reads
+/// and writes keys such as `valueString`, and reads with `types::Choice`, so
+/// that a value of the wrong type fails. When it has primitive types, its
+/// sibling enum is flattened beside it, as an option, for `_valueString`:
+///
+/// ```no_run
+///     /// Id and extensions of `value[x]`
+///     #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+///     pub value_element: Option<ExtensionValueElement>,
+/// ```
+///
+/// A primitive element has a sibling attribute for its id and extensions,
+/// which JSON puts in a key with an underscore, such as `_postalCode`:
+///
+/// ```no_run
+///     /// Id and extensions of `postalCode`
+///     #[serde(rename = "_postalCode")]
+///     pub postal_code_element: Option<types::Element>,
+/// ```
+///
 #[allow(dead_code)]
-pub fn element_into_rust_struct_attribute(element: &Element) -> SourceCodeString {
-    let name = last_word(&element.id);
+pub fn element_into_rust_struct_attribute(
+    element: &Element,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let cardinality = Cardinality::from_element(element);
+    if cardinality == Cardinality::Prohibited {
+        return String::new();
+    }
+    let json_name = element_into_json_name(element);
+    let mut serde_attributes = vec![];
+    if json_name_needs_serde_rename(json_name) {
+        serde_attributes.push(format!("rename = \"{json_name}\""));
+    }
+    if element_is_choice(element) {
+        serde_attributes.push(if cardinality == Cardinality::Required {
+            String::from("flatten, deserialize_with = \"types::Choice::deserialize_required\"")
+        } else {
+            String::from("flatten, deserialize_with = \"types::Choice::deserialize_option\"")
+        });
+    }
+    if cardinality == Cardinality::Repeated {
+        serde_attributes.push(String::from(
+            "default, skip_serializing_if = \"Vec::is_empty\"",
+        ));
+    }
+    let serde_attribute = if serde_attributes.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "{}#[serde({})]\n",
+            RUST_ATTRIBUTE_INDENT,
+            serde_attributes.join(", ")
+        )
+    };
+    let attribute = format!(
+        "{}{}{}pub {}: {},\n",
+        element_into_rust_doc_comment(element, RUST_ATTRIBUTE_INDENT),
+        serde_attribute,
+        RUST_ATTRIBUTE_INDENT,
+        json_name_into_rust_field_name(json_name),
+        element_into_rust_field_type(element, context),
+    );
+    if element_has_primitive_sibling(element) {
+        attribute + &element_into_rust_struct_sibling_attribute(json_name, cardinality)
+    } else if element_has_choice_sibling(element) {
+        format!(
+            "{attribute}{indent}/// Id and extensions of `{json_name}[x]`\n{indent}#[serde(flatten, deserialize_with = \"types::Choice::deserialize_option\")]\n{indent}pub {field}: Option<{name}>,\n",
+            indent = RUST_ATTRIBUTE_INDENT,
+            field = json_name_into_rust_field_name(&format!("{json_name}Element")),
+            name = choice_sibling_enum_name(element),
+        )
+    } else {
+        attribute
+    }
+}
+
+/// Given one primitive element JSON name, generate the Rust struct attribute
+/// for its id and extensions.
+///
+/// A repeated element has one optional sibling item per value, such as
+/// `"_given": [null, {"extension": […]}]`.
+///
+fn element_into_rust_struct_sibling_attribute(
+    json_name: &str,
+    cardinality: Cardinality,
+) -> SourceCodeString {
+    let (serde_attribute, field_type) = if cardinality == Cardinality::Repeated {
+        (
+            format!("rename = \"_{json_name}\", default, skip_serializing_if = \"Vec::is_empty\""),
+            "Vec<Option<types::Element>>",
+        )
+    } else {
+        (
+            format!("rename = \"_{json_name}\""),
+            "Option<types::Element>",
+        )
+    };
     format!(
-        "{}/// {}\n{}{}: ? // {}\n",
+        "{}/// Id and extensions of `{}`\n{}#[serde({})]\n{}pub {}: {},\n",
         RUST_ATTRIBUTE_INDENT,
-        element
-            .short
-            .as_ref()
-            .unwrap_or(&String::from("Short description goes here.")),
+        json_name,
         RUST_ATTRIBUTE_INDENT,
-        name,
-        match element.r#type.as_ref() {
-            Some(x) => x.first().unwrap().code.as_str(),
-            None => "?",
-        },
+        serde_attribute,
+        RUST_ATTRIBUTE_INDENT,
+        json_name_into_rust_field_name(&format!("{json_name}Element")),
+        field_type,
     )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::r5::parse::all::ElementType;
 
     #[test]
     fn test_element_into_rust_struct_attribute() {
         let element = Element {
             id: "Alfa.bravo".into(),
+            path: "Alfa.bravo".into(),
             short: Some(String::from("Short comment")),
+            r#type: Some(vec![ElementType {
+                code: String::from("string"),
+                ..ElementType::default()
+            }]),
             ..Element::default()
         };
-        let actual = element_into_rust_struct_attribute(&element);
-        let expect = concat!("    /// Short comment\n", "    bravo: ? // ?\n",);
+        let actual = element_into_rust_struct_attribute(&element, &CodegenContext::new());
+        let expect = concat!(
+            "    /// Short comment\n",
+            "    ///\n",
+            "    /// Types: `string`\n",
+            "    pub bravo: Option<types::String>,\n",
+            "    /// Id and extensions of `bravo`\n",
+            "    #[serde(rename = \"_bravo\")]\n",
+            "    pub bravo_element: Option<types::Element>,\n",
+        );
         assert_eq!(actual, expect);
     }
+
+    #[test]
+    fn test_element_into_rust_struct_attribute_with_cardinality() {
+        let element = |min: u32, max: &str| Element {
+            id: "Alfa.bravo".into(),
+            path: "Alfa.bravo".into(),
+            short: Some(String::from("Short comment")),
+            min: Some(min),
+            max: Some(max.into()),
+            r#type: Some(vec![ElementType {
+                code: String::from("string"),
+                ..ElementType::default()
+            }]),
+            ..Element::default()
+        };
+        assert_eq!(
+            element_into_rust_struct_attribute(&element(1, "1"), &CodegenContext::new()),
+            concat!(
+                "    /// Short comment\n",
+                "    ///\n",
+                "    /// Cardinality: 1..1\n",
+                "    ///\n",
+                "    /// Types: `string`\n",
+                "    pub bravo: types::String,\n",
+                "    /// Id and extensions of `bravo`\n",
+                "    #[serde(rename = \"_bravo\")]\n",
+                "    pub bravo_element: Option<types::Element>,\n",
+            ),
+        );
+        assert_eq!(
+            element_into_rust_struct_attribute(&element(0, "1"), &CodegenContext::new()),
+            concat!(
+                "    /// Short comment\n",
+                "    ///\n",
+                "    /// Cardinality: 0..1\n",
+                "    ///\n",
+                "    /// Types: `string`\n",
+                "    pub bravo: Option<types::String>,\n",
+                "    /// Id and extensions of `bravo`\n",
+                "    #[serde(rename = \"_bravo\")]\n",
+                "    pub bravo_element: Option<types::Element>,\n",
+            ),
+        );
+        assert_eq!(
+            element_into_rust_struct_attribute(&element(0, "*"), &CodegenContext::new()),
+            concat!(
+                "    /// Short comment\n",
+                "    ///\n",
+                "    /// Cardinality: 0..*\n",
+                "    ///\n",
+                "    /// Types: `string`\n",
+                "    #[serde(default, skip_serializing_if = \"Vec::is_empty\")]\n",
+                "    pub bravo: Vec<Option<types::String>>,\n",
+                "    /// Id and extensions of `bravo`\n",
+                "    #[serde(rename = \"_bravo\", default, skip_serializing_if = \"Vec::is_empty\")]\n",
+                "    pub bravo_element: Vec<Option<types::Element>>,\n",
+            ),
+        );
+        assert_eq!(
+            element_into_rust_struct_attribute(&element(0, "0"), &CodegenContext::new()),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_element_into_rust_struct_attribute_with_keyword() {
+        let element = Element {
+            id: "Alfa.type".into(),
+            path: "Alfa.type".into(),
+            short: Some(String::from("Short comment")),
+            r#type: Some(vec![ElementType {
+                code: String::from("http://hl7.org/fhirpath/System.String"),
+                ..ElementType::default()
+            }]),
+            ..Element::default()
+        };
+        let actual = element_into_rust_struct_attribute(&element, &CodegenContext::new());
+        let expect = concat!(
+            "    /// Short comment\n",
+            "    ///\n",
+            "    /// Types: `String`\n",
+            "    pub r#type: Option<String>,\n"
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_element_into_rust_struct_attribute_with_keyword_sibling() {
+        let element = Element {
+            id: "Alfa.type".into(),
+            path: "Alfa.type".into(),
+            short: Some(String::from("Short comment")),
+            r#type: Some(vec![ElementType {
+                code: String::from("code"),
+                ..ElementType::default()
+            }]),
+            ..Element::default()
+        };
+        let actual = element_into_rust_struct_attribute(&element, &CodegenContext::new());
+        assert!(
+            actual.ends_with(
+                "    #[serde(rename = \"_type\")]\n    pub type_element: Option<types::Element>,\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_element_into_rust_struct_attribute_with_choice_sibling() {
+        let element = Element {
+            id: "Alfa.value[x]".into(),
+            path: "Alfa.value[x]".into(),
+            r#type: Some(
+                ["Quantity", "string"]
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        };
+        let actual = element_into_rust_struct_attribute(&element, &CodegenContext::new());
+        assert!(
+            actual.ends_with(
+                "    #[serde(flatten, deserialize_with = \"types::Choice::deserialize_option\")]\n    pub value_element: Option<AlfaValueElement>,\n"
+            ),
+            "{}",
+            actual
+        );
+    }
 }
@@ -0,0 +1,480 @@
+//! Element into Rust type
+//!
+//! Map each FHIR element to the Rust type and Rust field name that represent
+//! it in generated source code:
+//!
+//! - FHIRPath system types, such as `http://hl7.org/fhirpath/System.String`,
+//!   map to Rust primitives, such as `String`.
+//!
+//! - FHIR primitive types, such as `dateTime`, and FHIR complex types, such
+//!   as `Quantity`, map to the `r5::types` structs, such as `types::DateTime`
+//!   and `types::Quantity`.
+//!
+//! - Inline elements, such as `Dosage.doseAndRate`, map to a nested struct
+//!   named by their path, such as `DosageDoseAndRate`.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+
+/// The URL prefix of the FHIRPath system types.
+pub const FHIRPATH_SYSTEM_PREFIX: &str = "http://hl7.org/fhirpath/System.";
+
+/// Rust keywords that a raw identifier can escape, such as `r#type`.
+const RUST_RAW_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords that a raw identifier can not escape.
+const RUST_NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Given one FHIR type code, return its Rust type.
+///
+/// Example:
+///
+/// ```no_run
+/// type_code_into_rust_type("http://hl7.org/fhirpath/System.String") => "String"
+/// type_code_into_rust_type("dateTime") => "types::DateTime"
+/// type_code_into_rust_type("Quantity") => "types::Quantity"
+/// ```
+///
+#[allow(dead_code)]
+pub fn type_code_into_rust_type(code: &str) -> SourceCodeString {
+    match code.strip_prefix(FHIRPATH_SYSTEM_PREFIX) {
+        Some(system) => String::from(match system {
+            "Boolean" => "bool",
+            "Integer" => "i32",
+            "Long" => "i64",
+            // A JSON number is Eq, and keeps the exact decimal digits, such
+            // as `1.50`, with the serde_json `arbitrary_precision` feature.
+            "Decimal" => "::serde_json::Number",
+            _ => "String",
+        }),
+        None => format!("types::{}", code.to_case(Case::Pascal)),
+    }
+}
+
+/// Is the FHIR type code a primitive type, such as `string` or `dateTime`?
+///
+/// FHIR names each primitive type in lower camel case, and each complex type
+/// in upper camel case, such as `Quantity`.
+///
+#[allow(dead_code)]
+pub fn type_code_is_primitive(code: &str) -> bool {
+    !code.starts_with(FHIRPATH_SYSTEM_PREFIX) && code.starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+/// Does the element have a primitive type that can carry an id and
+/// extensions in a sibling JSON key, such as `_birthDate`?
+///
+/// The `xhtml` type can not carry extensions, so it has no sibling. A choice
+/// element has its siblings in a flattened enum; see `choice_sibling_codes`.
+///
+#[allow(dead_code)]
+pub fn element_has_primitive_sibling(element: &Element) -> bool {
+    element.content_reference.is_none()
+        && !element.path.ends_with("[x]")
+        && match element.r#type.as_deref() {
+            Some([element_type]) => {
+                type_code_is_primitive(&element_type.code) && element_type.code != "xhtml"
+            }
+            _ => false,
+        }
+}
+
+/// Given one element path, return the name of its nested Rust struct.
+///
+/// Example:
+///
+/// ```no_run
+/// path_into_rust_struct_name("Dosage.doseAndRate") => "DosageDoseAndRate"
+/// ```
+///
+#[allow(dead_code)]
+pub fn path_into_rust_struct_name(path: &str) -> SourceCodeString {
+    path.split('.')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Does the element define its own children inline, as a BackboneElement does?
+#[allow(dead_code)]
+pub fn element_is_inline_struct(element: &Element) -> bool {
+    element.path.contains('.')
+        && element.content_reference.is_none()
+        && element
+            .r#type
+            .as_ref()
+            .is_some_and(|types| types.iter().any(|x| is_inline_code(&x.code)))
+}
+
+fn is_inline_code(code: &str) -> bool {
+    matches!(code, "Element" | "BackboneElement")
+}
+
+/// Given one element, return its Rust type, without cardinality.
+///
+/// Example:
+///
+/// ```no_run
+/// let element = … // e.g. path "Address.city", type code "string".
+/// element_into_rust_type(&element, &context) => "types::String"
+/// ```
+///
+/// A choice element, such as `Foo.value[x]`, maps to its choice enum,
+/// such as `FooValue`. A code with a required binding that expands maps to
+/// its code enum, such as `AddressUse`.
+///
+#[allow(dead_code)]
+pub fn element_into_rust_type(element: &Element, context: &CodegenContext) -> SourceCodeString {
+    if let Some(code_enum) = context.code_enum(element) {
+        return code_enum.name;
+    }
+    if let Some(content_reference) = element.content_reference.as_ref() {
+        let path = content_reference
+            .rsplit_once('#')
+            .map_or(content_reference.as_str(), |(_, path)| path);
+        return path_into_rust_struct_name(path);
+    }
+    if element_is_inline_struct(element) {
+        return path_into_rust_struct_name(&element.path);
+    }
+    if let Some(path) = element.path.strip_suffix("[x]") {
+        return path_into_rust_struct_name(path);
+    }
+    match element.r#type.as_deref() {
+        Some([element_type]) => type_code_into_rust_type(&element_type.code),
+        _ => String::from("::serde_json::Value"),
+    }
+}
+
+/// Given one element, return the Rust type of one of its items, which is
+/// its Rust type, boxed when the context breaks a recursive type there.
+///
+/// Example:
+///
+/// ```no_run
+/// let element = … // e.g. path "Reference.identifier", type code "Identifier".
+/// element_into_rust_item_type(&element, &context) => "Box<types::Identifier>"
+/// ```
+///
+#[allow(dead_code)]
+pub fn element_into_rust_item_type(
+    element: &Element,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let rust_type = element_into_rust_type(element, context);
+    if context.is_boxed(&element.path) {
+        format!("Box<{rust_type}>")
+    } else {
+        rust_type
+    }
+}
+
+/// The cardinality of an element, as a Rust field represents it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cardinality {
+    /// 1..1 => `T`
+    Required,
+
+    /// 0..1 => `Option<T>`
+    Optional,
+
+    /// 0..* or 1..* => `Vec<T>`
+    Repeated,
+
+    /// 0..0 => no field
+    Prohibited,
+}
+
+impl Cardinality {
+    /// Given one element, return its cardinality from its `min` and `max`.
+    pub fn from_element(element: &Element) -> Self {
+        match (
+            element.min.unwrap_or(0),
+            element.max.as_deref().unwrap_or("1"),
+        ) {
+            (_, "0") => Cardinality::Prohibited,
+            (0, "1") => Cardinality::Optional,
+            (_, "1") => Cardinality::Required,
+            _ => Cardinality::Repeated,
+        }
+    }
+}
+
+/// Given one element, return its Rust field type, with cardinality.
+///
+/// Example:
+///
+/// ```no_run
+/// let element = … // e.g. path "Address.line", type code "string", 0..*.
+/// element_into_rust_field_type(&element, &context) => "Vec<Option<types::String>>"
+/// ```
+///
+/// A repeated primitive element has an option per item, because FHIR JSON
+/// pads its values with `null` to line up with its sibling ids and
+/// extensions, such as `"line": ["a", null], "_line": [null, {"id": "l"}]`.
+///
+#[allow(dead_code)]
+pub fn element_into_rust_field_type(
+    element: &Element,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let rust_type = element_into_rust_item_type(element, context);
+    match Cardinality::from_element(element) {
+        Cardinality::Required | Cardinality::Prohibited => rust_type,
+        Cardinality::Optional => format!("Option<{rust_type}>"),
+        Cardinality::Repeated if element_has_primitive_sibling(element) => {
+            format!("Vec<Option<{rust_type}>>")
+        }
+        Cardinality::Repeated => format!("Vec<{rust_type}>"),
+    }
+}
+
+/// Given one element, return its FHIR JSON name, such as `postalCode`.
+///
+/// A choice element keeps its name without the `[x]` suffix, such as `value`.
+#[allow(dead_code)]
+pub fn element_into_json_name(element: &Element) -> &str {
+    let name = element
+        .path
+        .rsplit_once('.')
+        .map_or(element.path.as_str(), |(_, name)| name);
+    name.strip_suffix("[x]").unwrap_or(name)
+}
+
+/// Given one FHIR JSON name, return its Rust field name.
+///
+/// Example:
+///
+/// ```no_run
+/// json_name_into_rust_field_name("postalCode") => "postal_code"
+/// json_name_into_rust_field_name("type") => "r#type"
+/// ```
+///
+#[allow(dead_code)]
+pub fn json_name_into_rust_field_name(json_name: &str) -> SourceCodeString {
+    let name = json_name.to_case(Case::Snake);
+    if RUST_RAW_KEYWORDS.contains(&name.as_str()) {
+        format!("r#{name}")
+    } else if RUST_NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+/// Does the Rust field name need a serde rename to match its JSON name?
+///
+/// Structs rename all fields into camel case, the way serde does, which can
+/// not round trip every name, such as a name with consecutive capitals.
+#[allow(dead_code)]
+pub fn json_name_needs_serde_rename(json_name: &str) -> bool {
+    let field_name = json_name_into_rust_field_name(json_name);
+    let field_name = field_name.strip_prefix("r#").unwrap_or(&field_name);
+    serde_camel_case(field_name) != json_name
+}
+
+/// Rename a snake case field into camel case, as `rename_all = "camelCase"`.
+fn serde_camel_case(field_name: &str) -> String {
+    let mut camel = String::new();
+    let mut capitalize = false;
+    for c in field_name.chars() {
+        if c == '_' {
+            capitalize = !camel.is_empty();
+        } else if capitalize {
+            camel.extend(c.to_uppercase());
+            capitalize = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn element(path: &str, codes: &[&str]) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            r#type: Some(
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    #[test]
+    fn test_type_code_into_rust_type() {
+        let f = type_code_into_rust_type;
+        assert_eq!(f("http://hl7.org/fhirpath/System.String"), "String");
+        assert_eq!(f("http://hl7.org/fhirpath/System.Boolean"), "bool");
+        assert_eq!(f("http://hl7.org/fhirpath/System.Integer"), "i32");
+        assert_eq!(
+            f("http://hl7.org/fhirpath/System.Decimal"),
+            "::serde_json::Number"
+        );
+        assert_eq!(f("string"), "types::String");
+        assert_eq!(f("dateTime"), "types::DateTime");
+        assert_eq!(f("base64Binary"), "types::Base64Binary");
+        assert_eq!(f("integer64"), "types::Integer64");
+        assert_eq!(f("CodeableConcept"), "types::CodeableConcept");
+    }
+
+    #[test]
+    fn test_element_into_rust_type() {
+        assert_eq!(
+            element_into_rust_type(
+                &element("Address.city", &["string"]),
+                &CodegenContext::new()
+            ),
+            "types::String"
+        );
+        assert_eq!(
+            element_into_rust_type(
+                &element("Dosage.doseAndRate", &["Element"]),
+                &CodegenContext::new()
+            ),
+            "DosageDoseAndRate"
+        );
+        assert_eq!(
+            element_into_rust_type(&element("Element", &["Element"]), &CodegenContext::new()),
+            "types::Element"
+        );
+        let mut item = element("Questionnaire.item.item", &[]);
+        item.content_reference = Some(String::from(
+            "http://hl7.org/fhir/StructureDefinition/Questionnaire#Questionnaire.item",
+        ));
+        assert_eq!(
+            element_into_rust_type(&item, &CodegenContext::new()),
+            "QuestionnaireItem"
+        );
+        assert_eq!(
+            element_into_rust_type(
+                &element("Foo.value[x]", &["Quantity", "string"]),
+                &CodegenContext::new()
+            ),
+            "FooValue"
+        );
+    }
+
+    #[test]
+    fn test_type_code_is_primitive() {
+        assert!(type_code_is_primitive("string"));
+        assert!(type_code_is_primitive("dateTime"));
+        assert!(!type_code_is_primitive("Quantity"));
+        assert!(!type_code_is_primitive(
+            "http://hl7.org/fhirpath/System.String"
+        ));
+    }
+
+    #[test]
+    fn test_element_has_primitive_sibling() {
+        assert!(element_has_primitive_sibling(&element(
+            "Address.city",
+            &["string"]
+        )));
+        assert!(!element_has_primitive_sibling(&element(
+            "Address.period",
+            &["Period"]
+        )));
+        assert!(!element_has_primitive_sibling(&element(
+            "Narrative.div",
+            &["xhtml"]
+        )));
+        assert!(!element_has_primitive_sibling(&element(
+            "Foo.value[x]",
+            &["string"]
+        )));
+    }
+
+    fn element_with_cardinality(path: &str, min: u32, max: &str) -> Element {
+        Element {
+            min: Some(min),
+            max: Some(max.into()),
+            ..element(path, &["string"])
+        }
+    }
+
+    #[test]
+    fn test_cardinality_from_element() {
+        let f =
+            |min, max| Cardinality::from_element(&element_with_cardinality("Alfa.bravo", min, max));
+        assert_eq!(f(1, "1"), Cardinality::Required);
+        assert_eq!(f(0, "1"), Cardinality::Optional);
+        assert_eq!(f(0, "*"), Cardinality::Repeated);
+        assert_eq!(f(1, "*"), Cardinality::Repeated);
+        assert_eq!(f(0, "0"), Cardinality::Prohibited);
+    }
+
+    #[test]
+    fn test_element_into_rust_field_type() {
+        let f = |min, max| {
+            element_into_rust_field_type(
+                &element_with_cardinality("Alfa.bravo", min, max),
+                &CodegenContext::new(),
+            )
+        };
+        assert_eq!(f(1, "1"), "types::String");
+        assert_eq!(f(0, "1"), "Option<types::String>");
+        assert_eq!(f(0, "*"), "Vec<Option<types::String>>");
+        let mut coding = element("Alfa.coding", &["Coding"]);
+        coding.max = Some(String::from("*"));
+        assert_eq!(
+            element_into_rust_field_type(&coding, &CodegenContext::new()),
+            "Vec<types::Coding>"
+        );
+    }
+
+    #[test]
+    fn test_element_into_json_name() {
+        assert_eq!(
+            element_into_json_name(&element("Address.postalCode", &[])),
+            "postalCode"
+        );
+        assert_eq!(
+            element_into_json_name(&element("Extension.value[x]", &[])),
+            "value"
+        );
+    }
+
+    #[test]
+    fn test_json_name_into_rust_field_name() {
+        let f = json_name_into_rust_field_name;
+        assert_eq!(f("postalCode"), "postal_code");
+        assert_eq!(f("type"), "r#type");
+        assert_eq!(f("use"), "r#use");
+        assert_eq!(f("abstract"), "r#abstract");
+        assert_eq!(f("ref"), "r#ref");
+        assert_eq!(f("self"), "self_");
+    }
+
+    #[test]
+    fn test_json_name_needs_serde_rename() {
+        assert!(!json_name_needs_serde_rename("postalCode"));
+        assert!(!json_name_needs_serde_rename("type"));
+        assert!(!json_name_needs_serde_rename("self"));
+        assert!(!json_name_needs_serde_rename("base64Binary"));
+        assert!(json_name_needs_serde_rename("URL"));
+    }
+}
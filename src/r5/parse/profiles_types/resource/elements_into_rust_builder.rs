@@ -0,0 +1,350 @@
+//! Elements into Rust builder
+//!
+//! A builder sets the attributes of a struct one by one, such as
+//! `Quantity::builder().value(1).unit("mg").build()`, and its `build()`
+//! lists every required element that is not set, rather than filling it in
+//! with a default.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::child_elements;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+
+/// The module name of the generated builder error.
+pub const BUILDER_MODULE: &str = "builder";
+
+/// The Rust source code of the generated builder error module.
+pub const BUILDER_ERROR_BLOCK: &str = r#"//! Builder
+//!
+//! The error of a builder whose required elements are not all set.
+
+/// The paths of the required elements that a builder did not set, such as
+/// `Quantity.value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingElements {
+    pub paths: Vec<&'static str>,
+}
+
+impl std::fmt::Display for MissingElements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing required elements: {}", self.paths.join(", "))
+    }
+}
+
+impl std::error::Error for MissingElements {}
+"#;
+
+/// Rust struct name and FHIR elements => Rust builder block of source code,
+/// for the direct children of the element at the parent path.
+///
+/// Example:
+///
+/// ```no_run
+/// let elements = … // e.g. paths "Foo", "Foo.bravo" 1..1, "Foo.charlie" 0..*,
+/// // "Foo.delta" 0..*.
+/// elements_into_rust_builder_block("Foo", &elements, "Foo", &context)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// pub struct FooBuilder {
+///     inner: Foo,
+///     bravo: Option<types::String>,
+/// }
+///
+/// impl FooBuilder {
+///     pub fn bravo(mut self, value: impl Into<types::String>) -> Self { … }
+///     pub fn charlie(mut self, value: impl IntoIterator<Item = impl Into<types::Uri>>) -> Self { … }
+///     pub fn push_charlie(mut self, value: impl Into<types::Uri>) -> Self { … }
+///     pub fn delta(mut self, value: impl Into<Vec<types::Coding>>) -> Self { … }
+///     pub fn push_delta(mut self, value: impl Into<types::Coding>) -> Self { … }
+///     pub fn build(self) -> Result<Foo, types::MissingElements> { … }
+/// }
+/// ```
+///
+/// The builder holds each required attribute as an option until `build()`,
+/// and every other attribute in the inner struct. A choice element has one
+/// setter per type, such as `value_quantity()` and `value_string()`. A
+/// repeated primitive element, which has an option per item, takes items.
+///
+#[allow(dead_code)]
+pub fn elements_into_rust_builder_block(
+    name: &str,
+    elements: &[Element],
+    parent_path: &str,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let mut required_attributes = String::new();
+    let mut setters: Vec<String> = vec![];
+    let mut build_checks = String::new();
+    for element in child_elements(elements, parent_path) {
+        let cardinality = Cardinality::from_element(element);
+        if cardinality == Cardinality::Prohibited {
+            continue;
+        }
+        let json_name = element_into_json_name(element);
+        let field = json_name_into_rust_field_name(json_name);
+        let item_type = element_into_rust_item_type(element, context);
+        if cardinality == Cardinality::Required {
+            required_attributes.push_str(&format!("    {field}: Option<{item_type}>,\n"));
+            build_checks.push_str(&format!(
+                "        match self.{field} {{\n            Some(value) => inner.{field} = value,\n            None => missing.push(\"{path}\"),\n        }}\n",
+                path = element.path,
+            ));
+        }
+        if element_is_choice(element) {
+            let path = element.path.trim_end_matches("[x]");
+            for code in element.r#type.iter().flatten().map(|x| x.code.as_str()) {
+                let variant_type = type_code_into_rust_type(code);
+                let variant_value = if context.is_boxed(&choice_json_key(path, code)) {
+                    "Box::new(value.into())"
+                } else {
+                    "value.into()"
+                };
+                let variant = format!(
+                    "{item_type}::{}({variant_value})",
+                    code.to_case(Case::Pascal)
+                );
+                setters.push(setter_block(
+                    &json_name_into_rust_field_name(&choice_json_key(json_name, code)),
+                    &format!("impl Into<{variant_type}>"),
+                    &assignment(&field, cardinality, &variant),
+                    &format!("Set `{}` to a `{code}`.", element.path),
+                ));
+            }
+            continue;
+        }
+        match cardinality {
+            Cardinality::Repeated if element_has_primitive_sibling(element) => {
+                setters.push(setter_block(
+                    &field,
+                    &format!("impl IntoIterator<Item = impl Into<{item_type}>>"),
+                    &format!(
+                        "self.inner.{field} = value.into_iter().map(|x| Some(x.into())).collect();"
+                    ),
+                    &format!("Set every `{}`.", element.path),
+                ));
+                setters.push(setter_block(
+                    &format!("push_{}", field.trim_start_matches("r#")),
+                    &format!("impl Into<{item_type}>"),
+                    &format!("self.inner.{field}.push(Some(value.into()));"),
+                    &format!("Add one `{}`.", element.path),
+                ));
+            }
+            Cardinality::Repeated => {
+                setters.push(setter_block(
+                    &field,
+                    &format!("impl Into<Vec<{item_type}>>"),
+                    &format!("self.inner.{field} = value.into();"),
+                    &format!("Set every `{}`.", element.path),
+                ));
+                setters.push(setter_block(
+                    &format!("push_{}", field.trim_start_matches("r#")),
+                    &format!("impl Into<{item_type}>"),
+                    &format!("self.inner.{field}.push(value.into());"),
+                    &format!("Add one `{}`.", element.path),
+                ));
+            }
+            _ => setters.push(setter_block(
+                &field,
+                &format!("impl Into<{item_type}>"),
+                &assignment(&field, cardinality, "value.into()"),
+                &format!("Set `{}`.", element.path),
+            )),
+        }
+    }
+    let build_block = if build_checks.is_empty() {
+        String::from("        Ok(self.inner)\n")
+    } else {
+        format!(
+            concat!(
+                "        let mut inner = self.inner;\n",
+                "        let mut missing = vec![];\n",
+                "{}",
+                "        if missing.is_empty() {{\n",
+                "            Ok(inner)\n",
+                "        }} else {{\n",
+                "            Err(types::MissingElements {{ paths: missing }})\n",
+                "        }}\n",
+            ),
+            build_checks
+        )
+    };
+    formatdoc!(
+        r#"
+        impl {name} {{
+            /// Start a builder, with every attribute unset.
+            pub fn builder() -> {name}Builder {{
+                {name}Builder::default()
+            }}
+        }}
+
+        /// Build one `{name}`, one attribute at a time.
+        #[derive(Debug, Default, Clone)]
+        pub struct {name}Builder {{
+            inner: {name},
+        {required_attributes}}}
+
+        impl {name}Builder {{
+        {setter_block}
+            /// Build, or list the path of each required element that is not set.
+            pub fn build(self) -> Result<{name}, types::MissingElements> {{
+        {build_block}    }}
+        }}
+        "#,
+        setter_block = setters.join("\n"),
+    )
+}
+
+/// The statement of a setter that puts the value in its attribute: in the
+/// builder for a required attribute, or in the inner struct.
+fn assignment(field: &str, cardinality: Cardinality, value: &str) -> String {
+    match cardinality {
+        Cardinality::Required => format!("self.{field} = Some({value});"),
+        Cardinality::Repeated => format!("self.inner.{field}.push({value});"),
+        _ => format!("self.inner.{field} = Some({value});"),
+    }
+}
+
+fn setter_block(name: &str, parameter_type: &str, statement: &str, doc: &str) -> String {
+    format!(
+        concat!(
+            "    /// {}\n",
+            "    pub fn {}(mut self, value: {}) -> Self {{\n",
+            "        {}\n",
+            "        self\n",
+            "    }}\n",
+        ),
+        doc, name, parameter_type, statement,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn element(path: &str, min: u32, max: &str, codes: &[&str]) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            min: Some(min),
+            max: Some(max.into()),
+            r#type: Some(
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    fn elements() -> Vec<Element> {
+        vec![
+            element("Foo", 0, "*", &[]),
+            element("Foo.bravo", 1, "1", &["string"]),
+            element("Foo.type", 0, "*", &["uri"]),
+            element("Foo.coding", 0, "*", &["Coding"]),
+            element("Foo.value[x]", 0, "1", &["Quantity", "string"]),
+        ]
+    }
+
+    #[test]
+    fn test_elements_into_rust_builder_block() {
+        let actual =
+            elements_into_rust_builder_block("Foo", &elements(), "Foo", &CodegenContext::new());
+        let expect_builder = concat!(
+            "pub struct FooBuilder {\n",
+            "    inner: Foo,\n",
+            "    bravo: Option<types::String>,\n",
+            "}\n",
+        );
+        assert!(actual.contains(expect_builder), "{}", actual);
+        let expect_setter = concat!(
+            "    /// Set `Foo.bravo`.\n",
+            "    pub fn bravo(mut self, value: impl Into<types::String>) -> Self {\n",
+            "        self.bravo = Some(value.into());\n",
+            "        self\n",
+            "    }\n",
+        );
+        assert!(actual.contains(expect_setter), "{}", actual);
+        assert!(
+            actual.contains(
+                "    pub fn r#type(mut self, value: impl IntoIterator<Item = impl Into<types::Uri>>) -> Self {\n"
+            ),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(
+                "    pub fn push_type(mut self, value: impl Into<types::Uri>) -> Self {\n        self.inner.r#type.push(Some(value.into()));\n"
+            ),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(
+                "    pub fn coding(mut self, value: impl Into<Vec<types::Coding>>) -> Self {\n"
+            ),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(concat!(
+                "    pub fn value_quantity(mut self, value: impl Into<types::Quantity>) -> Self {\n",
+                "        self.inner.value = Some(FooValue::Quantity(value.into()));\n",
+            )),
+            "{}",
+            actual
+        );
+        let expect_build = concat!(
+            "        match self.bravo {\n",
+            "            Some(value) => inner.bravo = value,\n",
+            "            None => missing.push(\"Foo.bravo\"),\n",
+            "        }\n",
+        );
+        assert!(actual.contains(expect_build), "{}", actual);
+    }
+
+    #[test]
+    fn test_elements_into_rust_builder_block_without_required() {
+        let actual = elements_into_rust_builder_block(
+            "Foo",
+            &elements()[..1],
+            "Foo",
+            &CodegenContext::new(),
+        );
+        assert!(
+            actual.contains("pub struct FooBuilder {\n    inner: Foo,\n}\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("        Ok(self.inner)\n    }\n"),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_elements_into_rust_builder_block_with_boxed_paths() {
+        let context = CodegenContext {
+            boxed_paths: [String::from("Foo.valueQuantity")].into(),
+            ..CodegenContext::default()
+        };
+        let actual = elements_into_rust_builder_block("Foo", &elements(), "Foo", &context);
+        assert!(
+            actual.contains(
+                "        self.inner.value = Some(FooValue::Quantity(Box::new(value.into())));\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+}
@@ -0,0 +1,578 @@
+//! Elements into Rust extensions
+//!
+//! Each generated element has its extensions as a list of `Extension`, with
+//! a URL and an optional `value[x]`. A typed API finds an extension by its
+//! URL, and views its value as the Rust type of a `value[x]` type:
+//!
+//! ```no_run
+//! use types::HasExtensions;
+//!
+//! let fmm: Option<&types::Integer> = element.get_extension_value(types::extensions::STRUCTUREDEFINITION_FMM_URL);
+//! element.set_extension_value(types::extensions::STRUCTUREDEFINITION_FMM_URL, types::Integer::from(3));
+//! let removed = element.remove_extensions(types::extensions::STRUCTUREDEFINITION_FMM_URL);
+//! ```
+//!
+//! Each generated struct with an `extension` field implements the
+//! `HasExtensions` trait, including `Extension` itself, so that a complex
+//! extension finds its nested extensions the same way. The `CoreExtensions`
+//! trait has a typed accessor for each core extension definition of the
+//! `ExtensionRegistry`, such as `structuredefinition_fmm`.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::{
+    child_elements, resource_elements,
+};
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+use std::collections::BTreeSet;
+
+/// The module name of the generated extensions.
+pub const EXTENSIONS_MODULE: &str = "extensions";
+
+/// The URL prefix of the core extension definitions, that get a typed
+/// accessor in the `CoreExtensions` trait.
+pub const CORE_EXTENSION_URL_PREFIX: &str = "http://hl7.org/fhir/StructureDefinition/";
+
+/// The Rust source code of the `HasExtensions` trait of the generated
+/// extensions module, after the `ExtensionValueType` trait.
+pub const HAS_EXTENSIONS_BLOCK: &str = r#"
+/// A value with extensions, such as an `Element` or a `DomainResource`.
+pub trait HasExtensions {
+    /// The extensions, in order.
+    fn extensions(&self) -> &[types::Extension];
+
+    /// The extensions, mutably.
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension>;
+
+    /// The first extension with the URL, if any.
+    fn get_extension(&self, url: &str) -> Option<&types::Extension> {
+        self.extensions().iter().find(|extension| extension.url == url)
+    }
+
+    /// Each extension with the URL, in order.
+    fn get_extensions(&self, url: &str) -> Vec<&types::Extension> {
+        self.extensions()
+            .iter()
+            .filter(|extension| extension.url == url)
+            .collect()
+    }
+
+    /// The value of the first extension with the URL, if it has the type.
+    fn get_extension_value<T: ExtensionValueType>(&self, url: &str) -> Option<&T> {
+        self.get_extension(url)?.value_as()
+    }
+
+    /// Add the extension, after the others.
+    fn add_extension(&mut self, extension: types::Extension) {
+        self.extensions_mut().push(extension);
+    }
+
+    /// Replace each extension with the URL by one simple extension with the
+    /// value.
+    fn set_extension_value<T: ExtensionValueType>(&mut self, url: &str, value: T) {
+        self.remove_extensions(url);
+        self.add_extension(types::Extension::from_value(url, value));
+    }
+
+    /// Remove each extension with the URL, and return them, in order.
+    fn remove_extensions(&mut self, url: &str) -> Vec<types::Extension> {
+        let (removed, kept) = std::mem::take(self.extensions_mut())
+            .into_iter()
+            .partition(|extension| extension.url == url);
+        *self.extensions_mut() = kept;
+        removed
+    }
+}
+
+impl types::Extension {
+    /// Create a simple extension, with the URL and the value.
+    pub fn from_value<T: ExtensionValueType>(url: impl Into<String>, value: T) -> Self {
+        Self {
+            url: url.into(),
+            value: Some(value.into_extension_value()),
+            ..Self::default()
+        }
+    }
+
+    /// The value, if it has the type.
+    pub fn value_as<T: ExtensionValueType>(&self) -> Option<&T> {
+        self.value.as_ref().and_then(T::from_extension_value)
+    }
+}
+"#;
+
+/// Given one extension definition URL, return the name of its accessor.
+///
+/// Example:
+///
+/// ```no_run
+/// extension_accessor_name("http://hl7.org/fhir/StructureDefinition/structuredefinition-fmm") => "structuredefinition_fmm"
+/// extension_accessor_name("http://hl7.org/fhir/StructureDefinition/patient-birthPlace") => "patient_birth_place"
+/// ```
+///
+#[allow(dead_code)]
+pub fn extension_accessor_name(url: &str) -> SourceCodeString {
+    let segment = url.rsplit('/').next().unwrap_or(url);
+    json_name_into_rust_field_name(&segment.to_case(Case::Camel))
+}
+
+/// Rust type name and the expressions of its extensions => Rust
+/// `HasExtensions` impl block of source code.
+fn has_extensions_impl_block(
+    name: &str,
+    extensions: &str,
+    extensions_mut: &str,
+) -> SourceCodeString {
+    formatdoc!(
+        r#"
+
+        impl types::HasExtensions for {name} {{
+            fn extensions(&self) -> &[types::Extension] {{
+                {extensions}
+            }}
+
+            fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {{
+                {extensions_mut}
+            }}
+        }}
+        "#
+    )
+}
+
+/// Does the element at the parent path have a repeated `extension` child of
+/// type `Extension`?
+fn elements_have_extensions(elements: &[Element], parent_path: &str) -> bool {
+    child_elements(elements, parent_path).any(|element| {
+        let is_extension = matches!(
+            element.r#type.as_deref(),
+            Some([element_type]) if element_type.code == "Extension"
+        );
+        is_extension
+            && element_into_json_name(element) == "extension"
+            && Cardinality::from_element(element) == Cardinality::Repeated
+    })
+}
+
+/// Rust struct name and FHIR elements => Rust `HasExtensions` impl block of
+/// source code, when the element at the parent path has a repeated
+/// `extension` child, else nothing.
+///
+/// Example:
+///
+/// ```no_run
+/// let elements = … // e.g. paths "Foo", "Foo.extension" 0..* Extension.
+/// elements_into_rust_has_extensions_block("Foo", &elements, "Foo")
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// impl types::HasExtensions for Foo {
+///     fn extensions(&self) -> &[types::Extension] {
+///         &self.extension
+///     }
+///     …
+/// }
+/// ```
+///
+/// The `modifierExtension` field is not part of the `HasExtensions` impl,
+/// because a modifier extension changes the meaning of its element.
+///
+#[allow(dead_code)]
+pub fn elements_into_rust_has_extensions_block(
+    name: &str,
+    elements: &[Element],
+    parent_path: &str,
+) -> SourceCodeString {
+    if !elements_have_extensions(elements, parent_path) {
+        return String::new();
+    }
+    has_extensions_impl_block(name, "&self.extension", "&mut self.extension")
+}
+
+/// FHIR constraint profile => Rust `HasExtensions` impl block of source code,
+/// with the extensions of the base value, when the base type has them, else
+/// nothing.
+#[allow(dead_code)]
+pub fn resource_into_rust_has_extensions_profile_block(resource: &Resource) -> SourceCodeString {
+    let elements = resource_elements(resource);
+    match elements.first() {
+        Some(root) if elements_have_extensions(elements, &root.path) => has_extensions_impl_block(
+            &resource.id.to_case(Case::Pascal),
+            "types::HasExtensions::extensions(&self.0)",
+            "types::HasExtensions::extensions_mut(&mut self.0)",
+        ),
+        _ => String::new(),
+    }
+}
+
+/// The choice element `Extension.value[x]` and the Rust path of its enum,
+/// such as `types::extension::ExtensionValue`, from the `Extension` resource.
+fn extension_value_element<'a>(
+    resources: &'a [Resource],
+    config: &CodegenConfig,
+) -> Option<(&'a Element, SourceCodeString)> {
+    let resource = resources.iter().find(|x| x.id == "Extension")?;
+    let element = resource_elements(resource)
+        .iter()
+        .find(|element| element.path == "Extension.value[x]")?;
+    Some((
+        element,
+        format!(
+            "types::{}::{}",
+            config.module_name(resource),
+            path_into_rust_struct_name("Extension.value"),
+        ),
+    ))
+}
+
+/// The choice element `Extension.value[x]` => Rust `ExtensionValueType` impl
+/// blocks of source code, one per type, such as `types::Coding` for
+/// `valueCoding`.
+fn extension_value_type_blocks(
+    element: &Element,
+    enum_path: &str,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| {
+            let code = element_type.code.as_str();
+            let boxed = context.is_boxed(&choice_json_key("Extension.value", code));
+            formatdoc!(
+                r#"
+
+                impl ExtensionValueType for {rust_type} {{
+                    fn from_extension_value(value: &{enum_path}) -> Option<&Self> {{
+                        match value {{
+                            {enum_path}::{variant}(value) => Some({value}),
+                            _ => None,
+                        }}
+                    }}
+
+                    fn into_extension_value(self) -> {enum_path} {{
+                        {enum_path}::{variant}({into})
+                    }}
+                }}
+                "#,
+                rust_type = type_code_into_rust_type(code),
+                variant = code.to_case(Case::Pascal),
+                value = if boxed { "value.as_ref()" } else { "value" },
+                into = if boxed { "Box::new(self)" } else { "self" },
+            )
+        })
+        .collect()
+}
+
+/// The core extension definitions of the context, and the value types of
+/// `Extension.value[x]` => Rust source code of the URL consts, and of the
+/// `CoreExtensions` trait, with a typed accessor per definition.
+///
+/// A simple extension with one value type has a getter and a setter of that
+/// type, such as `Option<&types::Code>`. A simple extension with more value
+/// types has a getter and a setter of the `Extension.value[x]` enum. A
+/// complex extension has a getter of the extension, which has the nested
+/// extensions. When two definitions have the same accessor name, the first
+/// by URL wins.
+fn core_extensions_block(
+    value_codes: &[&str],
+    enum_path: &str,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let mut names: BTreeSet<String> = BTreeSet::new();
+    let mut consts = String::new();
+    let mut methods = String::new();
+    for definition in context
+        .extensions
+        .iter()
+        .filter(|x| x.url.starts_with(CORE_EXTENSION_URL_PREFIX))
+    {
+        let name = extension_accessor_name(&definition.url);
+        if !names.insert(name.clone()) {
+            continue;
+        }
+        let url = definition.url.as_str();
+        let url_const = format!(
+            "{}_URL",
+            name.trim_start_matches("r#").to_case(Case::UpperSnake)
+        );
+        let setter = format!("set_{}", name.trim_start_matches("r#"));
+        consts.push_str(&format!(
+            "\n/// The URL of the `{name}` extension.\npub const {url_const}: &str = \"{url}\";\n",
+            name = name.trim_start_matches("r#"),
+        ));
+        let value_type = match definition.value_types.as_slice() {
+            [] => {
+                methods.push_str(&format!(
+                    "\n    /// The complex extension `{url}`, if any.\n    fn {name}(&self) -> Option<&types::Extension> {{\n        self.get_extension({url_const})\n    }}\n"
+                ));
+                continue;
+            }
+            [code] if value_codes.contains(&code.as_str()) => type_code_into_rust_type(code),
+            _ => String::from(enum_path),
+        };
+        methods.push_str(&format!(
+            "\n    /// The value of the extension `{url}`, if any.\n    fn {name}(&self) -> Option<&{value_type}> {{\n        self.get_extension_value({url_const})\n    }}\n\n    /// Set the value of the extension `{url}`.\n    fn {setter}(&mut self, value: {value_type}) {{\n        self.set_extension_value({url_const}, value);\n    }}\n"
+        ));
+    }
+    format!(
+        "{consts}\n/// The typed accessors of the core extensions, for each value with\n/// extensions.\npub trait CoreExtensions: HasExtensions {{{methods}}}\n\nimpl<T: HasExtensions + ?Sized> CoreExtensions for T {{}}\n"
+    )
+}
+
+/// FHIR resources => Rust source code of the extensions module, with the
+/// `ExtensionValueType` trait, with an impl per type of `Extension.value[x]`,
+/// the `HasExtensions` trait, and the `CoreExtensions` trait, with a typed
+/// accessor per core extension definition of the context.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. resource id Extension, with value[x] code or integer.
+/// resources_into_rust_extensions_block(&resources, &context)
+/// ```
+///
+/// Output is approximately like this, with the `structuredefinition-fmm`
+/// definition:
+///
+/// ```no_run
+/// impl ExtensionValueType for types::Integer { … }
+///
+/// pub trait HasExtensions { … }
+///
+/// pub const STRUCTUREDEFINITION_FMM_URL: &str = "http://hl7.org/fhir/StructureDefinition/structuredefinition-fmm";
+///
+/// pub trait CoreExtensions: HasExtensions {
+///     fn structuredefinition_fmm(&self) -> Option<&types::Integer> { … }
+///     fn set_structuredefinition_fmm(&mut self, value: types::Integer) { … }
+/// }
+/// ```
+///
+/// Without the `Extension` resource, there is nothing to generate.
+///
+#[allow(dead_code)]
+pub fn resources_into_rust_extensions_block(
+    resources: &[Resource],
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let Some((element, enum_path)) = extension_value_element(resources, &context.config) else {
+        return String::new();
+    };
+    let value_codes: Vec<&str> = element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| element_type.code.as_str())
+        .collect();
+    formatdoc!(
+        r#"
+        //! Extensions
+        //!
+        //! Find the extensions of a value by URL, and view the value of an
+        //! extension as the Rust type of its `value[x]` type, such as
+        //! `types::Coding` for `valueCoding`.
+        //!
+        //! A complex extension has no value, and has nested extensions, that
+        //! `HasExtensions` finds the same way.
+
+        /// Use the FHIR R5 datatypes for the extension values.
+        {use_types_line}
+
+        /// The Rust type of a `value[x]` type of an extension, such as
+        /// `types::Coding` for `valueCoding`.
+        pub trait ExtensionValueType: Sized {{
+            /// The value, if the extension value has this type.
+            fn from_extension_value(value: &{enum_path}) -> Option<&Self>;
+
+            /// The extension value of this value.
+            fn into_extension_value(self) -> {enum_path};
+        }}
+
+        impl ExtensionValueType for {enum_path} {{
+            fn from_extension_value(value: &{enum_path}) -> Option<&Self> {{
+                Some(value)
+            }}
+
+            fn into_extension_value(self) -> {enum_path} {{
+                self
+            }}
+        }}
+        {value_type_blocks}{HAS_EXTENSIONS_BLOCK}{core_extensions_block}"#,
+        use_types_line = context.config.use_types_line(),
+        value_type_blocks = extension_value_type_blocks(element, &enum_path, context),
+        core_extensions_block = core_extensions_block(&value_codes, &enum_path, context),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+    use crate::r5::registry::ExtensionDefinition;
+
+    fn element(path: &str, max: &str, codes: &[&str]) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            min: Some(0),
+            max: Some(max.into()),
+            r#type: Some(
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    fn extension_resource() -> Resource {
+        Resource {
+            id: "Extension".into(),
+            kind: Some(String::from("complex-type")),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Extension", "*", &[]),
+                    element("Extension.extension", "*", &["Extension"]),
+                    element("Extension.url", "1", &["uri"]),
+                    element("Extension.value[x]", "1", &["code", "integer", "Coding"]),
+                ],
+                ..Snapshot::default()
+            }),
+            ..Resource::default()
+        }
+    }
+
+    fn definition(url: &str, value_types: &[&str]) -> ExtensionDefinition {
+        ExtensionDefinition {
+            url: url.into(),
+            value_types: value_types.iter().map(|x| String::from(*x)).collect(),
+            ..ExtensionDefinition::default()
+        }
+    }
+
+    #[test]
+    fn test_extension_accessor_name() {
+        assert_eq!(
+            extension_accessor_name(
+                "http://hl7.org/fhir/StructureDefinition/structuredefinition-fmm"
+            ),
+            "structuredefinition_fmm"
+        );
+        assert_eq!(
+            extension_accessor_name("http://hl7.org/fhir/StructureDefinition/patient-birthPlace"),
+            "patient_birth_place"
+        );
+    }
+
+    #[test]
+    fn test_elements_into_rust_has_extensions_block() {
+        let elements = vec![
+            element("Foo", "*", &[]),
+            element("Foo.extension", "*", &["Extension"]),
+        ];
+        let actual = elements_into_rust_has_extensions_block("Foo", &elements, "Foo");
+        let expect = concat!(
+            "\n",
+            "impl types::HasExtensions for Foo {\n",
+            "    fn extensions(&self) -> &[types::Extension] {\n",
+            "        &self.extension\n",
+            "    }\n",
+            "\n",
+            "    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {\n",
+            "        &mut self.extension\n",
+            "    }\n",
+            "}\n",
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_elements_into_rust_has_extensions_block_without_extensions() {
+        let elements = vec![
+            element("Foo", "*", &[]),
+            element("Foo.bravo", "*", &["Extension"]),
+        ];
+        assert_eq!(
+            elements_into_rust_has_extensions_block("Foo", &elements, "Foo"),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_resources_into_rust_extensions_block() {
+        let mut context = CodegenContext::new();
+        context.extensions = [
+            definition(
+                "http://hl7.org/fhir/StructureDefinition/structuredefinition-fmm",
+                &["integer"],
+            ),
+            definition(
+                "http://hl7.org/fhir/StructureDefinition/structuredefinition-standards-status",
+                &["code"],
+            ),
+            definition(
+                "http://hl7.org/fhir/StructureDefinition/patient-nationality",
+                &[],
+            ),
+            definition("http://example.org/StructureDefinition/alfa", &["code"]),
+        ]
+        .into_iter()
+        .collect();
+        let actual = resources_into_rust_extensions_block(&[extension_resource()], &context);
+        assert!(
+            actual.contains(concat!(
+                "impl ExtensionValueType for types::Coding {\n",
+                "    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {\n",
+                "        match value {\n",
+                "            types::extension::ExtensionValue::Coding(value) => Some(value),\n",
+            )),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(concat!(
+                "pub const STRUCTUREDEFINITION_FMM_URL: &str =",
+                " \"http://hl7.org/fhir/StructureDefinition/structuredefinition-fmm\";\n",
+            )),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(concat!(
+                "    fn structuredefinition_fmm(&self) -> Option<&types::Integer> {\n",
+                "        self.get_extension_value(STRUCTUREDEFINITION_FMM_URL)\n",
+                "    }\n",
+            )),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(
+                "    fn set_structuredefinition_standards_status(&mut self, value: types::Code) {\n"
+            ),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("    fn patient_nationality(&self) -> Option<&types::Extension> {\n"),
+            "{}",
+            actual
+        );
+        assert!(!actual.contains("alfa"), "{}", actual);
+    }
+
+    #[test]
+    fn test_resources_into_rust_extensions_block_without_extension() {
+        assert_eq!(
+            resources_into_rust_extensions_block(&[], &CodegenContext::new()),
+            ""
+        );
+    }
+}
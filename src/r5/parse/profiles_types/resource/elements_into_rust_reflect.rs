@@ -0,0 +1,744 @@
+//! Elements into Rust reflect
+//!
+//! Generic tools, such as a FHIRPath evaluator, a validator, or a form
+//! builder, need to know the child elements of a value without knowing its
+//! Rust type:
+//!
+//! ```no_run
+//! let patient: &dyn types::Reflect = &patient;
+//! for info in patient.element_infos() {
+//!     println!("{} {}..{:?} {:?}", info.name, info.min, info.max, info.types);
+//! }
+//! let birth_date = patient.child("birthDate"); // e.g. Some(ValueRef::String("1970-01-01"))
+//! ```
+//!
+//! Each generated type has an `ELEMENTS` table with an `ElementInfo` per
+//! child element, from its StructureDefinition, and implements the
+//! `Reflect` trait, which finds the value of a child element by its FHIR
+//! name, as a borrowed `ValueRef`.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::{
+    child_elements, resource_elements,
+};
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+
+/// The module name of the generated reflection.
+pub const REFLECT_MODULE: &str = "reflect";
+
+/// The Rust source code of the generated reflection module, that each
+/// generated `Reflect` impl uses.
+pub const REFLECT_BLOCK: &str = r#"//! Reflect
+//!
+//! The metadata of each child element of a generated type, from its
+//! StructureDefinition, such as its cardinality and its types, and the value
+//! of each child element by its FHIR name, such as `birthDate`.
+
+/// The binding of a coded element to a ValueSet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BindingInfo {
+    /// The binding strength, such as `required` or `extensible`.
+    pub strength: &'static str,
+
+    /// The canonical URL of the ValueSet, if any.
+    pub value_set: Option<&'static str>,
+}
+
+/// The metadata of one child element of a generated type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ElementInfo {
+    /// The FHIR name, such as `birthDate` or `value[x]`.
+    pub name: &'static str,
+
+    /// The JSON key, such as `birthDate`. A choice element has the prefix of
+    /// the key of each type, such as `value` for `valueQuantity`.
+    pub json_key: &'static str,
+
+    /// The Rust field, such as `birth_date`.
+    pub rust_field: &'static str,
+
+    /// The minimum cardinality.
+    pub min: u32,
+
+    /// The maximum cardinality, or none for `*`.
+    pub max: Option<u32>,
+
+    /// The FHIR type codes, such as `Quantity` and `string`.
+    pub types: &'static [&'static str],
+
+    /// The binding to a ValueSet, if any.
+    pub binding: Option<BindingInfo>,
+
+    /// Is the element in the summary of the resource?
+    pub is_summary: bool,
+
+    /// Can the element change the meaning of the resource, such as
+    /// `Patient.active`?
+    pub is_modifier: bool,
+}
+
+impl ElementInfo {
+    /// Is the name the FHIR name or the JSON key of the element?
+    pub fn matches(&self, name: &str) -> bool {
+        name == self.name || name == self.json_key
+    }
+
+    /// Can the element repeat, such as `Patient.name`?
+    pub fn is_list(&self) -> bool {
+        !matches!(self.max, Some(0 | 1))
+    }
+}
+
+/// A borrowed view of one value, by its FHIRPath kind.
+#[derive(Debug, Clone)]
+pub enum ValueRef<'a> {
+    /// The value of a `boolean`.
+    Boolean(bool),
+
+    /// The value of an `integer`, `integer64`, `positiveInt` or
+    /// `unsignedInt`.
+    Integer(i64),
+
+    /// The value of a `decimal`, with its exact digits.
+    Decimal(&'a ::serde_json::Number),
+
+    /// The value of any other primitive, such as a `string`, `code` or
+    /// `dateTime`.
+    String(&'a str),
+
+    /// A JSON value without a generated type.
+    Json(&'a ::serde_json::Value),
+
+    /// A value of a generated type, such as a `Quantity` or a `Patient`.
+    Element(&'a dyn Reflect),
+
+    /// The values of a repeating element, such as `Patient.name`.
+    List(Vec<ValueRef<'a>>),
+}
+
+impl<'a> ValueRef<'a> {
+    /// The value of a generated type, if it is one.
+    pub fn as_element(&self) -> Option<&'a dyn Reflect> {
+        match self {
+            Self::Element(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value of a child element by its FHIR name, when the value is of
+    /// a generated type.
+    pub fn child(&self, name: &str) -> Option<ValueRef<'a>> {
+        self.as_element()?.child(name)
+    }
+}
+
+/// View a value as a `ValueRef`.
+pub trait AsValueRef {
+    /// The view of the value, or none when the value is absent, such as an
+    /// empty option or an empty list.
+    fn as_value_ref(&self) -> Option<ValueRef<'_>>;
+}
+
+/// Reflect on a value of a generated type.
+pub trait Reflect: std::fmt::Debug {
+    /// The FHIR type name, such as `Patient`, or the element path of an
+    /// inline type, such as `Patient.contact`.
+    fn type_name(&self) -> &'static str;
+
+    /// The metadata of each child element, in the order of the
+    /// StructureDefinition.
+    fn element_infos(&self) -> &'static [ElementInfo];
+
+    /// The value of a child element by its FHIR name or JSON key, such as
+    /// `value[x]` or `value`, or none when it is absent or unknown.
+    fn child(&self, name: &str) -> Option<ValueRef<'_>>;
+
+    /// The metadata of a child element by its FHIR name or JSON key.
+    fn element_info(&self, name: &str) -> Option<&'static ElementInfo> {
+        self.element_infos().iter().find(|info| info.matches(name))
+    }
+}
+
+impl<T: AsValueRef> AsValueRef for Option<T> {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        self.as_ref().and_then(AsValueRef::as_value_ref)
+    }
+}
+
+impl<T: AsValueRef> AsValueRef for Vec<T> {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        let values: Vec<ValueRef<'_>> = self.iter().filter_map(AsValueRef::as_value_ref).collect();
+        (!values.is_empty()).then_some(ValueRef::List(values))
+    }
+}
+
+impl<T: AsValueRef> AsValueRef for Box<T> {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        (**self).as_value_ref()
+    }
+}
+
+impl AsValueRef for bool {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Boolean(*self))
+    }
+}
+
+/// Implement `AsValueRef` for the Rust type of an integer value.
+macro_rules! impl_as_value_ref_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl AsValueRef for $t {
+                fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+                    Some(ValueRef::Integer(i64::from(*self)))
+                }
+            }
+        )*
+    };
+}
+
+impl_as_value_ref_for_integer!(i32, i64, u32);
+
+impl AsValueRef for String {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::String(self))
+    }
+}
+
+impl AsValueRef for ::serde_json::Number {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Decimal(self))
+    }
+}
+
+impl AsValueRef for ::serde_json::Value {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Json(self))
+    }
+}
+"#;
+
+/// FHIR element => Rust `ElementInfo` expression of source code, with the
+/// indentation of an item of the `ELEMENTS` table.
+///
+/// Example:
+///
+/// ```no_run
+/// let element = … // e.g. path "Foo.value[x]" 0..1, types Quantity, string.
+/// element_into_rust_element_info(&element)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// types::ElementInfo {
+///     name: "value[x]",
+///     json_key: "value",
+///     rust_field: "value",
+///     min: 0,
+///     max: Some(1),
+///     types: &["Quantity", "string"],
+///     binding: None,
+///     is_summary: false,
+///     is_modifier: false,
+/// },
+/// ```
+///
+#[allow(dead_code)]
+pub fn element_into_rust_element_info(element: &Element) -> SourceCodeString {
+    let json_name = element_into_json_name(element);
+    let max = match element.max.as_deref().map(str::parse::<u32>) {
+        Some(Ok(max)) => format!("Some({max})"),
+        _ => String::from("None"),
+    };
+    let types = element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| format!("\"{}\"", element_type.code.escape_default()))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let binding = match &element.binding {
+        Some(binding) => format!(
+            "Some(types::BindingInfo {{\n                strength: \"{}\",\n                value_set: {},\n            }})",
+            binding.strength.escape_default(),
+            match &binding.value_set {
+                Some(value_set) => format!("Some(\"{}\")", value_set.escape_default()),
+                None => String::from("None"),
+            },
+        ),
+        None => String::from("None"),
+    };
+    format!(
+        concat!(
+            "        types::ElementInfo {{\n",
+            "            name: \"{name}\",\n",
+            "            json_key: \"{json_name}\",\n",
+            "            rust_field: \"{rust_field}\",\n",
+            "            min: {min},\n",
+            "            max: {max},\n",
+            "            types: &[{types}],\n",
+            "            binding: {binding},\n",
+            "            is_summary: {is_summary},\n",
+            "            is_modifier: {is_modifier},\n",
+            "        }},\n",
+        ),
+        name = element
+            .path
+            .rsplit_once('.')
+            .map_or(element.path.as_str(), |(_, name)| name),
+        json_name = json_name,
+        rust_field = json_name_into_rust_field_name(json_name),
+        min = element.min.unwrap_or(0),
+        max = max,
+        types = types,
+        binding = binding,
+        is_summary = element.is_summary.unwrap_or(false),
+        is_modifier = element.is_modifier.unwrap_or(false),
+    )
+}
+
+/// The direct children of the element at the parent path, that have a
+/// field, without the slices of a constraint profile.
+fn reflect_child_elements<'a>(
+    elements: &'a [Element],
+    parent_path: &'a str,
+) -> impl Iterator<Item = &'a Element> {
+    child_elements(elements, parent_path).filter(|element| {
+        element.slice_name.is_none()
+            && Cardinality::from_element(element) != Cardinality::Prohibited
+    })
+}
+
+/// Rust type name, FHIR elements, and the statements of `child` => Rust
+/// block of source code, with the `ELEMENTS` table, and the `Reflect` and
+/// `AsValueRef` impls.
+fn reflect_impl_block(
+    name: &str,
+    type_name: &str,
+    element_infos: &str,
+    child_statements: &str,
+) -> SourceCodeString {
+    let elements = if element_infos.is_empty() {
+        String::from("&[]")
+    } else {
+        format!("&[\n{element_infos}    ]")
+    };
+    let child = if child_statements.is_empty() {
+        String::from(
+            "fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {\n        None\n    }",
+        )
+    } else {
+        format!(
+            "fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {{\n{child_statements}    }}"
+        )
+    };
+    formatdoc!(
+        r#"
+
+        impl {name} {{
+            /// The metadata of each child element of `{type_name}`.
+            pub const ELEMENTS: &'static [types::ElementInfo] = {elements};
+        }}
+
+        impl types::Reflect for {name} {{
+            fn type_name(&self) -> &'static str {{
+                "{type_name}"
+            }}
+
+            fn element_infos(&self) -> &'static [types::ElementInfo] {{
+                Self::ELEMENTS
+            }}
+
+            {child}
+        }}
+
+        impl types::AsValueRef for {name} {{
+            fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {{
+                Some(types::ValueRef::Element(self))
+            }}
+        }}
+        "#
+    )
+}
+
+/// Rust struct name and FHIR elements => Rust reflection block of source
+/// code, for the direct children of the element at the parent path.
+///
+/// Example:
+///
+/// ```no_run
+/// let elements = … // e.g. paths "Foo", "Foo.bravo" 0..*, "Foo.value[x]" 0..1.
+/// elements_into_rust_reflect_block("Foo", &elements, "Foo")
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// impl Foo {
+///     pub const ELEMENTS: &'static [types::ElementInfo] = &[ … ];
+/// }
+///
+/// impl types::Reflect for Foo {
+///     …
+///     fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+///         match name {
+///             "bravo" => types::AsValueRef::as_value_ref(&self.bravo),
+///             "value[x]" | "value" => types::AsValueRef::as_value_ref(&self.value),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+///
+/// The type name of the struct of a type, such as `Foo`, is its FHIR type
+/// name; the type name of an inline struct, such as `FooBravo`, is its
+/// element path, such as `Foo.bravo`.
+///
+#[allow(dead_code)]
+pub fn elements_into_rust_reflect_block(
+    name: &str,
+    elements: &[Element],
+    parent_path: &str,
+) -> SourceCodeString {
+    let mut element_infos = String::new();
+    let mut arms = String::new();
+    for element in reflect_child_elements(elements, parent_path) {
+        element_infos.push_str(&element_into_rust_element_info(element));
+        let json_name = element_into_json_name(element);
+        let fhir_name = element
+            .path
+            .rsplit_once('.')
+            .map_or(element.path.as_str(), |(_, name)| name);
+        let pattern = if fhir_name == json_name {
+            format!("\"{json_name}\"")
+        } else {
+            format!("\"{fhir_name}\" | \"{json_name}\"")
+        };
+        arms.push_str(&format!(
+            "            {pattern} => types::AsValueRef::as_value_ref(&self.{}),\n",
+            json_name_into_rust_field_name(json_name)
+        ));
+    }
+    let child_statements = if arms.is_empty() {
+        arms
+    } else {
+        format!("        match name {{\n{arms}            _ => None,\n        }}\n")
+    };
+    let type_name = if parent_path.is_empty() {
+        name
+    } else {
+        parent_path
+    };
+    reflect_impl_block(name, type_name, &element_infos, &child_statements)
+}
+
+/// FHIR primitive type resource => Rust reflection block of source code,
+/// without child elements, and with an `AsValueRef` impl that views the
+/// value, such as `ValueRef::Boolean(true)`.
+///
+/// The id and extensions of a primitive are in the sibling field of its
+/// parent struct, such as `Patient._birthDate`.
+///
+#[allow(dead_code)]
+pub fn resource_into_rust_reflect_primitive_block(resource: &Resource) -> SourceCodeString {
+    formatdoc!(
+        r#"
+
+        impl {name} {{
+            /// The metadata of each child element of `{id}`.
+            pub const ELEMENTS: &'static [types::ElementInfo] = &[];
+        }}
+
+        impl types::Reflect for {name} {{
+            fn type_name(&self) -> &'static str {{
+                "{id}"
+            }}
+
+            fn element_infos(&self) -> &'static [types::ElementInfo] {{
+                Self::ELEMENTS
+            }}
+
+            fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {{
+                None
+            }}
+        }}
+
+        impl types::AsValueRef for {name} {{
+            fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {{
+                types::AsValueRef::as_value_ref(&self.value)
+            }}
+        }}
+        "#,
+        name = resource.id.to_case(Case::Pascal),
+        id = resource.id,
+    )
+}
+
+/// FHIR constraint profile => Rust reflection block of source code, with
+/// the `ELEMENTS` table of the profile, such as the tighter cardinalities
+/// of `SimpleQuantity`, and the child values of the base value.
+///
+/// The type name is the base type, such as `Quantity`, as for FHIRPath.
+///
+#[allow(dead_code)]
+pub fn resource_into_rust_reflect_profile_block(resource: &Resource) -> SourceCodeString {
+    let elements = resource_elements(resource);
+    let element_infos = elements
+        .first()
+        .map(|root| {
+            reflect_child_elements(elements, &root.path)
+                .map(element_into_rust_element_info)
+                .collect::<String>()
+        })
+        .unwrap_or_default();
+    reflect_impl_block(
+        &resource.id.to_case(Case::Pascal),
+        resource.r#type.as_deref().unwrap_or_default(),
+        &element_infos,
+        "        types::Reflect::child(&self.0, name)\n",
+    )
+}
+
+/// FHIR choice element => Rust `AsValueRef` impl block of source code, that
+/// views the value of the variant, such as the `Quantity` of
+/// `Observation.valueQuantity`.
+#[allow(dead_code)]
+pub fn element_into_rust_reflect_choice_block(element: &Element) -> SourceCodeString {
+    let path = element.path.trim_end_matches("[x]");
+    let arms = element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| {
+            format!(
+                "            Self::{}(value) => types::AsValueRef::as_value_ref(value),\n",
+                element_type.code.to_case(Case::Pascal),
+            )
+        })
+        .collect::<String>();
+    let body = if arms.is_empty() {
+        String::from("        None\n")
+    } else {
+        format!("        match self {{\n{arms}        }}\n")
+    };
+    formatdoc!(
+        r#"
+
+        impl types::AsValueRef for {name} {{
+            fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {{
+        {body}    }}
+        }}
+        "#,
+        name = path_into_rust_struct_name(path),
+    )
+}
+
+/// Code enum => Rust `AsValueRef` impl block of source code, that views the
+/// exact code of the variant, such as `ValueRef::String("home")`.
+#[allow(dead_code)]
+pub fn code_enum_into_rust_reflect_block(code_enum: &CodeEnum) -> SourceCodeString {
+    let arms = code_enum_into_rust_variants(code_enum)
+        .iter()
+        .map(|(variant, code)| {
+            format!(
+                "            Self::{variant} => \"{}\",\n",
+                code.escape_default()
+            )
+        })
+        .collect::<String>();
+    formatdoc!(
+        r#"
+
+        impl types::AsValueRef for {name} {{
+            fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {{
+                Some(types::ValueRef::String(match self {{
+        {arms}        }}))
+            }}
+        }}
+        "#,
+        name = code_enum.name,
+    )
+}
+
+/// Resource names => Rust `Reflect` and `AsValueRef` impl blocks of source
+/// code for the `Resource` enum, that reflect on the resource of each
+/// variant.
+#[allow(dead_code)]
+pub fn resources_into_rust_enum_reflect_block(
+    names: &[String],
+    config: &CodegenConfig,
+) -> SourceCodeString {
+    let arms = |call: &str| {
+        names
+            .iter()
+            .map(|name| {
+                let cfg = match features_cfg_attribute(config, &[name]) {
+                    cfg if cfg.is_empty() => cfg,
+                    cfg => format!("            {cfg}"),
+                };
+                format!("{cfg}            Self::{name}(value) => {call},\n")
+            })
+            .collect::<String>()
+    };
+    formatdoc!(
+        r#"
+
+        impl types::Reflect for Resource {{
+            fn type_name(&self) -> &'static str {{
+                match self {{
+        {type_name_arms}        }}
+            }}
+
+            fn element_infos(&self) -> &'static [types::ElementInfo] {{
+                match self {{
+        {element_infos_arms}        }}
+            }}
+
+            fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {{
+                match self {{
+        {child_arms}        }}
+            }}
+        }}
+
+        impl types::AsValueRef for Resource {{
+            fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {{
+                match self {{
+        {as_value_ref_arms}        }}
+            }}
+        }}
+        "#,
+        type_name_arms = arms("types::Reflect::type_name(value.as_ref())"),
+        element_infos_arms = arms("types::Reflect::element_infos(value.as_ref())"),
+        child_arms = arms("types::Reflect::child(value.as_ref(), name)"),
+        as_value_ref_arms = arms("types::AsValueRef::as_value_ref(value)"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::{Binding, ElementType};
+
+    fn element(path: &str, min: u32, max: &str, codes: &[&str]) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            min: Some(min),
+            max: Some(max.into()),
+            r#type: Some(
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    #[test]
+    fn test_element_into_rust_element_info() {
+        let mut bravo = element("Foo.bravo", 1, "*", &["code"]);
+        bravo.is_summary = Some(true);
+        bravo.binding = Some(Binding {
+            strength: String::from("required"),
+            value_set: Some(String::from("http://example.com/ValueSet/bravo")),
+            ..Binding::default()
+        });
+        let actual = element_into_rust_element_info(&bravo);
+        let expect = concat!(
+            "        types::ElementInfo {\n",
+            "            name: \"bravo\",\n",
+            "            json_key: \"bravo\",\n",
+            "            rust_field: \"bravo\",\n",
+            "            min: 1,\n",
+            "            max: None,\n",
+            "            types: &[\"code\"],\n",
+            "            binding: Some(types::BindingInfo {\n",
+            "                strength: \"required\",\n",
+            "                value_set: Some(\"http://example.com/ValueSet/bravo\"),\n",
+            "            }),\n",
+            "            is_summary: true,\n",
+            "            is_modifier: false,\n",
+            "        },\n",
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_elements_into_rust_reflect_block() {
+        let elements = vec![
+            element("Foo", 0, "*", &[]),
+            element("Foo.type", 0, "1", &["string"]),
+            element("Foo.value[x]", 0, "1", &["Quantity", "string"]),
+            element("Foo.charlie", 0, "0", &["string"]),
+        ];
+        let actual = elements_into_rust_reflect_block("Foo", &elements, "Foo");
+        assert!(
+            actual.contains(concat!(
+                "    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {\n",
+                "        match name {\n",
+                "            \"type\" => types::AsValueRef::as_value_ref(&self.r#type),\n",
+                "            \"value[x]\" | \"value\" => types::AsValueRef::as_value_ref(&self.value),\n",
+                "            _ => None,\n",
+                "        }\n",
+                "    }\n",
+            )),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("            rust_field: \"r#type\",\n"),
+            "{}",
+            actual
+        );
+        assert!(!actual.contains("charlie"), "{}", actual);
+        assert!(actual.contains("        \"Foo\"\n"), "{}", actual);
+    }
+
+    #[test]
+    fn test_elements_into_rust_reflect_block_with_inline_struct() {
+        let elements = vec![
+            element("Foo", 0, "*", &[]),
+            element("Foo.bravo", 0, "1", &[]),
+        ];
+        let actual = elements_into_rust_reflect_block("FooBravo", &elements, "Foo.bravo");
+        assert!(
+            actual.contains("    pub const ELEMENTS: &'static [types::ElementInfo] = &[];\n"),
+            "{}",
+            actual
+        );
+        assert!(actual.contains("        \"Foo.bravo\"\n"), "{}", actual);
+        assert!(
+            actual.contains("    fn child(&self, _name: &str)"),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_code_enum_into_rust_reflect_block() {
+        let code_enum = CodeEnum {
+            name: String::from("AddressUse"),
+            concepts: ["home", "work"]
+                .iter()
+                .map(|code| crate::r5::registry::CodeConcept {
+                    code: String::from(*code),
+                    ..Default::default()
+                })
+                .collect(),
+            ..CodeEnum::default()
+        };
+        let actual = code_enum_into_rust_reflect_block(&code_enum);
+        assert!(
+            actual.contains("            Self::Work => \"work\",\n"),
+            "{}",
+            actual
+        );
+    }
+}
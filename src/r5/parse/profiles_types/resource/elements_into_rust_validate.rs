@@ -0,0 +1,766 @@
+//! Elements into Rust validate
+//!
+//! A StructureDefinition encodes rules that a Rust type can not enforce by
+//! itself, such as the min of a repeated element, a `maxLength`, or a
+//! `minValueInteger`. Each generated type implements the `Validate` trait,
+//! which checks those rules on a value, and lists each issue with its path:
+//!
+//! ```no_run
+//! use types::Validate;
+//! let issues = patient.validate();
+//! // e.g. [ValidationIssue { path: "Patient.link[0].other", … }]
+//! ```
+//!
+//! The rules are in the generated source code, so validation does not load
+//! the FHIR specification files at runtime.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::{
+    child_elements, resource_elements,
+};
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+
+/// The module name of the generated validation.
+pub const VALIDATE_MODULE: &str = "validate";
+
+/// The Rust source code of the generated validation module.
+pub const VALIDATE_BLOCK: &str = r#"//! Validate
+//!
+//! Check the rules that the StructureDefinitions encode statically: the
+//! cardinality of repeated elements, `maxLength`, `minValue` and `maxValue`,
+//! fixed and pattern values, and required bindings.
+//!
+//! An issue path is an element path with the index of each repeated value,
+//! such as `Patient.name[0].given[1]`. A choice element has the JSON key of
+//! its type, such as `Observation.valueQuantity`.
+
+use ::serde::Serialize;
+use ::serde_json::Value;
+
+/// One rule that a value fails, such as `Patient.name[0].given[1]` with the
+/// message `has 0 values, but allows 1..*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.path, self.message)
+    }
+}
+
+/// Check the rules of a generated type on a value.
+pub trait Validate {
+    /// The element path of the type, such as `Patient`, that starts the path
+    /// of each issue of `validate()`.
+    const PATH: &'static str = "";
+
+    /// Check each rule, and push an issue for each rule that fails, with
+    /// the path of the value, such as `Patient.contact[0]`.
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>);
+
+    /// Check each rule, and list each rule that fails.
+    fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = vec![];
+        self.validate_into(Self::PATH, &mut issues);
+        issues
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    const PATH: &'static str = T::PATH;
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>) {
+        if let Some(value) = self {
+            value.validate_into(path, issues);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    const PATH: &'static str = T::PATH;
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>) {
+        for (index, value) in self.iter().enumerate() {
+            value.validate_into(&format!("{path}[{index}]"), issues);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Box<T> {
+    const PATH: &'static str = T::PATH;
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>) {
+        (**self).validate_into(path, issues);
+    }
+}
+
+/// Implement `Validate` for a type without rules, such as the Rust type of
+/// a FHIRPath system type.
+macro_rules! impl_validate_without_rules {
+    ($($t:ty),*) => {
+        $(
+            impl Validate for $t {
+                fn validate_into(&self, _path: &str, _issues: &mut Vec<ValidationIssue>) {}
+            }
+        )*
+    };
+}
+
+impl_validate_without_rules!(bool, i32, i64, u32, String, ::serde_json::Number, Value);
+
+/// Check that a repeated element has between min and max values, or at
+/// least min when max is none.
+pub fn check_count(
+    path: &str,
+    count: usize,
+    min: usize,
+    max: Option<usize>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if count < min || max.is_some_and(|max| count > max) {
+        let range = match max {
+            Some(max) => format!("{min}..{max}"),
+            None => format!("{min}..*"),
+        };
+        issues.push(ValidationIssue {
+            path: String::from(path),
+            message: format!("has {count} values, but allows {range}"),
+        });
+    }
+}
+
+/// Check that a string value has at most max characters.
+pub fn check_max_length(path: &str, value: &str, max: usize, issues: &mut Vec<ValidationIssue>) {
+    let count = value.chars().count();
+    if count > max {
+        issues.push(ValidationIssue {
+            path: String::from(path),
+            message: format!("has {count} characters, but allows at most {max}"),
+        });
+    }
+}
+
+/// Check that an integer value is between min and max, where each is
+/// inclusive, and none is unbounded.
+pub fn check_range(
+    path: &str,
+    value: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max) {
+        let bound = |x: Option<i64>| x.map(|x| x.to_string()).unwrap_or_default();
+        issues.push(ValidationIssue {
+            path: String::from(path),
+            message: format!("is {value}, but allows {}..{}", bound(min), bound(max)),
+        });
+    }
+}
+
+/// Check that the JSON of a value equals the fixed JSON value.
+pub fn check_fixed<T: Serialize>(
+    path: &str,
+    value: &T,
+    fixed: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let expect: Value = ::serde_json::from_str(fixed).unwrap_or_default();
+    if ::serde_json::to_value(value).ok().as_ref() != Some(&expect) {
+        issues.push(ValidationIssue {
+            path: String::from(path),
+            message: format!("is not the fixed value {fixed}"),
+        });
+    }
+}
+
+/// Check that the JSON of a value has every part of the pattern JSON value.
+pub fn check_pattern<T: Serialize>(
+    path: &str,
+    value: &T,
+    pattern: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let expect: Value = ::serde_json::from_str(pattern).unwrap_or_default();
+    if !::serde_json::to_value(value).is_ok_and(|json| contains(&json, &expect)) {
+        issues.push(ValidationIssue {
+            path: String::from(path),
+            message: format!("does not match the pattern {pattern}"),
+        });
+    }
+}
+
+/// Check that any of the codes of a value, such as the codes of each coding
+/// of a `CodeableConcept`, is a code of the required ValueSet, by the code
+/// enum of the ValueSet. A value without codes passes.
+pub fn check_codes<E: std::str::FromStr>(
+    path: &str,
+    codes: &[&str],
+    value_set: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if !codes.is_empty() && !codes.iter().any(|code| code.parse::<E>().is_ok()) {
+        issues.push(ValidationIssue {
+            path: String::from(path),
+            message: format!("has no code of the required ValueSet {value_set}"),
+        });
+    }
+}
+
+/// Does the value have every part of the pattern? Each key of a pattern
+/// object is in the value, and each item of a pattern array matches an item
+/// of the value.
+fn contains(value: &Value, pattern: &Value) -> bool {
+    match (value, pattern) {
+        (Value::Object(value), Value::Object(pattern)) => pattern
+            .iter()
+            .all(|(key, x)| value.get(key).is_some_and(|y| contains(y, x))),
+        (Value::Array(value), Value::Array(pattern)) => {
+            pattern.iter().all(|x| value.iter().any(|y| contains(y, x)))
+        }
+        (value, pattern) => value == pattern,
+    }
+}
+"#;
+
+/// The signature of `validate_into`, with unused parameters when it has no
+/// statements.
+fn validate_into_signature(statements: &str) -> &'static str {
+    if statements.is_empty() {
+        "fn validate_into(&self, _path: &str, _issues: &mut Vec<types::ValidationIssue>)"
+    } else {
+        "fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>)"
+    }
+}
+
+/// Rust type name, element path, and statements => Rust `Validate` impl.
+fn validate_impl_block(name: &str, path: &str, statements: &str) -> SourceCodeString {
+    let path_const = if path.is_empty() {
+        String::new()
+    } else {
+        format!("    const PATH: &'static str = \"{path}\";\n\n")
+    };
+    let body = if statements.is_empty() {
+        String::from(" {}\n")
+    } else {
+        format!(" {{\n{statements}    }}\n")
+    };
+    format!(
+        "\nimpl types::Validate for {name} {{\n{path_const}    {signature}{body}}}\n",
+        signature = validate_into_signature(statements),
+    )
+}
+
+/// Given one element, return the Rust expressions of the checks of one of
+/// its values, named `value`, at `path`: its `maxLength`, its `minValue` and
+/// `maxValue`, its fixed and pattern values, and its required binding.
+///
+/// The value of a FHIR primitive type, such as `types::String`, is in its
+/// `value` field; the value of a FHIRPath system type is itself.
+fn element_value_checks(element: &Element, context: &CodegenContext) -> Vec<SourceCodeString> {
+    let mut checks = vec![];
+    let code = match element.r#type.as_deref() {
+        Some([element_type]) => element_type.code.as_str(),
+        _ => "",
+    };
+    let (string_value, integer_value) = if type_code_is_primitive(code) {
+        let value_type = primitive_into_rust_value_type(code);
+        (
+            (value_type == "std::string::String").then_some("&value.value"),
+            matches!(value_type.as_str(), "i32" | "i64" | "u32")
+                .then_some("i64::from(value.value)"),
+        )
+    } else {
+        let value_type = type_code_into_rust_type(code);
+        (
+            (value_type == "String").then_some("value"),
+            matches!(value_type.as_str(), "i32" | "i64").then_some("i64::from(*value)"),
+        )
+    };
+    if let (Some(value), Some(max)) = (string_value, element.max_length) {
+        checks.push(format!(
+            "types::validate::check_max_length(path, {value}, {max}, issues);"
+        ));
+    }
+    let (min, max) = element_value_range(element);
+    if let Some(value) = integer_value.filter(|_| min.is_some() || max.is_some()) {
+        let option = |x: Option<i64>| x.map_or(String::from("None"), |x| format!("Some({x})"));
+        checks.push(format!(
+            "types::validate::check_range(path, {value}, {}, {}, issues);",
+            option(min),
+            option(max),
+        ));
+    }
+    if !element_is_choice(element) {
+        if let Some(value) = element_fixed_value(element) {
+            checks.push(format!(
+                "types::validate::check_fixed(path, value, {:?}, issues);",
+                value.to_string()
+            ));
+        }
+        if let Some(value) = element_pattern_value(element) {
+            checks.push(format!(
+                "types::validate::check_pattern(path, value, {:?}, issues);",
+                value.to_string()
+            ));
+        }
+    }
+    if let Some(code_enum) = context
+        .binding_enum(element)
+        .filter(|_| context.code_enum(element).is_none())
+    {
+        let codes = match code {
+            "Coding" => Some("value.code.iter()"),
+            "CodeableConcept" => Some("value.coding.iter().filter_map(|x| x.code.as_ref())"),
+            _ => None,
+        };
+        if let Some(codes) = codes {
+            checks.push(format!(
+                "let codes: Vec<&str> = {codes}.map(|x| x.value.as_str()).collect();\n\
+                 types::validate::check_codes::<{name}>(path, &codes, {value_set:?}, issues);",
+                name = code_enum.name,
+                value_set = code_enum.value_set,
+            ));
+        }
+    }
+    checks
+}
+
+/// Given one element, return its min and max integer values, such as the
+/// `minValueInteger` and `maxValueInteger64`.
+fn element_value_range(element: &Element) -> (Option<i64>, Option<i64>) {
+    let parse = |x: &Option<String>| x.as_deref().and_then(|x| x.parse::<i64>().ok());
+    let min = element
+        .min_value_integer
+        .or(element.min_value_unsigned_int)
+        .or(element.min_value_positive_int)
+        .or_else(|| parse(&element.min_value_integer_64))
+        .or_else(|| parse(&element.min_value_unsigned_int_64))
+        .or_else(|| parse(&element.min_value_positive_int_64));
+    let max = element
+        .max_value_integer
+        .or(element.max_value_unsigned_int)
+        .or(element.max_value_positive_int)
+        .or_else(|| parse(&element.max_value_integer_64))
+        .or_else(|| parse(&element.max_value_unsigned_int_64))
+        .or_else(|| parse(&element.max_value_positive_int_64));
+    (min, max)
+}
+
+/// Indent each line of the statements.
+fn indent(statements: &[String], indent: &str) -> SourceCodeString {
+    statements
+        .iter()
+        .flat_map(|x| x.lines())
+        .map(|line| format!("{indent}{line}\n"))
+        .collect()
+}
+
+/// Given one element, return the Rust statements that validate its field,
+/// and the sibling field of its id and extensions, if any.
+fn element_into_rust_validate_statements(
+    element: &Element,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let cardinality = Cardinality::from_element(element);
+    let json_name = element_into_json_name(element);
+    let field = json_name_into_rust_field_name(json_name);
+    let checks = element_value_checks(element, context);
+    let count_check = match (element.min.unwrap_or_default(), element.max.as_deref()) {
+        (min, max) if cardinality == Cardinality::Repeated => {
+            let max = max.and_then(|x| x.parse::<usize>().ok());
+            (min > 0 || max.is_some()).then(|| {
+                format!(
+                    "types::validate::check_count(path, self.{field}.len(), {min}, {}, issues);",
+                    max.map_or(String::from("None"), |x| format!("Some({x})"))
+                )
+            })
+        }
+        _ => None,
+    };
+    let mut out = if checks.is_empty() && count_check.is_none() {
+        format!(
+            "        types::Validate::validate_into(&self.{field}, &format!(\"{{path}}.{json_name}\"), issues);\n"
+        )
+    } else {
+        let mut statements: Vec<String> = count_check.into_iter().collect();
+        if !checks.is_empty() {
+            statements.push(match cardinality {
+                Cardinality::Repeated => format!(
+                    "for (index, value) in self.{field}.iter().enumerate() {{\n    let path = &format!(\"{{path}}[{{index}}]\");\n{}}}",
+                    indent(&checks, "    ")
+                ),
+                Cardinality::Optional => format!(
+                    "if let Some(value) = &self.{field} {{\n{}}}",
+                    indent(&checks, "    ")
+                ),
+                _ => format!("let value = &self.{field};\n{}", indent(&checks, "").trim_end()),
+            });
+        }
+        statements.push(format!(
+            "types::Validate::validate_into(&self.{field}, path, issues);"
+        ));
+        format!(
+            "        {{\n            let path = &format!(\"{{path}}.{json_name}\");\n{}        }}\n",
+            indent(&statements, "            ")
+        )
+    };
+    if element_has_primitive_sibling(element) || element_has_choice_sibling(element) {
+        out += &format!(
+            "        types::Validate::validate_into(&self.{}, &format!(\"{{path}}._{json_name}\"), issues);\n",
+            json_name_into_rust_field_name(&format!("{json_name}Element")),
+        );
+    }
+    out
+}
+
+/// Rust struct name and FHIR elements => Rust `Validate` impl block of
+/// source code, for the direct children of the element at the parent path.
+///
+/// Example:
+///
+/// ```no_run
+/// let elements = … // e.g. paths "Foo", "Foo.bravo" 1..*, "Foo.charlie" 0..1.
+/// elements_into_rust_validate_block("Foo", &elements, "Foo", &context)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// impl types::Validate for Foo {
+///     const PATH: &'static str = "Foo";
+///
+///     fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+///         {
+///             let path = &format!("{path}.bravo");
+///             types::validate::check_count(path, self.bravo.len(), 1, None, issues);
+///             types::Validate::validate_into(&self.bravo, path, issues);
+///         }
+///         types::Validate::validate_into(&self.charlie, &format!("{path}.charlie"), issues);
+///     }
+/// }
+/// ```
+///
+/// Each field validates its own value, so that the issues of a nested
+/// value, such as `Foo.charlie.delta`, have their full path.
+///
+#[allow(dead_code)]
+pub fn elements_into_rust_validate_block(
+    name: &str,
+    elements: &[Element],
+    parent_path: &str,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let statements = child_elements(elements, parent_path)
+        .filter(|element| Cardinality::from_element(element) != Cardinality::Prohibited)
+        .map(|element| element_into_rust_validate_statements(element, context))
+        .collect::<String>();
+    let path = if parent_path.is_empty() {
+        name
+    } else {
+        parent_path
+    };
+    validate_impl_block(name, path, &statements)
+}
+
+/// FHIR primitive type resource => Rust `Validate` impl block of source
+/// code, that checks the rules of its `value` element, such as the
+/// `maxLength` of `string.value`.
+#[allow(dead_code)]
+pub fn resource_into_rust_validate_primitive_block(
+    resource: &Resource,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let value_path = format!("{}.value", resource.id);
+    let checks = resource_elements(resource)
+        .iter()
+        .find(|x| x.path == value_path)
+        .map(|element| {
+            // The value of the primitive is the `value` field of `self`.
+            let element = Element {
+                r#type: Some(vec![crate::r5::parse::all::ElementType {
+                    code: resource.id.clone(),
+                    ..Default::default()
+                }]),
+                ..element.clone()
+            };
+            element_value_checks(&element, context)
+        })
+        .unwrap_or_default();
+    let statements = if checks.is_empty() {
+        String::new()
+    } else {
+        format!("        let value = self;\n{}", indent(&checks, "        "))
+    };
+    validate_impl_block(
+        &resource.id.to_case(Case::Pascal),
+        &resource.id,
+        &statements,
+    )
+}
+
+/// FHIR choice element => Rust `Validate` impl block of source code, that
+/// validates the value of each variant at the path of its JSON key, such as
+/// `Extension.valueQuantity`.
+#[allow(dead_code)]
+pub fn element_into_rust_validate_choice_block(element: &Element) -> SourceCodeString {
+    let path = element.path.trim_end_matches("[x]");
+    let codes: Vec<&str> = element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| element_type.code.as_str())
+        .collect();
+    validate_impl_block(
+        &path_into_rust_struct_name(path),
+        path,
+        &choice_validate_statements(&codes),
+    )
+}
+
+/// Choice element => Rust `Validate` impl block of source code for its
+/// sibling enum, that validates the id and extensions of each primitive
+/// type, at a path such as `Extension._valueString`.
+#[allow(dead_code)]
+pub fn element_into_rust_validate_choice_sibling_block(element: &Element) -> SourceCodeString {
+    validate_impl_block(
+        &choice_sibling_enum_name(element),
+        element.path.trim_end_matches("[x]"),
+        &choice_validate_statements(&choice_sibling_codes(element)),
+    )
+}
+
+/// Given the type codes of a choice, return the Rust statements that
+/// validate the value of each variant, at the path with the type suffix.
+fn choice_validate_statements(codes: &[&str]) -> SourceCodeString {
+    let arms = codes
+        .iter()
+        .map(|code| {
+            format!(
+                "            Self::{variant}(value) => {{\n                types::Validate::validate_into(value, &format!(\"{{path}}{suffix}\"), issues)\n            }}\n",
+                variant = code.to_case(Case::Pascal),
+                suffix = choice_json_key("", code),
+            )
+        })
+        .collect::<String>();
+    if arms.is_empty() {
+        String::new()
+    } else {
+        format!("        match self {{\n{arms}        }}\n")
+    }
+}
+
+/// Code enum => Rust `Validate` impl block of source code, without rules,
+/// because each variant is a code of the required ValueSet.
+#[allow(dead_code)]
+pub fn code_enum_into_rust_validate_block(code_enum: &CodeEnum) -> SourceCodeString {
+    validate_impl_block(&code_enum.name, "", "")
+}
+
+/// FHIR constraint profile => Rust `Validate` impl block of source code,
+/// that validates the base value.
+///
+/// The value already satisfies the rules of the profile, because the only
+/// way to make one is `TryFrom`, which checks them.
+///
+#[allow(dead_code)]
+pub fn resource_into_rust_validate_profile_block(resource: &Resource) -> SourceCodeString {
+    formatdoc!(
+        r#"
+
+        impl types::Validate for {name} {{
+            const PATH: &'static str = "{base}";
+
+            fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {{
+                types::Validate::validate_into(&self.0, path, issues);
+            }}
+        }}
+        "#,
+        name = resource.id.to_case(Case::Pascal),
+        base = resource.r#type.as_deref().unwrap_or_default(),
+    )
+}
+
+/// Resource names => Rust `Validate` impl block of source code for the
+/// `Resource` enum, that validates the resource of each variant, at the
+/// path of its `resourceType` when the path is empty.
+#[allow(dead_code)]
+pub fn resources_into_rust_enum_validate_block(
+    names: &[String],
+    config: &CodegenConfig,
+) -> SourceCodeString {
+    let arms = names
+        .iter()
+        .map(|name| {
+            let cfg = match features_cfg_attribute(config, &[name]) {
+                cfg if cfg.is_empty() => cfg,
+                cfg => format!("            {cfg}"),
+            };
+            format!(
+                "{cfg}            Self::{name}(value) => types::Validate::validate_into(value, path, issues),\n"
+            )
+        })
+        .collect::<String>();
+    formatdoc!(
+        r#"
+
+        impl types::Validate for Resource {{
+            fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {{
+                let path = if path.is_empty() {{
+                    self.resource_type()
+                }} else {{
+                    path
+                }};
+                match self {{
+        {arms}        }}
+            }}
+        }}
+        "#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn element(path: &str, min: u32, max: &str, codes: &[&str]) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            min: Some(min),
+            max: Some(max.into()),
+            r#type: Some(
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    #[test]
+    fn test_elements_into_rust_validate_block() {
+        let mut charlie = element("Foo.charlie", 0, "1", &["string"]);
+        charlie.max_length = Some(10);
+        let elements = vec![
+            element("Foo", 0, "*", &[]),
+            element("Foo.bravo", 1, "*", &["Quantity"]),
+            charlie,
+            element("Foo.value[x]", 0, "1", &["Quantity", "string"]),
+        ];
+        let actual =
+            elements_into_rust_validate_block("Foo", &elements, "Foo", &CodegenContext::new());
+        let expect = concat!(
+            "\n",
+            "impl types::Validate for Foo {\n",
+            "    const PATH: &'static str = \"Foo\";\n",
+            "\n",
+            "    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {\n",
+            "        {\n",
+            "            let path = &format!(\"{path}.bravo\");\n",
+            "            types::validate::check_count(path, self.bravo.len(), 1, None, issues);\n",
+            "            types::Validate::validate_into(&self.bravo, path, issues);\n",
+            "        }\n",
+            "        {\n",
+            "            let path = &format!(\"{path}.charlie\");\n",
+            "            if let Some(value) = &self.charlie {\n",
+            "                types::validate::check_max_length(path, &value.value, 10, issues);\n",
+            "            }\n",
+            "            types::Validate::validate_into(&self.charlie, path, issues);\n",
+            "        }\n",
+            "        types::Validate::validate_into(&self.charlie_element, &format!(\"{path}._charlie\"), issues);\n",
+            "        types::Validate::validate_into(&self.value, &format!(\"{path}.value\"), issues);\n",
+            "        types::Validate::validate_into(&self.value_element, &format!(\"{path}._value\"), issues);\n",
+            "    }\n",
+            "}\n",
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_elements_into_rust_validate_block_without_elements() {
+        let actual = elements_into_rust_validate_block(
+            "Foo",
+            &[element("Foo", 0, "*", &[])],
+            "Foo",
+            &CodegenContext::new(),
+        );
+        assert!(
+            actual.contains(
+                "    fn validate_into(&self, _path: &str, _issues: &mut Vec<types::ValidationIssue>) {}\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resource_into_rust_validate_primitive_block() {
+        let mut value = element("integer.value", 0, "1", &[]);
+        value.min_value_integer = Some(-2147483648);
+        value.max_value_integer = Some(2147483647);
+        let resource = Resource {
+            id: "integer".into(),
+            snapshot: Some(Snapshot {
+                element: vec![element("integer", 0, "*", &[]), value],
+            }),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_validate_primitive_block(&resource, &CodegenContext::new());
+        assert!(
+            actual.contains(concat!(
+                "        let value = self;\n",
+                "        types::validate::check_range(path, i64::from(value.value), Some(-2147483648), Some(2147483647), issues);\n",
+            )),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_element_into_rust_validate_choice_block() {
+        let actual = element_into_rust_validate_choice_block(&element(
+            "Foo.value[x]",
+            0,
+            "1",
+            &["Quantity", "dateTime"],
+        ));
+        assert!(
+            actual.contains("impl types::Validate for FooValue {\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(
+                "                types::Validate::validate_into(value, &format!(\"{path}DateTime\"), issues)\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resources_into_rust_enum_validate_block() {
+        let actual = resources_into_rust_enum_validate_block(
+            &[String::from("Patient")],
+            &CodegenConfig::new(),
+        );
+        assert!(
+            actual.contains(
+                "            Self::Patient(value) => types::Validate::validate_into(value, path, issues),\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+}
@@ -0,0 +1,621 @@
+//! Elements into Rust visit
+//!
+//! A visitor finds every value of a type inside a value, such as every
+//! `Reference` of a resource, wherever it is:
+//!
+//! ```no_run
+//! struct References(Vec<String>);
+//!
+//! impl types::Visit for References {
+//!     fn visit_reference(&mut self, path: &str, value: &types::Reference) {
+//!         self.0.push(String::from(path)); // e.g. "Patient.generalPractitioner[0]"
+//!         types::Walk::walk(value, path, self);
+//!     }
+//! }
+//!
+//! types::Walk::accept(&patient, "Patient", &mut references);
+//! ```
+//!
+//! The `Visit` and `VisitMut` traits have one method per generated type,
+//! such as `visit_reference` and `visit_reference_mut`, which by default
+//! walk each field of the value. Each generated type implements the `Walk`
+//! trait, which accepts the value of each of its fields, with its FHIR
+//! element path.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::child_elements;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+
+/// The module name of the generated visitors.
+pub const VISIT_MODULE: &str = "visit";
+
+/// The Rust source code of the `Walk` trait of the generated visitors
+/// module, after the `Visit` and `VisitMut` traits.
+pub const WALK_BLOCK: &str = r#"
+/// Walk a value: accept the value of each of its fields, with its path.
+pub trait Walk {
+    /// Accept the value: call the visit method of its type, if any, else
+    /// walk it.
+    fn accept<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        self.walk(path, visitor);
+    }
+
+    /// Accept the value of each field, such as `Patient.name` with the
+    /// path of the value, such as `Patient.name[0]`.
+    fn walk<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V);
+
+    /// Accept the value mutably: call the mutable visit method of its
+    /// type, if any, else walk it mutably.
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        self.walk_mut(path, visitor);
+    }
+
+    /// Accept the value of each field mutably.
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V);
+}
+
+impl<T: Walk> Walk for Option<T> {
+    fn accept<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.accept(path, visitor);
+        }
+    }
+
+    fn walk<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.walk(path, visitor);
+        }
+    }
+
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.accept_mut(path, visitor);
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.walk_mut(path, visitor);
+        }
+    }
+}
+
+impl<T: Walk> Walk for Vec<T> {
+    fn accept<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        for (index, value) in self.iter().enumerate() {
+            value.accept(&format!("{path}[{index}]"), visitor);
+        }
+    }
+
+    fn walk<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        for (index, value) in self.iter().enumerate() {
+            value.walk(&format!("{path}[{index}]"), visitor);
+        }
+    }
+
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        for (index, value) in self.iter_mut().enumerate() {
+            value.accept_mut(&format!("{path}[{index}]"), visitor);
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        for (index, value) in self.iter_mut().enumerate() {
+            value.walk_mut(&format!("{path}[{index}]"), visitor);
+        }
+    }
+}
+
+impl<T: Walk> Walk for Box<T> {
+    fn accept<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        (**self).accept(path, visitor);
+    }
+
+    fn walk<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        (**self).walk(path, visitor);
+    }
+
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        (**self).accept_mut(path, visitor);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        (**self).walk_mut(path, visitor);
+    }
+}
+
+/// Implement `Walk` for a type without fields, such as the Rust type of a
+/// FHIRPath system type.
+macro_rules! impl_walk_without_fields {
+    ($($t:ty),*) => {
+        $(
+            impl Walk for $t {
+                fn walk<V: Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}
+
+                fn walk_mut<V: VisitMut + ?Sized>(&mut self, _path: &str, _visitor: &mut V) {}
+            }
+        )*
+    };
+}
+
+impl_walk_without_fields!(bool, i32, i64, u32, String, ::serde_json::Number, ::serde_json::Value);
+"#;
+
+/// Given one FHIR type id, return the name of its visit method.
+///
+/// Example:
+///
+/// ```no_run
+/// visit_method_name("CodeableConcept") => "visit_codeable_concept"
+/// visit_method_name("dateTime") => "visit_date_time"
+/// ```
+///
+#[allow(dead_code)]
+pub fn visit_method_name(id: &str) -> SourceCodeString {
+    format!("visit_{}", id.from_case(Case::Pascal).to_case(Case::Snake))
+}
+
+/// Rust type name, visit method name if any, and the statements of `walk`
+/// and `walk_mut` => Rust `Walk` impl block of source code.
+///
+/// A type with a visit method accepts a value by calling its visit method;
+/// any other type, such as an inline struct, walks the value.
+fn walk_impl_block(
+    name: &str,
+    visit_method: Option<&str>,
+    walk_statements: &str,
+    walk_mut_statements: &str,
+) -> SourceCodeString {
+    let (accept, accept_mut) = match visit_method {
+        Some(method) => (
+            format!(
+                "    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {{\n        visitor.{method}(path, self);\n    }}\n\n"
+            ),
+            format!(
+                "    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {{\n        visitor.{method}_mut(path, self);\n    }}\n\n"
+            ),
+        ),
+        None => (String::new(), String::new()),
+    };
+    let body = |statements: &str| {
+        if statements.is_empty() {
+            String::from(" {}\n")
+        } else {
+            format!(" {{\n{statements}    }}\n")
+        }
+    };
+    let parameters = |statements: &str| {
+        if statements.is_empty() {
+            "_path: &str, _visitor: &mut V"
+        } else {
+            "path: &str, visitor: &mut V"
+        }
+    };
+    format!(
+        "\nimpl types::Walk for {name} {{\n{accept}    fn walk<V: types::Visit + ?Sized>(&self, {}){}\n{accept_mut}    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, {}){}}}\n",
+        parameters(walk_statements),
+        body(walk_statements),
+        parameters(walk_mut_statements),
+        body(walk_mut_statements),
+    )
+}
+
+/// Rust struct name and FHIR elements => Rust `Walk` impl block of source
+/// code, for the direct children of the element at the parent path.
+///
+/// Example:
+///
+/// ```no_run
+/// let elements = … // e.g. paths "Foo", "Foo.bravo" 0..*.
+/// elements_into_rust_walk_block("Foo", &elements, "Foo", &context)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// impl types::Walk for Foo {
+///     fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+///         visitor.visit_foo(path, self);
+///     }
+///
+///     fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+///         types::Walk::accept(&self.bravo, &format!("{path}.bravo"), visitor);
+///     }
+///     …
+/// }
+/// ```
+///
+/// The struct of a type, such as `Foo`, has a visit method; an inline
+/// struct, such as `FooBravo`, does not. A primitive element also walks its
+/// sibling field of its id and extensions, such as `Foo._bravo`.
+///
+#[allow(dead_code)]
+pub fn elements_into_rust_walk_block(
+    name: &str,
+    elements: &[Element],
+    parent_path: &str,
+) -> SourceCodeString {
+    let mut fields: Vec<(String, String)> = vec![];
+    for element in child_elements(elements, parent_path)
+        .filter(|element| Cardinality::from_element(element) != Cardinality::Prohibited)
+    {
+        let json_name = element_into_json_name(element);
+        fields.push((
+            json_name_into_rust_field_name(json_name),
+            String::from(json_name),
+        ));
+        if element_has_primitive_sibling(element) || element_has_choice_sibling(element) {
+            fields.push((
+                json_name_into_rust_field_name(&format!("{json_name}Element")),
+                format!("_{json_name}"),
+            ));
+        }
+    }
+    let statements = |accept: &str, reference: &str| {
+        fields
+            .iter()
+            .map(|(field, json_name)| {
+                format!(
+                    "        types::Walk::{accept}({reference}self.{field}, &format!(\"{{path}}.{json_name}\"), visitor);\n"
+                )
+            })
+            .collect::<String>()
+    };
+    let visit_method = (!parent_path.is_empty() && !parent_path.contains('.'))
+        .then(|| visit_method_name(parent_path));
+    walk_impl_block(
+        name,
+        visit_method.as_deref(),
+        &statements("accept", "&"),
+        &statements("accept_mut", "&mut "),
+    )
+}
+
+/// FHIR primitive type resource => Rust `Walk` impl block of source code,
+/// with a visit method, and without fields to walk.
+#[allow(dead_code)]
+pub fn resource_into_rust_walk_primitive_block(resource: &Resource) -> SourceCodeString {
+    walk_impl_block(
+        &resource.id.to_case(Case::Pascal),
+        Some(&visit_method_name(&resource.id)),
+        "",
+        "",
+    )
+}
+
+/// FHIR constraint profile => Rust `Walk` impl block of source code, with a
+/// visit method, that walks the base value, so that a visitor also visits a
+/// `SimpleQuantity` as a `Quantity`.
+///
+/// A mutable visitor can change the base value in a way that breaks a rule
+/// of the profile.
+///
+#[allow(dead_code)]
+pub fn resource_into_rust_walk_profile_block(resource: &Resource) -> SourceCodeString {
+    walk_impl_block(
+        &resource.id.to_case(Case::Pascal),
+        Some(&visit_method_name(&resource.id)),
+        "        types::Walk::accept(&self.0, path, visitor);\n",
+        "        types::Walk::accept_mut(&mut self.0, path, visitor);\n",
+    )
+}
+
+/// FHIR choice element => Rust `Walk` impl block of source code, that
+/// accepts the value of each variant at the path of its JSON key, such as
+/// `Extension.valueReference`.
+#[allow(dead_code)]
+pub fn element_into_rust_walk_choice_block(element: &Element) -> SourceCodeString {
+    let path = element.path.trim_end_matches("[x]");
+    let codes: Vec<&str> = element
+        .r#type
+        .iter()
+        .flatten()
+        .map(|element_type| element_type.code.as_str())
+        .collect();
+    walk_impl_block(
+        &path_into_rust_struct_name(path),
+        None,
+        &choice_walk_statements(&codes, "accept"),
+        &choice_walk_statements(&codes, "accept_mut"),
+    )
+}
+
+/// Choice element => Rust `Walk` impl block of source code for its sibling
+/// enum, that accepts the id and extensions of each primitive type.
+#[allow(dead_code)]
+pub fn element_into_rust_walk_choice_sibling_block(element: &Element) -> SourceCodeString {
+    let codes = choice_sibling_codes(element);
+    walk_impl_block(
+        &choice_sibling_enum_name(element),
+        None,
+        &choice_walk_statements(&codes, "accept"),
+        &choice_walk_statements(&codes, "accept_mut"),
+    )
+}
+
+/// Given the type codes of a choice, return the Rust statements that accept
+/// the value of each variant, at the path with the type suffix.
+fn choice_walk_statements(codes: &[&str], accept: &str) -> SourceCodeString {
+    let arms = codes
+        .iter()
+        .map(|code| {
+            format!(
+                "            Self::{variant}(value) => {{\n                types::Walk::{accept}(value, &format!(\"{{path}}{suffix}\"), visitor)\n            }}\n",
+                variant = code.to_case(Case::Pascal),
+                suffix = choice_json_key("", code),
+            )
+        })
+        .collect::<String>();
+    if arms.is_empty() {
+        String::new()
+    } else {
+        format!("        match self {{\n{arms}        }}\n")
+    }
+}
+
+/// Code enum => Rust `Walk` impl block of source code, without fields.
+#[allow(dead_code)]
+pub fn code_enum_into_rust_walk_block(code_enum: &CodeEnum) -> SourceCodeString {
+    walk_impl_block(&code_enum.name, None, "", "")
+}
+
+/// Resource names => Rust `Walk` impl block of source code for the
+/// `Resource` enum, with a visit method, that accepts the resource of each
+/// variant.
+#[allow(dead_code)]
+pub fn resources_into_rust_enum_walk_block(
+    names: &[String],
+    config: &CodegenConfig,
+) -> SourceCodeString {
+    let statements = |accept: &str| {
+        let arms = names
+            .iter()
+            .map(|name| {
+                let cfg = match features_cfg_attribute(config, &[name]) {
+                    cfg if cfg.is_empty() => cfg,
+                    cfg => format!("            {cfg}"),
+                };
+                format!(
+                    "{cfg}            Self::{name}(value) => types::Walk::{accept}(value, path, visitor),\n"
+                )
+            })
+            .collect::<String>();
+        format!("        match self {{\n{arms}        }}\n")
+    };
+    walk_impl_block(
+        "Resource",
+        Some(&visit_method_name(RESOURCE_ENUM_MODULE)),
+        &statements("accept"),
+        &statements("accept_mut"),
+    )
+}
+
+/// FHIR resources => Rust source code of the visitors module, with the
+/// `Visit` and `VisitMut` traits, which have a visit method per type, and
+/// the `Walk` trait.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. resource ids Coding, Reference.
+/// resources_into_rust_visit_block(&resources, &config)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// pub trait Visit {
+///     /// Visit one `Coding`, and by default walk it.
+///     fn visit_coding(&mut self, path: &str, value: &types::Coding) {
+///         types::Walk::walk(value, path, self);
+///     }
+///     …
+/// }
+/// ```
+///
+/// When there is any concrete resource, the traits have `visit_resource` for
+/// the `Resource` enum. When the config has features, each method has a
+/// `cfg` attribute for its feature.
+///
+#[allow(dead_code)]
+pub fn resources_into_rust_visit_block(
+    resources: &[Resource],
+    config: &CodegenConfig,
+) -> SourceCodeString {
+    let mut types: Vec<(String, String, String)> = resources
+        .iter()
+        .filter(|x| !x.id.is_empty() && !resource_is_abstract_resource(x))
+        .map(|resource| {
+            let name = resource.id.to_case(Case::Pascal);
+            let cfg = features_cfg_attribute(config, &[&name]);
+            (visit_method_name(&resource.id), name, cfg)
+        })
+        .collect();
+    types.sort();
+    types.dedup();
+    if resources.iter().any(resource_is_concrete_resource) {
+        types.push((
+            visit_method_name(RESOURCE_ENUM_MODULE),
+            String::from("Resource"),
+            features_cfg_attribute(config, &resource_enum_features(resources)),
+        ));
+    }
+    let methods = |suffix: &str, reference: &str| {
+        types
+            .iter()
+            .map(|(method, name, cfg)| {
+                let cfg = match cfg.as_str() {
+                    "" => String::new(),
+                    cfg => format!("    {cfg}"),
+                };
+                format!(
+                    "\n    /// Visit one `{name}`, and by default walk it.\n{cfg}    fn {method}{suffix}(&mut self, path: &str, value: {reference}types::{name}) {{\n        types::Walk::walk{suffix}(value, path, self);\n    }}\n"
+                )
+            })
+            .collect::<String>()
+    };
+    formatdoc!(
+        r#"
+        //! Visit
+        //!
+        //! Visit every value of a type inside a value, such as every `Reference`
+        //! of a resource, with the FHIR element path of each value, such as
+        //! `Patient.generalPractitioner[0]`.
+        //!
+        //! A visitor implements the visit method of each type that it needs,
+        //! and calls `types::Walk::walk` in it to continue into the value.
+
+        /// Use the FHIR R5 datatypes and resources for the visit methods.
+        {use_types_line}
+
+        /// Visit each value of a type, by reference.
+        pub trait Visit {{{visit_methods}}}
+
+        /// Visit each value of a type, by mutable reference.
+        pub trait VisitMut {{{visit_mut_methods}}}
+        {WALK_BLOCK}"#,
+        use_types_line = config.use_types_line(),
+        visit_methods = methods("", "&"),
+        visit_mut_methods = methods("_mut", "&mut "),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn element(path: &str, min: u32, max: &str, codes: &[&str]) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            min: Some(min),
+            max: Some(max.into()),
+            r#type: Some(
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    #[test]
+    fn test_visit_method_name() {
+        assert_eq!(
+            visit_method_name("CodeableConcept"),
+            "visit_codeable_concept"
+        );
+        assert_eq!(visit_method_name("dateTime"), "visit_date_time");
+    }
+
+    #[test]
+    fn test_elements_into_rust_walk_block() {
+        let elements = vec![
+            element("Foo", 0, "*", &[]),
+            element("Foo.bravo", 0, "*", &["Reference"]),
+            element("Foo.charlie", 0, "1", &["string"]),
+        ];
+        let actual = elements_into_rust_walk_block("Foo", &elements, "Foo");
+        let expect = concat!(
+            "\n",
+            "impl types::Walk for Foo {\n",
+            "    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {\n",
+            "        visitor.visit_foo(path, self);\n",
+            "    }\n",
+            "\n",
+            "    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {\n",
+            "        types::Walk::accept(&self.bravo, &format!(\"{path}.bravo\"), visitor);\n",
+            "        types::Walk::accept(&self.charlie, &format!(\"{path}.charlie\"), visitor);\n",
+            "        types::Walk::accept(&self.charlie_element, &format!(\"{path}._charlie\"), visitor);\n",
+            "    }\n",
+            "\n",
+            "    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {\n",
+            "        visitor.visit_foo_mut(path, self);\n",
+            "    }\n",
+            "\n",
+            "    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {\n",
+            "        types::Walk::accept_mut(&mut self.bravo, &format!(\"{path}.bravo\"), visitor);\n",
+            "        types::Walk::accept_mut(&mut self.charlie, &format!(\"{path}.charlie\"), visitor);\n",
+            "        types::Walk::accept_mut(&mut self.charlie_element, &format!(\"{path}._charlie\"), visitor);\n",
+            "    }\n",
+            "}\n",
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_elements_into_rust_walk_block_with_inline_struct() {
+        let elements = vec![
+            element("Foo", 0, "*", &[]),
+            element("Foo.bravo", 0, "1", &[]),
+        ];
+        let actual = elements_into_rust_walk_block("FooBravo", &elements, "Foo.bravo");
+        assert!(!actual.contains("fn accept"), "{}", actual);
+        assert!(
+            actual.contains(
+                "    fn walk<V: types::Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_element_into_rust_walk_choice_block() {
+        let actual = element_into_rust_walk_choice_block(&element(
+            "Foo.value[x]",
+            0,
+            "1",
+            &["Reference", "string"],
+        ));
+        assert!(
+            actual.contains(
+                "                types::Walk::accept_mut(value, &format!(\"{path}Reference\"), visitor)\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resources_into_rust_visit_block() {
+        let resources = vec![
+            Resource {
+                id: "Reference".into(),
+                kind: Some(String::from("complex-type")),
+                ..Resource::default()
+            },
+            Resource {
+                id: "Patient".into(),
+                kind: Some(String::from("resource")),
+                ..Resource::default()
+            },
+        ];
+        let actual = resources_into_rust_visit_block(&resources, &CodegenConfig::new());
+        assert!(
+            actual.contains(concat!(
+                "    /// Visit one `Reference`, and by default walk it.\n",
+                "    fn visit_reference(&mut self, path: &str, value: &types::Reference) {\n",
+                "        types::Walk::walk(value, path, self);\n",
+                "    }\n",
+            )),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(
+                "    fn visit_resource_mut(&mut self, path: &str, value: &mut types::Resource) {\n"
+            ),
+            "{}",
+            actual
+        );
+        assert!(actual.contains("pub trait Walk {\n"), "{}", actual);
+    }
+}
@@ -1494,6 +1494,12 @@ pub struct Resource {
     /// Example: "5.0.0"
     pub fhir_version: Option<String>,
 
+    /// Example: [{ "type" : "element", "expression" : "Element" }]
+    pub context: Option<Vec<Context>>,
+
+    /// Example: ["value.exists()"]
+    pub context_invariant: Option<Vec<String>>,
+
     /// Example: { "element": [...] }
     pub snapshot: Option<Snapshot>,
 
@@ -1,7 +1,10 @@
 use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust_primitive::primitive_default_json;
 use crate::r5::parse::profiles_types::*;
+use crate::util::{format_rust_source, write_if_changed};
 use ::convert_case::{Case, Casing};
 use ::indoc::formatdoc;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 /// FHIR resource => Rust source code file.
@@ -10,23 +13,27 @@ use std::path::PathBuf;
 ///
 /// ```no_run
 /// let resource = … // e.g. resource id AlfaBravo.
-/// let result = resource_into_rust(&resource);
+/// let result = resource_into_rust(&resource, &context);
 /// ```
 ///
-/// Outcome: a Rust source code file at `./tmp/out/alfa_bravo.rs`.
+/// Outcome: a Rust source code file at `./tmp/out/alfa_bravo.rs`, or
+/// wherever the config of the context puts it, formatted, and written only
+/// when its content changed.
 ///
 #[allow(dead_code)]
-pub fn resource_into_rust(resource: &Resource) -> std::io::Result<()> {
+pub fn resource_into_rust(resource: &Resource, context: &CodegenContext) -> std::io::Result<()> {
     println!(
         "{} {} {}",
         resource.id,
         resource.id.to_case(Case::Pascal),
         resource.id.to_case(Case::Snake),
     );
-    std::fs::write(
-        resource_into_rust_struct_path(&resource),
-        resource_into_rust_struct_block(&resource),
-    )
+    let path = resource_into_rust_struct_path(resource, &context.config);
+    write_if_changed(
+        &path,
+        format_rust_source(&resource_into_rust_struct_block(resource, context)),
+    )?;
+    Ok(())
 }
 
 /// FHIR resource => Rust struct file path.
@@ -35,20 +42,14 @@ pub fn resource_into_rust(resource: &Resource) -> std::io::Result<()> {
 ///
 /// ```no_run
 /// let resource = … // e.g. resource id AlfaBravo.
-/// let path_buf = resource_into_rust_struct_path(&resource);
+/// let path_buf = resource_into_rust_struct_path(&resource, &config);
 /// ```
 ///
-/// Output: `./tmp/out/alfa_bravo.rs`.
+/// Output: `./tmp/out/alfa_bravo.rs` by default.
 ///
 #[allow(dead_code)]
-pub fn resource_into_rust_struct_path(resource: &Resource) -> PathBuf {
-    PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap())
-        .join("tmp")
-        .join("out")
-        .join(format!(
-            "{}.rs",
-            resource.id.from_case(Case::Pascal).to_case(Case::Snake)
-        ))
+pub fn resource_into_rust_struct_path(resource: &Resource, config: &CodegenConfig) -> PathBuf {
+    config.file_path(resource)
 }
 
 /// FHIR resource => Rust struct block of source code.
@@ -57,90 +58,436 @@ pub fn resource_into_rust_struct_path(resource: &Resource) -> PathBuf {
 ///
 /// ```no_run
 /// let resource = … // e.g. resource id AlfaBravo.
-/// let source_code_string = resource_into_rust_struct(&resource);
+/// let source_code_string = resource_into_rust_struct(&resource, &context);
 /// ```
 ///
 /// Output: all the Rust struct source code, including comments,
 /// mods, attributes, etc. such that the source code can compile.
 ///
 #[allow(dead_code)]
-pub fn resource_into_rust_struct_block(resource: &Resource) -> SourceCodeString {
-    let question = String::from("?");
+pub fn resource_into_rust_struct_block(
+    resource: &Resource,
+    context: &CodegenContext,
+) -> SourceCodeString {
     formatdoc!(
-        r#"//! {id_pascal_case}
-        //!
-        //! URL: {url}
-        //!
-        //! Version: {version}
-        //!
-        //! {description}
-        //!
-        //! FHIR: <https://build.fhir.org/>
-        //!
-        //! UML: <https://build.fhir.org/uml.html>
-
-        // Allow unused crate::r5::types as types;
+        r#"{header}
         #![allow(unused_imports)]
 
-        /// Use all the relevant parse types for the goal.
-        use crate::r5::parse::profiles_types::*;
+        /// Use the FHIR R5 datatypes for the attributes.
+        {use_types_line}
 
         /// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
         use ::serde::{{Deserialize, Serialize}};
 
-        /// Skip serializing each attributes that is an option and set to none.
-        #[serde_with::skip_serializing_none]
-        /// Derive all our typical things for programming, serde, comparing, etc.
-        #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-        /// Rename all the snake case Rust attributes into camel case JSON keys.
-        #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-        pub struct {id_pascal_case} {{
-        {attribute_block}
-        }}
+        {struct_blocks}
+        {tests_block}"#,
+        header = context.config.header(resource),
+        use_types_line = context.config.use_types_line(),
+        struct_blocks = resource_into_rust_struct_blocks(resource, context),
+        tests_block = if resource_is_constraint_profile(resource) {
+            resource_into_rust_profile_tests_block(resource)
+        } else {
+            resource_into_rust_tests_block(resource)
+        },
+    )
+}
 
+/// FHIR resource => Rust tests block of source code, that checks the JSON
+/// of the default value, and the serde round trip.
+///
+/// A primitive type serializes as its bare value, such as `false` for
+/// `boolean`. A struct serializes with the key of each required element,
+/// such as `url` of `Extension`, and without any other key.
+fn resource_into_rust_tests_block(resource: &Resource) -> SourceCodeString {
+    let default_test_block = if resource.kind.as_deref() == Some("primitive-type") {
+        format!(
+            "let actual = ::serde_json::to_value(T::default()).expect(\"to_value\");\n    assert_eq!(actual, ::serde_json::json!({}));",
+            primitive_default_json(&resource.id)
+        )
+    } else {
+        format!(
+            "let actual = ::serde_json::to_value(T::default()).expect(\"to_value\");\n    let mut keys: Vec<&str> = actual.as_object().expect(\"object\").keys().map(String::as_str).collect();\n    keys.sort();\n    let expect: Vec<&str> = vec![{}];\n    assert_eq!(keys, expect);",
+            resource_default_json_keys(resource)
+                .iter()
+                .map(|key| format!("{key:?}"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    };
+    formatdoc!(
+        r#"
         #[cfg(test)]
         mod tests {{
             use super::*;
             type T = {id_pascal_case};
 
             #[test]
-            fn test_default() {{
-                let actual = T::default();
-                let expect = T {{}};
-                assert_eq!(actual, expect);
+            fn test_default_into_json() {{
+                {default_test_block}
             }}
 
             mod serde_json {{
                 use super::*;
-                use ::serde_json::json;
 
                 #[test]
-                fn test_serde_json_from_value() {{
-                    let json = json!({{}});
+                fn test_serde_json_round_trip() {{
+                    let json: ::serde_json::Value =
+                        ::serde_json::to_value(T::default()).expect("to_value");
                     let actual: T = ::serde_json::from_value(json).expect("from_value");
                     let expect: T = T::default();
                     assert_eq!(actual, expect);
                 }}
-
-                #[test]
-                fn test_serde_json_to_value() {{
-                    let actual: ::serde_json::Value =
-                        ::serde_json::to_value(T::default()).expect("to_value");
-                    let expect: ::serde_json::Value = json!({{}});
-                    assert_eq!(actual, expect);
-                }}
             }}
         }}
         "#,
         id_pascal_case = resource.id.to_case(Case::Pascal),
-        url = resource.url,
-        version = resource.version,
-        description = resource.description.as_ref().unwrap_or(&question),
-        attribute_block = resource_into_rust_struct_attribute_block(resource),
     )
 }
 
+/// FHIR resource => the sorted JSON keys of its default value: one per
+/// required element, such as `valueBase64Binary` for a required choice,
+/// which defaults to its first type.
+fn resource_default_json_keys(resource: &Resource) -> Vec<String> {
+    let elements = resource_elements(resource);
+    let Some(root) = elements.first() else {
+        return vec![];
+    };
+    let mut keys: Vec<String> = child_elements(elements, &root.path)
+        .filter(|element| Cardinality::from_element(element) == Cardinality::Required)
+        .map(|element| {
+            let json_name = element_into_json_name(element);
+            match element.r#type.as_deref() {
+                Some([first, ..]) if element_is_choice(element) => {
+                    choice_json_key(json_name, &first.code)
+                }
+                _ => String::from(json_name),
+            }
+        })
+        .collect();
+    if resource_is_concrete_resource(resource) {
+        keys.push(String::from("resourceType"));
+    }
+    keys.sort();
+    keys
+}
+
+/// FHIR resource => Rust struct blocks of source code, one for the resource
+/// and one for each of its inline elements, such as `Dosage.doseAndRate`.
+///
+/// Example:
+///
+/// ```no_run
+/// let resource = … // e.g. resource id Dosage.
+/// let source_code_string = resource_into_rust_struct_blocks(&resource, &context);
+/// ```
+///
+/// Output: `pub struct Dosage { … }` then `pub struct DosageDoseAndRate { … }`,
+/// then an enum for each choice element, such as `pub enum DosageDoseAndRateDose`.
+///
+/// A primitive type, such as `boolean`, is one struct that wraps its value.
+/// A constraint profile, such as `SimpleQuantity`, is one struct that wraps
+/// its base type; see `resource_into_rust_profile_block`.
+///
+/// When the config has validation, each struct and enum has its `Validate`
+/// impl, and each required binding has its code enum, such as the binding
+/// of a `CodeableConcept`, for the validation to check its codes. When the
+/// config has visitors, each struct and enum has its `Walk` impl. When the
+/// config has reflection, each struct and enum has its `AsValueRef` impl,
+/// and each struct has its `ELEMENTS` table and `Reflect` impl. When the
+/// config has extensions, each struct and profile with extensions has its
+/// `HasExtensions` impl.
+///
+/// Elements that bind one ValueSet under one binding name share one code
+/// enum. Two ValueSets under one binding name would give two enums of one
+/// name, so that panics.
+///
+#[allow(dead_code)]
+pub fn resource_into_rust_struct_blocks(
+    resource: &Resource,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let validation = context.config.validation;
+    let visitors = context.config.visitors;
+    let reflection = context.config.reflection;
+    if resource.kind.as_deref() == Some("primitive-type") {
+        let mut block = resource_into_rust_primitive_block(resource);
+        if validation {
+            block += &resource_into_rust_validate_primitive_block(resource, context);
+        }
+        if visitors {
+            block += &resource_into_rust_walk_primitive_block(resource);
+        }
+        if reflection {
+            block += &resource_into_rust_reflect_primitive_block(resource);
+        }
+        return block;
+    }
+    if resource_is_constraint_profile(resource) {
+        let mut block = resource_into_rust_profile_block(resource, context);
+        if validation {
+            block += &resource_into_rust_validate_profile_block(resource);
+        }
+        if visitors {
+            block += &resource_into_rust_walk_profile_block(resource);
+        }
+        if reflection {
+            block += &resource_into_rust_reflect_profile_block(resource);
+        }
+        if context.config.extensions {
+            block += &resource_into_rust_has_extensions_profile_block(resource);
+        }
+        return block;
+    }
+    let name = resource.id.to_case(Case::Pascal);
+    let resource_type = resource_is_concrete_resource(resource).then_some(name.as_str());
+    let elements = resource_elements(resource);
+    let Some(root) = elements.first() else {
+        return rust_struct_block(&name, elements, "", resource_type, context);
+    };
+    let mut blocks = vec![rust_struct_block(
+        &name,
+        elements,
+        &root.path,
+        resource_type,
+        context,
+    )];
+    if let Some(resource_type) = resource_type {
+        blocks.push(resource_into_rust_resource_type_block(resource_type));
+    }
+    for element in elements.iter().filter(|x| element_is_inline_struct(x)) {
+        blocks.push(rust_struct_block(
+            &path_into_rust_struct_name(&element.path),
+            elements,
+            &element.path,
+            None,
+            context,
+        ));
+    }
+    for element in elements.iter().filter(|x| element_is_choice(x)) {
+        if Cardinality::from_element(element) != Cardinality::Prohibited {
+            let mut block = element_into_rust_choice_enum(element, context);
+            if validation {
+                block += &element_into_rust_validate_choice_block(element);
+            }
+            if visitors {
+                block += &element_into_rust_walk_choice_block(element);
+            }
+            if reflection {
+                block += &element_into_rust_reflect_choice_block(element);
+            }
+            blocks.push(block);
+            if element_has_choice_sibling(element) {
+                let mut block = element_into_rust_choice_sibling_enum(element);
+                if validation {
+                    block += &element_into_rust_validate_choice_sibling_block(element);
+                }
+                if visitors {
+                    block += &element_into_rust_walk_choice_sibling_block(element);
+                }
+                blocks.push(block);
+            }
+        }
+    }
+    let mut code_enum_value_sets: BTreeMap<String, String> = BTreeMap::new();
+    for code_enum in elements.iter().filter_map(|x| {
+        if validation {
+            context.binding_enum(x)
+        } else {
+            context.code_enum(x)
+        }
+    }) {
+        match code_enum_value_sets.get(&code_enum.name) {
+            Some(value_set) if *value_set == code_enum.value_set => {}
+            Some(value_set) => panic!(
+                "{}: the code enum {} binds both {} and {}",
+                resource.id, code_enum.name, value_set, code_enum.value_set
+            ),
+            None => {
+                let mut block = code_enum_into_rust_enum(&code_enum);
+                if validation {
+                    block += &code_enum_into_rust_validate_block(&code_enum);
+                }
+                if visitors {
+                    block += &code_enum_into_rust_walk_block(&code_enum);
+                }
+                if reflection {
+                    block += &code_enum_into_rust_reflect_block(&code_enum);
+                }
+                blocks.push(block);
+                code_enum_value_sets.insert(code_enum.name, code_enum.value_set);
+            }
+        }
+    }
+    blocks.join("\n")
+}
+
+/// Rust struct name and FHIR elements => Rust struct block of source code,
+/// with an attribute for each direct child of the element at the parent
+/// path, and a constructor.
+///
+/// Serde can not deny unknown fields in a struct that flattens a choice, so
+/// such a struct flattens `types::DenyUnknownFields` last instead.
+///
+/// The struct of a concrete resource, such as `Patient`, starts with its
+/// `resource_type` attribute, which reads and writes the `resourceType` JSON
+/// key; see `resource_into_rust_resource_type_block`.
+///
+/// The struct doc comment has the rich text of the element at the parent
+/// path, such as the root element of the resource.
+///
+/// When the config has builders, the block ends with the builder. When the
+/// config has validation, the block ends with the `Validate` impl, and when
+/// it has visitors, with the `Walk` impl, and when it has reflection, with
+/// the `ELEMENTS` table and the `Reflect` impl, and when it has extensions,
+/// with the `HasExtensions` impl, if the struct has an `extension` field.
+fn rust_struct_block(
+    name: &str,
+    elements: &[Element],
+    parent_path: &str,
+    resource_type: Option<&str>,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let mut attribute_block =
+        elements_into_rust_struct_attribute_block(elements, parent_path, context);
+    if let Some(resource_type) = resource_type {
+        attribute_block = format!(
+            concat!(
+                "    /// The `resourceType` JSON key, which is always `{}`.\n",
+                "    pub resource_type: {}ResourceType,\n",
+                "\n{}",
+            ),
+            resource_type, resource_type, attribute_block
+        );
+    }
+    let constructor_block =
+        elements_into_rust_struct_constructor_block(elements, parent_path, context);
+    let deny_unknown_fields =
+        if child_elements(elements, parent_path).any(element_is_flattened_choice) {
+            attribute_block = format!("{attribute_block}\n{UNKNOWN_FIELDS_ATTRIBUTE}");
+            ""
+        } else {
+            "#[serde(deny_unknown_fields)]\n"
+        };
+    let doc_comment = elements
+        .iter()
+        .find(|element| element.path == parent_path)
+        .map(|element| format!("{}///\n", element_into_rust_doc_comment(element, "")))
+        .unwrap_or_default();
+    formatdoc!(
+        r#"
+        {doc_comment}/// Skip serializing each attributes that is an option and set to none.
+        #[serde_with::skip_serializing_none]
+        /// Derive all our typical things for programming, serde, comparing, etc.
+        #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+        /// Rename all the snake case Rust attributes into camel case JSON keys.
+        #[serde(rename_all = "camelCase")]
+        {deny_unknown_fields}pub struct {name} {{
+        {attribute_block}
+        }}
+
+        impl {name} {{
+        {constructor_block}
+        }}
+        {builder_block}{validate_block}{walk_block}{reflect_block}{extensions_block}"#,
+        builder_block = if context.config.builders {
+            format!(
+                "\n{}",
+                elements_into_rust_builder_block(name, elements, parent_path, context)
+            )
+        } else {
+            String::new()
+        },
+        validate_block = if context.config.validation {
+            elements_into_rust_validate_block(name, elements, parent_path, context)
+        } else {
+            String::new()
+        },
+        walk_block = if context.config.visitors {
+            elements_into_rust_walk_block(name, elements, parent_path)
+        } else {
+            String::new()
+        },
+        reflect_block = if context.config.reflection {
+            elements_into_rust_reflect_block(name, elements, parent_path)
+        } else {
+            String::new()
+        },
+        extensions_block = if context.config.extensions {
+            elements_into_rust_has_extensions_block(name, elements, parent_path)
+        } else {
+            String::new()
+        },
+    )
+}
+
+/// Rust struct name of a concrete resource => Rust source code of its
+/// `resourceType` tag, a unit struct that writes the resource name, and
+/// reads only the resource name, so that a resource struct round trips
+/// without the `Resource` enum.
+///
+/// Example:
+///
+/// ```no_run
+/// resource_into_rust_resource_type_block("Patient")
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// pub struct PatientResourceType;
+/// impl Serialize for PatientResourceType { … } // "Patient"
+/// impl<'de> Deserialize<'de> for PatientResourceType { … } // "Patient" or an error
+/// ```
+///
+fn resource_into_rust_resource_type_block(name: &str) -> SourceCodeString {
+    formatdoc!(
+        r#"
+        /// The `resourceType` JSON key of a `{name}`, which is always `{name}`.
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct {name}ResourceType;
+
+        impl Serialize for {name}ResourceType {{
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                serializer.serialize_str("{name}")
+            }}
+        }}
+
+        impl<'de> Deserialize<'de> for {name}ResourceType {{
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                let value = String::deserialize(deserializer)?;
+                if value == "{name}" {{
+                    Ok(Self)
+                }} else {{
+                    Err(::serde::de::Error::invalid_value(
+                        ::serde::de::Unexpected::Str(&value),
+                        &"{name}",
+                    ))
+                }}
+            }}
+        }}
+        "#
+    )
+}
+
+/// Does any struct of the resources flatten a choice, and so need the
+/// unknown fields check module?
+pub(crate) fn resources_flatten_choice(resources: &[Resource]) -> bool {
+    resources.iter().any(|resource| {
+        resource_elements(resource)
+            .iter()
+            .any(element_is_flattened_choice)
+    })
+}
+
+/// FHIR resource => its snapshot elements, or none.
+pub(crate) fn resource_elements(resource: &Resource) -> &[Element] {
+    resource
+        .snapshot
+        .as_ref()
+        .map(|snapshot| snapshot.element.as_slice())
+        .unwrap_or_default()
+}
+
 /// FHIR resource => Rust struct attribute block of source code.
 ///
 /// Example:
@@ -153,32 +500,219 @@ pub fn resource_into_rust_struct_block(resource: &Resource) -> SourceCodeString
 /// Output is approximately like this also with indentation:
 ///
 /// ```no_run
-/// alfa: int,
-/// bravo: int,
+/// pub alfa: types::String,
+/// pub bravo: types::Integer,
 /// ```
 ///
 #[allow(dead_code)]
-pub fn resource_into_rust_struct_attribute_block(resource: &Resource) -> SourceCodeString {
-    match resource.snapshot.as_ref() {
-        Some(snapshot) => snapshot
-            .element
-            .iter()
-            .map(element_into_rust_struct_attribute)
-            .collect::<Vec<String>>()
-            .join("\n"),
+pub fn resource_into_rust_struct_attribute_block(
+    resource: &Resource,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let elements = resource_elements(resource);
+    match elements.first() {
+        Some(root) => elements_into_rust_struct_attribute_block(elements, &root.path, context),
         None => String::from(""),
     }
 }
 
+/// FHIR elements => Rust struct attribute block of source code, for the
+/// direct children of the element at the parent path.
+#[allow(dead_code)]
+pub fn elements_into_rust_struct_attribute_block(
+    elements: &[Element],
+    parent_path: &str,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    child_elements(elements, parent_path)
+        .filter(|element| Cardinality::from_element(element) != Cardinality::Prohibited)
+        .map(|element| element_into_rust_struct_attribute(element, context))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// FHIR elements => Rust struct constructor of source code, which takes the
+/// required attributes, with cardinality 1..1, and defaults the rest.
+///
+/// Example:
+///
+/// ```no_run
+/// let elements = … // e.g. Alfa.bravo 1..1, Alfa.charlie 0..1.
+/// let constructor_block = elements_into_rust_struct_constructor_block(&elements, "Alfa", &context);
+/// ```
+///
+/// Output is approximately like this also with indentation:
+///
+/// ```no_run
+/// pub fn new(bravo: types::String) -> Self {
+///     Self {
+///         bravo,
+///         ..Default::default()
+///     }
+/// }
+/// ```
+///
+#[allow(dead_code)]
+pub fn elements_into_rust_struct_constructor_block(
+    elements: &[Element],
+    parent_path: &str,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let required: Vec<(String, String)> = child_elements(elements, parent_path)
+        .filter(|element| Cardinality::from_element(element) == Cardinality::Required)
+        .map(|element| {
+            (
+                json_name_into_rust_field_name(element_into_json_name(element)),
+                element_into_rust_field_type(element, context),
+            )
+        })
+        .collect();
+    if required.is_empty() {
+        return String::from(concat!(
+            "    /// Create with every attribute set to its default.\n",
+            "    pub fn new() -> Self {\n",
+            "        Self::default()\n",
+            "    }",
+        ));
+    }
+    let parameters = required
+        .iter()
+        .map(|(name, rust_type)| format!("{name}: {rust_type}"))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let fields = required
+        .iter()
+        .map(|(name, _)| format!("            {name},\n"))
+        .collect::<String>();
+    format!(
+        concat!(
+            "    /// Create with the required attributes, and every other attribute\n",
+            "    /// set to its default.\n",
+            "    pub fn new({}) -> Self {{\n",
+            "        Self {{\n",
+            "{}",
+            "            ..Default::default()\n",
+            "        }}\n",
+            "    }}",
+        ),
+        parameters, fields,
+    )
+}
+
+/// FHIR elements => the direct children of the element at the parent path.
+pub(crate) fn child_elements<'a>(
+    elements: &'a [Element],
+    parent_path: &'a str,
+) -> impl Iterator<Item = &'a Element> {
+    elements.iter().filter(move |element| {
+        element
+            .path
+            .strip_prefix(parent_path)
+            .and_then(|rest| rest.strip_prefix('.'))
+            .is_some_and(|name| !name.contains('.'))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn element(path: &str, short: Option<&str>, code: Option<&str>) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            short: short.map(String::from),
+            r#type: code.map(|code| {
+                vec![ElementType {
+                    code: code.into(),
+                    ..ElementType::default()
+                }]
+            }),
+            ..Element::default()
+        }
+    }
 
     #[test]
     fn test_resource_into_rust() {
-        let resource = Resource::default();
-        let actual = resource_into_rust(&resource);
-        assert!(actual.is_ok());
+        let dir = std::env::temp_dir().join(format!("resource_into_rust_{}", std::process::id()));
+        let context = CodegenContext {
+            config: CodegenConfig::new().with_output_dir(&dir),
+            ..CodegenContext::default()
+        };
+        let resource = Resource {
+            id: "AlfaBravo".into(),
+            ..Resource::default()
+        };
+        resource_into_rust(&resource, &context).unwrap();
+        let actual = std::fs::read_to_string(dir.join("alfa_bravo.rs")).unwrap();
+        assert!(actual.contains("pub struct AlfaBravo {}\n"), "{}", actual);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_resource_default_json_keys() {
+        let mut url = element("Foo.url", None, Some("uri"));
+        url.min = Some(1);
+        url.max = Some(String::from("1"));
+        let mut value = element("Foo.value[x]", None, Some("string"));
+        value.min = Some(1);
+        value.max = Some(String::from("1"));
+        let resource = Resource {
+            id: "Foo".into(),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Foo", None, None),
+                    url,
+                    value,
+                    element("Foo.bravo", None, Some("string")),
+                ],
+            }),
+            ..Resource::default()
+        };
+        assert_eq!(
+            resource_default_json_keys(&resource),
+            vec!["url", "valueString"]
+        );
+        let actual = resource_into_rust_struct_block(&resource, &CodegenContext::new());
+        assert!(
+            actual.contains("let expect: Vec<&str> = vec![\"url\", \"valueString\"];"),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resource_into_rust_struct_blocks_with_resource_type() {
+        let resource = Resource {
+            id: "Foo".into(),
+            kind: Some(String::from("resource")),
+            r#abstract: Some(false),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Foo", None, None),
+                    element("Foo.alfa", None, Some("string")),
+                ],
+            }),
+            ..Resource::default()
+        };
+        assert_eq!(resource_default_json_keys(&resource), vec!["resourceType"]);
+        let actual = resource_into_rust_struct_blocks(&resource, &CodegenContext::new());
+        assert!(
+            actual.contains("pub struct Foo {\n    /// The `resourceType` JSON key, which is always `Foo`.\n    pub resource_type: FooResourceType,\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("pub struct FooResourceType;\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("serializer.serialize_str(\"Foo\")"),
+            "{}",
+            actual
+        );
     }
 
     #[test]
@@ -186,38 +720,74 @@ mod tests {
         let resource = Resource {
             snapshot: Some(Snapshot {
                 element: vec![
-                    Element {
-                        id: "Foo.alfa".into(),
-                        short: Some(String::from("Short comment 0")),
-                        ..Element::default()
-                    },
-                    Element {
-                        id: "Foo.bravo".into(),
-                        short: Some(String::from("Short comment 1")),
-                        ..Element::default()
-                    },
+                    element("Foo", None, None),
+                    element("Foo.alfa", Some("Short comment 0"), Some("string")),
+                    element("Foo.bravo", Some("Short comment 1"), Some("Element")),
+                    element("Foo.bravo.charlie", Some("Short comment 2"), Some("uri")),
                 ],
             }),
             ..Resource::default()
         };
-        let actual = resource_into_rust_struct_attribute_block(&resource);
+        let actual = resource_into_rust_struct_attribute_block(&resource, &CodegenContext::new());
         let expect = concat!(
             "    /// Short comment 0\n",
-            "    alfa: ? // ?\n",
+            "    ///\n",
+            "    /// Types: `string`\n",
+            "    pub alfa: Option<types::String>,\n",
+            "    /// Id and extensions of `alfa`\n",
+            "    #[serde(rename = \"_alfa\")]\n",
+            "    pub alfa_element: Option<types::Element>,\n",
             "\n",
             "    /// Short comment 1\n",
-            "    bravo: ? // ?\n",
+            "    ///\n",
+            "    /// Types: `Element`\n",
+            "    pub bravo: Option<FooBravo>,\n",
         );
         assert_eq!(actual, expect);
     }
 
+    #[test]
+    fn test_elements_into_rust_struct_constructor_block() {
+        let mut bravo = element("Foo.bravo", None, Some("string"));
+        bravo.min = Some(1);
+        bravo.max = Some(String::from("1"));
+        let mut charlie = element("Foo.type", None, Some("uri"));
+        charlie.min = Some(1);
+        charlie.max = Some(String::from("*"));
+        let elements = vec![
+            element("Foo", None, None),
+            bravo,
+            charlie,
+            element("Foo.delta", None, Some("uri")),
+        ];
+        let actual =
+            elements_into_rust_struct_constructor_block(&elements, "Foo", &CodegenContext::new());
+        let expect = concat!(
+            "    /// Create with the required attributes, and every other attribute\n",
+            "    /// set to its default.\n",
+            "    pub fn new(bravo: types::String) -> Self {\n",
+            "        Self {\n",
+            "            bravo,\n",
+            "            ..Default::default()\n",
+            "        }\n",
+            "    }",
+        );
+        assert_eq!(actual, expect);
+        let actual = elements_into_rust_struct_constructor_block(
+            &elements[..1],
+            "Foo",
+            &CodegenContext::new(),
+        );
+        assert!(actual.contains("pub fn new() -> Self {\n"), "{}", actual);
+    }
+
     #[test]
     fn test_resource_into_rust_struct_path() {
         let resource = Resource {
             id: "AlfaBravoCharlie".into(),
             ..Resource::default()
         };
-        let actual = resource_into_rust_struct_path(&resource);
+        let actual = resource_into_rust_struct_path(&resource, &CodegenConfig::new());
         assert!(
             actual
                 .to_string_lossy()
@@ -233,7 +803,248 @@ mod tests {
             id: "AlfaBravoCharlie".into(),
             ..Resource::default()
         };
-        let actual = resource_into_rust_struct_block(&resource);
+        let actual = resource_into_rust_struct_block(&resource, &CodegenContext::new());
         assert!(actual.contains("//! AlfaBravoCharlie\n"), "{}", actual);
     }
+
+    #[test]
+    fn test_resource_into_rust_struct_blocks() {
+        let resource = Resource {
+            id: "Foo".into(),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Foo", None, None),
+                    element("Foo.bravo", Some("Short comment 1"), Some("Element")),
+                    element("Foo.bravo.charlie", Some("Short comment 2"), Some("uri")),
+                ],
+            }),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_struct_blocks(&resource, &CodegenContext::new());
+        assert!(actual.contains("pub struct Foo {\n"), "{}", actual);
+        assert!(
+            actual.contains("pub bravo: Option<FooBravo>,\n"),
+            "{}",
+            actual
+        );
+        assert!(actual.contains("pub struct FooBravo {\n"), "{}", actual);
+        assert!(
+            actual.contains("pub charlie: Option<types::Uri>,\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("#[serde(deny_unknown_fields)]\n"),
+            "{}",
+            actual
+        );
+    }
+
+    fn address_use_context() -> CodegenContext {
+        let value_sets: Vec<crate::r5::parse::value_sets::Resource> =
+            ::serde_json::from_value(::serde_json::json!([
+                {
+                    "resourceType": "CodeSystem",
+                    "id": "address-use",
+                    "url": "http://hl7.org/fhir/address-use",
+                    "status": "active",
+                    "content": "complete",
+                    "concept": [{ "code": "home" }, { "code": "work" }]
+                },
+                {
+                    "resourceType": "ValueSet",
+                    "id": "address-use",
+                    "url": "http://hl7.org/fhir/ValueSet/address-use",
+                    "status": "active",
+                    "compose": { "include": [{ "system": "http://hl7.org/fhir/address-use" }] }
+                },
+                {
+                    "resourceType": "ValueSet",
+                    "id": "alfa-address-use",
+                    "url": "http://example.org/ValueSet/alfa-address-use",
+                    "status": "active",
+                    "compose": { "include": [{ "system": "http://hl7.org/fhir/address-use" }] }
+                }
+            ]))
+            .unwrap();
+        CodegenContext {
+            value_sets: value_sets.into_iter().collect(),
+            ..CodegenContext::default()
+        }
+    }
+
+    fn address_use_element(path: &str, value_set: &str) -> Element {
+        let mut element = element(path, None, Some("code"));
+        element.binding = ::serde_json::from_value(::serde_json::json!({
+            "extension": [{ "url": BINDING_NAME_URL, "valueString": "AddressUse" }],
+            "strength": "required",
+            "valueSet": value_set
+        }))
+        .unwrap();
+        element
+    }
+
+    #[test]
+    fn test_resource_into_rust_struct_blocks_with_code_enum() {
+        let value_set = "http://hl7.org/fhir/ValueSet/address-use|5.0.0";
+        let resource = Resource {
+            id: "Foo".into(),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Foo", None, None),
+                    address_use_element("Foo.use", value_set),
+                    address_use_element("Foo.otherUse", value_set),
+                ],
+            }),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_struct_blocks(&resource, &address_use_context());
+        assert!(
+            actual.contains("pub r#use: Option<AddressUse>,\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("pub other_use: Option<AddressUse>,\n"),
+            "{}",
+            actual
+        );
+        assert_eq!(
+            actual.matches("pub enum AddressUse {\n").count(),
+            1,
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("    #[serde(rename = \"work\")]\n    Work,\n"),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Foo: the code enum AddressUse binds both")]
+    fn test_resource_into_rust_struct_blocks_with_code_enum_name_clash() {
+        let resource = Resource {
+            id: "Foo".into(),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Foo", None, None),
+                    address_use_element("Foo.use", "http://hl7.org/fhir/ValueSet/address-use"),
+                    address_use_element(
+                        "Foo.otherUse",
+                        "http://example.org/ValueSet/alfa-address-use",
+                    ),
+                ],
+            }),
+            ..Resource::default()
+        };
+        resource_into_rust_struct_blocks(&resource, &address_use_context());
+    }
+
+    #[test]
+    fn test_resource_into_rust_struct_blocks_with_choice() {
+        let resource = Resource {
+            id: "Foo".into(),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Foo", None, None),
+                    element("Foo.value[x]", Some("Short comment 1"), Some("string")),
+                ],
+            }),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_struct_blocks(&resource, &CodegenContext::new());
+        assert!(
+            actual.contains(concat!(
+                "    #[serde(flatten, deserialize_with = \"types::Choice::deserialize_option\")]\n",
+                "    pub value: Option<FooValue>,\n",
+            )),
+            "{}",
+            actual
+        );
+        assert!(actual.contains("pub enum FooValue {\n"), "{}", actual);
+        assert!(
+            !actual.contains("#[serde(deny_unknown_fields)]\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(
+                "    #[serde(flatten)]\n    pub unknown_fields: types::DenyUnknownFields,\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resource_into_rust_struct_blocks_with_primitive() {
+        let resource = Resource {
+            id: "dateTime".into(),
+            kind: Some(String::from("primitive-type")),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("dateTime", None, None),
+                    element(
+                        "dateTime.value",
+                        None,
+                        Some("http://hl7.org/fhirpath/System.DateTime"),
+                    ),
+                ],
+            }),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_struct_blocks(&resource, &CodegenContext::new());
+        assert!(actual.contains("#[serde(transparent)]\npub struct DateTime {\n    pub value: std::string::String,\n}\n"), "{}", actual);
+        assert!(!actual.contains("DateTimeValue"), "{}", actual);
+    }
+
+    #[test]
+    fn test_resource_into_rust_struct_blocks_with_boxed_paths() {
+        let resource = Resource {
+            id: "Reference".into(),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Reference", None, None),
+                    element("Reference.identifier", None, Some("Identifier")),
+                ],
+            }),
+            ..Resource::default()
+        };
+        let context = CodegenContext {
+            boxed_paths: [String::from("Reference.identifier")].into(),
+            ..CodegenContext::default()
+        };
+        let actual = resource_into_rust_struct_blocks(&resource, &context);
+        assert!(
+            actual.contains("pub identifier: Option<Box<types::Identifier>>,\n"),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resource_into_rust_struct_blocks_with_builders() {
+        let resource = Resource {
+            id: "Foo".into(),
+            snapshot: Some(Snapshot {
+                element: vec![
+                    element("Foo", None, None),
+                    element("Foo.bravo", Some("Short comment 1"), Some("Element")),
+                ],
+            }),
+            ..Resource::default()
+        };
+        let mut context = CodegenContext::new();
+        assert!(!resource_into_rust_struct_blocks(&resource, &context).contains("Builder"));
+        context.config.builders = true;
+        let actual = resource_into_rust_struct_blocks(&resource, &context);
+        assert!(actual.contains("pub struct FooBuilder {\n"), "{}", actual);
+        assert!(
+            actual.contains("pub struct FooBravoBuilder {\n"),
+            "{}",
+            actual
+        );
+    }
 }
@@ -0,0 +1,261 @@
+//! Resource into Rust primitive
+//!
+//! A FHIR primitive type, such as `boolean` or `dateTime`, is a bare JSON
+//! value, such as `true` or `"2025-01-01"`. Its id and extensions go in a
+//! sibling JSON key with an underscore, such as `_birthDate`, which the
+//! parent struct holds as a `types::Element`.
+//!
+//! The Rust struct wraps the value, and serializes as the bare JSON value.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+
+/// Given one FHIR primitive type id, return the Rust type of its value.
+///
+/// Example:
+///
+/// ```no_run
+/// primitive_into_rust_value_type("boolean") => "bool"
+/// primitive_into_rust_value_type("dateTime") => "std::string::String"
+/// ```
+///
+/// The string type is always qualified, because the `string` primitive
+/// generates a struct named `String`.
+///
+#[allow(dead_code)]
+pub fn primitive_into_rust_value_type(id: &str) -> SourceCodeString {
+    String::from(match id {
+        "boolean" => "bool",
+        "integer" => "i32",
+        "positiveInt" | "unsignedInt" => "u32",
+        "integer64" => "i64",
+        // A JSON number is Eq, and keeps the exact decimal digits, such as
+        // `1.50`, with the serde_json `arbitrary_precision` feature.
+        "decimal" => "::serde_json::Number",
+        _ => "std::string::String",
+    })
+}
+
+/// Is the JSON value of the primitive a string of digits, such as the
+/// `integer64` value `"9007199254740993"`?
+fn primitive_is_string_of_digits(id: &str) -> bool {
+    id == "integer64"
+}
+
+/// Given one primitive type id, return the JSON of its default value, such
+/// as `false` for `boolean`, or `"0"` for `integer64`.
+pub(crate) fn primitive_default_json(id: &str) -> &'static str {
+    match primitive_into_rust_value_type(id).as_str() {
+        "bool" => "false",
+        _ if primitive_is_string_of_digits(id) => "\"0\"",
+        "i32" | "u32" | "::serde_json::Number" => "0",
+        _ => "\"\"",
+    }
+}
+
+/// Given one primitive type id, return the other Rust types that convert
+/// into its value type, so that a builder takes a literal, such as
+/// `.unit("mg")` or `.value(1)` for a `decimal`.
+fn primitive_from_rust_types(id: &str) -> &'static [&'static str] {
+    match primitive_into_rust_value_type(id).as_str() {
+        "std::string::String" => &["&str"],
+        "::serde_json::Number" => &["i32", "i64", "u32", "u64"],
+        _ => &[],
+    }
+}
+
+/// Does the Rust type of the primitive value implement `Default`?
+///
+/// A `serde_json::Number` does not, so its struct implements `Default` as
+/// zero.
+fn primitive_has_default_value(id: &str) -> bool {
+    id != "decimal"
+}
+
+/// FHIR primitive type resource => Rust struct block of source code.
+///
+/// Example:
+///
+/// ```no_run
+/// let resource = … // e.g. resource id boolean.
+/// let source_code_string = resource_into_rust_primitive_block(&resource);
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// #[serde(transparent)]
+/// pub struct Boolean {
+///     pub value: bool,
+/// }
+/// ```
+///
+#[allow(dead_code)]
+pub fn resource_into_rust_primitive_block(resource: &Resource) -> SourceCodeString {
+    let name = resource.id.to_case(Case::Pascal);
+    let value_type = primitive_into_rust_value_type(&resource.id);
+    let (serde_as, value_serde_as) = if primitive_is_string_of_digits(&resource.id) {
+        (
+            "#[serde_with::serde_as]\n",
+            "    #[serde_as(as = \"::serde_with::DisplayFromStr\")]\n",
+        )
+    } else if resource.id == "decimal" {
+        (
+            "",
+            "    /// The exact digits, such as `1.50`, with the serde_json\n    /// `arbitrary_precision` feature.\n",
+        )
+    } else {
+        ("", "")
+    };
+    let (derive_default, impl_default) = if primitive_has_default_value(&resource.id) {
+        ("Default, ", String::new())
+    } else {
+        (
+            "",
+            formatdoc!(
+                r#"
+
+                impl Default for {name} {{
+                    fn default() -> Self {{
+                        Self::new({value_type}::from(0))
+                    }}
+                }}
+                "#
+            ),
+        )
+    };
+    let impl_from = primitive_from_rust_types(&resource.id)
+        .iter()
+        .map(|from_type| {
+            formatdoc!(
+                r#"
+
+                impl From<{from_type}> for {name} {{
+                    fn from(value: {from_type}) -> Self {{
+                        Self {{ value: value.into() }}
+                    }}
+                }}
+                "#
+            )
+        })
+        .collect::<String>();
+    formatdoc!(
+        r#"
+        /// Wrap the value of the FHIR `{id}` primitive type.
+        {serde_as}#[derive(Debug, {derive_default}Clone, Serialize, Deserialize, PartialEq, Eq)]
+        /// Serialize as the bare JSON value, without a wrapping object.
+        #[serde(transparent)]
+        pub struct {name} {{
+        {value_serde_as}    pub value: {value_type},
+        }}
+
+        impl {name} {{
+            /// Create with the value.
+            pub fn new(value: {value_type}) -> Self {{
+                Self {{ value }}
+            }}
+        }}
+
+        impl From<{value_type}> for {name} {{
+            fn from(value: {value_type}) -> Self {{
+                Self {{ value }}
+            }}
+        }}
+        {impl_from}{impl_default}"#,
+        id = resource.id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitive_into_rust_value_type() {
+        let f = primitive_into_rust_value_type;
+        assert_eq!(f("boolean"), "bool");
+        assert_eq!(f("integer"), "i32");
+        assert_eq!(f("unsignedInt"), "u32");
+        assert_eq!(f("integer64"), "i64");
+        assert_eq!(f("decimal"), "::serde_json::Number");
+        assert_eq!(f("string"), "std::string::String");
+        assert_eq!(f("dateTime"), "std::string::String");
+    }
+
+    #[test]
+    fn test_resource_into_rust_primitive_block() {
+        let resource = Resource {
+            id: "boolean".into(),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_primitive_block(&resource);
+        assert!(
+            actual
+                .contains("#[serde(transparent)]\npub struct Boolean {\n    pub value: bool,\n}\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("impl From<bool> for Boolean {\n"),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resource_into_rust_primitive_block_with_integer_64() {
+        let resource = Resource {
+            id: "integer64".into(),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_primitive_block(&resource);
+        assert!(actual.starts_with("/// Wrap the value of the FHIR `integer64` primitive type.\n#[serde_with::serde_as]\n"), "{}", actual);
+        assert!(
+            actual.contains(
+                "    #[serde_as(as = \"::serde_with::DisplayFromStr\")]\n    pub value: i64,\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resource_into_rust_primitive_block_with_decimal() {
+        let resource = Resource {
+            id: "decimal".into(),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_primitive_block(&resource);
+        assert!(
+            actual.contains("#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("impl Default for Decimal {\n    fn default() -> Self {\n        Self::new(::serde_json::Number::from(0))\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("impl From<i32> for Decimal {\n    fn from(value: i32) -> Self {\n        Self { value: value.into() }\n"),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resource_into_rust_primitive_block_with_string() {
+        let resource = Resource {
+            id: "string".into(),
+            ..Resource::default()
+        };
+        let actual = resource_into_rust_primitive_block(&resource);
+        assert!(
+            actual.contains("impl From<&str> for String {\n"),
+            "{}",
+            actual
+        );
+    }
+}
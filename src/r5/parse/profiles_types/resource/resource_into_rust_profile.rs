@@ -0,0 +1,790 @@
+//! Resource into Rust profile
+//!
+//! A constraint profile, such as `SimpleQuantity`, narrows a base type, such
+//! as `Quantity`: it narrows cardinalities, fixes or patterns values, and
+//! slices repeated elements. Its JSON is the JSON of the base type.
+//!
+//! The Rust struct wraps a value of the base type, and checks the rules of
+//! the profile when it converts from the base type, or deserializes:
+//!
+//! ```no_run
+//! let quantity = types::Quantity::default();
+//! let simple_quantity = types::SimpleQuantity::try_from(quantity)?;
+//! ```
+//!
+//! The rules come from the differential of the profile. A FHIRPath
+//! invariant, such as `sqty-1`, is in the doc comment, but is not checked.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::resource_elements;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+use ::serde_json::Value;
+
+/// The module name of the generated profile rules.
+pub const PROFILE_MODULE: &str = "profile";
+
+/// The Rust source code of the generated profile rules module.
+pub const PROFILE_RULES_BLOCK: &str = r#"//! Profile
+//!
+//! The rules of a constraint profile, checked on the JSON of a value of its
+//! base type.
+//!
+//! A rule path is a FHIR element path, such as `Quantity.comparator`. A
+//! choice element, such as `Extension.value[x]`, matches any of its JSON
+//! keys, such as `valueString`.
+
+use ::serde::Serialize;
+use ::serde_json::Value;
+
+/// One rule of a constraint profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileRule {
+    /// Each parent has between min and max values of the element, or at
+    /// least min when max is none.
+    Cardinality {
+        path: &'static str,
+        min: usize,
+        max: Option<usize>,
+    },
+
+    /// Each value of the element equals the JSON value.
+    Fixed {
+        path: &'static str,
+        value: &'static str,
+    },
+
+    /// Each value of the element has every part of the JSON value.
+    Pattern {
+        path: &'static str,
+        value: &'static str,
+    },
+
+    /// Each parent has between min and max values of the element that
+    /// match the slice: at each discriminator path, such as `url`, a value
+    /// has every part of the JSON value.
+    Slice {
+        path: &'static str,
+        name: &'static str,
+        discriminators: &'static [(&'static str, &'static str)],
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+/// One rule that a value fails, such as `Quantity.comparator` with the
+/// message `has 1 values, but allows 0..0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileIssue {
+    pub path: String,
+    pub message: String,
+}
+
+/// Every rule of a profile that a value fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileIssues {
+    /// The canonical URL of the profile.
+    pub profile: &'static str,
+    pub issues: Vec<ProfileIssue>,
+}
+
+impl std::fmt::Display for ProfileIssues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "does not conform to profile {}: ", self.profile)?;
+        let issues: Vec<String> = self
+            .issues
+            .iter()
+            .map(|issue| format!("{} {}", issue.path, issue.message))
+            .collect();
+        write!(f, "{}", issues.join("; "))
+    }
+}
+
+impl std::error::Error for ProfileIssues {}
+
+/// Check every rule on the JSON of the value.
+pub fn check_profile_rules<T: Serialize>(
+    profile: &'static str,
+    value: &T,
+    rules: &[ProfileRule],
+) -> Result<(), ProfileIssues> {
+    let mut issues = vec![];
+    match ::serde_json::to_value(value) {
+        Ok(json) => {
+            for rule in rules {
+                check_rule(&json, rule, &mut issues);
+            }
+        }
+        Err(error) => issues.push(ProfileIssue {
+            path: String::new(),
+            message: error.to_string(),
+        }),
+    }
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(ProfileIssues { profile, issues })
+    }
+}
+
+/// Does the item match each discriminator of a slice?
+pub fn slice_matches<T: Serialize>(item: &T, discriminators: &[(&str, &str)]) -> bool {
+    ::serde_json::to_value(item).is_ok_and(|json| json_slice_matches(&json, discriminators))
+}
+
+fn check_rule(json: &Value, rule: &ProfileRule, issues: &mut Vec<ProfileIssue>) {
+    let mut issue = |path: &str, message: String| {
+        issues.push(ProfileIssue {
+            path: String::from(path),
+            message,
+        })
+    };
+    match *rule {
+        ProfileRule::Cardinality { path, min, max } => {
+            for count in counts(json, path, |_| true) {
+                if count < min || max.is_some_and(|max| count > max) {
+                    issue(
+                        path,
+                        format!("has {count} values, but allows {}", range(min, max)),
+                    );
+                }
+            }
+        }
+        ProfileRule::Fixed { path, value } => {
+            let expect: Value = ::serde_json::from_str(value).unwrap_or_default();
+            if select(json, relative_path(path)).any(|x| *x != expect) {
+                issue(path, format!("is not the fixed value {value}"));
+            }
+        }
+        ProfileRule::Pattern { path, value } => {
+            let expect: Value = ::serde_json::from_str(value).unwrap_or_default();
+            if select(json, relative_path(path)).any(|x| !contains(x, &expect)) {
+                issue(path, format!("does not match the pattern {value}"));
+            }
+        }
+        ProfileRule::Slice {
+            path,
+            name,
+            discriminators,
+            min,
+            max,
+        } => {
+            for count in counts(json, path, |x| json_slice_matches(x, discriminators)) {
+                if count < min || max.is_some_and(|max| count > max) {
+                    issue(
+                        path,
+                        format!(
+                            "has {count} values of slice {name}, but allows {}",
+                            range(min, max)
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn range(min: usize, max: Option<usize>) -> String {
+    match max {
+        Some(max) => format!("{min}..{max}"),
+        None => format!("{min}..*"),
+    }
+}
+
+/// Strip the type name of an element path, such as `Quantity.` of
+/// `Quantity.comparator`.
+fn relative_path(path: &str) -> &str {
+    path.split_once('.').map(|x| x.1).unwrap_or_default()
+}
+
+/// For each parent of the element, count its values that match.
+fn counts(json: &Value, path: &str, matches: impl Fn(&Value) -> bool) -> Vec<usize> {
+    let path = relative_path(path);
+    let (parent, key) = path.rsplit_once('.').unwrap_or(("", path));
+    select(json, parent)
+        .map(|parent| children(parent, key).filter(|x| matches(x)).count())
+        .collect()
+}
+
+fn json_slice_matches(json: &Value, discriminators: &[(&str, &str)]) -> bool {
+    discriminators.iter().all(|(path, value)| {
+        let expect: Value = ::serde_json::from_str(value).unwrap_or_default();
+        select(json, path).any(|x| contains(x, &expect))
+    })
+}
+
+/// Select the values at a dotted path, such as `coding.code`, with each
+/// array flattened.
+fn select<'a>(json: &'a Value, path: &'a str) -> Box<dyn Iterator<Item = &'a Value> + 'a> {
+    path.split('.')
+        .filter(|key| !key.is_empty() && *key != "$this")
+        .fold(
+            Box::new(std::iter::once(json)) as Box<dyn Iterator<Item = &'a Value> + 'a>,
+            |values, key| Box::new(values.flat_map(move |value| children(value, key))),
+        )
+}
+
+/// Select the values of one key, such as `code`, or of a choice key, such
+/// as `value[x]`, with an array flattened.
+fn children<'a>(json: &'a Value, key: &'a str) -> impl Iterator<Item = &'a Value> + 'a {
+    json.as_object()
+        .into_iter()
+        .flatten()
+        .filter(move |(name, _)| match key.strip_suffix("[x]") {
+            Some(prefix) => name
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase())),
+            None => *name == key,
+        })
+        .flat_map(|(_, value)| match value {
+            Value::Array(items) => items.iter().collect::<Vec<&Value>>(),
+            value => vec![value],
+        })
+}
+
+/// Does the value have every part of the pattern? Each key of a pattern
+/// object is in the value, and each item of a pattern array matches an item
+/// of the value.
+fn contains(value: &Value, pattern: &Value) -> bool {
+    match (value, pattern) {
+        (Value::Object(value), Value::Object(pattern)) => pattern
+            .iter()
+            .all(|(key, x)| value.get(key).is_some_and(|y| contains(y, x))),
+        (Value::Array(value), Value::Array(pattern)) => {
+            pattern.iter().all(|x| value.iter().any(|y| contains(y, x)))
+        }
+        (value, pattern) => value == pattern,
+    }
+}
+"#;
+
+/// Is the resource a constraint profile of a complex type or resource, such
+/// as `SimpleQuantity` of `Quantity`?
+#[allow(dead_code)]
+pub fn resource_is_constraint_profile(resource: &Resource) -> bool {
+    resource.derivation.as_deref() == Some("constraint")
+        && matches!(resource.kind.as_deref(), Some("complex-type" | "resource"))
+        && resource
+            .r#type
+            .as_deref()
+            .is_some_and(|x| x.to_case(Case::Pascal) != resource.id.to_case(Case::Pascal))
+}
+
+/// Given one element, return its fixed value as JSON, such as the
+/// `fixedUri` value `"urn:iso:std:iso:4217"`.
+#[allow(dead_code)]
+pub fn element_fixed_value(element: &Element) -> Option<Value> {
+    let strings = [
+        &element.fixed_code,
+        &element.fixed_markdown,
+        &element.fixed_string,
+        &element.fixed_uri,
+    ];
+    strings
+        .into_iter()
+        .find_map(|x| x.clone().map(Value::String))
+        .or_else(|| {
+            element
+                .fixed_codeable_concept
+                .as_ref()
+                .and_then(|x| ::serde_json::to_value(x).ok())
+        })
+        .or_else(|| element.fixed_quantity.clone())
+        .or_else(|| element.fixed_value.clone())
+}
+
+/// Given one element, return its pattern value as JSON, such as the
+/// `patternCodeableConcept` value `{"coding": [{"code": "vital-signs"}]}`.
+#[allow(dead_code)]
+pub fn element_pattern_value(element: &Element) -> Option<Value> {
+    element
+        .pattern_code
+        .clone()
+        .map(Value::String)
+        .or_else(|| {
+            element
+                .pattern_codeable_concept
+                .as_ref()
+                .and_then(|x| ::serde_json::to_value(x).ok())
+        })
+        .or_else(|| element.pattern_value.clone())
+}
+
+/// Given one JSON value, return it as a Rust string literal of its JSON
+/// text, such as `"\"urn:iso:std:iso:4217\""`.
+fn json_into_rust_literal(value: &Value) -> SourceCodeString {
+    format!("{:?}", value.to_string())
+}
+
+/// Given a max cardinality, such as `"1"` or `"*"`, return its Rust option,
+/// such as `Some(1)` or `None`.
+fn max_into_rust_option(max: Option<&str>) -> SourceCodeString {
+    match max.and_then(|x| x.parse::<usize>().ok()) {
+        Some(max) => format!("Some({max})"),
+        None => String::from("None"),
+    }
+}
+
+/// The elements of the differential of the resource, or none.
+fn differential_elements(resource: &Resource) -> &[Element] {
+    resource
+        .differential
+        .as_ref()
+        .map(|differential| differential.element.as_slice())
+        .unwrap_or_default()
+}
+
+/// Find the element by its id, in the snapshot, else in the differential.
+fn find_element<'a>(resource: &'a Resource, id: &str) -> Option<&'a Element> {
+    resource_elements(resource)
+        .iter()
+        .chain(differential_elements(resource))
+        .find(|x| x.id == id)
+}
+
+/// Given one slice element, such as `Observation.category:VSCat`, return
+/// each discriminator path and the JSON value that a matching item has
+/// there, as Rust tuples.
+///
+/// A `value`, `pattern`, or `fixed` discriminator takes its value from the
+/// child element at its path, such as `Extension.extension:foo.url`, else
+/// from the pattern of the slice. Any other discriminator, such as `type`,
+/// or a path with a function, such as `resolve()`, is not supported.
+///
+fn slice_discriminators(resource: &Resource, slice: &Element) -> Vec<SourceCodeString> {
+    let Some((sliced_id, _)) = slice.id.rsplit_once(':') else {
+        return vec![];
+    };
+    let Some(slicing) = find_element(resource, sliced_id).and_then(|x| x.slicing.as_ref()) else {
+        return vec![];
+    };
+    slicing
+        .discriminator
+        .iter()
+        .filter(|x| matches!(x.r#type.as_str(), "value" | "pattern" | "fixed"))
+        .filter(|x| !x.path.contains('('))
+        .filter_map(|discriminator| {
+            let value = if discriminator.path == "$this" {
+                element_fixed_value(slice).or_else(|| element_pattern_value(slice))
+            } else {
+                find_element(resource, &format!("{}.{}", slice.id, discriminator.path))
+                    .and_then(|x| element_fixed_value(x).or_else(|| element_pattern_value(x)))
+                    .or_else(|| {
+                        let pattern =
+                            element_fixed_value(slice).or_else(|| element_pattern_value(slice))?;
+                        json_at_path(&pattern, &discriminator.path)
+                    })
+            }?;
+            Some(format!(
+                "({:?}, {})",
+                discriminator.path,
+                json_into_rust_literal(&value)
+            ))
+        })
+        .collect()
+}
+
+/// Given a JSON value and a dotted path, such as `coding.code`, return the
+/// value at the path, through the first item of each array.
+fn json_at_path(json: &Value, path: &str) -> Option<Value> {
+    let mut value = json;
+    for key in path.split('.') {
+        if let Value::Array(items) = value {
+            value = items.first()?;
+        }
+        value = value.get(key)?;
+    }
+    match value {
+        Value::Array(items) => items.first().cloned(),
+        value => Some(value.clone()),
+    }
+}
+
+/// FHIR constraint profile => the Rust expression of each of its rules.
+///
+/// Example:
+///
+/// ```no_run
+/// let resource = … // e.g. resource id SimpleQuantity.
+/// resource_profile_rules(&resource)
+/// ```
+///
+/// Output:
+///
+/// ```no_run
+/// ["types::ProfileRule::Cardinality { path: \"Quantity.comparator\", min: 0, max: Some(0) }"]
+/// ```
+///
+/// An element of the differential with a min or max has a cardinality
+/// rule, with a fixed value has a fixed rule, with a pattern has a pattern
+/// rule, and with a slice name has a slice rule. An element inside a slice,
+/// such as `Observation.category:VSCat.coding`, is part of the slice rule.
+///
+#[allow(dead_code)]
+pub fn resource_profile_rules(resource: &Resource) -> Vec<SourceCodeString> {
+    let mut rules = vec![];
+    for element in differential_elements(resource) {
+        if !element.path.contains('.') {
+            continue;
+        }
+        let snapshot = find_element(resource, &element.id).unwrap_or(element);
+        let min = snapshot.min.unwrap_or_default();
+        let max = max_into_rust_option(snapshot.max.as_deref());
+        let path = &element.path;
+        if element.slice_name.is_some() {
+            let discriminators = slice_discriminators(resource, element);
+            if !discriminators.is_empty() {
+                rules.push(format!(
+                    "types::ProfileRule::Slice {{ path: {path:?}, name: {:?}, discriminators: &[{}], min: {min}, max: {max} }}",
+                    element.slice_name.as_deref().unwrap_or_default(),
+                    discriminators.join(", "),
+                ));
+            }
+            continue;
+        }
+        if element.id.contains(':') {
+            continue;
+        }
+        if element.min.is_some() || element.max.is_some() {
+            rules.push(format!(
+                "types::ProfileRule::Cardinality {{ path: {path:?}, min: {min}, max: {max} }}"
+            ));
+        }
+        if let Some(value) = element_fixed_value(element) {
+            rules.push(format!(
+                "types::ProfileRule::Fixed {{ path: {path:?}, value: {} }}",
+                json_into_rust_literal(&value)
+            ));
+        }
+        if let Some(value) = element_pattern_value(element) {
+            rules.push(format!(
+                "types::ProfileRule::Pattern {{ path: {path:?}, value: {} }}",
+                json_into_rust_literal(&value)
+            ));
+        }
+    }
+    rules
+}
+
+/// FHIR constraint profile => Rust methods that return the items of each
+/// slice of a repeated attribute of the base type, such as
+/// `category_vs_cat` for `Observation.category:VSCat`.
+fn resource_profile_slice_accessors(resource: &Resource, context: &CodegenContext) -> String {
+    let base = Resource {
+        id: resource.r#type.clone().unwrap_or_default(),
+        kind: resource.kind.clone(),
+        ..Resource::default()
+    };
+    let base_module = match context.config.group_name(&base) {
+        Some(group) => format!("{group}::{}", context.config.module_name(&base)),
+        None => context.config.module_name(&base),
+    };
+    let mut block = String::new();
+    for slice in differential_elements(resource) {
+        let Some(slice_name) = slice.slice_name.as_deref() else {
+            continue;
+        };
+        let Some((sliced_id, _)) = slice.id.rsplit_once(':') else {
+            continue;
+        };
+        let Some(sliced) = find_element(resource, sliced_id) else {
+            continue;
+        };
+        let discriminators = slice_discriminators(resource, slice);
+        if sliced.path.matches('.').count() != 1
+            || sliced.id.contains(':')
+            || element_is_choice(sliced)
+            || Cardinality::from_element(sliced) != Cardinality::Repeated
+            || discriminators.is_empty()
+        {
+            continue;
+        }
+        let json_name = element_into_json_name(sliced);
+        let item_type = element_into_rust_item_type(sliced, context);
+        let item_type = if item_type.contains("::")
+            || matches!(
+                item_type.as_str(),
+                "String" | "bool" | "i32" | "i64" | "u32"
+            ) {
+            item_type
+        } else {
+            format!("types::{base_module}::{item_type}")
+        };
+        block.push_str(&format!(
+            "\n    /// The items of the `{slice_name}` slice of `{path}`.\n    pub fn {method}(&self) -> Vec<&{item_type}> {{\n        self.0\n            .{field}\n            .iter()\n            .filter(|item| types::profile::slice_matches(*item, &[{discriminators}]))\n            .collect()\n    }}\n",
+            path = sliced.path,
+            method = json_name_into_rust_field_name(&format!(
+                "{json_name}{}",
+                slice_name.to_case(Case::Pascal)
+            )),
+            field = json_name_into_rust_field_name(json_name),
+            discriminators = discriminators.join(", "),
+        ));
+    }
+    block
+}
+
+/// FHIR constraint profile => Rust newtype block of source code, that wraps
+/// the base type.
+///
+/// Example:
+///
+/// ```no_run
+/// let resource = … // e.g. resource id SimpleQuantity.
+/// let source_code_string = resource_into_rust_profile_block(&resource, &context);
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// #[serde(try_from = "types::Quantity", into = "types::Quantity")]
+/// pub struct SimpleQuantity(types::Quantity);
+///
+/// impl TryFrom<types::Quantity> for SimpleQuantity { … }
+/// ```
+///
+#[allow(dead_code)]
+pub fn resource_into_rust_profile_block(
+    resource: &Resource,
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let name = resource.id.to_case(Case::Pascal);
+    let base_name = resource
+        .r#type
+        .as_deref()
+        .unwrap_or_default()
+        .to_case(Case::Pascal);
+    let doc_comment = resource_elements(resource)
+        .first()
+        .map(|root| format!("{}///\n", element_into_rust_doc_comment(root, "")))
+        .unwrap_or_default();
+    let rules = resource_profile_rules(resource);
+    let rules_block = if rules.is_empty() {
+        String::from("&[]")
+    } else {
+        format!(
+            "&[\n{}    ]",
+            rules
+                .iter()
+                .map(|rule| format!("        {rule},\n"))
+                .collect::<String>()
+        )
+    };
+    formatdoc!(
+        r#"
+        {doc_comment}/// A `{base_name}` that satisfies the rules of the profile.
+        #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+        /// Check the rules when deserializing, and serialize as the base type.
+        #[serde(try_from = "types::{base_name}", into = "types::{base_name}")]
+        pub struct {name}(types::{base_name});
+
+        impl {name} {{
+            /// The canonical URL of the profile.
+            pub const URL: &'static str = "{url}";
+
+            /// The rules of the profile, beyond the rules of `{base_name}`.
+            pub const RULES: &'static [types::ProfileRule] = {rules_block};
+
+            /// Borrow the base value.
+            pub fn as_inner(&self) -> &types::{base_name} {{
+                &self.0
+            }}
+
+            /// Unwrap the base value.
+            pub fn into_inner(self) -> types::{base_name} {{
+                self.0
+            }}
+        {slice_block}}}
+
+        impl TryFrom<types::{base_name}> for {name} {{
+            type Error = types::ProfileIssues;
+
+            /// Check each rule of the profile, and list each rule that fails.
+            fn try_from(value: types::{base_name}) -> Result<Self, Self::Error> {{
+                types::profile::check_profile_rules(Self::URL, &value, Self::RULES)?;
+                Ok(Self(value))
+            }}
+        }}
+
+        impl From<{name}> for types::{base_name} {{
+            fn from(value: {name}) -> Self {{
+                value.0
+            }}
+        }}
+
+        impl std::ops::Deref for {name} {{
+            type Target = types::{base_name};
+
+            fn deref(&self) -> &Self::Target {{
+                &self.0
+            }}
+        }}
+        "#,
+        url = resource.url,
+        slice_block = resource_profile_slice_accessors(resource, context),
+    )
+}
+
+/// FHIR constraint profile => Rust tests block of source code, that checks
+/// the conversion from the default value of the base type, and the serde
+/// round trip.
+#[allow(dead_code)]
+pub fn resource_into_rust_profile_tests_block(resource: &Resource) -> SourceCodeString {
+    formatdoc!(
+        r#"
+        #[cfg(test)]
+        mod tests {{
+            use super::*;
+            type T = {name};
+
+            #[test]
+            fn test_try_from_default() {{
+                let base = types::{base_name}::default();
+                match T::try_from(base.clone()) {{
+                    Ok(actual) => assert_eq!(actual.into_inner(), base),
+                    Err(actual) => assert!(!actual.issues.is_empty()),
+                }}
+            }}
+
+            mod serde_json {{
+                use super::*;
+
+                #[test]
+                fn test_serde_json_round_trip() {{
+                    let Ok(expect) = T::try_from(types::{base_name}::default()) else {{
+                        return;
+                    }};
+                    let json = ::serde_json::to_value(&expect).expect("to_value");
+                    let actual: T = ::serde_json::from_value(json).expect("from_value");
+                    assert_eq!(actual, expect);
+                }}
+            }}
+        }}
+        "#,
+        name = resource.id.to_case(Case::Pascal),
+        base_name = resource
+            .r#type
+            .as_deref()
+            .unwrap_or_default()
+            .to_case(Case::Pascal),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile() -> Resource {
+        Resource {
+            id: "vitalsigns".into(),
+            url: String::from("http://hl7.org/fhir/StructureDefinition/vitalsigns"),
+            kind: Some(String::from("resource")),
+            r#type: Some(String::from("Observation")),
+            derivation: Some(String::from("constraint")),
+            snapshot: Some(
+                ::serde_json::from_value(::serde_json::json!({
+                    "element": [
+                        { "id": "Observation", "path": "Observation", "min": 0, "max": "*" },
+                        {
+                            "id": "Observation.category",
+                            "path": "Observation.category",
+                            "min": 1,
+                            "max": "*",
+                            "type": [{ "code": "CodeableConcept" }],
+                            "slicing": {
+                                "discriminator": [{ "type": "value", "path": "coding.code" }],
+                                "rules": "open"
+                            }
+                        },
+                        {
+                            "id": "Observation.category:VSCat",
+                            "path": "Observation.category",
+                            "sliceName": "VSCat",
+                            "min": 1,
+                            "max": "1",
+                            "type": [{ "code": "CodeableConcept" }],
+                            "patternCodeableConcept": { "coding": [{ "code": "vital-signs" }] }
+                        },
+                        {
+                            "id": "Observation.status",
+                            "path": "Observation.status",
+                            "min": 1,
+                            "max": "1",
+                            "type": [{ "code": "code" }],
+                            "fixedCode": "final"
+                        }
+                    ]
+                }))
+                .unwrap(),
+            ),
+            differential: Some(
+                ::serde_json::from_value(::serde_json::json!({
+                    "element": [
+                        { "id": "Observation", "path": "Observation" },
+                        { "id": "Observation.category", "path": "Observation.category", "min": 1 },
+                        {
+                            "id": "Observation.category:VSCat",
+                            "path": "Observation.category",
+                            "sliceName": "VSCat",
+                            "min": 1,
+                            "max": "1",
+                            "patternCodeableConcept": { "coding": [{ "code": "vital-signs" }] }
+                        },
+                        { "id": "Observation.status", "path": "Observation.status", "fixedCode": "final" }
+                    ]
+                }))
+                .unwrap(),
+            ),
+            ..Resource::default()
+        }
+    }
+
+    #[test]
+    fn test_resource_is_constraint_profile() {
+        assert!(resource_is_constraint_profile(&profile()));
+        let resource = Resource {
+            id: "Quantity".into(),
+            kind: Some(String::from("complex-type")),
+            r#type: Some(String::from("Quantity")),
+            derivation: Some(String::from("specialization")),
+            ..Resource::default()
+        };
+        assert!(!resource_is_constraint_profile(&resource));
+    }
+
+    #[test]
+    fn test_resource_profile_rules() {
+        let actual = resource_profile_rules(&profile());
+        let expect = vec![
+            "types::ProfileRule::Cardinality { path: \"Observation.category\", min: 1, max: None }",
+            "types::ProfileRule::Slice { path: \"Observation.category\", name: \"VSCat\", discriminators: &[(\"coding.code\", \"\\\"vital-signs\\\"\")], min: 1, max: Some(1) }",
+            "types::ProfileRule::Fixed { path: \"Observation.status\", value: \"\\\"final\\\"\" }",
+        ];
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_resource_into_rust_profile_block() {
+        let actual = resource_into_rust_profile_block(&profile(), &CodegenContext::new());
+        assert!(
+            actual.contains(concat!(
+                "#[serde(try_from = \"types::Observation\", into = \"types::Observation\")]\n",
+                "pub struct Vitalsigns(types::Observation);\n",
+            )),
+            "{}",
+            actual
+        );
+        assert!(
+            actual
+                .contains("    pub fn category_vs_cat(&self) -> Vec<&types::CodeableConcept> {\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("impl TryFrom<types::Observation> for Vitalsigns {\n"),
+            "{}",
+            actual
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! Resources into Rust
+//!
+//! Generate every file of the output directory: one file per resource, the
+//! `Resource` enum, and the module files.
+
+use crate::r5::parse::profiles_types::*;
+
+/// FHIR resources => Rust source code files, in the output directory of the
+/// config of the context.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. every resource of profiles-types.json.
+/// let result = resources_into_rust(&resources, &context);
+/// ```
+///
+/// Outcome: `./tmp/out/address.rs`, `./tmp/out/age.rs`, …, and
+/// `./tmp/out/mod.rs`.
+///
+/// When the config has features, the output directory has the `[features]`
+/// table, `features.toml`.
+///
+/// An abstract resource, such as `DomainResource`, has no file. The single
+/// file layout writes only the module file; see
+/// `resources_into_rust_single_file`.
+///
+#[allow(dead_code)]
+pub fn resources_into_rust(
+    resources: &[Resource],
+    context: &CodegenContext,
+) -> std::io::Result<()> {
+    if context.config.features {
+        resources_into_cargo_features(resources, context)?;
+    }
+    if context.config.layout == ModuleLayout::SingleFile {
+        return resources_into_rust_single_file(resources, context);
+    }
+    for resource in resources
+        .iter()
+        .filter(|x| !resource_is_abstract_resource(x))
+    {
+        resource_into_rust(resource, context)?;
+    }
+    resources_into_rust_enum(resources, context)?;
+    resources_into_rust_mod(resources, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resources_into_rust() {
+        let dir = std::env::temp_dir().join(format!("resources_into_rust_{}", std::process::id()));
+        let context = CodegenContext {
+            config: CodegenConfig::new().with_output_dir(&dir),
+            ..CodegenContext::default()
+        };
+        let resources = vec![
+            Resource {
+                id: "Age".into(),
+                kind: Some(String::from("complex-type")),
+                ..Resource::default()
+            },
+            Resource {
+                id: "DomainResource".into(),
+                kind: Some(String::from("resource")),
+                r#abstract: Some(true),
+                ..Resource::default()
+            },
+        ];
+        resources_into_rust(&resources, &context).unwrap();
+        assert!(dir.join("age.rs").exists());
+        assert!(dir.join("mod.rs").exists());
+        assert!(!dir.join("domain_resource.rs").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_resources_into_rust_writes_only_changed_files() {
+        let dir = std::env::temp_dir().join(format!(
+            "resources_into_rust_changed_{}",
+            std::process::id()
+        ));
+        let context = CodegenContext {
+            config: CodegenConfig::new().with_output_dir(&dir),
+            ..CodegenContext::default()
+        };
+        let resources = vec![Resource {
+            id: "Age".into(),
+            kind: Some(String::from("complex-type")),
+            ..Resource::default()
+        }];
+        let modified = |name: &str| {
+            std::fs::metadata(dir.join(name))
+                .unwrap()
+                .modified()
+                .unwrap()
+        };
+        resources_into_rust(&resources, &context).unwrap();
+        let expect = modified("age.rs");
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        resources_into_rust(&resources, &context).unwrap();
+        assert_eq!(modified("age.rs"), expect);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,296 @@
+//! Resources into Rust enum
+//!
+//! Every FHIR resource instance has a `resourceType` key, such as
+//! `"resourceType": "Patient"`. The `Resource` enum has one variant per
+//! concrete resource, and deserializing any resource instance dispatches on
+//! that key to its generated struct, which reads and checks the key itself,
+//! and writes it back.
+//!
+//! An abstract resource, such as `DomainResource`, has no struct, because
+//! each concrete resource snapshot already has its fields, and the enum takes
+//! the place of the abstract `Resource`.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::*;
+use crate::util::{format_rust_source, write_if_changed};
+use ::convert_case::{Case, Casing};
+use ::indoc::formatdoc;
+
+/// The module name of the generated `Resource` enum.
+pub const RESOURCE_ENUM_MODULE: &str = "resource";
+
+/// Is the resource an abstract resource, such as `Resource` or
+/// `DomainResource`, which has no generated struct?
+#[allow(dead_code)]
+pub fn resource_is_abstract_resource(resource: &Resource) -> bool {
+    resource.kind.as_deref() == Some("resource") && resource.r#abstract == Some(true)
+}
+
+/// Is the resource a concrete resource, such as `Patient`, which is one
+/// variant of the `Resource` enum?
+#[allow(dead_code)]
+pub fn resource_is_concrete_resource(resource: &Resource) -> bool {
+    resource.kind.as_deref() == Some("resource") && resource.r#abstract != Some(true)
+}
+
+/// FHIR resources => Rust source code of the `Resource` enum, with a boxed
+/// variant for each concrete resource, sorted by name.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. resource ids Observation, Patient.
+/// resources_into_rust_enum_block(&resources, &config)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// #[serde(untagged)]
+/// pub enum Resource {
+///     Observation(Box<types::Observation>),
+///     Patient(Box<types::Patient>),
+/// }
+/// ```
+///
+/// Each variant is boxed, so that the enum is small, and so that a resource
+/// can contain a resource, such as `Parameters.parameter.resource`.
+///
+/// When the config has validation, the enum validates the resource of each
+/// variant, when it has visitors, walks it, and when it has reflection,
+/// reflects on it.
+///
+#[allow(dead_code)]
+pub fn resources_into_rust_enum_block(
+    resources: &[Resource],
+    config: &CodegenConfig,
+) -> SourceCodeString {
+    let mut names: Vec<String> = resources
+        .iter()
+        .filter(|x| resource_is_concrete_resource(x))
+        .map(|x| x.id.to_case(Case::Pascal))
+        .collect();
+    names.sort();
+    names.dedup();
+    let cfg = |name: &String, indent: &str| match features_cfg_attribute(config, &[name]) {
+        cfg if cfg.is_empty() => cfg,
+        cfg => format!("{indent}{cfg}"),
+    };
+    let variant_block = names
+        .iter()
+        .map(|name| format!("{}    {name}(Box<types::{name}>),\n", cfg(name, "    ")))
+        .collect::<String>();
+    let resource_type_block = names
+        .iter()
+        .map(|name| {
+            format!(
+                "{}            Self::{name}(_) => \"{name}\",\n",
+                cfg(name, "            ")
+            )
+        })
+        .collect::<String>();
+    let deserialize_block = names
+        .iter()
+        .map(|name| {
+            format!(
+                "{}            \"{name}\" => ::serde_json::from_value(value)\n                .map(|x| Self::{name}(Box::new(x)))\n                .map_err(D::Error::custom),\n",
+                cfg(name, "            ")
+            )
+        })
+        .collect::<String>();
+    let from_block = names
+        .iter()
+        .map(|name| {
+            formatdoc!(
+                r#"
+
+                {cfg}impl From<types::{name}> for Resource {{
+                    fn from(value: types::{name}) -> Self {{
+                        Self::{name}(Box::new(value))
+                    }}
+                }}
+                "#,
+                cfg = cfg(name, ""),
+            )
+        })
+        .collect::<String>();
+    formatdoc!(
+        r#"
+        //! Resource
+        //!
+        //! Any FHIR resource, tagged on its `resourceType` JSON key.
+
+        /// Use the FHIR R5 datatypes and resources for the variants.
+        {use_types_line}
+
+        /// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
+        use ::serde::{{Deserialize, Serialize}};
+
+        /// Derive all our typical things for programming, serde, comparing, etc.
+        #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+        /// Write the resource as is, because its struct writes its own
+        /// `resourceType` JSON key.
+        #[serde(untagged)]
+        pub enum Resource {{
+        {variant_block}}}
+
+        impl Resource {{
+            /// The `resourceType` of the resource, such as `Patient`.
+            pub fn resource_type(&self) -> &'static str {{
+                match self {{
+        {resource_type_block}        }}
+            }}
+        }}
+
+        /// Read the `resourceType` JSON key, then read the resource into the
+        /// struct of that variant, which checks the key again.
+        impl<'de> Deserialize<'de> for Resource {{
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                use ::serde::de::Error;
+                let value = ::serde_json::Value::deserialize(deserializer)?;
+                let resource_type = match value.get("resourceType") {{
+                    Some(::serde_json::Value::String(resource_type)) => resource_type.clone(),
+                    Some(_) => return Err(D::Error::custom("resourceType is not a string")),
+                    None => return Err(D::Error::missing_field("resourceType")),
+                }};
+                match resource_type.as_str() {{
+        {deserialize_block}            _ => Err(D::Error::custom(format!("unknown resourceType `{{resource_type}}`"))),
+                }}
+            }}
+        }}
+        {from_block}{validate_block}{walk_block}{reflect_block}"#,
+        use_types_line = config.use_types_line(),
+        validate_block = if config.validation {
+            resources_into_rust_enum_validate_block(&names, config)
+        } else {
+            String::new()
+        },
+        walk_block = if config.visitors {
+            resources_into_rust_enum_walk_block(&names, config)
+        } else {
+            String::new()
+        },
+        reflect_block = if config.reflection {
+            resources_into_rust_enum_reflect_block(&names, config)
+        } else {
+            String::new()
+        },
+    )
+}
+
+/// FHIR resources => Rust source code file of the `Resource` enum, such as
+/// `./tmp/out/resource.rs`, when there is any concrete resource.
+#[allow(dead_code)]
+pub fn resources_into_rust_enum(
+    resources: &[Resource],
+    context: &CodegenContext,
+) -> std::io::Result<()> {
+    if !resources.iter().any(resource_is_concrete_resource) {
+        return Ok(());
+    }
+    let config = &context.config;
+    write_if_changed(
+        &config.output_dir.join(format!("{RESOURCE_ENUM_MODULE}.rs")),
+        format_rust_source(&resources_into_rust_enum_block(resources, config)),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(id: &str, kind: &str, r#abstract: bool) -> Resource {
+        Resource {
+            id: id.into(),
+            kind: Some(String::from(kind)),
+            r#abstract: Some(r#abstract),
+            ..Resource::default()
+        }
+    }
+
+    #[test]
+    fn test_resource_is_abstract_resource() {
+        assert!(resource_is_abstract_resource(&resource(
+            "DomainResource",
+            "resource",
+            true
+        )));
+        assert!(!resource_is_abstract_resource(&resource(
+            "Patient", "resource", false
+        )));
+        assert!(!resource_is_abstract_resource(&resource(
+            "Element",
+            "complex-type",
+            true
+        )));
+    }
+
+    #[test]
+    fn test_resources_into_rust_enum_block() {
+        let resources = vec![
+            resource("Patient", "resource", false),
+            resource("DomainResource", "resource", true),
+            resource("Observation", "resource", false),
+            resource("Quantity", "complex-type", false),
+        ];
+        let actual = resources_into_rust_enum_block(&resources, &CodegenConfig::new());
+        assert!(
+            actual.contains(concat!(
+                "#[serde(untagged)]\n",
+                "pub enum Resource {\n",
+                "    Observation(Box<types::Observation>),\n",
+                "    Patient(Box<types::Patient>),\n",
+                "}\n",
+            )),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("            Self::Patient(_) => \"Patient\",\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("            \"Patient\" => ::serde_json::from_value(value)\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("impl From<types::Patient> for Resource {\n"),
+            "{}",
+            actual
+        );
+        assert!(!actual.contains("DomainResource"), "{}", actual);
+    }
+
+    #[test]
+    fn test_resources_into_rust_enum_block_with_features() {
+        let resources = vec![resource("Patient", "resource", false)];
+        let config = CodegenConfig {
+            features: true,
+            ..CodegenConfig::new()
+        };
+        let actual = resources_into_rust_enum_block(&resources, &config);
+        assert!(
+            actual
+                .contains("    #[cfg(feature = \"Patient\")]\n    Patient(Box<types::Patient>),\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(
+                "            #[cfg(feature = \"Patient\")]\n            Self::Patient(_) => \"Patient\",\n"
+            ),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains(
+                "\n#[cfg(feature = \"Patient\")]\nimpl From<types::Patient> for Resource {\n"
+            ),
+            "{}",
+            actual
+        );
+    }
+}
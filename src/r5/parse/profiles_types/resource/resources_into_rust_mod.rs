@@ -0,0 +1,296 @@
+//! Resources into Rust mod
+//!
+//! The module file of the generated files, such as `mod.rs`, declares each
+//! generated module, and re-exports its main type, so that the output
+//! directory is a drop-in module of a crate:
+//!
+//! ```no_run
+//! pub mod address;
+//! pub use address::Address;
+//! ```
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::resources_flatten_choice;
+use crate::r5::parse::profiles_types::*;
+use crate::util::{format_rust_source, write_if_changed};
+use ::convert_case::{Case, Casing};
+use std::collections::BTreeMap;
+
+/// The first line of each generated module file.
+const MOD_HEADER: &str = "//! FHIR R5 types, generated from the FHIR StructureDefinitions.\n";
+
+/// FHIR resources => Rust module file, such as `mod.rs`, with a `pub mod` and
+/// a `pub use` for each resource, sorted by module name.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. resource ids Address, Age.
+/// resources_into_rust_mod_block(&resources, &config)
+/// ```
+///
+/// Output:
+///
+/// ```no_run
+/// pub mod address;
+/// pub use address::Address;
+///
+/// pub mod age;
+/// pub use age::Age;
+/// ```
+///
+#[allow(dead_code)]
+pub fn resources_into_rust_mod_block(
+    resources: &[&Resource],
+    config: &CodegenConfig,
+) -> SourceCodeString {
+    let modules: BTreeMap<String, String> = resources
+        .iter()
+        .filter(|resource| !resource.id.is_empty())
+        .map(|resource| {
+            (
+                config.module_name(resource),
+                resource.id.to_case(Case::Pascal),
+            )
+        })
+        .collect();
+    let mut block = String::from(MOD_HEADER);
+    for (module, name) in modules {
+        let cfg = features_cfg_attribute(config, &[&name]);
+        block.push_str(&format!(
+            "\n{cfg}pub mod {module};\n{cfg}pub use {module}::{name};\n"
+        ));
+    }
+    block
+}
+
+/// FHIR resources => Rust module files, written by the layout of the config.
+///
+/// The flat layout writes one module file. The grouped layout writes one
+/// module file per group, such as `complex_types/mod.rs`, and a module file
+/// that declares and re-exports each group. Each file is formatted, and
+/// written only when its content changed.
+///
+/// An abstract resource, such as `DomainResource`, has no module. When there
+/// is any concrete resource, the module file declares the `Resource` enum.
+/// When any struct flattens a choice, the module file declares the unknown
+/// fields check, and writes its module. When the config has builders, the module file declares the builder error,
+/// and writes its module. When the config has validation, the module file
+/// declares the `Validate` trait, and writes its module. When the config has
+/// visitors, the module file declares the visitor traits, and writes their
+/// module. When the config has reflection, the module file declares the
+/// `Reflect` trait, and writes its module. When the config has extensions,
+/// and there is the `Extension` type, the module file declares the
+/// `HasExtensions` trait, and writes its module. When the config has features,
+/// each module has a `cfg` attribute for its feature. When there is any
+/// constraint profile, the module file declares the profile rules, and
+/// writes their module.
+///
+#[allow(dead_code)]
+pub fn resources_into_rust_mod(
+    resources: &[Resource],
+    context: &CodegenContext,
+) -> std::io::Result<()> {
+    let config = &context.config;
+    let mut groups: BTreeMap<Option<&str>, Vec<&Resource>> = BTreeMap::new();
+    for resource in resources
+        .iter()
+        .filter(|x| !resource_is_abstract_resource(x))
+    {
+        groups
+            .entry(config.group_name(resource))
+            .or_default()
+            .push(resource);
+    }
+    let mut mod_block = String::from(MOD_HEADER);
+    for (group, resources) in groups {
+        let block = resources_into_rust_mod_block(&resources, config);
+        match group {
+            None => mod_block = block,
+            Some(group) => {
+                write_if_changed(
+                    &config.output_dir.join(group).join("mod.rs"),
+                    format_rust_source(&block),
+                )?;
+                mod_block.push_str(&format!("\npub mod {group};\npub use {group}::*;\n"));
+            }
+        }
+    }
+    if resources.iter().any(resource_is_concrete_resource) {
+        let cfg = features_cfg_attribute(config, &resource_enum_features(resources));
+        mod_block.push_str(&format!(
+            "\n{cfg}pub mod {RESOURCE_ENUM_MODULE};\n{cfg}pub use {RESOURCE_ENUM_MODULE}::Resource;\n"
+        ));
+    }
+    if resources_flatten_choice(resources) {
+        write_if_changed(
+            &config
+                .output_dir
+                .join(format!("{UNKNOWN_FIELDS_MODULE}.rs")),
+            format_rust_source(UNKNOWN_FIELDS_BLOCK),
+        )?;
+        mod_block.push_str(&format!(
+            "\npub mod {UNKNOWN_FIELDS_MODULE};\npub use {UNKNOWN_FIELDS_MODULE}::DenyUnknownFields;\n"
+        ));
+        write_if_changed(
+            &config.output_dir.join(format!("{CHOICE_MODULE}.rs")),
+            format_rust_source(CHOICE_BLOCK),
+        )?;
+        mod_block.push_str(&format!(
+            "\npub mod {CHOICE_MODULE};\npub use {CHOICE_MODULE}::Choice;\n"
+        ));
+    }
+    if config.builders {
+        write_if_changed(
+            &config.output_dir.join(format!("{BUILDER_MODULE}.rs")),
+            format_rust_source(BUILDER_ERROR_BLOCK),
+        )?;
+        mod_block.push_str(&format!(
+            "\npub mod {BUILDER_MODULE};\npub use {BUILDER_MODULE}::MissingElements;\n"
+        ));
+    }
+    if config.validation {
+        write_if_changed(
+            &config.output_dir.join(format!("{VALIDATE_MODULE}.rs")),
+            format_rust_source(VALIDATE_BLOCK),
+        )?;
+        mod_block.push_str(&format!(
+            "\npub mod {VALIDATE_MODULE};\npub use {VALIDATE_MODULE}::{{Validate, ValidationIssue}};\n"
+        ));
+    }
+    if config.visitors {
+        write_if_changed(
+            &config.output_dir.join(format!("{VISIT_MODULE}.rs")),
+            format_rust_source(&resources_into_rust_visit_block(resources, config)),
+        )?;
+        mod_block.push_str(&format!(
+            "\npub mod {VISIT_MODULE};\npub use {VISIT_MODULE}::{{Visit, VisitMut, Walk}};\n"
+        ));
+    }
+    if config.reflection {
+        write_if_changed(
+            &config.output_dir.join(format!("{REFLECT_MODULE}.rs")),
+            format_rust_source(REFLECT_BLOCK),
+        )?;
+        mod_block.push_str(&format!(
+            "\npub mod {REFLECT_MODULE};\npub use {REFLECT_MODULE}::{{AsValueRef, BindingInfo, ElementInfo, Reflect, ValueRef}};\n"
+        ));
+    }
+    let extensions_block = if config.extensions {
+        resources_into_rust_extensions_block(resources, context)
+    } else {
+        String::new()
+    };
+    if !extensions_block.is_empty() {
+        write_if_changed(
+            &config.output_dir.join(format!("{EXTENSIONS_MODULE}.rs")),
+            format_rust_source(&extensions_block),
+        )?;
+        let cfg = features_cfg_attribute(config, &["Extension"]);
+        mod_block.push_str(&format!(
+            "
+{cfg}pub mod {EXTENSIONS_MODULE};
+{cfg}pub use {EXTENSIONS_MODULE}::{{CoreExtensions, ExtensionValueType, HasExtensions}};
+"
+        ));
+    }
+    if resources.iter().any(resource_is_constraint_profile) {
+        write_if_changed(
+            &config.output_dir.join(format!("{PROFILE_MODULE}.rs")),
+            format_rust_source(PROFILE_RULES_BLOCK),
+        )?;
+        mod_block.push_str(&format!(
+            "\npub mod {PROFILE_MODULE};\npub use {PROFILE_MODULE}::{{ProfileIssue, ProfileIssues, ProfileRule}};\n"
+        ));
+    }
+    write_if_changed(&config.mod_file, format_rust_source(&mod_block))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(id: &str, kind: &str) -> Resource {
+        Resource {
+            id: id.into(),
+            kind: Some(String::from(kind)),
+            ..Resource::default()
+        }
+    }
+
+    #[test]
+    fn test_resources_into_rust_mod_block() {
+        let age = resource("Age", "complex-type");
+        let date_time = resource("dateTime", "primitive-type");
+        let actual = resources_into_rust_mod_block(&[&date_time, &age], &CodegenConfig::new());
+        let expect = concat!(
+            "//! FHIR R5 types, generated from the FHIR StructureDefinitions.\n",
+            "\n",
+            "pub mod age;\n",
+            "pub use age::Age;\n",
+            "\n",
+            "pub mod date_time;\n",
+            "pub use date_time::DateTime;\n",
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_resources_into_rust_mod_block_with_features() {
+        let age = resource("Age", "complex-type");
+        let config = CodegenConfig {
+            features: true,
+            ..CodegenConfig::new()
+        };
+        let actual = resources_into_rust_mod_block(&[&age], &config);
+        assert!(
+            actual.ends_with(concat!(
+                "\n",
+                "#[cfg(feature = \"Age\")]\n",
+                "pub mod age;\n",
+                "#[cfg(feature = \"Age\")]\n",
+                "pub use age::Age;\n",
+            )),
+            "{}",
+            actual
+        );
+    }
+
+    #[test]
+    fn test_resources_into_rust_mod_with_grouped_by_kind() {
+        let dir =
+            std::env::temp_dir().join(format!("resources_into_rust_mod_{}", std::process::id()));
+        let context = CodegenContext {
+            config: CodegenConfig {
+                layout: ModuleLayout::GroupedByKind,
+                ..CodegenConfig::new().with_output_dir(&dir)
+            },
+            ..CodegenContext::default()
+        };
+        let resources = vec![
+            resource("Age", "complex-type"),
+            resource("dateTime", "primitive-type"),
+            resource("Patient", "resource"),
+        ];
+        resources_into_rust_mod(&resources, &context).unwrap();
+        let actual = std::fs::read_to_string(dir.join("mod.rs")).unwrap();
+        assert!(
+            actual.contains("pub mod complex_types;\npub use complex_types::*;\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.ends_with("pub mod resource;\npub use resource::Resource;\n"),
+            "{}",
+            actual
+        );
+        let actual = std::fs::read_to_string(dir.join("primitive_types").join("mod.rs")).unwrap();
+        assert!(
+            actual.contains("pub mod date_time;\npub use date_time::DateTime;\n"),
+            "{}",
+            actual
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
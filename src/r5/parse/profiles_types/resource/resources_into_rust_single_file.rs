@@ -0,0 +1,211 @@
+//! Resources into Rust single file
+//!
+//! The single file layout puts every generated module inline in the module
+//! file, so that a crate can include the file from its `OUT_DIR`:
+//!
+//! ```no_run
+//! pub mod fhir {
+//!     include!(concat!(env!("OUT_DIR"), "/fhir.rs"));
+//! }
+//! ```
+//!
+//! An included file can not start with an inner doc comment, such as `//!`,
+//! so its header is a plain comment. Each inline module keeps the inner doc
+//! comment of its generated file.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::{
+    resource_into_rust_struct_block, resources_flatten_choice,
+};
+use crate::r5::parse::profiles_types::*;
+use crate::util::{format_rust_source, write_if_changed};
+use ::convert_case::{Case, Casing};
+use std::collections::BTreeMap;
+
+/// The first line of the single file.
+const SINGLE_FILE_HEADER: &str =
+    "// FHIR R5 types, generated from the FHIR StructureDefinitions.\n";
+
+/// Given one module name, its main type name, its source code, and its `cfg`
+/// attribute line, return the inline module block with its `pub use`.
+fn inline_module_block(module: &str, name: &str, block: &str, cfg: &str) -> SourceCodeString {
+    format!("\n{cfg}pub mod {module} {{\n{block}}}\n{cfg}pub use {module}::{name};\n")
+}
+
+/// FHIR resources => Rust source code of one file, with an inline module
+/// for each resource, sorted by module name, then the `Resource` enum, then
+/// the unknown fields check, then the builder error, then the validation, then the visitors, then the
+/// reflection, then the extensions, then the profile rules.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. resource ids Address, Age.
+/// resources_into_rust_single_file_block(&resources, &context)
+/// ```
+///
+/// Output is approximately like this:
+///
+/// ```no_run
+/// pub mod address {
+/// //! Address
+/// …
+/// }
+/// pub use address::Address;
+///
+/// pub mod age {
+/// …
+/// }
+/// pub use age::Age;
+/// ```
+///
+#[allow(dead_code)]
+pub fn resources_into_rust_single_file_block(
+    resources: &[Resource],
+    context: &CodegenContext,
+) -> SourceCodeString {
+    let config = &context.config;
+    let modules: BTreeMap<String, &Resource> = resources
+        .iter()
+        .filter(|x| !x.id.is_empty() && !resource_is_abstract_resource(x))
+        .map(|resource| (config.module_name(resource), resource))
+        .collect();
+    let mut block = String::from(SINGLE_FILE_HEADER);
+    for (module, resource) in modules {
+        let name = resource.id.to_case(Case::Pascal);
+        block.push_str(&inline_module_block(
+            &module,
+            &name,
+            &resource_into_rust_struct_block(resource, context),
+            &features_cfg_attribute(config, &[&name]),
+        ));
+    }
+    if resources.iter().any(resource_is_concrete_resource) {
+        block.push_str(&inline_module_block(
+            RESOURCE_ENUM_MODULE,
+            "Resource",
+            &resources_into_rust_enum_block(resources, config),
+            &features_cfg_attribute(config, &resource_enum_features(resources)),
+        ));
+    }
+    if resources_flatten_choice(resources) {
+        block.push_str(&inline_module_block(
+            UNKNOWN_FIELDS_MODULE,
+            "DenyUnknownFields",
+            UNKNOWN_FIELDS_BLOCK,
+            "",
+        ));
+        block.push_str(&inline_module_block(
+            CHOICE_MODULE,
+            "Choice",
+            CHOICE_BLOCK,
+            "",
+        ));
+    }
+    if config.builders {
+        block.push_str(&inline_module_block(
+            BUILDER_MODULE,
+            "MissingElements",
+            BUILDER_ERROR_BLOCK,
+            "",
+        ));
+    }
+    if config.validation {
+        block.push_str(&inline_module_block(
+            VALIDATE_MODULE,
+            "{Validate, ValidationIssue}",
+            VALIDATE_BLOCK,
+            "",
+        ));
+    }
+    if config.visitors {
+        block.push_str(&inline_module_block(
+            VISIT_MODULE,
+            "{Visit, VisitMut, Walk}",
+            &resources_into_rust_visit_block(resources, config),
+            "",
+        ));
+    }
+    if config.reflection {
+        block.push_str(&inline_module_block(
+            REFLECT_MODULE,
+            "{AsValueRef, BindingInfo, ElementInfo, Reflect, ValueRef}",
+            REFLECT_BLOCK,
+            "",
+        ));
+    }
+    let extensions_block = if config.extensions {
+        resources_into_rust_extensions_block(resources, context)
+    } else {
+        String::new()
+    };
+    if !extensions_block.is_empty() {
+        block.push_str(&inline_module_block(
+            EXTENSIONS_MODULE,
+            "{CoreExtensions, ExtensionValueType, HasExtensions}",
+            &extensions_block,
+            &features_cfg_attribute(config, &["Extension"]),
+        ));
+    }
+    if resources.iter().any(resource_is_constraint_profile) {
+        block.push_str(&inline_module_block(
+            PROFILE_MODULE,
+            "{ProfileIssue, ProfileIssues, ProfileRule}",
+            PROFILE_RULES_BLOCK,
+            "",
+        ));
+    }
+    block
+}
+
+/// FHIR resources => Rust source code file, at the module file of the config
+/// of the context, such as `$OUT_DIR/fhir.rs`.
+#[allow(dead_code)]
+pub fn resources_into_rust_single_file(
+    resources: &[Resource],
+    context: &CodegenContext,
+) -> std::io::Result<()> {
+    write_if_changed(
+        &context.config.mod_file,
+        format_rust_source(&resources_into_rust_single_file_block(resources, context)),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(id: &str, kind: &str) -> Resource {
+        Resource {
+            id: id.into(),
+            kind: Some(String::from(kind)),
+            ..Resource::default()
+        }
+    }
+
+    #[test]
+    fn test_resources_into_rust_single_file_block() {
+        let resources = vec![
+            resource("dateTime", "primitive-type"),
+            resource("Age", "complex-type"),
+            resource("Patient", "resource"),
+        ];
+        let actual = resources_into_rust_single_file_block(&resources, &CodegenContext::new());
+        assert!(
+            actual.starts_with("// FHIR R5 types, generated from the FHIR StructureDefinitions.\n\npub mod age {\n//! Age\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.contains("}\npub use age::Age;\n\npub mod date_time {\n"),
+            "{}",
+            actual
+        );
+        assert!(
+            actual.ends_with("}\npub use resource::Resource;\n"),
+            "{}",
+            actual
+        );
+    }
+}
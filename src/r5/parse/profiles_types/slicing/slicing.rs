@@ -2,7 +2,7 @@
 //!
 //! For an example see the sibling file of JSON.
 
-use crate::r5::parse::data_elements::*;
+use crate::r5::parse::profiles_types::Discriminator;
 use ::serde::{Deserialize, Serialize};
 
 #[serde_with::skip_serializing_none]
@@ -10,14 +10,18 @@ use ::serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
 pub struct Slicing {
-    /// Example: TODO
-    discriminator: Vec<Discriminator>,
+    /// Example: [{"type": "value", "path": "url"}]
+    #[serde(default)]
+    pub discriminator: Vec<Discriminator>,
 
     /// Example: "Extensions are always sliced by (at least) url"
-    description: String,
+    pub description: Option<String>,
+
+    /// Example: false
+    pub ordered: Option<bool>,
 
     /// Example: "open"
-    rules: String,
+    pub rules: String,
 }
 
 #[cfg(test)]
@@ -0,0 +1,190 @@
+//! Type dependencies
+//!
+//! A generated Rust type compiles only with every FHIR type that its fields
+//! refer to, such as `Dosage` with `Timing`, and `Timing` with `Period`.
+//!
+//! The dependencies of a resource are the FHIR types of its elements, and
+//! the `Element` type of the sibling attribute of each primitive element.
+//! The dependency of a constraint profile, such as `SimpleQuantity`, is its
+//! base type, such as `Quantity`.
+//! The dependency closure of a set of resources adds the dependencies of
+//! each dependency, until no dependency is new.
+//!
+//! A `Reference(Patient)` refers to a patient by URL, so it depends on the
+//! `Reference` type, but not on `Patient`.
+//!
+//! Every type has extensions, and `Extension.value[x]` can be any open
+//! datatype, so the closure of any type has every datatype that a choice
+//! can hold. It has no resource that is not included, and no profile, such
+//! as `MoneyQuantity`, that no element refers to.
+
+use crate::r5::parse::profiles_types::element::element_into_rust_type::FHIRPATH_SYSTEM_PREFIX;
+use crate::r5::parse::profiles_types::resource::resource_into_rust::resource_elements;
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Given one resource, return the name of each FHIR type that its generated
+/// Rust code refers to, such as `Period` and `String`, without itself.
+///
+/// Example:
+///
+/// ```no_run
+/// let resource = … // e.g. resource id Period.
+/// resource_type_dependencies(&resource) => {"DateTime", "Element", "Extension", "String"}
+/// ```
+///
+#[allow(dead_code)]
+pub fn resource_type_dependencies(resource: &Resource) -> BTreeSet<String> {
+    let name = resource.id.to_case(Case::Pascal);
+    let mut dependencies = BTreeSet::new();
+    if resource.kind.as_deref() == Some("primitive-type") {
+        return dependencies;
+    }
+    if resource_is_constraint_profile(resource) {
+        dependencies.extend(resource.r#type.iter().map(|x| x.to_case(Case::Pascal)));
+        return dependencies;
+    }
+    for element in resource_elements(resource) {
+        if !element.path.contains('.')
+            || element.content_reference.is_some()
+            || Cardinality::from_element(element) == Cardinality::Prohibited
+        {
+            continue;
+        }
+        if element_has_primitive_sibling(element) || element_has_choice_sibling(element) {
+            dependencies.insert(String::from("Element"));
+        }
+        let inline = element_is_inline_struct(element);
+        for element_type in element.r#type.iter().flatten() {
+            let code = element_type.code.as_str();
+            if code.starts_with(FHIRPATH_SYSTEM_PREFIX)
+                || (inline && matches!(code, "Element" | "BackboneElement"))
+            {
+                continue;
+            }
+            dependencies.insert(code.to_case(Case::Pascal));
+        }
+    }
+    dependencies.remove(&name);
+    dependencies
+}
+
+/// Given the resources and the names of the included types, return the name
+/// of each included type and of each type that it depends on, directly or
+/// through other types.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. every resource of profiles-types.json.
+/// resources_type_closure(&resources, ["Period"]) => {"DateTime", "Element", "Extension", "Period", …}
+/// ```
+///
+/// A name matches a resource id, such as `dateTime`, or its Rust name, such
+/// as `DateTime`. A name that matches no resource, such as the `Resource`
+/// enum, stays in the closure, without dependencies.
+///
+#[allow(dead_code)]
+pub fn resources_type_closure<I, S>(resources: &[Resource], include: I) -> BTreeSet<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let dependencies: BTreeMap<String, BTreeSet<String>> = resources
+        .iter()
+        .map(|resource| {
+            (
+                resource.id.to_case(Case::Pascal),
+                resource_type_dependencies(resource),
+            )
+        })
+        .collect();
+    let mut closure = BTreeSet::new();
+    let mut pending: Vec<String> = include
+        .into_iter()
+        .map(|name| name.as_ref().to_case(Case::Pascal))
+        .collect();
+    while let Some(name) = pending.pop() {
+        if closure.contains(&name) {
+            continue;
+        }
+        if let Some(names) = dependencies.get(&name) {
+            pending.extend(names.iter().cloned());
+        }
+        closure.insert(name);
+    }
+    closure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn element(path: &str, codes: &[&str]) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            r#type: Some(
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    fn resource(id: &str, elements: Vec<Element>) -> Resource {
+        Resource {
+            id: id.into(),
+            kind: Some(String::from("complex-type")),
+            snapshot: Some(Snapshot {
+                element: elements,
+                ..Snapshot::default()
+            }),
+            ..Resource::default()
+        }
+    }
+
+    #[test]
+    fn test_resource_type_dependencies() {
+        let resource = resource(
+            "Alfa",
+            vec![
+                element("Alfa", &[]),
+                element("Alfa.id", &["http://hl7.org/fhirpath/System.String"]),
+                element("Alfa.bravo", &["dateTime"]),
+                element("Alfa.charlie", &["BackboneElement"]),
+                element("Alfa.charlie.delta", &["Quantity", "Alfa"]),
+                element("Alfa.echo[x]", &["string", "Period"]),
+            ],
+        );
+        let actual = resource_type_dependencies(&resource);
+        let expect: BTreeSet<String> = ["DateTime", "Element", "Period", "Quantity", "String"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_resources_type_closure() {
+        let resources = vec![
+            resource("Alfa", vec![element("Alfa.bravo", &["Bravo"])]),
+            resource("Bravo", vec![element("Bravo.charlie", &["Charlie"])]),
+            resource("Charlie", vec![element("Charlie.alfa", &["Alfa"])]),
+            resource("Delta", vec![element("Delta.alfa", &["Alfa"])]),
+        ];
+        let actual = resources_type_closure(&resources, ["bravo"]);
+        let expect: BTreeSet<String> = ["Alfa", "Bravo", "Charlie"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(actual, expect);
+    }
+}
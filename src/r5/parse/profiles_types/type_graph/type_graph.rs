@@ -0,0 +1,294 @@
+//! Type graph
+//!
+//! A Rust struct can not contain itself, directly or through other structs,
+//! because its size would be infinite. FHIR types recurse often, such as
+//! `Reference.identifier` → `Identifier.assigner` → `Reference`.
+//!
+//! The type graph has a node for each generated Rust type, and an edge for
+//! each field or enum variant that holds another type inline, i.e. as `T` or
+//! `Option<T>`. A `Vec<T>` holds its items on the heap, so it has no edge.
+//!
+//! Each strongly connected component of the graph is a set of types that
+//! reach each other. An edge between two types of the same component is part
+//! of a cycle, so its field needs a `Box`. An edge between components never
+//! is, so its field stays unboxed.
+
+use crate::r5::parse::profiles_types::resource::resource_into_rust::{
+    child_elements, resource_elements,
+};
+use crate::r5::parse::profiles_types::*;
+use ::convert_case::{Case, Casing};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// One field or enum variant that holds a type inline.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TypeEdge {
+    /// The name of the held type, such as `Identifier`.
+    pub to: String,
+
+    /// The element path of the field, such as `Reference.identifier`, or of
+    /// the choice variant, such as `Extension.valueIdentifier`.
+    pub path: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TypeGraph {
+    edges: BTreeMap<String, Vec<TypeEdge>>,
+}
+
+impl TypeGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the graph of every type that the resources generate.
+    pub fn from_resources(resources: &[Resource], context: &CodegenContext) -> Self {
+        let mut graph = Self::new();
+        for resource in resources {
+            graph.insert_resource(resource, context);
+        }
+        graph
+    }
+
+    /// Add the types of one resource: its struct, its inline structs, and
+    /// its choice enums.
+    pub fn insert_resource(&mut self, resource: &Resource, context: &CodegenContext) {
+        if resource.kind.as_deref() == Some("primitive-type") {
+            return;
+        }
+        let elements = resource_elements(resource);
+        let Some(root) = elements.first() else {
+            return;
+        };
+        let mut structs = vec![(resource.id.to_case(Case::Pascal), root.path.as_str())];
+        structs.extend(
+            elements
+                .iter()
+                .filter(|x| element_is_inline_struct(x))
+                .map(|x| (path_into_rust_struct_name(&x.path), x.path.as_str())),
+        );
+        for (name, parent_path) in structs {
+            self.edges.entry(name.clone()).or_default();
+            for element in child_elements(elements, parent_path) {
+                match Cardinality::from_element(element) {
+                    Cardinality::Required | Cardinality::Optional => {}
+                    Cardinality::Repeated | Cardinality::Prohibited => continue,
+                }
+                let to = element_into_rust_type(element, context);
+                if element_is_choice(element) {
+                    self.insert_choice(&to, element);
+                }
+                self.insert_edge(&name, &to, &element.path);
+            }
+        }
+    }
+
+    /// Add one choice enum, with an edge for each of its variants.
+    fn insert_choice(&mut self, name: &str, element: &Element) {
+        self.edges.entry(String::from(name)).or_default();
+        let path = element.path.trim_end_matches("[x]");
+        for element_type in element.r#type.iter().flatten() {
+            self.insert_edge(
+                name,
+                &type_code_into_rust_type(&element_type.code),
+                &choice_json_key(path, &element_type.code),
+            );
+        }
+    }
+
+    fn insert_edge(&mut self, from: &str, to: &str, path: &str) {
+        let to = to.strip_prefix("types::").unwrap_or(to);
+        self.edges
+            .entry(String::from(from))
+            .or_default()
+            .push(TypeEdge {
+                to: String::from(to),
+                path: String::from(path),
+            });
+    }
+
+    pub fn edges(&self, from: &str) -> &[TypeEdge] {
+        self.edges.get(from).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Find the strongly connected components, by Tarjan's algorithm.
+    ///
+    /// Each component lists its type names in order, and the components are
+    /// in reverse topological order, so the output is stable.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<String>> {
+        let mut tarjan = Tarjan::default();
+        for name in self.edges.keys() {
+            if !tarjan.index.contains_key(name.as_str()) {
+                tarjan.visit(self, name);
+            }
+        }
+        tarjan.components
+    }
+
+    /// The element paths of every field and choice variant that needs a
+    /// `Box`, because it is an edge within one strongly connected component.
+    ///
+    /// A choice field stays unboxed, because every cycle through its enum
+    /// also goes through one of its variants, which is boxed.
+    pub fn boxed_paths(&self) -> BTreeSet<String> {
+        let mut component_of: BTreeMap<&str, usize> = BTreeMap::new();
+        let components = self.strongly_connected_components();
+        for (i, component) in components.iter().enumerate() {
+            for name in component {
+                component_of.insert(name, i);
+            }
+        }
+        let mut paths = BTreeSet::new();
+        for (from, edges) in &self.edges {
+            for edge in edges {
+                if !edge.path.ends_with("[x]")
+                    && component_of.get(from.as_str()) == component_of.get(edge.to.as_str())
+                {
+                    paths.insert(edge.path.clone());
+                }
+            }
+        }
+        paths
+    }
+}
+
+/// The state of Tarjan's strongly connected components algorithm.
+#[derive(Default)]
+struct Tarjan<'a> {
+    index: BTreeMap<&'a str, usize>,
+    low_link: BTreeMap<&'a str, usize>,
+    stack: Vec<&'a str>,
+    on_stack: BTreeSet<&'a str>,
+    components: Vec<Vec<String>>,
+}
+
+impl<'a> Tarjan<'a> {
+    fn visit(&mut self, graph: &'a TypeGraph, name: &'a str) {
+        let index = self.index.len();
+        self.index.insert(name, index);
+        self.low_link.insert(name, index);
+        self.stack.push(name);
+        self.on_stack.insert(name);
+        for edge in graph.edges(name) {
+            let to = edge.to.as_str();
+            if !self.index.contains_key(to) {
+                self.visit(graph, to);
+                let low_link = self.low_link[name].min(self.low_link[to]);
+                self.low_link.insert(name, low_link);
+            } else if self.on_stack.contains(to) {
+                let low_link = self.low_link[name].min(self.index[to]);
+                self.low_link.insert(name, low_link);
+            }
+        }
+        if self.low_link[name] == index {
+            let mut component = vec![];
+            while let Some(x) = self.stack.pop() {
+                self.on_stack.remove(x);
+                component.push(String::from(x));
+                if x == name {
+                    break;
+                }
+            }
+            component.sort();
+            self.components.push(component);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::parse::all::ElementType;
+
+    fn element(path: &str, max: &str, codes: &[&str]) -> Element {
+        Element {
+            id: path.into(),
+            path: path.into(),
+            min: Some(0),
+            max: Some(max.into()),
+            r#type: Some(
+                codes
+                    .iter()
+                    .map(|code| ElementType {
+                        code: String::from(*code),
+                        ..ElementType::default()
+                    })
+                    .collect(),
+            ),
+            ..Element::default()
+        }
+    }
+
+    fn resource(id: &str, elements: Vec<Element>) -> Resource {
+        Resource {
+            id: id.into(),
+            kind: Some(String::from("complex-type")),
+            snapshot: Some(Snapshot { element: elements }),
+            ..Resource::default()
+        }
+    }
+
+    fn resources() -> Vec<Resource> {
+        vec![
+            resource(
+                "Reference",
+                vec![
+                    element("Reference", "*", &[]),
+                    element("Reference.reference", "1", &["string"]),
+                    element("Reference.identifier", "1", &["Identifier"]),
+                ],
+            ),
+            resource(
+                "Identifier",
+                vec![
+                    element("Identifier", "*", &[]),
+                    element("Identifier.period", "1", &["Period"]),
+                    element("Identifier.assigner", "1", &["Reference"]),
+                ],
+            ),
+            resource(
+                "Period",
+                vec![
+                    element("Period", "*", &[]),
+                    element("Period.start", "1", &["dateTime"]),
+                ],
+            ),
+            resource(
+                "Extension",
+                vec![
+                    element("Extension", "*", &[]),
+                    element("Extension.extension", "*", &["Extension"]),
+                    element(
+                        "Extension.value[x]",
+                        "1",
+                        &["Period", "Reference", "Extension"],
+                    ),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = TypeGraph::from_resources(&resources(), &CodegenContext::new());
+        let components = graph.strongly_connected_components();
+        assert!(components.contains(&vec![String::from("Identifier"), String::from("Reference")]));
+        assert!(components.contains(&vec![
+            String::from("Extension"),
+            String::from("ExtensionValue")
+        ]));
+        assert!(components.contains(&vec![String::from("Period")]));
+    }
+
+    #[test]
+    fn test_boxed_paths() {
+        let graph = TypeGraph::from_resources(&resources(), &CodegenContext::new());
+        let actual: Vec<String> = graph.boxed_paths().into_iter().collect();
+        let expect = vec![
+            String::from("Extension.valueExtension"),
+            String::from("Identifier.assigner"),
+            String::from("Reference.identifier"),
+        ];
+        assert_eq!(actual, expect);
+    }
+}
@@ -42,8 +42,15 @@ pub use entry::entry::Entry;
 
 pub mod resource {
     pub mod resource;
+    pub mod resources_into_rust;
 }
 pub use resource::resource::Resource;
+pub use resource::resources_into_rust::{
+    DOMAIN_RESOURCE, NON_DOMAIN_RESOURCES, RESOURCE, SEARCH_PARAMETERS_BLOCK, resources_by_base,
+    resources_into_rust, resources_into_rust_search_parameters_block, search_param_type_into_rust,
+    search_parameter_ancestors, search_parameter_description, search_parameter_expression,
+    search_parameter_variant_name, search_parameters_enum_name,
+};
 
 pub mod snapshot {
     pub mod snapshot;
@@ -0,0 +1,791 @@
+//! Resources into Rust
+//!
+//! Generate the search parameters of each resource type, from the
+//! SearchParameters of search-parameters.json:
+//!
+//! - `mod.rs`: the definition struct, and the enums of search parameter
+//!   types, modifiers and comparators.
+//!
+//! - One file per base resource type, such as `observation.rs`: a constant
+//!   definition per search parameter, and an enum, such as
+//!   `ObservationSearchParameter`, with a variant per search parameter.
+//!
+//! Each resource type's enum includes the search parameters that it
+//! inherits: those of `Resource`, such as `_id` and `_lastUpdated`, and
+//! those of `DomainResource`, such as `_text`, for each resource type except
+//! the few that are not domain resources. The inherited definitions stay in
+//! `resource.rs` and `domain_resource.rs`. A definition of a more derived
+//! type replaces a definition with the same code.
+//!
+//! The definitions have no modifiers, so the allowed modifiers of each
+//! search parameter come from its type, as in the FHIR R5 modifier table.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::markdown_into_rustdoc;
+use crate::r5::parse::search_parameters::*;
+use crate::r5::parse::value_sets::code_into_rust_variant_name;
+use crate::util::{format_rust_source, write_if_changed};
+use ::convert_case::{Case, Casing};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// The base type of every resource type.
+pub const RESOURCE: &str = "Resource";
+
+/// The base type of every resource type, except the `NON_DOMAIN_RESOURCES`.
+pub const DOMAIN_RESOURCE: &str = "DomainResource";
+
+/// The resource types that derive from `Resource` rather than from
+/// `DomainResource`, so they have no narrative.
+pub const NON_DOMAIN_RESOURCES: &[&str] = &["Binary", "Bundle", "Parameters"];
+
+/// The runtime types of the generated search parameters: the `mod.rs` file
+/// before its module declarations.
+pub const SEARCH_PARAMETERS_BLOCK: &str = r#"//! Search parameters
+//!
+//! The search parameters of each resource type, including the search
+//! parameters that it inherits from `Resource` and `DomainResource`.
+
+/// The type of a search parameter, which defines how it matches values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchParamType {
+    Number,
+    Date,
+    String,
+    Token,
+    Reference,
+    Composite,
+    Quantity,
+    Uri,
+    Special,
+}
+
+impl SearchParamType {
+    /// The code, such as `token`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Number => "number",
+            Self::Date => "date",
+            Self::String => "string",
+            Self::Token => "token",
+            Self::Reference => "reference",
+            Self::Composite => "composite",
+            Self::Quantity => "quantity",
+            Self::Uri => "uri",
+            Self::Special => "special",
+        }
+    }
+
+    /// The modifiers that a search parameter of this type allows.
+    pub fn modifiers(self) -> &'static [SearchModifier] {
+        use SearchModifier::*;
+        match self {
+            Self::Number | Self::Date | Self::Quantity => &[Missing],
+            Self::String => &[Contains, Exact, Missing, Text],
+            Self::Token => &[
+                Above,
+                Below,
+                CodeText,
+                In,
+                Missing,
+                Not,
+                NotIn,
+                OfType,
+                Text,
+                TextAdvanced,
+            ],
+            Self::Reference => &[
+                Above,
+                Below,
+                CodeText,
+                Identifier,
+                Missing,
+                Text,
+                TextAdvanced,
+                Type,
+            ],
+            Self::Uri => &[Above, Below, Contains, Missing],
+            Self::Composite | Self::Special => &[],
+        }
+    }
+}
+
+/// A search modifier, such as `:missing` in `gender:missing=true`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchModifier {
+    Above,
+    Below,
+    CodeText,
+    Contains,
+    Exact,
+    Identifier,
+    In,
+    Missing,
+    Not,
+    NotIn,
+    OfType,
+    Text,
+    TextAdvanced,
+    /// A resource type, such as `:Patient` in `subject:Patient=123`.
+    Type,
+}
+
+impl SearchModifier {
+    /// The code, such as `missing`, or `[type]` for a resource type.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Above => "above",
+            Self::Below => "below",
+            Self::CodeText => "code-text",
+            Self::Contains => "contains",
+            Self::Exact => "exact",
+            Self::Identifier => "identifier",
+            Self::In => "in",
+            Self::Missing => "missing",
+            Self::Not => "not",
+            Self::NotIn => "not-in",
+            Self::OfType => "of-type",
+            Self::Text => "text",
+            Self::TextAdvanced => "text-advanced",
+            Self::Type => "[type]",
+        }
+    }
+}
+
+/// A search comparator, such as `ge` in `date=ge2020-01-01`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchComparator {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Sa,
+    Eb,
+    Ap,
+}
+
+impl SearchComparator {
+    /// The code, such as `ge`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Eq => "eq",
+            Self::Ne => "ne",
+            Self::Gt => "gt",
+            Self::Lt => "lt",
+            Self::Ge => "ge",
+            Self::Le => "le",
+            Self::Sa => "sa",
+            Self::Eb => "eb",
+            Self::Ap => "ap",
+        }
+    }
+}
+
+/// One component of a composite search parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SearchParameterComponent {
+    /// The URL of the component's search parameter definition.
+    pub definition: &'static str,
+
+    /// The FHIRPath expression of the component, relative to the composite.
+    pub expression: &'static str,
+}
+
+/// The definition of one search parameter of one resource type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SearchParameterDefinition {
+    /// The code in a search URL, such as `_id` or `code`.
+    pub code: &'static str,
+
+    /// The canonical URL of the SearchParameter.
+    pub url: &'static str,
+
+    pub r#type: SearchParamType,
+
+    /// The FHIRPath expression of the values, for this resource type, if
+    /// any; a special search parameter may have none.
+    pub expression: Option<&'static str>,
+
+    /// The resource types that a reference search parameter may refer to.
+    pub targets: &'static [&'static str],
+
+    pub comparators: &'static [SearchComparator],
+
+    pub components: &'static [SearchParameterComponent],
+
+    pub multiple_or: Option<bool>,
+}
+
+impl SearchParameterDefinition {
+    /// The modifiers that this search parameter allows, by its type.
+    pub fn modifiers(&self) -> &'static [SearchModifier] {
+        self.r#type.modifiers()
+    }
+}
+"#;
+
+/// FHIR search parameter type code => Rust variant of `SearchParamType`.
+///
+/// Example:
+///
+/// ```no_run
+/// search_param_type_into_rust("token") => "SearchParamType::Token"
+/// ```
+///
+#[allow(dead_code)]
+pub fn search_param_type_into_rust(code: &str) -> SourceCodeString {
+    format!("SearchParamType::{}", code.to_case(Case::Pascal))
+}
+
+/// FHIR search parameter code => Rust variant name.
+///
+/// Example:
+///
+/// ```no_run
+/// search_parameter_variant_name("code-value-quantity") => "CodeValueQuantity"
+/// search_parameter_variant_name("_lastUpdated") => "UnderscoreLastUpdated"
+/// ```
+///
+/// A leading underscore becomes `Underscore`, as with JSON keys, so that
+/// `_language` and `language` differ.
+///
+#[allow(dead_code)]
+pub fn search_parameter_variant_name(code: &str) -> SourceCodeString {
+    match code.strip_prefix('_') {
+        Some(rest) => format!("Underscore{}", code_into_rust_variant_name(rest)),
+        None => code_into_rust_variant_name(code),
+    }
+}
+
+/// The parts of a FHIRPath expression that apply to the resource type.
+///
+/// Example:
+///
+/// ```no_run
+/// search_parameter_expression("Account.name | Patient.name", "Patient") => "Patient.name"
+/// ```
+///
+/// An expression of many resource types is a union, with parts that start
+/// with the resource type. When no part starts with the resource type, the
+/// expression is whole.
+///
+#[allow(dead_code)]
+pub fn search_parameter_expression(expression: &str, base: &str) -> String {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    let bytes = expression.as_bytes();
+    for (i, byte) in bytes.iter().enumerate() {
+        match byte {
+            b'(' => depth += 1,
+            b')' => depth -= 1,
+            b'|' if depth == 0 => {
+                parts.push(expression[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(expression[start..].trim());
+    let kept: Vec<&str> = parts
+        .into_iter()
+        .filter(|part| {
+            let part = part.trim_start_matches('(');
+            part == base
+                || part.starts_with(&format!("{base}."))
+                || part.starts_with(&format!("{base} "))
+        })
+        .collect();
+    if kept.is_empty() {
+        String::from(expression.trim())
+    } else {
+        kept.join(" | ")
+    }
+}
+
+/// The description of a search parameter for the resource type: the line
+/// of a description of many resource types that links the resource type,
+/// or else the first line.
+///
+/// Example:
+///
+/// ```no_run
+/// search_parameter_description("Multiple Resources: \r\n\r\n* [Patient](patient.html): A name", "Patient")
+/// => "A name"
+/// ```
+///
+#[allow(dead_code)]
+pub fn search_parameter_description(description: &str, base: &str) -> String {
+    let prefix = format!("* [{base}](");
+    let line = description
+        .lines()
+        .map(str::trim)
+        .find_map(|line| {
+            line.strip_prefix(&prefix)
+                .and_then(|rest| rest.split_once("): "))
+                .map(|(_, text)| text)
+        })
+        .or_else(|| description.lines().map(str::trim).find(|x| !x.is_empty()))
+        .unwrap_or_default();
+    String::from(line)
+}
+
+/// The SearchParameters of each base resource type, in order, with one
+/// SearchParameter per code.
+#[allow(dead_code)]
+pub fn resources_by_base(resources: &[Resource]) -> BTreeMap<&str, Vec<&Resource>> {
+    let mut map: BTreeMap<&str, Vec<&Resource>> = BTreeMap::new();
+    for resource in resources
+        .iter()
+        .filter(|x| x.resource_type == "SearchParameter")
+    {
+        for base in resource.base.iter().flatten() {
+            let list = map.entry(base.as_str()).or_default();
+            if !list.iter().any(|x| x.code == resource.code) {
+                list.push(resource);
+            }
+        }
+    }
+    for list in map.values_mut() {
+        list.sort_by(|a, b| a.code.cmp(&b.code));
+    }
+    map
+}
+
+/// The base types that a resource type inherits search parameters from, in
+/// order from the most generic.
+#[allow(dead_code)]
+pub fn search_parameter_ancestors(base: &str) -> &'static [&'static str] {
+    match base {
+        RESOURCE => &[],
+        DOMAIN_RESOURCE => &[RESOURCE],
+        _ if NON_DOMAIN_RESOURCES.contains(&base) => &[RESOURCE],
+        _ => &[RESOURCE, DOMAIN_RESOURCE],
+    }
+}
+
+/// The widest line that rustfmt keeps.
+const MAX_WIDTH: usize = 100;
+
+/// The widest array literal that rustfmt keeps on one line.
+const MAX_ARRAY_WIDTH: usize = 60;
+
+/// One search parameter of a resource type, with the module that defines
+/// it, which is not the resource type's module when it is inherited.
+struct SearchParameterItem<'a> {
+    module: SourceCodeString,
+    variant: SourceCodeString,
+    resource: &'a Resource,
+}
+
+/// A list of Rust items, on one line when it fits in the width, or else one
+/// item per line, as rustfmt wraps it.
+fn list_block(prefix: &str, items: &[String], suffix: &str, indent: &str) -> SourceCodeString {
+    let line = format!("{indent}{prefix}{}{suffix}", items.join(", "));
+    let array_width = items.join(", ").chars().count();
+    if items.is_empty() || (line.chars().count() <= MAX_WIDTH && array_width <= MAX_ARRAY_WIDTH) {
+        return line + "\n";
+    }
+    let mut out = format!("{indent}{prefix}\n");
+    for item in items {
+        out += &format!("{indent}    {item},\n");
+    }
+    out + indent + suffix + "\n"
+}
+
+/// One field of a struct literal, with a string value, as rustfmt wraps it.
+fn field_option_str(name: &str, value: Option<&str>) -> SourceCodeString {
+    let value = match value {
+        Some(x) => format!("Some({x:?})"),
+        None => String::from("None"),
+    };
+    let line = format!("    {name}: {value},");
+    if line.chars().count() <= MAX_WIDTH {
+        return line + "\n";
+    }
+    let inner = &value["Some(".len()..value.len() - 1];
+    format!("    {name}: Some(\n        {inner},\n    ),\n")
+}
+
+/// SearchParameter => Rust constant of its definition for the base type.
+fn search_parameter_into_rust_const(resource: &Resource, base: &str) -> SourceCodeString {
+    let code = resource.code.as_deref().unwrap_or_default();
+    let description =
+        search_parameter_description(resource.description.as_deref().unwrap_or_default(), base);
+    let mut doc = format!("/// `{code}`");
+    if !description.is_empty() {
+        doc += &format!(": {}", markdown_into_rustdoc(&description));
+    }
+    doc += "\n";
+    let mut out = String::new();
+    out += &format!("    code: {code:?},\n");
+    out += &format!("    url: {:?},\n", resource.url);
+    out += &format!(
+        "    r#type: {},\n",
+        search_param_type_into_rust(resource.r#type.as_deref().unwrap_or("special"))
+    );
+    let expression = resource
+        .expression
+        .as_deref()
+        .map(|x| search_parameter_expression(x, base));
+    out += &field_option_str("expression", expression.as_deref());
+    let targets: Vec<String> = resource
+        .target
+        .iter()
+        .flatten()
+        .map(|x| format!("{x:?}"))
+        .collect();
+    out += &list_block("targets: &[", &targets, "],", "    ");
+    let comparators: Vec<String> = resource
+        .comparator
+        .iter()
+        .flatten()
+        .map(|x| format!("SearchComparator::{}", x.to_case(Case::Pascal)))
+        .collect();
+    out += &list_block("comparators: &[", &comparators, "],", "    ");
+    let components: Vec<String> = resource
+        .component
+        .iter()
+        .flatten()
+        .map(|x| {
+            format!(
+                "SearchParameterComponent {{\n            definition: {:?},\n            expression: {:?},\n        }}",
+                x.definition, x.expression
+            )
+        })
+        .collect();
+    if components.is_empty() {
+        out += "    components: &[],\n";
+    } else {
+        out += "    components: &[\n";
+        for component in components {
+            out += &format!("        {component},\n");
+        }
+        out += "    ],\n";
+    }
+    out += &format!(
+        "    multiple_or: {},\n",
+        match resource.multiple_or {
+            Some(x) => format!("Some({x})"),
+            None => String::from("None"),
+        }
+    );
+    let constant = search_parameter_variant_name(code).to_case(Case::UpperSnake);
+    let line =
+        format!("pub const {constant}: SearchParameterDefinition = SearchParameterDefinition {{");
+    if line.chars().count() <= MAX_WIDTH {
+        return format!("{doc}{line}\n{out}}};\n");
+    }
+    // As rustfmt wraps it, when the first line is too wide.
+    let out: String = out.lines().map(|x| format!("    {x}\n")).collect();
+    format!(
+        "{doc}pub const {constant}: SearchParameterDefinition =\n    SearchParameterDefinition {{\n{out}    }};\n"
+    )
+}
+
+/// The Rust enum name of the search parameters of the base type.
+///
+/// Example:
+///
+/// ```no_run
+/// search_parameters_enum_name("Observation") => "ObservationSearchParameter"
+/// ```
+///
+#[allow(dead_code)]
+pub fn search_parameters_enum_name(base: &str) -> SourceCodeString {
+    format!("{base}SearchParameter")
+}
+
+/// The search parameters of the base type => Rust source code file block.
+///
+/// Example:
+///
+/// ```no_run
+/// let by_base = resources_by_base(&resources);
+/// let block = resources_into_rust_search_parameters_block("Observation", &by_base);
+/// ```
+///
+/// Outcome: the constants of Observation's own search parameters, and the
+/// enum `ObservationSearchParameter` of all its search parameters.
+///
+#[allow(dead_code)]
+pub fn resources_into_rust_search_parameters_block(
+    base: &str,
+    by_base: &BTreeMap<&str, Vec<&Resource>>,
+) -> SourceCodeString {
+    let name = search_parameters_enum_name(base);
+    let own = by_base.get(base).cloned().unwrap_or_default();
+    let mut items: Vec<SearchParameterItem> = vec![];
+    for ancestor in search_parameter_ancestors(base)
+        .iter()
+        .copied()
+        .chain([base])
+    {
+        let (module, resources) = if ancestor == base {
+            (String::from("self"), own.clone())
+        } else {
+            (
+                format!("super::{}", ancestor.to_case(Case::Snake)),
+                by_base.get(ancestor).cloned().unwrap_or_default(),
+            )
+        };
+        for resource in resources {
+            items.retain(|x| x.resource.code != resource.code);
+            items.push(SearchParameterItem {
+                module: module.clone(),
+                variant: search_parameter_variant_name(
+                    resource.code.as_deref().unwrap_or_default(),
+                ),
+                resource,
+            });
+        }
+    }
+
+    let mut out = format!("//! {base} search parameters\n\n");
+    out += "use super::*;\n";
+    for resource in &own {
+        out += "\n";
+        out += &search_parameter_into_rust_const(resource, base);
+    }
+    if items.is_empty() {
+        return out;
+    }
+
+    out += &format!("\n/// The search parameters of {base}.\n");
+    out += "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]\n";
+    out += &format!("pub enum {name} {{\n");
+    for item in &items {
+        out += &format!(
+            "    /// `{}`\n",
+            item.resource.code.as_deref().unwrap_or_default()
+        );
+        out += &format!("    {},\n", item.variant);
+    }
+    out += "}\n";
+
+    out += &format!("\nimpl {name} {{\n");
+    out += "    /// Every search parameter, inherited ones first.\n";
+    let variants: Vec<String> = items
+        .iter()
+        .map(|x| format!("Self::{}", x.variant))
+        .collect();
+    out += &list_block(
+        "pub const ALL: &'static [Self] = &[",
+        &variants,
+        "];",
+        "    ",
+    );
+    out += "\n    /// The definition.\n";
+    out += "    pub fn definition(self) -> &'static SearchParameterDefinition {\n";
+    out += "        match self {\n";
+    for item in &items {
+        let constant = item.variant.to_case(Case::UpperSnake);
+        let path = if item.module == "self" {
+            constant
+        } else {
+            format!("{}::{constant}", item.module)
+        };
+        out += &format!("            Self::{} => &{path},\n", item.variant);
+    }
+    out += "        }\n    }\n";
+    out += "\n    /// The code in a search URL, such as `_id`.\n";
+    out += "    pub fn code(self) -> &'static str {\n        self.definition().code\n    }\n";
+    out += "\n    /// The search parameter of the given code, if any.\n";
+    out += "    pub fn from_code(code: &str) -> Option<Self> {\n";
+    out += "        Self::ALL.iter().copied().find(|x| x.code() == code)\n    }\n}\n";
+    out += &format!(
+        "\nimpl std::fmt::Display for {name} {{\n    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{\n        f.write_str(self.code())\n    }}\n}}\n"
+    );
+    out += &format!(
+        r#"
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    type T = {name};
+
+    #[test]
+    fn test_from_code() {{
+        for x in T::ALL {{
+            assert_eq!(T::from_code(x.code()), Some(*x));
+        }}
+    }}
+}}
+"#
+    );
+    out
+}
+
+/// FHIR SearchParameters => Rust source code files in the directory: one
+/// per base resource type, and `mod.rs`.
+///
+/// Example:
+///
+/// ```no_run
+/// let resources = … // e.g. every resource of search-parameters.json.
+/// let result = resources_into_rust(&resources, dir);
+/// ```
+///
+/// Outcome: `{dir}/account.rs`, …, `{dir}/resource.rs`, …, and
+/// `{dir}/mod.rs`, each formatted, and written only when its content
+/// changed.
+///
+#[allow(dead_code)]
+pub fn resources_into_rust(resources: &[Resource], dir: &Path) -> std::io::Result<()> {
+    let by_base = resources_by_base(resources);
+    let bases: BTreeSet<&str> = by_base
+        .keys()
+        .copied()
+        .chain([RESOURCE, DOMAIN_RESOURCE])
+        .collect();
+    let mut mod_block = format!("{SEARCH_PARAMETERS_BLOCK}\n");
+    for base in &bases {
+        let module = base.to_case(Case::Snake);
+        write_if_changed(
+            &dir.join(format!("{module}.rs")),
+            format_rust_source(&resources_into_rust_search_parameters_block(base, &by_base)),
+        )?;
+        mod_block += &format!("pub mod {module};\n");
+    }
+    mod_block += "\n";
+    for base in &bases {
+        mod_block += &format!(
+            "pub use {}::{};\n",
+            base.to_case(Case::Snake),
+            search_parameters_enum_name(base)
+        );
+    }
+    write_if_changed(&dir.join("mod.rs"), format_rust_source(&mod_block))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search_parameter(code: &str, base: &[&str], r#type: &str, expression: &str) -> Resource {
+        Resource {
+            resource_type: "SearchParameter".into(),
+            url: format!("http://hl7.org/fhir/SearchParameter/{}-{code}", base[0]),
+            code: Some(String::from(code)),
+            base: Some(base.iter().map(|x| String::from(*x)).collect()),
+            r#type: Some(String::from(r#type)),
+            expression: Some(String::from(expression)).filter(|x| !x.is_empty()),
+            ..Resource::default()
+        }
+    }
+
+    fn resources() -> Vec<Resource> {
+        vec![
+            search_parameter("_id", &["Resource"], "token", "Resource.id"),
+            search_parameter("_text", &["Resource"], "string", ""),
+            search_parameter("_text", &["DomainResource"], "special", ""),
+            search_parameter(
+                "name",
+                &["Account", "Patient"],
+                "string",
+                "Account.name | Patient.name",
+            ),
+            search_parameter(
+                "language",
+                &["Patient"],
+                "token",
+                "Patient.communication.language",
+            ),
+            search_parameter("type", &["Bundle"], "token", "Bundle.type"),
+        ]
+    }
+
+    #[test]
+    fn test_search_parameter_variant_name() {
+        assert_eq!(
+            search_parameter_variant_name("_lastUpdated"),
+            "UnderscoreLastUpdated"
+        );
+        assert_eq!(
+            search_parameter_variant_name("code-value-quantity"),
+            "CodeValueQuantity"
+        );
+    }
+
+    #[test]
+    fn test_search_parameter_expression() {
+        assert_eq!(
+            search_parameter_expression("Account.name | Patient.name", "Patient"),
+            "Patient.name"
+        );
+        assert_eq!(
+            search_parameter_expression(
+                "(Account.a | Account.b) | Patient.value.ofType(Quantity) | (Patient.c)",
+                "Patient"
+            ),
+            "Patient.value.ofType(Quantity) | (Patient.c)"
+        );
+        assert_eq!(
+            search_parameter_expression("Bundle.entry[0].resource", "Composition"),
+            "Bundle.entry[0].resource"
+        );
+    }
+
+    #[test]
+    fn test_search_parameter_description() {
+        let description = "Multiple Resources: \r\n\r\n* [Account](account.html): A\r\n* [Patient](patient.html): B\r\n";
+        assert_eq!(search_parameter_description(description, "Patient"), "B");
+        assert_eq!(
+            search_parameter_description(description, "Group"),
+            "Multiple Resources:"
+        );
+    }
+
+    #[test]
+    fn test_search_parameter_ancestors() {
+        assert_eq!(search_parameter_ancestors("Resource"), &[] as &[&str]);
+        assert_eq!(search_parameter_ancestors("Bundle"), &["Resource"]);
+        assert_eq!(
+            search_parameter_ancestors("Patient"),
+            &["Resource", "DomainResource"]
+        );
+    }
+
+    #[test]
+    fn test_resources_into_rust_search_parameters_block() {
+        let resources = resources();
+        let by_base = resources_by_base(&resources);
+        let actual = resources_into_rust_search_parameters_block("Patient", &by_base);
+        assert!(actual.contains("pub const NAME: SearchParameterDefinition"));
+        assert!(actual.contains("    expression: Some(\"Patient.name\"),\n"));
+        assert!(actual.contains("Self::UnderscoreId => &super::resource::UNDERSCORE_ID,"));
+        assert!(
+            actual.contains("Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,")
+        );
+        assert!(actual.contains("Self::Language => &LANGUAGE,"));
+        assert!(!actual.contains("pub const UNDERSCORE_ID"));
+
+        let actual = resources_into_rust_search_parameters_block("Bundle", &by_base);
+        assert!(actual.contains("Self::UnderscoreText => &super::resource::UNDERSCORE_TEXT,"));
+    }
+
+    #[test]
+    fn test_resources_into_rust() {
+        let dir = std::env::temp_dir().join(format!(
+            "search_parameters_resources_into_rust_{}",
+            std::process::id()
+        ));
+        resources_into_rust(&resources(), &dir).unwrap();
+        let actual = std::fs::read_to_string(dir.join("mod.rs")).unwrap();
+        assert!(actual.starts_with(&format_rust_source(SEARCH_PARAMETERS_BLOCK)));
+        assert!(actual.contains("pub mod domain_resource;\n"));
+        assert!(actual.contains("pub use patient::PatientSearchParameter;\n"));
+        for name in [
+            "account",
+            "bundle",
+            "domain_resource",
+            "patient",
+            "resource",
+        ] {
+            assert!(dir.join(format!("{name}.rs")).exists(), "{name}");
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
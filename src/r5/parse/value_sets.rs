@@ -18,6 +18,19 @@ pub mod bundle {
 }
 pub use bundle::bundle::Bundle;
 
+pub mod code_system {
+    pub mod code_system;
+    pub mod code_system_into_rust;
+}
+pub use code_system::code_system::{
+    CodeSystem, CodeSystemConcept, CodeSystemConceptProperty, resource_into_code_system,
+};
+pub use code_system::code_system_into_rust::{
+    CodeSystemVariant, code_into_rust_variant_name, code_system_into_rust,
+    code_system_into_rust_block, code_system_module_name, code_system_type_name,
+    code_system_variants, code_systems_into_rust,
+};
+
 pub mod compose {
     pub mod compose;
 }
@@ -0,0 +1,143 @@
+//! Parse FHIR R5 specifications JSON file.
+//!
+//! For an example see the sibling file of JSON.
+//!
+//! A CodeSystem is one resource type of valuesets.json, read from the
+//! generic `Resource`. Its concepts nest, so each concept may have child
+//! concepts.
+//!
+//! Unlike the other structs of this module, these structs allow unknown
+//! fields, because they read only the fields that code generation needs.
+
+use crate::r5::parse::value_sets::*;
+use ::serde::{Deserialize, Serialize};
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeSystem {
+    /// Example: "publication-status"
+    pub id: String,
+
+    /// Example: "http://hl7.org/fhir/publication-status"
+    pub url: Option<String>,
+
+    /// Example: "5.0.0"
+    pub version: Option<String>,
+
+    /// Example: "PublicationStatus"
+    pub name: Option<String>,
+
+    /// Example: "PublicationStatus"
+    pub title: Option<String>,
+
+    /// Example: "The lifecycle status of an artifact."
+    pub description: Option<String>,
+
+    /// Example: true
+    pub case_sensitive: Option<bool>,
+
+    /// Example: "is-a"
+    pub hierarchy_meaning: Option<String>,
+
+    /// Example: draft, active, retired, unknown.
+    #[serde(default)]
+    pub concept: Vec<CodeSystemConcept>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeSystemConcept {
+    /// Example: "draft"
+    pub code: String,
+
+    /// Example: "Draft"
+    pub display: Option<String>,
+
+    /// Example: "This resource is still under development …"
+    pub definition: Option<String>,
+
+    /// Example: a property with code "parent" and a code value.
+    #[serde(default)]
+    pub property: Vec<CodeSystemConceptProperty>,
+
+    /// The child concepts.
+    #[serde(default)]
+    pub concept: Vec<CodeSystemConcept>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeSystemConceptProperty {
+    /// Example: "parent"
+    pub code: String,
+
+    /// Example: "draft"
+    pub value_code: Option<String>,
+}
+
+/// FHIR CodeSystem resource => its code system, or none for another
+/// resource type, such as a ValueSet.
+///
+/// Example:
+///
+/// ```no_run
+/// let resource = … // e.g. resource id publication-status.
+/// let code_system = resource_into_code_system(&resource);
+/// ```
+///
+#[allow(dead_code)]
+pub fn resource_into_code_system(resource: &Resource) -> Option<CodeSystem> {
+    if resource.resource_type != "CodeSystem" {
+        return None;
+    }
+    ::serde_json::to_value(resource)
+        .and_then(::serde_json::from_value)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    type T = CodeSystem;
+
+    #[test]
+    fn test_serde_json_from_reader() {
+        let path = crate::r5::parse::value_sets::DIR
+            .join("code_system")
+            .join("code_system.json");
+        let file = std::fs::File::open(path).expect("open");
+        let reader = std::io::BufReader::new(file);
+        let actual: T = ::serde_json::from_reader(reader).unwrap();
+        assert_eq!(actual.name.as_deref(), Some("PublicationStatus"));
+        assert_eq!(actual.concept.len(), 4);
+    }
+
+    #[test]
+    fn test_resource_into_code_system() {
+        let resource = Resource {
+            resource_type: "CodeSystem".into(),
+            id: "publication-status".into(),
+            status: "active".into(),
+            url: Some(String::from("http://hl7.org/fhir/publication-status")),
+            concept: Some(::serde_json::json!([
+                { "code": "draft", "concept": [{ "code": "active" }] }
+            ])),
+            ..Resource::default()
+        };
+        let actual = resource_into_code_system(&resource).unwrap();
+        assert_eq!(actual.id, "publication-status");
+        assert_eq!(actual.concept[0].concept[0].code, "active");
+    }
+
+    #[test]
+    fn test_resource_into_code_system_with_other_resource_type() {
+        let resource = Resource {
+            resource_type: "ValueSet".into(),
+            ..Resource::default()
+        };
+        assert_eq!(resource_into_code_system(&resource), None);
+    }
+}
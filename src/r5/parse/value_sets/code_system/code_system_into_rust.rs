@@ -0,0 +1,544 @@
+//! Code system into Rust
+//!
+//! Generate one Rust file per CodeSystem, with its constants and an enum of
+//! its codes:
+//!
+//! - `URL` and `VERSION` constants.
+//!
+//! - An enum with one variant per concept, in the order of the code system,
+//!   including nested concepts. Each variant has methods for its code,
+//!   display and definition, and serializes as its code.
+//!
+//! - Hierarchy helpers: `parents` and `is_a`, from nested concepts, and from
+//!   the concept properties `parent` and `subsumedBy`.
+//!
+//! Nested concepts and `parent` properties mean subsumption only when the
+//! code system's hierarchy meaning is `is-a`, or has no value; otherwise,
+//! only `subsumedBy` properties make parents.
+
+use crate::SourceCodeString;
+use crate::r5::parse::profiles_types::markdown_into_rustdoc;
+use crate::r5::parse::value_sets::*;
+use crate::util::{format_rust_source, write_if_changed};
+use ::convert_case::{Case, Casing};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// One concept of a code system, flattened from its nested concepts, with
+/// its Rust variant name and the codes of its parents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeSystemVariant<'a> {
+    pub name: SourceCodeString,
+    pub concept: &'a CodeSystemConcept,
+    pub parents: Vec<&'a str>,
+}
+
+/// Given a code, return a Rust identifier in Pascal case.
+///
+/// Example:
+///
+/// ```no_run
+/// code_into_rust_variant_name("entered-in-error") => "EnteredInError"
+/// code_into_rust_variant_name("<=") => "LessOrEqual"
+/// code_into_rust_variant_name("1.2") => "Code1_2"
+/// ```
+///
+/// A code may have no letters or digits, such as a comparator, so a few
+/// symbols have names; any other code of symbols is `Code`.
+///
+#[allow(dead_code)]
+pub fn code_into_rust_variant_name(code: &str) -> SourceCodeString {
+    let symbol = match code {
+        "<" => Some("LessThan"),
+        "<=" => Some("LessOrEqual"),
+        ">" => Some("GreaterThan"),
+        ">=" => Some("GreaterOrEqual"),
+        "=" => Some("Equal"),
+        "!=" => Some("NotEqual"),
+        "*" => Some("Any"),
+        _ => None,
+    };
+    if let Some(symbol) = symbol {
+        return String::from(symbol);
+    }
+    let words: Vec<&str> = code
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|x| !x.is_empty())
+        .collect();
+    let name = words
+        .iter()
+        .map(|word| word.to_case(Case::Pascal))
+        .collect::<Vec<_>>();
+    match name.first() {
+        None => String::from("Code"),
+        Some(first) if first.starts_with(|c: char| c.is_ascii_digit()) => {
+            format!("Code{}", name.join("_"))
+        }
+        Some(_) => name.concat(),
+    }
+}
+
+/// The Rust type name of a code system, from its name, or else its id.
+///
+/// Example:
+///
+/// ```no_run
+/// code_system_type_name(&code_system) => "PublicationStatus"
+/// ```
+///
+#[allow(dead_code)]
+pub fn code_system_type_name(code_system: &CodeSystem) -> SourceCodeString {
+    let name = code_system.name.as_deref().unwrap_or(&code_system.id);
+    if name.chars().all(|c| c.is_ascii_alphanumeric()) && name.starts_with(char::is_uppercase) {
+        return String::from(name);
+    }
+    code_into_rust_variant_name(name)
+}
+
+/// The Rust module name of a code system.
+///
+/// Example:
+///
+/// ```no_run
+/// code_system_module_name(&code_system) => "publication_status"
+/// ```
+///
+#[allow(dead_code)]
+pub fn code_system_module_name(code_system: &CodeSystem) -> SourceCodeString {
+    code_system_type_name(code_system).to_case(Case::Snake)
+}
+
+/// Flatten the concepts of a code system, depth first, with a unique
+/// variant name and the parent codes of each.
+///
+/// A code that repeats in the code system has one variant, the first.
+///
+#[allow(dead_code)]
+pub fn code_system_variants(code_system: &CodeSystem) -> Vec<CodeSystemVariant<'_>> {
+    let is_a = matches!(
+        code_system.hierarchy_meaning.as_deref(),
+        None | Some("is-a")
+    );
+    let mut variants = vec![];
+    let mut stack: Vec<(&CodeSystemConcept, Option<&str>)> = code_system
+        .concept
+        .iter()
+        .rev()
+        .map(|x| (x, None))
+        .collect();
+    let mut codes = BTreeSet::new();
+    let mut names = BTreeSet::new();
+    while let Some((concept, parent)) = stack.pop() {
+        stack.extend(
+            concept
+                .concept
+                .iter()
+                .rev()
+                .map(|x| (x, Some(concept.code.as_str()))),
+        );
+        if !codes.insert(concept.code.as_str()) {
+            continue;
+        }
+        let mut parents: Vec<&str> = vec![];
+        for (code, property) in parent
+            .filter(|_| is_a)
+            .map(|x| (x, "parent"))
+            .into_iter()
+            .chain(concept.property.iter().filter_map(|property| {
+                Some((property.value_code.as_deref()?, property.code.as_str()))
+            }))
+        {
+            if (property == "subsumedBy" || (is_a && property == "parent"))
+                && !parents.contains(&code)
+            {
+                parents.push(code);
+            }
+        }
+        let base = code_into_rust_variant_name(&concept.code);
+        let mut name = base.clone();
+        let mut i = 2;
+        while !names.insert(name.clone()) {
+            name = format!("{base}{i}");
+            i += 1;
+        }
+        variants.push(CodeSystemVariant {
+            name,
+            concept,
+            parents,
+        });
+    }
+    // A parent must be a code of the code system.
+    let known: BTreeSet<&str> = variants.iter().map(|x| x.concept.code.as_str()).collect();
+    for variant in &mut variants {
+        variant.parents.retain(|x| known.contains(x));
+    }
+    variants
+}
+
+/// Given text, return the lines of a doc comment, each with the prefix.
+fn doc_comment(text: &str, prefix: &str) -> SourceCodeString {
+    markdown_into_rustdoc(text)
+        .lines()
+        .map(|line| format!("{prefix}{line}").trim_end().to_string() + "\n")
+        .collect()
+}
+
+/// The widest line that rustfmt keeps.
+const MAX_WIDTH: usize = 100;
+
+/// The widest array literal that rustfmt keeps on one line.
+const MAX_ARRAY_WIDTH: usize = 60;
+
+/// One match arm that returns an optional string, wrapped as rustfmt wraps
+/// it.
+fn match_arm_option_str(name: &str, value: Option<&str>) -> SourceCodeString {
+    let value = match value {
+        Some(x) => format!("Some({x:?})"),
+        None => String::from("None"),
+    };
+    let line = format!("            Self::{name} => {value},");
+    if line.chars().count() <= MAX_WIDTH {
+        return line + "\n";
+    }
+    if value.chars().count() + 16 <= MAX_WIDTH {
+        return format!(
+            "            Self::{name} => {{\n                {value}\n            }}\n"
+        );
+    }
+    let inner = &value["Some(".len()..value.len() - 1];
+    format!("            Self::{name} => Some(\n                {inner},\n            ),\n")
+}
+
+/// A list of variants, on one line after the prefix, or else one variant
+/// per line, as rustfmt wraps it.
+fn variant_list(prefix: &str, names: &[String], suffix: &str, indent: &str) -> SourceCodeString {
+    let items: Vec<String> = names.iter().map(|x| format!("Self::{x}")).collect();
+    let line = format!("{indent}{prefix}{}{suffix}", items.join(", "));
+    let array_width = items.join(", ").chars().count();
+    if items.is_empty() || (line.chars().count() <= MAX_WIDTH && array_width <= MAX_ARRAY_WIDTH) {
+        return line + "\n";
+    }
+    let mut out = format!("{indent}{prefix}\n");
+    for item in items {
+        out += &format!("{indent}    {item},\n");
+    }
+    out + indent + suffix + "\n"
+}
+
+/// FHIR CodeSystem => Rust source code.
+///
+/// Example:
+///
+/// ```no_run
+/// let code_system = … // e.g. publication-status.
+/// let block = code_system_into_rust_block(&code_system);
+/// ```
+///
+/// Outcome: the module of `PublicationStatus`, such as
+/// `src/r5/todo/publication_status.rs`. A code system without concepts has
+/// only its constants.
+///
+#[allow(dead_code)]
+pub fn code_system_into_rust_block(code_system: &CodeSystem) -> SourceCodeString {
+    let name = code_system_type_name(code_system);
+    let variants = code_system_variants(code_system);
+    let mut out = format!("//! {name}\n");
+    if let Some(description) = &code_system.description {
+        out += "//!\n";
+        out += &doc_comment(description, "//! ");
+    }
+    if let Some(url) = &code_system.url {
+        out += &format!("//!\n//! URL: <{url}>\n");
+    }
+    if !variants.is_empty() {
+        out += "\nuse ::serde::{Deserialize, Serialize};\n";
+    }
+    out += &format!(
+        "\n/// The canonical URL of the code system.\npub const URL: &str = {:?};\n",
+        code_system.url.as_deref().unwrap_or_default()
+    );
+    out += &format!(
+        "\n/// The version of the code system, if any.\npub const VERSION: Option<&str> = {};\n",
+        match &code_system.version {
+            Some(version) => format!("Some({version:?})"),
+            None => String::from("None"),
+        }
+    );
+    if variants.is_empty() {
+        return out;
+    }
+
+    // The enum.
+    out += "\n/// The codes of the code system.\n";
+    out += "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]\n";
+    out += &format!("pub enum {name} {{\n");
+    for (i, variant) in variants.iter().enumerate() {
+        if i > 0 {
+            out += "\n";
+        }
+        let concept = variant.concept;
+        out += &doc_comment(
+            concept.display.as_deref().unwrap_or(&concept.code),
+            "    /// ",
+        );
+        if let Some(definition) = &concept.definition {
+            out += "    ///\n";
+            out += &doc_comment(definition, "    /// ");
+        }
+        out += &format!("    #[serde(rename = {:?})]\n", concept.code);
+        out += &format!("    {},\n", variant.name);
+    }
+    out += "}\n";
+
+    // The methods.
+    out += &format!("\nimpl {name} {{\n");
+    out += "    /// Every code, in the order of the code system.\n";
+    let names: Vec<String> = variants.iter().map(|x| x.name.clone()).collect();
+    out += &variant_list("pub const ALL: &'static [Self] = &[", &names, "];", "    ");
+    out += "\n    /// The code, such as in JSON.\n";
+    out += "    pub fn code(self) -> &'static str {\n        match self {\n";
+    for variant in &variants {
+        out += &format!(
+            "            Self::{} => {:?},\n",
+            variant.name, variant.concept.code
+        );
+    }
+    out += "        }\n    }\n";
+    out += "\n    /// The display text, if any.\n";
+    out += "    pub fn display(self) -> Option<&'static str> {\n        match self {\n";
+    for variant in &variants {
+        out += &match_arm_option_str(&variant.name, variant.concept.display.as_deref());
+    }
+    out += "        }\n    }\n";
+    out += "\n    /// The definition, if any.\n";
+    out += "    pub fn definition(self) -> Option<&'static str> {\n        match self {\n";
+    for variant in &variants {
+        out += &match_arm_option_str(&variant.name, variant.concept.definition.as_deref());
+    }
+    out += "        }\n    }\n";
+    let compare = if code_system.case_sensitive == Some(false) {
+        "x.code().eq_ignore_ascii_case(code)"
+    } else {
+        "x.code() == code"
+    };
+    out += &format!(
+        "\n    /// The variant of the given code, if any.\n    pub fn from_code(code: &str) -> Option<Self> {{\n        Self::ALL.iter().copied().find(|x| {compare})\n    }}\n"
+    );
+    out += "\n    /// The codes that directly subsume this code.\n";
+    out += "    pub fn parents(self) -> &'static [Self] {\n        match self {\n";
+    for variant in &variants {
+        let parents: Vec<String> = variant
+            .parents
+            .iter()
+            .filter_map(|code| variants.iter().find(|x| x.concept.code == *code))
+            .map(|x| x.name.clone())
+            .collect();
+        let prefix = format!("Self::{} => &[", variant.name);
+        out += &variant_list(&prefix, &parents, "],", "            ");
+    }
+    out += "        }\n    }\n";
+    out += "\n    /// Is this code the given code, or subsumed by it?\n";
+    out += "    pub fn is_a(self, ancestor: Self) -> bool {\n";
+    out += "        self == ancestor || self.parents().iter().any(|x| x.is_a(ancestor))\n";
+    out += "    }\n}\n";
+    out += &format!(
+        "\nimpl std::fmt::Display for {name} {{\n    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{\n        f.write_str(self.code())\n    }}\n}}\n"
+    );
+
+    // The tests.
+    out += &format!(
+        r#"
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    type T = {name};
+
+    #[test]
+    fn test_from_code() {{
+        for x in T::ALL {{
+            assert_eq!(T::from_code(x.code()), Some(*x));
+        }}
+    }}
+
+    #[test]
+    fn test_serde_json() {{
+        for x in T::ALL {{
+            let value = ::serde_json::to_value(x).unwrap();
+            assert_eq!(value, ::serde_json::json!(x.code()));
+            assert_eq!(::serde_json::from_value::<T>(value).unwrap(), *x);
+        }}
+    }}
+
+    #[test]
+    fn test_is_a() {{
+        for x in T::ALL {{
+            assert!(x.is_a(*x));
+        }}
+    }}
+}}
+"#
+    );
+    out
+}
+
+/// FHIR CodeSystem => Rust source code file, in the directory.
+///
+/// Example:
+///
+/// ```no_run
+/// let code_system = … // e.g. publication-status.
+/// let result = code_system_into_rust(&code_system, dir);
+/// ```
+///
+/// Outcome: a Rust source code file at `{dir}/publication_status.rs`.
+///
+#[allow(dead_code)]
+pub fn code_system_into_rust(code_system: &CodeSystem, dir: &Path) -> std::io::Result<()> {
+    write_if_changed(
+        &dir.join(format!("{}.rs", code_system_module_name(code_system))),
+        format_rust_source(&code_system_into_rust_block(code_system)),
+    )?;
+    Ok(())
+}
+
+/// FHIR CodeSystems => Rust source code files, in the directory, and its
+/// module file, `mod.rs`.
+///
+/// When two code systems have the same module name, such as two versions,
+/// the first one wins. The module file is sorted by module name.
+///
+#[allow(dead_code)]
+pub fn code_systems_into_rust(code_systems: &[CodeSystem], dir: &Path) -> std::io::Result<()> {
+    let mut modules: BTreeMap<String, &CodeSystem> = BTreeMap::new();
+    for code_system in code_systems {
+        modules
+            .entry(code_system_module_name(code_system))
+            .or_insert(code_system);
+    }
+    let mut mod_block = String::from("//! Code systems\n\n");
+    for (module, code_system) in modules {
+        code_system_into_rust(code_system, dir)?;
+        mod_block += &format!("pub mod {module};\n");
+        if !code_system_variants(code_system).is_empty() {
+            mod_block += &format!(
+                "pub use {module}::{};\n",
+                code_system_type_name(code_system)
+            );
+        }
+    }
+    write_if_changed(&dir.join("mod.rs"), format_rust_source(&mod_block))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn concept(code: &str, concept: Vec<CodeSystemConcept>) -> CodeSystemConcept {
+        CodeSystemConcept {
+            code: code.into(),
+            concept,
+            ..CodeSystemConcept::default()
+        }
+    }
+
+    fn fixture() -> CodeSystem {
+        let path = crate::r5::parse::value_sets::DIR
+            .join("code_system")
+            .join("code_system.json");
+        ::serde_json::from_reader(std::fs::File::open(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_code_into_rust_variant_name() {
+        assert_eq!(code_into_rust_variant_name("draft"), "Draft");
+        assert_eq!(
+            code_into_rust_variant_name("entered-in-error"),
+            "EnteredInError"
+        );
+        assert_eq!(code_into_rust_variant_name("<="), "LessOrEqual");
+        assert_eq!(code_into_rust_variant_name("1.2"), "Code1_2");
+        assert_eq!(code_into_rust_variant_name("?"), "Code");
+    }
+
+    #[test]
+    fn test_code_system_type_name() {
+        let mut code_system = fixture();
+        assert_eq!(code_system_type_name(&code_system), "PublicationStatus");
+        assert_eq!(code_system_module_name(&code_system), "publication_status");
+        code_system.name = None;
+        assert_eq!(code_system_type_name(&code_system), "PublicationStatus");
+    }
+
+    #[test]
+    fn test_code_system_variants() {
+        let mut code_system = CodeSystem {
+            id: "alfa".into(),
+            concept: vec![
+                concept("a", vec![concept("b", vec![]), concept("a", vec![])]),
+                concept("c", vec![]),
+                concept("B", vec![]),
+            ],
+            ..CodeSystem::default()
+        };
+        code_system.concept[1]
+            .property
+            .push(CodeSystemConceptProperty {
+                code: "subsumedBy".into(),
+                value_code: Some(String::from("b")),
+            });
+        let actual: Vec<(String, Vec<&str>)> = code_system_variants(&code_system)
+            .into_iter()
+            .map(|x| (x.name, x.parents))
+            .collect();
+        let expect = vec![
+            (String::from("A"), vec![]),
+            (String::from("B"), vec!["a"]),
+            (String::from("C"), vec!["b"]),
+            (String::from("B2"), vec![]),
+        ];
+        assert_eq!(actual, expect);
+
+        code_system.hierarchy_meaning = Some(String::from("part-of"));
+        let actual: Vec<Vec<&str>> = code_system_variants(&code_system)
+            .into_iter()
+            .map(|x| x.parents)
+            .collect();
+        assert_eq!(actual, vec![vec![], vec![], vec!["b"], vec![]]);
+    }
+
+    /// The generated code of publication-status is the module
+    /// `r5::todo::publication_status`, so that it compiles, and its
+    /// generated tests run.
+    #[test]
+    fn test_code_system_into_rust_block() {
+        let actual = code_system_into_rust_block(&fixture());
+        let expect = include_str!("../../../todo/publication_status.rs");
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_code_system_into_rust_block_without_concepts() {
+        let code_system = CodeSystem {
+            id: "alfa".into(),
+            url: Some(String::from("http://example.com/alfa")),
+            ..CodeSystem::default()
+        };
+        let actual = code_system_into_rust_block(&code_system);
+        assert!(actual.contains("pub const URL: &str = \"http://example.com/alfa\";"));
+        assert!(actual.contains("pub const VERSION: Option<&str> = None;"));
+        assert!(!actual.contains("pub enum"));
+    }
+
+    #[test]
+    fn test_code_systems_into_rust() {
+        let dir =
+            std::env::temp_dir().join(format!("code_systems_into_rust_{}", std::process::id()));
+        code_systems_into_rust(&[fixture(), fixture()], &dir).unwrap();
+        let actual = std::fs::read_to_string(dir.join("mod.rs")).unwrap();
+        let expect = "//! Code systems\n\npub mod publication_status;\npub use publication_status::PublicationStatus;\n";
+        assert_eq!(actual, expect);
+        assert!(dir.join("publication_status.rs").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,15 @@
+//! Registries of loaded FHIR R5 definitions.
+//!
+//! A registry indexes definitions by canonical URL, so spec checks and code
+//! generation can resolve `baseDefinition`, profile, and extension references
+//! without rescanning the bundles.
+
+pub mod canonical_registry;
+pub mod extension_registry;
+pub mod structure_definition_registry;
+pub mod value_set_registry;
+
+pub use canonical_registry::{CanonicalEntry, CanonicalRegistry};
+pub use extension_registry::{ExtensionDefinition, ExtensionRegistry};
+pub use structure_definition_registry::StructureDefinitionRegistry;
+pub use value_set_registry::{CodeConcept, ValueSetRegistry};
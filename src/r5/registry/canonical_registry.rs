@@ -0,0 +1,99 @@
+//! CanonicalRegistry
+//!
+//! Index of every loaded canonical resource by URL: StructureDefinitions,
+//! ValueSets, CodeSystems, ConceptMaps, SearchParameters, and so on. One URL
+//! may be loaded in more than one version.
+
+use crate::util::split_canonical;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CanonicalEntry {
+    pub url: String,
+    pub version: Option<String>,
+    pub resource_type: String,
+    pub id: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CanonicalRegistry {
+    entries: BTreeMap<String, Vec<CanonicalEntry>>,
+}
+
+impl CanonicalRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, entry: CanonicalEntry) {
+        self.entries
+            .entry(entry.url.clone())
+            .or_default()
+            .push(entry);
+    }
+
+    /// Resolve a canonical reference, which may carry a `|version` suffix.
+    ///
+    /// An unversioned reference resolves to the first loaded version.
+    pub fn resolve(&self, canonical: &str) -> Option<&CanonicalEntry> {
+        let (url, version) = split_canonical(canonical);
+        self.entries.get(url)?.iter().find(|entry| match version {
+            Some(version) => entry.version.as_deref() == Some(version),
+            None => true,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CanonicalEntry> {
+        self.entries.values().flatten()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl FromIterator<CanonicalEntry> for CanonicalRegistry {
+    fn from_iter<I: IntoIterator<Item = CanonicalEntry>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        for entry in iter {
+            registry.insert(entry);
+        }
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    type T = CanonicalRegistry;
+
+    fn entry(version: &str) -> CanonicalEntry {
+        CanonicalEntry {
+            url: String::from("http://hl7.org/fhir/ValueSet/address-type"),
+            version: Some(version.into()),
+            resource_type: String::from("ValueSet"),
+            id: String::from("address-type"),
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let registry: T = [entry("4.0.1"), entry("5.0.0")].into_iter().collect();
+        let url = "http://hl7.org/fhir/ValueSet/address-type";
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.resolve(url), Some(&entry("4.0.1")));
+        assert_eq!(
+            registry.resolve(&format!("{url}|5.0.0")),
+            Some(&entry("5.0.0"))
+        );
+        assert_eq!(registry.resolve(&format!("{url}|3.0.2")), None);
+        assert_eq!(
+            registry.resolve("http://hl7.org/fhir/ValueSet/missing"),
+            None
+        );
+    }
+}
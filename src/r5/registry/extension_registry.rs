@@ -0,0 +1,253 @@
+//! ExtensionRegistry
+//!
+//! Index of extension definitions by URL, with the value types and the
+//! contexts that each definition allows.
+//!
+//! An extension definition is a StructureDefinition with `type` Extension and
+//! derivation constraint. Its `Extension.value[x]` element lists the allowed
+//! value types; a complex extension sets that element's max to "0".
+
+use crate::r5::parse::all::Context;
+use crate::r5::parse::profiles_types::{Bundle, Element, Resource};
+use crate::r5::registry::StructureDefinitionRegistry;
+use std::collections::BTreeMap;
+
+/// The extension definitions of the FHIR R5 specification, such as
+/// `structuredefinition-fmm`.
+///
+/// This file is not in the checked-in definitions directory, which has only
+/// profiles-types.json among the StructureDefinition bundles; see
+/// [`ExtensionRegistry::from_definitions_file`].
+pub static DEFINITIONS_FILE: std::sync::LazyLock<std::path::PathBuf> =
+    std::sync::LazyLock::new(|| crate::DEFINITIONS_DIR.join("extension-definitions.json"));
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExtensionDefinition {
+    pub url: String,
+    pub name: Option<String>,
+    pub contexts: Vec<Context>,
+
+    /// Allowed `value[x]` type codes; empty for a complex extension.
+    pub value_types: Vec<String>,
+}
+
+impl ExtensionDefinition {
+    /// Read an extension definition, or `None` when the resource is not one.
+    pub fn from_resource(resource: &Resource) -> Option<Self> {
+        if resource.r#type.as_deref() != Some("Extension")
+            || resource.derivation.as_deref() != Some("constraint")
+        {
+            return None;
+        }
+        let elements: &[Element] = resource
+            .snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.element.as_slice())
+            .or_else(|| {
+                resource
+                    .differential
+                    .as_ref()
+                    .map(|differential| differential.element.as_slice())
+            })
+            .unwrap_or_default();
+        let value_types = elements
+            .iter()
+            .find(|element| element.id == "Extension.value[x]")
+            .filter(|element| element.max.as_deref() != Some("0"))
+            .and_then(|element| element.r#type.as_ref())
+            .map(|types| types.iter().map(|x| x.code.clone()).collect())
+            .unwrap_or_default();
+        Some(Self {
+            url: resource.url.clone(),
+            name: resource.name.clone(),
+            contexts: resource.context.clone().unwrap_or_default(),
+            value_types,
+        })
+    }
+
+    pub fn is_complex(&self) -> bool {
+        self.value_types.is_empty()
+    }
+
+    /// Does any `element` context match one of the given names?
+    ///
+    /// The names describe the place of use: its element path, its type, and
+    /// the type's ancestors. A `fhirpath` context cannot be decided without a
+    /// FHIRPath engine, so it is taken as allowed, as is a definition that
+    /// declares no contexts at all.
+    pub fn allows_context(&self, names: &[String]) -> bool {
+        self.contexts.is_empty()
+            || self
+                .contexts
+                .iter()
+                .any(|context| match context.r#type.as_str() {
+                    "element" => {
+                        let expression = context
+                            .expression
+                            .rsplit_once('#')
+                            .map_or(context.expression.as_str(), |(_, path)| path);
+                        names.iter().any(|name| name == expression)
+                    }
+                    "fhirpath" => true,
+                    _ => false,
+                })
+    }
+
+    pub fn allows_value_type(&self, code: &str) -> bool {
+        self.value_types.iter().any(|x| x == code)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExtensionRegistry {
+    definitions: BTreeMap<String, ExtensionDefinition>,
+}
+
+impl ExtensionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the registry from the extension-definitions.json file.
+    ///
+    /// When that file is absent, fall back to the extension definitions in
+    /// profiles-types.json, the way the generator skips the other absent
+    /// bundles. The R5 profiles-types.json has none, so the registry is then
+    /// empty, and checks that use it skip every extension.
+    pub fn from_definitions_file() -> std::io::Result<Self> {
+        if !DEFINITIONS_FILE.exists() {
+            return Ok(Self::from(
+                &StructureDefinitionRegistry::from_definitions_file()?,
+            ));
+        }
+        let file = std::fs::File::open(&*DEFINITIONS_FILE)?;
+        let reader = std::io::BufReader::new(file);
+        let bundle: Bundle = ::serde_json::from_reader(reader)?;
+        Ok(Self::from(&StructureDefinitionRegistry::from(bundle)))
+    }
+
+    /// Add a definition, returning any previous one with the same URL.
+    pub fn insert(&mut self, definition: ExtensionDefinition) -> Option<ExtensionDefinition> {
+        self.definitions.insert(definition.url.clone(), definition)
+    }
+
+    pub fn get(&self, url: &str) -> Option<&ExtensionDefinition> {
+        self.definitions.get(url)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ExtensionDefinition> {
+        self.definitions.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+}
+
+impl From<&StructureDefinitionRegistry> for ExtensionRegistry {
+    fn from(registry: &StructureDefinitionRegistry) -> Self {
+        registry
+            .iter()
+            .filter_map(ExtensionDefinition::from_resource)
+            .collect()
+    }
+}
+
+impl FromIterator<ExtensionDefinition> for ExtensionRegistry {
+    fn from_iter<I: IntoIterator<Item = ExtensionDefinition>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        for definition in iter {
+            registry.insert(definition);
+        }
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde_json::json;
+
+    fn standards_status() -> Resource {
+        ::serde_json::from_value(json!({
+            "resourceType": "StructureDefinition",
+            "id": "structuredefinition-standards-status",
+            "meta": {},
+            "url": "http://hl7.org/fhir/StructureDefinition/structuredefinition-standards-status",
+            "version": "5.0.0",
+            "status": "active",
+            "experimental": false,
+            "type": "Extension",
+            "derivation": "constraint",
+            "context": [
+                { "type": "element", "expression": "Element" },
+                { "type": "element", "expression": "StructureDefinition" }
+            ],
+            "snapshot": {
+                "element": [
+                    { "id": "Extension", "path": "Extension" },
+                    {
+                        "id": "Extension.value[x]",
+                        "path": "Extension.value[x]",
+                        "min": 1,
+                        "max": "1",
+                        "type": [{ "code": "code" }]
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_resource() {
+        let actual = ExtensionDefinition::from_resource(&standards_status()).unwrap();
+        assert_eq!(actual.value_types, vec![String::from("code")]);
+        assert_eq!(actual.contexts.len(), 2);
+        assert!(!actual.is_complex());
+    }
+
+    #[test]
+    fn test_from_resource_with_non_extension() {
+        let resource = Resource {
+            r#type: Some(String::from("Quantity")),
+            ..Resource::default()
+        };
+        assert_eq!(ExtensionDefinition::from_resource(&resource), None);
+    }
+
+    #[test]
+    fn test_allows_context() {
+        let definition = ExtensionDefinition::from_resource(&standards_status()).unwrap();
+        assert!(definition.allows_context(&[String::from("StructureDefinition")]));
+        assert!(definition.allows_context(&[
+            String::from("ElementDefinition.binding"),
+            String::from("Element"),
+        ]));
+        assert!(!definition.allows_context(&[String::from("Resource")]));
+    }
+
+    #[test]
+    fn test_from_structure_definition_registry() {
+        let registry: StructureDefinitionRegistry = [standards_status()].into_iter().collect();
+        let actual = ExtensionRegistry::from(&registry);
+        assert_eq!(actual.len(), 1);
+        assert!(
+            actual
+                .get("http://hl7.org/fhir/StructureDefinition/structuredefinition-standards-status")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_from_definitions_file() {
+        let actual = ExtensionRegistry::from_definitions_file().unwrap();
+        if !DEFINITIONS_FILE.exists() {
+            assert!(actual.is_empty());
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! StructureDefinitionRegistry
+//!
+//! Index of StructureDefinition resources by canonical URL.
+
+use crate::r5::parse::profiles_types::{Bundle, Resource};
+use crate::util::split_canonical;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StructureDefinitionRegistry {
+    resources: BTreeMap<String, Resource>,
+}
+
+impl StructureDefinitionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the registry from the profiles-types.json file.
+    pub fn from_definitions_file() -> std::io::Result<Self> {
+        let file = std::fs::File::open(&*crate::r5::parse::profiles_types::DEFINITIONS_FILE)?;
+        let reader = std::io::BufReader::new(file);
+        let bundle: Bundle = ::serde_json::from_reader(reader)?;
+        Ok(Self::from(bundle))
+    }
+
+    /// Add a StructureDefinition, returning any previous one with the same URL.
+    pub fn insert(&mut self, resource: Resource) -> Option<Resource> {
+        self.resources.insert(resource.url.clone(), resource)
+    }
+
+    /// Resolve a canonical reference, which may carry a `|version` suffix.
+    ///
+    /// A versioned reference only resolves when the loaded version matches.
+    pub fn get(&self, canonical: &str) -> Option<&Resource> {
+        let (url, version) = split_canonical(canonical);
+        self.resources
+            .get(url)
+            .filter(|resource| version.is_none_or(|version| resource.version == version))
+    }
+
+    /// Find the base (non-constraint) definition of a FHIR type by its name,
+    /// such as `Quantity` or `string`.
+    pub fn get_type(&self, type_name: &str) -> Option<&Resource> {
+        self.get(&format!(
+            "http://hl7.org/fhir/StructureDefinition/{type_name}"
+        ))
+        .or_else(|| {
+            self.resources.values().find(|resource| {
+                resource.r#type.as_deref() == Some(type_name)
+                    && resource.derivation.as_deref() != Some("constraint")
+            })
+        })
+    }
+
+    /// Walk the `baseDefinition` chain of a resource, nearest ancestor first.
+    pub fn ancestors<'a>(&'a self, resource: &'a Resource) -> Vec<&'a Resource> {
+        let mut ancestors: Vec<&Resource> = vec![];
+        let mut current = resource;
+        while let Some(base) = current
+            .base_definition
+            .as_deref()
+            .and_then(|base_definition| self.get(base_definition))
+        {
+            if base.url == resource.url || ancestors.iter().any(|x| x.url == base.url) {
+                break;
+            }
+            ancestors.push(base);
+            current = base;
+        }
+        ancestors
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Resource> {
+        self.resources.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+}
+
+impl From<Bundle> for StructureDefinitionRegistry {
+    fn from(bundle: Bundle) -> Self {
+        let mut registry = Self::new();
+        for entry in bundle.entry {
+            registry.insert(entry.resource);
+        }
+        registry
+    }
+}
+
+impl FromIterator<Resource> for StructureDefinitionRegistry {
+    fn from_iter<I: IntoIterator<Item = Resource>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        for resource in iter {
+            registry.insert(resource);
+        }
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    type T = StructureDefinitionRegistry;
+
+    fn resource(id: &str, base: Option<&str>) -> Resource {
+        Resource {
+            id: id.into(),
+            url: format!("http://hl7.org/fhir/StructureDefinition/{id}"),
+            version: String::from("5.0.0"),
+            r#type: Some(id.into()),
+            base_definition: base.map(|x| format!("http://hl7.org/fhir/StructureDefinition/{x}")),
+            ..Resource::default()
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let registry: T = [resource("Quantity", None)].into_iter().collect();
+        let url = "http://hl7.org/fhir/StructureDefinition/Quantity";
+        assert!(registry.get(url).is_some());
+        assert!(registry.get(&format!("{url}|5.0.0")).is_some());
+        assert!(registry.get(&format!("{url}|4.0.1")).is_none());
+        assert!(registry.get("http://example.com/Missing").is_none());
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let registry: T = [
+            resource("Base", None),
+            resource("Element", Some("Base")),
+            resource("DataType", Some("Element")),
+            resource("Quantity", Some("DataType")),
+        ]
+        .into_iter()
+        .collect();
+        let quantity = registry.get_type("Quantity").unwrap();
+        let actual: Vec<&str> = registry
+            .ancestors(quantity)
+            .iter()
+            .map(|x| x.id.as_str())
+            .collect();
+        assert_eq!(actual, vec!["DataType", "Element", "Base"]);
+    }
+
+    #[test]
+    fn test_from_definitions_file() {
+        let registry = T::from_definitions_file().unwrap();
+        assert!(registry.get_type("Address").is_some());
+        assert!(registry.get_type("string").is_some());
+    }
+}
@@ -0,0 +1,287 @@
+//! ValueSetRegistry
+//!
+//! Index of ValueSet and CodeSystem resources by canonical URL, which can
+//! expand a ValueSet into its codes, when every code comes from a loaded
+//! CodeSystem or an explicit list.
+//!
+//! An expansion that needs a filter, or a CodeSystem that is not loaded,
+//! such as MIME types or UCUM units, is not expanded.
+
+use crate::r5::parse::value_sets::{Bundle, Resource};
+use crate::util::split_canonical;
+use ::serde_json::Value;
+use std::collections::BTreeMap;
+
+/// One code of an expanded ValueSet.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CodeConcept {
+    pub system: String,
+    pub code: String,
+    pub display: Option<String>,
+    pub definition: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValueSetRegistry {
+    value_sets: BTreeMap<String, Resource>,
+    code_systems: BTreeMap<String, Resource>,
+}
+
+impl ValueSetRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the registry from the valuesets.json file.
+    pub fn from_definitions_file() -> std::io::Result<Self> {
+        let file = std::fs::File::open(&*crate::r5::parse::value_sets::DEFINITIONS_FILE)?;
+        let reader = std::io::BufReader::new(file);
+        let bundle: Bundle = ::serde_json::from_reader(reader)?;
+        Ok(Self::from(bundle))
+    }
+
+    /// Add a ValueSet or CodeSystem, with its contained resources.
+    pub fn insert(&mut self, resource: Resource) {
+        for contained in resource.contained.iter().flatten() {
+            self.insert(contained.clone());
+        }
+        let Some(url) = resource.url.clone() else {
+            return;
+        };
+        match resource.resource_type.as_str() {
+            "ValueSet" => {
+                self.value_sets.insert(url, resource);
+            }
+            "CodeSystem" => {
+                self.code_systems.insert(url, resource);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn get_value_set(&self, canonical: &str) -> Option<&Resource> {
+        self.value_sets.get(split_canonical(canonical).0)
+    }
+
+    pub fn get_code_system(&self, canonical: &str) -> Option<&Resource> {
+        self.code_systems.get(split_canonical(canonical).0)
+    }
+
+    /// Expand a ValueSet, which may carry a `|version` suffix, into its codes.
+    ///
+    /// Return none when any part of the ValueSet can not be expanded.
+    pub fn expand(&self, canonical: &str) -> Option<Vec<CodeConcept>> {
+        self.expand_with_depth(canonical, 0)
+    }
+
+    fn expand_with_depth(&self, canonical: &str, depth: usize) -> Option<Vec<CodeConcept>> {
+        // Guard against a ValueSet that includes itself.
+        if depth > 8 {
+            return None;
+        }
+        let compose = self.get_value_set(canonical)?.compose.as_ref()?;
+        let mut concepts: Vec<CodeConcept> = vec![];
+        for include in &compose.include {
+            for concept in self.expand_include(include, depth)? {
+                if !concepts
+                    .iter()
+                    .any(|x| x.system == concept.system && x.code == concept.code)
+                {
+                    concepts.push(concept);
+                }
+            }
+        }
+        for exclude in compose.exclude.iter().flatten() {
+            let excluded = self.expand_include(exclude, depth)?;
+            concepts.retain(|x| {
+                !excluded
+                    .iter()
+                    .any(|y| x.system == y.system && x.code == y.code)
+            });
+        }
+        Some(concepts)
+    }
+
+    /// Expand one `compose.include` or `compose.exclude` item.
+    fn expand_include(&self, include: &Value, depth: usize) -> Option<Vec<CodeConcept>> {
+        if include.get("filter").is_some() {
+            return None;
+        }
+        let mut concepts: Vec<CodeConcept> = match include["system"].as_str() {
+            Some(system) => match include["concept"].as_array() {
+                Some(listed) => listed
+                    .iter()
+                    .map(|x| {
+                        let code = x["code"].as_str().unwrap_or_default();
+                        let defined = self
+                            .code_system_concepts(system)
+                            .and_then(|all| all.into_iter().find(|y| y.code == code));
+                        CodeConcept {
+                            system: String::from(system),
+                            code: String::from(code),
+                            display: x["display"]
+                                .as_str()
+                                .map(String::from)
+                                .or_else(|| defined.as_ref().and_then(|y| y.display.clone())),
+                            definition: defined.and_then(|y| y.definition),
+                        }
+                    })
+                    .collect(),
+                None => self.code_system_concepts(system)?,
+            },
+            None => vec![],
+        };
+        for value_set in include["valueSet"].as_array().into_iter().flatten() {
+            let expanded = self.expand_with_depth(value_set.as_str()?, depth + 1)?;
+            if include["system"].is_string() {
+                concepts.retain(|x| expanded.contains(x));
+            } else {
+                concepts.extend(expanded);
+            }
+        }
+        Some(concepts)
+    }
+
+    /// All the concepts of a CodeSystem, with nested concepts flattened.
+    fn code_system_concepts(&self, system: &str) -> Option<Vec<CodeConcept>> {
+        let code_system = self.get_code_system(system)?;
+        if code_system
+            .content
+            .as_deref()
+            .is_some_and(|x| x != "complete")
+        {
+            return None;
+        }
+        let mut concepts = vec![];
+        flatten_concepts(system, code_system.concept.as_ref(), &mut concepts);
+        Some(concepts)
+    }
+
+    pub fn len(&self) -> usize {
+        self.value_sets.len() + self.code_systems.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value_sets.is_empty() && self.code_systems.is_empty()
+    }
+}
+
+fn flatten_concepts(system: &str, concept: Option<&Value>, concepts: &mut Vec<CodeConcept>) {
+    for x in concept.and_then(Value::as_array).into_iter().flatten() {
+        if let Some(code) = x["code"].as_str() {
+            concepts.push(CodeConcept {
+                system: String::from(system),
+                code: String::from(code),
+                display: x["display"].as_str().map(String::from),
+                definition: x["definition"].as_str().map(String::from),
+            });
+        }
+        flatten_concepts(system, x.get("concept"), concepts);
+    }
+}
+
+impl From<Bundle> for ValueSetRegistry {
+    fn from(bundle: Bundle) -> Self {
+        bundle
+            .entry
+            .into_iter()
+            .map(|entry| entry.resource)
+            .collect()
+    }
+}
+
+impl FromIterator<Resource> for ValueSetRegistry {
+    fn from_iter<I: IntoIterator<Item = Resource>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        for resource in iter {
+            registry.insert(resource);
+        }
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde_json::json;
+
+    fn registry() -> ValueSetRegistry {
+        let resources: Vec<Resource> = ::serde_json::from_value(json!([
+            {
+                "resourceType": "CodeSystem",
+                "id": "address-use",
+                "url": "http://hl7.org/fhir/address-use",
+                "status": "active",
+                "content": "complete",
+                "concept": [
+                    { "code": "home", "display": "Home", "definition": "A communication address at a home." },
+                    {
+                        "code": "work",
+                        "display": "Work",
+                        "definition": "An office address.",
+                        "concept": [
+                            { "code": "billing", "display": "Billing", "definition": "An address to send bills." }
+                        ]
+                    }
+                ]
+            },
+            {
+                "resourceType": "ValueSet",
+                "id": "address-use",
+                "url": "http://hl7.org/fhir/ValueSet/address-use",
+                "status": "active",
+                "compose": {
+                    "include": [{ "system": "http://hl7.org/fhir/address-use" }],
+                    "exclude": [{ "system": "http://hl7.org/fhir/address-use", "concept": [{ "code": "billing" }] }]
+                }
+            },
+            {
+                "resourceType": "ValueSet",
+                "id": "mimetypes",
+                "url": "http://hl7.org/fhir/ValueSet/mimetypes",
+                "status": "active",
+                "compose": {
+                    "include": [{ "system": "urn:ietf:bcp:13" }]
+                }
+            }
+        ]))
+        .unwrap();
+        resources.into_iter().collect()
+    }
+
+    #[test]
+    fn test_expand() {
+        let registry = registry();
+        let actual = registry
+            .expand("http://hl7.org/fhir/ValueSet/address-use|5.0.0")
+            .unwrap();
+        let expect = vec![
+            CodeConcept {
+                system: String::from("http://hl7.org/fhir/address-use"),
+                code: String::from("home"),
+                display: Some(String::from("Home")),
+                definition: Some(String::from("A communication address at a home.")),
+            },
+            CodeConcept {
+                system: String::from("http://hl7.org/fhir/address-use"),
+                code: String::from("work"),
+                display: Some(String::from("Work")),
+                definition: Some(String::from("An office address.")),
+            },
+        ];
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_expand_without_code_system() {
+        let registry = registry();
+        assert_eq!(
+            registry.expand("http://hl7.org/fhir/ValueSet/mimetypes"),
+            None
+        );
+        assert_eq!(
+            registry.expand("http://hl7.org/fhir/ValueSet/missing"),
+            None
+        );
+    }
+}
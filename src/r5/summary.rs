@@ -0,0 +1,16 @@
+//! Summary, mustSupport, and isModifier views of StructureDefinitions.
+//!
+//! <https://build.fhir.org/search.html#_summary>
+//!
+//! The views read the `isSummary`, `mustSupport`, `isModifier`, and
+//! `isModifierReason` flags of each snapshot element. The trim functions use
+//! the same flags to cut a JSON resource instance down to the form that a
+//! server returns for the `_summary` and `_elements` search parameters.
+
+pub mod element_views;
+pub mod summary_mode;
+pub mod trim;
+
+pub use element_views::{ElementViews, ModifierElement};
+pub use summary_mode::SummaryMode;
+pub use trim::{trim_to_elements, trim_to_summary};
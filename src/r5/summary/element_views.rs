@@ -0,0 +1,152 @@
+//! ElementViews
+//!
+//! Read-only views of the snapshot elements of one StructureDefinition.
+
+use crate::r5::parse::profiles_types::{Element, Resource};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModifierElement<'a> {
+    pub path: &'a str,
+    pub reason: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementViews<'a> {
+    resource: &'a Resource,
+}
+
+impl<'a> ElementViews<'a> {
+    pub fn new(resource: &'a Resource) -> Self {
+        Self { resource }
+    }
+
+    /// All snapshot elements, or none when the resource has no snapshot.
+    pub fn elements(&self) -> &'a [Element] {
+        self.resource
+            .snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.element.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The path of the root element, such as `Patient`.
+    pub fn root_path(&self) -> &'a str {
+        self.elements()
+            .first()
+            .map(|element| element.path.as_str())
+            .unwrap_or_default()
+    }
+
+    /// The elements that `_summary=true` returns.
+    pub fn summary(&self) -> Vec<&'a Element> {
+        self.elements()
+            .iter()
+            .filter(|element| element.is_summary == Some(true))
+            .collect()
+    }
+
+    /// The elements that implementations must support.
+    pub fn must_support(&self) -> Vec<&'a Element> {
+        self.elements()
+            .iter()
+            .filter(|element| element.must_support == Some(true))
+            .collect()
+    }
+
+    /// The elements that modify the meaning of their parent, with reasons.
+    pub fn modifiers(&self) -> Vec<ModifierElement<'a>> {
+        self.elements()
+            .iter()
+            .filter(|element| element.is_modifier == Some(true))
+            .map(|element| ModifierElement {
+                path: element.path.as_str(),
+                reason: element.is_modifier_reason.as_deref(),
+            })
+            .collect()
+    }
+
+    /// The direct children of the element at a path.
+    pub fn children(&self, parent_path: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.elements().iter().filter(move |element| {
+            element
+                .path
+                .strip_prefix(parent_path)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .is_some_and(|name| !name.contains('.'))
+        })
+    }
+
+    /// Find the child element for a JSON key.
+    ///
+    /// The key may name a choice type, such as `valueQuantity` for
+    /// `value[x]`, and may be the `_key` that carries a primitive's id and
+    /// extensions.
+    pub fn child(&self, parent_path: &'a str, key: &str) -> Option<&'a Element> {
+        let key = key.strip_prefix('_').unwrap_or(key);
+        self.children(parent_path).find(|element| {
+            let name = &element.path[parent_path.len() + 1..];
+            match name.strip_suffix("[x]") {
+                Some(stem) => key.strip_prefix(stem).is_some_and(|type_name| {
+                    element.r#type.iter().flatten().any(|element_type| {
+                        let mut chars = element_type.code.chars();
+                        chars.next().is_some_and(|first| {
+                            type_name.starts_with(first.to_ascii_uppercase())
+                                && type_name[1..] == *chars.as_str()
+                        })
+                    })
+                }),
+                None => name == key,
+            }
+        })
+    }
+
+    /// Does the element at a path have children defined inline, as a
+    /// BackboneElement does?
+    pub fn has_children(&self, path: &'a str) -> bool {
+        self.children(path).next().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r5::registry::StructureDefinitionRegistry;
+
+    #[test]
+    fn test_views_with_address() {
+        let registry = StructureDefinitionRegistry::from_definitions_file().unwrap();
+        let views = ElementViews::new(registry.get_type("Address").unwrap());
+        let summary: Vec<&str> = views.summary().iter().map(|x| x.path.as_str()).collect();
+        assert!(summary.contains(&"Address.city"));
+        assert!(!summary.contains(&"Address.extension"));
+        assert_eq!(
+            views.modifiers(),
+            vec![ModifierElement {
+                path: "Address.use",
+                reason: Some(
+                    "This is labeled as \"Is Modifier\" because applications should not mistake a temporary or old address etc.for a current/permanent one"
+                ),
+            }]
+        );
+        assert_eq!(views.must_support(), Vec::<&Element>::new());
+    }
+
+    #[test]
+    fn test_child() {
+        let registry = StructureDefinitionRegistry::from_definitions_file().unwrap();
+        let views = ElementViews::new(registry.get_type("Extension").unwrap());
+        let path = |key| views.child("Extension", key).map(|x| x.path.as_str());
+        assert_eq!(path("url"), Some("Extension.url"));
+        assert_eq!(path("valueQuantity"), Some("Extension.value[x]"));
+        assert_eq!(path("_valueString"), Some("Extension.value[x]"));
+        assert_eq!(path("valueNothing"), None);
+    }
+
+    #[test]
+    fn test_has_children() {
+        let registry = StructureDefinitionRegistry::from_definitions_file().unwrap();
+        let views = ElementViews::new(registry.get_type("Dosage").unwrap());
+        assert!(views.has_children("Dosage.doseAndRate"));
+        assert!(!views.has_children("Dosage.text"));
+    }
+}
@@ -0,0 +1,51 @@
+//! SummaryMode
+//!
+//! The values of the `_summary` search parameter that apply to one resource.
+//! The `count` value is left out, because it returns no resource content.
+
+use std::str::FromStr;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryMode {
+    /// Return the elements marked as summary, plus id and meta.
+    True,
+
+    /// Return the text, id, meta, and top-level mandatory elements.
+    Text,
+
+    /// Return everything except the text.
+    Data,
+
+    /// Return the full resource.
+    #[default]
+    False,
+}
+
+impl FromStr for SummaryMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "true" => Ok(Self::True),
+            "text" => Ok(Self::Text),
+            "data" => Ok(Self::Data),
+            "false" => Ok(Self::False),
+            _ => Err(format!("unknown _summary value: {s}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    type T = SummaryMode;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("true".parse::<T>(), Ok(T::True));
+        assert_eq!("text".parse::<T>(), Ok(T::Text));
+        assert_eq!("data".parse::<T>(), Ok(T::Data));
+        assert_eq!("false".parse::<T>(), Ok(T::False));
+        assert!("count".parse::<T>().is_err());
+    }
+}
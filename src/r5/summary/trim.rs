@@ -0,0 +1,248 @@
+//! Trim a JSON resource instance to its `_summary` or `_elements` form.
+//!
+//! <https://build.fhir.org/search.html#_summary>
+//!
+//! <https://build.fhir.org/search.html#_elements>
+//!
+//! A trimmed resource is tagged SUBSETTED in its `meta.tag`, so that it is
+//! not mistaken for the full resource.
+
+use crate::r5::parse::profiles_types::Resource;
+use crate::r5::summary::{ElementViews, SummaryMode};
+use ::serde_json::{Map, Value, json};
+
+/// The system of the SUBSETTED tag.
+pub const SUBSETTED_SYSTEM: &str = "http://terminology.hl7.org/CodeSystem/v3-ObservationValue";
+
+/// Trim a resource instance as a server does for `_summary`.
+///
+/// For `true`, the elements marked as summary are kept, and the same rule
+/// is applied inside BackboneElements; a kept datatype is kept whole.
+pub fn trim_to_summary(instance: &Value, definition: &Resource, mode: SummaryMode) -> Value {
+    let Value::Object(object) = instance else {
+        return instance.clone();
+    };
+    let views = ElementViews::new(definition);
+    let root = views.root_path();
+    let trimmed = match mode {
+        SummaryMode::True => summary_object(&views, root, object),
+        SummaryMode::Text => filter_object(object, |key| {
+            matches!(key, "resourceType" | "id" | "meta" | "text")
+                || is_mandatory(&views, root, key)
+        }),
+        SummaryMode::Data => filter_object(object, |key| !matches!(key, "text" | "_text")),
+        SummaryMode::False => object.clone(),
+    };
+    subsetted(object, trimmed)
+}
+
+/// Trim a resource instance as a server does for `_elements`.
+///
+/// The named top-level elements are kept, along with the resource type, id,
+/// meta, and every mandatory element. A name may carry the resource type as
+/// a prefix, such as `Patient.name`, and names a choice element without its
+/// `[x]` suffix, such as `value`.
+pub fn trim_to_elements(instance: &Value, definition: &Resource, elements: &[&str]) -> Value {
+    let Value::Object(object) = instance else {
+        return instance.clone();
+    };
+    let views = ElementViews::new(definition);
+    let root = views.root_path();
+    let names: Vec<&str> = elements
+        .iter()
+        .map(|name| {
+            name.strip_prefix(root)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .unwrap_or(name)
+        })
+        .collect();
+    let trimmed = filter_object(object, |key| {
+        matches!(key, "resourceType" | "id" | "meta")
+            || is_mandatory(&views, root, key)
+            || views.child(root, key).is_some_and(|element| {
+                let name = &element.path[root.len() + 1..];
+                names.contains(&name.strip_suffix("[x]").unwrap_or(name))
+            })
+    });
+    subsetted(object, trimmed)
+}
+
+fn filter_object(object: &Map<String, Value>, keep: impl Fn(&str) -> bool) -> Map<String, Value> {
+    object
+        .iter()
+        .filter(|(key, _)| keep(key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+fn is_mandatory(views: &ElementViews, parent_path: &str, key: &str) -> bool {
+    views
+        .child(parent_path, key)
+        .is_some_and(|element| element.min.unwrap_or(0) > 0)
+}
+
+fn summary_object(
+    views: &ElementViews,
+    path: &str,
+    object: &Map<String, Value>,
+) -> Map<String, Value> {
+    object
+        .iter()
+        .filter_map(|(key, value)| {
+            if key == "resourceType" {
+                return Some((key.clone(), value.clone()));
+            }
+            let element = views.child(path, key)?;
+            if element.is_summary != Some(true) {
+                return None;
+            }
+            let value = if views.has_children(&element.path) {
+                summary_value(views, &element.path, value)
+            } else {
+                value.clone()
+            };
+            Some((key.clone(), value))
+        })
+        .collect()
+}
+
+fn summary_value(views: &ElementViews, path: &str, value: &Value) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(summary_object(views, path, object)),
+        Value::Array(array) => Value::Array(
+            array
+                .iter()
+                .map(|item| summary_value(views, path, item))
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+/// Tag the trimmed object as SUBSETTED when anything was removed.
+fn subsetted(original: &Map<String, Value>, mut trimmed: Map<String, Value>) -> Value {
+    if *original != trimmed {
+        let meta = trimmed
+            .entry("meta")
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(meta) = meta
+            && let Value::Array(tag) = meta.entry("tag").or_insert_with(|| Value::Array(vec![]))
+            && !tag.iter().any(|x| x["code"] == "SUBSETTED")
+        {
+            tag.push(json!({ "system": SUBSETTED_SYSTEM, "code": "SUBSETTED" }));
+        }
+    }
+    Value::Object(trimmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition() -> Resource {
+        ::serde_json::from_value(json!({
+            "resourceType": "StructureDefinition",
+            "id": "Alfa",
+            "meta": {},
+            "url": "http://example.com/StructureDefinition/Alfa",
+            "version": "5.0.0",
+            "status": "active",
+            "experimental": false,
+            "snapshot": {
+                "element": [
+                    { "id": "Alfa", "path": "Alfa" },
+                    { "id": "Alfa.id", "path": "Alfa.id", "isSummary": true },
+                    { "id": "Alfa.meta", "path": "Alfa.meta", "isSummary": true },
+                    { "id": "Alfa.text", "path": "Alfa.text" },
+                    { "id": "Alfa.status", "path": "Alfa.status", "min": 1, "isSummary": true, "isModifier": true },
+                    { "id": "Alfa.note", "path": "Alfa.note" },
+                    {
+                        "id": "Alfa.value[x]",
+                        "path": "Alfa.value[x]",
+                        "isSummary": true,
+                        "type": [{ "code": "Quantity" }, { "code": "string" }]
+                    },
+                    { "id": "Alfa.step", "path": "Alfa.step", "isSummary": true },
+                    { "id": "Alfa.step.name", "path": "Alfa.step.name", "isSummary": true },
+                    { "id": "Alfa.step.detail", "path": "Alfa.step.detail" }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    fn instance() -> Value {
+        json!({
+            "resourceType": "Alfa",
+            "id": "1",
+            "text": { "status": "generated" },
+            "status": "final",
+            "_status": { "extension": [] },
+            "note": "Hello",
+            "valueString": "Bravo",
+            "step": [{ "name": "Charlie", "detail": "Delta" }]
+        })
+    }
+
+    fn subsetted_meta() -> Value {
+        json!({ "tag": [{ "system": SUBSETTED_SYSTEM, "code": "SUBSETTED" }] })
+    }
+
+    #[test]
+    fn test_trim_to_summary_with_true() {
+        let actual = trim_to_summary(&instance(), &definition(), SummaryMode::True);
+        let expect = json!({
+            "resourceType": "Alfa",
+            "id": "1",
+            "meta": subsetted_meta(),
+            "status": "final",
+            "_status": { "extension": [] },
+            "valueString": "Bravo",
+            "step": [{ "name": "Charlie" }]
+        });
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_trim_to_summary_with_text() {
+        let actual = trim_to_summary(&instance(), &definition(), SummaryMode::Text);
+        let expect = json!({
+            "resourceType": "Alfa",
+            "id": "1",
+            "meta": subsetted_meta(),
+            "text": { "status": "generated" },
+            "status": "final",
+            "_status": { "extension": [] }
+        });
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn test_trim_to_summary_with_data() {
+        let actual = trim_to_summary(&instance(), &definition(), SummaryMode::Data);
+        assert_eq!(actual.get("text"), None);
+        assert_eq!(actual["note"], "Hello");
+        assert_eq!(actual["meta"], subsetted_meta());
+    }
+
+    #[test]
+    fn test_trim_to_summary_with_false() {
+        let actual = trim_to_summary(&instance(), &definition(), SummaryMode::False);
+        assert_eq!(actual, instance());
+    }
+
+    #[test]
+    fn test_trim_to_elements() {
+        let actual = trim_to_elements(&instance(), &definition(), &["note", "Alfa.value"]);
+        let expect = json!({
+            "resourceType": "Alfa",
+            "id": "1",
+            "meta": subsetted_meta(),
+            "status": "final",
+            "_status": { "extension": [] },
+            "note": "Hello",
+            "valueString": "Bravo"
+        });
+        assert_eq!(actual, expect);
+    }
+}
@@ -1,12 +1,111 @@
 //! PublicationStatus
 //!
-//! TODO
+//! The lifecycle status of an artifact.
+//!
+//! URL: <http://hl7.org/fhir/publication-status>
 
 use ::serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
-pub struct PublicationStatus {}
+/// The canonical URL of the code system.
+pub const URL: &str = "http://hl7.org/fhir/publication-status";
+
+/// The version of the code system, if any.
+pub const VERSION: Option<&str> = Some("5.0.0");
+
+/// The codes of the code system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PublicationStatus {
+    /// Draft
+    ///
+    /// This resource is still under development and is not yet considered to be ready for normal use.
+    #[serde(rename = "draft")]
+    Draft,
+
+    /// Active
+    ///
+    /// This resource is ready for normal use.
+    #[serde(rename = "active")]
+    Active,
+
+    /// Retired
+    ///
+    /// This resource has been withdrawn or superseded and should no longer be used.
+    #[serde(rename = "retired")]
+    Retired,
+
+    /// Unknown
+    ///
+    /// The authoring system does not know which of the status values currently applies for this resource.  Note: This concept is not to be used for "other" - one of the listed statuses is presumed to apply, it's just not known which one.
+    #[serde(rename = "unknown")]
+    Unknown,
+}
+
+impl PublicationStatus {
+    /// Every code, in the order of the code system.
+    pub const ALL: &'static [Self] = &[Self::Draft, Self::Active, Self::Retired, Self::Unknown];
+
+    /// The code, such as in JSON.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Active => "active",
+            Self::Retired => "retired",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// The display text, if any.
+    pub fn display(self) -> Option<&'static str> {
+        match self {
+            Self::Draft => Some("Draft"),
+            Self::Active => Some("Active"),
+            Self::Retired => Some("Retired"),
+            Self::Unknown => Some("Unknown"),
+        }
+    }
+
+    /// The definition, if any.
+    pub fn definition(self) -> Option<&'static str> {
+        match self {
+            Self::Draft => Some(
+                "This resource is still under development and is not yet considered to be ready for normal use.",
+            ),
+            Self::Active => Some("This resource is ready for normal use."),
+            Self::Retired => {
+                Some("This resource has been withdrawn or superseded and should no longer be used.")
+            }
+            Self::Unknown => Some(
+                "The authoring system does not know which of the status values currently applies for this resource.  Note: This concept is not to be used for \"other\" - one of the listed statuses is presumed to apply, it's just not known which one.",
+            ),
+        }
+    }
+
+    /// The variant of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+
+    /// The codes that directly subsume this code.
+    pub fn parents(self) -> &'static [Self] {
+        match self {
+            Self::Draft => &[],
+            Self::Active => &[],
+            Self::Retired => &[],
+            Self::Unknown => &[],
+        }
+    }
+
+    /// Is this code the given code, or subsumed by it?
+    pub fn is_a(self, ancestor: Self) -> bool {
+        self == ancestor || self.parents().iter().any(|x| x.is_a(ancestor))
+    }
+}
+
+impl std::fmt::Display for PublicationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -14,9 +113,25 @@ mod tests {
     type T = PublicationStatus;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(*x));
+        }
+    }
+
+    #[test]
+    fn test_serde_json() {
+        for x in T::ALL {
+            let value = ::serde_json::to_value(x).unwrap();
+            assert_eq!(value, ::serde_json::json!(x.code()));
+            assert_eq!(::serde_json::from_value::<T>(value).unwrap(), *x);
+        }
+    }
+
+    #[test]
+    fn test_is_a() {
+        for x in T::ALL {
+            assert!(x.is_a(*x));
+        }
     }
 }
@@ -85,7 +85,7 @@ pub mod xhtml;
 //// Convenience
 pub use address::Address;
 pub use age::Age;
-pub use annotation::Annotation;
+pub use annotation::{Annotation, AnnotationAuthor};
 pub use attachment::Attachment;
 pub use availability::Availability;
 pub use backbone_type::BackboneType;
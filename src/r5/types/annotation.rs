@@ -20,7 +20,8 @@ use ::serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Annotation {
-    pub author: types::String, // DataType [0..1] // « Reference( Practitioner | PractitionerRole | Patient | RelatedPerson |Organization )| string »
+    #[serde(flatten)]
+    pub author: Option<AnnotationAuthor>, // DataType [0..1] // « Reference( Practitioner | PractitionerRole | Patient | RelatedPerson |Organization )| string »
     pub time: Option<types::DateTime>,
 
     /// # text
@@ -121,6 +122,21 @@ pub struct Annotation {
     pub text: types::Markdown,
 }
 
+/// Choice of types for `Annotation.author[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AnnotationAuthor {
+    #[serde(rename = "authorReference")]
+    Reference(types::Reference),
+    #[serde(rename = "authorString")]
+    String(types::String),
+}
+
+impl Default for AnnotationAuthor {
+    fn default() -> Self {
+        Self::Reference(Default::default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,7 +146,7 @@ mod tests {
     fn test_default() {
         let actual = T::default();
         let expect = T {
-            author: types::String::default(),
+            author: None,
             time: None,
             text: types::Markdown::default(),
         };
@@ -141,11 +157,28 @@ mod tests {
         use super::*;
         use ::serde_json::json;
 
+        #[test]
+        fn test_serde_json_with_author() {
+            let json = json!(
+                {
+                    "authorString": {},
+                    "text": {}
+                }
+            );
+            let actual: T = ::serde_json::from_value(json.clone()).expect("from_value");
+            let expect: T = T {
+                author: Some(AnnotationAuthor::String(types::String::default())),
+                ..T::default()
+            };
+            assert_eq!(actual, expect);
+            let actual: ::serde_json::Value = ::serde_json::to_value(expect).expect("to_value");
+            assert_eq!(actual, json);
+        }
+
         #[test]
         fn test_serde_json_from_value() {
             let json = json!(
                 {
-                    "author": {},
                     "text": {}
                 }
             );
@@ -160,7 +193,6 @@ mod tests {
                 ::serde_json::to_value(T::default()).expect("to_value");
             let expect: ::serde_json::Value = json!(
                 {
-                    "author": {},
                     "text": {}
                 }
             );
@@ -20,8 +20,8 @@ use ::serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Range {
-    pub low: types::Quantity,  // Quantity(SimpleQuantity) [0..1] « C »
-    pub high: types::Quantity, // Quantity(SimpleQuantity) [0..1] « C »
+    pub low: Option<types::Quantity>,  // Quantity(SimpleQuantity) [0..1] « C »
+    pub high: Option<types::Quantity>, // Quantity(SimpleQuantity) [0..1] « C »
 }
 
 #[cfg(test)]
@@ -33,8 +33,8 @@ mod tests {
     fn test_default() {
         let actual = T::default();
         let expect = T {
-            low: types::Quantity::default(),
-            high: types::Quantity::default(),
+            low: None,
+            high: None,
         };
         assert_eq!(actual, expect);
     }
@@ -45,12 +45,7 @@ mod tests {
 
         #[test]
         fn test_serde_json_from_value() {
-            let json = json!(
-                {
-                    "low": {},
-                    "high": {}
-                }
-            );
+            let json = json!({});
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
@@ -60,12 +55,7 @@ mod tests {
         fn test_serde_json_to_value() {
             let actual: ::serde_json::Value =
                 ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!(
-                {
-                    "low": {},
-                    "high": {}
-                }
-            );
+            let expect: ::serde_json::Value = json!({});
             assert_eq!(actual, expect);
         }
     }
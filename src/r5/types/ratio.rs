@@ -20,8 +20,8 @@ use ::serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Ratio {
-    pub numerator: Option<types::Quantity>, // « C »
-    pub denominator: types::Quantity,       // Quantity(SimpleQuantity)
+    pub numerator: Option<types::Quantity>,   // « C »
+    pub denominator: Option<types::Quantity>, // Quantity(SimpleQuantity)
 }
 
 #[cfg(test)]
@@ -34,7 +34,7 @@ mod tests {
         let actual = T::default();
         let expect = T {
             numerator: None,
-            denominator: types::Quantity::default(),
+            denominator: None,
         };
         assert_eq!(actual, expect);
     }
@@ -45,11 +45,7 @@ mod tests {
 
         #[test]
         fn test_serde_json_from_value() {
-            let json = json!(
-                {
-                    "denominator": {}
-                }
-            );
+            let json = json!({});
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
@@ -59,11 +55,7 @@ mod tests {
         fn test_serde_json_to_value() {
             let actual: ::serde_json::Value =
                 ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!(
-                {
-                    "denominator": {}
-                }
-            );
+            let expect: ::serde_json::Value = json!({});
             assert_eq!(actual, expect);
         }
     }
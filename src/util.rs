@@ -61,3 +61,357 @@ mod test_last_word {
         assert_eq!(last_word("alfa bravo charlie"), "charlie");
     }
 }
+
+/// Given a canonical reference, return its URL and optional version.
+///
+/// A canonical may carry a version after a vertical bar, and may carry a
+/// fragment after a hash; the fragment stays with the URL.
+///
+/// Example:
+///
+/// ```no_run
+/// split_canonical("http://x/alfa") => ("http://x/alfa", None)
+/// split_canonical("http://x/alfa|1.0") => ("http://x/alfa", Some("1.0"))
+/// ```
+///
+#[allow(dead_code)]
+pub fn split_canonical(s: &str) -> (&str, Option<&str>) {
+    match s.split_once('|') {
+        Some((url, version)) => (url, Some(version)),
+        None => (s, None),
+    }
+}
+
+#[cfg(test)]
+mod test_split_canonical {
+    use super::*;
+    #[test]
+    fn test_split_canonical() {
+        assert_eq!(split_canonical("http://x/alfa"), ("http://x/alfa", None));
+        assert_eq!(
+            split_canonical("http://x/alfa|1.0"),
+            ("http://x/alfa", Some("1.0"))
+        );
+    }
+}
+
+/// Given Rust source code, return it formatted, the same way on every run,
+/// without running rustfmt.
+///
+/// The formatter keeps doc comments, and the leading line comments of the
+/// file, such as a header that an `include!` file can not have as an inner
+/// doc comment. It would drop any other comment, so source code with one
+/// after the header stays as is, like source code that does not parse,
+/// whose compiler errors then point at the generated lines.
+///
+/// Example:
+///
+/// ```no_run
+/// format_rust_source("pub struct Alfa{pub bravo:u32}") => "pub struct Alfa {\n    pub bravo: u32,\n}\n"
+/// format_rust_source("pub struct Alfa; // Bravo") => "pub struct Alfa; // Bravo"
+/// ```
+///
+#[allow(dead_code)]
+pub fn format_rust_source(source: &str) -> String {
+    let header_len: usize = source
+        .split_inclusive('\n')
+        .take_while(|line| {
+            line.trim().is_empty()
+                || line.starts_with("//") && !line.starts_with("///") && !line.starts_with("//!")
+        })
+        .map(str::len)
+        .sum();
+    let (header, body) = source.split_at(header_len);
+    if scan_rust_source(body).has_plain_comment {
+        return String::from(source);
+    }
+    match ::syn::parse_file(body) {
+        Ok(file) if header.trim().is_empty() => separate_items(&::prettyplease::unparse(&file)),
+        Ok(file) => format!(
+            "{}\n\n{}",
+            header.trim_end(),
+            separate_items(&::prettyplease::unparse(&file))
+        ),
+        Err(_) => String::from(source),
+    }
+}
+
+/// Given formatted Rust source code, return it with a blank line between
+/// items, such as between two impl blocks or two documented fields, the
+/// way rustfmt keeps them, because the formatter drops every blank line.
+fn separate_items(source: &str) -> String {
+    const ITEM_STARTS: &[&str] = &[
+        "///", "#[", "pub ", "impl", "fn ", "mod ", "const ", "static ", "struct ", "enum ",
+        "trait ", "type ", "use ", "macro_rules!",
+    ];
+    let line_starts_in_code = scan_rust_source(source).line_starts_in_code;
+    let mut out = String::with_capacity(source.len());
+    let mut previous = "";
+    for (line, in_code) in source.lines().zip(line_starts_in_code) {
+        let indent = line.len() - line.trim_start().len();
+        let previous_indent = previous.len() - previous.trim_start().len();
+        let (trimmed, previous_trimmed) = (line.trim_start(), previous.trim_start());
+        let is_use = |line: &str| line.starts_with("use ") || line.starts_with("pub use ");
+        let separate = in_code
+            && if previous_trimmed.starts_with("//!") {
+                !trimmed.starts_with("//!")
+            } else if previous_trimmed.starts_with("#![") {
+                !trimmed.starts_with("#![")
+            } else if indent != previous_indent
+                || previous_trimmed.starts_with("///")
+                || previous_trimmed.starts_with("#[")
+            {
+                false
+            } else if previous_trimmed.ends_with(',') {
+                trimmed.starts_with("///")
+            } else if is_use(trimmed) {
+                !is_use(previous_trimmed) && previous_trimmed.ends_with('}')
+            } else {
+                (previous_trimmed.ends_with('}') || previous_trimmed.ends_with(';'))
+                    && ITEM_STARTS.iter().any(|start| trimmed.starts_with(start))
+            };
+        if separate {
+            out.push('\n');
+        }
+        out.push_str(line);
+        out.push('\n');
+        previous = line;
+    }
+    out
+}
+
+/// The lexical state at one character of Rust source code: in code, or in
+/// a string literal, a raw string literal with its number of `#`, or block
+/// comments with their nesting depth.
+#[derive(Clone, Copy, PartialEq)]
+enum Lexical {
+    Code,
+    Str,
+    RawStr(usize),
+    BlockComment(usize),
+}
+
+/// What a scan of Rust source code finds.
+struct RustSourceScan {
+    /// For each line, whether it starts in code, rather than inside a
+    /// multi-line string literal or block comment.
+    line_starts_in_code: Vec<bool>,
+
+    /// Whether the source code has a comment that is not a doc comment.
+    has_plain_comment: bool,
+}
+
+/// Given Rust source code, scan its literals and comments, so that a quote
+/// in a char literal, such as `'"'`, or in a raw string, such as
+/// `r#"a"b"#`, does not read as the start or the end of a string.
+fn scan_rust_source(source: &str) -> RustSourceScan {
+    let chars: Vec<char> = source.chars().collect();
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let at = |n: usize| chars.get(n).copied().unwrap_or('\0');
+    let mut scan = RustSourceScan {
+        line_starts_in_code: vec![true],
+        has_plain_comment: false,
+    };
+    let mut state = Lexical::Code;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match state {
+            _ if c == '\n' => {
+                scan.line_starts_in_code.push(state == Lexical::Code);
+            }
+            Lexical::Code if c == '/' && at(i + 1) == '/' => {
+                // A doc comment is `///` or `//!`, but not `////`.
+                let is_doc = (at(i + 2) == '/' && at(i + 3) != '/') || at(i + 2) == '!';
+                scan.has_plain_comment |= !is_doc;
+                while i + 1 < chars.len() && chars[i + 1] != '\n' {
+                    i += 1;
+                }
+            }
+            Lexical::Code if c == '/' && at(i + 1) == '*' => {
+                // A doc comment is `/**` or `/*!`, but not `/**/` or `/***`.
+                let is_doc =
+                    (at(i + 2) == '*' && at(i + 3) != '/' && at(i + 3) != '*') || at(i + 2) == '!';
+                scan.has_plain_comment |= !is_doc;
+                state = Lexical::BlockComment(1);
+                i += 1;
+            }
+            Lexical::Code if c == '"' => state = Lexical::Str,
+            Lexical::Code if c == '\'' => {
+                // A char literal, such as `'"'` or `'\''`, or a lifetime.
+                if at(i + 1) == '\\' {
+                    i += 2;
+                    while i + 1 < chars.len() && chars[i + 1] != '\'' {
+                        i += 1;
+                    }
+                    i += 1;
+                } else if at(i + 2) == '\'' {
+                    i += 2;
+                }
+            }
+            Lexical::Code if is_ident(c) && (i == 0 || !is_ident(chars[i - 1])) => {
+                let start = i;
+                while i + 1 < chars.len() && is_ident(chars[i + 1]) {
+                    i += 1;
+                }
+                let word: String = chars[start..=i].iter().collect();
+                let hashes = chars[i + 1..].iter().take_while(|x| **x == '#').count();
+                if matches!(word.as_str(), "r" | "br" | "cr") && at(i + hashes + 1) == '"' {
+                    state = Lexical::RawStr(hashes);
+                    i += hashes + 1;
+                }
+            }
+            Lexical::Code => {}
+            Lexical::Str if c == '\\' => {
+                if at(i + 1) == '\n' {
+                    scan.line_starts_in_code.push(false);
+                }
+                i += 1;
+            }
+            Lexical::Str if c == '"' => state = Lexical::Code,
+            Lexical::Str => {}
+            Lexical::RawStr(hashes) if c == '"' && (1..=hashes).all(|n| at(i + n) == '#') => {
+                state = Lexical::Code;
+                i += hashes;
+            }
+            Lexical::RawStr(_) => {}
+            Lexical::BlockComment(depth) if c == '/' && at(i + 1) == '*' => {
+                state = Lexical::BlockComment(depth + 1);
+                i += 1;
+            }
+            Lexical::BlockComment(depth) if c == '*' && at(i + 1) == '/' => {
+                state = match depth {
+                    1 => Lexical::Code,
+                    _ => Lexical::BlockComment(depth - 1),
+                };
+                i += 1;
+            }
+            Lexical::BlockComment(_) => {}
+        }
+        i += 1;
+    }
+    scan
+}
+
+#[cfg(test)]
+mod test_format_rust_source {
+    use super::*;
+    #[test]
+    fn test_format_rust_source() {
+        assert_eq!(
+            format_rust_source("/// Alfa\npub struct Alfa{pub bravo:u32}"),
+            "/// Alfa\npub struct Alfa {\n    pub bravo: u32,\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_rust_source_with_blank_lines() {
+        assert_eq!(
+            format_rust_source(concat!(
+                "//! Alfa\n#![allow(unused_imports)]\nuse std::fmt;\nuse std::str;\n",
+                "pub mod alfa;\npub use alfa::Alfa;\npub mod bravo;\n",
+                "pub struct Alfa {\n/// Bravo\npub bravo: u32,\n/// Charlie\npub charlie: u32,\n}\n",
+                "impl Alfa {\nfn new() -> Self { Self { bravo: 0, charlie: 0 } }\n",
+                "fn alfa(&self) -> &'static str { \"}\\\"\" }\n}\n",
+            )),
+            concat!(
+                "//! Alfa\n\n#![allow(unused_imports)]\n\nuse std::fmt;\nuse std::str;\n\n",
+                "pub mod alfa;\npub use alfa::Alfa;\n\npub mod bravo;\n\n",
+                "pub struct Alfa {\n    /// Bravo\n    pub bravo: u32,\n\n    /// Charlie\n    pub charlie: u32,\n}\n\n",
+                "impl Alfa {\n    fn new() -> Self {\n        Self { bravo: 0, charlie: 0 }\n    }\n\n",
+                "    fn alfa(&self) -> &'static str {\n        \"}\\\"\"\n    }\n}\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_rust_source_with_header() {
+        assert_eq!(
+            format_rust_source("// Alfa\n\n// Bravo\npub struct Alfa;"),
+            "// Alfa\n\n// Bravo\n\npub struct Alfa;\n"
+        );
+    }
+
+    #[test]
+    fn test_format_rust_source_with_quote_char_and_raw_string() {
+        assert_eq!(
+            format_rust_source(concat!(
+                "impl Alfa {\nfn alfa() -> char { '\"' }\nfn bravo() -> char { '\\'' }\n",
+                "fn charlie<'a>(x: &'a str) -> &'a str { x }\n",
+                "fn delta() -> &'static str { r#\"a\"b\"# }\nfn echo() {}\n}\n",
+            )),
+            concat!(
+                "impl Alfa {\n    fn alfa() -> char {\n        '\"'\n    }\n\n",
+                "    fn bravo() -> char {\n        '\\''\n    }\n\n",
+                "    fn charlie<'a>(x: &'a str) -> &'a str {\n        x\n    }\n\n",
+                "    fn delta() -> &'static str {\n        r#\"a\"b\"#\n    }\n\n",
+                "    fn echo() {}\n}\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_rust_source_with_comment() {
+        assert_eq!(
+            format_rust_source("pub struct Alfa; // Bravo"),
+            "pub struct Alfa; // Bravo"
+        );
+        assert_eq!(
+            format_rust_source("pub struct Alfa; /* Bravo */"),
+            "pub struct Alfa; /* Bravo */"
+        );
+        assert_eq!(
+            format_rust_source("//! Alfa\n/// Bravo\npub const BRAVO:&str=\"http://x/*\";"),
+            "//! Alfa\n\n/// Bravo\npub const BRAVO: &str = \"http://x/*\";\n"
+        );
+    }
+
+    #[test]
+    fn test_format_rust_source_with_syntax_error() {
+        assert_eq!(format_rust_source("pub struct {"), "pub struct {");
+    }
+}
+
+/// Write the contents into the file at the path, only when the file does not
+/// already have the contents, so that an unchanged file keeps its
+/// modification time, and a rebuild skips it.
+///
+/// Return whether the file changed.
+///
+/// Example:
+///
+/// ```no_run
+/// write_if_changed(Path::new("./tmp/out/alfa.rs"), "…") => Ok(true)
+/// write_if_changed(Path::new("./tmp/out/alfa.rs"), "…") => Ok(false)
+/// ```
+///
+#[allow(dead_code)]
+pub fn write_if_changed(
+    path: &std::path::Path,
+    contents: impl AsRef<[u8]>,
+) -> std::io::Result<bool> {
+    let contents = contents.as_ref();
+    if std::fs::read(path).is_ok_and(|existing| existing == contents) {
+        return Ok(false);
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, contents)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test_write_if_changed {
+    use super::*;
+    #[test]
+    fn test_write_if_changed() {
+        let dir = std::env::temp_dir().join(format!("write_if_changed_{}", std::process::id()));
+        let path = dir.join("alfa").join("alfa.rs");
+        assert!(write_if_changed(&path, "alfa").unwrap());
+        assert!(!write_if_changed(&path, "alfa").unwrap());
+        assert!(write_if_changed(&path, "bravo").unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "bravo");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
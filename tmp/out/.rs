@@ -1,66 +0,0 @@
-//! 
-        //!
-        //! URL: 
-        //!
-        //! Version: 
-        //!
-        //! ?
-        //!
-        //! FHIR: <https://build.fhir.org/>
-        //!
-        //! UML: <https://build.fhir.org/uml.html>
-
-        // Allow unused crate::r5::types as types;
-        #![allow(unused_imports)]
-
-        /// Use all the relevant parse types for the goal.
-        use crate::r5::parse::profiles_types::*;
-
-        /// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
-        use ::serde::{Deserialize, Serialize};
-
-        /// Skip serializing each attributes that is an option and set to none.
-        #[serde_with::skip_serializing_none]
-        /// Derive all our typical things for programming, serde, comparing, etc.
-        #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-        /// Rename all the snake case Rust attributes into camel case JSON keys.
-        #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
-        pub struct  {
-        
-        }
-
-        #[cfg(test)]
-        mod tests {
-            use super::*;
-            type T = ;
-
-            #[test]
-            fn test_default() {
-                let actual = T::default();
-                let expect = T {};
-                assert_eq!(actual, expect);
-            }
-
-            mod serde_json {
-                use super::*;
-                use ::serde_json::json;
-
-                #[test]
-                fn test_serde_json_from_value() {
-                    let json = json!({});
-                    let actual: T = ::serde_json::from_value(json).expect("from_value");
-                    let expect: T = T::default();
-                    assert_eq!(actual, expect);
-                }
-
-                #[test]
-                fn test_serde_json_to_value() {
-                    let actual: ::serde_json::Value =
-                        ::serde_json::to_value(T::default()).expect("to_value");
-                    let expect: ::serde_json::Value = json!({});
-                    assert_eq!(actual, expect);
-                }
-            }
-        }
-        
\ No newline at end of file
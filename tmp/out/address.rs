@@ -1,97 +1,742 @@
 //! Address
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Address
+//! URL: <http://hl7.org/fhir/StructureDefinition/Address>
 //!
 //! Version: 5.0.0
 //!
 //! Address Type: An address expressed using postal conventions (as opposed to GPS or other location definition formats).  This data type may be used to convey addresses for use in delivering mail as well as for visiting locations which might not be valid for mail delivery.  There are a variety of postal address formats defined around the world.
-The ISO21090-codedString may be used to provide a coded representation of the contents of strings in an Address.
+//! The ISO21090-codedString may be used to provide a coded representation of the contents of strings in an Address.
 //!
 //! FHIR: <https://build.fhir.org/>
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// An address expressed using postal conventions (as opposed to GPS or other location definition formats)
+///
+/// An address expressed using postal conventions (as opposed to GPS or other location definition formats).  This data type may be used to convey addresses for use in delivering mail as well as for visiting locations which might not be valid for mail delivery.  There are a variety of postal address formats defined around the world.
+/// The ISO21090-codedString may be used to provide a coded representation of the contents of strings in an Address.
+///
+/// Comment: Note: address is intended to describe postal addresses for administrative purposes, not to describe absolute geographical coordinates.  Postal addresses are often used as proxies for physical locations (also see the [Location](https://build.fhir.org/location.html#) resource).
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Address {
-    /// An address expressed using postal conventions (as opposed to GPS or other location definition formats)
-    Address: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// home | work | temp | old | billing - purpose of this address
-    use: ? // code
+    ///
+    /// The purpose of this address.
+    ///
+    /// Comment: Applications can assume that an address is current unless it explicitly says that it is temporary or old.
+    ///
+    /// Requirements: Allows an appropriate address to be chosen from a list of many.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/address-use|5.0.0`](http://hl7.org/fhir/ValueSet/address-use)
+    ///
+    /// The use of an address (home / work / etc.).
+    ///
+    /// Modifier: This is labeled as "Is Modifier" because applications should not mistake a temporary or old address etc.for a current/permanent one
+    pub r#use: Option<types::Code>,
+
+    /// Id and extensions of `use`
+    #[serde(rename = "_use")]
+    pub use_element: Option<types::Element>,
 
     /// postal | physical | both
-    type: ? // code
+    ///
+    /// Distinguishes between physical addresses (those you can visit) and mailing addresses (e.g. PO Boxes and care-of addresses). Most addresses are both.
+    ///
+    /// Comment: The definition of Address states that "address is intended to describe postal addresses, not physical locations". However, many applications track whether an address has a dual purpose of being a location that can be visited as well as being a valid delivery destination, and Postal addresses are often used as proxies for physical locations (also see the [Location](https://build.fhir.org/location.html#) resource).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/address-type|5.0.0`](http://hl7.org/fhir/ValueSet/address-type)
+    ///
+    /// The type of an address (physical / postal).
+    pub r#type: Option<types::Code>,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
 
     /// Text representation of the address
-    text: ? // string
+    ///
+    /// Specifies the entire address as it should be displayed e.g. on a postal label. This may be provided instead of or as well as the specific parts.
+    ///
+    /// Comment: Can provide both a text representation and parts. Applications updating an address SHALL ensure that  when both text and parts are present,  no content is included in the text that isn't found in a part.
+    ///
+    /// Requirements: A renderable, unencoded form.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub text: Option<types::String>,
+
+    /// Id and extensions of `text`
+    #[serde(rename = "_text")]
+    pub text_element: Option<types::Element>,
 
     /// Street name, number, direction & P.O. Box etc.
-    line: ? // string
+    ///
+    /// This component contains the house number, apartment number, street name, street direction,  P.O. Box number, delivery hints, and similar address information.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `string`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line: Vec<Option<types::String>>,
+
+    /// Id and extensions of `line`
+    #[serde(rename = "_line", default, skip_serializing_if = "Vec::is_empty")]
+    pub line_element: Vec<Option<types::Element>>,
 
     /// Name of city, town etc.
-    city: ? // string
+    ///
+    /// The name of the city, town, suburb, village or other community or delivery center.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub city: Option<types::String>,
+
+    /// Id and extensions of `city`
+    #[serde(rename = "_city")]
+    pub city_element: Option<types::Element>,
 
     /// District name (aka county)
-    district: ? // string
+    ///
+    /// The name of the administrative area (county).
+    ///
+    /// Comment: District is sometimes known as county, but in some regions 'county' is used in place of city (municipality), so county name should be conveyed in city instead.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub district: Option<types::String>,
+
+    /// Id and extensions of `district`
+    #[serde(rename = "_district")]
+    pub district_element: Option<types::Element>,
 
     /// Sub-unit of country (abbreviations ok)
-    state: ? // string
+    ///
+    /// Sub-unit of a country with limited sovereignty in a federally organized country. A code may be used if codes are in common use (e.g. US 2 letter state codes).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub state: Option<types::String>,
+
+    /// Id and extensions of `state`
+    #[serde(rename = "_state")]
+    pub state_element: Option<types::Element>,
 
     /// Postal code for area
-    postalCode: ? // string
+    ///
+    /// A postal code designating a region defined by the postal service.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub postal_code: Option<types::String>,
+
+    /// Id and extensions of `postalCode`
+    #[serde(rename = "_postalCode")]
+    pub postal_code_element: Option<types::Element>,
 
     /// Country (e.g. may be ISO 3166 2 or 3 letter code)
-    country: ? // string
+    ///
+    /// Country - a nation as commonly understood or generally accepted.
+    ///
+    /// Comment: ISO 3166 2- or 3- letter codes MAY be used in place of a human readable country name.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub country: Option<types::String>,
+
+    /// Id and extensions of `country`
+    #[serde(rename = "_country")]
+    pub country_element: Option<types::Element>,
 
     /// Time period when address was/is in use
-    period: ? // Period
+    ///
+    /// Time period when address was/is in use.
+    ///
+    /// Requirements: Allows addresses to be placed in historical context.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Period`
+    pub period: Option<types::Period>,
+}
+
+impl Address {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
+impl Address {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> AddressBuilder {
+        AddressBuilder::default()
+    }
+}
+
+/// Build one `Address`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct AddressBuilder {
+    inner: Address,
+}
+
+impl AddressBuilder {
+    /// Set `Address.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Address.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Address.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Address.use`.
+    pub fn r#use(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.r#use = Some(value.into());
+        self
+    }
+
+    /// Set `Address.type`.
+    pub fn r#type(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `Address.text`.
+    pub fn text(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.text = Some(value.into());
+        self
+    }
+
+    /// Set every `Address.line`.
+    pub fn line(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::String>>,
+    ) -> Self {
+        self.inner.line = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `Address.line`.
+    pub fn push_line(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.line.push(Some(value.into()));
+        self
+    }
+
+    /// Set `Address.city`.
+    pub fn city(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.city = Some(value.into());
+        self
+    }
+
+    /// Set `Address.district`.
+    pub fn district(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.district = Some(value.into());
+        self
+    }
+
+    /// Set `Address.state`.
+    pub fn state(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.state = Some(value.into());
+        self
+    }
+
+    /// Set `Address.postalCode`.
+    pub fn postal_code(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.postal_code = Some(value.into());
+        self
+    }
+
+    /// Set `Address.country`.
+    pub fn country(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.country = Some(value.into());
+        self
+    }
+
+    /// Set `Address.period`.
+    pub fn period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.period = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Address, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Address {
+    const PATH: &'static str = "Address";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#use, &format!("{path}.use"), issues);
+        types::Validate::validate_into(
+            &self.use_element,
+            &format!("{path}._use"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(&self.text, &format!("{path}.text"), issues);
+        types::Validate::validate_into(
+            &self.text_element,
+            &format!("{path}._text"),
+            issues,
+        );
+        types::Validate::validate_into(&self.line, &format!("{path}.line"), issues);
+        types::Validate::validate_into(
+            &self.line_element,
+            &format!("{path}._line"),
+            issues,
+        );
+        types::Validate::validate_into(&self.city, &format!("{path}.city"), issues);
+        types::Validate::validate_into(
+            &self.city_element,
+            &format!("{path}._city"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.district,
+            &format!("{path}.district"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.district_element,
+            &format!("{path}._district"),
+            issues,
+        );
+        types::Validate::validate_into(&self.state, &format!("{path}.state"), issues);
+        types::Validate::validate_into(
+            &self.state_element,
+            &format!("{path}._state"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.postal_code,
+            &format!("{path}.postalCode"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.postal_code_element,
+            &format!("{path}._postalCode"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.country,
+            &format!("{path}.country"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.country_element,
+            &format!("{path}._country"),
+            issues,
+        );
+        types::Validate::validate_into(&self.period, &format!("{path}.period"), issues);
+    }
+}
+
+impl types::Walk for Address {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_address(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept(&self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept(&self.text_element, &format!("{path}._text"), visitor);
+        types::Walk::accept(&self.line, &format!("{path}.line"), visitor);
+        types::Walk::accept(&self.line_element, &format!("{path}._line"), visitor);
+        types::Walk::accept(&self.city, &format!("{path}.city"), visitor);
+        types::Walk::accept(&self.city_element, &format!("{path}._city"), visitor);
+        types::Walk::accept(&self.district, &format!("{path}.district"), visitor);
+        types::Walk::accept(
+            &self.district_element,
+            &format!("{path}._district"),
+            visitor,
+        );
+        types::Walk::accept(&self.state, &format!("{path}.state"), visitor);
+        types::Walk::accept(&self.state_element, &format!("{path}._state"), visitor);
+        types::Walk::accept(&self.postal_code, &format!("{path}.postalCode"), visitor);
+        types::Walk::accept(
+            &self.postal_code_element,
+            &format!("{path}._postalCode"),
+            visitor,
+        );
+        types::Walk::accept(&self.country, &format!("{path}.country"), visitor);
+        types::Walk::accept(&self.country_element, &format!("{path}._country"), visitor);
+        types::Walk::accept(&self.period, &format!("{path}.period"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_address_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept_mut(&mut self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept_mut(
+            &mut self.text_element,
+            &format!("{path}._text"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.line, &format!("{path}.line"), visitor);
+        types::Walk::accept_mut(
+            &mut self.line_element,
+            &format!("{path}._line"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.city, &format!("{path}.city"), visitor);
+        types::Walk::accept_mut(
+            &mut self.city_element,
+            &format!("{path}._city"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.district,
+            &format!("{path}.district"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.district_element,
+            &format!("{path}._district"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.state, &format!("{path}.state"), visitor);
+        types::Walk::accept_mut(
+            &mut self.state_element,
+            &format!("{path}._state"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.postal_code,
+            &format!("{path}.postalCode"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.postal_code_element,
+            &format!("{path}._postalCode"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.country, &format!("{path}.country"), visitor);
+        types::Walk::accept_mut(
+            &mut self.country_element,
+            &format!("{path}._country"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.period, &format!("{path}.period"), visitor);
+    }
+}
+
+impl Address {
+    /// The metadata of each child element of `Address`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "use",
+            json_key: "use",
+            rust_field: "r#use",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/address-use|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/address-type|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "text",
+            json_key: "text",
+            rust_field: "text",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "line",
+            json_key: "line",
+            rust_field: "line",
+            min: 0,
+            max: None,
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "city",
+            json_key: "city",
+            rust_field: "city",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "district",
+            json_key: "district",
+            rust_field: "district",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "state",
+            json_key: "state",
+            rust_field: "state",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "postalCode",
+            json_key: "postalCode",
+            rust_field: "postal_code",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "country",
+            json_key: "country",
+            rust_field: "country",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "period",
+            json_key: "period",
+            rust_field: "period",
+            min: 0,
+            max: Some(1),
+            types: &["Period"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Address {
+    fn type_name(&self) -> &'static str {
+        "Address"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "use" => types::AsValueRef::as_value_ref(&self.r#use),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "text" => types::AsValueRef::as_value_ref(&self.text),
+            "line" => types::AsValueRef::as_value_ref(&self.line),
+            "city" => types::AsValueRef::as_value_ref(&self.city),
+            "district" => types::AsValueRef::as_value_ref(&self.district),
+            "state" => types::AsValueRef::as_value_ref(&self.state),
+            "postalCode" => types::AsValueRef::as_value_ref(&self.postal_code),
+            "country" => types::AsValueRef::as_value_ref(&self.country),
+            "period" => types::AsValueRef::as_value_ref(&self.period),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Address {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Address {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Address;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
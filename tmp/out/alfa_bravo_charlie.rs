@@ -1,56 +0,0 @@
-//! AlfaBravoCharlie
-//!
-//! URL: ?
-//!
-//! Version: ?
-//!
-//! ?
-//!
-//! FHIR: <https://build.fhir.org/>
-//!
-//! UML: <https://build.fhir.org/uml.html>
-
-// Allow unused crate::r5::types as types;
-#![allow(unused_imports)]
-
-use crate::r5::types;
-use ::serde::{Serialize, Deserialize};
-
-#[serde_with::skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
-pub struct AlfaBravoCharlie {}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    type T = AlfaBravoCharlie;
-
-    #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
-    }
-
-    mod serde_json {
-        use super::*;
-        use ::serde_json::json;
-
-        #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
-            let actual: T = ::serde_json::from_value(json).expect("from_value");
-            let expect: T = T::default();
-            assert_eq!(actual, expect);
-        }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
-    }
-}
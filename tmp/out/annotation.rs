@@ -1,6 +1,6 @@
 //! Annotation
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Annotation
+//! URL: <http://hl7.org/fhir/StructureDefinition/Annotation>
 //!
 //! Version: 5.0.0
 //!
@@ -10,66 +10,504 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Text node with attribution
+///
+/// A  text note which also  contains information about who made the statement and when.
+///
+/// Comment: For systems that do not have structured annotations, they can simply communicate a single annotation with no author or time.  This element may need to be included in narrative because of the potential for modifying information.  *Annotations SHOULD NOT* be used to communicate "modifying" information that could be computable. (This is a SHOULD because enforcing user behavior is nearly impossible).
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
 pub struct Annotation {
-    /// Text node with attribution
-    Annotation: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Individual responsible for the annotation
-    : ? // Reference
+    ///
+    /// The individual responsible for making the annotation.
+    ///
+    /// Comment: Organization is used when there's no need for specific attribution as to who made the comment.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Reference(Practitioner | PractitionerRole | Patient | RelatedPerson | Organization)`, `string`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub author: Option<AnnotationAuthor>,
+
+    /// Id and extensions of `author[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub author_element: Option<AnnotationAuthorElement>,
 
     /// When the annotation was made
-    time: ? // dateTime
+    ///
+    /// Indicates when this particular annotation was made.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `dateTime`
+    pub time: Option<types::DateTime>,
+
+    /// Id and extensions of `time`
+    #[serde(rename = "_time")]
+    pub time_element: Option<types::Element>,
 
     /// The annotation  - text content (as markdown)
-    text: ? // markdown
+    ///
+    /// The text of the annotation in markdown format.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `markdown`
+    pub text: types::Markdown,
+
+    /// Id and extensions of `text`
+    #[serde(rename = "_text")]
+    pub text_element: Option<types::Element>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl Annotation {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(text: types::Markdown) -> Self {
+        Self { text, ..Default::default() }
+    }
+}
+
+impl Annotation {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> AnnotationBuilder {
+        AnnotationBuilder::default()
+    }
+}
+
+/// Build one `Annotation`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct AnnotationBuilder {
+    inner: Annotation,
+    text: Option<types::Markdown>,
+}
+
+impl AnnotationBuilder {
+    /// Set `Annotation.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Annotation.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Annotation.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Annotation.author[x]` to a `Reference`.
+    pub fn author_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.author = Some(AnnotationAuthor::Reference(value.into()));
+        self
+    }
+
+    /// Set `Annotation.author[x]` to a `string`.
+    pub fn author_string(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.author = Some(AnnotationAuthor::String(value.into()));
+        self
+    }
+
+    /// Set `Annotation.time`.
+    pub fn time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.time = Some(value.into());
+        self
+    }
 
+    /// Set `Annotation.text`.
+    pub fn text(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Annotation, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.text {
+            Some(value) => inner.text = value,
+            None => missing.push("Annotation.text"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for Annotation {
+    const PATH: &'static str = "Annotation";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.author, &format!("{path}.author"), issues);
+        types::Validate::validate_into(
+            &self.author_element,
+            &format!("{path}._author"),
+            issues,
+        );
+        types::Validate::validate_into(&self.time, &format!("{path}.time"), issues);
+        types::Validate::validate_into(
+            &self.time_element,
+            &format!("{path}._time"),
+            issues,
+        );
+        types::Validate::validate_into(&self.text, &format!("{path}.text"), issues);
+        types::Validate::validate_into(
+            &self.text_element,
+            &format!("{path}._text"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Annotation {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_annotation(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.author, &format!("{path}.author"), visitor);
+        types::Walk::accept(&self.author_element, &format!("{path}._author"), visitor);
+        types::Walk::accept(&self.time, &format!("{path}.time"), visitor);
+        types::Walk::accept(&self.time_element, &format!("{path}._time"), visitor);
+        types::Walk::accept(&self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept(&self.text_element, &format!("{path}._text"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_annotation_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.author, &format!("{path}.author"), visitor);
+        types::Walk::accept_mut(
+            &mut self.author_element,
+            &format!("{path}._author"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.time, &format!("{path}.time"), visitor);
+        types::Walk::accept_mut(
+            &mut self.time_element,
+            &format!("{path}._time"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept_mut(
+            &mut self.text_element,
+            &format!("{path}._text"),
+            visitor,
+        );
+    }
+}
+
+impl Annotation {
+    /// The metadata of each child element of `Annotation`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "author[x]",
+            json_key: "author",
+            rust_field: "author",
+            min: 0,
+            max: Some(1),
+            types: &["Reference", "string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "time",
+            json_key: "time",
+            rust_field: "time",
+            min: 0,
+            max: Some(1),
+            types: &["dateTime"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "text",
+            json_key: "text",
+            rust_field: "text",
+            min: 1,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Annotation {
+    fn type_name(&self) -> &'static str {
+        "Annotation"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "author[x]" | "author" => types::AsValueRef::as_value_ref(&self.author),
+            "time" => types::AsValueRef::as_value_ref(&self.time),
+            "text" => types::AsValueRef::as_value_ref(&self.text),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Annotation {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Annotation {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `Annotation.author[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AnnotationAuthor {
+    #[serde(rename = "authorReference")]
+    Reference(types::Reference),
+    #[serde(rename = "authorString")]
+    String(types::String),
+}
+
+impl Default for AnnotationAuthor {
+    fn default() -> Self {
+        Self::Reference(Default::default())
+    }
+}
+
+impl types::Choice for AnnotationAuthor {
+    const KEYS: &'static [&'static str] = &["authorReference", "authorString"];
+}
+
+impl types::Validate for AnnotationAuthor {
+    const PATH: &'static str = "Annotation.author";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for AnnotationAuthor {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for AnnotationAuthor {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+            Self::String(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `Annotation.author[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AnnotationAuthorElement {
+    #[serde(rename = "_authorString")]
+    String(types::Element),
+}
+
+impl types::Choice for AnnotationAuthorElement {
+    const KEYS: &'static [&'static str] = &["_authorString"];
+}
+
+impl types::Validate for AnnotationAuthorElement {
+    const PATH: &'static str = "Annotation.author";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for AnnotationAuthorElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Annotation;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["text"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
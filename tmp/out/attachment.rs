@@ -1,6 +1,6 @@
 //! Attachment
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Attachment
+//! URL: <http://hl7.org/fhir/StructureDefinition/Attachment>
 //!
 //! Version: 5.0.0
 //!
@@ -10,96 +10,907 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Content in a format defined elsewhere
+///
+/// For referring to data content defined in other formats.
+///
+/// Comment: When providing a summary view (for example with Observation.value\[x\]) Attachment should be represented with a brief display text such as "Signed Procedure Consent".
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `att-1`: If the Attachment has data, it SHALL have a contentType
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Attachment {
-    /// Content in a format defined elsewhere
-    Attachment: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Mime type of the content, with charset etc.
-    contentType: ? // code
+    ///
+    /// Identifies the type of the data in the attachment and allows a method to be chosen to interpret or render the data. Includes mime type parameters such as charset where appropriate.
+    ///
+    /// Requirements: Processors of the data need to be able to know how to interpret the data.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/mimetypes|5.0.0`](http://hl7.org/fhir/ValueSet/mimetypes)
+    ///
+    /// BCP 13 (RFCs 2045, 2046, 2047, 4288, 4289 and 2049)
+    pub content_type: Option<types::Code>,
+
+    /// Id and extensions of `contentType`
+    #[serde(rename = "_contentType")]
+    pub content_type_element: Option<types::Element>,
 
     /// Human language of the content (BCP-47)
-    language: ? // code
+    ///
+    /// The human language of the content. The value can be any valid value according to BCP 47.
+    ///
+    /// Requirements: Users need to be able to choose between the languages in a set of attachments.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/all-languages|5.0.0`](http://hl7.org/fhir/ValueSet/all-languages)
+    ///
+    /// IETF language tag for a human language.
+    pub language: Option<types::Code>,
+
+    /// Id and extensions of `language`
+    #[serde(rename = "_language")]
+    pub language_element: Option<types::Element>,
 
     /// Data inline, base64ed
-    data: ? // base64Binary
+    ///
+    /// The actual data of the attachment - a sequence of bytes, base64 encoded.
+    ///
+    /// Comment: The base64-encoded data SHALL be expressed in the same character set as the base resource XML or JSON.
+    ///
+    /// Requirements: The data needs to able to be transmitted inline.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `base64Binary`
+    pub data: Option<types::Base64Binary>,
+
+    /// Id and extensions of `data`
+    #[serde(rename = "_data")]
+    pub data_element: Option<types::Element>,
 
     /// Uri where the data can be found
-    url: ? // url
+    ///
+    /// A location where the data can be accessed.
+    ///
+    /// Comment: If both data and url are provided, the url SHALL point to the same content as the data contains. Urls may be relative references or may reference transient locations such as a wrapping envelope using cid: though this has ramifications for using signatures. Relative URLs are interpreted relative to the service url, like a resource reference, rather than relative to the resource itself. If a URL is provided, it SHALL resolve to actual data.
+    ///
+    /// Requirements: The data needs to be transmitted by reference.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `url`
+    pub url: Option<types::Url>,
+
+    /// Id and extensions of `url`
+    #[serde(rename = "_url")]
+    pub url_element: Option<types::Element>,
 
     /// Number of bytes of content (if url provided)
-    size: ? // integer64
+    ///
+    /// The number of bytes of data that make up this attachment (before base64 encoding, if that is done).
+    ///
+    /// Comment: The number of bytes is redundant if the data is provided as a base64binary, but is useful if the data is provided as a url reference.
+    ///
+    /// Requirements: Representing the size allows applications to determine whether they should fetch the content automatically in advance, or refuse to fetch it at all.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `integer64`
+    pub size: Option<types::Integer64>,
+
+    /// Id and extensions of `size`
+    #[serde(rename = "_size")]
+    pub size_element: Option<types::Element>,
 
     /// Hash of the data (sha-1, base64ed)
-    hash: ? // base64Binary
+    ///
+    /// The calculated hash of the data using SHA-1. Represented using base64.
+    ///
+    /// Comment: The hash is calculated on the data prior to base64 encoding, if the data is based64 encoded. The hash is not intended to support digital signatures. Where protection against malicious threats a digital signature should be considered, see [Provenance.signature](https://build.fhir.org/provenance-definitions.html#Provenance.signature) for mechanism to protect a resource with a digital signature.
+    ///
+    /// Requirements: Included so that applications can verify that the contents of a location have not changed due to technical failures (e.g., storage rot, transport glitch, incorrect version).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `base64Binary`
+    pub hash: Option<types::Base64Binary>,
+
+    /// Id and extensions of `hash`
+    #[serde(rename = "_hash")]
+    pub hash_element: Option<types::Element>,
 
     /// Label to display in place of the data
-    title: ? // string
+    ///
+    /// A label or set of text to display in place of the data.
+    ///
+    /// Comment: May sometimes be derived from the source filename.
+    ///
+    /// Requirements: Applications need a label to display to a human user in place of the actual data if the data cannot be rendered or perceived by the viewer.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub title: Option<types::String>,
+
+    /// Id and extensions of `title`
+    #[serde(rename = "_title")]
+    pub title_element: Option<types::Element>,
 
     /// Date attachment was first created
-    creation: ? // dateTime
+    ///
+    /// The date that the attachment was first created.
+    ///
+    /// Requirements: This is often tracked as an integrity issue for use of the attachment.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `dateTime`
+    pub creation: Option<types::DateTime>,
+
+    /// Id and extensions of `creation`
+    #[serde(rename = "_creation")]
+    pub creation_element: Option<types::Element>,
 
     /// Height of the image in pixels (photo/video)
-    height: ? // positiveInt
+    ///
+    /// Height of the image in pixels (photo/video).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub height: Option<types::PositiveInt>,
+
+    /// Id and extensions of `height`
+    #[serde(rename = "_height")]
+    pub height_element: Option<types::Element>,
 
     /// Width of the image in pixels (photo/video)
-    width: ? // positiveInt
+    ///
+    /// Width of the image in pixels (photo/video).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub width: Option<types::PositiveInt>,
+
+    /// Id and extensions of `width`
+    #[serde(rename = "_width")]
+    pub width_element: Option<types::Element>,
 
-    /// Number of frames if > 1 (photo)
-    frames: ? // positiveInt
+    /// Number of frames if &gt; 1 (photo)
+    ///
+    /// The number of frames in a photo. This is used with a multi-page fax, or an imaging acquisition context that takes multiple slices in a single image, or an animated gif. If there is more than one frame, this SHALL have a value in order to alert interface software that a multi-frame capable rendering widget is required.
+    ///
+    /// Comment: if the number of frames is not supplied, the value may be unknown. Applications should not assume that there is only one frame unless it is explicitly stated.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub frames: Option<types::PositiveInt>,
+
+    /// Id and extensions of `frames`
+    #[serde(rename = "_frames")]
+    pub frames_element: Option<types::Element>,
 
     /// Length in seconds (audio / video)
-    duration: ? // decimal
+    ///
+    /// The duration of the recording in seconds - for audio and video.
+    ///
+    /// Comment: The duration might differ from occurrencePeriod if recording was paused.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub duration: Option<types::Decimal>,
+
+    /// Id and extensions of `duration`
+    #[serde(rename = "_duration")]
+    pub duration_element: Option<types::Element>,
 
     /// Number of printed pages
-    pages: ? // positiveInt
+    ///
+    /// The number of pages when printed.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub pages: Option<types::PositiveInt>,
+
+    /// Id and extensions of `pages`
+    #[serde(rename = "_pages")]
+    pub pages_element: Option<types::Element>,
+}
+
+impl Attachment {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Attachment {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> AttachmentBuilder {
+        AttachmentBuilder::default()
+    }
+}
 
+/// Build one `Attachment`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct AttachmentBuilder {
+    inner: Attachment,
+}
+
+impl AttachmentBuilder {
+    /// Set `Attachment.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Attachment.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Attachment.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Attachment.contentType`.
+    pub fn content_type(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.content_type = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.language`.
+    pub fn language(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.language = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.data`.
+    pub fn data(mut self, value: impl Into<types::Base64Binary>) -> Self {
+        self.inner.data = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.url`.
+    pub fn url(mut self, value: impl Into<types::Url>) -> Self {
+        self.inner.url = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.size`.
+    pub fn size(mut self, value: impl Into<types::Integer64>) -> Self {
+        self.inner.size = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.hash`.
+    pub fn hash(mut self, value: impl Into<types::Base64Binary>) -> Self {
+        self.inner.hash = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.title`.
+    pub fn title(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.title = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.creation`.
+    pub fn creation(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.creation = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.height`.
+    pub fn height(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.height = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.width`.
+    pub fn width(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.width = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.frames`.
+    pub fn frames(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.frames = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.duration`.
+    pub fn duration(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.duration = Some(value.into());
+        self
+    }
+
+    /// Set `Attachment.pages`.
+    pub fn pages(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.pages = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Attachment, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Attachment {
+    const PATH: &'static str = "Attachment";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.content_type,
+            &format!("{path}.contentType"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.content_type_element,
+            &format!("{path}._contentType"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.language,
+            &format!("{path}.language"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.language_element,
+            &format!("{path}._language"),
+            issues,
+        );
+        types::Validate::validate_into(&self.data, &format!("{path}.data"), issues);
+        types::Validate::validate_into(
+            &self.data_element,
+            &format!("{path}._data"),
+            issues,
+        );
+        types::Validate::validate_into(&self.url, &format!("{path}.url"), issues);
+        types::Validate::validate_into(
+            &self.url_element,
+            &format!("{path}._url"),
+            issues,
+        );
+        types::Validate::validate_into(&self.size, &format!("{path}.size"), issues);
+        types::Validate::validate_into(
+            &self.size_element,
+            &format!("{path}._size"),
+            issues,
+        );
+        types::Validate::validate_into(&self.hash, &format!("{path}.hash"), issues);
+        types::Validate::validate_into(
+            &self.hash_element,
+            &format!("{path}._hash"),
+            issues,
+        );
+        types::Validate::validate_into(&self.title, &format!("{path}.title"), issues);
+        types::Validate::validate_into(
+            &self.title_element,
+            &format!("{path}._title"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.creation,
+            &format!("{path}.creation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.creation_element,
+            &format!("{path}._creation"),
+            issues,
+        );
+        types::Validate::validate_into(&self.height, &format!("{path}.height"), issues);
+        types::Validate::validate_into(
+            &self.height_element,
+            &format!("{path}._height"),
+            issues,
+        );
+        types::Validate::validate_into(&self.width, &format!("{path}.width"), issues);
+        types::Validate::validate_into(
+            &self.width_element,
+            &format!("{path}._width"),
+            issues,
+        );
+        types::Validate::validate_into(&self.frames, &format!("{path}.frames"), issues);
+        types::Validate::validate_into(
+            &self.frames_element,
+            &format!("{path}._frames"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.duration,
+            &format!("{path}.duration"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.duration_element,
+            &format!("{path}._duration"),
+            issues,
+        );
+        types::Validate::validate_into(&self.pages, &format!("{path}.pages"), issues);
+        types::Validate::validate_into(
+            &self.pages_element,
+            &format!("{path}._pages"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Attachment {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_attachment(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.content_type, &format!("{path}.contentType"), visitor);
+        types::Walk::accept(
+            &self.content_type_element,
+            &format!("{path}._contentType"),
+            visitor,
+        );
+        types::Walk::accept(&self.language, &format!("{path}.language"), visitor);
+        types::Walk::accept(
+            &self.language_element,
+            &format!("{path}._language"),
+            visitor,
+        );
+        types::Walk::accept(&self.data, &format!("{path}.data"), visitor);
+        types::Walk::accept(&self.data_element, &format!("{path}._data"), visitor);
+        types::Walk::accept(&self.url, &format!("{path}.url"), visitor);
+        types::Walk::accept(&self.url_element, &format!("{path}._url"), visitor);
+        types::Walk::accept(&self.size, &format!("{path}.size"), visitor);
+        types::Walk::accept(&self.size_element, &format!("{path}._size"), visitor);
+        types::Walk::accept(&self.hash, &format!("{path}.hash"), visitor);
+        types::Walk::accept(&self.hash_element, &format!("{path}._hash"), visitor);
+        types::Walk::accept(&self.title, &format!("{path}.title"), visitor);
+        types::Walk::accept(&self.title_element, &format!("{path}._title"), visitor);
+        types::Walk::accept(&self.creation, &format!("{path}.creation"), visitor);
+        types::Walk::accept(
+            &self.creation_element,
+            &format!("{path}._creation"),
+            visitor,
+        );
+        types::Walk::accept(&self.height, &format!("{path}.height"), visitor);
+        types::Walk::accept(&self.height_element, &format!("{path}._height"), visitor);
+        types::Walk::accept(&self.width, &format!("{path}.width"), visitor);
+        types::Walk::accept(&self.width_element, &format!("{path}._width"), visitor);
+        types::Walk::accept(&self.frames, &format!("{path}.frames"), visitor);
+        types::Walk::accept(&self.frames_element, &format!("{path}._frames"), visitor);
+        types::Walk::accept(&self.duration, &format!("{path}.duration"), visitor);
+        types::Walk::accept(
+            &self.duration_element,
+            &format!("{path}._duration"),
+            visitor,
+        );
+        types::Walk::accept(&self.pages, &format!("{path}.pages"), visitor);
+        types::Walk::accept(&self.pages_element, &format!("{path}._pages"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_attachment_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.content_type,
+            &format!("{path}.contentType"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.content_type_element,
+            &format!("{path}._contentType"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.language,
+            &format!("{path}.language"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.language_element,
+            &format!("{path}._language"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.data, &format!("{path}.data"), visitor);
+        types::Walk::accept_mut(
+            &mut self.data_element,
+            &format!("{path}._data"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.url, &format!("{path}.url"), visitor);
+        types::Walk::accept_mut(&mut self.url_element, &format!("{path}._url"), visitor);
+        types::Walk::accept_mut(&mut self.size, &format!("{path}.size"), visitor);
+        types::Walk::accept_mut(
+            &mut self.size_element,
+            &format!("{path}._size"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.hash, &format!("{path}.hash"), visitor);
+        types::Walk::accept_mut(
+            &mut self.hash_element,
+            &format!("{path}._hash"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.title, &format!("{path}.title"), visitor);
+        types::Walk::accept_mut(
+            &mut self.title_element,
+            &format!("{path}._title"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.creation,
+            &format!("{path}.creation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.creation_element,
+            &format!("{path}._creation"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.height, &format!("{path}.height"), visitor);
+        types::Walk::accept_mut(
+            &mut self.height_element,
+            &format!("{path}._height"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.width, &format!("{path}.width"), visitor);
+        types::Walk::accept_mut(
+            &mut self.width_element,
+            &format!("{path}._width"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.frames, &format!("{path}.frames"), visitor);
+        types::Walk::accept_mut(
+            &mut self.frames_element,
+            &format!("{path}._frames"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.duration,
+            &format!("{path}.duration"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.duration_element,
+            &format!("{path}._duration"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.pages, &format!("{path}.pages"), visitor);
+        types::Walk::accept_mut(
+            &mut self.pages_element,
+            &format!("{path}._pages"),
+            visitor,
+        );
+    }
+}
+
+impl Attachment {
+    /// The metadata of each child element of `Attachment`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "contentType",
+            json_key: "contentType",
+            rust_field: "content_type",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/mimetypes|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "language",
+            json_key: "language",
+            rust_field: "language",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/all-languages|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "data",
+            json_key: "data",
+            rust_field: "data",
+            min: 0,
+            max: Some(1),
+            types: &["base64Binary"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "url",
+            json_key: "url",
+            rust_field: "url",
+            min: 0,
+            max: Some(1),
+            types: &["url"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "size",
+            json_key: "size",
+            rust_field: "size",
+            min: 0,
+            max: Some(1),
+            types: &["integer64"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "hash",
+            json_key: "hash",
+            rust_field: "hash",
+            min: 0,
+            max: Some(1),
+            types: &["base64Binary"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "title",
+            json_key: "title",
+            rust_field: "title",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "creation",
+            json_key: "creation",
+            rust_field: "creation",
+            min: 0,
+            max: Some(1),
+            types: &["dateTime"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "height",
+            json_key: "height",
+            rust_field: "height",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "width",
+            json_key: "width",
+            rust_field: "width",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "frames",
+            json_key: "frames",
+            rust_field: "frames",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "duration",
+            json_key: "duration",
+            rust_field: "duration",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "pages",
+            json_key: "pages",
+            rust_field: "pages",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Attachment {
+    fn type_name(&self) -> &'static str {
+        "Attachment"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "contentType" => types::AsValueRef::as_value_ref(&self.content_type),
+            "language" => types::AsValueRef::as_value_ref(&self.language),
+            "data" => types::AsValueRef::as_value_ref(&self.data),
+            "url" => types::AsValueRef::as_value_ref(&self.url),
+            "size" => types::AsValueRef::as_value_ref(&self.size),
+            "hash" => types::AsValueRef::as_value_ref(&self.hash),
+            "title" => types::AsValueRef::as_value_ref(&self.title),
+            "creation" => types::AsValueRef::as_value_ref(&self.creation),
+            "height" => types::AsValueRef::as_value_ref(&self.height),
+            "width" => types::AsValueRef::as_value_ref(&self.width),
+            "frames" => types::AsValueRef::as_value_ref(&self.frames),
+            "duration" => types::AsValueRef::as_value_ref(&self.duration),
+            "pages" => types::AsValueRef::as_value_ref(&self.pages),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Attachment {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Attachment {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Attachment;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
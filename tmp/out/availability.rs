@@ -1,6 +1,6 @@
 //! Availability
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Availability
+//! URL: <http://hl7.org/fhir/StructureDefinition/Availability>
 //!
 //! Version: 5.0.0
 //!
@@ -10,93 +10,1064 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Availability data for an {item}
+///
+/// Availability data for an {item}.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Availability {
-    /// Availability data for an {item}
-    Availability: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Times the {item} is available
-    availableTime: ? // Element
+    ///
+    /// Times the {item} is available.
+    ///
+    /// Requirements: A collection of times that the {item} is available.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    ///
+    /// Constraints:
+    ///
+    /// - `av-1`: Cannot include start/end times when selecting all day availability.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub available_time: Vec<AvailabilityAvailableTime>,
+
+    /// Not available during this time due to provided reason
+    ///
+    /// Not available during this time due to provided reason.
+    ///
+    /// Requirements: The {item} is not available during this period of time due to the provided reason.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub not_available_time: Vec<AvailabilityNotAvailableTime>,
+}
+
+impl Availability {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Availability {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> AvailabilityBuilder {
+        AvailabilityBuilder::default()
+    }
+}
+
+/// Build one `Availability`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct AvailabilityBuilder {
+    inner: Availability,
+}
+
+impl AvailabilityBuilder {
+    /// Set `Availability.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Availability.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Availability.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `Availability.availableTime`.
+    pub fn available_time(
+        mut self,
+        value: impl Into<Vec<AvailabilityAvailableTime>>,
+    ) -> Self {
+        self.inner.available_time = value.into();
+        self
+    }
+
+    /// Add one `Availability.availableTime`.
+    pub fn push_available_time(
+        mut self,
+        value: impl Into<AvailabilityAvailableTime>,
+    ) -> Self {
+        self.inner.available_time.push(value.into());
+        self
+    }
+
+    /// Set every `Availability.notAvailableTime`.
+    pub fn not_available_time(
+        mut self,
+        value: impl Into<Vec<AvailabilityNotAvailableTime>>,
+    ) -> Self {
+        self.inner.not_available_time = value.into();
+        self
+    }
+
+    /// Add one `Availability.notAvailableTime`.
+    pub fn push_not_available_time(
+        mut self,
+        value: impl Into<AvailabilityNotAvailableTime>,
+    ) -> Self {
+        self.inner.not_available_time.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Availability, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Availability {
+    const PATH: &'static str = "Availability";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.available_time,
+            &format!("{path}.availableTime"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.not_available_time,
+            &format!("{path}.notAvailableTime"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Availability {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_availability(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(
+            &self.available_time,
+            &format!("{path}.availableTime"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.not_available_time,
+            &format!("{path}.notAvailableTime"),
+            visitor,
+        );
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_availability_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.available_time,
+            &format!("{path}.availableTime"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.not_available_time,
+            &format!("{path}.notAvailableTime"),
+            visitor,
+        );
+    }
+}
+
+impl Availability {
+    /// The metadata of each child element of `Availability`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "availableTime",
+            json_key: "availableTime",
+            rust_field: "available_time",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "notAvailableTime",
+            json_key: "notAvailableTime",
+            rust_field: "not_available_time",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
 
+impl types::Reflect for Availability {
+    fn type_name(&self) -> &'static str {
+        "Availability"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "availableTime" => types::AsValueRef::as_value_ref(&self.available_time),
+            "notAvailableTime" => {
+                types::AsValueRef::as_value_ref(&self.not_available_time)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Availability {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Availability {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Times the {item} is available
+///
+/// Times the {item} is available.
+///
+/// Requirements: A collection of times that the {item} is available.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Constraints:
+///
+/// - `av-1`: Cannot include start/end times when selecting all day availability.
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct AvailabilityAvailableTime {
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// mon | tue | wed | thu | fri | sat | sun
-    daysOfWeek: ? // code
+    ///
+    /// mon | tue | wed | thu | fri | sat | sun.
+    ///
+    /// Requirements: Indicates which days of the week are available between the start and end Times.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/days-of-week|5.0.0`](http://hl7.org/fhir/ValueSet/days-of-week)
+    ///
+    /// The purpose for which an extended contact detail should be used.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub days_of_week: Vec<Option<types::Code>>,
+
+    /// Id and extensions of `daysOfWeek`
+    #[serde(rename = "_daysOfWeek", default, skip_serializing_if = "Vec::is_empty")]
+    pub days_of_week_element: Vec<Option<types::Element>>,
 
     /// Always available? i.e. 24 hour service
-    allDay: ? // boolean
+    ///
+    /// Always available? i.e. 24 hour service.
+    ///
+    /// Requirements: Is this always available? (hence times are irrelevant) i.e. 24 hour service.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub all_day: Option<types::Boolean>,
+
+    /// Id and extensions of `allDay`
+    #[serde(rename = "_allDay")]
+    pub all_day_element: Option<types::Element>,
 
     /// Opening time of day (ignored if allDay = true)
-    availableStartTime: ? // time
+    ///
+    /// Opening time of day (ignored if allDay = true).
+    ///
+    /// Comment: The timezone is expected to be specified or implied by the context this datatype is used.
+    ///
+    /// Requirements: The opening time of day. Note: If the AllDay flag is set, then this time is ignored.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `time`
+    pub available_start_time: Option<types::Time>,
+
+    /// Id and extensions of `availableStartTime`
+    #[serde(rename = "_availableStartTime")]
+    pub available_start_time_element: Option<types::Element>,
 
     /// Closing time of day (ignored if allDay = true)
-    availableEndTime: ? // time
+    ///
+    /// Closing time of day (ignored if allDay = true).
+    ///
+    /// Comment: The timezone is expected to be specified or implied by the context this datatype is used.
+    ///
+    /// Requirements: The closing time of day. Note: If the AllDay flag is set, then this time is ignored.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `time`
+    pub available_end_time: Option<types::Time>,
 
-    /// Not available during this time due to provided reason
-    notAvailableTime: ? // Element
+    /// Id and extensions of `availableEndTime`
+    #[serde(rename = "_availableEndTime")]
+    pub available_end_time_element: Option<types::Element>,
+}
+
+impl AvailabilityAvailableTime {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AvailabilityAvailableTime {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> AvailabilityAvailableTimeBuilder {
+        AvailabilityAvailableTimeBuilder::default()
+    }
+}
+
+/// Build one `AvailabilityAvailableTime`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct AvailabilityAvailableTimeBuilder {
+    inner: AvailabilityAvailableTime,
+}
+
+impl AvailabilityAvailableTimeBuilder {
+    /// Set `Availability.availableTime.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
 
+    /// Set every `Availability.availableTime.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Availability.availableTime.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `Availability.availableTime.daysOfWeek`.
+    pub fn days_of_week(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Code>>,
+    ) -> Self {
+        self.inner.days_of_week = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `Availability.availableTime.daysOfWeek`.
+    pub fn push_days_of_week(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.days_of_week.push(Some(value.into()));
+        self
+    }
+
+    /// Set `Availability.availableTime.allDay`.
+    pub fn all_day(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.all_day = Some(value.into());
+        self
+    }
+
+    /// Set `Availability.availableTime.availableStartTime`.
+    pub fn available_start_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.available_start_time = Some(value.into());
+        self
+    }
+
+    /// Set `Availability.availableTime.availableEndTime`.
+    pub fn available_end_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.available_end_time = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<AvailabilityAvailableTime, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for AvailabilityAvailableTime {
+    const PATH: &'static str = "Availability.availableTime";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.days_of_week,
+            &format!("{path}.daysOfWeek"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.days_of_week_element,
+            &format!("{path}._daysOfWeek"),
+            issues,
+        );
+        types::Validate::validate_into(&self.all_day, &format!("{path}.allDay"), issues);
+        types::Validate::validate_into(
+            &self.all_day_element,
+            &format!("{path}._allDay"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.available_start_time,
+            &format!("{path}.availableStartTime"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.available_start_time_element,
+            &format!("{path}._availableStartTime"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.available_end_time,
+            &format!("{path}.availableEndTime"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.available_end_time_element,
+            &format!("{path}._availableEndTime"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for AvailabilityAvailableTime {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.days_of_week, &format!("{path}.daysOfWeek"), visitor);
+        types::Walk::accept(
+            &self.days_of_week_element,
+            &format!("{path}._daysOfWeek"),
+            visitor,
+        );
+        types::Walk::accept(&self.all_day, &format!("{path}.allDay"), visitor);
+        types::Walk::accept(&self.all_day_element, &format!("{path}._allDay"), visitor);
+        types::Walk::accept(
+            &self.available_start_time,
+            &format!("{path}.availableStartTime"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.available_start_time_element,
+            &format!("{path}._availableStartTime"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.available_end_time,
+            &format!("{path}.availableEndTime"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.available_end_time_element,
+            &format!("{path}._availableEndTime"),
+            visitor,
+        );
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.days_of_week,
+            &format!("{path}.daysOfWeek"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.days_of_week_element,
+            &format!("{path}._daysOfWeek"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.all_day, &format!("{path}.allDay"), visitor);
+        types::Walk::accept_mut(
+            &mut self.all_day_element,
+            &format!("{path}._allDay"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.available_start_time,
+            &format!("{path}.availableStartTime"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.available_start_time_element,
+            &format!("{path}._availableStartTime"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.available_end_time,
+            &format!("{path}.availableEndTime"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.available_end_time_element,
+            &format!("{path}._availableEndTime"),
+            visitor,
+        );
+    }
+}
+
+impl AvailabilityAvailableTime {
+    /// The metadata of each child element of `Availability.availableTime`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "daysOfWeek",
+            json_key: "daysOfWeek",
+            rust_field: "days_of_week",
+            min: 0,
+            max: None,
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/days-of-week|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "allDay",
+            json_key: "allDay",
+            rust_field: "all_day",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "availableStartTime",
+            json_key: "availableStartTime",
+            rust_field: "available_start_time",
+            min: 0,
+            max: Some(1),
+            types: &["time"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "availableEndTime",
+            json_key: "availableEndTime",
+            rust_field: "available_end_time",
+            min: 0,
+            max: Some(1),
+            types: &["time"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for AvailabilityAvailableTime {
+    fn type_name(&self) -> &'static str {
+        "Availability.availableTime"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "daysOfWeek" => types::AsValueRef::as_value_ref(&self.days_of_week),
+            "allDay" => types::AsValueRef::as_value_ref(&self.all_day),
+            "availableStartTime" => {
+                types::AsValueRef::as_value_ref(&self.available_start_time)
+            }
+            "availableEndTime" => {
+                types::AsValueRef::as_value_ref(&self.available_end_time)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for AvailabilityAvailableTime {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for AvailabilityAvailableTime {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Not available during this time due to provided reason
+///
+/// Not available during this time due to provided reason.
+///
+/// Requirements: The {item} is not available during this period of time due to the provided reason.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct AvailabilityNotAvailableTime {
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Reason presented to the user explaining why time not available
-    description: ? // string
+    ///
+    /// Reason presented to the user explaining why time not available.
+    ///
+    /// Comment: The reason will generally be provided to give the textual reason for displaying when the {item} is not available, e.g. 'Closed public holidays' or 'Independence Day'. In cases such as this, the `during` might not be included and local knowledge would be required in such cases (as don't desire to keep updating when the holiday occurs each year).
+    ///
+    /// e.g.2: 'Closed for maintenance over the summer' for this example you would want to include the `during` period, unless this was a university hospital and the "summer" period was well known, but would recommend its inclusion anyway.
+    ///
+    /// Requirements: The reason that can be presented to the user as to why this time is not available.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub description: Option<types::String>,
+
+    /// Id and extensions of `description`
+    #[serde(rename = "_description")]
+    pub description_element: Option<types::Element>,
 
     /// Service not available during this period
-    during: ? // Period
+    ///
+    /// Service not available during this period.
+    ///
+    /// Requirements: The {item} is not available (seasonally or for a public holiday) during this period.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Period`
+    pub during: Option<types::Period>,
+}
 
+impl AvailabilityNotAvailableTime {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AvailabilityNotAvailableTime {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> AvailabilityNotAvailableTimeBuilder {
+        AvailabilityNotAvailableTimeBuilder::default()
+    }
+}
+
+/// Build one `AvailabilityNotAvailableTime`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct AvailabilityNotAvailableTimeBuilder {
+    inner: AvailabilityNotAvailableTime,
+}
+
+impl AvailabilityNotAvailableTimeBuilder {
+    /// Set `Availability.notAvailableTime.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Availability.notAvailableTime.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Availability.notAvailableTime.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Availability.notAvailableTime.description`.
+    pub fn description(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.description = Some(value.into());
+        self
+    }
+
+    /// Set `Availability.notAvailableTime.during`.
+    pub fn during(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.during = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<AvailabilityNotAvailableTime, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for AvailabilityNotAvailableTime {
+    const PATH: &'static str = "Availability.notAvailableTime";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.description,
+            &format!("{path}.description"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.description_element,
+            &format!("{path}._description"),
+            issues,
+        );
+        types::Validate::validate_into(&self.during, &format!("{path}.during"), issues);
+    }
+}
+
+impl types::Walk for AvailabilityNotAvailableTime {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.description, &format!("{path}.description"), visitor);
+        types::Walk::accept(
+            &self.description_element,
+            &format!("{path}._description"),
+            visitor,
+        );
+        types::Walk::accept(&self.during, &format!("{path}.during"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.description,
+            &format!("{path}.description"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.description_element,
+            &format!("{path}._description"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.during, &format!("{path}.during"), visitor);
+    }
+}
+
+impl AvailabilityNotAvailableTime {
+    /// The metadata of each child element of `Availability.notAvailableTime`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "description",
+            json_key: "description",
+            rust_field: "description",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "during",
+            json_key: "during",
+            rust_field: "during",
+            min: 0,
+            max: Some(1),
+            types: &["Period"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for AvailabilityNotAvailableTime {
+    fn type_name(&self) -> &'static str {
+        "Availability.notAvailableTime"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "description" => types::AsValueRef::as_value_ref(&self.description),
+            "during" => types::AsValueRef::as_value_ref(&self.during),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for AvailabilityNotAvailableTime {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for AvailabilityNotAvailableTime {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Availability;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! Base
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Base
+//! URL: <http://hl7.org/fhir/StructureDefinition/Base>
 //!
 //! Version: 5.0.0
 //!
@@ -10,51 +10,131 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Base for all types and resources
+///
+/// Base definition for all types defined in FHIR type system.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
-pub struct Base {
-    /// Base for all types and resources
-    Base: ? // ?
+#[serde(deny_unknown_fields)]
+pub struct Base {}
+
+impl Base {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Base {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> BaseBuilder {
+        BaseBuilder::default()
+    }
+}
 
+/// Build one `Base`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct BaseBuilder {
+    inner: Base,
+}
+
+impl BaseBuilder {
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Base, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Base {
+    const PATH: &'static str = "Base";
+
+    fn validate_into(&self, _path: &str, _issues: &mut Vec<types::ValidationIssue>) {}
+}
+
+impl types::Walk for Base {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_base(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_base_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, _path: &str, _visitor: &mut V) {}
+}
+
+impl Base {
+    /// The metadata of each child element of `Base`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[];
+}
+
+impl types::Reflect for Base {
+    fn type_name(&self) -> &'static str {
+        "Base"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {
+        None
+    }
+}
+
+impl types::AsValueRef for Base {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Base;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
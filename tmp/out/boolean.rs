@@ -1,6 +1,6 @@
 //! Boolean
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/boolean
+//! URL: <http://hl7.org/fhir/StructureDefinition/boolean>
 //!
 //! Version: 5.0.0
 //!
@@ -10,60 +10,102 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
-#[serde_with::skip_serializing_none]
+/// Wrap the value of the FHIR `boolean` primitive type.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+/// Serialize as the bare JSON value, without a wrapping object.
+#[serde(transparent)]
 pub struct Boolean {
-    /// Primitive Type boolean
-    boolean: ? // ?
+    pub value: bool,
+}
+
+impl Boolean {
+    /// Create with the value.
+    pub fn new(value: bool) -> Self {
+        Self { value }
+    }
+}
+
+impl From<bool> for Boolean {
+    fn from(value: bool) -> Self {
+        Self { value }
+    }
+}
+
+impl types::Validate for Boolean {
+    const PATH: &'static str = "boolean";
+
+    fn validate_into(&self, _path: &str, _issues: &mut Vec<types::ValidationIssue>) {}
+}
+
+impl types::Walk for Boolean {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_boolean(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_boolean_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, _path: &str, _visitor: &mut V) {}
+}
 
-    /// xml:id (or equivalent in JSON)
-    id: ? // http://hl7.org/fhirpath/System.String
+impl Boolean {
+    /// The metadata of each child element of `boolean`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[];
+}
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+impl types::Reflect for Boolean {
+    fn type_name(&self) -> &'static str {
+        "boolean"
+    }
 
-    /// Primitive value for boolean
-    value: ? // http://hl7.org/fhirpath/System.Boolean
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
 
+    fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {
+        None
+    }
+}
+
+impl types::AsValueRef for Boolean {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        types::AsValueRef::as_value_ref(&self.value)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Boolean;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        assert_eq!(actual, ::serde_json::json!(false));
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
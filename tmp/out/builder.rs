@@ -0,0 +1,18 @@
+//! Builder
+//!
+//! The error of a builder whose required elements are not all set.
+
+/// The paths of the required elements that a builder did not set, such as
+/// `Quantity.value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingElements {
+    pub paths: Vec<&'static str>,
+}
+
+impl std::fmt::Display for MissingElements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing required elements: {}", self.paths.join(", "))
+    }
+}
+
+impl std::error::Error for MissingElements {}
@@ -0,0 +1,69 @@
+//! Choice
+//!
+//! A choice enum, such as `ExtensionValue`, is flattened into its parent
+//! struct, and reads one of its JSON keys, such as `valueString`. Serde reads
+//! a flattened `Option` as none when its value fails, so a parent struct
+//! reads a choice with `Choice::deserialize_option`, which fails instead.
+
+use ::serde::de::{DeserializeOwned, Error, MapAccess, Visitor};
+use ::serde::Deserializer;
+use std::marker::PhantomData;
+
+/// An enum that is flattened into its parent struct, with one JSON key per
+/// variant.
+pub trait Choice: DeserializeOwned {
+    /// The JSON key of each variant, such as `valueString`.
+    const KEYS: &'static [&'static str];
+
+    /// Read the one key of the choice, or none, and fail when its value
+    /// fails, or when there is more than one key.
+    fn deserialize_option<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Self>, D::Error> {
+        let visitor = ChoiceVisitor(PhantomData);
+        deserializer.deserialize_struct("Choice", Self::KEYS, visitor)
+    }
+
+    /// Read the one key of a required choice, and fail when there is none.
+    fn deserialize_required<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        Self::deserialize_option(deserializer)?
+            .ok_or_else(|| {
+                D::Error::custom(format!("missing one of the fields {:?}", Self::KEYS))
+            })
+    }
+}
+
+/// Read the keys of a choice, as a JSON value each, so that a number keeps
+/// its digits, then read the enum from the one key.
+struct ChoiceVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Choice> Visitor<'de> for ChoiceVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "one of the fields {:?}", T::KEYS)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut choice = None;
+        while let Some(key) = map.next_key::<String>()? {
+            let value: ::serde_json::Value = map.next_value()?;
+            let object = ::serde_json::Value::Object(
+                [(key.clone(), value)].into_iter().collect(),
+            );
+            let variant = T::deserialize(object).map_err(A::Error::custom)?;
+            if choice.replace(variant).is_some() {
+                return Err(
+                    A::Error::custom(
+                        format!(
+                            "more than one of the fields {:?}, such as `{key}`", T::KEYS
+                        ),
+                    ),
+                );
+            }
+        }
+        Ok(choice)
+    }
+}
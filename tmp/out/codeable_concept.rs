@@ -1,6 +1,6 @@
 //! CodeableConcept
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/CodeableConcept
+//! URL: <http://hl7.org/fhir/StructureDefinition/CodeableConcept>
 //!
 //! Version: 5.0.0
 //!
@@ -10,63 +10,320 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Concept - reference to a terminology or just  text
+///
+/// A concept that may be defined by a formal reference to a terminology or ontology or may be provided by text.
+///
+/// Comment: Not all terminology uses fit this general pattern. In some cases, models should not use CodeableConcept and use Coding directly and provide their own structure for managing text, codings, translations and the relationship between elements and pre- and post-coordination.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct CodeableConcept {
-    /// Concept - reference to a terminology or just  text
-    CodeableConcept: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Code defined by a terminology system
-    coding: ? // Coding
+    ///
+    /// A reference to a code defined by a terminology system.
+    ///
+    /// Comment: Codes may be defined very casually in enumerations, or code lists, up to very formal definitions such as SNOMED CT - see the HL7 v3 Core Principles for more information.  Ordering of codings is undefined and SHALL NOT be used to infer meaning. Generally, at most only one of the coding values will be labeled as UserSelected = true.
+    ///
+    /// Requirements: Allows for alternative encodings within a code system, and translations to other code systems.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Coding`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coding: Vec<types::Coding>,
 
     /// Plain text representation of the concept
-    text: ? // string
+    ///
+    /// A human language representation of the concept as seen/selected/uttered by the user who entered the data and/or which represents the intended meaning of the user.
+    ///
+    /// Comment: Very often the text is the same as a displayName of one of the codings.
+    ///
+    /// Requirements: The codes from the terminologies do not always capture the correct meaning with all the nuances of the human using them, or sometimes there is no appropriate code at all. In these cases, the text is used to capture the full meaning of the source.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub text: Option<types::String>,
+
+    /// Id and extensions of `text`
+    #[serde(rename = "_text")]
+    pub text_element: Option<types::Element>,
+}
+
+impl CodeableConcept {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CodeableConcept {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> CodeableConceptBuilder {
+        CodeableConceptBuilder::default()
+    }
+}
 
+/// Build one `CodeableConcept`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct CodeableConceptBuilder {
+    inner: CodeableConcept,
+}
+
+impl CodeableConceptBuilder {
+    /// Set `CodeableConcept.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `CodeableConcept.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `CodeableConcept.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `CodeableConcept.coding`.
+    pub fn coding(mut self, value: impl Into<Vec<types::Coding>>) -> Self {
+        self.inner.coding = value.into();
+        self
+    }
+
+    /// Add one `CodeableConcept.coding`.
+    pub fn push_coding(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.coding.push(value.into());
+        self
+    }
+
+    /// Set `CodeableConcept.text`.
+    pub fn text(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.text = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<CodeableConcept, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for CodeableConcept {
+    const PATH: &'static str = "CodeableConcept";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.coding, &format!("{path}.coding"), issues);
+        types::Validate::validate_into(&self.text, &format!("{path}.text"), issues);
+        types::Validate::validate_into(
+            &self.text_element,
+            &format!("{path}._text"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for CodeableConcept {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_codeable_concept(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.coding, &format!("{path}.coding"), visitor);
+        types::Walk::accept(&self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept(&self.text_element, &format!("{path}._text"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_codeable_concept_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.coding, &format!("{path}.coding"), visitor);
+        types::Walk::accept_mut(&mut self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept_mut(
+            &mut self.text_element,
+            &format!("{path}._text"),
+            visitor,
+        );
+    }
+}
+
+impl CodeableConcept {
+    /// The metadata of each child element of `CodeableConcept`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "coding",
+            json_key: "coding",
+            rust_field: "coding",
+            min: 0,
+            max: None,
+            types: &["Coding"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "text",
+            json_key: "text",
+            rust_field: "text",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for CodeableConcept {
+    fn type_name(&self) -> &'static str {
+        "CodeableConcept"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "coding" => types::AsValueRef::as_value_ref(&self.coding),
+            "text" => types::AsValueRef::as_value_ref(&self.text),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for CodeableConcept {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for CodeableConcept {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = CodeableConcept;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
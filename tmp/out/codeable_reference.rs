@@ -1,6 +1,6 @@
 //! CodeableReference
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/CodeableReference
+//! URL: <http://hl7.org/fhir/StructureDefinition/CodeableReference>
 //!
 //! Version: 5.0.0
 //!
@@ -10,63 +10,300 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Reference to a resource or a concept
+///
+/// A reference to a resource (by instance), or instead, a reference to a concept defined in a terminology or ontology (by class).
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct CodeableReference {
-    /// Reference to a resource or a concept
-    CodeableReference: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Reference to a concept (by class)
-    concept: ? // CodeableConcept
+    ///
+    /// A reference to a concept - e.g. the information is identified by its general class to the degree of precision found in the terminology.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    pub concept: Option<types::CodeableConcept>,
 
     /// Reference to a resource (by instance)
-    reference: ? // Reference
+    ///
+    /// A reference to a resource the provides exact details about the information being referenced.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Reference`
+    pub reference: Option<types::Reference>,
+}
+
+impl CodeableReference {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CodeableReference {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> CodeableReferenceBuilder {
+        CodeableReferenceBuilder::default()
+    }
+}
+
+/// Build one `CodeableReference`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct CodeableReferenceBuilder {
+    inner: CodeableReference,
+}
+
+impl CodeableReferenceBuilder {
+    /// Set `CodeableReference.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `CodeableReference.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `CodeableReference.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `CodeableReference.concept`.
+    pub fn concept(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.concept = Some(value.into());
+        self
+    }
+
+    /// Set `CodeableReference.reference`.
+    pub fn reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.reference = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<CodeableReference, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for CodeableReference {
+    const PATH: &'static str = "CodeableReference";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.concept,
+            &format!("{path}.concept"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.reference,
+            &format!("{path}.reference"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for CodeableReference {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_codeable_reference(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.concept, &format!("{path}.concept"), visitor);
+        types::Walk::accept(&self.reference, &format!("{path}.reference"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_codeable_reference_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.concept, &format!("{path}.concept"), visitor);
+        types::Walk::accept_mut(
+            &mut self.reference,
+            &format!("{path}.reference"),
+            visitor,
+        );
+    }
+}
 
+impl CodeableReference {
+    /// The metadata of each child element of `CodeableReference`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "concept",
+            json_key: "concept",
+            rust_field: "concept",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "reference",
+            json_key: "reference",
+            rust_field: "reference",
+            min: 0,
+            max: Some(1),
+            types: &["Reference"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for CodeableReference {
+    fn type_name(&self) -> &'static str {
+        "CodeableReference"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "concept" => types::AsValueRef::as_value_ref(&self.concept),
+            "reference" => types::AsValueRef::as_value_ref(&self.reference),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for CodeableReference {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for CodeableReference {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = CodeableReference;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! Coding
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Coding
+//! URL: <http://hl7.org/fhir/StructureDefinition/Coding>
 //!
 //! Version: 5.0.0
 //!
@@ -10,72 +10,497 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// A reference to a code defined by a terminology system
+///
+/// A reference to a code defined by a terminology system.
+///
+/// Comment: Codes may be defined very casually in enumerations or code lists, up to very formal definitions such as SNOMED CT - see the HL7 v3 Core Principles for more information.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `cod-1`: A Coding SHOULD NOT have a display unless a code is also present.  Computation on Coding.display alone is generally unsafe.  Consider using CodeableConcept.text
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Coding {
-    /// A reference to a code defined by a terminology system
-    Coding: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Identity of the terminology system
-    system: ? // uri
+    ///
+    /// The identification of the code system that defines the meaning of the symbol in the code.
+    ///
+    /// Comment: The URI may be an OID (urn:oid:...) or a UUID (urn:uuid:...).  OIDs and UUIDs SHALL be references to the HL7 OID registry. Otherwise, the URI should come from HL7's list of FHIR defined special URIs or it should be an absolute reference to some definition that establishes the system clearly and unambiguously.
+    ///
+    /// Requirements: Need to be unambiguous about the source of the definition of the symbol.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `uri`
+    pub system: Option<types::Uri>,
+
+    /// Id and extensions of `system`
+    #[serde(rename = "_system")]
+    pub system_element: Option<types::Element>,
 
     /// Version of the system - if relevant
-    version: ? // string
+    ///
+    /// The version of the code system which was used when choosing this code. Note that a well-maintained code system does not need the version reported, because the meaning of codes is consistent across versions. However this cannot consistently be assured, and when the meaning is not guaranteed to be consistent, the version SHOULD be exchanged.
+    ///
+    /// Comment: Where the terminology does not clearly define what string should be used to identify code system versions, the recommendation is to use the date (expressed in FHIR date format) on which that version was officially published as the version date.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub version: Option<types::String>,
+
+    /// Id and extensions of `version`
+    #[serde(rename = "_version")]
+    pub version_element: Option<types::Element>,
 
     /// Symbol in syntax defined by the system
-    code: ? // code
+    ///
+    /// A symbol in syntax defined by the system. The symbol may be a predefined code or an expression in a syntax defined by the coding system (e.g. post-coordination).
+    ///
+    /// Requirements: Need to refer to a particular code in the system.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    pub code: Option<types::Code>,
+
+    /// Id and extensions of `code`
+    #[serde(rename = "_code")]
+    pub code_element: Option<types::Element>,
 
     /// Representation defined by the system
-    display: ? // string
+    ///
+    /// A representation of the meaning of the code in the system, following the rules of the system.
+    ///
+    /// Requirements: Need to be able to carry a human-readable meaning of the code for readers that do not know  the system.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub display: Option<types::String>,
+
+    /// Id and extensions of `display`
+    #[serde(rename = "_display")]
+    pub display_element: Option<types::Element>,
 
     /// If this coding was chosen directly by the user
-    userSelected: ? // boolean
+    ///
+    /// Indicates that this coding was chosen by a user directly - e.g. off a pick list of available items (codes or displays).
+    ///
+    /// Comment: Amongst a set of alternatives, a directly chosen code is the most appropriate starting point for new translations. There is some ambiguity about what exactly 'directly chosen' implies, and trading partner agreement may be needed to clarify the use of this element and its consequences more completely.
+    ///
+    /// Requirements: This has been identified as a clinical safety criterium - that this exact system/code pair was chosen explicitly, rather than inferred by the system based on some rules or language processing.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub user_selected: Option<types::Boolean>,
+
+    /// Id and extensions of `userSelected`
+    #[serde(rename = "_userSelected")]
+    pub user_selected_element: Option<types::Element>,
+}
+
+impl Coding {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Coding {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> CodingBuilder {
+        CodingBuilder::default()
+    }
+}
 
+/// Build one `Coding`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct CodingBuilder {
+    inner: Coding,
+}
+
+impl CodingBuilder {
+    /// Set `Coding.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Coding.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Coding.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Coding.system`.
+    pub fn system(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.system = Some(value.into());
+        self
+    }
+
+    /// Set `Coding.version`.
+    pub fn version(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.version = Some(value.into());
+        self
+    }
+
+    /// Set `Coding.code`.
+    pub fn code(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.code = Some(value.into());
+        self
+    }
+
+    /// Set `Coding.display`.
+    pub fn display(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.display = Some(value.into());
+        self
+    }
+
+    /// Set `Coding.userSelected`.
+    pub fn user_selected(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.user_selected = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Coding, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Coding {
+    const PATH: &'static str = "Coding";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.system, &format!("{path}.system"), issues);
+        types::Validate::validate_into(
+            &self.system_element,
+            &format!("{path}._system"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.version,
+            &format!("{path}.version"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.version_element,
+            &format!("{path}._version"),
+            issues,
+        );
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+        types::Validate::validate_into(
+            &self.code_element,
+            &format!("{path}._code"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.display,
+            &format!("{path}.display"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.display_element,
+            &format!("{path}._display"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.user_selected,
+            &format!("{path}.userSelected"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.user_selected_element,
+            &format!("{path}._userSelected"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Coding {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_coding(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.system, &format!("{path}.system"), visitor);
+        types::Walk::accept(&self.system_element, &format!("{path}._system"), visitor);
+        types::Walk::accept(&self.version, &format!("{path}.version"), visitor);
+        types::Walk::accept(&self.version_element, &format!("{path}._version"), visitor);
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept(&self.code_element, &format!("{path}._code"), visitor);
+        types::Walk::accept(&self.display, &format!("{path}.display"), visitor);
+        types::Walk::accept(&self.display_element, &format!("{path}._display"), visitor);
+        types::Walk::accept(
+            &self.user_selected,
+            &format!("{path}.userSelected"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.user_selected_element,
+            &format!("{path}._userSelected"),
+            visitor,
+        );
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_coding_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.system, &format!("{path}.system"), visitor);
+        types::Walk::accept_mut(
+            &mut self.system_element,
+            &format!("{path}._system"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.version, &format!("{path}.version"), visitor);
+        types::Walk::accept_mut(
+            &mut self.version_element,
+            &format!("{path}._version"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept_mut(
+            &mut self.code_element,
+            &format!("{path}._code"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.display, &format!("{path}.display"), visitor);
+        types::Walk::accept_mut(
+            &mut self.display_element,
+            &format!("{path}._display"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.user_selected,
+            &format!("{path}.userSelected"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.user_selected_element,
+            &format!("{path}._userSelected"),
+            visitor,
+        );
+    }
+}
+
+impl Coding {
+    /// The metadata of each child element of `Coding`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "system",
+            json_key: "system",
+            rust_field: "system",
+            min: 0,
+            max: Some(1),
+            types: &["uri"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "version",
+            json_key: "version",
+            rust_field: "version",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "display",
+            json_key: "display",
+            rust_field: "display",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "userSelected",
+            json_key: "userSelected",
+            rust_field: "user_selected",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Coding {
+    fn type_name(&self) -> &'static str {
+        "Coding"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "system" => types::AsValueRef::as_value_ref(&self.system),
+            "version" => types::AsValueRef::as_value_ref(&self.version),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            "display" => types::AsValueRef::as_value_ref(&self.display),
+            "userSelected" => types::AsValueRef::as_value_ref(&self.user_selected),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Coding {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Coding {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Coding;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! ContactDetail
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/ContactDetail
+//! URL: <http://hl7.org/fhir/StructureDefinition/ContactDetail>
 //!
 //! Version: 5.0.0
 //!
@@ -10,63 +10,316 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Contact information
+///
+/// Specifies contact information for a person or organization.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct ContactDetail {
-    /// Contact information
-    ContactDetail: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Name of an individual to contact
-    name: ? // string
+    ///
+    /// The name of an individual to contact.
+    ///
+    /// Comment: If there is no named individual, the telecom information is for the organization as a whole.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub name: Option<types::String>,
+
+    /// Id and extensions of `name`
+    #[serde(rename = "_name")]
+    pub name_element: Option<types::Element>,
 
     /// Contact details for individual or organization
-    telecom: ? // ContactPoint
+    ///
+    /// The contact details for the individual (if a name was provided) or the organization.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `ContactPoint`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub telecom: Vec<types::ContactPoint>,
+}
+
+impl ContactDetail {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ContactDetail {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ContactDetailBuilder {
+        ContactDetailBuilder::default()
+    }
+}
 
+/// Build one `ContactDetail`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ContactDetailBuilder {
+    inner: ContactDetail,
+}
+
+impl ContactDetailBuilder {
+    /// Set `ContactDetail.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ContactDetail.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ContactDetail.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ContactDetail.name`.
+    pub fn name(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.name = Some(value.into());
+        self
+    }
+
+    /// Set every `ContactDetail.telecom`.
+    pub fn telecom(mut self, value: impl Into<Vec<types::ContactPoint>>) -> Self {
+        self.inner.telecom = value.into();
+        self
+    }
+
+    /// Add one `ContactDetail.telecom`.
+    pub fn push_telecom(mut self, value: impl Into<types::ContactPoint>) -> Self {
+        self.inner.telecom.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ContactDetail, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for ContactDetail {
+    const PATH: &'static str = "ContactDetail";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.name, &format!("{path}.name"), issues);
+        types::Validate::validate_into(
+            &self.name_element,
+            &format!("{path}._name"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.telecom,
+            &format!("{path}.telecom"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ContactDetail {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_contact_detail(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept(&self.name_element, &format!("{path}._name"), visitor);
+        types::Walk::accept(&self.telecom, &format!("{path}.telecom"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_contact_detail_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept_mut(
+            &mut self.name_element,
+            &format!("{path}._name"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.telecom, &format!("{path}.telecom"), visitor);
+    }
+}
+
+impl ContactDetail {
+    /// The metadata of each child element of `ContactDetail`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "name",
+            json_key: "name",
+            rust_field: "name",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "telecom",
+            json_key: "telecom",
+            rust_field: "telecom",
+            min: 0,
+            max: None,
+            types: &["ContactPoint"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ContactDetail {
+    fn type_name(&self) -> &'static str {
+        "ContactDetail"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "name" => types::AsValueRef::as_value_ref(&self.name),
+            "telecom" => types::AsValueRef::as_value_ref(&self.telecom),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ContactDetail {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ContactDetail {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = ContactDetail;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! ContactPoint
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/ContactPoint
+//! URL: <http://hl7.org/fhir/StructureDefinition/ContactPoint>
 //!
 //! Version: 5.0.0
 //!
@@ -10,72 +10,470 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Details of a Technology mediated contact point (phone, fax, email, etc.)
+///
+/// Details for all kinds of technology mediated contact points for a person or organization, including telephone, email, etc.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `cpt-2`: A system is required if a value is provided.
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct ContactPoint {
-    /// Details of a Technology mediated contact point (phone, fax, email, etc.)
-    ContactPoint: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// phone | fax | email | pager | url | sms | other
-    system: ? // code
+    ///
+    /// Telecommunications form for contact point - what communications system is required to make use of the contact.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/contact-point-system|5.0.0`](http://hl7.org/fhir/ValueSet/contact-point-system)
+    ///
+    /// Telecommunications form for contact point.
+    pub system: Option<types::Code>,
+
+    /// Id and extensions of `system`
+    #[serde(rename = "_system")]
+    pub system_element: Option<types::Element>,
 
     /// The actual contact point details
-    value: ? // string
+    ///
+    /// The actual contact point details, in a form that is meaningful to the designated communication system (i.e. phone number or email address).
+    ///
+    /// Comment: Additional text data such as phone extension numbers, or notes about use of the contact are sometimes included in the value.
+    ///
+    /// Requirements: Need to support legacy numbers that are not in a tightly controlled format.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub value: Option<types::String>,
+
+    /// Id and extensions of `value`
+    #[serde(rename = "_value")]
+    pub value_element: Option<types::Element>,
 
     /// home | work | temp | old | mobile - purpose of this contact point
-    use: ? // code
+    ///
+    /// Identifies the purpose for the contact point.
+    ///
+    /// Comment: Applications can assume that a contact is current unless it explicitly says that it is temporary or old.
+    ///
+    /// Requirements: Need to track the way a person uses this contact, so a user can choose which is appropriate for their purpose.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/contact-point-use|5.0.0`](http://hl7.org/fhir/ValueSet/contact-point-use)
+    ///
+    /// Use of contact point.
+    ///
+    /// Modifier: This is labeled as "Is Modifier" because applications should not mistake a temporary or old contact etc.for a current/permanent one
+    pub r#use: Option<types::Code>,
+
+    /// Id and extensions of `use`
+    #[serde(rename = "_use")]
+    pub use_element: Option<types::Element>,
 
     /// Specify preferred order of use (1 = highest)
-    rank: ? // positiveInt
+    ///
+    /// Specifies a preferred order in which to use a set of contacts. ContactPoints with lower rank values are more preferred than those with higher rank values.
+    ///
+    /// Comment: Note that rank does not necessarily follow the order in which the contacts are represented in the instance.
+    ///
+    /// Ranks need not be unique.  E.g. it's possible to have multiple contacts with rank=1.  If the ranks have different systems or uses, this would be interpreted to mean "X is my most preferred phone number, Y is my most preferred email address" or "X is my preferred home email and Y is my preferred work email".  If the system and use for equally-ranked contacts are the same, then the level of preference is equivalent for both repetitions.
+    ///
+    /// Ranks need not be sequential and not all repetitions must have a rank.  For example, it's possible to have 4 contacts with ranks of 2, 5 and two with no rank specified.  That would be interpreted to mean the first is preferred over the second and no preference stated for the remaining contacts.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub rank: Option<types::PositiveInt>,
+
+    /// Id and extensions of `rank`
+    #[serde(rename = "_rank")]
+    pub rank_element: Option<types::Element>,
 
     /// Time period when the contact point was/is in use
-    period: ? // Period
+    ///
+    /// Time period when the contact point was/is in use.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Period`
+    pub period: Option<types::Period>,
+}
+
+impl ContactPoint {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ContactPoint {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ContactPointBuilder {
+        ContactPointBuilder::default()
+    }
+}
+
+/// Build one `ContactPoint`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ContactPointBuilder {
+    inner: ContactPoint,
+}
+
+impl ContactPointBuilder {
+    /// Set `ContactPoint.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ContactPoint.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ContactPoint.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ContactPoint.system`.
+    pub fn system(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.system = Some(value.into());
+        self
+    }
+
+    /// Set `ContactPoint.value`.
+    pub fn value(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.value = Some(value.into());
+        self
+    }
+
+    /// Set `ContactPoint.use`.
+    pub fn r#use(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.r#use = Some(value.into());
+        self
+    }
+
+    /// Set `ContactPoint.rank`.
+    pub fn rank(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.rank = Some(value.into());
+        self
+    }
+
+    /// Set `ContactPoint.period`.
+    pub fn period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.period = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ContactPoint, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
 
+impl types::Validate for ContactPoint {
+    const PATH: &'static str = "ContactPoint";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.system, &format!("{path}.system"), issues);
+        types::Validate::validate_into(
+            &self.system_element,
+            &format!("{path}._system"),
+            issues,
+        );
+        types::Validate::validate_into(&self.value, &format!("{path}.value"), issues);
+        types::Validate::validate_into(
+            &self.value_element,
+            &format!("{path}._value"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#use, &format!("{path}.use"), issues);
+        types::Validate::validate_into(
+            &self.use_element,
+            &format!("{path}._use"),
+            issues,
+        );
+        types::Validate::validate_into(&self.rank, &format!("{path}.rank"), issues);
+        types::Validate::validate_into(
+            &self.rank_element,
+            &format!("{path}._rank"),
+            issues,
+        );
+        types::Validate::validate_into(&self.period, &format!("{path}.period"), issues);
+    }
+}
+
+impl types::Walk for ContactPoint {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_contact_point(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.system, &format!("{path}.system"), visitor);
+        types::Walk::accept(&self.system_element, &format!("{path}._system"), visitor);
+        types::Walk::accept(&self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept(&self.value_element, &format!("{path}._value"), visitor);
+        types::Walk::accept(&self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept(&self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept(&self.rank, &format!("{path}.rank"), visitor);
+        types::Walk::accept(&self.rank_element, &format!("{path}._rank"), visitor);
+        types::Walk::accept(&self.period, &format!("{path}.period"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_contact_point_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.system, &format!("{path}.system"), visitor);
+        types::Walk::accept_mut(
+            &mut self.system_element,
+            &format!("{path}._system"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept_mut(
+            &mut self.value_element,
+            &format!("{path}._value"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept_mut(&mut self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept_mut(&mut self.rank, &format!("{path}.rank"), visitor);
+        types::Walk::accept_mut(
+            &mut self.rank_element,
+            &format!("{path}._rank"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.period, &format!("{path}.period"), visitor);
+    }
+}
+
+impl ContactPoint {
+    /// The metadata of each child element of `ContactPoint`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "system",
+            json_key: "system",
+            rust_field: "system",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/contact-point-system|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value",
+            json_key: "value",
+            rust_field: "value",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "use",
+            json_key: "use",
+            rust_field: "r#use",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/contact-point-use|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "rank",
+            json_key: "rank",
+            rust_field: "rank",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "period",
+            json_key: "period",
+            rust_field: "period",
+            min: 0,
+            max: Some(1),
+            types: &["Period"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ContactPoint {
+    fn type_name(&self) -> &'static str {
+        "ContactPoint"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "system" => types::AsValueRef::as_value_ref(&self.system),
+            "value" => types::AsValueRef::as_value_ref(&self.value),
+            "use" => types::AsValueRef::as_value_ref(&self.r#use),
+            "rank" => types::AsValueRef::as_value_ref(&self.rank),
+            "period" => types::AsValueRef::as_value_ref(&self.period),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ContactPoint {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ContactPoint {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = ContactPoint;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
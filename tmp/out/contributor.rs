@@ -1,6 +1,6 @@
 //! Contributor
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Contributor
+//! URL: <http://hl7.org/fhir/StructureDefinition/Contributor>
 //!
 //! Version: 5.0.0
 //!
@@ -10,66 +10,389 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Contributor information
+///
+/// A contributor to the content of a knowledge asset, including authors, editors, reviewers, and endorsers.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Contributor {
-    /// Contributor information
-    Contributor: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// author | editor | reviewer | endorser
-    type: ? // code
+    ///
+    /// The type of contributor.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/contributor-type|5.0.0`](http://hl7.org/fhir/ValueSet/contributor-type)
+    ///
+    /// The type of contributor.
+    pub r#type: types::Code,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
 
     /// Who contributed the content
-    name: ? // string
+    ///
+    /// The name of the individual or organization responsible for the contribution.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub name: types::String,
+
+    /// Id and extensions of `name`
+    #[serde(rename = "_name")]
+    pub name_element: Option<types::Element>,
 
     /// Contact details of the contributor
-    contact: ? // ContactDetail
+    ///
+    /// Contact details to assist a user in finding and communicating with the contributor.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `ContactDetail`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contact: Vec<types::ContactDetail>,
+}
+
+impl Contributor {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(r#type: types::Code, name: types::String) -> Self {
+        Self {
+            r#type,
+            name,
+            ..Default::default()
+        }
+    }
+}
+
+impl Contributor {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ContributorBuilder {
+        ContributorBuilder::default()
+    }
+}
+
+/// Build one `Contributor`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ContributorBuilder {
+    inner: Contributor,
+    r#type: Option<types::Code>,
+    name: Option<types::String>,
+}
+
+impl ContributorBuilder {
+    /// Set `Contributor.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Contributor.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Contributor.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Contributor.type`.
+    pub fn r#type(mut self, value: impl Into<types::Code>) -> Self {
+        self.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `Contributor.name`.
+    pub fn name(mut self, value: impl Into<types::String>) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Set every `Contributor.contact`.
+    pub fn contact(mut self, value: impl Into<Vec<types::ContactDetail>>) -> Self {
+        self.inner.contact = value.into();
+        self
+    }
+
+    /// Add one `Contributor.contact`.
+    pub fn push_contact(mut self, value: impl Into<types::ContactDetail>) -> Self {
+        self.inner.contact.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Contributor, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.r#type {
+            Some(value) => inner.r#type = value,
+            None => missing.push("Contributor.type"),
+        }
+        match self.name {
+            Some(value) => inner.name = value,
+            None => missing.push("Contributor.name"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
 
+impl types::Validate for Contributor {
+    const PATH: &'static str = "Contributor";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(&self.name, &format!("{path}.name"), issues);
+        types::Validate::validate_into(
+            &self.name_element,
+            &format!("{path}._name"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.contact,
+            &format!("{path}.contact"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Contributor {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_contributor(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept(&self.name_element, &format!("{path}._name"), visitor);
+        types::Walk::accept(&self.contact, &format!("{path}.contact"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_contributor_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept_mut(
+            &mut self.name_element,
+            &format!("{path}._name"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.contact, &format!("{path}.contact"), visitor);
+    }
+}
+
+impl Contributor {
+    /// The metadata of each child element of `Contributor`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/contributor-type|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "name",
+            json_key: "name",
+            rust_field: "name",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "contact",
+            json_key: "contact",
+            rust_field: "contact",
+            min: 0,
+            max: None,
+            types: &["ContactDetail"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Contributor {
+    fn type_name(&self) -> &'static str {
+        "Contributor"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "name" => types::AsValueRef::as_value_ref(&self.name),
+            "contact" => types::AsValueRef::as_value_ref(&self.contact),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Contributor {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Contributor {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Contributor;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["name", "type"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
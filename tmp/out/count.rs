@@ -1,6 +1,6 @@
 //! Count
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Count
+//! URL: <http://hl7.org/fhir/StructureDefinition/Count>
 //!
 //! Version: 5.0.0
 //!
@@ -10,72 +10,495 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// A measured or measurable amount
+///
+/// A measured amount (or an amount that can potentially be measured). Note that measured amounts include amounts that are not precisely quantified, including amounts involving arbitrary units and floating currencies.
+///
+/// Comment: The context of use may frequently define what kind of quantity this is and therefore what kind of units can be used. The context of use may also restrict the values for the comparator.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `cnt-3`: There SHALL be a code with a value of "1" if there is a value. If system is present, it SHALL be UCUM.  If present, the value SHALL be a whole number.
+/// - `qty-3`: If a code for the unit is present, the system SHALL also be present
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Count {
-    /// A measured or measurable amount
-    Count: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Numerical value (with implicit precision)
-    value: ? // decimal
+    ///
+    /// The value of the measured amount. The value includes an implicit precision in the presentation of the value.
+    ///
+    /// Comment: The implicit precision in the value should always be honored. Monetary values have their own rules for handling precision (refer to standard accounting text books).
+    ///
+    /// Requirements: Precision is handled implicitly in almost all cases of measurement.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub value: Option<types::Decimal>,
+
+    /// Id and extensions of `value`
+    #[serde(rename = "_value")]
+    pub value_element: Option<types::Element>,
 
-    /// < | <= | >= | > | ad - how to understand the value
-    comparator: ? // code
+    /// &lt; | &lt;= | &gt;= | &gt; | ad - how to understand the value
+    ///
+    /// How the value should be understood and represented - whether the actual value is greater or less than the stated value due to measurement issues; e.g. if the comparator is "&lt;" , then the real value is &lt; stated value.
+    ///
+    /// Requirements: Need a framework for handling measures where the value is &lt;5ug/L or &gt;400mg/L due to the limitations of measuring methodology.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/quantity-comparator|5.0.0`](http://hl7.org/fhir/ValueSet/quantity-comparator)
+    ///
+    /// How the Quantity should be understood and represented.
+    ///
+    /// Modifier: This is labeled as "Is Modifier" because the comparator modifies the interpretation of the value significantly. If there is no comparator, then there is no modification of the value
+    pub comparator: Option<types::Code>,
+
+    /// Id and extensions of `comparator`
+    #[serde(rename = "_comparator")]
+    pub comparator_element: Option<types::Element>,
 
     /// Unit representation
-    unit: ? // string
+    ///
+    /// A human-readable form of the unit.
+    ///
+    /// Requirements: There are many representations for units of measure and in many contexts, particular representations are fixed and required. I.e. mcg for micrograms.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub unit: Option<types::String>,
+
+    /// Id and extensions of `unit`
+    #[serde(rename = "_unit")]
+    pub unit_element: Option<types::Element>,
 
     /// System that defines coded unit form
-    system: ? // uri
+    ///
+    /// The identification of the system that provides the coded form of the unit.
+    ///
+    /// Requirements: Need to know the system that defines the coded form of the unit.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `uri`
+    pub system: Option<types::Uri>,
+
+    /// Id and extensions of `system`
+    #[serde(rename = "_system")]
+    pub system_element: Option<types::Element>,
 
     /// Coded form of the unit
-    code: ? // code
+    ///
+    /// A computer processable form of the unit in some unit representation system.
+    ///
+    /// Comment: The preferred system is UCUM, but SNOMED CT can also be used (for customary units) or ISO 4217 for currency.  The context of use may additionally require a code from a particular system.
+    ///
+    /// Requirements: Need a computable form of the unit that is fixed across all forms. UCUM provides this for quantities, but SNOMED CT provides many units of interest.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    pub code: Option<types::Code>,
+
+    /// Id and extensions of `code`
+    #[serde(rename = "_code")]
+    pub code_element: Option<types::Element>,
+}
+
+impl Count {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Count {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> CountBuilder {
+        CountBuilder::default()
+    }
+}
 
+/// Build one `Count`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct CountBuilder {
+    inner: Count,
+}
+
+impl CountBuilder {
+    /// Set `Count.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Count.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Count.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Count.value`.
+    pub fn value(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.value = Some(value.into());
+        self
+    }
+
+    /// Set `Count.comparator`.
+    pub fn comparator(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.comparator = Some(value.into());
+        self
+    }
+
+    /// Set `Count.unit`.
+    pub fn unit(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.unit = Some(value.into());
+        self
+    }
+
+    /// Set `Count.system`.
+    pub fn system(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.system = Some(value.into());
+        self
+    }
+
+    /// Set `Count.code`.
+    pub fn code(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.code = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Count, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Count {
+    const PATH: &'static str = "Count";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.value, &format!("{path}.value"), issues);
+        types::Validate::validate_into(
+            &self.value_element,
+            &format!("{path}._value"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.comparator,
+            &format!("{path}.comparator"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.comparator_element,
+            &format!("{path}._comparator"),
+            issues,
+        );
+        types::Validate::validate_into(&self.unit, &format!("{path}.unit"), issues);
+        types::Validate::validate_into(
+            &self.unit_element,
+            &format!("{path}._unit"),
+            issues,
+        );
+        types::Validate::validate_into(&self.system, &format!("{path}.system"), issues);
+        types::Validate::validate_into(
+            &self.system_element,
+            &format!("{path}._system"),
+            issues,
+        );
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+        types::Validate::validate_into(
+            &self.code_element,
+            &format!("{path}._code"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Count {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_count(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept(&self.value_element, &format!("{path}._value"), visitor);
+        types::Walk::accept(&self.comparator, &format!("{path}.comparator"), visitor);
+        types::Walk::accept(
+            &self.comparator_element,
+            &format!("{path}._comparator"),
+            visitor,
+        );
+        types::Walk::accept(&self.unit, &format!("{path}.unit"), visitor);
+        types::Walk::accept(&self.unit_element, &format!("{path}._unit"), visitor);
+        types::Walk::accept(&self.system, &format!("{path}.system"), visitor);
+        types::Walk::accept(&self.system_element, &format!("{path}._system"), visitor);
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept(&self.code_element, &format!("{path}._code"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_count_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept_mut(
+            &mut self.value_element,
+            &format!("{path}._value"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.comparator,
+            &format!("{path}.comparator"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.comparator_element,
+            &format!("{path}._comparator"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.unit, &format!("{path}.unit"), visitor);
+        types::Walk::accept_mut(
+            &mut self.unit_element,
+            &format!("{path}._unit"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.system, &format!("{path}.system"), visitor);
+        types::Walk::accept_mut(
+            &mut self.system_element,
+            &format!("{path}._system"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept_mut(
+            &mut self.code_element,
+            &format!("{path}._code"),
+            visitor,
+        );
+    }
+}
+
+impl Count {
+    /// The metadata of each child element of `Count`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value",
+            json_key: "value",
+            rust_field: "value",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "comparator",
+            json_key: "comparator",
+            rust_field: "comparator",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/quantity-comparator|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "unit",
+            json_key: "unit",
+            rust_field: "unit",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "system",
+            json_key: "system",
+            rust_field: "system",
+            min: 0,
+            max: Some(1),
+            types: &["uri"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Count {
+    fn type_name(&self) -> &'static str {
+        "Count"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "value" => types::AsValueRef::as_value_ref(&self.value),
+            "comparator" => types::AsValueRef::as_value_ref(&self.comparator),
+            "unit" => types::AsValueRef::as_value_ref(&self.unit),
+            "system" => types::AsValueRef::as_value_ref(&self.system),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Count {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Count {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Count;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! DataRequirement
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/DataRequirement
+//! URL: <http://hl7.org/fhir/StructureDefinition/DataRequirement>
 //!
 //! Version: 5.0.0
 //!
@@ -10,147 +10,2517 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Describes a required data item
+///
+/// Describes a required data item for evaluation in terms of the type of data, and optional code or date-based filters of the data.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
 pub struct DataRequirement {
-    /// Describes a required data item
-    DataRequirement: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// The type of the required data
-    type: ? // code
+    ///
+    /// The type of the required data, specified as the type name of a resource. For profiles, this value is set to the type of the base resource of the profile.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/fhir-types|5.0.0`](http://hl7.org/fhir/ValueSet/fhir-types)
+    ///
+    /// List of FHIR types (resources, data types).
+    pub r#type: types::Code,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
 
     /// The profile of the required data
-    profile: ? // canonical
+    ///
+    /// The profile of the required data, specified as the uri of the profile definition.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `canonical(StructureDefinition)`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profile: Vec<Option<types::Canonical>>,
+
+    /// Id and extensions of `profile`
+    #[serde(rename = "_profile", default, skip_serializing_if = "Vec::is_empty")]
+    pub profile_element: Vec<Option<types::Element>>,
 
     /// E.g. Patient, Practitioner, RelatedPerson, Organization, Location, Device
-    : ? // CodeableConcept
+    ///
+    /// The intended subjects of the data requirement. If this element is not provided, a Patient subject is assumed.
+    ///
+    /// Comment: The subject of a data requirement is critical, as the data being specified is determined with respect to a particular subject. This corresponds roughly to the notion of a Compartment in that it limits what data is available based on its relationship to the subject. In CQL, this corresponds to the context declaration.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`, `Reference(Group)`
+    ///
+    /// Binding: extensible to [`http://hl7.org/fhir/ValueSet/participant-resource-types`](http://hl7.org/fhir/ValueSet/participant-resource-types)
+    ///
+    /// The possible types of subjects for a data requirement (E.g., Patient, Practitioner, Organization, Location, etc.).
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub subject: Option<DataRequirementSubject>,
 
     /// Indicates specific structure elements that are referenced by the knowledge module
-    mustSupport: ? // string
+    ///
+    /// Indicates that specific elements of the type are referenced by the knowledge module and must be supported by the consumer in order to obtain an effective evaluation. This does not mean that a value is required for this element, only that the consuming system must understand the element and be able to provide values for it if they are available.
+    ///
+    /// The value of mustSupport SHALL be a FHIRPath resolvable on the type of the DataRequirement. The path SHALL consist only of identifiers, constant indexers, and .resolve() (see the [Simple FHIRPath Profile](https://build.fhir.org/fhirpath.html#simple) for full details).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `string`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub must_support: Vec<Option<types::String>>,
+
+    /// Id and extensions of `mustSupport`
+    #[serde(rename = "_mustSupport", default, skip_serializing_if = "Vec::is_empty")]
+    pub must_support_element: Vec<Option<types::Element>>,
 
     /// What codes are expected
-    codeFilter: ? // Element
+    ///
+    /// Code filters specify additional constraints on the data, specifying the value set of interest for a particular element of the data. Each code filter defines an additional constraint on the data, i.e. code filters are AND'ed, not OR'ed.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    ///
+    /// Constraints:
+    ///
+    /// - `drq-1`: Either a path or a searchParam must be provided, but not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub code_filter: Vec<DataRequirementCodeFilter>,
+
+    /// What dates/date ranges are expected
+    ///
+    /// Date filters specify additional constraints on the data in terms of the applicable date range for specific elements. Each date filter specifies an additional constraint on the data, i.e. date filters are AND'ed, not OR'ed.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    ///
+    /// Constraints:
+    ///
+    /// - `drq-2`: Either a path or a searchParam must be provided, but not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub date_filter: Vec<DataRequirementDateFilter>,
+
+    /// What values are expected
+    ///
+    /// Value filters specify additional constraints on the data for elements other than code-valued or date-valued. Each value filter specifies an additional constraint on the data (i.e. valueFilters are AND'ed, not OR'ed).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub value_filter: Vec<DataRequirementValueFilter>,
+
+    /// Number of results
+    ///
+    /// Specifies a maximum number of results that are required (uses the _count search parameter).
+    ///
+    /// Comment: This element can be used in combination with the sort element to specify quota requirements such as "the most recent 5" or "the highest 5".
+    ///
+    /// Requirements: Enables the requirement "most recent 5 results" to be expressed.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub limit: Option<types::PositiveInt>,
+
+    /// Id and extensions of `limit`
+    #[serde(rename = "_limit")]
+    pub limit_element: Option<types::Element>,
+
+    /// Order of the results
+    ///
+    /// Specifies the order of the results to be returned.
+    ///
+    /// Comment: This element can be used in combination with the sort element to specify quota requirements such as "the most recent 5" or "the highest 5". When multiple sorts are specified, they are applied in the order they appear in the resource.
+    ///
+    /// Requirements: Enables the requirement "most recent 5 results" to be expressed.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sort: Vec<DataRequirementSort>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl DataRequirement {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(r#type: types::Code) -> Self {
+        Self {
+            r#type,
+            ..Default::default()
+        }
+    }
+}
+
+impl DataRequirement {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> DataRequirementBuilder {
+        DataRequirementBuilder::default()
+    }
+}
+
+/// Build one `DataRequirement`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct DataRequirementBuilder {
+    inner: DataRequirement,
+    r#type: Option<types::Code>,
+}
+
+impl DataRequirementBuilder {
+    /// Set `DataRequirement.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.type`.
+    pub fn r#type(mut self, value: impl Into<types::Code>) -> Self {
+        self.r#type = Some(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.profile`.
+    pub fn profile(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Canonical>>,
+    ) -> Self {
+        self.inner.profile = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `DataRequirement.profile`.
+    pub fn push_profile(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.profile.push(Some(value.into()));
+        self
+    }
+
+    /// Set `DataRequirement.subject[x]` to a `CodeableConcept`.
+    pub fn subject_codeable_concept(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.inner.subject = Some(DataRequirementSubject::CodeableConcept(value.into()));
+        self
+    }
+
+    /// Set `DataRequirement.subject[x]` to a `Reference`.
+    pub fn subject_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.subject = Some(DataRequirementSubject::Reference(value.into()));
+        self
+    }
+
+    /// Set every `DataRequirement.mustSupport`.
+    pub fn must_support(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::String>>,
+    ) -> Self {
+        self.inner.must_support = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `DataRequirement.mustSupport`.
+    pub fn push_must_support(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.must_support.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `DataRequirement.codeFilter`.
+    pub fn code_filter(
+        mut self,
+        value: impl Into<Vec<DataRequirementCodeFilter>>,
+    ) -> Self {
+        self.inner.code_filter = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.codeFilter`.
+    pub fn push_code_filter(
+        mut self,
+        value: impl Into<DataRequirementCodeFilter>,
+    ) -> Self {
+        self.inner.code_filter.push(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.dateFilter`.
+    pub fn date_filter(
+        mut self,
+        value: impl Into<Vec<DataRequirementDateFilter>>,
+    ) -> Self {
+        self.inner.date_filter = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.dateFilter`.
+    pub fn push_date_filter(
+        mut self,
+        value: impl Into<DataRequirementDateFilter>,
+    ) -> Self {
+        self.inner.date_filter.push(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.valueFilter`.
+    pub fn value_filter(
+        mut self,
+        value: impl Into<Vec<DataRequirementValueFilter>>,
+    ) -> Self {
+        self.inner.value_filter = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.valueFilter`.
+    pub fn push_value_filter(
+        mut self,
+        value: impl Into<DataRequirementValueFilter>,
+    ) -> Self {
+        self.inner.value_filter.push(value.into());
+        self
+    }
 
+    /// Set `DataRequirement.limit`.
+    pub fn limit(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.limit = Some(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.sort`.
+    pub fn sort(mut self, value: impl Into<Vec<DataRequirementSort>>) -> Self {
+        self.inner.sort = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.sort`.
+    pub fn push_sort(mut self, value: impl Into<DataRequirementSort>) -> Self {
+        self.inner.sort.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<DataRequirement, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.r#type {
+            Some(value) => inner.r#type = value,
+            None => missing.push("DataRequirement.type"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for DataRequirement {
+    const PATH: &'static str = "DataRequirement";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.profile,
+            &format!("{path}.profile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.profile_element,
+            &format!("{path}._profile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.subject,
+            &format!("{path}.subject"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.must_support,
+            &format!("{path}.mustSupport"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.must_support_element,
+            &format!("{path}._mustSupport"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.code_filter,
+            &format!("{path}.codeFilter"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.date_filter,
+            &format!("{path}.dateFilter"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_filter,
+            &format!("{path}.valueFilter"),
+            issues,
+        );
+        types::Validate::validate_into(&self.limit, &format!("{path}.limit"), issues);
+        types::Validate::validate_into(
+            &self.limit_element,
+            &format!("{path}._limit"),
+            issues,
+        );
+        types::Validate::validate_into(&self.sort, &format!("{path}.sort"), issues);
+    }
+}
+
+impl types::Walk for DataRequirement {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_data_requirement(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.profile, &format!("{path}.profile"), visitor);
+        types::Walk::accept(&self.profile_element, &format!("{path}._profile"), visitor);
+        types::Walk::accept(&self.subject, &format!("{path}.subject"), visitor);
+        types::Walk::accept(&self.must_support, &format!("{path}.mustSupport"), visitor);
+        types::Walk::accept(
+            &self.must_support_element,
+            &format!("{path}._mustSupport"),
+            visitor,
+        );
+        types::Walk::accept(&self.code_filter, &format!("{path}.codeFilter"), visitor);
+        types::Walk::accept(&self.date_filter, &format!("{path}.dateFilter"), visitor);
+        types::Walk::accept(&self.value_filter, &format!("{path}.valueFilter"), visitor);
+        types::Walk::accept(&self.limit, &format!("{path}.limit"), visitor);
+        types::Walk::accept(&self.limit_element, &format!("{path}._limit"), visitor);
+        types::Walk::accept(&self.sort, &format!("{path}.sort"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_data_requirement_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.profile, &format!("{path}.profile"), visitor);
+        types::Walk::accept_mut(
+            &mut self.profile_element,
+            &format!("{path}._profile"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.subject, &format!("{path}.subject"), visitor);
+        types::Walk::accept_mut(
+            &mut self.must_support,
+            &format!("{path}.mustSupport"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.must_support_element,
+            &format!("{path}._mustSupport"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.code_filter,
+            &format!("{path}.codeFilter"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.date_filter,
+            &format!("{path}.dateFilter"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_filter,
+            &format!("{path}.valueFilter"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.limit, &format!("{path}.limit"), visitor);
+        types::Walk::accept_mut(
+            &mut self.limit_element,
+            &format!("{path}._limit"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.sort, &format!("{path}.sort"), visitor);
+    }
+}
+
+impl DataRequirement {
+    /// The metadata of each child element of `DataRequirement`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/fhir-types|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "profile",
+            json_key: "profile",
+            rust_field: "profile",
+            min: 0,
+            max: None,
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "subject[x]",
+            json_key: "subject",
+            rust_field: "subject",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept", "Reference"],
+            binding: Some(types::BindingInfo {
+                strength: "extensible",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/participant-resource-types",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "mustSupport",
+            json_key: "mustSupport",
+            rust_field: "must_support",
+            min: 0,
+            max: None,
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "codeFilter",
+            json_key: "codeFilter",
+            rust_field: "code_filter",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "dateFilter",
+            json_key: "dateFilter",
+            rust_field: "date_filter",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "valueFilter",
+            json_key: "valueFilter",
+            rust_field: "value_filter",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "limit",
+            json_key: "limit",
+            rust_field: "limit",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "sort",
+            json_key: "sort",
+            rust_field: "sort",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for DataRequirement {
+    fn type_name(&self) -> &'static str {
+        "DataRequirement"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "profile" => types::AsValueRef::as_value_ref(&self.profile),
+            "subject[x]" | "subject" => types::AsValueRef::as_value_ref(&self.subject),
+            "mustSupport" => types::AsValueRef::as_value_ref(&self.must_support),
+            "codeFilter" => types::AsValueRef::as_value_ref(&self.code_filter),
+            "dateFilter" => types::AsValueRef::as_value_ref(&self.date_filter),
+            "valueFilter" => types::AsValueRef::as_value_ref(&self.value_filter),
+            "limit" => types::AsValueRef::as_value_ref(&self.limit),
+            "sort" => types::AsValueRef::as_value_ref(&self.sort),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for DataRequirement {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for DataRequirement {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// What codes are expected
+///
+/// Code filters specify additional constraints on the data, specifying the value set of interest for a particular element of the data. Each code filter defines an additional constraint on the data, i.e. code filters are AND'ed, not OR'ed.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Constraints:
+///
+/// - `drq-1`: Either a path or a searchParam must be provided, but not both
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct DataRequirementCodeFilter {
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// A code-valued attribute to filter on
-    path: ? // string
+    ///
+    /// The code-valued attribute of the filter. The specified path SHALL be a FHIRPath resolvable on the specified type of the DataRequirement, and SHALL consist only of identifiers, constant indexers, and .resolve(). The path is allowed to contain qualifiers (.) to traverse sub-elements, as well as indexers (\[x\]) to traverse multiple-cardinality sub-elements (see the [Simple FHIRPath Profile](https://build.fhir.org/fhirpath.html#simple) for full details). Note that the index must be an integer constant. The path must resolve to an element of type code, Coding, or CodeableConcept.
+    ///
+    /// Comment: The path attribute contains a [Simple FHIRPath Subset](https://build.fhir.org/fhirpath.html#simple) that allows path traversal, but not calculation.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub path: Option<types::String>,
+
+    /// Id and extensions of `path`
+    #[serde(rename = "_path")]
+    pub path_element: Option<types::Element>,
 
     /// A coded (token) parameter to search on
-    searchParam: ? // string
+    ///
+    /// A token parameter that refers to a search parameter defined on the specified type of the DataRequirement, and which searches on elements of type code, Coding, or CodeableConcept.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub search_param: Option<types::String>,
+
+    /// Id and extensions of `searchParam`
+    #[serde(rename = "_searchParam")]
+    pub search_param_element: Option<types::Element>,
 
     /// ValueSet for the filter
-    valueSet: ? // canonical
+    ///
+    /// The valueset for the code filter. The valueSet and code elements are additive. If valueSet is specified, the filter will return only those data items for which the value of the code-valued element specified in the path is a member of the specified valueset.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `canonical(ValueSet)`
+    pub value_set: Option<types::Canonical>,
+
+    /// Id and extensions of `valueSet`
+    #[serde(rename = "_valueSet")]
+    pub value_set_element: Option<types::Element>,
 
     /// What code is expected
-    code: ? // Coding
+    ///
+    /// The codes for the code filter. If values are given, the filter will return only those data items for which the code-valued attribute specified by the path has a value that is one of the specified codes. If codes are specified in addition to a value set, the filter returns items matching a code in the value set or one of the specified codes.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Coding`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub code: Vec<types::Coding>,
+}
 
-    /// What dates/date ranges are expected
-    dateFilter: ? // Element
+impl DataRequirementCodeFilter {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
+impl DataRequirementCodeFilter {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> DataRequirementCodeFilterBuilder {
+        DataRequirementCodeFilterBuilder::default()
+    }
+}
+
+/// Build one `DataRequirementCodeFilter`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct DataRequirementCodeFilterBuilder {
+    inner: DataRequirementCodeFilter,
+}
+
+impl DataRequirementCodeFilterBuilder {
+    /// Set `DataRequirement.codeFilter.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.codeFilter.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.codeFilter.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.codeFilter.path`.
+    pub fn path(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.path = Some(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.codeFilter.searchParam`.
+    pub fn search_param(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.search_param = Some(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.codeFilter.valueSet`.
+    pub fn value_set(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.value_set = Some(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.codeFilter.code`.
+    pub fn code(mut self, value: impl Into<Vec<types::Coding>>) -> Self {
+        self.inner.code = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.codeFilter.code`.
+    pub fn push_code(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.code.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<DataRequirementCodeFilter, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for DataRequirementCodeFilter {
+    const PATH: &'static str = "DataRequirement.codeFilter";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.path, &format!("{path}.path"), issues);
+        types::Validate::validate_into(
+            &self.path_element,
+            &format!("{path}._path"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.search_param,
+            &format!("{path}.searchParam"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.search_param_element,
+            &format!("{path}._searchParam"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_set,
+            &format!("{path}.valueSet"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_set_element,
+            &format!("{path}._valueSet"),
+            issues,
+        );
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+    }
+}
+
+impl types::Walk for DataRequirementCodeFilter {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept(&self.path_element, &format!("{path}._path"), visitor);
+        types::Walk::accept(&self.search_param, &format!("{path}.searchParam"), visitor);
+        types::Walk::accept(
+            &self.search_param_element,
+            &format!("{path}._searchParam"),
+            visitor,
+        );
+        types::Walk::accept(&self.value_set, &format!("{path}.valueSet"), visitor);
+        types::Walk::accept(
+            &self.value_set_element,
+            &format!("{path}._valueSet"),
+            visitor,
+        );
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept_mut(
+            &mut self.path_element,
+            &format!("{path}._path"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.search_param,
+            &format!("{path}.searchParam"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.search_param_element,
+            &format!("{path}._searchParam"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_set,
+            &format!("{path}.valueSet"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_set_element,
+            &format!("{path}._valueSet"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+    }
+}
+
+impl DataRequirementCodeFilter {
+    /// The metadata of each child element of `DataRequirement.codeFilter`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "path",
+            json_key: "path",
+            rust_field: "path",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "searchParam",
+            json_key: "searchParam",
+            rust_field: "search_param",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "valueSet",
+            json_key: "valueSet",
+            rust_field: "value_set",
+            min: 0,
+            max: Some(1),
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 0,
+            max: None,
+            types: &["Coding"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for DataRequirementCodeFilter {
+    fn type_name(&self) -> &'static str {
+        "DataRequirement.codeFilter"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "path" => types::AsValueRef::as_value_ref(&self.path),
+            "searchParam" => types::AsValueRef::as_value_ref(&self.search_param),
+            "valueSet" => types::AsValueRef::as_value_ref(&self.value_set),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for DataRequirementCodeFilter {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for DataRequirementCodeFilter {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// What dates/date ranges are expected
+///
+/// Date filters specify additional constraints on the data in terms of the applicable date range for specific elements. Each date filter specifies an additional constraint on the data, i.e. date filters are AND'ed, not OR'ed.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Constraints:
+///
+/// - `drq-2`: Either a path or a searchParam must be provided, but not both
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+pub struct DataRequirementDateFilter {
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// A date-valued attribute to filter on
-    path: ? // string
+    ///
+    /// The date-valued attribute of the filter. The specified path SHALL be a FHIRPath resolvable on the specified type of the DataRequirement, and SHALL consist only of identifiers, constant indexers, and .resolve(). The path is allowed to contain qualifiers (.) to traverse sub-elements, as well as indexers (\[x\]) to traverse multiple-cardinality sub-elements (see the [Simple FHIRPath Profile](https://build.fhir.org/fhirpath.html#simple) for full details). Note that the index must be an integer constant. The path must resolve to an element of type date, dateTime, Period, Schedule, or Timing.
+    ///
+    /// Comment: The path attribute contains a [Simple FHIR Subset](https://build.fhir.org/fhirpath.html#simple) that allows path traversal, but not calculation.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub path: Option<types::String>,
+
+    /// Id and extensions of `path`
+    #[serde(rename = "_path")]
+    pub path_element: Option<types::Element>,
 
     /// A date valued parameter to search on
-    searchParam: ? // string
+    ///
+    /// A date parameter that refers to a search parameter defined on the specified type of the DataRequirement, and which searches on elements of type date, dateTime, Period, Schedule, or Timing.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub search_param: Option<types::String>,
+
+    /// Id and extensions of `searchParam`
+    #[serde(rename = "_searchParam")]
+    pub search_param_element: Option<types::Element>,
 
     /// The value of the filter, as a Period, DateTime, or Duration value
-    : ? // dateTime
+    ///
+    /// The value of the filter. If period is specified, the filter will return only those data items that fall within the bounds determined by the Period, inclusive of the period boundaries. If dateTime is specified, the filter will return only those data items that are equal to the specified dateTime. If a Duration is specified, the filter will return only those data items that fall within Duration before now.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `dateTime`, `Period`, `Duration`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub value: Option<DataRequirementDateFilterValue>,
 
-    /// What values are expected
-    valueFilter: ? // Element
+    /// Id and extensions of `value[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub value_element: Option<DataRequirementDateFilterValueElement>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl DataRequirementDateFilter {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DataRequirementDateFilter {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> DataRequirementDateFilterBuilder {
+        DataRequirementDateFilterBuilder::default()
+    }
+}
+
+/// Build one `DataRequirementDateFilter`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct DataRequirementDateFilterBuilder {
+    inner: DataRequirementDateFilter,
+}
+
+impl DataRequirementDateFilterBuilder {
+    /// Set `DataRequirement.dateFilter.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.dateFilter.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.dateFilter.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.dateFilter.path`.
+    pub fn path(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.path = Some(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.dateFilter.searchParam`.
+    pub fn search_param(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.search_param = Some(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.dateFilter.value[x]` to a `dateTime`.
+    pub fn value_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.value = Some(DataRequirementDateFilterValue::DateTime(value.into()));
+        self
+    }
+
+    /// Set `DataRequirement.dateFilter.value[x]` to a `Period`.
+    pub fn value_period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.value = Some(DataRequirementDateFilterValue::Period(value.into()));
+        self
+    }
+
+    /// Set `DataRequirement.dateFilter.value[x]` to a `Duration`.
+    pub fn value_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.inner.value = Some(DataRequirementDateFilterValue::Duration(value.into()));
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<DataRequirementDateFilter, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for DataRequirementDateFilter {
+    const PATH: &'static str = "DataRequirement.dateFilter";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.path, &format!("{path}.path"), issues);
+        types::Validate::validate_into(
+            &self.path_element,
+            &format!("{path}._path"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.search_param,
+            &format!("{path}.searchParam"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.search_param_element,
+            &format!("{path}._searchParam"),
+            issues,
+        );
+        types::Validate::validate_into(&self.value, &format!("{path}.value"), issues);
+        types::Validate::validate_into(
+            &self.value_element,
+            &format!("{path}._value"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for DataRequirementDateFilter {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept(&self.path_element, &format!("{path}._path"), visitor);
+        types::Walk::accept(&self.search_param, &format!("{path}.searchParam"), visitor);
+        types::Walk::accept(
+            &self.search_param_element,
+            &format!("{path}._searchParam"),
+            visitor,
+        );
+        types::Walk::accept(&self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept(&self.value_element, &format!("{path}._value"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept_mut(
+            &mut self.path_element,
+            &format!("{path}._path"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.search_param,
+            &format!("{path}.searchParam"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.search_param_element,
+            &format!("{path}._searchParam"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept_mut(
+            &mut self.value_element,
+            &format!("{path}._value"),
+            visitor,
+        );
+    }
+}
+
+impl DataRequirementDateFilter {
+    /// The metadata of each child element of `DataRequirement.dateFilter`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "path",
+            json_key: "path",
+            rust_field: "path",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "searchParam",
+            json_key: "searchParam",
+            rust_field: "search_param",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value[x]",
+            json_key: "value",
+            rust_field: "value",
+            min: 0,
+            max: Some(1),
+            types: &["dateTime", "Period", "Duration"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for DataRequirementDateFilter {
+    fn type_name(&self) -> &'static str {
+        "DataRequirement.dateFilter"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "path" => types::AsValueRef::as_value_ref(&self.path),
+            "searchParam" => types::AsValueRef::as_value_ref(&self.search_param),
+            "value[x]" | "value" => types::AsValueRef::as_value_ref(&self.value),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for DataRequirementDateFilter {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for DataRequirementDateFilter {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
 
+/// What values are expected
+///
+/// Value filters specify additional constraints on the data for elements other than code-valued or date-valued. Each value filter specifies an additional constraint on the data (i.e. valueFilters are AND'ed, not OR'ed).
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+pub struct DataRequirementValueFilter {
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// An attribute to filter on
-    path: ? // string
+    ///
+    /// The attribute of the filter. The specified path SHALL be a FHIRPath resolvable on the specified type of the DataRequirement, and SHALL consist only of identifiers, constant indexers, and .resolve(). The path is allowed to contain qualifiers (.) to traverse sub-elements, as well as indexers (\[x\]) to traverse multiple-cardinality sub-elements (see the [Simple FHIRPath Profile](https://build.fhir.org/fhirpath.html#simple) for full details). Note that the index must be an integer constant. The path must resolve to an element of a type that is comparable to the valueFilter.value\[x\] element for the filter.
+    ///
+    /// Comment: The path attribute contains a [Simple FHIR Subset](https://build.fhir.org/fhirpath.html#simple) that allows path traversal, but not calculation.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub path: Option<types::String>,
+
+    /// Id and extensions of `path`
+    #[serde(rename = "_path")]
+    pub path_element: Option<types::Element>,
 
     /// A parameter to search on
-    searchParam: ? // string
+    ///
+    /// A search parameter defined on the specified type of the DataRequirement, and which searches on elements of a type compatible with the type of the valueFilter.value\[x\] for the filter.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub search_param: Option<types::String>,
+
+    /// Id and extensions of `searchParam`
+    #[serde(rename = "_searchParam")]
+    pub search_param_element: Option<types::Element>,
 
     /// eq | gt | lt | ge | le | sa | eb
-    comparator: ? // code
+    ///
+    /// The comparator to be used to determine whether the value is matching.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/value-filter-comparator|5.0.0`](http://hl7.org/fhir/ValueSet/value-filter-comparator)
+    ///
+    /// Possible comparators for the valueFilter element.
+    pub comparator: Option<types::Code>,
+
+    /// Id and extensions of `comparator`
+    #[serde(rename = "_comparator")]
+    pub comparator_element: Option<types::Element>,
 
     /// The value of the filter, as a Period, DateTime, or Duration value
-    : ? // dateTime
+    ///
+    /// The value of the filter.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `dateTime`, `Period`, `Duration`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub value: Option<DataRequirementValueFilterValue>,
 
-    /// Number of results
-    limit: ? // positiveInt
+    /// Id and extensions of `value[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub value_element: Option<DataRequirementValueFilterValueElement>,
 
-    /// Order of the results
-    sort: ? // Element
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl DataRequirementValueFilter {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DataRequirementValueFilter {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> DataRequirementValueFilterBuilder {
+        DataRequirementValueFilterBuilder::default()
+    }
+}
+
+/// Build one `DataRequirementValueFilter`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct DataRequirementValueFilterBuilder {
+    inner: DataRequirementValueFilter,
+}
+
+impl DataRequirementValueFilterBuilder {
+    /// Set `DataRequirement.valueFilter.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.valueFilter.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.valueFilter.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.valueFilter.path`.
+    pub fn path(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.path = Some(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.valueFilter.searchParam`.
+    pub fn search_param(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.search_param = Some(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.valueFilter.comparator`.
+    pub fn comparator(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.comparator = Some(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.valueFilter.value[x]` to a `dateTime`.
+    pub fn value_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.value = Some(DataRequirementValueFilterValue::DateTime(value.into()));
+        self
+    }
+
+    /// Set `DataRequirement.valueFilter.value[x]` to a `Period`.
+    pub fn value_period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.value = Some(DataRequirementValueFilterValue::Period(value.into()));
+        self
+    }
+
+    /// Set `DataRequirement.valueFilter.value[x]` to a `Duration`.
+    pub fn value_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.inner.value = Some(DataRequirementValueFilterValue::Duration(value.into()));
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<DataRequirementValueFilter, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for DataRequirementValueFilter {
+    const PATH: &'static str = "DataRequirement.valueFilter";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.path, &format!("{path}.path"), issues);
+        types::Validate::validate_into(
+            &self.path_element,
+            &format!("{path}._path"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.search_param,
+            &format!("{path}.searchParam"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.search_param_element,
+            &format!("{path}._searchParam"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.comparator,
+            &format!("{path}.comparator"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.comparator_element,
+            &format!("{path}._comparator"),
+            issues,
+        );
+        types::Validate::validate_into(&self.value, &format!("{path}.value"), issues);
+        types::Validate::validate_into(
+            &self.value_element,
+            &format!("{path}._value"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for DataRequirementValueFilter {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept(&self.path_element, &format!("{path}._path"), visitor);
+        types::Walk::accept(&self.search_param, &format!("{path}.searchParam"), visitor);
+        types::Walk::accept(
+            &self.search_param_element,
+            &format!("{path}._searchParam"),
+            visitor,
+        );
+        types::Walk::accept(&self.comparator, &format!("{path}.comparator"), visitor);
+        types::Walk::accept(
+            &self.comparator_element,
+            &format!("{path}._comparator"),
+            visitor,
+        );
+        types::Walk::accept(&self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept(&self.value_element, &format!("{path}._value"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept_mut(
+            &mut self.path_element,
+            &format!("{path}._path"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.search_param,
+            &format!("{path}.searchParam"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.search_param_element,
+            &format!("{path}._searchParam"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.comparator,
+            &format!("{path}.comparator"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.comparator_element,
+            &format!("{path}._comparator"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept_mut(
+            &mut self.value_element,
+            &format!("{path}._value"),
+            visitor,
+        );
+    }
+}
+
+impl DataRequirementValueFilter {
+    /// The metadata of each child element of `DataRequirement.valueFilter`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "path",
+            json_key: "path",
+            rust_field: "path",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "searchParam",
+            json_key: "searchParam",
+            rust_field: "search_param",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "comparator",
+            json_key: "comparator",
+            rust_field: "comparator",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/value-filter-comparator|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value[x]",
+            json_key: "value",
+            rust_field: "value",
+            min: 0,
+            max: Some(1),
+            types: &["dateTime", "Period", "Duration"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for DataRequirementValueFilter {
+    fn type_name(&self) -> &'static str {
+        "DataRequirement.valueFilter"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "path" => types::AsValueRef::as_value_ref(&self.path),
+            "searchParam" => types::AsValueRef::as_value_ref(&self.search_param),
+            "comparator" => types::AsValueRef::as_value_ref(&self.comparator),
+            "value[x]" | "value" => types::AsValueRef::as_value_ref(&self.value),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for DataRequirementValueFilter {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for DataRequirementValueFilter {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
 
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Order of the results
+///
+/// Specifies the order of the results to be returned.
+///
+/// Comment: This element can be used in combination with the sort element to specify quota requirements such as "the most recent 5" or "the highest 5". When multiple sorts are specified, they are applied in the order they appear in the resource.
+///
+/// Requirements: Enables the requirement "most recent 5 results" to be expressed.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct DataRequirementSort {
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// The name of the attribute to perform the sort
-    path: ? // string
+    ///
+    /// The attribute of the sort. The specified path must be resolvable from the type of the required data. The path is allowed to contain qualifiers (.) to traverse sub-elements, as well as indexers (\[x\]) to traverse multiple-cardinality sub-elements. Note that the index must be an integer constant.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub path: types::String,
+
+    /// Id and extensions of `path`
+    #[serde(rename = "_path")]
+    pub path_element: Option<types::Element>,
 
     /// ascending | descending
-    direction: ? // code
+    ///
+    /// The direction of the sort, ascending or descending.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/sort-direction|5.0.0`](http://hl7.org/fhir/ValueSet/sort-direction)
+    ///
+    /// The possible sort directions, ascending or descending.
+    pub direction: types::Code,
+
+    /// Id and extensions of `direction`
+    #[serde(rename = "_direction")]
+    pub direction_element: Option<types::Element>,
+}
+
+impl DataRequirementSort {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(path: types::String, direction: types::Code) -> Self {
+        Self {
+            path,
+            direction,
+            ..Default::default()
+        }
+    }
+}
+
+impl DataRequirementSort {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> DataRequirementSortBuilder {
+        DataRequirementSortBuilder::default()
+    }
+}
+
+/// Build one `DataRequirementSort`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct DataRequirementSortBuilder {
+    inner: DataRequirementSort,
+    path: Option<types::String>,
+    direction: Option<types::Code>,
+}
+
+impl DataRequirementSortBuilder {
+    /// Set `DataRequirement.sort.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `DataRequirement.sort.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `DataRequirement.sort.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.sort.path`.
+    pub fn path(mut self, value: impl Into<types::String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Set `DataRequirement.sort.direction`.
+    pub fn direction(mut self, value: impl Into<types::Code>) -> Self {
+        self.direction = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<DataRequirementSort, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.path {
+            Some(value) => inner.path = value,
+            None => missing.push("DataRequirement.sort.path"),
+        }
+        match self.direction {
+            Some(value) => inner.direction = value,
+            None => missing.push("DataRequirement.sort.direction"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for DataRequirementSort {
+    const PATH: &'static str = "DataRequirement.sort";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.path, &format!("{path}.path"), issues);
+        types::Validate::validate_into(
+            &self.path_element,
+            &format!("{path}._path"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.direction,
+            &format!("{path}.direction"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.direction_element,
+            &format!("{path}._direction"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for DataRequirementSort {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept(&self.path_element, &format!("{path}._path"), visitor);
+        types::Walk::accept(&self.direction, &format!("{path}.direction"), visitor);
+        types::Walk::accept(
+            &self.direction_element,
+            &format!("{path}._direction"),
+            visitor,
+        );
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept_mut(
+            &mut self.path_element,
+            &format!("{path}._path"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.direction,
+            &format!("{path}.direction"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.direction_element,
+            &format!("{path}._direction"),
+            visitor,
+        );
+    }
+}
+
+impl DataRequirementSort {
+    /// The metadata of each child element of `DataRequirement.sort`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "path",
+            json_key: "path",
+            rust_field: "path",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "direction",
+            json_key: "direction",
+            rust_field: "direction",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/sort-direction|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for DataRequirementSort {
+    fn type_name(&self) -> &'static str {
+        "DataRequirement.sort"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "path" => types::AsValueRef::as_value_ref(&self.path),
+            "direction" => types::AsValueRef::as_value_ref(&self.direction),
+            _ => None,
+        }
+    }
+}
 
+impl types::AsValueRef for DataRequirementSort {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for DataRequirementSort {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `DataRequirement.subject[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DataRequirementSubject {
+    #[serde(rename = "subjectCodeableConcept")]
+    CodeableConcept(types::CodeableConcept),
+    #[serde(rename = "subjectReference")]
+    Reference(types::Reference),
+}
+
+impl Default for DataRequirementSubject {
+    fn default() -> Self {
+        Self::CodeableConcept(Default::default())
+    }
+}
+
+impl types::Choice for DataRequirementSubject {
+    const KEYS: &'static [&'static str] = &[
+        "subjectCodeableConcept",
+        "subjectReference",
+    ];
+}
+
+impl types::Validate for DataRequirementSubject {
+    const PATH: &'static str = "DataRequirement.subject";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::CodeableConcept(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    issues,
+                )
+            }
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+        }
+    }
+}
+
+impl types::Walk for DataRequirementSubject {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::CodeableConcept(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableConcept"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::CodeableConcept(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    visitor,
+                )
+            }
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for DataRequirementSubject {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::CodeableConcept(value) => types::AsValueRef::as_value_ref(value),
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Choice of types for `DataRequirement.dateFilter.value[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DataRequirementDateFilterValue {
+    #[serde(rename = "valueDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "valuePeriod")]
+    Period(types::Period),
+    #[serde(rename = "valueDuration")]
+    Duration(types::Duration),
+}
+
+impl Default for DataRequirementDateFilterValue {
+    fn default() -> Self {
+        Self::DateTime(Default::default())
+    }
+}
+
+impl types::Choice for DataRequirementDateFilterValue {
+    const KEYS: &'static [&'static str] = &[
+        "valueDateTime",
+        "valuePeriod",
+        "valueDuration",
+    ];
+}
+
+impl types::Validate for DataRequirementDateFilterValue {
+    const PATH: &'static str = "DataRequirement.dateFilter.value";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Period(value) => {
+                types::Validate::validate_into(value, &format!("{path}Period"), issues)
+            }
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for DataRequirementDateFilterValue {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept(value, &format!("{path}Period"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Period"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for DataRequirementDateFilterValue {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Period(value) => types::AsValueRef::as_value_ref(value),
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `DataRequirement.dateFilter.value[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DataRequirementDateFilterValueElement {
+    #[serde(rename = "_valueDateTime")]
+    DateTime(types::Element),
+}
+
+impl types::Choice for DataRequirementDateFilterValueElement {
+    const KEYS: &'static [&'static str] = &["_valueDateTime"];
+}
+
+impl types::Validate for DataRequirementDateFilterValueElement {
+    const PATH: &'static str = "DataRequirement.dateFilter.value";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for DataRequirementDateFilterValueElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+        }
+    }
+}
+
+/// Choice of types for `DataRequirement.valueFilter.value[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DataRequirementValueFilterValue {
+    #[serde(rename = "valueDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "valuePeriod")]
+    Period(types::Period),
+    #[serde(rename = "valueDuration")]
+    Duration(types::Duration),
+}
+
+impl Default for DataRequirementValueFilterValue {
+    fn default() -> Self {
+        Self::DateTime(Default::default())
+    }
+}
+
+impl types::Choice for DataRequirementValueFilterValue {
+    const KEYS: &'static [&'static str] = &[
+        "valueDateTime",
+        "valuePeriod",
+        "valueDuration",
+    ];
+}
+
+impl types::Validate for DataRequirementValueFilterValue {
+    const PATH: &'static str = "DataRequirement.valueFilter.value";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Period(value) => {
+                types::Validate::validate_into(value, &format!("{path}Period"), issues)
+            }
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for DataRequirementValueFilterValue {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept(value, &format!("{path}Period"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Period"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for DataRequirementValueFilterValue {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Period(value) => types::AsValueRef::as_value_ref(value),
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `DataRequirement.valueFilter.value[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DataRequirementValueFilterValueElement {
+    #[serde(rename = "_valueDateTime")]
+    DateTime(types::Element),
+}
+
+impl types::Choice for DataRequirementValueFilterValueElement {
+    const KEYS: &'static [&'static str] = &["_valueDateTime"];
+}
+
+impl types::Validate for DataRequirementValueFilterValueElement {
+    const PATH: &'static str = "DataRequirement.valueFilter.value";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for DataRequirementValueFilterValueElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = DataRequirement;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["type"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
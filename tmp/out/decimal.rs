@@ -1,6 +1,6 @@
 //! Decimal
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/decimal
+//! URL: <http://hl7.org/fhir/StructureDefinition/decimal>
 //!
 //! Version: 5.0.0
 //!
@@ -10,60 +10,134 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
-#[serde_with::skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+/// Wrap the value of the FHIR `decimal` primitive type.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Serialize as the bare JSON value, without a wrapping object.
+#[serde(transparent)]
 pub struct Decimal {
-    /// Primitive Type decimal
-    decimal: ? // ?
+    /// The exact digits, such as `1.50`, with the serde_json
+    /// `arbitrary_precision` feature.
+    pub value: ::serde_json::Number,
+}
+
+impl Decimal {
+    /// Create with the value.
+    pub fn new(value: ::serde_json::Number) -> Self {
+        Self { value }
+    }
+}
 
-    /// xml:id (or equivalent in JSON)
-    id: ? // http://hl7.org/fhirpath/System.String
+impl From<::serde_json::Number> for Decimal {
+    fn from(value: ::serde_json::Number) -> Self {
+        Self { value }
+    }
+}
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+impl From<i32> for Decimal {
+    fn from(value: i32) -> Self {
+        Self { value: value.into() }
+    }
+}
 
-    /// Primitive value for decimal
-    value: ? // http://hl7.org/fhirpath/System.Decimal
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Self { value: value.into() }
+    }
+}
 
+impl From<u32> for Decimal {
+    fn from(value: u32) -> Self {
+        Self { value: value.into() }
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(value: u64) -> Self {
+        Self { value: value.into() }
+    }
+}
+
+impl Default for Decimal {
+    fn default() -> Self {
+        Self::new(::serde_json::Number::from(0))
+    }
+}
+
+impl types::Validate for Decimal {
+    const PATH: &'static str = "decimal";
+
+    fn validate_into(&self, _path: &str, _issues: &mut Vec<types::ValidationIssue>) {}
+}
+
+impl types::Walk for Decimal {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_decimal(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_decimal_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, _path: &str, _visitor: &mut V) {}
+}
+
+impl Decimal {
+    /// The metadata of each child element of `decimal`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[];
+}
+
+impl types::Reflect for Decimal {
+    fn type_name(&self) -> &'static str {
+        "decimal"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {
+        None
+    }
+}
+
+impl types::AsValueRef for Decimal {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        types::AsValueRef::as_value_ref(&self.value)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Decimal;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        assert_eq!(actual, ::serde_json::json!(0));
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! Dosage
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Dosage
+//! URL: <http://hl7.org/fhir/StructureDefinition/Dosage>
 //!
 //! Version: 5.0.0
 //!
@@ -10,117 +10,1480 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// How the medication is/was taken or should be taken
+///
+/// Indicates how the medication is/was taken or should be taken by the patient.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `dos-1`: AsNeededFor can only be set if AsNeeded is empty or true
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Dosage {
-    /// How the medication is/was taken or should be taken
-    Dosage: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Extensions that cannot be ignored even if unrecognized
-    modifierExtension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element and that modifies the understanding of the element in which it is contained and/or the understanding of the containing element's descendants. Usually modifier elements provide negation or qualification. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension. Applications processing a resource are required to check for modifier extensions.
+    ///
+    /// Modifier extensions SHALL NOT change the meaning of any elements on Resource or DomainResource (including cannot change the meaning of modifierExtension itself).
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Requirements: Modifier extensions allow for extensions that *cannot* be safely ignored to be clearly distinguished from the vast majority of extensions which can be safely ignored.  This promotes interoperability by eliminating the need for implementers to prohibit the presence of extensions. For further information, see the [definition of modifier extensions](https://build.fhir.org/extensibility.html#modifierExtension).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    ///
+    /// Modifier: Modifier extensions are expected to modify the meaning or interpretation of the element that contains them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modifier_extension: Vec<types::Extension>,
 
     /// The order of the dosage instructions
-    sequence: ? // integer
+    ///
+    /// Indicates the order in which the dosage instructions should be applied or interpreted.
+    ///
+    /// Requirements: If the sequence number of multiple Dosages is the same, then it is implied that the instructions are to be treated as concurrent.  If the sequence number is different, then the Dosages are intended to be sequential.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `integer`
+    pub sequence: Option<types::Integer>,
+
+    /// Id and extensions of `sequence`
+    #[serde(rename = "_sequence")]
+    pub sequence_element: Option<types::Element>,
 
     /// Free text dosage instructions e.g. SIG
-    text: ? // string
+    ///
+    /// Free text dosage instructions e.g. SIG.
+    ///
+    /// Requirements: Free text dosage instructions can be used for cases where the instructions are too complex to code.  The content of this attribute does not include the name or description of the medication. When coded instructions are present, the free text instructions may still be present for display to humans taking or administering the medication. It is expected that the text instructions will always be populated.  If the dosage.timing attribute is also populated, then the dosage.text should reflect the same information as the timing.  Additional information about administration or preparation of the medication should be included as text.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub text: Option<types::String>,
+
+    /// Id and extensions of `text`
+    #[serde(rename = "_text")]
+    pub text_element: Option<types::Element>,
 
     /// Supplemental instruction or warnings to the patient - e.g. "with meals", "may cause drowsiness"
-    additionalInstruction: ? // CodeableConcept
+    ///
+    /// Supplemental instructions to the patient on how to take the medication  (e.g. "with meals" or"take half to one hour before food") or warnings for the patient about the medication (e.g. "may cause drowsiness" or "avoid exposure of skin to direct sunlight or sunlamps").
+    ///
+    /// Comment: Information about administration or preparation of the medication (e.g. "infuse as rapidly as possibly via intraperitoneal port" or take "immediately following drug x") should be populated in dosage.text.
+    ///
+    /// Requirements: Additional instruction is intended to be coded, but where no code exists, the element could include text.  For example, "Swallow with plenty of water" which might or might not be coded.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/additional-instruction-codes`](http://hl7.org/fhir/ValueSet/additional-instruction-codes)
+    ///
+    /// A coded concept identifying additional instructions such as "take with water" or "avoid operating heavy machinery".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_instruction: Vec<types::CodeableConcept>,
 
     /// Patient or consumer oriented instructions
-    patientInstruction: ? // string
+    ///
+    /// Instructions in terms that are understood by the patient or consumer.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub patient_instruction: Option<types::String>,
+
+    /// Id and extensions of `patientInstruction`
+    #[serde(rename = "_patientInstruction")]
+    pub patient_instruction_element: Option<types::Element>,
 
     /// When medication should be administered
-    timing: ? // Timing
+    ///
+    /// When medication should be administered.
+    ///
+    /// Comment: This attribute might not always be populated while the Dosage.text is expected to be populated.  If both are populated, then the Dosage.text should reflect the content of the Dosage.timing.
+    ///
+    /// Requirements: The timing schedule for giving the medication to the patient. This  data type allows many different expressions. For example: "Every 8 hours"; "Three times a day"; "1/2 an hour before breakfast for 10 days from 23-Dec 2011:"; "15 Oct 2013, 17 Oct 2013 and 1 Nov 2013".  Sometimes, a rate can imply duration when expressed as total volume / duration (e.g.  500mL/2 hours implies a duration of 2 hours).  However, when rate doesn't imply duration (e.g. 250mL/hour), then the timing.repeat.duration is needed to convey the infuse over time period.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Timing`
+    pub timing: Option<types::Timing>,
 
     /// Take "as needed"
-    asNeeded: ? // boolean
+    ///
+    /// Indicates whether the Medication is only taken when needed within a specific dosing schedule (Boolean option).
+    ///
+    /// Comment: Can express "as needed" without a reason by setting the Boolean = True.  In this case the CodeableConcept is not populated.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub as_needed: Option<types::Boolean>,
+
+    /// Id and extensions of `asNeeded`
+    #[serde(rename = "_asNeeded")]
+    pub as_needed_element: Option<types::Element>,
 
     /// Take "as needed" (for x)
-    asNeededFor: ? // CodeableConcept
+    ///
+    /// Indicates whether the Medication is only taken based on a precondition for taking the Medication (CodeableConcept).
+    ///
+    /// Comment: Can express "as needed" with a reason by including the CodeableConcept.  In this case the Boolean is assumed to be False, then the dose is given according to the schedule and is not "prn" or "as needed".
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/medication-as-needed-reason`](http://hl7.org/fhir/ValueSet/medication-as-needed-reason)
+    ///
+    /// A coded concept identifying the precondition that should be met or evaluated prior to consuming or administering a medication dose.  For example "pain", "30 minutes prior to sexual intercourse", "on flare-up" etc.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub as_needed_for: Vec<types::CodeableConcept>,
 
     /// Body site to administer to
-    site: ? // CodeableConcept
+    ///
+    /// Body site to administer to.
+    ///
+    /// Comment: If the use case requires attributes from the BodySite resource (e.g. to identify and track separately) then use the standard extension [http://hl7.org/fhir/StructureDefinition/bodySite](http://hl7.org/fhir/extensions/StructureDefinition-bodySite.html).  May be a summary code, or a reference to a very precise definition of the location, or both.
+    ///
+    /// Requirements: A coded specification of the anatomic site where the medication first enters the body.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/approach-site-codes`](http://hl7.org/fhir/ValueSet/approach-site-codes)
+    ///
+    /// A coded concept describing the site location the medicine enters into or onto the body.
+    pub site: Option<types::CodeableConcept>,
 
     /// How drug should enter body
-    route: ? // CodeableConcept
+    ///
+    /// How drug should enter body.
+    ///
+    /// Requirements: A code specifying the route or physiological path of administration of a therapeutic agent into or onto a patient's body.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/route-codes`](http://hl7.org/fhir/ValueSet/route-codes)
+    ///
+    /// A coded concept describing the route or physiological path of administration of a therapeutic agent into or onto the body of a subject.
+    pub route: Option<types::CodeableConcept>,
 
     /// Technique for administering medication
-    method: ? // CodeableConcept
+    ///
+    /// Technique for administering medication.
+    ///
+    /// Comment: Terminologies used often pre-coordinate this term with the route and or form of administration.
+    ///
+    /// Requirements: A coded value indicating the method by which the medication is introduced into or onto the body. Most commonly used for injections.  For examples, Slow Push; Deep IV.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/administration-method-codes`](http://hl7.org/fhir/ValueSet/administration-method-codes)
+    ///
+    /// A coded concept describing the technique by which the medicine is administered.
+    pub method: Option<types::CodeableConcept>,
 
     /// Amount of medication administered, to be administered or typical amount to be administered
-    doseAndRate: ? // Element
+    ///
+    /// Depending on the resource,this is the amount of medication administered, to  be administered or typical amount to be administered.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dose_and_rate: Vec<DosageDoseAndRate>,
+
+    /// Upper limit on medication per unit of time
+    ///
+    /// Upper limit on medication per unit of time.
+    ///
+    /// Comment: This is intended for use as an adjunct to the dosage when there is an upper cap.  For example "2 tablets every 4 hours to a maximum of 8/day".
+    ///
+    /// Requirements: The maximum total quantity of a therapeutic substance that may be administered to a subject over the period of time.  For example, 1000mg in 24 hours.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Ratio`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub max_dose_per_period: Vec<types::Ratio>,
+
+    /// Upper limit on medication per administration
+    ///
+    /// Upper limit on medication per administration.
+    ///
+    /// Comment: This is intended for use as an adjunct to the dosage when there is an upper cap.  For example, a body surface area related dose with a maximum amount, such as 1.5 mg/m2 (maximum 2 mg) IV over 5 – 10 minutes would have doseQuantity of 1.5 mg/m2 and maxDosePerAdministration of 2 mg.
+    ///
+    /// Requirements: The maximum total quantity of a therapeutic substance that may be administered to a subject per administration.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Quantity`
+    pub max_dose_per_administration: Option<types::Quantity>,
+
+    /// Upper limit on medication per lifetime of the patient
+    ///
+    /// Upper limit on medication per lifetime of the patient.
+    ///
+    /// Requirements: The maximum total quantity of a therapeutic substance that may be administered per lifetime of the subject.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Quantity`
+    pub max_dose_per_lifetime: Option<types::Quantity>,
+}
+
+impl Dosage {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Dosage {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> DosageBuilder {
+        DosageBuilder::default()
+    }
+}
+
+/// Build one `Dosage`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct DosageBuilder {
+    inner: Dosage,
+}
+
+impl DosageBuilder {
+    /// Set `Dosage.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Dosage.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Dosage.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `Dosage.modifierExtension`.
+    pub fn modifier_extension(
+        mut self,
+        value: impl Into<Vec<types::Extension>>,
+    ) -> Self {
+        self.inner.modifier_extension = value.into();
+        self
+    }
+
+    /// Add one `Dosage.modifierExtension`.
+    pub fn push_modifier_extension(
+        mut self,
+        value: impl Into<types::Extension>,
+    ) -> Self {
+        self.inner.modifier_extension.push(value.into());
+        self
+    }
+
+    /// Set `Dosage.sequence`.
+    pub fn sequence(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.sequence = Some(value.into());
+        self
+    }
+
+    /// Set `Dosage.text`.
+    pub fn text(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.text = Some(value.into());
+        self
+    }
+
+    /// Set every `Dosage.additionalInstruction`.
+    pub fn additional_instruction(
+        mut self,
+        value: impl Into<Vec<types::CodeableConcept>>,
+    ) -> Self {
+        self.inner.additional_instruction = value.into();
+        self
+    }
+
+    /// Add one `Dosage.additionalInstruction`.
+    pub fn push_additional_instruction(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.inner.additional_instruction.push(value.into());
+        self
+    }
+
+    /// Set `Dosage.patientInstruction`.
+    pub fn patient_instruction(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.patient_instruction = Some(value.into());
+        self
+    }
+
+    /// Set `Dosage.timing`.
+    pub fn timing(mut self, value: impl Into<types::Timing>) -> Self {
+        self.inner.timing = Some(value.into());
+        self
+    }
+
+    /// Set `Dosage.asNeeded`.
+    pub fn as_needed(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.as_needed = Some(value.into());
+        self
+    }
+
+    /// Set every `Dosage.asNeededFor`.
+    pub fn as_needed_for(
+        mut self,
+        value: impl Into<Vec<types::CodeableConcept>>,
+    ) -> Self {
+        self.inner.as_needed_for = value.into();
+        self
+    }
+
+    /// Add one `Dosage.asNeededFor`.
+    pub fn push_as_needed_for(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.inner.as_needed_for.push(value.into());
+        self
+    }
+
+    /// Set `Dosage.site`.
+    pub fn site(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.site = Some(value.into());
+        self
+    }
+
+    /// Set `Dosage.route`.
+    pub fn route(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.route = Some(value.into());
+        self
+    }
 
+    /// Set `Dosage.method`.
+    pub fn method(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.method = Some(value.into());
+        self
+    }
+
+    /// Set every `Dosage.doseAndRate`.
+    pub fn dose_and_rate(mut self, value: impl Into<Vec<DosageDoseAndRate>>) -> Self {
+        self.inner.dose_and_rate = value.into();
+        self
+    }
+
+    /// Add one `Dosage.doseAndRate`.
+    pub fn push_dose_and_rate(mut self, value: impl Into<DosageDoseAndRate>) -> Self {
+        self.inner.dose_and_rate.push(value.into());
+        self
+    }
+
+    /// Set every `Dosage.maxDosePerPeriod`.
+    pub fn max_dose_per_period(mut self, value: impl Into<Vec<types::Ratio>>) -> Self {
+        self.inner.max_dose_per_period = value.into();
+        self
+    }
+
+    /// Add one `Dosage.maxDosePerPeriod`.
+    pub fn push_max_dose_per_period(mut self, value: impl Into<types::Ratio>) -> Self {
+        self.inner.max_dose_per_period.push(value.into());
+        self
+    }
+
+    /// Set `Dosage.maxDosePerAdministration`.
+    pub fn max_dose_per_administration(
+        mut self,
+        value: impl Into<types::Quantity>,
+    ) -> Self {
+        self.inner.max_dose_per_administration = Some(value.into());
+        self
+    }
+
+    /// Set `Dosage.maxDosePerLifetime`.
+    pub fn max_dose_per_lifetime(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.max_dose_per_lifetime = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Dosage, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Dosage {
+    const PATH: &'static str = "Dosage";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.sequence,
+            &format!("{path}.sequence"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.sequence_element,
+            &format!("{path}._sequence"),
+            issues,
+        );
+        types::Validate::validate_into(&self.text, &format!("{path}.text"), issues);
+        types::Validate::validate_into(
+            &self.text_element,
+            &format!("{path}._text"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.additional_instruction,
+            &format!("{path}.additionalInstruction"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.patient_instruction,
+            &format!("{path}.patientInstruction"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.patient_instruction_element,
+            &format!("{path}._patientInstruction"),
+            issues,
+        );
+        types::Validate::validate_into(&self.timing, &format!("{path}.timing"), issues);
+        types::Validate::validate_into(
+            &self.as_needed,
+            &format!("{path}.asNeeded"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.as_needed_element,
+            &format!("{path}._asNeeded"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.as_needed_for,
+            &format!("{path}.asNeededFor"),
+            issues,
+        );
+        types::Validate::validate_into(&self.site, &format!("{path}.site"), issues);
+        types::Validate::validate_into(&self.route, &format!("{path}.route"), issues);
+        types::Validate::validate_into(&self.method, &format!("{path}.method"), issues);
+        types::Validate::validate_into(
+            &self.dose_and_rate,
+            &format!("{path}.doseAndRate"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_dose_per_period,
+            &format!("{path}.maxDosePerPeriod"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_dose_per_administration,
+            &format!("{path}.maxDosePerAdministration"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_dose_per_lifetime,
+            &format!("{path}.maxDosePerLifetime"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Dosage {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_dosage(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept(&self.sequence, &format!("{path}.sequence"), visitor);
+        types::Walk::accept(
+            &self.sequence_element,
+            &format!("{path}._sequence"),
+            visitor,
+        );
+        types::Walk::accept(&self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept(&self.text_element, &format!("{path}._text"), visitor);
+        types::Walk::accept(
+            &self.additional_instruction,
+            &format!("{path}.additionalInstruction"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.patient_instruction,
+            &format!("{path}.patientInstruction"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.patient_instruction_element,
+            &format!("{path}._patientInstruction"),
+            visitor,
+        );
+        types::Walk::accept(&self.timing, &format!("{path}.timing"), visitor);
+        types::Walk::accept(&self.as_needed, &format!("{path}.asNeeded"), visitor);
+        types::Walk::accept(
+            &self.as_needed_element,
+            &format!("{path}._asNeeded"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.as_needed_for,
+            &format!("{path}.asNeededFor"),
+            visitor,
+        );
+        types::Walk::accept(&self.site, &format!("{path}.site"), visitor);
+        types::Walk::accept(&self.route, &format!("{path}.route"), visitor);
+        types::Walk::accept(&self.method, &format!("{path}.method"), visitor);
+        types::Walk::accept(
+            &self.dose_and_rate,
+            &format!("{path}.doseAndRate"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.max_dose_per_period,
+            &format!("{path}.maxDosePerPeriod"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.max_dose_per_administration,
+            &format!("{path}.maxDosePerAdministration"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.max_dose_per_lifetime,
+            &format!("{path}.maxDosePerLifetime"),
+            visitor,
+        );
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_dosage_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.sequence,
+            &format!("{path}.sequence"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.sequence_element,
+            &format!("{path}._sequence"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept_mut(
+            &mut self.text_element,
+            &format!("{path}._text"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.additional_instruction,
+            &format!("{path}.additionalInstruction"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.patient_instruction,
+            &format!("{path}.patientInstruction"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.patient_instruction_element,
+            &format!("{path}._patientInstruction"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.timing, &format!("{path}.timing"), visitor);
+        types::Walk::accept_mut(
+            &mut self.as_needed,
+            &format!("{path}.asNeeded"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.as_needed_element,
+            &format!("{path}._asNeeded"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.as_needed_for,
+            &format!("{path}.asNeededFor"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.site, &format!("{path}.site"), visitor);
+        types::Walk::accept_mut(&mut self.route, &format!("{path}.route"), visitor);
+        types::Walk::accept_mut(&mut self.method, &format!("{path}.method"), visitor);
+        types::Walk::accept_mut(
+            &mut self.dose_and_rate,
+            &format!("{path}.doseAndRate"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_dose_per_period,
+            &format!("{path}.maxDosePerPeriod"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_dose_per_administration,
+            &format!("{path}.maxDosePerAdministration"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_dose_per_lifetime,
+            &format!("{path}.maxDosePerLifetime"),
+            visitor,
+        );
+    }
+}
+
+impl Dosage {
+    /// The metadata of each child element of `Dosage`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "modifierExtension",
+            json_key: "modifierExtension",
+            rust_field: "modifier_extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "sequence",
+            json_key: "sequence",
+            rust_field: "sequence",
+            min: 0,
+            max: Some(1),
+            types: &["integer"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "text",
+            json_key: "text",
+            rust_field: "text",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "additionalInstruction",
+            json_key: "additionalInstruction",
+            rust_field: "additional_instruction",
+            min: 0,
+            max: None,
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/additional-instruction-codes",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "patientInstruction",
+            json_key: "patientInstruction",
+            rust_field: "patient_instruction",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "timing",
+            json_key: "timing",
+            rust_field: "timing",
+            min: 0,
+            max: Some(1),
+            types: &["Timing"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "asNeeded",
+            json_key: "asNeeded",
+            rust_field: "as_needed",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "asNeededFor",
+            json_key: "asNeededFor",
+            rust_field: "as_needed_for",
+            min: 0,
+            max: None,
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/medication-as-needed-reason",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "site",
+            json_key: "site",
+            rust_field: "site",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some("http://hl7.org/fhir/ValueSet/approach-site-codes"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "route",
+            json_key: "route",
+            rust_field: "route",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some("http://hl7.org/fhir/ValueSet/route-codes"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "method",
+            json_key: "method",
+            rust_field: "method",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/administration-method-codes",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "doseAndRate",
+            json_key: "doseAndRate",
+            rust_field: "dose_and_rate",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "maxDosePerPeriod",
+            json_key: "maxDosePerPeriod",
+            rust_field: "max_dose_per_period",
+            min: 0,
+            max: None,
+            types: &["Ratio"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "maxDosePerAdministration",
+            json_key: "maxDosePerAdministration",
+            rust_field: "max_dose_per_administration",
+            min: 0,
+            max: Some(1),
+            types: &["Quantity"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "maxDosePerLifetime",
+            json_key: "maxDosePerLifetime",
+            rust_field: "max_dose_per_lifetime",
+            min: 0,
+            max: Some(1),
+            types: &["Quantity"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Dosage {
+    fn type_name(&self) -> &'static str {
+        "Dosage"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "modifierExtension" => {
+                types::AsValueRef::as_value_ref(&self.modifier_extension)
+            }
+            "sequence" => types::AsValueRef::as_value_ref(&self.sequence),
+            "text" => types::AsValueRef::as_value_ref(&self.text),
+            "additionalInstruction" => {
+                types::AsValueRef::as_value_ref(&self.additional_instruction)
+            }
+            "patientInstruction" => {
+                types::AsValueRef::as_value_ref(&self.patient_instruction)
+            }
+            "timing" => types::AsValueRef::as_value_ref(&self.timing),
+            "asNeeded" => types::AsValueRef::as_value_ref(&self.as_needed),
+            "asNeededFor" => types::AsValueRef::as_value_ref(&self.as_needed_for),
+            "site" => types::AsValueRef::as_value_ref(&self.site),
+            "route" => types::AsValueRef::as_value_ref(&self.route),
+            "method" => types::AsValueRef::as_value_ref(&self.method),
+            "doseAndRate" => types::AsValueRef::as_value_ref(&self.dose_and_rate),
+            "maxDosePerPeriod" => {
+                types::AsValueRef::as_value_ref(&self.max_dose_per_period)
+            }
+            "maxDosePerAdministration" => {
+                types::AsValueRef::as_value_ref(&self.max_dose_per_administration)
+            }
+            "maxDosePerLifetime" => {
+                types::AsValueRef::as_value_ref(&self.max_dose_per_lifetime)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Dosage {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Dosage {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Amount of medication administered, to be administered or typical amount to be administered
+///
+/// Depending on the resource,this is the amount of medication administered, to  be administered or typical amount to be administered.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+pub struct DosageDoseAndRate {
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// The kind of dose or rate specified
-    type: ? // CodeableConcept
+    ///
+    /// The kind of dose or rate specified, for example, ordered or calculated.
+    ///
+    /// Requirements: If the type is not populated, assume to be "ordered".
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: example to [`http://terminology.hl7.org/ValueSet/dose-rate-type`](http://terminology.hl7.org/ValueSet/dose-rate-type)
+    ///
+    /// The kind of dose or rate specified.
+    pub r#type: Option<types::CodeableConcept>,
 
     /// Amount of medication per dose
-    : ? // Range
+    ///
+    /// Amount of medication per dose.
+    ///
+    /// Comment: Note that this specifies the quantity of the specified medication, not the quantity for each active ingredient(s). Each ingredient amount can be communicated in the Medication resource. For example, if one wants to communicate that a tablet was 375 mg, where the dose was one tablet, you can use the Medication resource to document that the tablet was comprised of 375 mg of drug XYZ. Alternatively if the dose was 375 mg, then you may only need to use the Medication resource to indicate this was a tablet. If the example were an IV such as dopamine and you wanted to communicate that 400mg of dopamine was mixed in 500 ml of some IV solution, then this would all be communicated in the Medication resource. If the administration is not intended to be instantaneous (rate is present or timing has a duration), this can be specified to convey the total amount to be administered over the period of time as indicated by the schedule e.g. 500 ml in dose, with timing used to convey that this should be done over 4 hours.
+    ///
+    /// Requirements: The amount of therapeutic or other substance given at one administration event.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Range`, `Quantity`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub dose: Option<DosageDoseAndRateDose>,
 
     /// Amount of medication per unit of time
-    : ? // Ratio
+    ///
+    /// Amount of medication per unit of time.
+    ///
+    /// Comment: It is possible to supply both a rate and a doseQuantity to provide full details about how the medication is to be administered and supplied. If the rate is intended to change over time, depending on local rules/regulations, each change should be captured as a new version of the MedicationRequest with an updated rate, or captured with a new MedicationRequest with the new rate.
+    ///
+    /// It is possible to specify a rate over time (for example, 100 ml/hour) using either the rateRatio and rateQuantity.  The rateQuantity approach requires systems to have the capability to parse UCUM grammar where ml/hour is included rather than a specific ratio where the time is specified as the denominator.  Where a rate such as 500ml over 2 hours is specified, the use of rateRatio may be more semantically correct than specifying using a rateQuantity of 250 mg/hour.
+    ///
+    /// Requirements: Identifies the speed with which the medication was or will be introduced into the patient. Typically the rate for an infusion e.g. 100 ml per 1 hour or 100 ml/hr.  May also be expressed as a rate per unit of time e.g. 500 ml per 2 hours.   Other examples: 200 mcg/min or 200 mcg/1 minute; 1 liter/8 hours.  Sometimes, a rate can imply duration when expressed as total volume / duration (e.g.  500mL/2 hours implies a duration of 2 hours).  However, when rate doesn't imply duration (e.g. 250mL/hour), then the timing.repeat.duration is needed to convey the infuse over time period.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Ratio`, `Range`, `Quantity`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub rate: Option<DosageDoseAndRateRate>,
 
-    /// Upper limit on medication per unit of time
-    maxDosePerPeriod: ? // Ratio
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
 
-    /// Upper limit on medication per administration
-    maxDosePerAdministration: ? // Quantity
+impl DosageDoseAndRate {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
-    /// Upper limit on medication per lifetime of the patient
-    maxDosePerLifetime: ? // Quantity
+impl DosageDoseAndRate {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> DosageDoseAndRateBuilder {
+        DosageDoseAndRateBuilder::default()
+    }
+}
+
+/// Build one `DosageDoseAndRate`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct DosageDoseAndRateBuilder {
+    inner: DosageDoseAndRate,
+}
+
+impl DosageDoseAndRateBuilder {
+    /// Set `Dosage.doseAndRate.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Dosage.doseAndRate.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Dosage.doseAndRate.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
 
+    /// Set `Dosage.doseAndRate.type`.
+    pub fn r#type(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `Dosage.doseAndRate.dose[x]` to a `Range`.
+    pub fn dose_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.inner.dose = Some(DosageDoseAndRateDose::Range(value.into()));
+        self
+    }
+
+    /// Set `Dosage.doseAndRate.dose[x]` to a `Quantity`.
+    pub fn dose_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.dose = Some(DosageDoseAndRateDose::Quantity(value.into()));
+        self
+    }
+
+    /// Set `Dosage.doseAndRate.rate[x]` to a `Ratio`.
+    pub fn rate_ratio(mut self, value: impl Into<types::Ratio>) -> Self {
+        self.inner.rate = Some(DosageDoseAndRateRate::Ratio(value.into()));
+        self
+    }
+
+    /// Set `Dosage.doseAndRate.rate[x]` to a `Range`.
+    pub fn rate_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.inner.rate = Some(DosageDoseAndRateRate::Range(value.into()));
+        self
+    }
+
+    /// Set `Dosage.doseAndRate.rate[x]` to a `Quantity`.
+    pub fn rate_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.rate = Some(DosageDoseAndRateRate::Quantity(value.into()));
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<DosageDoseAndRate, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for DosageDoseAndRate {
+    const PATH: &'static str = "Dosage.doseAndRate";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(&self.dose, &format!("{path}.dose"), issues);
+        types::Validate::validate_into(&self.rate, &format!("{path}.rate"), issues);
+    }
+}
+
+impl types::Walk for DosageDoseAndRate {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.dose, &format!("{path}.dose"), visitor);
+        types::Walk::accept(&self.rate, &format!("{path}.rate"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(&mut self.dose, &format!("{path}.dose"), visitor);
+        types::Walk::accept_mut(&mut self.rate, &format!("{path}.rate"), visitor);
+    }
+}
+
+impl DosageDoseAndRate {
+    /// The metadata of each child element of `Dosage.doseAndRate`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some("http://terminology.hl7.org/ValueSet/dose-rate-type"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "dose[x]",
+            json_key: "dose",
+            rust_field: "dose",
+            min: 0,
+            max: Some(1),
+            types: &["Range", "Quantity"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "rate[x]",
+            json_key: "rate",
+            rust_field: "rate",
+            min: 0,
+            max: Some(1),
+            types: &["Ratio", "Range", "Quantity"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for DosageDoseAndRate {
+    fn type_name(&self) -> &'static str {
+        "Dosage.doseAndRate"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "dose[x]" | "dose" => types::AsValueRef::as_value_ref(&self.dose),
+            "rate[x]" | "rate" => types::AsValueRef::as_value_ref(&self.rate),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for DosageDoseAndRate {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for DosageDoseAndRate {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `Dosage.doseAndRate.dose[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DosageDoseAndRateDose {
+    #[serde(rename = "doseRange")]
+    Range(types::Range),
+    #[serde(rename = "doseQuantity")]
+    Quantity(types::Quantity),
+}
+
+impl Default for DosageDoseAndRateDose {
+    fn default() -> Self {
+        Self::Range(Default::default())
+    }
+}
+
+impl types::Choice for DosageDoseAndRateDose {
+    const KEYS: &'static [&'static str] = &["doseRange", "doseQuantity"];
+}
+
+impl types::Validate for DosageDoseAndRateDose {
+    const PATH: &'static str = "Dosage.doseAndRate.dose";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for DosageDoseAndRateDose {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for DosageDoseAndRateDose {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Choice of types for `Dosage.doseAndRate.rate[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DosageDoseAndRateRate {
+    #[serde(rename = "rateRatio")]
+    Ratio(types::Ratio),
+    #[serde(rename = "rateRange")]
+    Range(types::Range),
+    #[serde(rename = "rateQuantity")]
+    Quantity(types::Quantity),
+}
+
+impl Default for DosageDoseAndRateRate {
+    fn default() -> Self {
+        Self::Ratio(Default::default())
+    }
+}
+
+impl types::Choice for DosageDoseAndRateRate {
+    const KEYS: &'static [&'static str] = &["rateRatio", "rateRange", "rateQuantity"];
+}
+
+impl types::Validate for DosageDoseAndRateRate {
+    const PATH: &'static str = "Dosage.doseAndRate.rate";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Ratio(value) => {
+                types::Validate::validate_into(value, &format!("{path}Ratio"), issues)
+            }
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for DosageDoseAndRateRate {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Ratio(value) => {
+                types::Walk::accept(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Ratio(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for DosageDoseAndRateRate {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Ratio(value) => types::AsValueRef::as_value_ref(value),
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Dosage;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
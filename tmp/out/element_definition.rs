@@ -1,6 +1,6 @@
 //! ElementDefinition
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/ElementDefinition
+//! URL: <http://hl7.org/fhir/StructureDefinition/ElementDefinition>
 //!
 //! Version: 5.0.0
 //!
@@ -10,332 +10,13533 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Definition of an element in a resource or extension
+///
+/// Captures constraints on each element within the resource, profile, or extension.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `eld-2`: Min &lt;= Max
+/// - `eld-5`: if the element definition has a contentReference, it cannot have type, defaultValue, fixed, pattern, example, minValue, maxValue, maxLength, or binding
+/// - `eld-6`: Fixed value may only be specified if there is one type
+/// - `eld-7`: Pattern may only be specified if there is one type
+/// - `eld-8`: Pattern and fixed are mutually exclusive
+/// - `eld-11`: Binding can only be present for coded elements, string, and uri if using FHIR-defined types
+/// - `eld-13`: Types must be unique by code
+/// - `eld-14`: Constraints must be unique by key
+/// - `eld-15`: default value and meaningWhenMissing are mutually exclusive
+/// - `eld-16`: sliceName must be composed of proper tokens separated by "/"
+/// - `eld-18`: Must have a modifier reason if isModifier = true
+/// - `eld-19`: Element path SHALL be expressed as a set of '.'-separated components with each component restricted to a maximum of 64 characters and with some limits on the allowed choice of characters
+/// - `eld-20`: The first component of the path should be UpperCamelCase.  Additional components (following a '.') should be lowerCamelCase.  If this syntax is not adhered to, code generation tools may be broken. Logical models may be less concerned about this implication.
+/// - `eld-22`: sliceIsConstraining can only appear if slicename is present
+/// - `eld-24`: pattern\[x\] should be used rather than fixed\[x\]
+/// - `eld-25`: Order has no meaning (and cannot be asserted to have meaning), so enforcing rules on order is improper
+/// - `eld-27`: Mappings SHOULD be unique by key
+/// - `eld-28`: Can't have valueAlternatives if mustHaveValue is true
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
 pub struct ElementDefinition {
-    /// Definition of an element in a resource or extension
-    ElementDefinition: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Extensions that cannot be ignored even if unrecognized
-    modifierExtension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element and that modifies the understanding of the element in which it is contained and/or the understanding of the containing element's descendants. Usually modifier elements provide negation or qualification. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension. Applications processing a resource are required to check for modifier extensions.
+    ///
+    /// Modifier extensions SHALL NOT change the meaning of any elements on Resource or DomainResource (including cannot change the meaning of modifierExtension itself).
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Requirements: Modifier extensions allow for extensions that *cannot* be safely ignored to be clearly distinguished from the vast majority of extensions which can be safely ignored.  This promotes interoperability by eliminating the need for implementers to prohibit the presence of extensions. For further information, see the [definition of modifier extensions](https://build.fhir.org/extensibility.html#modifierExtension).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    ///
+    /// Modifier: Modifier extensions are expected to modify the meaning or interpretation of the element that contains them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modifier_extension: Vec<types::Extension>,
 
     /// Path of the element in the hierarchy of elements
-    path: ? // string
+    ///
+    /// The path identifies the element and is expressed as a "."-separated list of ancestor elements, beginning with the name of the resource or extension.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub path: types::String,
+
+    /// Id and extensions of `path`
+    #[serde(rename = "_path")]
+    pub path_element: Option<types::Element>,
 
     /// xmlAttr | xmlText | typeAttr | cdaText | xhtml
-    representation: ? // code
+    ///
+    /// Codes that define how this element is represented in instances, when the deviation varies from the normal case. No extensions are allowed on elements with a representation of 'xmlAttr', no matter what FHIR serialization format is used.
+    ///
+    /// Comment: In resources, this is rarely used except for special cases where the representation deviates from the normal, and can only be done in the base standard (and profiles must reproduce what the base standard does). This element is used quite commonly in Logical models when the logical models represent a specific serialization format (e.g. CDA, v2 etc.).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/property-representation|5.0.0`](http://hl7.org/fhir/ValueSet/property-representation)
+    ///
+    /// How a property is represented when serialized.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub representation: Vec<Option<types::Code>>,
+
+    /// Id and extensions of `representation`
+    #[serde(rename = "_representation", default, skip_serializing_if = "Vec::is_empty")]
+    pub representation_element: Vec<Option<types::Element>>,
 
     /// Name for this particular element (in a set of slices)
-    sliceName: ? // string
+    ///
+    /// The name of this element definition slice, when slicing is working. The name must be a token with no dots or spaces. This is a unique name referring to a specific set of constraints applied to this element, used to provide a name to different slices of the same element.
+    ///
+    /// Comment: The name SHALL be unique within the structure within the context of the constrained resource element.  (Though to avoid confusion, uniqueness across all elements is recommended.).
+    ///
+    /// Requirements: May also be used for code generation purposes.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub slice_name: Option<types::String>,
+
+    /// Id and extensions of `sliceName`
+    #[serde(rename = "_sliceName")]
+    pub slice_name_element: Option<types::Element>,
 
     /// If this slice definition constrains an inherited slice definition (or not)
-    sliceIsConstraining: ? // boolean
+    ///
+    /// If true, indicates that this slice definition is constraining a slice definition with the same name in an inherited profile. If false, the slice is not overriding any slice in an inherited profile. If missing, the slice might or might not be overriding a slice in an inherited profile, depending on the sliceName.
+    ///
+    /// Comment: If set to true, an ancestor profile SHALL have a slicing definition with this name.  If set to false, no ancestor profile is permitted to have a slicing definition with this name.
+    ///
+    /// Requirements: Allows detection of a situation where an ancestor profile adds or removes slicing with the same name where that might impact the child profile.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub slice_is_constraining: Option<types::Boolean>,
+
+    /// Id and extensions of `sliceIsConstraining`
+    #[serde(rename = "_sliceIsConstraining")]
+    pub slice_is_constraining_element: Option<types::Element>,
 
     /// Name for element to display with or prompt for element
-    label: ? // string
+    ///
+    /// A single preferred label which is the text to display beside the element indicating its meaning or to use to prompt for the element in a user display or form.
+    ///
+    /// Comment: See also the extension [http://hl7.org/fhir/StructureDefinition/elementdefinition-question](http://hl7.org/fhir/extensions/StructureDefinition-elementdefinition-question.html).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub label: Option<types::String>,
+
+    /// Id and extensions of `label`
+    #[serde(rename = "_label")]
+    pub label_element: Option<types::Element>,
 
     /// Corresponding codes in terminologies
-    code: ? // Coding
+    ///
+    /// A code that has the same meaning as the element in a particular terminology.
+    ///
+    /// Comment: The concept SHALL be properly aligned with the data element definition and other constraints, as defined in the code system, including relationships, of any code listed here.  Where multiple codes exist in a terminology that could correspond to the data element, the most granular code(s) should be selected, so long as they are not more restrictive than the data element itself. The mappings may be used to provide more or less granular or structured equivalences in the code system.
+    ///
+    /// Requirements: Links the meaning of an element to an external terminology, and is very useful for searching and indexing.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Coding`
+    ///
+    /// Binding: example to [`http://loinc.org/vs`](http://loinc.org/vs)
+    ///
+    /// Codes that indicate the meaning of a data element.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub code: Vec<types::Coding>,
 
     /// This element is sliced - slices follow
-    slicing: ? // Element
+    ///
+    /// Indicates that the element is sliced into a set of alternative definitions (i.e. in a structure definition, there are multiple different constraints on a single element in the base resource). Slicing can be used in any resource that has cardinality ..* on the base resource, or any resource with a choice of types. The set of slices is any elements that come after this in the element sequence that have the same path, until a shorter path occurs (the shorter path terminates the set).
+    ///
+    /// Comment: The first element in the sequence, the one that carries the slicing, is the definition that applies to all the slices. This is based on the unconstrained element, but can apply any constraints as appropriate. This may include the common constraints on the children of the element.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Element`
+    pub slicing: Option<ElementDefinitionSlicing>,
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// Concise definition for space-constrained presentation
+    ///
+    /// A concise description of what this element means (e.g. for use in autogenerated summaries).
+    ///
+    /// Comment: It is easy for a different short definition to change the meaning of an element and this can have nasty downstream consequences. Please be careful when providing short definitions in a profile.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub short: Option<types::String>,
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Id and extensions of `short`
+    #[serde(rename = "_short")]
+    pub short_element: Option<types::Element>,
 
-    /// Element values that are used to distinguish the slices
-    discriminator: ? // Element
+    /// Full formal definition as narrative text
+    ///
+    /// Provides a complete explanation of the meaning of the data element for human readability.  For the case of elements derived from existing elements (e.g. constraints), the definition SHALL be consistent with the base definition, but convey the meaning of the element in the particular context of use of the resource. (Note: The text you are reading is specified in ElementDefinition.definition).
+    ///
+    /// Comment: It is easy for a different definition to change the meaning of an element and this can have nasty downstream consequences. Please be careful when providing definitions in a profile.
+    ///
+    /// Requirements: To allow a user to state the usage of an element in a particular context.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub definition: Option<types::Markdown>,
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// Id and extensions of `definition`
+    #[serde(rename = "_definition")]
+    pub definition_element: Option<types::Element>,
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Comments about the use of this element
+    ///
+    /// Explanatory notes and implementation guidance about the data element, including notes about how to use the data properly, exceptions to proper use, etc. (Note: The text you are reading is specified in ElementDefinition.comment).
+    ///
+    /// Comment: If it is possible to capture usage rules using constraints, that mechanism should be used in preference to this element.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub comment: Option<types::Markdown>,
 
-    /// value | exists | type | profile | position
-    type: ? // code
+    /// Id and extensions of `comment`
+    #[serde(rename = "_comment")]
+    pub comment_element: Option<types::Element>,
 
-    /// Path to element value
-    path: ? // string
+    /// Why this resource has been created
+    ///
+    /// This element is for traceability of why the element was created and why the constraints exist as they do. This may be used to point to source materials or specifications that drove the structure of this element.
+    ///
+    /// Comment: This element does not describe the usage of the element (that's done in comments), rather it's for traceability of *why* the element is either needed or why the constraints exist as they do.  This may be used to point to source materials or specifications that drove the structure of this data element.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub requirements: Option<types::Markdown>,
 
-    /// Text description of how slicing works (or not)
-    description: ? // string
+    /// Id and extensions of `requirements`
+    #[serde(rename = "_requirements")]
+    pub requirements_element: Option<types::Element>,
 
-    /// If elements must be in same order as slices
-    ordered: ? // boolean
+    /// Other names
+    ///
+    /// Identifies additional names by which this element might also be known.
+    ///
+    /// Requirements: Allows for better easier recognition of the element by multiple communities, including international communities.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `string`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alias: Vec<Option<types::String>>,
 
-    /// closed | open | openAtEnd
-    rules: ? // code
+    /// Id and extensions of `alias`
+    #[serde(rename = "_alias", default, skip_serializing_if = "Vec::is_empty")]
+    pub alias_element: Vec<Option<types::Element>>,
 
-    /// Concise definition for space-constrained presentation
-    short: ? // string
+    /// Minimum Cardinality
+    ///
+    /// The minimum number of times this element SHALL appear in the instance.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `unsignedInt`
+    pub min: Option<types::UnsignedInt>,
 
-    /// Full formal definition as narrative text
-    definition: ? // markdown
+    /// Id and extensions of `min`
+    #[serde(rename = "_min")]
+    pub min_element: Option<types::Element>,
 
-    /// Comments about the use of this element
-    comment: ? // markdown
+    /// Maximum Cardinality (a number or *)
+    ///
+    /// The maximum number of times this element is permitted to appear in the instance.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    ///
+    /// Constraints:
+    ///
+    /// - `eld-3`: Max SHALL be a number or "*"
+    pub max: Option<types::String>,
 
-    /// Why this resource has been created
-    requirements: ? // markdown
+    /// Id and extensions of `max`
+    #[serde(rename = "_max")]
+    pub max_element: Option<types::Element>,
 
-    /// Other names
-    alias: ? // string
+    /// Base definition information for tools
+    ///
+    /// Information about the base definition of the element, provided to make it unnecessary for tools to trace the deviation of the element through the derived and related profiles. When the element definition is not the original definition of an element - e.g. either in a constraint on another type, or for elements from a super type in a snap shot - then the information in provided in the element definition may be different to the base definition. On the original definition of the element, it will be same.
+    ///
+    /// Comment: The base information does not carry any information that could not be determined from the path and related profiles, but making this determination requires both that the related profiles are available, and that the algorithm to determine them be available. For tooling simplicity, the base information must always be populated in element definitions in snap shots, even if it is the same.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Element`
+    pub base: Option<ElementDefinitionBase>,
 
-    /// Minimum Cardinality
-    min: ? // unsignedInt
+    /// Reference to definition of content for the element
+    ///
+    /// Identifies an element defined elsewhere in the definition whose content rules should be applied to the current element. ContentReferences bring across all the rules that are in the ElementDefinition for the element, including definitions, cardinality constraints, bindings, invariants etc.
+    ///
+    /// Comment: ContentReferences can only be defined in specializations, not constrained types, and they cannot be changed and always reference the non-constrained definition.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `uri`
+    pub content_reference: Option<types::Uri>,
 
-    /// Maximum Cardinality (a number or *)
-    max: ? // string
+    /// Id and extensions of `contentReference`
+    #[serde(rename = "_contentReference")]
+    pub content_reference_element: Option<types::Element>,
 
-    /// Base definition information for tools
-    base: ? // Element
+    /// Data type and Profile for this element
+    ///
+    /// The data type or resource that the value of this element is permitted to be.
+    ///
+    /// Comment: The Type of the element can be left blank in a differential constraint, in which case the type is inherited from the resource. Abstract types are not permitted to appear as a type when multiple types are listed.  (I.e. Abstract types cannot be part of a choice).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    ///
+    /// Constraints:
+    ///
+    /// - `eld-4`: Aggregation may only be specified if one of the allowed types for the element is a reference
+    /// - `eld-17`: targetProfile is only allowed if the type is Reference or canonical
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub r#type: Vec<ElementDefinitionType>,
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// Specified value if missing from instance
+    ///
+    /// The value that should be used if there is no value stated in the instance (e.g. 'if not otherwise specified, the abstract is false').
+    ///
+    /// Comment: Specifying a default value means that the property can never been unknown - it must always have a value. Further, the default value can never be changed, or changed in constraints on content models. Defining default values creates many difficulties in implementation (e.g. when is a value missing?). For these reasons, default values are (and should be) used extremely sparingly.
+    ///
+    /// No default values are ever defined in the FHIR specification, nor can they be defined in constraints ("profiles") on data types or resources. This element only exists so that default values may be defined in logical models.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `base64Binary`, `boolean`, `canonical`, `code`, `date`, `dateTime`, `decimal`, `id`, `instant`, `integer`, `integer64`, `markdown`, `oid`, `positiveInt`, `string`, `time`, `unsignedInt`, `uri`, `url`, `uuid`, `Address`, `Age`, `Annotation`, `Attachment`, `CodeableConcept`, `CodeableReference`, `Coding`, `ContactPoint`, `Count`, `Distance`, `Duration`, `HumanName`, `Identifier`, `Money`, `Period`, `Quantity`, `Range`, `Ratio`, `RatioRange`, `Reference`, `SampledData`, `Signature`, `Timing`, `ContactDetail`, `DataRequirement`, `Expression`, `ParameterDefinition`, `RelatedArtifact`, `TriggerDefinition`, `UsageContext`, `Availability`, `ExtendedContactDetail`, `Dosage`, `Meta`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub default_value: Option<ElementDefinitionDefaultValue>,
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Id and extensions of `defaultValue[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub default_value_element: Option<ElementDefinitionDefaultValueElement>,
 
-    /// Path that identifies the base element
-    path: ? // string
+    /// Implicit meaning when this element is missing
+    ///
+    /// The Implicit meaning that is to be understood when this element is missing (e.g. 'when this element is missing, the period is ongoing').
+    ///
+    /// Comment: Implicit meanings for missing values can only be specified on a resource, data type, or extension definition, and never in a profile that applies to one of these. An implicit meaning for a missing value can never be changed, and specifying one has the consequence that constraining its use in profiles eliminates use cases as possibilities, not merely moving them out of scope.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub meaning_when_missing: Option<types::Markdown>,
 
-    /// Min cardinality of the base element
-    min: ? // unsignedInt
+    /// Id and extensions of `meaningWhenMissing`
+    #[serde(rename = "_meaningWhenMissing")]
+    pub meaning_when_missing_element: Option<types::Element>,
 
-    /// Max cardinality of the base element
-    max: ? // string
+    /// What the order of the elements means
+    ///
+    /// If present, indicates that the order of the repeating element has meaning and describes what that meaning is.  If absent, it means that the order of the element has no meaning.
+    ///
+    /// Comment: This element can only be asserted on repeating elements and can only be introduced when defining resources or data types.  It can be further refined profiled elements but if absent in the base type, a profile cannot assert meaning.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub order_meaning: Option<types::String>,
 
-    /// Reference to definition of content for the element
-    contentReference: ? // uri
+    /// Id and extensions of `orderMeaning`
+    #[serde(rename = "_orderMeaning")]
+    pub order_meaning_element: Option<types::Element>,
 
-    /// Data type and Profile for this element
-    type: ? // Element
+    /// Value must be exactly this
+    ///
+    /// Specifies a value that SHALL be exactly the value  for this element in the instance, if present. For purposes of comparison, non-significant whitespace is ignored, and all values must be an exact match (case and accent sensitive). Missing elements/attributes must also be missing.
+    ///
+    /// Comment: This is not recommended for Coding and CodeableConcept since these often have highly contextual properties such as version or display.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `base64Binary`, `boolean`, `canonical`, `code`, `date`, `dateTime`, `decimal`, `id`, `instant`, `integer`, `integer64`, `markdown`, `oid`, `positiveInt`, `string`, `time`, `unsignedInt`, `uri`, `url`, `uuid`, `Address`, `Age`, `Annotation`, `Attachment`, `CodeableConcept`, `CodeableReference`, `Coding`, `ContactPoint`, `Count`, `Distance`, `Duration`, `HumanName`, `Identifier`, `Money`, `Period`, `Quantity`, `Range`, `Ratio`, `RatioRange`, `Reference`, `SampledData`, `Signature`, `Timing`, `ContactDetail`, `DataRequirement`, `Expression`, `ParameterDefinition`, `RelatedArtifact`, `TriggerDefinition`, `UsageContext`, `Availability`, `ExtendedContactDetail`, `Dosage`, `Meta`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub fixed: Option<ElementDefinitionFixed>,
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// Id and extensions of `fixed[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub fixed_element: Option<ElementDefinitionFixedElement>,
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Value must have at least these property values
+    ///
+    /// Specifies a value that each occurrence of the element in the instance SHALL follow - that is, any value in the pattern must be found in the instance, if the element has a value. Other additional values may be found too. This is effectively constraint by example.
+    ///
+    /// When pattern\[x\] is used to constrain a primitive, it means that the value provided in the pattern\[x\] must match the instance value exactly.
+    ///
+    /// When an element within a pattern\[x\] is used to constrain an array, it means that each element provided in the pattern\[x\] must (recursively) match at least one element from the instance array.
+    ///
+    /// When pattern\[x\] is used to constrain a complex object, it means that each property in the pattern must be present in the complex object, and its value must recursively match -- i.e.,
+    ///
+    /// 1. If primitive: it must match exactly the pattern value
+    /// 2. If a complex object: it must match (recursively) the pattern value
+    /// 3. If an array: it must match (recursively) the pattern value
+    ///
+    /// If a pattern\[x\] is declared on a repeating element, the pattern applies to all repetitions.  If the desire is for a pattern to apply to only one element or a subset of elements, slicing must be used. See [Examples of Patterns](https://build.fhir.org/elementdefinition-examples.html#pattern-examples) for examples of pattern usage and the effect it will have.
+    ///
+    /// Comment: Mostly used for fixing values of CodeableConcept. In general, pattern\[x\] is not intended for use with primitive types, where is has the same meaning as fixed\[x\].
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `base64Binary`, `boolean`, `canonical`, `code`, `date`, `dateTime`, `decimal`, `id`, `instant`, `integer`, `integer64`, `markdown`, `oid`, `positiveInt`, `string`, `time`, `unsignedInt`, `uri`, `url`, `uuid`, `Address`, `Age`, `Annotation`, `Attachment`, `CodeableConcept`, `CodeableReference`, `Coding`, `ContactPoint`, `Count`, `Distance`, `Duration`, `HumanName`, `Identifier`, `Money`, `Period`, `Quantity`, `Range`, `Ratio`, `RatioRange`, `Reference`, `SampledData`, `Signature`, `Timing`, `ContactDetail`, `DataRequirement`, `Expression`, `ParameterDefinition`, `RelatedArtifact`, `TriggerDefinition`, `UsageContext`, `Availability`, `ExtendedContactDetail`, `Dosage`, `Meta`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub pattern: Option<ElementDefinitionPattern>,
 
-    /// Data type or Resource (reference to definition)
-    code: ? // uri
+    /// Id and extensions of `pattern[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub pattern_element: Option<ElementDefinitionPatternElement>,
 
-    /// Profiles (StructureDefinition or IG) - one must apply
-    profile: ? // canonical
+    /// Example value (as defined for type)
+    ///
+    /// A sample value for this element demonstrating the type of information that would typically be found in the element.
+    ///
+    /// Comment: Examples will most commonly be present for data where it's not implicitly obvious from either the data type or value set what the values might be.  (I.e. Example values for dates or quantities would generally be unnecessary.)  If the example value is fully populated, the publication tool can generate an instance automatically.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub example: Vec<ElementDefinitionExample>,
 
-    /// Profile (StructureDefinition or IG) on the Reference/canonical target - one must apply
-    targetProfile: ? // canonical
+    /// Minimum Allowed Value (for some types)
+    ///
+    /// The minimum allowed value for the element. The value is inclusive. This is allowed for the types date, dateTime, instant, time, decimal, integer, and Quantity.
+    ///
+    /// Comment: Except for date/date/instant, the type of the minValue\[x\] SHALL be the same as the specified type of the element. For the date/dateTime/instant values, the type of minValue\[x\] SHALL be either the same, or a [Duration](https://build.fhir.org/datatypes.html#Duration) which specifies a relative time limit to the current time. The duration value is positive, and is subtracted from the current clock to determine the minimum allowable value.   A minimum value for a Quantity is interpreted as a canonical minimum - e.g. you cannot provide 100mg if the minimum value is 10g.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `date`, `dateTime`, `instant`, `time`, `decimal`, `integer`, `integer64`, `positiveInt`, `unsignedInt`, `Quantity`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub min_value: Option<ElementDefinitionMinValue>,
 
-    /// contained | referenced | bundled - how aggregated
-    aggregation: ? // code
+    /// Id and extensions of `minValue[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub min_value_element: Option<ElementDefinitionMinValueElement>,
 
-    /// either | independent | specific
-    versioning: ? // code
+    /// Maximum Allowed Value (for some types)
+    ///
+    /// The maximum allowed value for the element. The value is inclusive. This is allowed for the types date, dateTime, instant, time, decimal, integer, and Quantity.
+    ///
+    /// Comment: Except for date/date/instant, the type of the maxValue\[x\] SHALL be the same as the specified type of the element. For the date/dateTime/instant values, the type of maxValue\[x\] SHALL be either the same, or a [Duration](https://build.fhir.org/datatypes.html#Duration) which specifies a relative time limit to the current time. The duration value is positive, and is added to the current clock to determine the maximum allowable value.   A maximum value for a Quantity is interpreted as a canonical maximum - e.g. you cannot provide 10g if the maximum value is 50mg.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `date`, `dateTime`, `instant`, `time`, `decimal`, `integer`, `integer64`, `positiveInt`, `unsignedInt`, `Quantity`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub max_value: Option<ElementDefinitionMaxValue>,
 
-    /// Specified value if missing from instance
-    : ? // base64Binary
+    /// Id and extensions of `maxValue[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub max_value_element: Option<ElementDefinitionMaxValueElement>,
 
-    /// Implicit meaning when this element is missing
-    meaningWhenMissing: ? // markdown
+    /// Max length for string type data
+    ///
+    /// Indicates the maximum length in characters that is permitted to be present in conformant instances and which is expected to be supported by conformant consumers that support the element. ```maxLength``` SHOULD only be used on primitive data types that have a string representation (see [http://hl7.org/fhir/StructureDefinition/structuredefinition-type-characteristics](http://hl7.org/fhir/extensions/StructureDefinition-structuredefinition-type-characteristics.html)).
+    ///
+    /// Comment: Receivers are not required to reject instances that exceed the maximum length.  The full length could be stored.  In some cases, data might be truncated, though truncation should be undertaken with care and an understanding of the consequences of doing so. If not specified, there is no conformance expectation for length support.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `integer`
+    pub max_length: Option<types::Integer>,
 
-    /// What the order of the elements means
-    orderMeaning: ? // string
+    /// Id and extensions of `maxLength`
+    #[serde(rename = "_maxLength")]
+    pub max_length_element: Option<types::Element>,
 
-    /// Value must be exactly this
-    : ? // base64Binary
+    /// Reference to invariant about presence
+    ///
+    /// A reference to an invariant that may make additional statements about the cardinality or value in the instance.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `id`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub condition: Vec<Option<types::Id>>,
 
-    /// Value must have at least these property values
-    : ? // base64Binary
+    /// Id and extensions of `condition`
+    #[serde(rename = "_condition", default, skip_serializing_if = "Vec::is_empty")]
+    pub condition_element: Vec<Option<types::Element>>,
 
-    /// Example value (as defined for type)
-    example: ? // Element
+    /// Condition that must evaluate to true
+    ///
+    /// Formal constraints such as co-occurrence and other constraints that can be computationally evaluated within the context of the instance.
+    ///
+    /// Comment: Constraints should be declared on the "context" element - the lowest element in the hierarchy that is common to all nodes referenced by the constraint.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    ///
+    /// Constraints:
+    ///
+    /// - `eld-21`: Constraints should have an expression or else validators will not be able to enforce them
+    /// - `eld-26`: Errors cannot be suppressed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub constraint: Vec<ElementDefinitionConstraint>,
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// For primitives, that a value must be present - not replaced by an extension
+    ///
+    /// Specifies for a primitive data type that the value of the data type cannot be replaced by an extension.
+    ///
+    /// Comment: Typical extensions that can be provided in place of a the value are [http://hl7.org/fhir/StructureDefinition/data-absent-reason](http://hl7.org/fhir/extensions/StructureDefinition-data-absent-reason.html),  [http://hl7.org/fhir/StructureDefinition/iso21090-nullFlavor](http://hl7.org/fhir/extensions/StructureDefinition-iso21090-nullFlavor.html),  [http://hl7.org/fhir/StructureDefinition/originalText](http://hl7.org/fhir/extensions/StructureDefinition-originalText.html), and [http://hl7.org/fhir/StructureDefinition/cqf-expression](http://hl7.org/fhir/extensions/StructureDefinition-cqf-expression.html). Note that this element has no meaning when a value is provided, and extensions can be provided in addition to a value.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub must_have_value: Option<types::Boolean>,
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Id and extensions of `mustHaveValue`
+    #[serde(rename = "_mustHaveValue")]
+    pub must_have_value_element: Option<types::Element>,
 
-    /// Describes the purpose of this example
-    label: ? // string
+    /// Extensions that are allowed to replace a primitive value
+    ///
+    /// Specifies a list of extensions that can appear in place of a primitive value.
+    ///
+    /// Comment: if mustHaveValue is true, then this element should not be present, since no extensions are allowed in place of the value. Note that this element has no impact if the value is present.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `canonical(StructureDefinition)`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub value_alternatives: Vec<Option<types::Canonical>>,
 
-    /// Value of Example (one of allowed types)
-    : ? // base64Binary
+    /// Id and extensions of `valueAlternatives`
+    #[serde(
+        rename = "_valueAlternatives",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub value_alternatives_element: Vec<Option<types::Element>>,
 
-    /// Minimum Allowed Value (for some types)
-    : ? // date
+    /// If the element must be supported (discouraged - see obligations)
+    ///
+    /// If true, implementations that produce or consume resources SHALL provide "support" for the element in some meaningful way. Note that this is being phased out and replaced by obligations (see below).  If false, the element may be ignored and not supported. If false, whether to populate or use the data element in any way is at the discretion of the implementation.
+    ///
+    /// Comment: "Something meaningful" is context dependent and impossible to describe in the base FHIR specification. For this reason, the mustSupport flag is never set to true by the FHIR specification itself - it is only set to true in profiles.  A profile on a type can always make mustSupport = true if it is false in the base type but cannot make mustSupport = false if it is true in the base type.   This is done in [Resource Profiles](https://build.fhir.org/profiling.html#mustsupport), where the profile labels an element as mustSupport=true. When a profile does this, it SHALL also make clear exactly what kind of "support" is required, as this can mean many things.    Note that an element that has the property IsModifier is not necessarily a "key" element (e.g. one of the important elements to make use of the resource), nor is it automatically mustSupport - however both of these things are more likely to be true for IsModifier elements than for other elements.
+    ///
+    /// Requirements: Allows a profile to set expectations for system capabilities beyond merely respecting cardinality constraints.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub must_support: Option<types::Boolean>,
 
-    /// Maximum Allowed Value (for some types)
-    : ? // date
+    /// Id and extensions of `mustSupport`
+    #[serde(rename = "_mustSupport")]
+    pub must_support_element: Option<types::Element>,
 
-    /// Max length for string type data
-    maxLength: ? // integer
+    /// If this modifies the meaning of other elements
+    ///
+    /// If true, the value of this element affects the interpretation of the element or resource that contains it, and the value of the element cannot be ignored. Typically, this is used for status, negation and qualification codes. The effect of this is that the element cannot be ignored by systems: they SHALL either recognize the element and process it, and/or a pre-determination has been made that it is not relevant to their particular system. When used on the root element in an extension definition, this indicates whether or not the extension is a modifier extension.
+    ///
+    /// Comment: Only the definition of an element can set IsModifier true - either the specification itself or where an extension is originally defined. Once set, it cannot be changed in derived profiles (except in the special case of the defining a new extension). An element/extension that has isModifier=true SHOULD also have a minimum cardinality of 1, so that there is no lack of clarity about what to do if it is missing. If it can be missing, the definition SHALL make the meaning of a missing element clear.
+    ///
+    /// Requirements: Allows elements to be introduced into a specification that can't safely be ignored by applications that don't recognize them.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub is_modifier: Option<types::Boolean>,
 
-    /// Reference to invariant about presence
-    condition: ? // id
+    /// Id and extensions of `isModifier`
+    #[serde(rename = "_isModifier")]
+    pub is_modifier_element: Option<types::Element>,
 
-    /// Condition that must evaluate to true
-    constraint: ? // Element
+    /// Reason that this element is marked as a modifier
+    ///
+    /// Explains how that element affects the interpretation of the resource or element that contains it.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub is_modifier_reason: Option<types::String>,
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// Id and extensions of `isModifierReason`
+    #[serde(rename = "_isModifierReason")]
+    pub is_modifier_reason_element: Option<types::Element>,
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Include when _summary = true?
+    ///
+    /// Whether the element should be included if a client requests a search with the parameter _summary=true.
+    ///
+    /// Comment: Some resources include a set of simple metadata, and some very large data. This element is used to reduce the quantity of data returned in searches. Note that servers may pre-cache summarized resources for optimal performance. When a request is made with _summary=true, serializers only include elements marked as 'isSummary = true'. With a few exceptions (listed below), all datatype properties are included in the summary form. In resource and datatype definitions, if an element has a descendant marked as isSummary=true or if it satisfies both of the following conditions, it must be marked as isSummary=true:
+    /// * is at the root or has a parent that is 'mustSupport'
+    /// * has a minimum cardinality of 1 or is a modifier element
+    ///
+    /// The following datatype properties are exceptions, and are not marked as isSummary:
+    /// * Attachment.data
+    /// * Signature.data, Signature.targetFormat, Signature.sigFormat
+    /// * Narrative.status, Narrative.div
+    /// * SampledData.data.
+    ///
+    /// Requirements: Allow clients to search through large resources quickly.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub is_summary: Option<types::Boolean>,
 
-    /// Target of 'condition' reference above
-    key: ? // id
+    /// Id and extensions of `isSummary`
+    #[serde(rename = "_isSummary")]
+    pub is_summary_element: Option<types::Element>,
 
-    /// Why this constraint is necessary or appropriate
-    requirements: ? // markdown
+    /// ValueSet details if this is coded
+    ///
+    /// Binds to a value set if this element is coded (code, Coding, CodeableConcept, Quantity), or the data types (string, uri).
+    ///
+    /// Comment: For a CodeableConcept, when no codes are allowed - only text, use a binding of strength "required" with a description explaining that no coded values are allowed and what sort of information to put in the "text" element.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Element`
+    ///
+    /// Constraints:
+    ///
+    /// - `eld-12`: ValueSet SHALL start with <http://> or <https://> or urn: or #
+    /// - `eld-23`: binding SHALL have either description or valueSet
+    pub binding: Option<ElementDefinitionBinding>,
 
-    /// error | warning
-    severity: ? // code
+    /// Map element to another set of definitions
+    ///
+    /// Identifies a concept from an external specification that roughly corresponds to this element.
+    ///
+    /// Comment: Mappings are not necessarily specific enough for safe translation.
+    ///
+    /// Requirements: Provides guidance to implementers familiar with or converting content from other specifications.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mapping: Vec<ElementDefinitionMapping>,
 
-    /// Suppress warning or hint in profile
-    suppress: ? // boolean
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
 
-    /// Human description of constraint
-    human: ? // string
+impl ElementDefinition {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(path: types::String) -> Self {
+        Self { path, ..Default::default() }
+    }
+}
 
-    /// FHIRPath expression of constraint
-    expression: ? // string
+impl ElementDefinition {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionBuilder {
+        ElementDefinitionBuilder::default()
+    }
+}
 
-    /// Reference to original source of constraint
-    source: ? // canonical
+/// Build one `ElementDefinition`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionBuilder {
+    inner: ElementDefinition,
+    path: Option<types::String>,
+}
 
-    /// For primitives, that a value must be present - not replaced by an extension
-    mustHaveValue: ? // boolean
+impl ElementDefinitionBuilder {
+    /// Set `ElementDefinition.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
 
-    /// Extensions that are allowed to replace a primitive value
-    valueAlternatives: ? // canonical
+    /// Set every `ElementDefinition.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
 
-    /// If the element must be supported (discouraged - see obligations)
-    mustSupport: ? // boolean
+    /// Add one `ElementDefinition.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
 
-    /// If this modifies the meaning of other elements
-    isModifier: ? // boolean
+    /// Set every `ElementDefinition.modifierExtension`.
+    pub fn modifier_extension(
+        mut self,
+        value: impl Into<Vec<types::Extension>>,
+    ) -> Self {
+        self.inner.modifier_extension = value.into();
+        self
+    }
 
-    /// Reason that this element is marked as a modifier
-    isModifierReason: ? // string
+    /// Add one `ElementDefinition.modifierExtension`.
+    pub fn push_modifier_extension(
+        mut self,
+        value: impl Into<types::Extension>,
+    ) -> Self {
+        self.inner.modifier_extension.push(value.into());
+        self
+    }
 
-    /// Include when _summary = true?
-    isSummary: ? // boolean
+    /// Set `ElementDefinition.path`.
+    pub fn path(mut self, value: impl Into<types::String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
 
-    /// ValueSet details if this is coded
-    binding: ? // Element
+    /// Set every `ElementDefinition.representation`.
+    pub fn representation(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Code>>,
+    ) -> Self {
+        self.inner.representation = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// Add one `ElementDefinition.representation`.
+    pub fn push_representation(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.representation.push(Some(value.into()));
+        self
+    }
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Set `ElementDefinition.sliceName`.
+    pub fn slice_name(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.slice_name = Some(value.into());
+        self
+    }
 
-    /// required | extensible | preferred | example
-    strength: ? // code
+    /// Set `ElementDefinition.sliceIsConstraining`.
+    pub fn slice_is_constraining(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.slice_is_constraining = Some(value.into());
+        self
+    }
 
-    /// Intended use of codes in the bound value set
-    description: ? // markdown
+    /// Set `ElementDefinition.label`.
+    pub fn label(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.label = Some(value.into());
+        self
+    }
 
-    /// Source of value set
-    valueSet: ? // canonical
+    /// Set every `ElementDefinition.code`.
+    pub fn code(mut self, value: impl Into<Vec<types::Coding>>) -> Self {
+        self.inner.code = value.into();
+        self
+    }
 
-    /// Additional Bindings - more rules about the binding
-    additional: ? // Element
+    /// Add one `ElementDefinition.code`.
+    pub fn push_code(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.code.push(value.into());
+        self
+    }
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// Set `ElementDefinition.slicing`.
+    pub fn slicing(mut self, value: impl Into<ElementDefinitionSlicing>) -> Self {
+        self.inner.slicing = Some(value.into());
+        self
+    }
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Set `ElementDefinition.short`.
+    pub fn short(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.short = Some(value.into());
+        self
+    }
 
-    /// maximum | minimum | required | extensible | candidate | current | preferred | ui | starter | component
-    purpose: ? // code
+    /// Set `ElementDefinition.definition`.
+    pub fn definition(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.definition = Some(value.into());
+        self
+    }
 
-    /// The value set for the additional binding
-    valueSet: ? // canonical
+    /// Set `ElementDefinition.comment`.
+    pub fn comment(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
 
-    /// Documentation of the purpose of use of the binding
-    documentation: ? // markdown
+    /// Set `ElementDefinition.requirements`.
+    pub fn requirements(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.requirements = Some(value.into());
+        self
+    }
 
-    /// Concise documentation - for summary tables
-    shortDoco: ? // string
+    /// Set every `ElementDefinition.alias`.
+    pub fn alias(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::String>>,
+    ) -> Self {
+        self.inner.alias = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
 
-    /// Qualifies the usage - jurisdiction, gender, workflow status etc.
-    usage: ? // UsageContext
+    /// Add one `ElementDefinition.alias`.
+    pub fn push_alias(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.alias.push(Some(value.into()));
+        self
+    }
 
-    /// Whether binding can applies to all repeats, or just one
-    any: ? // boolean
+    /// Set `ElementDefinition.min`.
+    pub fn min(mut self, value: impl Into<types::UnsignedInt>) -> Self {
+        self.inner.min = Some(value.into());
+        self
+    }
 
-    /// Map element to another set of definitions
-    mapping: ? // Element
+    /// Set `ElementDefinition.max`.
+    pub fn max(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.max = Some(value.into());
+        self
+    }
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    /// Set `ElementDefinition.base`.
+    pub fn base(mut self, value: impl Into<ElementDefinitionBase>) -> Self {
+        self.inner.base = Some(value.into());
+        self
+    }
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    /// Set `ElementDefinition.contentReference`.
+    pub fn content_reference(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.content_reference = Some(value.into());
+        self
+    }
 
-    /// Reference to mapping declaration
-    identity: ? // id
+    /// Set every `ElementDefinition.type`.
+    pub fn r#type(mut self, value: impl Into<Vec<ElementDefinitionType>>) -> Self {
+        self.inner.r#type = value.into();
+        self
+    }
 
-    /// Computable language of mapping
-    language: ? // code
+    /// Add one `ElementDefinition.type`.
+    pub fn push_type(mut self, value: impl Into<ElementDefinitionType>) -> Self {
+        self.inner.r#type.push(value.into());
+        self
+    }
 
-    /// Details of the mapping
-    map: ? // string
+    /// Set `ElementDefinition.defaultValue[x]` to a `base64Binary`.
+    pub fn default_value_base_64_binary(
+        mut self,
+        value: impl Into<types::Base64Binary>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Base64Binary(value.into()),
+        );
+        self
+    }
 
-    /// Comments about the mapping or its use
-    comment: ? // markdown
+    /// Set `ElementDefinition.defaultValue[x]` to a `boolean`.
+    pub fn default_value_boolean(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Boolean(value.into()),
+        );
+        self
+    }
 
-}
+    /// Set `ElementDefinition.defaultValue[x]` to a `canonical`.
+    pub fn default_value_canonical(
+        mut self,
+        value: impl Into<types::Canonical>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Canonical(value.into()),
+        );
+        self
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    type T = ElementDefinition;
+    /// Set `ElementDefinition.defaultValue[x]` to a `code`.
+    pub fn default_value_code(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Code(value.into()),
+        );
+        self
+    }
 
-    #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    /// Set `ElementDefinition.defaultValue[x]` to a `date`.
+    pub fn default_value_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Date(value.into()),
+        );
+        self
     }
 
-    mod serde_json {
-        use super::*;
-        use ::serde_json::json;
+    /// Set `ElementDefinition.defaultValue[x]` to a `dateTime`.
+    pub fn default_value_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::DateTime(value.into()),
+        );
+        self
+    }
 
-        #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
-            let actual: T = ::serde_json::from_value(json).expect("from_value");
-            let expect: T = T::default();
-            assert_eq!(actual, expect);
-        }
+    /// Set `ElementDefinition.defaultValue[x]` to a `decimal`.
+    pub fn default_value_decimal(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Decimal(value.into()),
+        );
+        self
+    }
 
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
+    /// Set `ElementDefinition.defaultValue[x]` to a `id`.
+    pub fn default_value_id(mut self, value: impl Into<types::Id>) -> Self {
+        self.inner.default_value = Some(ElementDefinitionDefaultValue::Id(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `instant`.
+    pub fn default_value_instant(mut self, value: impl Into<types::Instant>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Instant(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `integer`.
+    pub fn default_value_integer(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Integer(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `integer64`.
+    pub fn default_value_integer_64(
+        mut self,
+        value: impl Into<types::Integer64>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Integer64(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `markdown`.
+    pub fn default_value_markdown(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Markdown(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `oid`.
+    pub fn default_value_oid(mut self, value: impl Into<types::Oid>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Oid(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `positiveInt`.
+    pub fn default_value_positive_int(
+        mut self,
+        value: impl Into<types::PositiveInt>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::PositiveInt(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `string`.
+    pub fn default_value_string(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::String(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `time`.
+    pub fn default_value_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Time(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `unsignedInt`.
+    pub fn default_value_unsigned_int(
+        mut self,
+        value: impl Into<types::UnsignedInt>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::UnsignedInt(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `uri`.
+    pub fn default_value_uri(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Uri(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `url`.
+    pub fn default_value_url(mut self, value: impl Into<types::Url>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Url(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `uuid`.
+    pub fn default_value_uuid(mut self, value: impl Into<types::Uuid>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Uuid(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Address`.
+    pub fn default_value_address(mut self, value: impl Into<types::Address>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Address(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Age`.
+    pub fn default_value_age(mut self, value: impl Into<types::Age>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Age(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Annotation`.
+    pub fn default_value_annotation(
+        mut self,
+        value: impl Into<types::Annotation>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Annotation(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Attachment`.
+    pub fn default_value_attachment(
+        mut self,
+        value: impl Into<types::Attachment>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Attachment(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `CodeableConcept`.
+    pub fn default_value_codeable_concept(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::CodeableConcept(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `CodeableReference`.
+    pub fn default_value_codeable_reference(
+        mut self,
+        value: impl Into<types::CodeableReference>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::CodeableReference(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Coding`.
+    pub fn default_value_coding(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Coding(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `ContactPoint`.
+    pub fn default_value_contact_point(
+        mut self,
+        value: impl Into<types::ContactPoint>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::ContactPoint(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Count`.
+    pub fn default_value_count(mut self, value: impl Into<types::Count>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Count(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Distance`.
+    pub fn default_value_distance(mut self, value: impl Into<types::Distance>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Distance(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Duration`.
+    pub fn default_value_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Duration(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `HumanName`.
+    pub fn default_value_human_name(
+        mut self,
+        value: impl Into<types::HumanName>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::HumanName(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Identifier`.
+    pub fn default_value_identifier(
+        mut self,
+        value: impl Into<types::Identifier>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Identifier(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Money`.
+    pub fn default_value_money(mut self, value: impl Into<types::Money>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Money(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Period`.
+    pub fn default_value_period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Period(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Quantity`.
+    pub fn default_value_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Quantity(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Range`.
+    pub fn default_value_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Range(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Ratio`.
+    pub fn default_value_ratio(mut self, value: impl Into<types::Ratio>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Ratio(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `RatioRange`.
+    pub fn default_value_ratio_range(
+        mut self,
+        value: impl Into<types::RatioRange>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::RatioRange(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Reference`.
+    pub fn default_value_reference(
+        mut self,
+        value: impl Into<types::Reference>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Reference(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `SampledData`.
+    pub fn default_value_sampled_data(
+        mut self,
+        value: impl Into<types::SampledData>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::SampledData(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Signature`.
+    pub fn default_value_signature(
+        mut self,
+        value: impl Into<types::Signature>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Signature(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Timing`.
+    pub fn default_value_timing(mut self, value: impl Into<types::Timing>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Timing(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `ContactDetail`.
+    pub fn default_value_contact_detail(
+        mut self,
+        value: impl Into<types::ContactDetail>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::ContactDetail(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `DataRequirement`.
+    pub fn default_value_data_requirement(
+        mut self,
+        value: impl Into<types::DataRequirement>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::DataRequirement(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Expression`.
+    pub fn default_value_expression(
+        mut self,
+        value: impl Into<types::Expression>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Expression(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `ParameterDefinition`.
+    pub fn default_value_parameter_definition(
+        mut self,
+        value: impl Into<types::ParameterDefinition>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::ParameterDefinition(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `RelatedArtifact`.
+    pub fn default_value_related_artifact(
+        mut self,
+        value: impl Into<types::RelatedArtifact>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::RelatedArtifact(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `TriggerDefinition`.
+    pub fn default_value_trigger_definition(
+        mut self,
+        value: impl Into<types::TriggerDefinition>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::TriggerDefinition(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `UsageContext`.
+    pub fn default_value_usage_context(
+        mut self,
+        value: impl Into<types::UsageContext>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::UsageContext(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Availability`.
+    pub fn default_value_availability(
+        mut self,
+        value: impl Into<types::Availability>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Availability(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `ExtendedContactDetail`.
+    pub fn default_value_extended_contact_detail(
+        mut self,
+        value: impl Into<types::ExtendedContactDetail>,
+    ) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::ExtendedContactDetail(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Dosage`.
+    pub fn default_value_dosage(mut self, value: impl Into<types::Dosage>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Dosage(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.defaultValue[x]` to a `Meta`.
+    pub fn default_value_meta(mut self, value: impl Into<types::Meta>) -> Self {
+        self.inner.default_value = Some(
+            ElementDefinitionDefaultValue::Meta(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.meaningWhenMissing`.
+    pub fn meaning_when_missing(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.meaning_when_missing = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.orderMeaning`.
+    pub fn order_meaning(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.order_meaning = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `base64Binary`.
+    pub fn fixed_base_64_binary(
+        mut self,
+        value: impl Into<types::Base64Binary>,
+    ) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Base64Binary(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `boolean`.
+    pub fn fixed_boolean(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Boolean(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `canonical`.
+    pub fn fixed_canonical(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Canonical(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `code`.
+    pub fn fixed_code(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Code(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `date`.
+    pub fn fixed_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Date(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `dateTime`.
+    pub fn fixed_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::DateTime(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `decimal`.
+    pub fn fixed_decimal(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Decimal(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `id`.
+    pub fn fixed_id(mut self, value: impl Into<types::Id>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Id(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `instant`.
+    pub fn fixed_instant(mut self, value: impl Into<types::Instant>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Instant(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `integer`.
+    pub fn fixed_integer(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Integer(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `integer64`.
+    pub fn fixed_integer_64(mut self, value: impl Into<types::Integer64>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Integer64(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `markdown`.
+    pub fn fixed_markdown(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Markdown(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `oid`.
+    pub fn fixed_oid(mut self, value: impl Into<types::Oid>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Oid(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `positiveInt`.
+    pub fn fixed_positive_int(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::PositiveInt(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `string`.
+    pub fn fixed_string(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::String(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `time`.
+    pub fn fixed_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Time(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `unsignedInt`.
+    pub fn fixed_unsigned_int(mut self, value: impl Into<types::UnsignedInt>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::UnsignedInt(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `uri`.
+    pub fn fixed_uri(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Uri(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `url`.
+    pub fn fixed_url(mut self, value: impl Into<types::Url>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Url(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `uuid`.
+    pub fn fixed_uuid(mut self, value: impl Into<types::Uuid>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Uuid(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Address`.
+    pub fn fixed_address(mut self, value: impl Into<types::Address>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Address(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Age`.
+    pub fn fixed_age(mut self, value: impl Into<types::Age>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Age(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Annotation`.
+    pub fn fixed_annotation(mut self, value: impl Into<types::Annotation>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Annotation(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Attachment`.
+    pub fn fixed_attachment(mut self, value: impl Into<types::Attachment>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Attachment(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `CodeableConcept`.
+    pub fn fixed_codeable_concept(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::CodeableConcept(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `CodeableReference`.
+    pub fn fixed_codeable_reference(
+        mut self,
+        value: impl Into<types::CodeableReference>,
+    ) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::CodeableReference(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Coding`.
+    pub fn fixed_coding(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Coding(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `ContactPoint`.
+    pub fn fixed_contact_point(mut self, value: impl Into<types::ContactPoint>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::ContactPoint(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Count`.
+    pub fn fixed_count(mut self, value: impl Into<types::Count>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Count(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Distance`.
+    pub fn fixed_distance(mut self, value: impl Into<types::Distance>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Distance(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Duration`.
+    pub fn fixed_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Duration(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `HumanName`.
+    pub fn fixed_human_name(mut self, value: impl Into<types::HumanName>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::HumanName(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Identifier`.
+    pub fn fixed_identifier(mut self, value: impl Into<types::Identifier>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Identifier(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Money`.
+    pub fn fixed_money(mut self, value: impl Into<types::Money>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Money(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Period`.
+    pub fn fixed_period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Period(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Quantity`.
+    pub fn fixed_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Quantity(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Range`.
+    pub fn fixed_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Range(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Ratio`.
+    pub fn fixed_ratio(mut self, value: impl Into<types::Ratio>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Ratio(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `RatioRange`.
+    pub fn fixed_ratio_range(mut self, value: impl Into<types::RatioRange>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::RatioRange(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Reference`.
+    pub fn fixed_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Reference(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `SampledData`.
+    pub fn fixed_sampled_data(mut self, value: impl Into<types::SampledData>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::SampledData(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Signature`.
+    pub fn fixed_signature(mut self, value: impl Into<types::Signature>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Signature(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Timing`.
+    pub fn fixed_timing(mut self, value: impl Into<types::Timing>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Timing(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `ContactDetail`.
+    pub fn fixed_contact_detail(
+        mut self,
+        value: impl Into<types::ContactDetail>,
+    ) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::ContactDetail(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `DataRequirement`.
+    pub fn fixed_data_requirement(
+        mut self,
+        value: impl Into<types::DataRequirement>,
+    ) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::DataRequirement(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Expression`.
+    pub fn fixed_expression(mut self, value: impl Into<types::Expression>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Expression(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `ParameterDefinition`.
+    pub fn fixed_parameter_definition(
+        mut self,
+        value: impl Into<types::ParameterDefinition>,
+    ) -> Self {
+        self.inner.fixed = Some(
+            ElementDefinitionFixed::ParameterDefinition(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `RelatedArtifact`.
+    pub fn fixed_related_artifact(
+        mut self,
+        value: impl Into<types::RelatedArtifact>,
+    ) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::RelatedArtifact(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `TriggerDefinition`.
+    pub fn fixed_trigger_definition(
+        mut self,
+        value: impl Into<types::TriggerDefinition>,
+    ) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::TriggerDefinition(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `UsageContext`.
+    pub fn fixed_usage_context(mut self, value: impl Into<types::UsageContext>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::UsageContext(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Availability`.
+    pub fn fixed_availability(mut self, value: impl Into<types::Availability>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Availability(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `ExtendedContactDetail`.
+    pub fn fixed_extended_contact_detail(
+        mut self,
+        value: impl Into<types::ExtendedContactDetail>,
+    ) -> Self {
+        self.inner.fixed = Some(
+            ElementDefinitionFixed::ExtendedContactDetail(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Dosage`.
+    pub fn fixed_dosage(mut self, value: impl Into<types::Dosage>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Dosage(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.fixed[x]` to a `Meta`.
+    pub fn fixed_meta(mut self, value: impl Into<types::Meta>) -> Self {
+        self.inner.fixed = Some(ElementDefinitionFixed::Meta(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `base64Binary`.
+    pub fn pattern_base_64_binary(
+        mut self,
+        value: impl Into<types::Base64Binary>,
+    ) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Base64Binary(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `boolean`.
+    pub fn pattern_boolean(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Boolean(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `canonical`.
+    pub fn pattern_canonical(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Canonical(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `code`.
+    pub fn pattern_code(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Code(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `date`.
+    pub fn pattern_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Date(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `dateTime`.
+    pub fn pattern_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::DateTime(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `decimal`.
+    pub fn pattern_decimal(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Decimal(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `id`.
+    pub fn pattern_id(mut self, value: impl Into<types::Id>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Id(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `instant`.
+    pub fn pattern_instant(mut self, value: impl Into<types::Instant>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Instant(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `integer`.
+    pub fn pattern_integer(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Integer(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `integer64`.
+    pub fn pattern_integer_64(mut self, value: impl Into<types::Integer64>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Integer64(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `markdown`.
+    pub fn pattern_markdown(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Markdown(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `oid`.
+    pub fn pattern_oid(mut self, value: impl Into<types::Oid>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Oid(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `positiveInt`.
+    pub fn pattern_positive_int(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::PositiveInt(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `string`.
+    pub fn pattern_string(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::String(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `time`.
+    pub fn pattern_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Time(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `unsignedInt`.
+    pub fn pattern_unsigned_int(mut self, value: impl Into<types::UnsignedInt>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::UnsignedInt(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `uri`.
+    pub fn pattern_uri(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Uri(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `url`.
+    pub fn pattern_url(mut self, value: impl Into<types::Url>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Url(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `uuid`.
+    pub fn pattern_uuid(mut self, value: impl Into<types::Uuid>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Uuid(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Address`.
+    pub fn pattern_address(mut self, value: impl Into<types::Address>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Address(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Age`.
+    pub fn pattern_age(mut self, value: impl Into<types::Age>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Age(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Annotation`.
+    pub fn pattern_annotation(mut self, value: impl Into<types::Annotation>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Annotation(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Attachment`.
+    pub fn pattern_attachment(mut self, value: impl Into<types::Attachment>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Attachment(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `CodeableConcept`.
+    pub fn pattern_codeable_concept(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.inner.pattern = Some(
+            ElementDefinitionPattern::CodeableConcept(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `CodeableReference`.
+    pub fn pattern_codeable_reference(
+        mut self,
+        value: impl Into<types::CodeableReference>,
+    ) -> Self {
+        self.inner.pattern = Some(
+            ElementDefinitionPattern::CodeableReference(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Coding`.
+    pub fn pattern_coding(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Coding(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `ContactPoint`.
+    pub fn pattern_contact_point(
+        mut self,
+        value: impl Into<types::ContactPoint>,
+    ) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::ContactPoint(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Count`.
+    pub fn pattern_count(mut self, value: impl Into<types::Count>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Count(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Distance`.
+    pub fn pattern_distance(mut self, value: impl Into<types::Distance>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Distance(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Duration`.
+    pub fn pattern_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Duration(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `HumanName`.
+    pub fn pattern_human_name(mut self, value: impl Into<types::HumanName>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::HumanName(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Identifier`.
+    pub fn pattern_identifier(mut self, value: impl Into<types::Identifier>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Identifier(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Money`.
+    pub fn pattern_money(mut self, value: impl Into<types::Money>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Money(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Period`.
+    pub fn pattern_period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Period(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Quantity`.
+    pub fn pattern_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Quantity(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Range`.
+    pub fn pattern_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Range(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Ratio`.
+    pub fn pattern_ratio(mut self, value: impl Into<types::Ratio>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Ratio(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `RatioRange`.
+    pub fn pattern_ratio_range(mut self, value: impl Into<types::RatioRange>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::RatioRange(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Reference`.
+    pub fn pattern_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Reference(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `SampledData`.
+    pub fn pattern_sampled_data(mut self, value: impl Into<types::SampledData>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::SampledData(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Signature`.
+    pub fn pattern_signature(mut self, value: impl Into<types::Signature>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Signature(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Timing`.
+    pub fn pattern_timing(mut self, value: impl Into<types::Timing>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Timing(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `ContactDetail`.
+    pub fn pattern_contact_detail(
+        mut self,
+        value: impl Into<types::ContactDetail>,
+    ) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::ContactDetail(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `DataRequirement`.
+    pub fn pattern_data_requirement(
+        mut self,
+        value: impl Into<types::DataRequirement>,
+    ) -> Self {
+        self.inner.pattern = Some(
+            ElementDefinitionPattern::DataRequirement(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Expression`.
+    pub fn pattern_expression(mut self, value: impl Into<types::Expression>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Expression(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `ParameterDefinition`.
+    pub fn pattern_parameter_definition(
+        mut self,
+        value: impl Into<types::ParameterDefinition>,
+    ) -> Self {
+        self.inner.pattern = Some(
+            ElementDefinitionPattern::ParameterDefinition(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `RelatedArtifact`.
+    pub fn pattern_related_artifact(
+        mut self,
+        value: impl Into<types::RelatedArtifact>,
+    ) -> Self {
+        self.inner.pattern = Some(
+            ElementDefinitionPattern::RelatedArtifact(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `TriggerDefinition`.
+    pub fn pattern_trigger_definition(
+        mut self,
+        value: impl Into<types::TriggerDefinition>,
+    ) -> Self {
+        self.inner.pattern = Some(
+            ElementDefinitionPattern::TriggerDefinition(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `UsageContext`.
+    pub fn pattern_usage_context(
+        mut self,
+        value: impl Into<types::UsageContext>,
+    ) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::UsageContext(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Availability`.
+    pub fn pattern_availability(
+        mut self,
+        value: impl Into<types::Availability>,
+    ) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Availability(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `ExtendedContactDetail`.
+    pub fn pattern_extended_contact_detail(
+        mut self,
+        value: impl Into<types::ExtendedContactDetail>,
+    ) -> Self {
+        self.inner.pattern = Some(
+            ElementDefinitionPattern::ExtendedContactDetail(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Dosage`.
+    pub fn pattern_dosage(mut self, value: impl Into<types::Dosage>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Dosage(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.pattern[x]` to a `Meta`.
+    pub fn pattern_meta(mut self, value: impl Into<types::Meta>) -> Self {
+        self.inner.pattern = Some(ElementDefinitionPattern::Meta(value.into()));
+        self
+    }
+
+    /// Set every `ElementDefinition.example`.
+    pub fn example(mut self, value: impl Into<Vec<ElementDefinitionExample>>) -> Self {
+        self.inner.example = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.example`.
+    pub fn push_example(mut self, value: impl Into<ElementDefinitionExample>) -> Self {
+        self.inner.example.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `date`.
+    pub fn min_value_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.inner.min_value = Some(ElementDefinitionMinValue::Date(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `dateTime`.
+    pub fn min_value_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.min_value = Some(ElementDefinitionMinValue::DateTime(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `instant`.
+    pub fn min_value_instant(mut self, value: impl Into<types::Instant>) -> Self {
+        self.inner.min_value = Some(ElementDefinitionMinValue::Instant(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `time`.
+    pub fn min_value_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.min_value = Some(ElementDefinitionMinValue::Time(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `decimal`.
+    pub fn min_value_decimal(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.min_value = Some(ElementDefinitionMinValue::Decimal(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `integer`.
+    pub fn min_value_integer(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.min_value = Some(ElementDefinitionMinValue::Integer(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `integer64`.
+    pub fn min_value_integer_64(mut self, value: impl Into<types::Integer64>) -> Self {
+        self.inner.min_value = Some(ElementDefinitionMinValue::Integer64(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `positiveInt`.
+    pub fn min_value_positive_int(
+        mut self,
+        value: impl Into<types::PositiveInt>,
+    ) -> Self {
+        self.inner.min_value = Some(
+            ElementDefinitionMinValue::PositiveInt(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `unsignedInt`.
+    pub fn min_value_unsigned_int(
+        mut self,
+        value: impl Into<types::UnsignedInt>,
+    ) -> Self {
+        self.inner.min_value = Some(
+            ElementDefinitionMinValue::UnsignedInt(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.minValue[x]` to a `Quantity`.
+    pub fn min_value_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.min_value = Some(ElementDefinitionMinValue::Quantity(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `date`.
+    pub fn max_value_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.inner.max_value = Some(ElementDefinitionMaxValue::Date(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `dateTime`.
+    pub fn max_value_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.max_value = Some(ElementDefinitionMaxValue::DateTime(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `instant`.
+    pub fn max_value_instant(mut self, value: impl Into<types::Instant>) -> Self {
+        self.inner.max_value = Some(ElementDefinitionMaxValue::Instant(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `time`.
+    pub fn max_value_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.max_value = Some(ElementDefinitionMaxValue::Time(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `decimal`.
+    pub fn max_value_decimal(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.max_value = Some(ElementDefinitionMaxValue::Decimal(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `integer`.
+    pub fn max_value_integer(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.max_value = Some(ElementDefinitionMaxValue::Integer(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `integer64`.
+    pub fn max_value_integer_64(mut self, value: impl Into<types::Integer64>) -> Self {
+        self.inner.max_value = Some(ElementDefinitionMaxValue::Integer64(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `positiveInt`.
+    pub fn max_value_positive_int(
+        mut self,
+        value: impl Into<types::PositiveInt>,
+    ) -> Self {
+        self.inner.max_value = Some(
+            ElementDefinitionMaxValue::PositiveInt(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `unsignedInt`.
+    pub fn max_value_unsigned_int(
+        mut self,
+        value: impl Into<types::UnsignedInt>,
+    ) -> Self {
+        self.inner.max_value = Some(
+            ElementDefinitionMaxValue::UnsignedInt(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.maxValue[x]` to a `Quantity`.
+    pub fn max_value_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.max_value = Some(ElementDefinitionMaxValue::Quantity(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.maxLength`.
+    pub fn max_length(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.max_length = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.condition`.
+    pub fn condition(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Id>>,
+    ) -> Self {
+        self.inner.condition = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `ElementDefinition.condition`.
+    pub fn push_condition(mut self, value: impl Into<types::Id>) -> Self {
+        self.inner.condition.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `ElementDefinition.constraint`.
+    pub fn constraint(
+        mut self,
+        value: impl Into<Vec<ElementDefinitionConstraint>>,
+    ) -> Self {
+        self.inner.constraint = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.constraint`.
+    pub fn push_constraint(
+        mut self,
+        value: impl Into<ElementDefinitionConstraint>,
+    ) -> Self {
+        self.inner.constraint.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.mustHaveValue`.
+    pub fn must_have_value(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.must_have_value = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.valueAlternatives`.
+    pub fn value_alternatives(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Canonical>>,
+    ) -> Self {
+        self.inner.value_alternatives = value
+            .into_iter()
+            .map(|x| Some(x.into()))
+            .collect();
+        self
+    }
+
+    /// Add one `ElementDefinition.valueAlternatives`.
+    pub fn push_value_alternatives(
+        mut self,
+        value: impl Into<types::Canonical>,
+    ) -> Self {
+        self.inner.value_alternatives.push(Some(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.mustSupport`.
+    pub fn must_support(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.must_support = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.isModifier`.
+    pub fn is_modifier(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.is_modifier = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.isModifierReason`.
+    pub fn is_modifier_reason(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.is_modifier_reason = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.isSummary`.
+    pub fn is_summary(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.is_summary = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding`.
+    pub fn binding(mut self, value: impl Into<ElementDefinitionBinding>) -> Self {
+        self.inner.binding = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.mapping`.
+    pub fn mapping(mut self, value: impl Into<Vec<ElementDefinitionMapping>>) -> Self {
+        self.inner.mapping = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.mapping`.
+    pub fn push_mapping(mut self, value: impl Into<ElementDefinitionMapping>) -> Self {
+        self.inner.mapping.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ElementDefinition, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.path {
+            Some(value) => inner.path = value,
+            None => missing.push("ElementDefinition.path"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinition {
+    const PATH: &'static str = "ElementDefinition";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.path, &format!("{path}.path"), issues);
+        types::Validate::validate_into(
+            &self.path_element,
+            &format!("{path}._path"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.representation,
+            &format!("{path}.representation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.representation_element,
+            &format!("{path}._representation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.slice_name,
+            &format!("{path}.sliceName"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.slice_name_element,
+            &format!("{path}._sliceName"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.slice_is_constraining,
+            &format!("{path}.sliceIsConstraining"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.slice_is_constraining_element,
+            &format!("{path}._sliceIsConstraining"),
+            issues,
+        );
+        types::Validate::validate_into(&self.label, &format!("{path}.label"), issues);
+        types::Validate::validate_into(
+            &self.label_element,
+            &format!("{path}._label"),
+            issues,
+        );
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+        types::Validate::validate_into(
+            &self.slicing,
+            &format!("{path}.slicing"),
+            issues,
+        );
+        types::Validate::validate_into(&self.short, &format!("{path}.short"), issues);
+        types::Validate::validate_into(
+            &self.short_element,
+            &format!("{path}._short"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.definition,
+            &format!("{path}.definition"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.definition_element,
+            &format!("{path}._definition"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.comment,
+            &format!("{path}.comment"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.comment_element,
+            &format!("{path}._comment"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.requirements,
+            &format!("{path}.requirements"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.requirements_element,
+            &format!("{path}._requirements"),
+            issues,
+        );
+        types::Validate::validate_into(&self.alias, &format!("{path}.alias"), issues);
+        types::Validate::validate_into(
+            &self.alias_element,
+            &format!("{path}._alias"),
+            issues,
+        );
+        types::Validate::validate_into(&self.min, &format!("{path}.min"), issues);
+        types::Validate::validate_into(
+            &self.min_element,
+            &format!("{path}._min"),
+            issues,
+        );
+        types::Validate::validate_into(&self.max, &format!("{path}.max"), issues);
+        types::Validate::validate_into(
+            &self.max_element,
+            &format!("{path}._max"),
+            issues,
+        );
+        types::Validate::validate_into(&self.base, &format!("{path}.base"), issues);
+        types::Validate::validate_into(
+            &self.content_reference,
+            &format!("{path}.contentReference"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.content_reference_element,
+            &format!("{path}._contentReference"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.default_value,
+            &format!("{path}.defaultValue"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.default_value_element,
+            &format!("{path}._defaultValue"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.meaning_when_missing,
+            &format!("{path}.meaningWhenMissing"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.meaning_when_missing_element,
+            &format!("{path}._meaningWhenMissing"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.order_meaning,
+            &format!("{path}.orderMeaning"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.order_meaning_element,
+            &format!("{path}._orderMeaning"),
+            issues,
+        );
+        types::Validate::validate_into(&self.fixed, &format!("{path}.fixed"), issues);
+        types::Validate::validate_into(
+            &self.fixed_element,
+            &format!("{path}._fixed"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.pattern,
+            &format!("{path}.pattern"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.pattern_element,
+            &format!("{path}._pattern"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.example,
+            &format!("{path}.example"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.min_value,
+            &format!("{path}.minValue"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.min_value_element,
+            &format!("{path}._minValue"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_value,
+            &format!("{path}.maxValue"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_value_element,
+            &format!("{path}._maxValue"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_length,
+            &format!("{path}.maxLength"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_length_element,
+            &format!("{path}._maxLength"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.condition,
+            &format!("{path}.condition"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.condition_element,
+            &format!("{path}._condition"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.constraint,
+            &format!("{path}.constraint"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.must_have_value,
+            &format!("{path}.mustHaveValue"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.must_have_value_element,
+            &format!("{path}._mustHaveValue"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_alternatives,
+            &format!("{path}.valueAlternatives"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_alternatives_element,
+            &format!("{path}._valueAlternatives"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.must_support,
+            &format!("{path}.mustSupport"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.must_support_element,
+            &format!("{path}._mustSupport"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.is_modifier,
+            &format!("{path}.isModifier"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.is_modifier_element,
+            &format!("{path}._isModifier"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.is_modifier_reason,
+            &format!("{path}.isModifierReason"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.is_modifier_reason_element,
+            &format!("{path}._isModifierReason"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.is_summary,
+            &format!("{path}.isSummary"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.is_summary_element,
+            &format!("{path}._isSummary"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.binding,
+            &format!("{path}.binding"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.mapping,
+            &format!("{path}.mapping"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinition {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_element_definition(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept(&self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept(&self.path_element, &format!("{path}._path"), visitor);
+        types::Walk::accept(
+            &self.representation,
+            &format!("{path}.representation"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.representation_element,
+            &format!("{path}._representation"),
+            visitor,
+        );
+        types::Walk::accept(&self.slice_name, &format!("{path}.sliceName"), visitor);
+        types::Walk::accept(
+            &self.slice_name_element,
+            &format!("{path}._sliceName"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.slice_is_constraining,
+            &format!("{path}.sliceIsConstraining"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.slice_is_constraining_element,
+            &format!("{path}._sliceIsConstraining"),
+            visitor,
+        );
+        types::Walk::accept(&self.label, &format!("{path}.label"), visitor);
+        types::Walk::accept(&self.label_element, &format!("{path}._label"), visitor);
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept(&self.slicing, &format!("{path}.slicing"), visitor);
+        types::Walk::accept(&self.short, &format!("{path}.short"), visitor);
+        types::Walk::accept(&self.short_element, &format!("{path}._short"), visitor);
+        types::Walk::accept(&self.definition, &format!("{path}.definition"), visitor);
+        types::Walk::accept(
+            &self.definition_element,
+            &format!("{path}._definition"),
+            visitor,
+        );
+        types::Walk::accept(&self.comment, &format!("{path}.comment"), visitor);
+        types::Walk::accept(&self.comment_element, &format!("{path}._comment"), visitor);
+        types::Walk::accept(
+            &self.requirements,
+            &format!("{path}.requirements"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.requirements_element,
+            &format!("{path}._requirements"),
+            visitor,
+        );
+        types::Walk::accept(&self.alias, &format!("{path}.alias"), visitor);
+        types::Walk::accept(&self.alias_element, &format!("{path}._alias"), visitor);
+        types::Walk::accept(&self.min, &format!("{path}.min"), visitor);
+        types::Walk::accept(&self.min_element, &format!("{path}._min"), visitor);
+        types::Walk::accept(&self.max, &format!("{path}.max"), visitor);
+        types::Walk::accept(&self.max_element, &format!("{path}._max"), visitor);
+        types::Walk::accept(&self.base, &format!("{path}.base"), visitor);
+        types::Walk::accept(
+            &self.content_reference,
+            &format!("{path}.contentReference"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.content_reference_element,
+            &format!("{path}._contentReference"),
+            visitor,
+        );
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(
+            &self.default_value,
+            &format!("{path}.defaultValue"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.default_value_element,
+            &format!("{path}._defaultValue"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.meaning_when_missing,
+            &format!("{path}.meaningWhenMissing"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.meaning_when_missing_element,
+            &format!("{path}._meaningWhenMissing"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.order_meaning,
+            &format!("{path}.orderMeaning"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.order_meaning_element,
+            &format!("{path}._orderMeaning"),
+            visitor,
+        );
+        types::Walk::accept(&self.fixed, &format!("{path}.fixed"), visitor);
+        types::Walk::accept(&self.fixed_element, &format!("{path}._fixed"), visitor);
+        types::Walk::accept(&self.pattern, &format!("{path}.pattern"), visitor);
+        types::Walk::accept(&self.pattern_element, &format!("{path}._pattern"), visitor);
+        types::Walk::accept(&self.example, &format!("{path}.example"), visitor);
+        types::Walk::accept(&self.min_value, &format!("{path}.minValue"), visitor);
+        types::Walk::accept(
+            &self.min_value_element,
+            &format!("{path}._minValue"),
+            visitor,
+        );
+        types::Walk::accept(&self.max_value, &format!("{path}.maxValue"), visitor);
+        types::Walk::accept(
+            &self.max_value_element,
+            &format!("{path}._maxValue"),
+            visitor,
+        );
+        types::Walk::accept(&self.max_length, &format!("{path}.maxLength"), visitor);
+        types::Walk::accept(
+            &self.max_length_element,
+            &format!("{path}._maxLength"),
+            visitor,
+        );
+        types::Walk::accept(&self.condition, &format!("{path}.condition"), visitor);
+        types::Walk::accept(
+            &self.condition_element,
+            &format!("{path}._condition"),
+            visitor,
+        );
+        types::Walk::accept(&self.constraint, &format!("{path}.constraint"), visitor);
+        types::Walk::accept(
+            &self.must_have_value,
+            &format!("{path}.mustHaveValue"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.must_have_value_element,
+            &format!("{path}._mustHaveValue"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.value_alternatives,
+            &format!("{path}.valueAlternatives"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.value_alternatives_element,
+            &format!("{path}._valueAlternatives"),
+            visitor,
+        );
+        types::Walk::accept(&self.must_support, &format!("{path}.mustSupport"), visitor);
+        types::Walk::accept(
+            &self.must_support_element,
+            &format!("{path}._mustSupport"),
+            visitor,
+        );
+        types::Walk::accept(&self.is_modifier, &format!("{path}.isModifier"), visitor);
+        types::Walk::accept(
+            &self.is_modifier_element,
+            &format!("{path}._isModifier"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.is_modifier_reason,
+            &format!("{path}.isModifierReason"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.is_modifier_reason_element,
+            &format!("{path}._isModifierReason"),
+            visitor,
+        );
+        types::Walk::accept(&self.is_summary, &format!("{path}.isSummary"), visitor);
+        types::Walk::accept(
+            &self.is_summary_element,
+            &format!("{path}._isSummary"),
+            visitor,
+        );
+        types::Walk::accept(&self.binding, &format!("{path}.binding"), visitor);
+        types::Walk::accept(&self.mapping, &format!("{path}.mapping"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_element_definition_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept_mut(
+            &mut self.path_element,
+            &format!("{path}._path"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.representation,
+            &format!("{path}.representation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.representation_element,
+            &format!("{path}._representation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.slice_name,
+            &format!("{path}.sliceName"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.slice_name_element,
+            &format!("{path}._sliceName"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.slice_is_constraining,
+            &format!("{path}.sliceIsConstraining"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.slice_is_constraining_element,
+            &format!("{path}._sliceIsConstraining"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.label, &format!("{path}.label"), visitor);
+        types::Walk::accept_mut(
+            &mut self.label_element,
+            &format!("{path}._label"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept_mut(&mut self.slicing, &format!("{path}.slicing"), visitor);
+        types::Walk::accept_mut(&mut self.short, &format!("{path}.short"), visitor);
+        types::Walk::accept_mut(
+            &mut self.short_element,
+            &format!("{path}._short"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.definition,
+            &format!("{path}.definition"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.definition_element,
+            &format!("{path}._definition"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.comment, &format!("{path}.comment"), visitor);
+        types::Walk::accept_mut(
+            &mut self.comment_element,
+            &format!("{path}._comment"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.requirements,
+            &format!("{path}.requirements"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.requirements_element,
+            &format!("{path}._requirements"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.alias, &format!("{path}.alias"), visitor);
+        types::Walk::accept_mut(
+            &mut self.alias_element,
+            &format!("{path}._alias"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.min, &format!("{path}.min"), visitor);
+        types::Walk::accept_mut(&mut self.min_element, &format!("{path}._min"), visitor);
+        types::Walk::accept_mut(&mut self.max, &format!("{path}.max"), visitor);
+        types::Walk::accept_mut(&mut self.max_element, &format!("{path}._max"), visitor);
+        types::Walk::accept_mut(&mut self.base, &format!("{path}.base"), visitor);
+        types::Walk::accept_mut(
+            &mut self.content_reference,
+            &format!("{path}.contentReference"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.content_reference_element,
+            &format!("{path}._contentReference"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.default_value,
+            &format!("{path}.defaultValue"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.default_value_element,
+            &format!("{path}._defaultValue"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.meaning_when_missing,
+            &format!("{path}.meaningWhenMissing"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.meaning_when_missing_element,
+            &format!("{path}._meaningWhenMissing"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.order_meaning,
+            &format!("{path}.orderMeaning"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.order_meaning_element,
+            &format!("{path}._orderMeaning"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.fixed, &format!("{path}.fixed"), visitor);
+        types::Walk::accept_mut(
+            &mut self.fixed_element,
+            &format!("{path}._fixed"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.pattern, &format!("{path}.pattern"), visitor);
+        types::Walk::accept_mut(
+            &mut self.pattern_element,
+            &format!("{path}._pattern"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.example, &format!("{path}.example"), visitor);
+        types::Walk::accept_mut(
+            &mut self.min_value,
+            &format!("{path}.minValue"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.min_value_element,
+            &format!("{path}._minValue"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_value,
+            &format!("{path}.maxValue"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_value_element,
+            &format!("{path}._maxValue"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_length,
+            &format!("{path}.maxLength"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_length_element,
+            &format!("{path}._maxLength"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.condition,
+            &format!("{path}.condition"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.condition_element,
+            &format!("{path}._condition"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.constraint,
+            &format!("{path}.constraint"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.must_have_value,
+            &format!("{path}.mustHaveValue"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.must_have_value_element,
+            &format!("{path}._mustHaveValue"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_alternatives,
+            &format!("{path}.valueAlternatives"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_alternatives_element,
+            &format!("{path}._valueAlternatives"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.must_support,
+            &format!("{path}.mustSupport"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.must_support_element,
+            &format!("{path}._mustSupport"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.is_modifier,
+            &format!("{path}.isModifier"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.is_modifier_element,
+            &format!("{path}._isModifier"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.is_modifier_reason,
+            &format!("{path}.isModifierReason"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.is_modifier_reason_element,
+            &format!("{path}._isModifierReason"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.is_summary,
+            &format!("{path}.isSummary"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.is_summary_element,
+            &format!("{path}._isSummary"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.binding, &format!("{path}.binding"), visitor);
+        types::Walk::accept_mut(&mut self.mapping, &format!("{path}.mapping"), visitor);
+    }
+}
+
+impl ElementDefinition {
+    /// The metadata of each child element of `ElementDefinition`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "modifierExtension",
+            json_key: "modifierExtension",
+            rust_field: "modifier_extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "path",
+            json_key: "path",
+            rust_field: "path",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "representation",
+            json_key: "representation",
+            rust_field: "representation",
+            min: 0,
+            max: None,
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/property-representation|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "sliceName",
+            json_key: "sliceName",
+            rust_field: "slice_name",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "sliceIsConstraining",
+            json_key: "sliceIsConstraining",
+            rust_field: "slice_is_constraining",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "label",
+            json_key: "label",
+            rust_field: "label",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 0,
+            max: None,
+            types: &["Coding"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some("http://loinc.org/vs"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "slicing",
+            json_key: "slicing",
+            rust_field: "slicing",
+            min: 0,
+            max: Some(1),
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "short",
+            json_key: "short",
+            rust_field: "short",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "definition",
+            json_key: "definition",
+            rust_field: "definition",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "comment",
+            json_key: "comment",
+            rust_field: "comment",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "requirements",
+            json_key: "requirements",
+            rust_field: "requirements",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "alias",
+            json_key: "alias",
+            rust_field: "alias",
+            min: 0,
+            max: None,
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "min",
+            json_key: "min",
+            rust_field: "min",
+            min: 0,
+            max: Some(1),
+            types: &["unsignedInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "max",
+            json_key: "max",
+            rust_field: "max",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "base",
+            json_key: "base",
+            rust_field: "base",
+            min: 0,
+            max: Some(1),
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "contentReference",
+            json_key: "contentReference",
+            rust_field: "content_reference",
+            min: 0,
+            max: Some(1),
+            types: &["uri"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "defaultValue[x]",
+            json_key: "defaultValue",
+            rust_field: "default_value",
+            min: 0,
+            max: Some(1),
+            types: &[
+                "base64Binary",
+                "boolean",
+                "canonical",
+                "code",
+                "date",
+                "dateTime",
+                "decimal",
+                "id",
+                "instant",
+                "integer",
+                "integer64",
+                "markdown",
+                "oid",
+                "positiveInt",
+                "string",
+                "time",
+                "unsignedInt",
+                "uri",
+                "url",
+                "uuid",
+                "Address",
+                "Age",
+                "Annotation",
+                "Attachment",
+                "CodeableConcept",
+                "CodeableReference",
+                "Coding",
+                "ContactPoint",
+                "Count",
+                "Distance",
+                "Duration",
+                "HumanName",
+                "Identifier",
+                "Money",
+                "Period",
+                "Quantity",
+                "Range",
+                "Ratio",
+                "RatioRange",
+                "Reference",
+                "SampledData",
+                "Signature",
+                "Timing",
+                "ContactDetail",
+                "DataRequirement",
+                "Expression",
+                "ParameterDefinition",
+                "RelatedArtifact",
+                "TriggerDefinition",
+                "UsageContext",
+                "Availability",
+                "ExtendedContactDetail",
+                "Dosage",
+                "Meta",
+            ],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "meaningWhenMissing",
+            json_key: "meaningWhenMissing",
+            rust_field: "meaning_when_missing",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "orderMeaning",
+            json_key: "orderMeaning",
+            rust_field: "order_meaning",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "fixed[x]",
+            json_key: "fixed",
+            rust_field: "fixed",
+            min: 0,
+            max: Some(1),
+            types: &[
+                "base64Binary",
+                "boolean",
+                "canonical",
+                "code",
+                "date",
+                "dateTime",
+                "decimal",
+                "id",
+                "instant",
+                "integer",
+                "integer64",
+                "markdown",
+                "oid",
+                "positiveInt",
+                "string",
+                "time",
+                "unsignedInt",
+                "uri",
+                "url",
+                "uuid",
+                "Address",
+                "Age",
+                "Annotation",
+                "Attachment",
+                "CodeableConcept",
+                "CodeableReference",
+                "Coding",
+                "ContactPoint",
+                "Count",
+                "Distance",
+                "Duration",
+                "HumanName",
+                "Identifier",
+                "Money",
+                "Period",
+                "Quantity",
+                "Range",
+                "Ratio",
+                "RatioRange",
+                "Reference",
+                "SampledData",
+                "Signature",
+                "Timing",
+                "ContactDetail",
+                "DataRequirement",
+                "Expression",
+                "ParameterDefinition",
+                "RelatedArtifact",
+                "TriggerDefinition",
+                "UsageContext",
+                "Availability",
+                "ExtendedContactDetail",
+                "Dosage",
+                "Meta",
+            ],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "pattern[x]",
+            json_key: "pattern",
+            rust_field: "pattern",
+            min: 0,
+            max: Some(1),
+            types: &[
+                "base64Binary",
+                "boolean",
+                "canonical",
+                "code",
+                "date",
+                "dateTime",
+                "decimal",
+                "id",
+                "instant",
+                "integer",
+                "integer64",
+                "markdown",
+                "oid",
+                "positiveInt",
+                "string",
+                "time",
+                "unsignedInt",
+                "uri",
+                "url",
+                "uuid",
+                "Address",
+                "Age",
+                "Annotation",
+                "Attachment",
+                "CodeableConcept",
+                "CodeableReference",
+                "Coding",
+                "ContactPoint",
+                "Count",
+                "Distance",
+                "Duration",
+                "HumanName",
+                "Identifier",
+                "Money",
+                "Period",
+                "Quantity",
+                "Range",
+                "Ratio",
+                "RatioRange",
+                "Reference",
+                "SampledData",
+                "Signature",
+                "Timing",
+                "ContactDetail",
+                "DataRequirement",
+                "Expression",
+                "ParameterDefinition",
+                "RelatedArtifact",
+                "TriggerDefinition",
+                "UsageContext",
+                "Availability",
+                "ExtendedContactDetail",
+                "Dosage",
+                "Meta",
+            ],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "example",
+            json_key: "example",
+            rust_field: "example",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "minValue[x]",
+            json_key: "minValue",
+            rust_field: "min_value",
+            min: 0,
+            max: Some(1),
+            types: &[
+                "date",
+                "dateTime",
+                "instant",
+                "time",
+                "decimal",
+                "integer",
+                "integer64",
+                "positiveInt",
+                "unsignedInt",
+                "Quantity",
+            ],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "maxValue[x]",
+            json_key: "maxValue",
+            rust_field: "max_value",
+            min: 0,
+            max: Some(1),
+            types: &[
+                "date",
+                "dateTime",
+                "instant",
+                "time",
+                "decimal",
+                "integer",
+                "integer64",
+                "positiveInt",
+                "unsignedInt",
+                "Quantity",
+            ],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "maxLength",
+            json_key: "maxLength",
+            rust_field: "max_length",
+            min: 0,
+            max: Some(1),
+            types: &["integer"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "condition",
+            json_key: "condition",
+            rust_field: "condition",
+            min: 0,
+            max: None,
+            types: &["id"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "constraint",
+            json_key: "constraint",
+            rust_field: "constraint",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "mustHaveValue",
+            json_key: "mustHaveValue",
+            rust_field: "must_have_value",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "valueAlternatives",
+            json_key: "valueAlternatives",
+            rust_field: "value_alternatives",
+            min: 0,
+            max: None,
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "mustSupport",
+            json_key: "mustSupport",
+            rust_field: "must_support",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "isModifier",
+            json_key: "isModifier",
+            rust_field: "is_modifier",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "isModifierReason",
+            json_key: "isModifierReason",
+            rust_field: "is_modifier_reason",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "isSummary",
+            json_key: "isSummary",
+            rust_field: "is_summary",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "binding",
+            json_key: "binding",
+            rust_field: "binding",
+            min: 0,
+            max: Some(1),
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "mapping",
+            json_key: "mapping",
+            rust_field: "mapping",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinition {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "modifierExtension" => {
+                types::AsValueRef::as_value_ref(&self.modifier_extension)
+            }
+            "path" => types::AsValueRef::as_value_ref(&self.path),
+            "representation" => types::AsValueRef::as_value_ref(&self.representation),
+            "sliceName" => types::AsValueRef::as_value_ref(&self.slice_name),
+            "sliceIsConstraining" => {
+                types::AsValueRef::as_value_ref(&self.slice_is_constraining)
+            }
+            "label" => types::AsValueRef::as_value_ref(&self.label),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            "slicing" => types::AsValueRef::as_value_ref(&self.slicing),
+            "short" => types::AsValueRef::as_value_ref(&self.short),
+            "definition" => types::AsValueRef::as_value_ref(&self.definition),
+            "comment" => types::AsValueRef::as_value_ref(&self.comment),
+            "requirements" => types::AsValueRef::as_value_ref(&self.requirements),
+            "alias" => types::AsValueRef::as_value_ref(&self.alias),
+            "min" => types::AsValueRef::as_value_ref(&self.min),
+            "max" => types::AsValueRef::as_value_ref(&self.max),
+            "base" => types::AsValueRef::as_value_ref(&self.base),
+            "contentReference" => {
+                types::AsValueRef::as_value_ref(&self.content_reference)
+            }
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "defaultValue[x]" | "defaultValue" => {
+                types::AsValueRef::as_value_ref(&self.default_value)
+            }
+            "meaningWhenMissing" => {
+                types::AsValueRef::as_value_ref(&self.meaning_when_missing)
+            }
+            "orderMeaning" => types::AsValueRef::as_value_ref(&self.order_meaning),
+            "fixed[x]" | "fixed" => types::AsValueRef::as_value_ref(&self.fixed),
+            "pattern[x]" | "pattern" => types::AsValueRef::as_value_ref(&self.pattern),
+            "example" => types::AsValueRef::as_value_ref(&self.example),
+            "minValue[x]" | "minValue" => {
+                types::AsValueRef::as_value_ref(&self.min_value)
+            }
+            "maxValue[x]" | "maxValue" => {
+                types::AsValueRef::as_value_ref(&self.max_value)
+            }
+            "maxLength" => types::AsValueRef::as_value_ref(&self.max_length),
+            "condition" => types::AsValueRef::as_value_ref(&self.condition),
+            "constraint" => types::AsValueRef::as_value_ref(&self.constraint),
+            "mustHaveValue" => types::AsValueRef::as_value_ref(&self.must_have_value),
+            "valueAlternatives" => {
+                types::AsValueRef::as_value_ref(&self.value_alternatives)
+            }
+            "mustSupport" => types::AsValueRef::as_value_ref(&self.must_support),
+            "isModifier" => types::AsValueRef::as_value_ref(&self.is_modifier),
+            "isModifierReason" => {
+                types::AsValueRef::as_value_ref(&self.is_modifier_reason)
+            }
+            "isSummary" => types::AsValueRef::as_value_ref(&self.is_summary),
+            "binding" => types::AsValueRef::as_value_ref(&self.binding),
+            "mapping" => types::AsValueRef::as_value_ref(&self.mapping),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinition {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinition {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// This element is sliced - slices follow
+///
+/// Indicates that the element is sliced into a set of alternative definitions (i.e. in a structure definition, there are multiple different constraints on a single element in the base resource). Slicing can be used in any resource that has cardinality ..* on the base resource, or any resource with a choice of types. The set of slices is any elements that come after this in the element sequence that have the same path, until a shorter path occurs (the shorter path terminates the set).
+///
+/// Comment: The first element in the sequence, the one that carries the slicing, is the definition that applies to all the slices. This is based on the unconstrained element, but can apply any constraints as appropriate. This may include the common constraints on the children of the element.
+///
+/// Cardinality: 0..1
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ElementDefinitionSlicing {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// Element values that are used to distinguish the slices
+    ///
+    /// Designates which child elements are used to discriminate between the slices when processing an instance. If one or more discriminators are provided, the value of the child elements in the instance data SHALL completely distinguish which slice the element in the resource matches based on the allowed values for those elements in each of the slices.
+    ///
+    /// Comment: If there is no discriminator, the content is hard to process, so this should be avoided.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub discriminator: Vec<ElementDefinitionSlicingDiscriminator>,
+
+    /// Text description of how slicing works (or not)
+    ///
+    /// A human-readable text description of how the slicing works. If there is no discriminator, this is required to be present to provide whatever information is possible about how the slices can be differentiated.
+    ///
+    /// Comment: If it's really not possible to differentiate them, the design should be re-evaluated to make the content usable.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub description: Option<types::String>,
+
+    /// Id and extensions of `description`
+    #[serde(rename = "_description")]
+    pub description_element: Option<types::Element>,
+
+    /// If elements must be in same order as slices
+    ///
+    /// If the matching elements have to occur in the same order as defined in the profile.
+    ///
+    /// Comment: Order should only be required when it is a pressing concern for presentation. Profile authors should consider making the order a feature of the rules about the narrative, not the rules about the data - requiring ordered data makes the profile much less re-usable.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub ordered: Option<types::Boolean>,
+
+    /// Id and extensions of `ordered`
+    #[serde(rename = "_ordered")]
+    pub ordered_element: Option<types::Element>,
+
+    /// closed | open | openAtEnd
+    ///
+    /// Whether additional slices are allowed or not. When the slices are ordered, profile authors can also say that additional slices are only allowed at the end.
+    ///
+    /// Comment: Allowing additional elements makes for a much for flexible template - it's open for use in wider contexts, but also means that the content of the resource is not closed, and applications have to decide how to handle content not described by the profile.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/resource-slicing-rules|5.0.0`](http://hl7.org/fhir/ValueSet/resource-slicing-rules)
+    ///
+    /// How slices are interpreted when evaluating an instance.
+    pub rules: types::Code,
+
+    /// Id and extensions of `rules`
+    #[serde(rename = "_rules")]
+    pub rules_element: Option<types::Element>,
+}
+
+impl ElementDefinitionSlicing {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(rules: types::Code) -> Self {
+        Self {
+            rules,
+            ..Default::default()
+        }
+    }
+}
+
+impl ElementDefinitionSlicing {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionSlicingBuilder {
+        ElementDefinitionSlicingBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionSlicing`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionSlicingBuilder {
+    inner: ElementDefinitionSlicing,
+    rules: Option<types::Code>,
+}
+
+impl ElementDefinitionSlicingBuilder {
+    /// Set `ElementDefinition.slicing.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.slicing.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.slicing.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.slicing.discriminator`.
+    pub fn discriminator(
+        mut self,
+        value: impl Into<Vec<ElementDefinitionSlicingDiscriminator>>,
+    ) -> Self {
+        self.inner.discriminator = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.slicing.discriminator`.
+    pub fn push_discriminator(
+        mut self,
+        value: impl Into<ElementDefinitionSlicingDiscriminator>,
+    ) -> Self {
+        self.inner.discriminator.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.slicing.description`.
+    pub fn description(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.description = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.slicing.ordered`.
+    pub fn ordered(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.ordered = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.slicing.rules`.
+    pub fn rules(mut self, value: impl Into<types::Code>) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ElementDefinitionSlicing, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.rules {
+            Some(value) => inner.rules = value,
+            None => missing.push("ElementDefinition.slicing.rules"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionSlicing {
+    const PATH: &'static str = "ElementDefinition.slicing";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.discriminator,
+            &format!("{path}.discriminator"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.description,
+            &format!("{path}.description"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.description_element,
+            &format!("{path}._description"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.ordered,
+            &format!("{path}.ordered"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.ordered_element,
+            &format!("{path}._ordered"),
+            issues,
+        );
+        types::Validate::validate_into(&self.rules, &format!("{path}.rules"), issues);
+        types::Validate::validate_into(
+            &self.rules_element,
+            &format!("{path}._rules"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionSlicing {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(
+            &self.discriminator,
+            &format!("{path}.discriminator"),
+            visitor,
+        );
+        types::Walk::accept(&self.description, &format!("{path}.description"), visitor);
+        types::Walk::accept(
+            &self.description_element,
+            &format!("{path}._description"),
+            visitor,
+        );
+        types::Walk::accept(&self.ordered, &format!("{path}.ordered"), visitor);
+        types::Walk::accept(&self.ordered_element, &format!("{path}._ordered"), visitor);
+        types::Walk::accept(&self.rules, &format!("{path}.rules"), visitor);
+        types::Walk::accept(&self.rules_element, &format!("{path}._rules"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.discriminator,
+            &format!("{path}.discriminator"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.description,
+            &format!("{path}.description"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.description_element,
+            &format!("{path}._description"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.ordered, &format!("{path}.ordered"), visitor);
+        types::Walk::accept_mut(
+            &mut self.ordered_element,
+            &format!("{path}._ordered"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.rules, &format!("{path}.rules"), visitor);
+        types::Walk::accept_mut(
+            &mut self.rules_element,
+            &format!("{path}._rules"),
+            visitor,
+        );
+    }
+}
+
+impl ElementDefinitionSlicing {
+    /// The metadata of each child element of `ElementDefinition.slicing`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "discriminator",
+            json_key: "discriminator",
+            rust_field: "discriminator",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "description",
+            json_key: "description",
+            rust_field: "description",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "ordered",
+            json_key: "ordered",
+            rust_field: "ordered",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "rules",
+            json_key: "rules",
+            rust_field: "rules",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/resource-slicing-rules|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionSlicing {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.slicing"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "discriminator" => types::AsValueRef::as_value_ref(&self.discriminator),
+            "description" => types::AsValueRef::as_value_ref(&self.description),
+            "ordered" => types::AsValueRef::as_value_ref(&self.ordered),
+            "rules" => types::AsValueRef::as_value_ref(&self.rules),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionSlicing {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionSlicing {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Element values that are used to distinguish the slices
+///
+/// Designates which child elements are used to discriminate between the slices when processing an instance. If one or more discriminators are provided, the value of the child elements in the instance data SHALL completely distinguish which slice the element in the resource matches based on the allowed values for those elements in each of the slices.
+///
+/// Comment: If there is no discriminator, the content is hard to process, so this should be avoided.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ElementDefinitionSlicingDiscriminator {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// value | exists | type | profile | position
+    ///
+    /// How the element value is interpreted when discrimination is evaluated.
+    ///
+    /// Comment: 'pattern' is deprecated - it works exactly the same as 'value'.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/discriminator-type|5.0.0`](http://hl7.org/fhir/ValueSet/discriminator-type)
+    ///
+    /// How an element value is interpreted when discrimination is evaluated.
+    pub r#type: types::Code,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
+
+    /// Path to element value
+    ///
+    /// A FHIRPath expression, using [the simple subset of FHIRPath](https://build.fhir.org/fhirpath.html#simple), that is used to identify the element on which discrimination is based.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub path: types::String,
+
+    /// Id and extensions of `path`
+    #[serde(rename = "_path")]
+    pub path_element: Option<types::Element>,
+}
+
+impl ElementDefinitionSlicingDiscriminator {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(r#type: types::Code, path: types::String) -> Self {
+        Self {
+            r#type,
+            path,
+            ..Default::default()
+        }
+    }
+}
+
+impl ElementDefinitionSlicingDiscriminator {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionSlicingDiscriminatorBuilder {
+        ElementDefinitionSlicingDiscriminatorBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionSlicingDiscriminator`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionSlicingDiscriminatorBuilder {
+    inner: ElementDefinitionSlicingDiscriminator,
+    r#type: Option<types::Code>,
+    path: Option<types::String>,
+}
+
+impl ElementDefinitionSlicingDiscriminatorBuilder {
+    /// Set `ElementDefinition.slicing.discriminator.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.slicing.discriminator.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.slicing.discriminator.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.slicing.discriminator.type`.
+    pub fn r#type(mut self, value: impl Into<types::Code>) -> Self {
+        self.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.slicing.discriminator.path`.
+    pub fn path(mut self, value: impl Into<types::String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(
+        self,
+    ) -> Result<ElementDefinitionSlicingDiscriminator, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.r#type {
+            Some(value) => inner.r#type = value,
+            None => missing.push("ElementDefinition.slicing.discriminator.type"),
+        }
+        match self.path {
+            Some(value) => inner.path = value,
+            None => missing.push("ElementDefinition.slicing.discriminator.path"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionSlicingDiscriminator {
+    const PATH: &'static str = "ElementDefinition.slicing.discriminator";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(&self.path, &format!("{path}.path"), issues);
+        types::Validate::validate_into(
+            &self.path_element,
+            &format!("{path}._path"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionSlicingDiscriminator {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept(&self.path_element, &format!("{path}._path"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept_mut(
+            &mut self.path_element,
+            &format!("{path}._path"),
+            visitor,
+        );
+    }
+}
+
+impl ElementDefinitionSlicingDiscriminator {
+    /// The metadata of each child element of `ElementDefinition.slicing.discriminator`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/discriminator-type|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "path",
+            json_key: "path",
+            rust_field: "path",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionSlicingDiscriminator {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.slicing.discriminator"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "path" => types::AsValueRef::as_value_ref(&self.path),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionSlicingDiscriminator {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionSlicingDiscriminator {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Base definition information for tools
+///
+/// Information about the base definition of the element, provided to make it unnecessary for tools to trace the deviation of the element through the derived and related profiles. When the element definition is not the original definition of an element - e.g. either in a constraint on another type, or for elements from a super type in a snap shot - then the information in provided in the element definition may be different to the base definition. On the original definition of the element, it will be same.
+///
+/// Comment: The base information does not carry any information that could not be determined from the path and related profiles, but making this determination requires both that the related profiles are available, and that the algorithm to determine them be available. For tooling simplicity, the base information must always be populated in element definitions in snap shots, even if it is the same.
+///
+/// Cardinality: 0..1
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ElementDefinitionBase {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// Path that identifies the base element
+    ///
+    /// The Path that identifies the base element - this matches the ElementDefinition.path for that element. Across FHIR, there is only one base definition of any element - that is, an element definition on a [StructureDefinition](https://build.fhir.org/structuredefinition.html#) without a StructureDefinition.base.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub path: types::String,
+
+    /// Id and extensions of `path`
+    #[serde(rename = "_path")]
+    pub path_element: Option<types::Element>,
+
+    /// Min cardinality of the base element
+    ///
+    /// Minimum cardinality of the base element identified by the path.
+    ///
+    /// Comment: This is provided for consistency with max, and may affect code generation of mandatory elements of the base resource are generated differently (some reference implementations have done this).
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `unsignedInt`
+    pub min: types::UnsignedInt,
+
+    /// Id and extensions of `min`
+    #[serde(rename = "_min")]
+    pub min_element: Option<types::Element>,
+
+    /// Max cardinality of the base element
+    ///
+    /// Maximum cardinality of the base element identified by the path.
+    ///
+    /// Comment: This is provided to code generation, since the serialization representation in JSON differs depending on whether the base element has max &gt; 1. Also, some forms of code generation may differ.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub max: types::String,
+
+    /// Id and extensions of `max`
+    #[serde(rename = "_max")]
+    pub max_element: Option<types::Element>,
+}
+
+impl ElementDefinitionBase {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(
+        path: types::String,
+        min: types::UnsignedInt,
+        max: types::String,
+    ) -> Self {
+        Self {
+            path,
+            min,
+            max,
+            ..Default::default()
+        }
+    }
+}
+
+impl ElementDefinitionBase {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionBaseBuilder {
+        ElementDefinitionBaseBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionBase`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionBaseBuilder {
+    inner: ElementDefinitionBase,
+    path: Option<types::String>,
+    min: Option<types::UnsignedInt>,
+    max: Option<types::String>,
+}
+
+impl ElementDefinitionBaseBuilder {
+    /// Set `ElementDefinition.base.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.base.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.base.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.base.path`.
+    pub fn path(mut self, value: impl Into<types::String>) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.base.min`.
+    pub fn min(mut self, value: impl Into<types::UnsignedInt>) -> Self {
+        self.min = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.base.max`.
+    pub fn max(mut self, value: impl Into<types::String>) -> Self {
+        self.max = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ElementDefinitionBase, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.path {
+            Some(value) => inner.path = value,
+            None => missing.push("ElementDefinition.base.path"),
+        }
+        match self.min {
+            Some(value) => inner.min = value,
+            None => missing.push("ElementDefinition.base.min"),
+        }
+        match self.max {
+            Some(value) => inner.max = value,
+            None => missing.push("ElementDefinition.base.max"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionBase {
+    const PATH: &'static str = "ElementDefinition.base";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.path, &format!("{path}.path"), issues);
+        types::Validate::validate_into(
+            &self.path_element,
+            &format!("{path}._path"),
+            issues,
+        );
+        types::Validate::validate_into(&self.min, &format!("{path}.min"), issues);
+        types::Validate::validate_into(
+            &self.min_element,
+            &format!("{path}._min"),
+            issues,
+        );
+        types::Validate::validate_into(&self.max, &format!("{path}.max"), issues);
+        types::Validate::validate_into(
+            &self.max_element,
+            &format!("{path}._max"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionBase {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept(&self.path_element, &format!("{path}._path"), visitor);
+        types::Walk::accept(&self.min, &format!("{path}.min"), visitor);
+        types::Walk::accept(&self.min_element, &format!("{path}._min"), visitor);
+        types::Walk::accept(&self.max, &format!("{path}.max"), visitor);
+        types::Walk::accept(&self.max_element, &format!("{path}._max"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.path, &format!("{path}.path"), visitor);
+        types::Walk::accept_mut(
+            &mut self.path_element,
+            &format!("{path}._path"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.min, &format!("{path}.min"), visitor);
+        types::Walk::accept_mut(&mut self.min_element, &format!("{path}._min"), visitor);
+        types::Walk::accept_mut(&mut self.max, &format!("{path}.max"), visitor);
+        types::Walk::accept_mut(&mut self.max_element, &format!("{path}._max"), visitor);
+    }
+}
+
+impl ElementDefinitionBase {
+    /// The metadata of each child element of `ElementDefinition.base`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "path",
+            json_key: "path",
+            rust_field: "path",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "min",
+            json_key: "min",
+            rust_field: "min",
+            min: 1,
+            max: Some(1),
+            types: &["unsignedInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "max",
+            json_key: "max",
+            rust_field: "max",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionBase {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.base"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "path" => types::AsValueRef::as_value_ref(&self.path),
+            "min" => types::AsValueRef::as_value_ref(&self.min),
+            "max" => types::AsValueRef::as_value_ref(&self.max),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionBase {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionBase {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Data type and Profile for this element
+///
+/// The data type or resource that the value of this element is permitted to be.
+///
+/// Comment: The Type of the element can be left blank in a differential constraint, in which case the type is inherited from the resource. Abstract types are not permitted to appear as a type when multiple types are listed.  (I.e. Abstract types cannot be part of a choice).
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Constraints:
+///
+/// - `eld-4`: Aggregation may only be specified if one of the allowed types for the element is a reference
+/// - `eld-17`: targetProfile is only allowed if the type is Reference or canonical
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ElementDefinitionType {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// Data type or Resource (reference to definition)
+    ///
+    /// URL of Data type or Resource that is a(or the) type used for this element. References are URLs that are relative to <http://hl7.org/fhir/StructureDefinition> e.g. "string" is a reference to <http://hl7.org/fhir/StructureDefinition/string>. Absolute URLs are only allowed in logical models.
+    ///
+    /// Comment: If the element is a reference to another resource, this element contains "Reference", and the targetProfile element defines what resources can be referenced. The targetProfile may be a reference to the general definition of a resource (e.g. <http://hl7.org/fhir/StructureDefinition/Patient>).
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `uri`
+    ///
+    /// Binding: extensible to [`http://hl7.org/fhir/ValueSet/elementdefinition-types`](http://hl7.org/fhir/ValueSet/elementdefinition-types)
+    ///
+    /// List of all Types for ElementDefinition.type.code.
+    pub code: types::Uri,
+
+    /// Id and extensions of `code`
+    #[serde(rename = "_code")]
+    pub code_element: Option<types::Element>,
+
+    /// Profiles (StructureDefinition or IG) - one must apply
+    ///
+    /// Identifies a profile structure or implementation Guide that applies to the datatype this element refers to. If any profiles are specified, then the content must conform to at least one of them. The URL can be a local reference - to a contained StructureDefinition, or a reference to another StructureDefinition or Implementation Guide by a canonical URL. When an implementation guide is specified, the type SHALL conform to at least one profile defined in the implementation guide.
+    ///
+    /// Comment: It is possible to profile  backbone element (e.g. part of a resource), using the [http://hl7.org/fhir/StructureDefinition/elementdefinition-profile-element](http://hl7.org/fhir/extensions/StructureDefinition-elementdefinition-profile-element.html) extension.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `canonical(StructureDefinition | ImplementationGuide)`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profile: Vec<Option<types::Canonical>>,
+
+    /// Id and extensions of `profile`
+    #[serde(rename = "_profile", default, skip_serializing_if = "Vec::is_empty")]
+    pub profile_element: Vec<Option<types::Element>>,
+
+    /// Profile (StructureDefinition or IG) on the Reference/canonical target - one must apply
+    ///
+    /// Used when the type is "Reference" or "canonical", and identifies a profile structure or implementation Guide that applies to the target of the reference this element refers to. If any profiles are specified, then the content must conform to at least one of them. The URL can be a local reference - to a contained StructureDefinition, or a reference to another StructureDefinition or Implementation Guide by a canonical URL. When an implementation guide is specified, the target resource SHALL conform to at least one profile defined in the implementation guide.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `canonical(StructureDefinition | ImplementationGuide)`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub target_profile: Vec<Option<types::Canonical>>,
+
+    /// Id and extensions of `targetProfile`
+    #[serde(rename = "_targetProfile", default, skip_serializing_if = "Vec::is_empty")]
+    pub target_profile_element: Vec<Option<types::Element>>,
+
+    /// contained | referenced | bundled - how aggregated
+    ///
+    /// If the type is a reference to another resource, how the resource is or can be aggregated - is it a contained resource, or a reference, and if the context is a bundle, is it included in the bundle.
+    ///
+    /// Comment: See [Aggregation Rules](https://build.fhir.org/elementdefinition.html#aggregation) for further clarification.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/resource-aggregation-mode|5.0.0`](http://hl7.org/fhir/ValueSet/resource-aggregation-mode)
+    ///
+    /// How resource references can be aggregated.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aggregation: Vec<Option<types::Code>>,
+
+    /// Id and extensions of `aggregation`
+    #[serde(rename = "_aggregation", default, skip_serializing_if = "Vec::is_empty")]
+    pub aggregation_element: Vec<Option<types::Element>>,
+
+    /// either | independent | specific
+    ///
+    /// Whether this reference needs to be version specific or version independent, or whether either can be used.
+    ///
+    /// Comment: The base specification never makes a rule as to which form is allowed, but implementation guides may do this. See [Aggregation Rules](https://build.fhir.org/elementdefinition.html#aggregation) for further clarification.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/reference-version-rules|5.0.0`](http://hl7.org/fhir/ValueSet/reference-version-rules)
+    ///
+    /// Whether a reference needs to be version specific or version independent, or whether either can be used.
+    pub versioning: Option<types::Code>,
+
+    /// Id and extensions of `versioning`
+    #[serde(rename = "_versioning")]
+    pub versioning_element: Option<types::Element>,
+}
+
+impl ElementDefinitionType {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(code: types::Uri) -> Self {
+        Self { code, ..Default::default() }
+    }
+}
+
+impl ElementDefinitionType {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionTypeBuilder {
+        ElementDefinitionTypeBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionType`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionTypeBuilder {
+    inner: ElementDefinitionType,
+    code: Option<types::Uri>,
+}
+
+impl ElementDefinitionTypeBuilder {
+    /// Set `ElementDefinition.type.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.type.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.type.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.type.code`.
+    pub fn code(mut self, value: impl Into<types::Uri>) -> Self {
+        self.code = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.type.profile`.
+    pub fn profile(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Canonical>>,
+    ) -> Self {
+        self.inner.profile = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `ElementDefinition.type.profile`.
+    pub fn push_profile(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.profile.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `ElementDefinition.type.targetProfile`.
+    pub fn target_profile(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Canonical>>,
+    ) -> Self {
+        self.inner.target_profile = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `ElementDefinition.type.targetProfile`.
+    pub fn push_target_profile(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.target_profile.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `ElementDefinition.type.aggregation`.
+    pub fn aggregation(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Code>>,
+    ) -> Self {
+        self.inner.aggregation = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `ElementDefinition.type.aggregation`.
+    pub fn push_aggregation(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.aggregation.push(Some(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.type.versioning`.
+    pub fn versioning(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.versioning = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ElementDefinitionType, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.code {
+            Some(value) => inner.code = value,
+            None => missing.push("ElementDefinition.type.code"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionType {
+    const PATH: &'static str = "ElementDefinition.type";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+        types::Validate::validate_into(
+            &self.code_element,
+            &format!("{path}._code"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.profile,
+            &format!("{path}.profile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.profile_element,
+            &format!("{path}._profile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.target_profile,
+            &format!("{path}.targetProfile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.target_profile_element,
+            &format!("{path}._targetProfile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.aggregation,
+            &format!("{path}.aggregation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.aggregation_element,
+            &format!("{path}._aggregation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.versioning,
+            &format!("{path}.versioning"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.versioning_element,
+            &format!("{path}._versioning"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionType {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept(&self.code_element, &format!("{path}._code"), visitor);
+        types::Walk::accept(&self.profile, &format!("{path}.profile"), visitor);
+        types::Walk::accept(&self.profile_element, &format!("{path}._profile"), visitor);
+        types::Walk::accept(
+            &self.target_profile,
+            &format!("{path}.targetProfile"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.target_profile_element,
+            &format!("{path}._targetProfile"),
+            visitor,
+        );
+        types::Walk::accept(&self.aggregation, &format!("{path}.aggregation"), visitor);
+        types::Walk::accept(
+            &self.aggregation_element,
+            &format!("{path}._aggregation"),
+            visitor,
+        );
+        types::Walk::accept(&self.versioning, &format!("{path}.versioning"), visitor);
+        types::Walk::accept(
+            &self.versioning_element,
+            &format!("{path}._versioning"),
+            visitor,
+        );
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept_mut(
+            &mut self.code_element,
+            &format!("{path}._code"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.profile, &format!("{path}.profile"), visitor);
+        types::Walk::accept_mut(
+            &mut self.profile_element,
+            &format!("{path}._profile"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.target_profile,
+            &format!("{path}.targetProfile"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.target_profile_element,
+            &format!("{path}._targetProfile"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.aggregation,
+            &format!("{path}.aggregation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.aggregation_element,
+            &format!("{path}._aggregation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.versioning,
+            &format!("{path}.versioning"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.versioning_element,
+            &format!("{path}._versioning"),
+            visitor,
+        );
+    }
+}
+
+impl ElementDefinitionType {
+    /// The metadata of each child element of `ElementDefinition.type`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 1,
+            max: Some(1),
+            types: &["uri"],
+            binding: Some(types::BindingInfo {
+                strength: "extensible",
+                value_set: Some("http://hl7.org/fhir/ValueSet/elementdefinition-types"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "profile",
+            json_key: "profile",
+            rust_field: "profile",
+            min: 0,
+            max: None,
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "targetProfile",
+            json_key: "targetProfile",
+            rust_field: "target_profile",
+            min: 0,
+            max: None,
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "aggregation",
+            json_key: "aggregation",
+            rust_field: "aggregation",
+            min: 0,
+            max: None,
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/resource-aggregation-mode|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "versioning",
+            json_key: "versioning",
+            rust_field: "versioning",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/reference-version-rules|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionType {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.type"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            "profile" => types::AsValueRef::as_value_ref(&self.profile),
+            "targetProfile" => types::AsValueRef::as_value_ref(&self.target_profile),
+            "aggregation" => types::AsValueRef::as_value_ref(&self.aggregation),
+            "versioning" => types::AsValueRef::as_value_ref(&self.versioning),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionType {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionType {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Example value (as defined for type)
+///
+/// A sample value for this element demonstrating the type of information that would typically be found in the element.
+///
+/// Comment: Examples will most commonly be present for data where it's not implicitly obvious from either the data type or value set what the values might be.  (I.e. Example values for dates or quantities would generally be unnecessary.)  If the example value is fully populated, the publication tool can generate an instance automatically.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+pub struct ElementDefinitionExample {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// Describes the purpose of this example
+    ///
+    /// Describes the purpose of this example among the set of examples.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub label: types::String,
+
+    /// Id and extensions of `label`
+    #[serde(rename = "_label")]
+    pub label_element: Option<types::Element>,
+
+    /// Value of Example (one of allowed types)
+    ///
+    /// The actual value for the element, which must be one of the types allowed for this element.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `base64Binary`, `boolean`, `canonical`, `code`, `date`, `dateTime`, `decimal`, `id`, `instant`, `integer`, `integer64`, `markdown`, `oid`, `positiveInt`, `string`, `time`, `unsignedInt`, `uri`, `url`, `uuid`, `Address`, `Age`, `Annotation`, `Attachment`, `CodeableConcept`, `CodeableReference`, `Coding`, `ContactPoint`, `Count`, `Distance`, `Duration`, `HumanName`, `Identifier`, `Money`, `Period`, `Quantity`, `Range`, `Ratio`, `RatioRange`, `Reference`, `SampledData`, `Signature`, `Timing`, `ContactDetail`, `DataRequirement`, `Expression`, `ParameterDefinition`, `RelatedArtifact`, `TriggerDefinition`, `UsageContext`, `Availability`, `ExtendedContactDetail`, `Dosage`, `Meta`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_required")]
+    pub value: ElementDefinitionExampleValue,
+
+    /// Id and extensions of `value[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub value_element: Option<ElementDefinitionExampleValueElement>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl ElementDefinitionExample {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(label: types::String, value: ElementDefinitionExampleValue) -> Self {
+        Self {
+            label,
+            value,
+            ..Default::default()
+        }
+    }
+}
+
+impl ElementDefinitionExample {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionExampleBuilder {
+        ElementDefinitionExampleBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionExample`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionExampleBuilder {
+    inner: ElementDefinitionExample,
+    label: Option<types::String>,
+    value: Option<ElementDefinitionExampleValue>,
+}
+
+impl ElementDefinitionExampleBuilder {
+    /// Set `ElementDefinition.example.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.example.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.example.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.example.label`.
+    pub fn label(mut self, value: impl Into<types::String>) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `base64Binary`.
+    pub fn value_base_64_binary(
+        mut self,
+        value: impl Into<types::Base64Binary>,
+    ) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Base64Binary(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `boolean`.
+    pub fn value_boolean(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Boolean(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `canonical`.
+    pub fn value_canonical(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Canonical(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `code`.
+    pub fn value_code(mut self, value: impl Into<types::Code>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Code(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `date`.
+    pub fn value_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Date(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `dateTime`.
+    pub fn value_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::DateTime(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `decimal`.
+    pub fn value_decimal(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Decimal(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `id`.
+    pub fn value_id(mut self, value: impl Into<types::Id>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Id(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `instant`.
+    pub fn value_instant(mut self, value: impl Into<types::Instant>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Instant(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `integer`.
+    pub fn value_integer(mut self, value: impl Into<types::Integer>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Integer(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `integer64`.
+    pub fn value_integer_64(mut self, value: impl Into<types::Integer64>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Integer64(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `markdown`.
+    pub fn value_markdown(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Markdown(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `oid`.
+    pub fn value_oid(mut self, value: impl Into<types::Oid>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Oid(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `positiveInt`.
+    pub fn value_positive_int(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::PositiveInt(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `string`.
+    pub fn value_string(mut self, value: impl Into<types::String>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::String(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `time`.
+    pub fn value_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Time(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `unsignedInt`.
+    pub fn value_unsigned_int(mut self, value: impl Into<types::UnsignedInt>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::UnsignedInt(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `uri`.
+    pub fn value_uri(mut self, value: impl Into<types::Uri>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Uri(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `url`.
+    pub fn value_url(mut self, value: impl Into<types::Url>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Url(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `uuid`.
+    pub fn value_uuid(mut self, value: impl Into<types::Uuid>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Uuid(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Address`.
+    pub fn value_address(mut self, value: impl Into<types::Address>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Address(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Age`.
+    pub fn value_age(mut self, value: impl Into<types::Age>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Age(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Annotation`.
+    pub fn value_annotation(mut self, value: impl Into<types::Annotation>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Annotation(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Attachment`.
+    pub fn value_attachment(mut self, value: impl Into<types::Attachment>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Attachment(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `CodeableConcept`.
+    pub fn value_codeable_concept(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::CodeableConcept(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `CodeableReference`.
+    pub fn value_codeable_reference(
+        mut self,
+        value: impl Into<types::CodeableReference>,
+    ) -> Self {
+        self.value = Some(
+            ElementDefinitionExampleValue::CodeableReference(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Coding`.
+    pub fn value_coding(mut self, value: impl Into<types::Coding>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Coding(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `ContactPoint`.
+    pub fn value_contact_point(mut self, value: impl Into<types::ContactPoint>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::ContactPoint(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Count`.
+    pub fn value_count(mut self, value: impl Into<types::Count>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Count(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Distance`.
+    pub fn value_distance(mut self, value: impl Into<types::Distance>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Distance(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Duration`.
+    pub fn value_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Duration(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `HumanName`.
+    pub fn value_human_name(mut self, value: impl Into<types::HumanName>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::HumanName(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Identifier`.
+    pub fn value_identifier(mut self, value: impl Into<types::Identifier>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Identifier(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Money`.
+    pub fn value_money(mut self, value: impl Into<types::Money>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Money(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Period`.
+    pub fn value_period(mut self, value: impl Into<types::Period>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Period(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Quantity`.
+    pub fn value_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Quantity(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Range`.
+    pub fn value_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Range(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Ratio`.
+    pub fn value_ratio(mut self, value: impl Into<types::Ratio>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Ratio(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `RatioRange`.
+    pub fn value_ratio_range(mut self, value: impl Into<types::RatioRange>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::RatioRange(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Reference`.
+    pub fn value_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Reference(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `SampledData`.
+    pub fn value_sampled_data(mut self, value: impl Into<types::SampledData>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::SampledData(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Signature`.
+    pub fn value_signature(mut self, value: impl Into<types::Signature>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Signature(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Timing`.
+    pub fn value_timing(mut self, value: impl Into<types::Timing>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Timing(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `ContactDetail`.
+    pub fn value_contact_detail(
+        mut self,
+        value: impl Into<types::ContactDetail>,
+    ) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::ContactDetail(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `DataRequirement`.
+    pub fn value_data_requirement(
+        mut self,
+        value: impl Into<types::DataRequirement>,
+    ) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::DataRequirement(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Expression`.
+    pub fn value_expression(mut self, value: impl Into<types::Expression>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Expression(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `ParameterDefinition`.
+    pub fn value_parameter_definition(
+        mut self,
+        value: impl Into<types::ParameterDefinition>,
+    ) -> Self {
+        self.value = Some(
+            ElementDefinitionExampleValue::ParameterDefinition(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `RelatedArtifact`.
+    pub fn value_related_artifact(
+        mut self,
+        value: impl Into<types::RelatedArtifact>,
+    ) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::RelatedArtifact(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `TriggerDefinition`.
+    pub fn value_trigger_definition(
+        mut self,
+        value: impl Into<types::TriggerDefinition>,
+    ) -> Self {
+        self.value = Some(
+            ElementDefinitionExampleValue::TriggerDefinition(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `UsageContext`.
+    pub fn value_usage_context(mut self, value: impl Into<types::UsageContext>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::UsageContext(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Availability`.
+    pub fn value_availability(mut self, value: impl Into<types::Availability>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Availability(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `ExtendedContactDetail`.
+    pub fn value_extended_contact_detail(
+        mut self,
+        value: impl Into<types::ExtendedContactDetail>,
+    ) -> Self {
+        self.value = Some(
+            ElementDefinitionExampleValue::ExtendedContactDetail(value.into()),
+        );
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Dosage`.
+    pub fn value_dosage(mut self, value: impl Into<types::Dosage>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Dosage(value.into()));
+        self
+    }
+
+    /// Set `ElementDefinition.example.value[x]` to a `Meta`.
+    pub fn value_meta(mut self, value: impl Into<types::Meta>) -> Self {
+        self.value = Some(ElementDefinitionExampleValue::Meta(value.into()));
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ElementDefinitionExample, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.label {
+            Some(value) => inner.label = value,
+            None => missing.push("ElementDefinition.example.label"),
+        }
+        match self.value {
+            Some(value) => inner.value = value,
+            None => missing.push("ElementDefinition.example.value[x]"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionExample {
+    const PATH: &'static str = "ElementDefinition.example";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.label, &format!("{path}.label"), issues);
+        types::Validate::validate_into(
+            &self.label_element,
+            &format!("{path}._label"),
+            issues,
+        );
+        types::Validate::validate_into(&self.value, &format!("{path}.value"), issues);
+        types::Validate::validate_into(
+            &self.value_element,
+            &format!("{path}._value"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionExample {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.label, &format!("{path}.label"), visitor);
+        types::Walk::accept(&self.label_element, &format!("{path}._label"), visitor);
+        types::Walk::accept(&self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept(&self.value_element, &format!("{path}._value"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.label, &format!("{path}.label"), visitor);
+        types::Walk::accept_mut(
+            &mut self.label_element,
+            &format!("{path}._label"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept_mut(
+            &mut self.value_element,
+            &format!("{path}._value"),
+            visitor,
+        );
+    }
+}
+
+impl ElementDefinitionExample {
+    /// The metadata of each child element of `ElementDefinition.example`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "label",
+            json_key: "label",
+            rust_field: "label",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value[x]",
+            json_key: "value",
+            rust_field: "value",
+            min: 1,
+            max: Some(1),
+            types: &[
+                "base64Binary",
+                "boolean",
+                "canonical",
+                "code",
+                "date",
+                "dateTime",
+                "decimal",
+                "id",
+                "instant",
+                "integer",
+                "integer64",
+                "markdown",
+                "oid",
+                "positiveInt",
+                "string",
+                "time",
+                "unsignedInt",
+                "uri",
+                "url",
+                "uuid",
+                "Address",
+                "Age",
+                "Annotation",
+                "Attachment",
+                "CodeableConcept",
+                "CodeableReference",
+                "Coding",
+                "ContactPoint",
+                "Count",
+                "Distance",
+                "Duration",
+                "HumanName",
+                "Identifier",
+                "Money",
+                "Period",
+                "Quantity",
+                "Range",
+                "Ratio",
+                "RatioRange",
+                "Reference",
+                "SampledData",
+                "Signature",
+                "Timing",
+                "ContactDetail",
+                "DataRequirement",
+                "Expression",
+                "ParameterDefinition",
+                "RelatedArtifact",
+                "TriggerDefinition",
+                "UsageContext",
+                "Availability",
+                "ExtendedContactDetail",
+                "Dosage",
+                "Meta",
+            ],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionExample {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.example"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "label" => types::AsValueRef::as_value_ref(&self.label),
+            "value[x]" | "value" => types::AsValueRef::as_value_ref(&self.value),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionExample {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionExample {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Condition that must evaluate to true
+///
+/// Formal constraints such as co-occurrence and other constraints that can be computationally evaluated within the context of the instance.
+///
+/// Comment: Constraints should be declared on the "context" element - the lowest element in the hierarchy that is common to all nodes referenced by the constraint.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Constraints:
+///
+/// - `eld-21`: Constraints should have an expression or else validators will not be able to enforce them
+/// - `eld-26`: Errors cannot be suppressed
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ElementDefinitionConstraint {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// Target of 'condition' reference above
+    ///
+    /// Allows identification of which elements have their cardinalities impacted by the constraint.  Will not be referenced for constraints that do not affect cardinality.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `id`
+    pub key: types::Id,
+
+    /// Id and extensions of `key`
+    #[serde(rename = "_key")]
+    pub key_element: Option<types::Element>,
+
+    /// Why this constraint is necessary or appropriate
+    ///
+    /// Description of why this constraint is necessary or appropriate.
+    ///
+    /// Comment: To be used if the reason for the constraint might not be intuitive to all implementers.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub requirements: Option<types::Markdown>,
+
+    /// Id and extensions of `requirements`
+    #[serde(rename = "_requirements")]
+    pub requirements_element: Option<types::Element>,
+
+    /// error | warning
+    ///
+    /// Identifies the impact constraint violation has on the conformance of the instance.
+    ///
+    /// Comment: This allows constraints to be asserted as "shall" (error) and "should" (warning).
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/constraint-severity|5.0.0`](http://hl7.org/fhir/ValueSet/constraint-severity)
+    ///
+    /// SHALL applications comply with this constraint?
+    pub severity: types::Code,
+
+    /// Id and extensions of `severity`
+    #[serde(rename = "_severity")]
+    pub severity_element: Option<types::Element>,
+
+    /// Suppress warning or hint in profile
+    ///
+    /// If true, indicates that the warning or best practice guideline should be suppressed.
+    ///
+    /// Comment: This element should only be present in a derived profile where a warning or hint has been determined to be spurious/incorrect. E.g. a warning encouraging the inclusion of an element that the profile explicitly prohibits.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub suppress: Option<types::Boolean>,
+
+    /// Id and extensions of `suppress`
+    #[serde(rename = "_suppress")]
+    pub suppress_element: Option<types::Element>,
+
+    /// Human description of constraint
+    ///
+    /// Text that can be used to describe the constraint in messages identifying that the constraint has been violated.
+    ///
+    /// Comment: Should be expressed in business terms as much as possible.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub human: types::String,
+
+    /// Id and extensions of `human`
+    #[serde(rename = "_human")]
+    pub human_element: Option<types::Element>,
+
+    /// FHIRPath expression of constraint
+    ///
+    /// A [FHIRPath](https://build.fhir.org/fhirpath.html) expression of constraint that can be executed to see if this constraint is met.
+    ///
+    /// Comment: In the absense of an expression, the expression is likely not enforceable by validators, and might be missed by many systems.
+    ///
+    /// Requirements: Used by validation tooling tests of the validity of the resource.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub expression: Option<types::String>,
+
+    /// Id and extensions of `expression`
+    #[serde(rename = "_expression")]
+    pub expression_element: Option<types::Element>,
+
+    /// Reference to original source of constraint
+    ///
+    /// A reference to the original source of the constraint, for traceability purposes.
+    ///
+    /// Comment: This is used when, e.g. rendering, where it is not useful to present inherited constraints when rendering the snapshot.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `canonical(StructureDefinition)`
+    pub source: Option<types::Canonical>,
+
+    /// Id and extensions of `source`
+    #[serde(rename = "_source")]
+    pub source_element: Option<types::Element>,
+}
+
+impl ElementDefinitionConstraint {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(key: types::Id, severity: types::Code, human: types::String) -> Self {
+        Self {
+            key,
+            severity,
+            human,
+            ..Default::default()
+        }
+    }
+}
+
+impl ElementDefinitionConstraint {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionConstraintBuilder {
+        ElementDefinitionConstraintBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionConstraint`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionConstraintBuilder {
+    inner: ElementDefinitionConstraint,
+    key: Option<types::Id>,
+    severity: Option<types::Code>,
+    human: Option<types::String>,
+}
+
+impl ElementDefinitionConstraintBuilder {
+    /// Set `ElementDefinition.constraint.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.constraint.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.constraint.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.constraint.key`.
+    pub fn key(mut self, value: impl Into<types::Id>) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.constraint.requirements`.
+    pub fn requirements(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.requirements = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.constraint.severity`.
+    pub fn severity(mut self, value: impl Into<types::Code>) -> Self {
+        self.severity = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.constraint.suppress`.
+    pub fn suppress(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.suppress = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.constraint.human`.
+    pub fn human(mut self, value: impl Into<types::String>) -> Self {
+        self.human = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.constraint.expression`.
+    pub fn expression(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.expression = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.constraint.source`.
+    pub fn source(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.source = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ElementDefinitionConstraint, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.key {
+            Some(value) => inner.key = value,
+            None => missing.push("ElementDefinition.constraint.key"),
+        }
+        match self.severity {
+            Some(value) => inner.severity = value,
+            None => missing.push("ElementDefinition.constraint.severity"),
+        }
+        match self.human {
+            Some(value) => inner.human = value,
+            None => missing.push("ElementDefinition.constraint.human"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionConstraint {
+    const PATH: &'static str = "ElementDefinition.constraint";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.key, &format!("{path}.key"), issues);
+        types::Validate::validate_into(
+            &self.key_element,
+            &format!("{path}._key"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.requirements,
+            &format!("{path}.requirements"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.requirements_element,
+            &format!("{path}._requirements"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.severity,
+            &format!("{path}.severity"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.severity_element,
+            &format!("{path}._severity"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.suppress,
+            &format!("{path}.suppress"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.suppress_element,
+            &format!("{path}._suppress"),
+            issues,
+        );
+        types::Validate::validate_into(&self.human, &format!("{path}.human"), issues);
+        types::Validate::validate_into(
+            &self.human_element,
+            &format!("{path}._human"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.expression,
+            &format!("{path}.expression"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.expression_element,
+            &format!("{path}._expression"),
+            issues,
+        );
+        types::Validate::validate_into(&self.source, &format!("{path}.source"), issues);
+        types::Validate::validate_into(
+            &self.source_element,
+            &format!("{path}._source"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionConstraint {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.key, &format!("{path}.key"), visitor);
+        types::Walk::accept(&self.key_element, &format!("{path}._key"), visitor);
+        types::Walk::accept(
+            &self.requirements,
+            &format!("{path}.requirements"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.requirements_element,
+            &format!("{path}._requirements"),
+            visitor,
+        );
+        types::Walk::accept(&self.severity, &format!("{path}.severity"), visitor);
+        types::Walk::accept(
+            &self.severity_element,
+            &format!("{path}._severity"),
+            visitor,
+        );
+        types::Walk::accept(&self.suppress, &format!("{path}.suppress"), visitor);
+        types::Walk::accept(
+            &self.suppress_element,
+            &format!("{path}._suppress"),
+            visitor,
+        );
+        types::Walk::accept(&self.human, &format!("{path}.human"), visitor);
+        types::Walk::accept(&self.human_element, &format!("{path}._human"), visitor);
+        types::Walk::accept(&self.expression, &format!("{path}.expression"), visitor);
+        types::Walk::accept(
+            &self.expression_element,
+            &format!("{path}._expression"),
+            visitor,
+        );
+        types::Walk::accept(&self.source, &format!("{path}.source"), visitor);
+        types::Walk::accept(&self.source_element, &format!("{path}._source"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.key, &format!("{path}.key"), visitor);
+        types::Walk::accept_mut(&mut self.key_element, &format!("{path}._key"), visitor);
+        types::Walk::accept_mut(
+            &mut self.requirements,
+            &format!("{path}.requirements"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.requirements_element,
+            &format!("{path}._requirements"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.severity,
+            &format!("{path}.severity"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.severity_element,
+            &format!("{path}._severity"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.suppress,
+            &format!("{path}.suppress"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.suppress_element,
+            &format!("{path}._suppress"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.human, &format!("{path}.human"), visitor);
+        types::Walk::accept_mut(
+            &mut self.human_element,
+            &format!("{path}._human"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.expression,
+            &format!("{path}.expression"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.expression_element,
+            &format!("{path}._expression"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.source, &format!("{path}.source"), visitor);
+        types::Walk::accept_mut(
+            &mut self.source_element,
+            &format!("{path}._source"),
+            visitor,
+        );
+    }
+}
+
+impl ElementDefinitionConstraint {
+    /// The metadata of each child element of `ElementDefinition.constraint`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "key",
+            json_key: "key",
+            rust_field: "key",
+            min: 1,
+            max: Some(1),
+            types: &["id"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "requirements",
+            json_key: "requirements",
+            rust_field: "requirements",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "severity",
+            json_key: "severity",
+            rust_field: "severity",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/constraint-severity|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "suppress",
+            json_key: "suppress",
+            rust_field: "suppress",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "human",
+            json_key: "human",
+            rust_field: "human",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "expression",
+            json_key: "expression",
+            rust_field: "expression",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "source",
+            json_key: "source",
+            rust_field: "source",
+            min: 0,
+            max: Some(1),
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionConstraint {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.constraint"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "key" => types::AsValueRef::as_value_ref(&self.key),
+            "requirements" => types::AsValueRef::as_value_ref(&self.requirements),
+            "severity" => types::AsValueRef::as_value_ref(&self.severity),
+            "suppress" => types::AsValueRef::as_value_ref(&self.suppress),
+            "human" => types::AsValueRef::as_value_ref(&self.human),
+            "expression" => types::AsValueRef::as_value_ref(&self.expression),
+            "source" => types::AsValueRef::as_value_ref(&self.source),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionConstraint {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionConstraint {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// ValueSet details if this is coded
+///
+/// Binds to a value set if this element is coded (code, Coding, CodeableConcept, Quantity), or the data types (string, uri).
+///
+/// Comment: For a CodeableConcept, when no codes are allowed - only text, use a binding of strength "required" with a description explaining that no coded values are allowed and what sort of information to put in the "text" element.
+///
+/// Cardinality: 0..1
+///
+/// Types: `Element`
+///
+/// Constraints:
+///
+/// - `eld-12`: ValueSet SHALL start with <http://> or <https://> or urn: or #
+/// - `eld-23`: binding SHALL have either description or valueSet
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ElementDefinitionBinding {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// required | extensible | preferred | example
+    ///
+    /// Indicates the degree of conformance expectations associated with this binding - that is, the degree to which the provided value set must be adhered to in the instances.
+    ///
+    /// Comment: For further discussion, see [Using Terminologies](https://build.fhir.org/terminologies.html).
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/binding-strength|5.0.0`](http://hl7.org/fhir/ValueSet/binding-strength)
+    ///
+    /// Indication of the degree of conformance expectations associated with a binding.
+    pub strength: types::Code,
+
+    /// Id and extensions of `strength`
+    #[serde(rename = "_strength")]
+    pub strength_element: Option<types::Element>,
+
+    /// Intended use of codes in the bound value set
+    ///
+    /// Describes the intended use of this particular set of codes.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub description: Option<types::Markdown>,
+
+    /// Id and extensions of `description`
+    #[serde(rename = "_description")]
+    pub description_element: Option<types::Element>,
+
+    /// Source of value set
+    ///
+    /// Refers to the value set that identifies the set of codes the binding refers to.
+    ///
+    /// Comment: The reference may be version-specific or not (e.g. have a |\[version\] at the end of the canonical URL).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `canonical(ValueSet)`
+    pub value_set: Option<types::Canonical>,
+
+    /// Id and extensions of `valueSet`
+    #[serde(rename = "_valueSet")]
+    pub value_set_element: Option<types::Element>,
+
+    /// Additional Bindings - more rules about the binding
+    ///
+    /// Additional bindings that help applications implementing this element. Additional bindings do not replace the main binding but provide more information and/or context.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Element`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional: Vec<ElementDefinitionBindingAdditional>,
+}
+
+impl ElementDefinitionBinding {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(strength: types::Code) -> Self {
+        Self {
+            strength,
+            ..Default::default()
+        }
+    }
+}
+
+impl ElementDefinitionBinding {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionBindingBuilder {
+        ElementDefinitionBindingBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionBinding`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionBindingBuilder {
+    inner: ElementDefinitionBinding,
+    strength: Option<types::Code>,
+}
+
+impl ElementDefinitionBindingBuilder {
+    /// Set `ElementDefinition.binding.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.binding.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.binding.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding.strength`.
+    pub fn strength(mut self, value: impl Into<types::Code>) -> Self {
+        self.strength = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding.description`.
+    pub fn description(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.description = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding.valueSet`.
+    pub fn value_set(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.value_set = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.binding.additional`.
+    pub fn additional(
+        mut self,
+        value: impl Into<Vec<ElementDefinitionBindingAdditional>>,
+    ) -> Self {
+        self.inner.additional = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.binding.additional`.
+    pub fn push_additional(
+        mut self,
+        value: impl Into<ElementDefinitionBindingAdditional>,
+    ) -> Self {
+        self.inner.additional.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ElementDefinitionBinding, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.strength {
+            Some(value) => inner.strength = value,
+            None => missing.push("ElementDefinition.binding.strength"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionBinding {
+    const PATH: &'static str = "ElementDefinition.binding";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.strength,
+            &format!("{path}.strength"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.strength_element,
+            &format!("{path}._strength"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.description,
+            &format!("{path}.description"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.description_element,
+            &format!("{path}._description"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_set,
+            &format!("{path}.valueSet"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_set_element,
+            &format!("{path}._valueSet"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.additional,
+            &format!("{path}.additional"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionBinding {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.strength, &format!("{path}.strength"), visitor);
+        types::Walk::accept(
+            &self.strength_element,
+            &format!("{path}._strength"),
+            visitor,
+        );
+        types::Walk::accept(&self.description, &format!("{path}.description"), visitor);
+        types::Walk::accept(
+            &self.description_element,
+            &format!("{path}._description"),
+            visitor,
+        );
+        types::Walk::accept(&self.value_set, &format!("{path}.valueSet"), visitor);
+        types::Walk::accept(
+            &self.value_set_element,
+            &format!("{path}._valueSet"),
+            visitor,
+        );
+        types::Walk::accept(&self.additional, &format!("{path}.additional"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.strength,
+            &format!("{path}.strength"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.strength_element,
+            &format!("{path}._strength"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.description,
+            &format!("{path}.description"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.description_element,
+            &format!("{path}._description"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_set,
+            &format!("{path}.valueSet"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_set_element,
+            &format!("{path}._valueSet"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.additional,
+            &format!("{path}.additional"),
+            visitor,
+        );
+    }
+}
+
+impl ElementDefinitionBinding {
+    /// The metadata of each child element of `ElementDefinition.binding`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "strength",
+            json_key: "strength",
+            rust_field: "strength",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/binding-strength|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "description",
+            json_key: "description",
+            rust_field: "description",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "valueSet",
+            json_key: "valueSet",
+            rust_field: "value_set",
+            min: 0,
+            max: Some(1),
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "additional",
+            json_key: "additional",
+            rust_field: "additional",
+            min: 0,
+            max: None,
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionBinding {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.binding"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "strength" => types::AsValueRef::as_value_ref(&self.strength),
+            "description" => types::AsValueRef::as_value_ref(&self.description),
+            "valueSet" => types::AsValueRef::as_value_ref(&self.value_set),
+            "additional" => types::AsValueRef::as_value_ref(&self.additional),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionBinding {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionBinding {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Additional Bindings - more rules about the binding
+///
+/// Additional bindings that help applications implementing this element. Additional bindings do not replace the main binding but provide more information and/or context.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ElementDefinitionBindingAdditional {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// maximum | minimum | required | extensible | candidate | current | preferred | ui | starter | component
+    ///
+    /// The use of this additional binding.
+    ///
+    /// Comment: Conformance bindings are in addition to the base binding, not instead of it.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/additional-binding-purpose|5.0.0`](http://hl7.org/fhir/ValueSet/additional-binding-purpose)
+    ///
+    /// The use of an additional binding.
+    pub purpose: types::Code,
+
+    /// Id and extensions of `purpose`
+    #[serde(rename = "_purpose")]
+    pub purpose_element: Option<types::Element>,
+
+    /// The value set for the additional binding
+    ///
+    /// The valueSet that is being bound for the purpose.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `canonical(ValueSet)`
+    pub value_set: types::Canonical,
+
+    /// Id and extensions of `valueSet`
+    #[serde(rename = "_valueSet")]
+    pub value_set_element: Option<types::Element>,
+
+    /// Documentation of the purpose of use of the binding
+    ///
+    /// Documentation of the purpose of use of the bindingproviding additional information about how it is intended to be used.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub documentation: Option<types::Markdown>,
+
+    /// Id and extensions of `documentation`
+    #[serde(rename = "_documentation")]
+    pub documentation_element: Option<types::Element>,
+
+    /// Concise documentation - for summary tables
+    ///
+    /// Concise documentation - for summary tables.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub short_doco: Option<types::String>,
+
+    /// Id and extensions of `shortDoco`
+    #[serde(rename = "_shortDoco")]
+    pub short_doco_element: Option<types::Element>,
+
+    /// Qualifies the usage - jurisdiction, gender, workflow status etc.
+    ///
+    /// Qualifies the usage of the binding. Typically bindings are qualified by jurisdiction, but they may also be qualified by gender, workflow status, clinical domain etc. The information to decide whether a usege context applies is usually outside the resource, determined by context, and this might present challenges for validation tooling.
+    ///
+    /// Comment: This specification does not define exactly how the context is determined for the usage; typically, this will be described in implementation guides.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `UsageContext`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub usage: Vec<types::UsageContext>,
+
+    /// Whether binding can applies to all repeats, or just one
+    ///
+    /// Whether the binding applies to all repeats, or just to any one of them. This is only relevant for elements that can repeat.
+    ///
+    /// Comment: A common pattern is to make a conformance binding to all repeats, and then make a binding that one of the repeats must conform to.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `boolean`
+    pub any: Option<types::Boolean>,
+
+    /// Id and extensions of `any`
+    #[serde(rename = "_any")]
+    pub any_element: Option<types::Element>,
+}
+
+impl ElementDefinitionBindingAdditional {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(purpose: types::Code, value_set: types::Canonical) -> Self {
+        Self {
+            purpose,
+            value_set,
+            ..Default::default()
+        }
+    }
+}
+
+impl ElementDefinitionBindingAdditional {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionBindingAdditionalBuilder {
+        ElementDefinitionBindingAdditionalBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionBindingAdditional`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionBindingAdditionalBuilder {
+    inner: ElementDefinitionBindingAdditional,
+    purpose: Option<types::Code>,
+    value_set: Option<types::Canonical>,
+}
+
+impl ElementDefinitionBindingAdditionalBuilder {
+    /// Set `ElementDefinition.binding.additional.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.binding.additional.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.binding.additional.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding.additional.purpose`.
+    pub fn purpose(mut self, value: impl Into<types::Code>) -> Self {
+        self.purpose = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding.additional.valueSet`.
+    pub fn value_set(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.value_set = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding.additional.documentation`.
+    pub fn documentation(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.documentation = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding.additional.shortDoco`.
+    pub fn short_doco(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.short_doco = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.binding.additional.usage`.
+    pub fn usage(mut self, value: impl Into<Vec<types::UsageContext>>) -> Self {
+        self.inner.usage = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.binding.additional.usage`.
+    pub fn push_usage(mut self, value: impl Into<types::UsageContext>) -> Self {
+        self.inner.usage.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.binding.additional.any`.
+    pub fn any(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.any = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(
+        self,
+    ) -> Result<ElementDefinitionBindingAdditional, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.purpose {
+            Some(value) => inner.purpose = value,
+            None => missing.push("ElementDefinition.binding.additional.purpose"),
+        }
+        match self.value_set {
+            Some(value) => inner.value_set = value,
+            None => missing.push("ElementDefinition.binding.additional.valueSet"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionBindingAdditional {
+    const PATH: &'static str = "ElementDefinition.binding.additional";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.purpose,
+            &format!("{path}.purpose"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.purpose_element,
+            &format!("{path}._purpose"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_set,
+            &format!("{path}.valueSet"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.value_set_element,
+            &format!("{path}._valueSet"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.documentation,
+            &format!("{path}.documentation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.documentation_element,
+            &format!("{path}._documentation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.short_doco,
+            &format!("{path}.shortDoco"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.short_doco_element,
+            &format!("{path}._shortDoco"),
+            issues,
+        );
+        types::Validate::validate_into(&self.usage, &format!("{path}.usage"), issues);
+        types::Validate::validate_into(&self.any, &format!("{path}.any"), issues);
+        types::Validate::validate_into(
+            &self.any_element,
+            &format!("{path}._any"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionBindingAdditional {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.purpose, &format!("{path}.purpose"), visitor);
+        types::Walk::accept(&self.purpose_element, &format!("{path}._purpose"), visitor);
+        types::Walk::accept(&self.value_set, &format!("{path}.valueSet"), visitor);
+        types::Walk::accept(
+            &self.value_set_element,
+            &format!("{path}._valueSet"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.documentation,
+            &format!("{path}.documentation"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.documentation_element,
+            &format!("{path}._documentation"),
+            visitor,
+        );
+        types::Walk::accept(&self.short_doco, &format!("{path}.shortDoco"), visitor);
+        types::Walk::accept(
+            &self.short_doco_element,
+            &format!("{path}._shortDoco"),
+            visitor,
+        );
+        types::Walk::accept(&self.usage, &format!("{path}.usage"), visitor);
+        types::Walk::accept(&self.any, &format!("{path}.any"), visitor);
+        types::Walk::accept(&self.any_element, &format!("{path}._any"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.purpose, &format!("{path}.purpose"), visitor);
+        types::Walk::accept_mut(
+            &mut self.purpose_element,
+            &format!("{path}._purpose"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_set,
+            &format!("{path}.valueSet"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.value_set_element,
+            &format!("{path}._valueSet"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.documentation,
+            &format!("{path}.documentation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.documentation_element,
+            &format!("{path}._documentation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.short_doco,
+            &format!("{path}.shortDoco"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.short_doco_element,
+            &format!("{path}._shortDoco"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.usage, &format!("{path}.usage"), visitor);
+        types::Walk::accept_mut(&mut self.any, &format!("{path}.any"), visitor);
+        types::Walk::accept_mut(&mut self.any_element, &format!("{path}._any"), visitor);
+    }
+}
+
+impl ElementDefinitionBindingAdditional {
+    /// The metadata of each child element of `ElementDefinition.binding.additional`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "purpose",
+            json_key: "purpose",
+            rust_field: "purpose",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/additional-binding-purpose|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "valueSet",
+            json_key: "valueSet",
+            rust_field: "value_set",
+            min: 1,
+            max: Some(1),
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "documentation",
+            json_key: "documentation",
+            rust_field: "documentation",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "shortDoco",
+            json_key: "shortDoco",
+            rust_field: "short_doco",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "usage",
+            json_key: "usage",
+            rust_field: "usage",
+            min: 0,
+            max: None,
+            types: &["UsageContext"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "any",
+            json_key: "any",
+            rust_field: "any",
+            min: 0,
+            max: Some(1),
+            types: &["boolean"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionBindingAdditional {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.binding.additional"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "purpose" => types::AsValueRef::as_value_ref(&self.purpose),
+            "valueSet" => types::AsValueRef::as_value_ref(&self.value_set),
+            "documentation" => types::AsValueRef::as_value_ref(&self.documentation),
+            "shortDoco" => types::AsValueRef::as_value_ref(&self.short_doco),
+            "usage" => types::AsValueRef::as_value_ref(&self.usage),
+            "any" => types::AsValueRef::as_value_ref(&self.any),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionBindingAdditional {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionBindingAdditional {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Map element to another set of definitions
+///
+/// Identifies a concept from an external specification that roughly corresponds to this element.
+///
+/// Comment: Mappings are not necessarily specific enough for safe translation.
+///
+/// Requirements: Provides guidance to implementers familiar with or converting content from other specifications.
+///
+/// Cardinality: 0..*
+///
+/// Types: `Element`
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ElementDefinitionMapping {
+    /// Unique id for inter-element referencing
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
+
+    /// Additional content defined by implementations
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
+
+    /// Reference to mapping declaration
+    ///
+    /// An internal reference to the definition of a mapping.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `id`
+    pub identity: types::Id,
+
+    /// Id and extensions of `identity`
+    #[serde(rename = "_identity")]
+    pub identity_element: Option<types::Element>,
+
+    /// Computable language of mapping
+    ///
+    /// Identifies the computable language in which mapping.map is expressed.
+    ///
+    /// Comment: If omitted, then there can be no expectation of computational interpretation of the mapping.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/mimetypes|5.0.0`](http://hl7.org/fhir/ValueSet/mimetypes)
+    ///
+    /// BCP 13 (RFCs 2045, 2046, 2047, 4288, 4289 and 2049)
+    pub language: Option<types::Code>,
+
+    /// Id and extensions of `language`
+    #[serde(rename = "_language")]
+    pub language_element: Option<types::Element>,
+
+    /// Details of the mapping
+    ///
+    /// Expresses what part of the target specification corresponds to this element.
+    ///
+    /// Comment: For most mappings, the syntax is undefined.  Syntax will be provided for mappings to the RIM.  Multiple mappings may be possible and may include constraints on other resource elements that identify when a particular mapping applies.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `string`
+    pub map: types::String,
+
+    /// Id and extensions of `map`
+    #[serde(rename = "_map")]
+    pub map_element: Option<types::Element>,
+
+    /// Comments about the mapping or its use
+    ///
+    /// Comments that provide information about the mapping or its use.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub comment: Option<types::Markdown>,
+
+    /// Id and extensions of `comment`
+    #[serde(rename = "_comment")]
+    pub comment_element: Option<types::Element>,
+}
+
+impl ElementDefinitionMapping {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(identity: types::Id, map: types::String) -> Self {
+        Self {
+            identity,
+            map,
+            ..Default::default()
+        }
+    }
+}
+
+impl ElementDefinitionMapping {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ElementDefinitionMappingBuilder {
+        ElementDefinitionMappingBuilder::default()
+    }
+}
+
+/// Build one `ElementDefinitionMapping`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ElementDefinitionMappingBuilder {
+    inner: ElementDefinitionMapping,
+    identity: Option<types::Id>,
+    map: Option<types::String>,
+}
+
+impl ElementDefinitionMappingBuilder {
+    /// Set `ElementDefinition.mapping.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ElementDefinition.mapping.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ElementDefinition.mapping.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.mapping.identity`.
+    pub fn identity(mut self, value: impl Into<types::Id>) -> Self {
+        self.identity = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.mapping.language`.
+    pub fn language(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.language = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.mapping.map`.
+    pub fn map(mut self, value: impl Into<types::String>) -> Self {
+        self.map = Some(value.into());
+        self
+    }
+
+    /// Set `ElementDefinition.mapping.comment`.
+    pub fn comment(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.comment = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ElementDefinitionMapping, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.identity {
+            Some(value) => inner.identity = value,
+            None => missing.push("ElementDefinition.mapping.identity"),
+        }
+        match self.map {
+            Some(value) => inner.map = value,
+            None => missing.push("ElementDefinition.mapping.map"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ElementDefinitionMapping {
+    const PATH: &'static str = "ElementDefinition.mapping";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.identity,
+            &format!("{path}.identity"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.identity_element,
+            &format!("{path}._identity"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.language,
+            &format!("{path}.language"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.language_element,
+            &format!("{path}._language"),
+            issues,
+        );
+        types::Validate::validate_into(&self.map, &format!("{path}.map"), issues);
+        types::Validate::validate_into(
+            &self.map_element,
+            &format!("{path}._map"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.comment,
+            &format!("{path}.comment"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.comment_element,
+            &format!("{path}._comment"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ElementDefinitionMapping {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.identity, &format!("{path}.identity"), visitor);
+        types::Walk::accept(
+            &self.identity_element,
+            &format!("{path}._identity"),
+            visitor,
+        );
+        types::Walk::accept(&self.language, &format!("{path}.language"), visitor);
+        types::Walk::accept(
+            &self.language_element,
+            &format!("{path}._language"),
+            visitor,
+        );
+        types::Walk::accept(&self.map, &format!("{path}.map"), visitor);
+        types::Walk::accept(&self.map_element, &format!("{path}._map"), visitor);
+        types::Walk::accept(&self.comment, &format!("{path}.comment"), visitor);
+        types::Walk::accept(&self.comment_element, &format!("{path}._comment"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.identity,
+            &format!("{path}.identity"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.identity_element,
+            &format!("{path}._identity"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.language,
+            &format!("{path}.language"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.language_element,
+            &format!("{path}._language"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.map, &format!("{path}.map"), visitor);
+        types::Walk::accept_mut(&mut self.map_element, &format!("{path}._map"), visitor);
+        types::Walk::accept_mut(&mut self.comment, &format!("{path}.comment"), visitor);
+        types::Walk::accept_mut(
+            &mut self.comment_element,
+            &format!("{path}._comment"),
+            visitor,
+        );
+    }
+}
+
+impl ElementDefinitionMapping {
+    /// The metadata of each child element of `ElementDefinition.mapping`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "identity",
+            json_key: "identity",
+            rust_field: "identity",
+            min: 1,
+            max: Some(1),
+            types: &["id"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "language",
+            json_key: "language",
+            rust_field: "language",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/mimetypes|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "map",
+            json_key: "map",
+            rust_field: "map",
+            min: 1,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "comment",
+            json_key: "comment",
+            rust_field: "comment",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ElementDefinitionMapping {
+    fn type_name(&self) -> &'static str {
+        "ElementDefinition.mapping"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "identity" => types::AsValueRef::as_value_ref(&self.identity),
+            "language" => types::AsValueRef::as_value_ref(&self.language),
+            "map" => types::AsValueRef::as_value_ref(&self.map),
+            "comment" => types::AsValueRef::as_value_ref(&self.comment),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionMapping {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ElementDefinitionMapping {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `ElementDefinition.defaultValue[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionDefaultValue {
+    #[serde(rename = "defaultValueBase64Binary")]
+    Base64Binary(types::Base64Binary),
+    #[serde(rename = "defaultValueBoolean")]
+    Boolean(types::Boolean),
+    #[serde(rename = "defaultValueCanonical")]
+    Canonical(types::Canonical),
+    #[serde(rename = "defaultValueCode")]
+    Code(types::Code),
+    #[serde(rename = "defaultValueDate")]
+    Date(types::Date),
+    #[serde(rename = "defaultValueDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "defaultValueDecimal")]
+    Decimal(types::Decimal),
+    #[serde(rename = "defaultValueId")]
+    Id(types::Id),
+    #[serde(rename = "defaultValueInstant")]
+    Instant(types::Instant),
+    #[serde(rename = "defaultValueInteger")]
+    Integer(types::Integer),
+    #[serde(rename = "defaultValueInteger64")]
+    Integer64(types::Integer64),
+    #[serde(rename = "defaultValueMarkdown")]
+    Markdown(types::Markdown),
+    #[serde(rename = "defaultValueOid")]
+    Oid(types::Oid),
+    #[serde(rename = "defaultValuePositiveInt")]
+    PositiveInt(types::PositiveInt),
+    #[serde(rename = "defaultValueString")]
+    String(types::String),
+    #[serde(rename = "defaultValueTime")]
+    Time(types::Time),
+    #[serde(rename = "defaultValueUnsignedInt")]
+    UnsignedInt(types::UnsignedInt),
+    #[serde(rename = "defaultValueUri")]
+    Uri(types::Uri),
+    #[serde(rename = "defaultValueUrl")]
+    Url(types::Url),
+    #[serde(rename = "defaultValueUuid")]
+    Uuid(types::Uuid),
+    #[serde(rename = "defaultValueAddress")]
+    Address(types::Address),
+    #[serde(rename = "defaultValueAge")]
+    Age(types::Age),
+    #[serde(rename = "defaultValueAnnotation")]
+    Annotation(types::Annotation),
+    #[serde(rename = "defaultValueAttachment")]
+    Attachment(types::Attachment),
+    #[serde(rename = "defaultValueCodeableConcept")]
+    CodeableConcept(types::CodeableConcept),
+    #[serde(rename = "defaultValueCodeableReference")]
+    CodeableReference(types::CodeableReference),
+    #[serde(rename = "defaultValueCoding")]
+    Coding(types::Coding),
+    #[serde(rename = "defaultValueContactPoint")]
+    ContactPoint(types::ContactPoint),
+    #[serde(rename = "defaultValueCount")]
+    Count(types::Count),
+    #[serde(rename = "defaultValueDistance")]
+    Distance(types::Distance),
+    #[serde(rename = "defaultValueDuration")]
+    Duration(types::Duration),
+    #[serde(rename = "defaultValueHumanName")]
+    HumanName(types::HumanName),
+    #[serde(rename = "defaultValueIdentifier")]
+    Identifier(types::Identifier),
+    #[serde(rename = "defaultValueMoney")]
+    Money(types::Money),
+    #[serde(rename = "defaultValuePeriod")]
+    Period(types::Period),
+    #[serde(rename = "defaultValueQuantity")]
+    Quantity(types::Quantity),
+    #[serde(rename = "defaultValueRange")]
+    Range(types::Range),
+    #[serde(rename = "defaultValueRatio")]
+    Ratio(types::Ratio),
+    #[serde(rename = "defaultValueRatioRange")]
+    RatioRange(types::RatioRange),
+    #[serde(rename = "defaultValueReference")]
+    Reference(types::Reference),
+    #[serde(rename = "defaultValueSampledData")]
+    SampledData(types::SampledData),
+    #[serde(rename = "defaultValueSignature")]
+    Signature(types::Signature),
+    #[serde(rename = "defaultValueTiming")]
+    Timing(types::Timing),
+    #[serde(rename = "defaultValueContactDetail")]
+    ContactDetail(types::ContactDetail),
+    #[serde(rename = "defaultValueDataRequirement")]
+    DataRequirement(types::DataRequirement),
+    #[serde(rename = "defaultValueExpression")]
+    Expression(types::Expression),
+    #[serde(rename = "defaultValueParameterDefinition")]
+    ParameterDefinition(types::ParameterDefinition),
+    #[serde(rename = "defaultValueRelatedArtifact")]
+    RelatedArtifact(types::RelatedArtifact),
+    #[serde(rename = "defaultValueTriggerDefinition")]
+    TriggerDefinition(types::TriggerDefinition),
+    #[serde(rename = "defaultValueUsageContext")]
+    UsageContext(types::UsageContext),
+    #[serde(rename = "defaultValueAvailability")]
+    Availability(types::Availability),
+    #[serde(rename = "defaultValueExtendedContactDetail")]
+    ExtendedContactDetail(types::ExtendedContactDetail),
+    #[serde(rename = "defaultValueDosage")]
+    Dosage(types::Dosage),
+    #[serde(rename = "defaultValueMeta")]
+    Meta(types::Meta),
+}
+
+impl Default for ElementDefinitionDefaultValue {
+    fn default() -> Self {
+        Self::Base64Binary(Default::default())
+    }
+}
+
+impl types::Choice for ElementDefinitionDefaultValue {
+    const KEYS: &'static [&'static str] = &[
+        "defaultValueBase64Binary",
+        "defaultValueBoolean",
+        "defaultValueCanonical",
+        "defaultValueCode",
+        "defaultValueDate",
+        "defaultValueDateTime",
+        "defaultValueDecimal",
+        "defaultValueId",
+        "defaultValueInstant",
+        "defaultValueInteger",
+        "defaultValueInteger64",
+        "defaultValueMarkdown",
+        "defaultValueOid",
+        "defaultValuePositiveInt",
+        "defaultValueString",
+        "defaultValueTime",
+        "defaultValueUnsignedInt",
+        "defaultValueUri",
+        "defaultValueUrl",
+        "defaultValueUuid",
+        "defaultValueAddress",
+        "defaultValueAge",
+        "defaultValueAnnotation",
+        "defaultValueAttachment",
+        "defaultValueCodeableConcept",
+        "defaultValueCodeableReference",
+        "defaultValueCoding",
+        "defaultValueContactPoint",
+        "defaultValueCount",
+        "defaultValueDistance",
+        "defaultValueDuration",
+        "defaultValueHumanName",
+        "defaultValueIdentifier",
+        "defaultValueMoney",
+        "defaultValuePeriod",
+        "defaultValueQuantity",
+        "defaultValueRange",
+        "defaultValueRatio",
+        "defaultValueRatioRange",
+        "defaultValueReference",
+        "defaultValueSampledData",
+        "defaultValueSignature",
+        "defaultValueTiming",
+        "defaultValueContactDetail",
+        "defaultValueDataRequirement",
+        "defaultValueExpression",
+        "defaultValueParameterDefinition",
+        "defaultValueRelatedArtifact",
+        "defaultValueTriggerDefinition",
+        "defaultValueUsageContext",
+        "defaultValueAvailability",
+        "defaultValueExtendedContactDetail",
+        "defaultValueDosage",
+        "defaultValueMeta",
+    ];
+}
+
+impl types::Validate for ElementDefinitionDefaultValue {
+    const PATH: &'static str = "ElementDefinition.defaultValue";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+            Self::Address(value) => {
+                types::Validate::validate_into(value, &format!("{path}Address"), issues)
+            }
+            Self::Age(value) => {
+                types::Validate::validate_into(value, &format!("{path}Age"), issues)
+            }
+            Self::Annotation(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Annotation"),
+                    issues,
+                )
+            }
+            Self::Attachment(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Attachment"),
+                    issues,
+                )
+            }
+            Self::CodeableConcept(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    issues,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    issues,
+                )
+            }
+            Self::Coding(value) => {
+                types::Validate::validate_into(value, &format!("{path}Coding"), issues)
+            }
+            Self::ContactPoint(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactPoint"),
+                    issues,
+                )
+            }
+            Self::Count(value) => {
+                types::Validate::validate_into(value, &format!("{path}Count"), issues)
+            }
+            Self::Distance(value) => {
+                types::Validate::validate_into(value, &format!("{path}Distance"), issues)
+            }
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+            Self::HumanName(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}HumanName"),
+                    issues,
+                )
+            }
+            Self::Identifier(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Identifier"),
+                    issues,
+                )
+            }
+            Self::Money(value) => {
+                types::Validate::validate_into(value, &format!("{path}Money"), issues)
+            }
+            Self::Period(value) => {
+                types::Validate::validate_into(value, &format!("{path}Period"), issues)
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Ratio(value) => {
+                types::Validate::validate_into(value, &format!("{path}Ratio"), issues)
+            }
+            Self::RatioRange(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RatioRange"),
+                    issues,
+                )
+            }
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+            Self::SampledData(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}SampledData"),
+                    issues,
+                )
+            }
+            Self::Signature(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Signature"),
+                    issues,
+                )
+            }
+            Self::Timing(value) => {
+                types::Validate::validate_into(value, &format!("{path}Timing"), issues)
+            }
+            Self::ContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactDetail"),
+                    issues,
+                )
+            }
+            Self::DataRequirement(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    issues,
+                )
+            }
+            Self::Expression(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Expression"),
+                    issues,
+                )
+            }
+            Self::ParameterDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    issues,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    issues,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    issues,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UsageContext"),
+                    issues,
+                )
+            }
+            Self::Availability(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Availability"),
+                    issues,
+                )
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    issues,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Validate::validate_into(value, &format!("{path}Dosage"), issues)
+            }
+            Self::Meta(value) => {
+                types::Validate::validate_into(value, &format!("{path}Meta"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionDefaultValue {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableConcept"), visitor)
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableReference"), visitor)
+            }
+            Self::Coding(value) => {
+                types::Walk::accept(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept(value, &format!("{path}DataRequirement"), visitor)
+            }
+            Self::Expression(value) => {
+                types::Walk::accept(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept(value, &format!("{path}RelatedArtifact"), visitor)
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept(value, &format!("{path}TriggerDefinition"), visitor)
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    visitor,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    visitor,
+                )
+            }
+            Self::Coding(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept_mut(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept_mut(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept_mut(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    visitor,
+                )
+            }
+            Self::Expression(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    visitor,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    visitor,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionDefaultValue {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Base64Binary(value) => types::AsValueRef::as_value_ref(value),
+            Self::Boolean(value) => types::AsValueRef::as_value_ref(value),
+            Self::Canonical(value) => types::AsValueRef::as_value_ref(value),
+            Self::Code(value) => types::AsValueRef::as_value_ref(value),
+            Self::Date(value) => types::AsValueRef::as_value_ref(value),
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Decimal(value) => types::AsValueRef::as_value_ref(value),
+            Self::Id(value) => types::AsValueRef::as_value_ref(value),
+            Self::Instant(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer64(value) => types::AsValueRef::as_value_ref(value),
+            Self::Markdown(value) => types::AsValueRef::as_value_ref(value),
+            Self::Oid(value) => types::AsValueRef::as_value_ref(value),
+            Self::PositiveInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::String(value) => types::AsValueRef::as_value_ref(value),
+            Self::Time(value) => types::AsValueRef::as_value_ref(value),
+            Self::UnsignedInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uri(value) => types::AsValueRef::as_value_ref(value),
+            Self::Url(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uuid(value) => types::AsValueRef::as_value_ref(value),
+            Self::Address(value) => types::AsValueRef::as_value_ref(value),
+            Self::Age(value) => types::AsValueRef::as_value_ref(value),
+            Self::Annotation(value) => types::AsValueRef::as_value_ref(value),
+            Self::Attachment(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableConcept(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableReference(value) => types::AsValueRef::as_value_ref(value),
+            Self::Coding(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactPoint(value) => types::AsValueRef::as_value_ref(value),
+            Self::Count(value) => types::AsValueRef::as_value_ref(value),
+            Self::Distance(value) => types::AsValueRef::as_value_ref(value),
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+            Self::HumanName(value) => types::AsValueRef::as_value_ref(value),
+            Self::Identifier(value) => types::AsValueRef::as_value_ref(value),
+            Self::Money(value) => types::AsValueRef::as_value_ref(value),
+            Self::Period(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Ratio(value) => types::AsValueRef::as_value_ref(value),
+            Self::RatioRange(value) => types::AsValueRef::as_value_ref(value),
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+            Self::SampledData(value) => types::AsValueRef::as_value_ref(value),
+            Self::Signature(value) => types::AsValueRef::as_value_ref(value),
+            Self::Timing(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::DataRequirement(value) => types::AsValueRef::as_value_ref(value),
+            Self::Expression(value) => types::AsValueRef::as_value_ref(value),
+            Self::ParameterDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::RelatedArtifact(value) => types::AsValueRef::as_value_ref(value),
+            Self::TriggerDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::UsageContext(value) => types::AsValueRef::as_value_ref(value),
+            Self::Availability(value) => types::AsValueRef::as_value_ref(value),
+            Self::ExtendedContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::Dosage(value) => types::AsValueRef::as_value_ref(value),
+            Self::Meta(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `ElementDefinition.defaultValue[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionDefaultValueElement {
+    #[serde(rename = "_defaultValueBase64Binary")]
+    Base64Binary(types::Element),
+    #[serde(rename = "_defaultValueBoolean")]
+    Boolean(types::Element),
+    #[serde(rename = "_defaultValueCanonical")]
+    Canonical(types::Element),
+    #[serde(rename = "_defaultValueCode")]
+    Code(types::Element),
+    #[serde(rename = "_defaultValueDate")]
+    Date(types::Element),
+    #[serde(rename = "_defaultValueDateTime")]
+    DateTime(types::Element),
+    #[serde(rename = "_defaultValueDecimal")]
+    Decimal(types::Element),
+    #[serde(rename = "_defaultValueId")]
+    Id(types::Element),
+    #[serde(rename = "_defaultValueInstant")]
+    Instant(types::Element),
+    #[serde(rename = "_defaultValueInteger")]
+    Integer(types::Element),
+    #[serde(rename = "_defaultValueInteger64")]
+    Integer64(types::Element),
+    #[serde(rename = "_defaultValueMarkdown")]
+    Markdown(types::Element),
+    #[serde(rename = "_defaultValueOid")]
+    Oid(types::Element),
+    #[serde(rename = "_defaultValuePositiveInt")]
+    PositiveInt(types::Element),
+    #[serde(rename = "_defaultValueString")]
+    String(types::Element),
+    #[serde(rename = "_defaultValueTime")]
+    Time(types::Element),
+    #[serde(rename = "_defaultValueUnsignedInt")]
+    UnsignedInt(types::Element),
+    #[serde(rename = "_defaultValueUri")]
+    Uri(types::Element),
+    #[serde(rename = "_defaultValueUrl")]
+    Url(types::Element),
+    #[serde(rename = "_defaultValueUuid")]
+    Uuid(types::Element),
+}
+
+impl types::Choice for ElementDefinitionDefaultValueElement {
+    const KEYS: &'static [&'static str] = &[
+        "_defaultValueBase64Binary",
+        "_defaultValueBoolean",
+        "_defaultValueCanonical",
+        "_defaultValueCode",
+        "_defaultValueDate",
+        "_defaultValueDateTime",
+        "_defaultValueDecimal",
+        "_defaultValueId",
+        "_defaultValueInstant",
+        "_defaultValueInteger",
+        "_defaultValueInteger64",
+        "_defaultValueMarkdown",
+        "_defaultValueOid",
+        "_defaultValuePositiveInt",
+        "_defaultValueString",
+        "_defaultValueTime",
+        "_defaultValueUnsignedInt",
+        "_defaultValueUri",
+        "_defaultValueUrl",
+        "_defaultValueUuid",
+    ];
+}
+
+impl types::Validate for ElementDefinitionDefaultValueElement {
+    const PATH: &'static str = "ElementDefinition.defaultValue";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionDefaultValueElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+}
+
+/// Choice of types for `ElementDefinition.fixed[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionFixed {
+    #[serde(rename = "fixedBase64Binary")]
+    Base64Binary(types::Base64Binary),
+    #[serde(rename = "fixedBoolean")]
+    Boolean(types::Boolean),
+    #[serde(rename = "fixedCanonical")]
+    Canonical(types::Canonical),
+    #[serde(rename = "fixedCode")]
+    Code(types::Code),
+    #[serde(rename = "fixedDate")]
+    Date(types::Date),
+    #[serde(rename = "fixedDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "fixedDecimal")]
+    Decimal(types::Decimal),
+    #[serde(rename = "fixedId")]
+    Id(types::Id),
+    #[serde(rename = "fixedInstant")]
+    Instant(types::Instant),
+    #[serde(rename = "fixedInteger")]
+    Integer(types::Integer),
+    #[serde(rename = "fixedInteger64")]
+    Integer64(types::Integer64),
+    #[serde(rename = "fixedMarkdown")]
+    Markdown(types::Markdown),
+    #[serde(rename = "fixedOid")]
+    Oid(types::Oid),
+    #[serde(rename = "fixedPositiveInt")]
+    PositiveInt(types::PositiveInt),
+    #[serde(rename = "fixedString")]
+    String(types::String),
+    #[serde(rename = "fixedTime")]
+    Time(types::Time),
+    #[serde(rename = "fixedUnsignedInt")]
+    UnsignedInt(types::UnsignedInt),
+    #[serde(rename = "fixedUri")]
+    Uri(types::Uri),
+    #[serde(rename = "fixedUrl")]
+    Url(types::Url),
+    #[serde(rename = "fixedUuid")]
+    Uuid(types::Uuid),
+    #[serde(rename = "fixedAddress")]
+    Address(types::Address),
+    #[serde(rename = "fixedAge")]
+    Age(types::Age),
+    #[serde(rename = "fixedAnnotation")]
+    Annotation(types::Annotation),
+    #[serde(rename = "fixedAttachment")]
+    Attachment(types::Attachment),
+    #[serde(rename = "fixedCodeableConcept")]
+    CodeableConcept(types::CodeableConcept),
+    #[serde(rename = "fixedCodeableReference")]
+    CodeableReference(types::CodeableReference),
+    #[serde(rename = "fixedCoding")]
+    Coding(types::Coding),
+    #[serde(rename = "fixedContactPoint")]
+    ContactPoint(types::ContactPoint),
+    #[serde(rename = "fixedCount")]
+    Count(types::Count),
+    #[serde(rename = "fixedDistance")]
+    Distance(types::Distance),
+    #[serde(rename = "fixedDuration")]
+    Duration(types::Duration),
+    #[serde(rename = "fixedHumanName")]
+    HumanName(types::HumanName),
+    #[serde(rename = "fixedIdentifier")]
+    Identifier(types::Identifier),
+    #[serde(rename = "fixedMoney")]
+    Money(types::Money),
+    #[serde(rename = "fixedPeriod")]
+    Period(types::Period),
+    #[serde(rename = "fixedQuantity")]
+    Quantity(types::Quantity),
+    #[serde(rename = "fixedRange")]
+    Range(types::Range),
+    #[serde(rename = "fixedRatio")]
+    Ratio(types::Ratio),
+    #[serde(rename = "fixedRatioRange")]
+    RatioRange(types::RatioRange),
+    #[serde(rename = "fixedReference")]
+    Reference(types::Reference),
+    #[serde(rename = "fixedSampledData")]
+    SampledData(types::SampledData),
+    #[serde(rename = "fixedSignature")]
+    Signature(types::Signature),
+    #[serde(rename = "fixedTiming")]
+    Timing(types::Timing),
+    #[serde(rename = "fixedContactDetail")]
+    ContactDetail(types::ContactDetail),
+    #[serde(rename = "fixedDataRequirement")]
+    DataRequirement(types::DataRequirement),
+    #[serde(rename = "fixedExpression")]
+    Expression(types::Expression),
+    #[serde(rename = "fixedParameterDefinition")]
+    ParameterDefinition(types::ParameterDefinition),
+    #[serde(rename = "fixedRelatedArtifact")]
+    RelatedArtifact(types::RelatedArtifact),
+    #[serde(rename = "fixedTriggerDefinition")]
+    TriggerDefinition(types::TriggerDefinition),
+    #[serde(rename = "fixedUsageContext")]
+    UsageContext(types::UsageContext),
+    #[serde(rename = "fixedAvailability")]
+    Availability(types::Availability),
+    #[serde(rename = "fixedExtendedContactDetail")]
+    ExtendedContactDetail(types::ExtendedContactDetail),
+    #[serde(rename = "fixedDosage")]
+    Dosage(types::Dosage),
+    #[serde(rename = "fixedMeta")]
+    Meta(types::Meta),
+}
+
+impl Default for ElementDefinitionFixed {
+    fn default() -> Self {
+        Self::Base64Binary(Default::default())
+    }
+}
+
+impl types::Choice for ElementDefinitionFixed {
+    const KEYS: &'static [&'static str] = &[
+        "fixedBase64Binary",
+        "fixedBoolean",
+        "fixedCanonical",
+        "fixedCode",
+        "fixedDate",
+        "fixedDateTime",
+        "fixedDecimal",
+        "fixedId",
+        "fixedInstant",
+        "fixedInteger",
+        "fixedInteger64",
+        "fixedMarkdown",
+        "fixedOid",
+        "fixedPositiveInt",
+        "fixedString",
+        "fixedTime",
+        "fixedUnsignedInt",
+        "fixedUri",
+        "fixedUrl",
+        "fixedUuid",
+        "fixedAddress",
+        "fixedAge",
+        "fixedAnnotation",
+        "fixedAttachment",
+        "fixedCodeableConcept",
+        "fixedCodeableReference",
+        "fixedCoding",
+        "fixedContactPoint",
+        "fixedCount",
+        "fixedDistance",
+        "fixedDuration",
+        "fixedHumanName",
+        "fixedIdentifier",
+        "fixedMoney",
+        "fixedPeriod",
+        "fixedQuantity",
+        "fixedRange",
+        "fixedRatio",
+        "fixedRatioRange",
+        "fixedReference",
+        "fixedSampledData",
+        "fixedSignature",
+        "fixedTiming",
+        "fixedContactDetail",
+        "fixedDataRequirement",
+        "fixedExpression",
+        "fixedParameterDefinition",
+        "fixedRelatedArtifact",
+        "fixedTriggerDefinition",
+        "fixedUsageContext",
+        "fixedAvailability",
+        "fixedExtendedContactDetail",
+        "fixedDosage",
+        "fixedMeta",
+    ];
+}
+
+impl types::Validate for ElementDefinitionFixed {
+    const PATH: &'static str = "ElementDefinition.fixed";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+            Self::Address(value) => {
+                types::Validate::validate_into(value, &format!("{path}Address"), issues)
+            }
+            Self::Age(value) => {
+                types::Validate::validate_into(value, &format!("{path}Age"), issues)
+            }
+            Self::Annotation(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Annotation"),
+                    issues,
+                )
+            }
+            Self::Attachment(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Attachment"),
+                    issues,
+                )
+            }
+            Self::CodeableConcept(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    issues,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    issues,
+                )
+            }
+            Self::Coding(value) => {
+                types::Validate::validate_into(value, &format!("{path}Coding"), issues)
+            }
+            Self::ContactPoint(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactPoint"),
+                    issues,
+                )
+            }
+            Self::Count(value) => {
+                types::Validate::validate_into(value, &format!("{path}Count"), issues)
+            }
+            Self::Distance(value) => {
+                types::Validate::validate_into(value, &format!("{path}Distance"), issues)
+            }
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+            Self::HumanName(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}HumanName"),
+                    issues,
+                )
+            }
+            Self::Identifier(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Identifier"),
+                    issues,
+                )
+            }
+            Self::Money(value) => {
+                types::Validate::validate_into(value, &format!("{path}Money"), issues)
+            }
+            Self::Period(value) => {
+                types::Validate::validate_into(value, &format!("{path}Period"), issues)
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Ratio(value) => {
+                types::Validate::validate_into(value, &format!("{path}Ratio"), issues)
+            }
+            Self::RatioRange(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RatioRange"),
+                    issues,
+                )
+            }
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+            Self::SampledData(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}SampledData"),
+                    issues,
+                )
+            }
+            Self::Signature(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Signature"),
+                    issues,
+                )
+            }
+            Self::Timing(value) => {
+                types::Validate::validate_into(value, &format!("{path}Timing"), issues)
+            }
+            Self::ContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactDetail"),
+                    issues,
+                )
+            }
+            Self::DataRequirement(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    issues,
+                )
+            }
+            Self::Expression(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Expression"),
+                    issues,
+                )
+            }
+            Self::ParameterDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    issues,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    issues,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    issues,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UsageContext"),
+                    issues,
+                )
+            }
+            Self::Availability(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Availability"),
+                    issues,
+                )
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    issues,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Validate::validate_into(value, &format!("{path}Dosage"), issues)
+            }
+            Self::Meta(value) => {
+                types::Validate::validate_into(value, &format!("{path}Meta"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionFixed {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableConcept"), visitor)
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableReference"), visitor)
+            }
+            Self::Coding(value) => {
+                types::Walk::accept(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept(value, &format!("{path}DataRequirement"), visitor)
+            }
+            Self::Expression(value) => {
+                types::Walk::accept(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept(value, &format!("{path}RelatedArtifact"), visitor)
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept(value, &format!("{path}TriggerDefinition"), visitor)
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    visitor,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    visitor,
+                )
+            }
+            Self::Coding(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept_mut(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept_mut(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept_mut(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    visitor,
+                )
+            }
+            Self::Expression(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    visitor,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    visitor,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionFixed {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Base64Binary(value) => types::AsValueRef::as_value_ref(value),
+            Self::Boolean(value) => types::AsValueRef::as_value_ref(value),
+            Self::Canonical(value) => types::AsValueRef::as_value_ref(value),
+            Self::Code(value) => types::AsValueRef::as_value_ref(value),
+            Self::Date(value) => types::AsValueRef::as_value_ref(value),
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Decimal(value) => types::AsValueRef::as_value_ref(value),
+            Self::Id(value) => types::AsValueRef::as_value_ref(value),
+            Self::Instant(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer64(value) => types::AsValueRef::as_value_ref(value),
+            Self::Markdown(value) => types::AsValueRef::as_value_ref(value),
+            Self::Oid(value) => types::AsValueRef::as_value_ref(value),
+            Self::PositiveInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::String(value) => types::AsValueRef::as_value_ref(value),
+            Self::Time(value) => types::AsValueRef::as_value_ref(value),
+            Self::UnsignedInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uri(value) => types::AsValueRef::as_value_ref(value),
+            Self::Url(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uuid(value) => types::AsValueRef::as_value_ref(value),
+            Self::Address(value) => types::AsValueRef::as_value_ref(value),
+            Self::Age(value) => types::AsValueRef::as_value_ref(value),
+            Self::Annotation(value) => types::AsValueRef::as_value_ref(value),
+            Self::Attachment(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableConcept(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableReference(value) => types::AsValueRef::as_value_ref(value),
+            Self::Coding(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactPoint(value) => types::AsValueRef::as_value_ref(value),
+            Self::Count(value) => types::AsValueRef::as_value_ref(value),
+            Self::Distance(value) => types::AsValueRef::as_value_ref(value),
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+            Self::HumanName(value) => types::AsValueRef::as_value_ref(value),
+            Self::Identifier(value) => types::AsValueRef::as_value_ref(value),
+            Self::Money(value) => types::AsValueRef::as_value_ref(value),
+            Self::Period(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Ratio(value) => types::AsValueRef::as_value_ref(value),
+            Self::RatioRange(value) => types::AsValueRef::as_value_ref(value),
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+            Self::SampledData(value) => types::AsValueRef::as_value_ref(value),
+            Self::Signature(value) => types::AsValueRef::as_value_ref(value),
+            Self::Timing(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::DataRequirement(value) => types::AsValueRef::as_value_ref(value),
+            Self::Expression(value) => types::AsValueRef::as_value_ref(value),
+            Self::ParameterDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::RelatedArtifact(value) => types::AsValueRef::as_value_ref(value),
+            Self::TriggerDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::UsageContext(value) => types::AsValueRef::as_value_ref(value),
+            Self::Availability(value) => types::AsValueRef::as_value_ref(value),
+            Self::ExtendedContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::Dosage(value) => types::AsValueRef::as_value_ref(value),
+            Self::Meta(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `ElementDefinition.fixed[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionFixedElement {
+    #[serde(rename = "_fixedBase64Binary")]
+    Base64Binary(types::Element),
+    #[serde(rename = "_fixedBoolean")]
+    Boolean(types::Element),
+    #[serde(rename = "_fixedCanonical")]
+    Canonical(types::Element),
+    #[serde(rename = "_fixedCode")]
+    Code(types::Element),
+    #[serde(rename = "_fixedDate")]
+    Date(types::Element),
+    #[serde(rename = "_fixedDateTime")]
+    DateTime(types::Element),
+    #[serde(rename = "_fixedDecimal")]
+    Decimal(types::Element),
+    #[serde(rename = "_fixedId")]
+    Id(types::Element),
+    #[serde(rename = "_fixedInstant")]
+    Instant(types::Element),
+    #[serde(rename = "_fixedInteger")]
+    Integer(types::Element),
+    #[serde(rename = "_fixedInteger64")]
+    Integer64(types::Element),
+    #[serde(rename = "_fixedMarkdown")]
+    Markdown(types::Element),
+    #[serde(rename = "_fixedOid")]
+    Oid(types::Element),
+    #[serde(rename = "_fixedPositiveInt")]
+    PositiveInt(types::Element),
+    #[serde(rename = "_fixedString")]
+    String(types::Element),
+    #[serde(rename = "_fixedTime")]
+    Time(types::Element),
+    #[serde(rename = "_fixedUnsignedInt")]
+    UnsignedInt(types::Element),
+    #[serde(rename = "_fixedUri")]
+    Uri(types::Element),
+    #[serde(rename = "_fixedUrl")]
+    Url(types::Element),
+    #[serde(rename = "_fixedUuid")]
+    Uuid(types::Element),
+}
+
+impl types::Choice for ElementDefinitionFixedElement {
+    const KEYS: &'static [&'static str] = &[
+        "_fixedBase64Binary",
+        "_fixedBoolean",
+        "_fixedCanonical",
+        "_fixedCode",
+        "_fixedDate",
+        "_fixedDateTime",
+        "_fixedDecimal",
+        "_fixedId",
+        "_fixedInstant",
+        "_fixedInteger",
+        "_fixedInteger64",
+        "_fixedMarkdown",
+        "_fixedOid",
+        "_fixedPositiveInt",
+        "_fixedString",
+        "_fixedTime",
+        "_fixedUnsignedInt",
+        "_fixedUri",
+        "_fixedUrl",
+        "_fixedUuid",
+    ];
+}
+
+impl types::Validate for ElementDefinitionFixedElement {
+    const PATH: &'static str = "ElementDefinition.fixed";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionFixedElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+}
+
+/// Choice of types for `ElementDefinition.pattern[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionPattern {
+    #[serde(rename = "patternBase64Binary")]
+    Base64Binary(types::Base64Binary),
+    #[serde(rename = "patternBoolean")]
+    Boolean(types::Boolean),
+    #[serde(rename = "patternCanonical")]
+    Canonical(types::Canonical),
+    #[serde(rename = "patternCode")]
+    Code(types::Code),
+    #[serde(rename = "patternDate")]
+    Date(types::Date),
+    #[serde(rename = "patternDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "patternDecimal")]
+    Decimal(types::Decimal),
+    #[serde(rename = "patternId")]
+    Id(types::Id),
+    #[serde(rename = "patternInstant")]
+    Instant(types::Instant),
+    #[serde(rename = "patternInteger")]
+    Integer(types::Integer),
+    #[serde(rename = "patternInteger64")]
+    Integer64(types::Integer64),
+    #[serde(rename = "patternMarkdown")]
+    Markdown(types::Markdown),
+    #[serde(rename = "patternOid")]
+    Oid(types::Oid),
+    #[serde(rename = "patternPositiveInt")]
+    PositiveInt(types::PositiveInt),
+    #[serde(rename = "patternString")]
+    String(types::String),
+    #[serde(rename = "patternTime")]
+    Time(types::Time),
+    #[serde(rename = "patternUnsignedInt")]
+    UnsignedInt(types::UnsignedInt),
+    #[serde(rename = "patternUri")]
+    Uri(types::Uri),
+    #[serde(rename = "patternUrl")]
+    Url(types::Url),
+    #[serde(rename = "patternUuid")]
+    Uuid(types::Uuid),
+    #[serde(rename = "patternAddress")]
+    Address(types::Address),
+    #[serde(rename = "patternAge")]
+    Age(types::Age),
+    #[serde(rename = "patternAnnotation")]
+    Annotation(types::Annotation),
+    #[serde(rename = "patternAttachment")]
+    Attachment(types::Attachment),
+    #[serde(rename = "patternCodeableConcept")]
+    CodeableConcept(types::CodeableConcept),
+    #[serde(rename = "patternCodeableReference")]
+    CodeableReference(types::CodeableReference),
+    #[serde(rename = "patternCoding")]
+    Coding(types::Coding),
+    #[serde(rename = "patternContactPoint")]
+    ContactPoint(types::ContactPoint),
+    #[serde(rename = "patternCount")]
+    Count(types::Count),
+    #[serde(rename = "patternDistance")]
+    Distance(types::Distance),
+    #[serde(rename = "patternDuration")]
+    Duration(types::Duration),
+    #[serde(rename = "patternHumanName")]
+    HumanName(types::HumanName),
+    #[serde(rename = "patternIdentifier")]
+    Identifier(types::Identifier),
+    #[serde(rename = "patternMoney")]
+    Money(types::Money),
+    #[serde(rename = "patternPeriod")]
+    Period(types::Period),
+    #[serde(rename = "patternQuantity")]
+    Quantity(types::Quantity),
+    #[serde(rename = "patternRange")]
+    Range(types::Range),
+    #[serde(rename = "patternRatio")]
+    Ratio(types::Ratio),
+    #[serde(rename = "patternRatioRange")]
+    RatioRange(types::RatioRange),
+    #[serde(rename = "patternReference")]
+    Reference(types::Reference),
+    #[serde(rename = "patternSampledData")]
+    SampledData(types::SampledData),
+    #[serde(rename = "patternSignature")]
+    Signature(types::Signature),
+    #[serde(rename = "patternTiming")]
+    Timing(types::Timing),
+    #[serde(rename = "patternContactDetail")]
+    ContactDetail(types::ContactDetail),
+    #[serde(rename = "patternDataRequirement")]
+    DataRequirement(types::DataRequirement),
+    #[serde(rename = "patternExpression")]
+    Expression(types::Expression),
+    #[serde(rename = "patternParameterDefinition")]
+    ParameterDefinition(types::ParameterDefinition),
+    #[serde(rename = "patternRelatedArtifact")]
+    RelatedArtifact(types::RelatedArtifact),
+    #[serde(rename = "patternTriggerDefinition")]
+    TriggerDefinition(types::TriggerDefinition),
+    #[serde(rename = "patternUsageContext")]
+    UsageContext(types::UsageContext),
+    #[serde(rename = "patternAvailability")]
+    Availability(types::Availability),
+    #[serde(rename = "patternExtendedContactDetail")]
+    ExtendedContactDetail(types::ExtendedContactDetail),
+    #[serde(rename = "patternDosage")]
+    Dosage(types::Dosage),
+    #[serde(rename = "patternMeta")]
+    Meta(types::Meta),
+}
+
+impl Default for ElementDefinitionPattern {
+    fn default() -> Self {
+        Self::Base64Binary(Default::default())
+    }
+}
+
+impl types::Choice for ElementDefinitionPattern {
+    const KEYS: &'static [&'static str] = &[
+        "patternBase64Binary",
+        "patternBoolean",
+        "patternCanonical",
+        "patternCode",
+        "patternDate",
+        "patternDateTime",
+        "patternDecimal",
+        "patternId",
+        "patternInstant",
+        "patternInteger",
+        "patternInteger64",
+        "patternMarkdown",
+        "patternOid",
+        "patternPositiveInt",
+        "patternString",
+        "patternTime",
+        "patternUnsignedInt",
+        "patternUri",
+        "patternUrl",
+        "patternUuid",
+        "patternAddress",
+        "patternAge",
+        "patternAnnotation",
+        "patternAttachment",
+        "patternCodeableConcept",
+        "patternCodeableReference",
+        "patternCoding",
+        "patternContactPoint",
+        "patternCount",
+        "patternDistance",
+        "patternDuration",
+        "patternHumanName",
+        "patternIdentifier",
+        "patternMoney",
+        "patternPeriod",
+        "patternQuantity",
+        "patternRange",
+        "patternRatio",
+        "patternRatioRange",
+        "patternReference",
+        "patternSampledData",
+        "patternSignature",
+        "patternTiming",
+        "patternContactDetail",
+        "patternDataRequirement",
+        "patternExpression",
+        "patternParameterDefinition",
+        "patternRelatedArtifact",
+        "patternTriggerDefinition",
+        "patternUsageContext",
+        "patternAvailability",
+        "patternExtendedContactDetail",
+        "patternDosage",
+        "patternMeta",
+    ];
+}
+
+impl types::Validate for ElementDefinitionPattern {
+    const PATH: &'static str = "ElementDefinition.pattern";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+            Self::Address(value) => {
+                types::Validate::validate_into(value, &format!("{path}Address"), issues)
+            }
+            Self::Age(value) => {
+                types::Validate::validate_into(value, &format!("{path}Age"), issues)
+            }
+            Self::Annotation(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Annotation"),
+                    issues,
+                )
+            }
+            Self::Attachment(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Attachment"),
+                    issues,
+                )
+            }
+            Self::CodeableConcept(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    issues,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    issues,
+                )
+            }
+            Self::Coding(value) => {
+                types::Validate::validate_into(value, &format!("{path}Coding"), issues)
+            }
+            Self::ContactPoint(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactPoint"),
+                    issues,
+                )
+            }
+            Self::Count(value) => {
+                types::Validate::validate_into(value, &format!("{path}Count"), issues)
+            }
+            Self::Distance(value) => {
+                types::Validate::validate_into(value, &format!("{path}Distance"), issues)
+            }
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+            Self::HumanName(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}HumanName"),
+                    issues,
+                )
+            }
+            Self::Identifier(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Identifier"),
+                    issues,
+                )
+            }
+            Self::Money(value) => {
+                types::Validate::validate_into(value, &format!("{path}Money"), issues)
+            }
+            Self::Period(value) => {
+                types::Validate::validate_into(value, &format!("{path}Period"), issues)
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Ratio(value) => {
+                types::Validate::validate_into(value, &format!("{path}Ratio"), issues)
+            }
+            Self::RatioRange(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RatioRange"),
+                    issues,
+                )
+            }
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+            Self::SampledData(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}SampledData"),
+                    issues,
+                )
+            }
+            Self::Signature(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Signature"),
+                    issues,
+                )
+            }
+            Self::Timing(value) => {
+                types::Validate::validate_into(value, &format!("{path}Timing"), issues)
+            }
+            Self::ContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactDetail"),
+                    issues,
+                )
+            }
+            Self::DataRequirement(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    issues,
+                )
+            }
+            Self::Expression(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Expression"),
+                    issues,
+                )
+            }
+            Self::ParameterDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    issues,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    issues,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    issues,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UsageContext"),
+                    issues,
+                )
+            }
+            Self::Availability(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Availability"),
+                    issues,
+                )
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    issues,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Validate::validate_into(value, &format!("{path}Dosage"), issues)
+            }
+            Self::Meta(value) => {
+                types::Validate::validate_into(value, &format!("{path}Meta"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionPattern {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableConcept"), visitor)
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableReference"), visitor)
+            }
+            Self::Coding(value) => {
+                types::Walk::accept(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept(value, &format!("{path}DataRequirement"), visitor)
+            }
+            Self::Expression(value) => {
+                types::Walk::accept(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept(value, &format!("{path}RelatedArtifact"), visitor)
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept(value, &format!("{path}TriggerDefinition"), visitor)
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    visitor,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    visitor,
+                )
+            }
+            Self::Coding(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept_mut(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept_mut(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept_mut(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    visitor,
+                )
+            }
+            Self::Expression(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    visitor,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    visitor,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionPattern {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Base64Binary(value) => types::AsValueRef::as_value_ref(value),
+            Self::Boolean(value) => types::AsValueRef::as_value_ref(value),
+            Self::Canonical(value) => types::AsValueRef::as_value_ref(value),
+            Self::Code(value) => types::AsValueRef::as_value_ref(value),
+            Self::Date(value) => types::AsValueRef::as_value_ref(value),
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Decimal(value) => types::AsValueRef::as_value_ref(value),
+            Self::Id(value) => types::AsValueRef::as_value_ref(value),
+            Self::Instant(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer64(value) => types::AsValueRef::as_value_ref(value),
+            Self::Markdown(value) => types::AsValueRef::as_value_ref(value),
+            Self::Oid(value) => types::AsValueRef::as_value_ref(value),
+            Self::PositiveInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::String(value) => types::AsValueRef::as_value_ref(value),
+            Self::Time(value) => types::AsValueRef::as_value_ref(value),
+            Self::UnsignedInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uri(value) => types::AsValueRef::as_value_ref(value),
+            Self::Url(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uuid(value) => types::AsValueRef::as_value_ref(value),
+            Self::Address(value) => types::AsValueRef::as_value_ref(value),
+            Self::Age(value) => types::AsValueRef::as_value_ref(value),
+            Self::Annotation(value) => types::AsValueRef::as_value_ref(value),
+            Self::Attachment(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableConcept(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableReference(value) => types::AsValueRef::as_value_ref(value),
+            Self::Coding(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactPoint(value) => types::AsValueRef::as_value_ref(value),
+            Self::Count(value) => types::AsValueRef::as_value_ref(value),
+            Self::Distance(value) => types::AsValueRef::as_value_ref(value),
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+            Self::HumanName(value) => types::AsValueRef::as_value_ref(value),
+            Self::Identifier(value) => types::AsValueRef::as_value_ref(value),
+            Self::Money(value) => types::AsValueRef::as_value_ref(value),
+            Self::Period(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Ratio(value) => types::AsValueRef::as_value_ref(value),
+            Self::RatioRange(value) => types::AsValueRef::as_value_ref(value),
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+            Self::SampledData(value) => types::AsValueRef::as_value_ref(value),
+            Self::Signature(value) => types::AsValueRef::as_value_ref(value),
+            Self::Timing(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::DataRequirement(value) => types::AsValueRef::as_value_ref(value),
+            Self::Expression(value) => types::AsValueRef::as_value_ref(value),
+            Self::ParameterDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::RelatedArtifact(value) => types::AsValueRef::as_value_ref(value),
+            Self::TriggerDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::UsageContext(value) => types::AsValueRef::as_value_ref(value),
+            Self::Availability(value) => types::AsValueRef::as_value_ref(value),
+            Self::ExtendedContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::Dosage(value) => types::AsValueRef::as_value_ref(value),
+            Self::Meta(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `ElementDefinition.pattern[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionPatternElement {
+    #[serde(rename = "_patternBase64Binary")]
+    Base64Binary(types::Element),
+    #[serde(rename = "_patternBoolean")]
+    Boolean(types::Element),
+    #[serde(rename = "_patternCanonical")]
+    Canonical(types::Element),
+    #[serde(rename = "_patternCode")]
+    Code(types::Element),
+    #[serde(rename = "_patternDate")]
+    Date(types::Element),
+    #[serde(rename = "_patternDateTime")]
+    DateTime(types::Element),
+    #[serde(rename = "_patternDecimal")]
+    Decimal(types::Element),
+    #[serde(rename = "_patternId")]
+    Id(types::Element),
+    #[serde(rename = "_patternInstant")]
+    Instant(types::Element),
+    #[serde(rename = "_patternInteger")]
+    Integer(types::Element),
+    #[serde(rename = "_patternInteger64")]
+    Integer64(types::Element),
+    #[serde(rename = "_patternMarkdown")]
+    Markdown(types::Element),
+    #[serde(rename = "_patternOid")]
+    Oid(types::Element),
+    #[serde(rename = "_patternPositiveInt")]
+    PositiveInt(types::Element),
+    #[serde(rename = "_patternString")]
+    String(types::Element),
+    #[serde(rename = "_patternTime")]
+    Time(types::Element),
+    #[serde(rename = "_patternUnsignedInt")]
+    UnsignedInt(types::Element),
+    #[serde(rename = "_patternUri")]
+    Uri(types::Element),
+    #[serde(rename = "_patternUrl")]
+    Url(types::Element),
+    #[serde(rename = "_patternUuid")]
+    Uuid(types::Element),
+}
+
+impl types::Choice for ElementDefinitionPatternElement {
+    const KEYS: &'static [&'static str] = &[
+        "_patternBase64Binary",
+        "_patternBoolean",
+        "_patternCanonical",
+        "_patternCode",
+        "_patternDate",
+        "_patternDateTime",
+        "_patternDecimal",
+        "_patternId",
+        "_patternInstant",
+        "_patternInteger",
+        "_patternInteger64",
+        "_patternMarkdown",
+        "_patternOid",
+        "_patternPositiveInt",
+        "_patternString",
+        "_patternTime",
+        "_patternUnsignedInt",
+        "_patternUri",
+        "_patternUrl",
+        "_patternUuid",
+    ];
+}
+
+impl types::Validate for ElementDefinitionPatternElement {
+    const PATH: &'static str = "ElementDefinition.pattern";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionPatternElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+}
+
+/// Choice of types for `ElementDefinition.example.value[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionExampleValue {
+    #[serde(rename = "valueBase64Binary")]
+    Base64Binary(types::Base64Binary),
+    #[serde(rename = "valueBoolean")]
+    Boolean(types::Boolean),
+    #[serde(rename = "valueCanonical")]
+    Canonical(types::Canonical),
+    #[serde(rename = "valueCode")]
+    Code(types::Code),
+    #[serde(rename = "valueDate")]
+    Date(types::Date),
+    #[serde(rename = "valueDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "valueDecimal")]
+    Decimal(types::Decimal),
+    #[serde(rename = "valueId")]
+    Id(types::Id),
+    #[serde(rename = "valueInstant")]
+    Instant(types::Instant),
+    #[serde(rename = "valueInteger")]
+    Integer(types::Integer),
+    #[serde(rename = "valueInteger64")]
+    Integer64(types::Integer64),
+    #[serde(rename = "valueMarkdown")]
+    Markdown(types::Markdown),
+    #[serde(rename = "valueOid")]
+    Oid(types::Oid),
+    #[serde(rename = "valuePositiveInt")]
+    PositiveInt(types::PositiveInt),
+    #[serde(rename = "valueString")]
+    String(types::String),
+    #[serde(rename = "valueTime")]
+    Time(types::Time),
+    #[serde(rename = "valueUnsignedInt")]
+    UnsignedInt(types::UnsignedInt),
+    #[serde(rename = "valueUri")]
+    Uri(types::Uri),
+    #[serde(rename = "valueUrl")]
+    Url(types::Url),
+    #[serde(rename = "valueUuid")]
+    Uuid(types::Uuid),
+    #[serde(rename = "valueAddress")]
+    Address(types::Address),
+    #[serde(rename = "valueAge")]
+    Age(types::Age),
+    #[serde(rename = "valueAnnotation")]
+    Annotation(types::Annotation),
+    #[serde(rename = "valueAttachment")]
+    Attachment(types::Attachment),
+    #[serde(rename = "valueCodeableConcept")]
+    CodeableConcept(types::CodeableConcept),
+    #[serde(rename = "valueCodeableReference")]
+    CodeableReference(types::CodeableReference),
+    #[serde(rename = "valueCoding")]
+    Coding(types::Coding),
+    #[serde(rename = "valueContactPoint")]
+    ContactPoint(types::ContactPoint),
+    #[serde(rename = "valueCount")]
+    Count(types::Count),
+    #[serde(rename = "valueDistance")]
+    Distance(types::Distance),
+    #[serde(rename = "valueDuration")]
+    Duration(types::Duration),
+    #[serde(rename = "valueHumanName")]
+    HumanName(types::HumanName),
+    #[serde(rename = "valueIdentifier")]
+    Identifier(types::Identifier),
+    #[serde(rename = "valueMoney")]
+    Money(types::Money),
+    #[serde(rename = "valuePeriod")]
+    Period(types::Period),
+    #[serde(rename = "valueQuantity")]
+    Quantity(types::Quantity),
+    #[serde(rename = "valueRange")]
+    Range(types::Range),
+    #[serde(rename = "valueRatio")]
+    Ratio(types::Ratio),
+    #[serde(rename = "valueRatioRange")]
+    RatioRange(types::RatioRange),
+    #[serde(rename = "valueReference")]
+    Reference(types::Reference),
+    #[serde(rename = "valueSampledData")]
+    SampledData(types::SampledData),
+    #[serde(rename = "valueSignature")]
+    Signature(types::Signature),
+    #[serde(rename = "valueTiming")]
+    Timing(types::Timing),
+    #[serde(rename = "valueContactDetail")]
+    ContactDetail(types::ContactDetail),
+    #[serde(rename = "valueDataRequirement")]
+    DataRequirement(types::DataRequirement),
+    #[serde(rename = "valueExpression")]
+    Expression(types::Expression),
+    #[serde(rename = "valueParameterDefinition")]
+    ParameterDefinition(types::ParameterDefinition),
+    #[serde(rename = "valueRelatedArtifact")]
+    RelatedArtifact(types::RelatedArtifact),
+    #[serde(rename = "valueTriggerDefinition")]
+    TriggerDefinition(types::TriggerDefinition),
+    #[serde(rename = "valueUsageContext")]
+    UsageContext(types::UsageContext),
+    #[serde(rename = "valueAvailability")]
+    Availability(types::Availability),
+    #[serde(rename = "valueExtendedContactDetail")]
+    ExtendedContactDetail(types::ExtendedContactDetail),
+    #[serde(rename = "valueDosage")]
+    Dosage(types::Dosage),
+    #[serde(rename = "valueMeta")]
+    Meta(types::Meta),
+}
+
+impl Default for ElementDefinitionExampleValue {
+    fn default() -> Self {
+        Self::Base64Binary(Default::default())
+    }
+}
+
+impl types::Choice for ElementDefinitionExampleValue {
+    const KEYS: &'static [&'static str] = &[
+        "valueBase64Binary",
+        "valueBoolean",
+        "valueCanonical",
+        "valueCode",
+        "valueDate",
+        "valueDateTime",
+        "valueDecimal",
+        "valueId",
+        "valueInstant",
+        "valueInteger",
+        "valueInteger64",
+        "valueMarkdown",
+        "valueOid",
+        "valuePositiveInt",
+        "valueString",
+        "valueTime",
+        "valueUnsignedInt",
+        "valueUri",
+        "valueUrl",
+        "valueUuid",
+        "valueAddress",
+        "valueAge",
+        "valueAnnotation",
+        "valueAttachment",
+        "valueCodeableConcept",
+        "valueCodeableReference",
+        "valueCoding",
+        "valueContactPoint",
+        "valueCount",
+        "valueDistance",
+        "valueDuration",
+        "valueHumanName",
+        "valueIdentifier",
+        "valueMoney",
+        "valuePeriod",
+        "valueQuantity",
+        "valueRange",
+        "valueRatio",
+        "valueRatioRange",
+        "valueReference",
+        "valueSampledData",
+        "valueSignature",
+        "valueTiming",
+        "valueContactDetail",
+        "valueDataRequirement",
+        "valueExpression",
+        "valueParameterDefinition",
+        "valueRelatedArtifact",
+        "valueTriggerDefinition",
+        "valueUsageContext",
+        "valueAvailability",
+        "valueExtendedContactDetail",
+        "valueDosage",
+        "valueMeta",
+    ];
+}
+
+impl types::Validate for ElementDefinitionExampleValue {
+    const PATH: &'static str = "ElementDefinition.example.value";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+            Self::Address(value) => {
+                types::Validate::validate_into(value, &format!("{path}Address"), issues)
+            }
+            Self::Age(value) => {
+                types::Validate::validate_into(value, &format!("{path}Age"), issues)
+            }
+            Self::Annotation(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Annotation"),
+                    issues,
+                )
+            }
+            Self::Attachment(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Attachment"),
+                    issues,
+                )
+            }
+            Self::CodeableConcept(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    issues,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    issues,
+                )
+            }
+            Self::Coding(value) => {
+                types::Validate::validate_into(value, &format!("{path}Coding"), issues)
+            }
+            Self::ContactPoint(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactPoint"),
+                    issues,
+                )
+            }
+            Self::Count(value) => {
+                types::Validate::validate_into(value, &format!("{path}Count"), issues)
+            }
+            Self::Distance(value) => {
+                types::Validate::validate_into(value, &format!("{path}Distance"), issues)
+            }
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+            Self::HumanName(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}HumanName"),
+                    issues,
+                )
+            }
+            Self::Identifier(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Identifier"),
+                    issues,
+                )
+            }
+            Self::Money(value) => {
+                types::Validate::validate_into(value, &format!("{path}Money"), issues)
+            }
+            Self::Period(value) => {
+                types::Validate::validate_into(value, &format!("{path}Period"), issues)
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Ratio(value) => {
+                types::Validate::validate_into(value, &format!("{path}Ratio"), issues)
+            }
+            Self::RatioRange(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RatioRange"),
+                    issues,
+                )
+            }
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+            Self::SampledData(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}SampledData"),
+                    issues,
+                )
+            }
+            Self::Signature(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Signature"),
+                    issues,
+                )
+            }
+            Self::Timing(value) => {
+                types::Validate::validate_into(value, &format!("{path}Timing"), issues)
+            }
+            Self::ContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactDetail"),
+                    issues,
+                )
+            }
+            Self::DataRequirement(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    issues,
+                )
+            }
+            Self::Expression(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Expression"),
+                    issues,
+                )
+            }
+            Self::ParameterDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    issues,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    issues,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    issues,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UsageContext"),
+                    issues,
+                )
+            }
+            Self::Availability(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Availability"),
+                    issues,
+                )
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    issues,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Validate::validate_into(value, &format!("{path}Dosage"), issues)
+            }
+            Self::Meta(value) => {
+                types::Validate::validate_into(value, &format!("{path}Meta"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionExampleValue {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableConcept"), visitor)
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableReference"), visitor)
+            }
+            Self::Coding(value) => {
+                types::Walk::accept(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept(value, &format!("{path}DataRequirement"), visitor)
+            }
+            Self::Expression(value) => {
+                types::Walk::accept(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept(value, &format!("{path}RelatedArtifact"), visitor)
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept(value, &format!("{path}TriggerDefinition"), visitor)
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    visitor,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    visitor,
+                )
+            }
+            Self::Coding(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept_mut(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept_mut(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept_mut(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    visitor,
+                )
+            }
+            Self::Expression(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    visitor,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    visitor,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionExampleValue {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Base64Binary(value) => types::AsValueRef::as_value_ref(value),
+            Self::Boolean(value) => types::AsValueRef::as_value_ref(value),
+            Self::Canonical(value) => types::AsValueRef::as_value_ref(value),
+            Self::Code(value) => types::AsValueRef::as_value_ref(value),
+            Self::Date(value) => types::AsValueRef::as_value_ref(value),
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Decimal(value) => types::AsValueRef::as_value_ref(value),
+            Self::Id(value) => types::AsValueRef::as_value_ref(value),
+            Self::Instant(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer64(value) => types::AsValueRef::as_value_ref(value),
+            Self::Markdown(value) => types::AsValueRef::as_value_ref(value),
+            Self::Oid(value) => types::AsValueRef::as_value_ref(value),
+            Self::PositiveInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::String(value) => types::AsValueRef::as_value_ref(value),
+            Self::Time(value) => types::AsValueRef::as_value_ref(value),
+            Self::UnsignedInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uri(value) => types::AsValueRef::as_value_ref(value),
+            Self::Url(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uuid(value) => types::AsValueRef::as_value_ref(value),
+            Self::Address(value) => types::AsValueRef::as_value_ref(value),
+            Self::Age(value) => types::AsValueRef::as_value_ref(value),
+            Self::Annotation(value) => types::AsValueRef::as_value_ref(value),
+            Self::Attachment(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableConcept(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableReference(value) => types::AsValueRef::as_value_ref(value),
+            Self::Coding(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactPoint(value) => types::AsValueRef::as_value_ref(value),
+            Self::Count(value) => types::AsValueRef::as_value_ref(value),
+            Self::Distance(value) => types::AsValueRef::as_value_ref(value),
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+            Self::HumanName(value) => types::AsValueRef::as_value_ref(value),
+            Self::Identifier(value) => types::AsValueRef::as_value_ref(value),
+            Self::Money(value) => types::AsValueRef::as_value_ref(value),
+            Self::Period(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Ratio(value) => types::AsValueRef::as_value_ref(value),
+            Self::RatioRange(value) => types::AsValueRef::as_value_ref(value),
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+            Self::SampledData(value) => types::AsValueRef::as_value_ref(value),
+            Self::Signature(value) => types::AsValueRef::as_value_ref(value),
+            Self::Timing(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::DataRequirement(value) => types::AsValueRef::as_value_ref(value),
+            Self::Expression(value) => types::AsValueRef::as_value_ref(value),
+            Self::ParameterDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::RelatedArtifact(value) => types::AsValueRef::as_value_ref(value),
+            Self::TriggerDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::UsageContext(value) => types::AsValueRef::as_value_ref(value),
+            Self::Availability(value) => types::AsValueRef::as_value_ref(value),
+            Self::ExtendedContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::Dosage(value) => types::AsValueRef::as_value_ref(value),
+            Self::Meta(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `ElementDefinition.example.value[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionExampleValueElement {
+    #[serde(rename = "_valueBase64Binary")]
+    Base64Binary(types::Element),
+    #[serde(rename = "_valueBoolean")]
+    Boolean(types::Element),
+    #[serde(rename = "_valueCanonical")]
+    Canonical(types::Element),
+    #[serde(rename = "_valueCode")]
+    Code(types::Element),
+    #[serde(rename = "_valueDate")]
+    Date(types::Element),
+    #[serde(rename = "_valueDateTime")]
+    DateTime(types::Element),
+    #[serde(rename = "_valueDecimal")]
+    Decimal(types::Element),
+    #[serde(rename = "_valueId")]
+    Id(types::Element),
+    #[serde(rename = "_valueInstant")]
+    Instant(types::Element),
+    #[serde(rename = "_valueInteger")]
+    Integer(types::Element),
+    #[serde(rename = "_valueInteger64")]
+    Integer64(types::Element),
+    #[serde(rename = "_valueMarkdown")]
+    Markdown(types::Element),
+    #[serde(rename = "_valueOid")]
+    Oid(types::Element),
+    #[serde(rename = "_valuePositiveInt")]
+    PositiveInt(types::Element),
+    #[serde(rename = "_valueString")]
+    String(types::Element),
+    #[serde(rename = "_valueTime")]
+    Time(types::Element),
+    #[serde(rename = "_valueUnsignedInt")]
+    UnsignedInt(types::Element),
+    #[serde(rename = "_valueUri")]
+    Uri(types::Element),
+    #[serde(rename = "_valueUrl")]
+    Url(types::Element),
+    #[serde(rename = "_valueUuid")]
+    Uuid(types::Element),
+}
+
+impl types::Choice for ElementDefinitionExampleValueElement {
+    const KEYS: &'static [&'static str] = &[
+        "_valueBase64Binary",
+        "_valueBoolean",
+        "_valueCanonical",
+        "_valueCode",
+        "_valueDate",
+        "_valueDateTime",
+        "_valueDecimal",
+        "_valueId",
+        "_valueInstant",
+        "_valueInteger",
+        "_valueInteger64",
+        "_valueMarkdown",
+        "_valueOid",
+        "_valuePositiveInt",
+        "_valueString",
+        "_valueTime",
+        "_valueUnsignedInt",
+        "_valueUri",
+        "_valueUrl",
+        "_valueUuid",
+    ];
+}
+
+impl types::Validate for ElementDefinitionExampleValueElement {
+    const PATH: &'static str = "ElementDefinition.example.value";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionExampleValueElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+}
+
+/// Choice of types for `ElementDefinition.minValue[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionMinValue {
+    #[serde(rename = "minValueDate")]
+    Date(types::Date),
+    #[serde(rename = "minValueDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "minValueInstant")]
+    Instant(types::Instant),
+    #[serde(rename = "minValueTime")]
+    Time(types::Time),
+    #[serde(rename = "minValueDecimal")]
+    Decimal(types::Decimal),
+    #[serde(rename = "minValueInteger")]
+    Integer(types::Integer),
+    #[serde(rename = "minValueInteger64")]
+    Integer64(types::Integer64),
+    #[serde(rename = "minValuePositiveInt")]
+    PositiveInt(types::PositiveInt),
+    #[serde(rename = "minValueUnsignedInt")]
+    UnsignedInt(types::UnsignedInt),
+    #[serde(rename = "minValueQuantity")]
+    Quantity(types::Quantity),
+}
+
+impl Default for ElementDefinitionMinValue {
+    fn default() -> Self {
+        Self::Date(Default::default())
+    }
+}
+
+impl types::Choice for ElementDefinitionMinValue {
+    const KEYS: &'static [&'static str] = &[
+        "minValueDate",
+        "minValueDateTime",
+        "minValueInstant",
+        "minValueTime",
+        "minValueDecimal",
+        "minValueInteger",
+        "minValueInteger64",
+        "minValuePositiveInt",
+        "minValueUnsignedInt",
+        "minValueQuantity",
+    ];
+}
+
+impl types::Validate for ElementDefinitionMinValue {
+    const PATH: &'static str = "ElementDefinition.minValue";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionMinValue {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionMinValue {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Date(value) => types::AsValueRef::as_value_ref(value),
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Instant(value) => types::AsValueRef::as_value_ref(value),
+            Self::Time(value) => types::AsValueRef::as_value_ref(value),
+            Self::Decimal(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer64(value) => types::AsValueRef::as_value_ref(value),
+            Self::PositiveInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::UnsignedInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `ElementDefinition.minValue[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionMinValueElement {
+    #[serde(rename = "_minValueDate")]
+    Date(types::Element),
+    #[serde(rename = "_minValueDateTime")]
+    DateTime(types::Element),
+    #[serde(rename = "_minValueInstant")]
+    Instant(types::Element),
+    #[serde(rename = "_minValueTime")]
+    Time(types::Element),
+    #[serde(rename = "_minValueDecimal")]
+    Decimal(types::Element),
+    #[serde(rename = "_minValueInteger")]
+    Integer(types::Element),
+    #[serde(rename = "_minValueInteger64")]
+    Integer64(types::Element),
+    #[serde(rename = "_minValuePositiveInt")]
+    PositiveInt(types::Element),
+    #[serde(rename = "_minValueUnsignedInt")]
+    UnsignedInt(types::Element),
+}
+
+impl types::Choice for ElementDefinitionMinValueElement {
+    const KEYS: &'static [&'static str] = &[
+        "_minValueDate",
+        "_minValueDateTime",
+        "_minValueInstant",
+        "_minValueTime",
+        "_minValueDecimal",
+        "_minValueInteger",
+        "_minValueInteger64",
+        "_minValuePositiveInt",
+        "_minValueUnsignedInt",
+    ];
+}
+
+impl types::Validate for ElementDefinitionMinValueElement {
+    const PATH: &'static str = "ElementDefinition.minValue";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionMinValueElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+        }
+    }
+}
+
+/// Choice of types for `ElementDefinition.maxValue[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionMaxValue {
+    #[serde(rename = "maxValueDate")]
+    Date(types::Date),
+    #[serde(rename = "maxValueDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "maxValueInstant")]
+    Instant(types::Instant),
+    #[serde(rename = "maxValueTime")]
+    Time(types::Time),
+    #[serde(rename = "maxValueDecimal")]
+    Decimal(types::Decimal),
+    #[serde(rename = "maxValueInteger")]
+    Integer(types::Integer),
+    #[serde(rename = "maxValueInteger64")]
+    Integer64(types::Integer64),
+    #[serde(rename = "maxValuePositiveInt")]
+    PositiveInt(types::PositiveInt),
+    #[serde(rename = "maxValueUnsignedInt")]
+    UnsignedInt(types::UnsignedInt),
+    #[serde(rename = "maxValueQuantity")]
+    Quantity(types::Quantity),
+}
+
+impl Default for ElementDefinitionMaxValue {
+    fn default() -> Self {
+        Self::Date(Default::default())
+    }
+}
+
+impl types::Choice for ElementDefinitionMaxValue {
+    const KEYS: &'static [&'static str] = &[
+        "maxValueDate",
+        "maxValueDateTime",
+        "maxValueInstant",
+        "maxValueTime",
+        "maxValueDecimal",
+        "maxValueInteger",
+        "maxValueInteger64",
+        "maxValuePositiveInt",
+        "maxValueUnsignedInt",
+        "maxValueQuantity",
+    ];
+}
+
+impl types::Validate for ElementDefinitionMaxValue {
+    const PATH: &'static str = "ElementDefinition.maxValue";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionMaxValue {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for ElementDefinitionMaxValue {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Date(value) => types::AsValueRef::as_value_ref(value),
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Instant(value) => types::AsValueRef::as_value_ref(value),
+            Self::Time(value) => types::AsValueRef::as_value_ref(value),
+            Self::Decimal(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer64(value) => types::AsValueRef::as_value_ref(value),
+            Self::PositiveInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::UnsignedInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `ElementDefinition.maxValue[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ElementDefinitionMaxValueElement {
+    #[serde(rename = "_maxValueDate")]
+    Date(types::Element),
+    #[serde(rename = "_maxValueDateTime")]
+    DateTime(types::Element),
+    #[serde(rename = "_maxValueInstant")]
+    Instant(types::Element),
+    #[serde(rename = "_maxValueTime")]
+    Time(types::Element),
+    #[serde(rename = "_maxValueDecimal")]
+    Decimal(types::Element),
+    #[serde(rename = "_maxValueInteger")]
+    Integer(types::Element),
+    #[serde(rename = "_maxValueInteger64")]
+    Integer64(types::Element),
+    #[serde(rename = "_maxValuePositiveInt")]
+    PositiveInt(types::Element),
+    #[serde(rename = "_maxValueUnsignedInt")]
+    UnsignedInt(types::Element),
+}
+
+impl types::Choice for ElementDefinitionMaxValueElement {
+    const KEYS: &'static [&'static str] = &[
+        "_maxValueDate",
+        "_maxValueDateTime",
+        "_maxValueInstant",
+        "_maxValueTime",
+        "_maxValueDecimal",
+        "_maxValueInteger",
+        "_maxValueInteger64",
+        "_maxValuePositiveInt",
+        "_maxValueUnsignedInt",
+    ];
+}
+
+impl types::Validate for ElementDefinitionMaxValueElement {
+    const PATH: &'static str = "ElementDefinition.maxValue";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+        }
+    }
+}
+
+impl types::Walk for ElementDefinitionMaxValueElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ElementDefinition;
+
+    #[test]
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["path"];
+        assert_eq!(keys, expect);
+    }
+
+    mod serde_json {
+        use super::*;
+
+        #[test]
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
+            let actual: T = ::serde_json::from_value(json).expect("from_value");
+            let expect: T = T::default();
             assert_eq!(actual, expect);
         }
     }
@@ -1,6 +1,6 @@
 //! Expression
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Expression
+//! URL: <http://hl7.org/fhir/StructureDefinition/Expression>
 //!
 //! Version: 5.0.0
 //!
@@ -10,72 +10,519 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// An expression that can be used to generate a value
+///
+/// A expression that is evaluated in a specified context and returns a value. The context of use of the expression must specify the context in which the expression is evaluated, and how the result of the expression is used.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `exp-1`: An expression or a reference must be provided
+/// - `exp-2`: The name must be a valid variable name in most computer languages
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Expression {
-    /// An expression that can be used to generate a value
-    Expression: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Natural language description of the condition
-    description: ? // string
+    ///
+    /// A brief, natural language description of the condition that effectively communicates the intended semantics.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub description: Option<types::String>,
+
+    /// Id and extensions of `description`
+    #[serde(rename = "_description")]
+    pub description_element: Option<types::Element>,
 
     /// Short name assigned to expression for reuse
-    name: ? // code
+    ///
+    /// A short name assigned to the expression to allow for multiple reuse of the expression in the context where it is defined.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    pub name: Option<types::Code>,
+
+    /// Id and extensions of `name`
+    #[serde(rename = "_name")]
+    pub name_element: Option<types::Element>,
 
     /// text/cql | text/fhirpath | application/x-fhir-query | etc.
-    language: ? // code
+    ///
+    /// The media type of the language for the expression.
+    ///
+    /// Requirements: The cardinality of this element is optional to allow text only as part of the authoring process.  However, in order to be executable, the expression element must be specified.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: extensible to [`http://hl7.org/fhir/ValueSet/expression-language`](http://hl7.org/fhir/ValueSet/expression-language)
+    ///
+    /// The media type of the expression language.
+    pub language: Option<types::Code>,
+
+    /// Id and extensions of `language`
+    #[serde(rename = "_language")]
+    pub language_element: Option<types::Element>,
 
     /// Expression in specified language
-    expression: ? // string
+    ///
+    /// An expression in the specified language that returns a value.
+    ///
+    /// Comment: If Expression.expression and Expression.reference are both present, the Expression.expression might just be a name pointing something within the referenced content.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub expression: Option<types::String>,
+
+    /// Id and extensions of `expression`
+    #[serde(rename = "_expression")]
+    pub expression_element: Option<types::Element>,
 
     /// Where the expression is found
-    reference: ? // uri
+    ///
+    /// A URI that defines where the expression is found.
+    ///
+    /// Comment: If both a reference and an expression is found, the reference SHALL point to the same expression.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `uri`
+    pub reference: Option<types::Uri>,
+
+    /// Id and extensions of `reference`
+    #[serde(rename = "_reference")]
+    pub reference_element: Option<types::Element>,
+}
+
+impl Expression {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Expression {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ExpressionBuilder {
+        ExpressionBuilder::default()
+    }
+}
 
+/// Build one `Expression`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ExpressionBuilder {
+    inner: Expression,
+}
+
+impl ExpressionBuilder {
+    /// Set `Expression.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Expression.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Expression.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Expression.description`.
+    pub fn description(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.description = Some(value.into());
+        self
+    }
+
+    /// Set `Expression.name`.
+    pub fn name(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.name = Some(value.into());
+        self
+    }
+
+    /// Set `Expression.language`.
+    pub fn language(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.language = Some(value.into());
+        self
+    }
+
+    /// Set `Expression.expression`.
+    pub fn expression(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.expression = Some(value.into());
+        self
+    }
+
+    /// Set `Expression.reference`.
+    pub fn reference(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.reference = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Expression, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Expression {
+    const PATH: &'static str = "Expression";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.description,
+            &format!("{path}.description"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.description_element,
+            &format!("{path}._description"),
+            issues,
+        );
+        types::Validate::validate_into(&self.name, &format!("{path}.name"), issues);
+        types::Validate::validate_into(
+            &self.name_element,
+            &format!("{path}._name"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.language,
+            &format!("{path}.language"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.language_element,
+            &format!("{path}._language"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.expression,
+            &format!("{path}.expression"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.expression_element,
+            &format!("{path}._expression"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.reference,
+            &format!("{path}.reference"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.reference_element,
+            &format!("{path}._reference"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Expression {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_expression(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.description, &format!("{path}.description"), visitor);
+        types::Walk::accept(
+            &self.description_element,
+            &format!("{path}._description"),
+            visitor,
+        );
+        types::Walk::accept(&self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept(&self.name_element, &format!("{path}._name"), visitor);
+        types::Walk::accept(&self.language, &format!("{path}.language"), visitor);
+        types::Walk::accept(
+            &self.language_element,
+            &format!("{path}._language"),
+            visitor,
+        );
+        types::Walk::accept(&self.expression, &format!("{path}.expression"), visitor);
+        types::Walk::accept(
+            &self.expression_element,
+            &format!("{path}._expression"),
+            visitor,
+        );
+        types::Walk::accept(&self.reference, &format!("{path}.reference"), visitor);
+        types::Walk::accept(
+            &self.reference_element,
+            &format!("{path}._reference"),
+            visitor,
+        );
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_expression_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.description,
+            &format!("{path}.description"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.description_element,
+            &format!("{path}._description"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept_mut(
+            &mut self.name_element,
+            &format!("{path}._name"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.language,
+            &format!("{path}.language"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.language_element,
+            &format!("{path}._language"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.expression,
+            &format!("{path}.expression"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.expression_element,
+            &format!("{path}._expression"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.reference,
+            &format!("{path}.reference"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.reference_element,
+            &format!("{path}._reference"),
+            visitor,
+        );
+    }
+}
+
+impl Expression {
+    /// The metadata of each child element of `Expression`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "description",
+            json_key: "description",
+            rust_field: "description",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "name",
+            json_key: "name",
+            rust_field: "name",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "language",
+            json_key: "language",
+            rust_field: "language",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "extensible",
+                value_set: Some("http://hl7.org/fhir/ValueSet/expression-language"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "expression",
+            json_key: "expression",
+            rust_field: "expression",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "reference",
+            json_key: "reference",
+            rust_field: "reference",
+            min: 0,
+            max: Some(1),
+            types: &["uri"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Expression {
+    fn type_name(&self) -> &'static str {
+        "Expression"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "description" => types::AsValueRef::as_value_ref(&self.description),
+            "name" => types::AsValueRef::as_value_ref(&self.name),
+            "language" => types::AsValueRef::as_value_ref(&self.language),
+            "expression" => types::AsValueRef::as_value_ref(&self.expression),
+            "reference" => types::AsValueRef::as_value_ref(&self.reference),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Expression {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Expression {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Expression;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
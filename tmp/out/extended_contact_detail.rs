@@ -1,6 +1,6 @@
 //! ExtendedContactDetail
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/ExtendedContactDetail
+//! URL: <http://hl7.org/fhir/StructureDefinition/ExtendedContactDetail>
 //!
 //! Version: 5.0.0
 //!
@@ -10,75 +10,465 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Contact information
+///
+/// Specifies contact information for a specific purpose over a period of time, might be handled/monitored by a specific named person or organization.
+///
+/// Comment: This datatype may be sparsely populated, i.e. only contain a purpose and phone number or address, but other cases could be completed filled out.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct ExtendedContactDetail {
-    /// Contact information
-    ExtendedContactDetail: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// The type of contact
-    purpose: ? // CodeableConcept
+    ///
+    /// The purpose/type of contact.
+    ///
+    /// Comment: If no purpose is defined, then these contact details may be used for any purpose.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: preferred to [`http://terminology.hl7.org/ValueSet/contactentity-type`](http://terminology.hl7.org/ValueSet/contactentity-type)
+    ///
+    /// The purpose for which an extended contact detail should be used.
+    pub purpose: Option<types::CodeableConcept>,
 
     /// Name of an individual to contact
-    name: ? // HumanName
+    ///
+    /// The name of an individual to contact, some types of contact detail are usually blank.
+    ///
+    /// Comment: If there is no named individual, the telecom/address information is not generally monitored by a specific individual.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `HumanName`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub name: Vec<types::HumanName>,
 
     /// Contact details (e.g.phone/fax/url)
-    telecom: ? // ContactPoint
+    ///
+    /// The contact details application for the purpose defined.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `ContactPoint`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub telecom: Vec<types::ContactPoint>,
 
     /// Address for the contact
-    address: ? // Address
+    ///
+    /// Address for the contact.
+    ///
+    /// Comment: More than 1 address would be for different purposes, and thus should be entered as a different entry,.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Address`
+    pub address: Option<types::Address>,
 
     /// This contact detail is handled/monitored by a specific organization
-    organization: ? // Reference
+    ///
+    /// This contact detail is handled/monitored by a specific organization. If the name is provided in the contact, then it is referring to the named individual within this organization.
+    ///
+    /// Requirements: Some specific types of contact information can be an handled by an organization (eg legal council is via a specific firm).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Reference(Organization)`
+    pub organization: Option<types::Reference>,
 
     /// Period that this contact was valid for usage
-    period: ? // Period
+    ///
+    /// Period that this contact was valid for usage.
+    ///
+    /// Comment: If the details have multiple periods, then enter in a new ExtendedContact with the new period.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Period`
+    pub period: Option<types::Period>,
+}
+
+impl ExtendedContactDetail {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ExtendedContactDetail {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ExtendedContactDetailBuilder {
+        ExtendedContactDetailBuilder::default()
+    }
+}
+
+/// Build one `ExtendedContactDetail`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ExtendedContactDetailBuilder {
+    inner: ExtendedContactDetail,
+}
+
+impl ExtendedContactDetailBuilder {
+    /// Set `ExtendedContactDetail.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ExtendedContactDetail.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ExtendedContactDetail.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ExtendedContactDetail.purpose`.
+    pub fn purpose(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.purpose = Some(value.into());
+        self
+    }
+
+    /// Set every `ExtendedContactDetail.name`.
+    pub fn name(mut self, value: impl Into<Vec<types::HumanName>>) -> Self {
+        self.inner.name = value.into();
+        self
+    }
+
+    /// Add one `ExtendedContactDetail.name`.
+    pub fn push_name(mut self, value: impl Into<types::HumanName>) -> Self {
+        self.inner.name.push(value.into());
+        self
+    }
+
+    /// Set every `ExtendedContactDetail.telecom`.
+    pub fn telecom(mut self, value: impl Into<Vec<types::ContactPoint>>) -> Self {
+        self.inner.telecom = value.into();
+        self
+    }
+
+    /// Add one `ExtendedContactDetail.telecom`.
+    pub fn push_telecom(mut self, value: impl Into<types::ContactPoint>) -> Self {
+        self.inner.telecom.push(value.into());
+        self
+    }
+
+    /// Set `ExtendedContactDetail.address`.
+    pub fn address(mut self, value: impl Into<types::Address>) -> Self {
+        self.inner.address = Some(value.into());
+        self
+    }
+
+    /// Set `ExtendedContactDetail.organization`.
+    pub fn organization(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.organization = Some(value.into());
+        self
+    }
+
+    /// Set `ExtendedContactDetail.period`.
+    pub fn period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.period = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ExtendedContactDetail, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for ExtendedContactDetail {
+    const PATH: &'static str = "ExtendedContactDetail";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.purpose,
+            &format!("{path}.purpose"),
+            issues,
+        );
+        types::Validate::validate_into(&self.name, &format!("{path}.name"), issues);
+        types::Validate::validate_into(
+            &self.telecom,
+            &format!("{path}.telecom"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.address,
+            &format!("{path}.address"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.organization,
+            &format!("{path}.organization"),
+            issues,
+        );
+        types::Validate::validate_into(&self.period, &format!("{path}.period"), issues);
+    }
+}
+
+impl types::Walk for ExtendedContactDetail {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_extended_contact_detail(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.purpose, &format!("{path}.purpose"), visitor);
+        types::Walk::accept(&self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept(&self.telecom, &format!("{path}.telecom"), visitor);
+        types::Walk::accept(&self.address, &format!("{path}.address"), visitor);
+        types::Walk::accept(
+            &self.organization,
+            &format!("{path}.organization"),
+            visitor,
+        );
+        types::Walk::accept(&self.period, &format!("{path}.period"), visitor);
+    }
 
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_extended_contact_detail_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.purpose, &format!("{path}.purpose"), visitor);
+        types::Walk::accept_mut(&mut self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept_mut(&mut self.telecom, &format!("{path}.telecom"), visitor);
+        types::Walk::accept_mut(&mut self.address, &format!("{path}.address"), visitor);
+        types::Walk::accept_mut(
+            &mut self.organization,
+            &format!("{path}.organization"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.period, &format!("{path}.period"), visitor);
+    }
+}
+
+impl ExtendedContactDetail {
+    /// The metadata of each child element of `ExtendedContactDetail`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "purpose",
+            json_key: "purpose",
+            rust_field: "purpose",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "preferred",
+                value_set: Some("http://terminology.hl7.org/ValueSet/contactentity-type"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "name",
+            json_key: "name",
+            rust_field: "name",
+            min: 0,
+            max: None,
+            types: &["HumanName"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "telecom",
+            json_key: "telecom",
+            rust_field: "telecom",
+            min: 0,
+            max: None,
+            types: &["ContactPoint"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "address",
+            json_key: "address",
+            rust_field: "address",
+            min: 0,
+            max: Some(1),
+            types: &["Address"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "organization",
+            json_key: "organization",
+            rust_field: "organization",
+            min: 0,
+            max: Some(1),
+            types: &["Reference"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "period",
+            json_key: "period",
+            rust_field: "period",
+            min: 0,
+            max: Some(1),
+            types: &["Period"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ExtendedContactDetail {
+    fn type_name(&self) -> &'static str {
+        "ExtendedContactDetail"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "purpose" => types::AsValueRef::as_value_ref(&self.purpose),
+            "name" => types::AsValueRef::as_value_ref(&self.name),
+            "telecom" => types::AsValueRef::as_value_ref(&self.telecom),
+            "address" => types::AsValueRef::as_value_ref(&self.address),
+            "organization" => types::AsValueRef::as_value_ref(&self.organization),
+            "period" => types::AsValueRef::as_value_ref(&self.period),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ExtendedContactDetail {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ExtendedContactDetail {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = ExtendedContactDetail;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
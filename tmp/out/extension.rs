@@ -1,6 +1,6 @@
 //! Extension
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Extension
+//! URL: <http://hl7.org/fhir/StructureDefinition/Extension>
 //!
 //! Version: 5.0.0
 //!
@@ -10,63 +10,1897 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Optional Extensions Element
+///
+/// Optional Extension Element - found in all resources.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `ext-1`: Must have either extensions or value\[x\], not both
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
 pub struct Extension {
-    /// Optional Extensions Element
-    Extension: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// identifies the meaning of the extension
-    url: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Source of the definition for the extension code - a logical name or a URL.
+    ///
+    /// Comment: The definition may point directly to a computable or human-readable definition of the extensibility codes, or it may be a logical URI as declared in some other specification. The definition SHALL be a URI for the Structure Definition defining the extension.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `String`
+    pub url: String,
 
     /// Value of extension
-    : ? // base64Binary
+    ///
+    /// Value of extension - must be one of a constrained set of the data types (see [Extensibility](https://build.fhir.org/extensibility.html) for a list).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `base64Binary`, `boolean`, `canonical`, `code`, `date`, `dateTime`, `decimal`, `id`, `instant`, `integer`, `integer64`, `markdown`, `oid`, `positiveInt`, `string`, `time`, `unsignedInt`, `uri`, `url`, `uuid`, `Address`, `Age`, `Annotation`, `Attachment`, `CodeableConcept`, `CodeableReference`, `Coding`, `ContactPoint`, `Count`, `Distance`, `Duration`, `HumanName`, `Identifier`, `Money`, `Period`, `Quantity`, `Range`, `Ratio`, `RatioRange`, `Reference`, `SampledData`, `Signature`, `Timing`, `ContactDetail`, `DataRequirement`, `Expression`, `ParameterDefinition`, `RelatedArtifact`, `TriggerDefinition`, `UsageContext`, `Availability`, `ExtendedContactDetail`, `Dosage`, `Meta`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub value: Option<ExtensionValue>,
 
+    /// Id and extensions of `value[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub value_element: Option<ExtensionValueElement>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl Extension {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(url: String) -> Self {
+        Self { url, ..Default::default() }
+    }
+}
+
+impl Extension {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ExtensionBuilder {
+        ExtensionBuilder::default()
+    }
+}
+
+/// Build one `Extension`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ExtensionBuilder {
+    inner: Extension,
+    url: Option<String>,
+}
+
+impl ExtensionBuilder {
+    /// Set `Extension.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Extension.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Extension.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Extension.url`.
+    pub fn url(mut self, value: impl Into<String>) -> Self {
+        self.url = Some(value.into());
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `base64Binary`.
+    pub fn value_base_64_binary(
+        mut self,
+        value: impl Into<types::Base64Binary>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::Base64Binary(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `boolean`.
+    pub fn value_boolean(mut self, value: impl Into<types::Boolean>) -> Self {
+        self.inner.value = Some(ExtensionValue::Boolean(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `canonical`.
+    pub fn value_canonical(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.value = Some(ExtensionValue::Canonical(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `code`.
+    pub fn value_code(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.value = Some(ExtensionValue::Code(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `date`.
+    pub fn value_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.inner.value = Some(ExtensionValue::Date(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `dateTime`.
+    pub fn value_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.value = Some(ExtensionValue::DateTime(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `decimal`.
+    pub fn value_decimal(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.value = Some(ExtensionValue::Decimal(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `id`.
+    pub fn value_id(mut self, value: impl Into<types::Id>) -> Self {
+        self.inner.value = Some(ExtensionValue::Id(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `instant`.
+    pub fn value_instant(mut self, value: impl Into<types::Instant>) -> Self {
+        self.inner.value = Some(ExtensionValue::Instant(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `integer`.
+    pub fn value_integer(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.value = Some(ExtensionValue::Integer(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `integer64`.
+    pub fn value_integer_64(mut self, value: impl Into<types::Integer64>) -> Self {
+        self.inner.value = Some(ExtensionValue::Integer64(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `markdown`.
+    pub fn value_markdown(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.value = Some(ExtensionValue::Markdown(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `oid`.
+    pub fn value_oid(mut self, value: impl Into<types::Oid>) -> Self {
+        self.inner.value = Some(ExtensionValue::Oid(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `positiveInt`.
+    pub fn value_positive_int(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.value = Some(ExtensionValue::PositiveInt(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `string`.
+    pub fn value_string(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.value = Some(ExtensionValue::String(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `time`.
+    pub fn value_time(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.value = Some(ExtensionValue::Time(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `unsignedInt`.
+    pub fn value_unsigned_int(mut self, value: impl Into<types::UnsignedInt>) -> Self {
+        self.inner.value = Some(ExtensionValue::UnsignedInt(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `uri`.
+    pub fn value_uri(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.value = Some(ExtensionValue::Uri(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `url`.
+    pub fn value_url(mut self, value: impl Into<types::Url>) -> Self {
+        self.inner.value = Some(ExtensionValue::Url(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `uuid`.
+    pub fn value_uuid(mut self, value: impl Into<types::Uuid>) -> Self {
+        self.inner.value = Some(ExtensionValue::Uuid(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Address`.
+    pub fn value_address(mut self, value: impl Into<types::Address>) -> Self {
+        self.inner.value = Some(ExtensionValue::Address(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Age`.
+    pub fn value_age(mut self, value: impl Into<types::Age>) -> Self {
+        self.inner.value = Some(ExtensionValue::Age(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Annotation`.
+    pub fn value_annotation(mut self, value: impl Into<types::Annotation>) -> Self {
+        self.inner.value = Some(ExtensionValue::Annotation(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Attachment`.
+    pub fn value_attachment(mut self, value: impl Into<types::Attachment>) -> Self {
+        self.inner.value = Some(ExtensionValue::Attachment(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `CodeableConcept`.
+    pub fn value_codeable_concept(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::CodeableConcept(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `CodeableReference`.
+    pub fn value_codeable_reference(
+        mut self,
+        value: impl Into<types::CodeableReference>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::CodeableReference(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Coding`.
+    pub fn value_coding(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.value = Some(ExtensionValue::Coding(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `ContactPoint`.
+    pub fn value_contact_point(mut self, value: impl Into<types::ContactPoint>) -> Self {
+        self.inner.value = Some(ExtensionValue::ContactPoint(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Count`.
+    pub fn value_count(mut self, value: impl Into<types::Count>) -> Self {
+        self.inner.value = Some(ExtensionValue::Count(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Distance`.
+    pub fn value_distance(mut self, value: impl Into<types::Distance>) -> Self {
+        self.inner.value = Some(ExtensionValue::Distance(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Duration`.
+    pub fn value_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.inner.value = Some(ExtensionValue::Duration(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `HumanName`.
+    pub fn value_human_name(mut self, value: impl Into<types::HumanName>) -> Self {
+        self.inner.value = Some(ExtensionValue::HumanName(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Identifier`.
+    pub fn value_identifier(mut self, value: impl Into<types::Identifier>) -> Self {
+        self.inner.value = Some(ExtensionValue::Identifier(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Money`.
+    pub fn value_money(mut self, value: impl Into<types::Money>) -> Self {
+        self.inner.value = Some(ExtensionValue::Money(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Period`.
+    pub fn value_period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.value = Some(ExtensionValue::Period(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Quantity`.
+    pub fn value_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.value = Some(ExtensionValue::Quantity(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Range`.
+    pub fn value_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.inner.value = Some(ExtensionValue::Range(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Ratio`.
+    pub fn value_ratio(mut self, value: impl Into<types::Ratio>) -> Self {
+        self.inner.value = Some(ExtensionValue::Ratio(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `RatioRange`.
+    pub fn value_ratio_range(mut self, value: impl Into<types::RatioRange>) -> Self {
+        self.inner.value = Some(ExtensionValue::RatioRange(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Reference`.
+    pub fn value_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.value = Some(ExtensionValue::Reference(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `SampledData`.
+    pub fn value_sampled_data(mut self, value: impl Into<types::SampledData>) -> Self {
+        self.inner.value = Some(ExtensionValue::SampledData(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Signature`.
+    pub fn value_signature(mut self, value: impl Into<types::Signature>) -> Self {
+        self.inner.value = Some(ExtensionValue::Signature(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Timing`.
+    pub fn value_timing(mut self, value: impl Into<types::Timing>) -> Self {
+        self.inner.value = Some(ExtensionValue::Timing(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `ContactDetail`.
+    pub fn value_contact_detail(
+        mut self,
+        value: impl Into<types::ContactDetail>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::ContactDetail(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `DataRequirement`.
+    pub fn value_data_requirement(
+        mut self,
+        value: impl Into<types::DataRequirement>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::DataRequirement(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Expression`.
+    pub fn value_expression(mut self, value: impl Into<types::Expression>) -> Self {
+        self.inner.value = Some(ExtensionValue::Expression(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `ParameterDefinition`.
+    pub fn value_parameter_definition(
+        mut self,
+        value: impl Into<types::ParameterDefinition>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::ParameterDefinition(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `RelatedArtifact`.
+    pub fn value_related_artifact(
+        mut self,
+        value: impl Into<types::RelatedArtifact>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::RelatedArtifact(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `TriggerDefinition`.
+    pub fn value_trigger_definition(
+        mut self,
+        value: impl Into<types::TriggerDefinition>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::TriggerDefinition(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `UsageContext`.
+    pub fn value_usage_context(mut self, value: impl Into<types::UsageContext>) -> Self {
+        self.inner.value = Some(ExtensionValue::UsageContext(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Availability`.
+    pub fn value_availability(mut self, value: impl Into<types::Availability>) -> Self {
+        self.inner.value = Some(ExtensionValue::Availability(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `ExtendedContactDetail`.
+    pub fn value_extended_contact_detail(
+        mut self,
+        value: impl Into<types::ExtendedContactDetail>,
+    ) -> Self {
+        self.inner.value = Some(ExtensionValue::ExtendedContactDetail(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Dosage`.
+    pub fn value_dosage(mut self, value: impl Into<types::Dosage>) -> Self {
+        self.inner.value = Some(ExtensionValue::Dosage(value.into()));
+        self
+    }
+
+    /// Set `Extension.value[x]` to a `Meta`.
+    pub fn value_meta(mut self, value: impl Into<types::Meta>) -> Self {
+        self.inner.value = Some(ExtensionValue::Meta(value.into()));
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Extension, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.url {
+            Some(value) => inner.url = value,
+            None => missing.push("Extension.url"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for Extension {
+    const PATH: &'static str = "Extension";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.url, &format!("{path}.url"), issues);
+        types::Validate::validate_into(&self.value, &format!("{path}.value"), issues);
+        types::Validate::validate_into(
+            &self.value_element,
+            &format!("{path}._value"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Extension {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_extension(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.url, &format!("{path}.url"), visitor);
+        types::Walk::accept(&self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept(&self.value_element, &format!("{path}._value"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_extension_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.url, &format!("{path}.url"), visitor);
+        types::Walk::accept_mut(&mut self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept_mut(
+            &mut self.value_element,
+            &format!("{path}._value"),
+            visitor,
+        );
+    }
+}
+
+impl Extension {
+    /// The metadata of each child element of `Extension`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "url",
+            json_key: "url",
+            rust_field: "url",
+            min: 1,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value[x]",
+            json_key: "value",
+            rust_field: "value",
+            min: 0,
+            max: Some(1),
+            types: &[
+                "base64Binary",
+                "boolean",
+                "canonical",
+                "code",
+                "date",
+                "dateTime",
+                "decimal",
+                "id",
+                "instant",
+                "integer",
+                "integer64",
+                "markdown",
+                "oid",
+                "positiveInt",
+                "string",
+                "time",
+                "unsignedInt",
+                "uri",
+                "url",
+                "uuid",
+                "Address",
+                "Age",
+                "Annotation",
+                "Attachment",
+                "CodeableConcept",
+                "CodeableReference",
+                "Coding",
+                "ContactPoint",
+                "Count",
+                "Distance",
+                "Duration",
+                "HumanName",
+                "Identifier",
+                "Money",
+                "Period",
+                "Quantity",
+                "Range",
+                "Ratio",
+                "RatioRange",
+                "Reference",
+                "SampledData",
+                "Signature",
+                "Timing",
+                "ContactDetail",
+                "DataRequirement",
+                "Expression",
+                "ParameterDefinition",
+                "RelatedArtifact",
+                "TriggerDefinition",
+                "UsageContext",
+                "Availability",
+                "ExtendedContactDetail",
+                "Dosage",
+                "Meta",
+            ],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Extension {
+    fn type_name(&self) -> &'static str {
+        "Extension"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "url" => types::AsValueRef::as_value_ref(&self.url),
+            "value[x]" | "value" => types::AsValueRef::as_value_ref(&self.value),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Extension {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Extension {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `Extension.value[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExtensionValue {
+    #[serde(rename = "valueBase64Binary")]
+    Base64Binary(types::Base64Binary),
+    #[serde(rename = "valueBoolean")]
+    Boolean(types::Boolean),
+    #[serde(rename = "valueCanonical")]
+    Canonical(types::Canonical),
+    #[serde(rename = "valueCode")]
+    Code(types::Code),
+    #[serde(rename = "valueDate")]
+    Date(types::Date),
+    #[serde(rename = "valueDateTime")]
+    DateTime(types::DateTime),
+    #[serde(rename = "valueDecimal")]
+    Decimal(types::Decimal),
+    #[serde(rename = "valueId")]
+    Id(types::Id),
+    #[serde(rename = "valueInstant")]
+    Instant(types::Instant),
+    #[serde(rename = "valueInteger")]
+    Integer(types::Integer),
+    #[serde(rename = "valueInteger64")]
+    Integer64(types::Integer64),
+    #[serde(rename = "valueMarkdown")]
+    Markdown(types::Markdown),
+    #[serde(rename = "valueOid")]
+    Oid(types::Oid),
+    #[serde(rename = "valuePositiveInt")]
+    PositiveInt(types::PositiveInt),
+    #[serde(rename = "valueString")]
+    String(types::String),
+    #[serde(rename = "valueTime")]
+    Time(types::Time),
+    #[serde(rename = "valueUnsignedInt")]
+    UnsignedInt(types::UnsignedInt),
+    #[serde(rename = "valueUri")]
+    Uri(types::Uri),
+    #[serde(rename = "valueUrl")]
+    Url(types::Url),
+    #[serde(rename = "valueUuid")]
+    Uuid(types::Uuid),
+    #[serde(rename = "valueAddress")]
+    Address(types::Address),
+    #[serde(rename = "valueAge")]
+    Age(types::Age),
+    #[serde(rename = "valueAnnotation")]
+    Annotation(types::Annotation),
+    #[serde(rename = "valueAttachment")]
+    Attachment(types::Attachment),
+    #[serde(rename = "valueCodeableConcept")]
+    CodeableConcept(types::CodeableConcept),
+    #[serde(rename = "valueCodeableReference")]
+    CodeableReference(types::CodeableReference),
+    #[serde(rename = "valueCoding")]
+    Coding(types::Coding),
+    #[serde(rename = "valueContactPoint")]
+    ContactPoint(types::ContactPoint),
+    #[serde(rename = "valueCount")]
+    Count(types::Count),
+    #[serde(rename = "valueDistance")]
+    Distance(types::Distance),
+    #[serde(rename = "valueDuration")]
+    Duration(types::Duration),
+    #[serde(rename = "valueHumanName")]
+    HumanName(types::HumanName),
+    #[serde(rename = "valueIdentifier")]
+    Identifier(types::Identifier),
+    #[serde(rename = "valueMoney")]
+    Money(types::Money),
+    #[serde(rename = "valuePeriod")]
+    Period(types::Period),
+    #[serde(rename = "valueQuantity")]
+    Quantity(types::Quantity),
+    #[serde(rename = "valueRange")]
+    Range(types::Range),
+    #[serde(rename = "valueRatio")]
+    Ratio(types::Ratio),
+    #[serde(rename = "valueRatioRange")]
+    RatioRange(types::RatioRange),
+    #[serde(rename = "valueReference")]
+    Reference(types::Reference),
+    #[serde(rename = "valueSampledData")]
+    SampledData(types::SampledData),
+    #[serde(rename = "valueSignature")]
+    Signature(types::Signature),
+    #[serde(rename = "valueTiming")]
+    Timing(types::Timing),
+    #[serde(rename = "valueContactDetail")]
+    ContactDetail(types::ContactDetail),
+    #[serde(rename = "valueDataRequirement")]
+    DataRequirement(types::DataRequirement),
+    #[serde(rename = "valueExpression")]
+    Expression(types::Expression),
+    #[serde(rename = "valueParameterDefinition")]
+    ParameterDefinition(types::ParameterDefinition),
+    #[serde(rename = "valueRelatedArtifact")]
+    RelatedArtifact(types::RelatedArtifact),
+    #[serde(rename = "valueTriggerDefinition")]
+    TriggerDefinition(types::TriggerDefinition),
+    #[serde(rename = "valueUsageContext")]
+    UsageContext(types::UsageContext),
+    #[serde(rename = "valueAvailability")]
+    Availability(types::Availability),
+    #[serde(rename = "valueExtendedContactDetail")]
+    ExtendedContactDetail(types::ExtendedContactDetail),
+    #[serde(rename = "valueDosage")]
+    Dosage(types::Dosage),
+    #[serde(rename = "valueMeta")]
+    Meta(types::Meta),
+}
+
+impl Default for ExtensionValue {
+    fn default() -> Self {
+        Self::Base64Binary(Default::default())
+    }
+}
+
+impl types::Choice for ExtensionValue {
+    const KEYS: &'static [&'static str] = &[
+        "valueBase64Binary",
+        "valueBoolean",
+        "valueCanonical",
+        "valueCode",
+        "valueDate",
+        "valueDateTime",
+        "valueDecimal",
+        "valueId",
+        "valueInstant",
+        "valueInteger",
+        "valueInteger64",
+        "valueMarkdown",
+        "valueOid",
+        "valuePositiveInt",
+        "valueString",
+        "valueTime",
+        "valueUnsignedInt",
+        "valueUri",
+        "valueUrl",
+        "valueUuid",
+        "valueAddress",
+        "valueAge",
+        "valueAnnotation",
+        "valueAttachment",
+        "valueCodeableConcept",
+        "valueCodeableReference",
+        "valueCoding",
+        "valueContactPoint",
+        "valueCount",
+        "valueDistance",
+        "valueDuration",
+        "valueHumanName",
+        "valueIdentifier",
+        "valueMoney",
+        "valuePeriod",
+        "valueQuantity",
+        "valueRange",
+        "valueRatio",
+        "valueRatioRange",
+        "valueReference",
+        "valueSampledData",
+        "valueSignature",
+        "valueTiming",
+        "valueContactDetail",
+        "valueDataRequirement",
+        "valueExpression",
+        "valueParameterDefinition",
+        "valueRelatedArtifact",
+        "valueTriggerDefinition",
+        "valueUsageContext",
+        "valueAvailability",
+        "valueExtendedContactDetail",
+        "valueDosage",
+        "valueMeta",
+    ];
+}
+
+impl types::Validate for ExtensionValue {
+    const PATH: &'static str = "Extension.value";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+            Self::Address(value) => {
+                types::Validate::validate_into(value, &format!("{path}Address"), issues)
+            }
+            Self::Age(value) => {
+                types::Validate::validate_into(value, &format!("{path}Age"), issues)
+            }
+            Self::Annotation(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Annotation"),
+                    issues,
+                )
+            }
+            Self::Attachment(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Attachment"),
+                    issues,
+                )
+            }
+            Self::CodeableConcept(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    issues,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    issues,
+                )
+            }
+            Self::Coding(value) => {
+                types::Validate::validate_into(value, &format!("{path}Coding"), issues)
+            }
+            Self::ContactPoint(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactPoint"),
+                    issues,
+                )
+            }
+            Self::Count(value) => {
+                types::Validate::validate_into(value, &format!("{path}Count"), issues)
+            }
+            Self::Distance(value) => {
+                types::Validate::validate_into(value, &format!("{path}Distance"), issues)
+            }
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+            Self::HumanName(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}HumanName"),
+                    issues,
+                )
+            }
+            Self::Identifier(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Identifier"),
+                    issues,
+                )
+            }
+            Self::Money(value) => {
+                types::Validate::validate_into(value, &format!("{path}Money"), issues)
+            }
+            Self::Period(value) => {
+                types::Validate::validate_into(value, &format!("{path}Period"), issues)
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Ratio(value) => {
+                types::Validate::validate_into(value, &format!("{path}Ratio"), issues)
+            }
+            Self::RatioRange(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RatioRange"),
+                    issues,
+                )
+            }
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+            Self::SampledData(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}SampledData"),
+                    issues,
+                )
+            }
+            Self::Signature(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Signature"),
+                    issues,
+                )
+            }
+            Self::Timing(value) => {
+                types::Validate::validate_into(value, &format!("{path}Timing"), issues)
+            }
+            Self::ContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactDetail"),
+                    issues,
+                )
+            }
+            Self::DataRequirement(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    issues,
+                )
+            }
+            Self::Expression(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Expression"),
+                    issues,
+                )
+            }
+            Self::ParameterDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    issues,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    issues,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    issues,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UsageContext"),
+                    issues,
+                )
+            }
+            Self::Availability(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Availability"),
+                    issues,
+                )
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    issues,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Validate::validate_into(value, &format!("{path}Dosage"), issues)
+            }
+            Self::Meta(value) => {
+                types::Validate::validate_into(value, &format!("{path}Meta"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ExtensionValue {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableConcept"), visitor)
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableReference"), visitor)
+            }
+            Self::Coding(value) => {
+                types::Walk::accept(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept(value, &format!("{path}DataRequirement"), visitor)
+            }
+            Self::Expression(value) => {
+                types::Walk::accept(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept(value, &format!("{path}RelatedArtifact"), visitor)
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept(value, &format!("{path}TriggerDefinition"), visitor)
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+            Self::Address(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Address"), visitor)
+            }
+            Self::Age(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Age"), visitor)
+            }
+            Self::Annotation(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Annotation"), visitor)
+            }
+            Self::Attachment(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Attachment"), visitor)
+            }
+            Self::CodeableConcept(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    visitor,
+                )
+            }
+            Self::CodeableReference(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableReference"),
+                    visitor,
+                )
+            }
+            Self::Coding(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Coding"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::Count(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Count"), visitor)
+            }
+            Self::Distance(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Distance"), visitor)
+            }
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+            Self::HumanName(value) => {
+                types::Walk::accept_mut(value, &format!("{path}HumanName"), visitor)
+            }
+            Self::Identifier(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Identifier"), visitor)
+            }
+            Self::Money(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Money"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Period"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Ratio(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Ratio"), visitor)
+            }
+            Self::RatioRange(value) => {
+                types::Walk::accept_mut(value, &format!("{path}RatioRange"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+            Self::SampledData(value) => {
+                types::Walk::accept_mut(value, &format!("{path}SampledData"), visitor)
+            }
+            Self::Signature(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Signature"), visitor)
+            }
+            Self::Timing(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Timing"), visitor)
+            }
+            Self::ContactDetail(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactDetail"), visitor)
+            }
+            Self::DataRequirement(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}DataRequirement"),
+                    visitor,
+                )
+            }
+            Self::Expression(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Expression"), visitor)
+            }
+            Self::ParameterDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ParameterDefinition"),
+                    visitor,
+                )
+            }
+            Self::RelatedArtifact(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}RelatedArtifact"),
+                    visitor,
+                )
+            }
+            Self::TriggerDefinition(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}TriggerDefinition"),
+                    visitor,
+                )
+            }
+            Self::UsageContext(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UsageContext"), visitor)
+            }
+            Self::Availability(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Availability"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+            Self::Dosage(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Dosage"), visitor)
+            }
+            Self::Meta(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Meta"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for ExtensionValue {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Base64Binary(value) => types::AsValueRef::as_value_ref(value),
+            Self::Boolean(value) => types::AsValueRef::as_value_ref(value),
+            Self::Canonical(value) => types::AsValueRef::as_value_ref(value),
+            Self::Code(value) => types::AsValueRef::as_value_ref(value),
+            Self::Date(value) => types::AsValueRef::as_value_ref(value),
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+            Self::Decimal(value) => types::AsValueRef::as_value_ref(value),
+            Self::Id(value) => types::AsValueRef::as_value_ref(value),
+            Self::Instant(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer(value) => types::AsValueRef::as_value_ref(value),
+            Self::Integer64(value) => types::AsValueRef::as_value_ref(value),
+            Self::Markdown(value) => types::AsValueRef::as_value_ref(value),
+            Self::Oid(value) => types::AsValueRef::as_value_ref(value),
+            Self::PositiveInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::String(value) => types::AsValueRef::as_value_ref(value),
+            Self::Time(value) => types::AsValueRef::as_value_ref(value),
+            Self::UnsignedInt(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uri(value) => types::AsValueRef::as_value_ref(value),
+            Self::Url(value) => types::AsValueRef::as_value_ref(value),
+            Self::Uuid(value) => types::AsValueRef::as_value_ref(value),
+            Self::Address(value) => types::AsValueRef::as_value_ref(value),
+            Self::Age(value) => types::AsValueRef::as_value_ref(value),
+            Self::Annotation(value) => types::AsValueRef::as_value_ref(value),
+            Self::Attachment(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableConcept(value) => types::AsValueRef::as_value_ref(value),
+            Self::CodeableReference(value) => types::AsValueRef::as_value_ref(value),
+            Self::Coding(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactPoint(value) => types::AsValueRef::as_value_ref(value),
+            Self::Count(value) => types::AsValueRef::as_value_ref(value),
+            Self::Distance(value) => types::AsValueRef::as_value_ref(value),
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+            Self::HumanName(value) => types::AsValueRef::as_value_ref(value),
+            Self::Identifier(value) => types::AsValueRef::as_value_ref(value),
+            Self::Money(value) => types::AsValueRef::as_value_ref(value),
+            Self::Period(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Ratio(value) => types::AsValueRef::as_value_ref(value),
+            Self::RatioRange(value) => types::AsValueRef::as_value_ref(value),
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+            Self::SampledData(value) => types::AsValueRef::as_value_ref(value),
+            Self::Signature(value) => types::AsValueRef::as_value_ref(value),
+            Self::Timing(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::DataRequirement(value) => types::AsValueRef::as_value_ref(value),
+            Self::Expression(value) => types::AsValueRef::as_value_ref(value),
+            Self::ParameterDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::RelatedArtifact(value) => types::AsValueRef::as_value_ref(value),
+            Self::TriggerDefinition(value) => types::AsValueRef::as_value_ref(value),
+            Self::UsageContext(value) => types::AsValueRef::as_value_ref(value),
+            Self::Availability(value) => types::AsValueRef::as_value_ref(value),
+            Self::ExtendedContactDetail(value) => types::AsValueRef::as_value_ref(value),
+            Self::Dosage(value) => types::AsValueRef::as_value_ref(value),
+            Self::Meta(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `Extension.value[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExtensionValueElement {
+    #[serde(rename = "_valueBase64Binary")]
+    Base64Binary(types::Element),
+    #[serde(rename = "_valueBoolean")]
+    Boolean(types::Element),
+    #[serde(rename = "_valueCanonical")]
+    Canonical(types::Element),
+    #[serde(rename = "_valueCode")]
+    Code(types::Element),
+    #[serde(rename = "_valueDate")]
+    Date(types::Element),
+    #[serde(rename = "_valueDateTime")]
+    DateTime(types::Element),
+    #[serde(rename = "_valueDecimal")]
+    Decimal(types::Element),
+    #[serde(rename = "_valueId")]
+    Id(types::Element),
+    #[serde(rename = "_valueInstant")]
+    Instant(types::Element),
+    #[serde(rename = "_valueInteger")]
+    Integer(types::Element),
+    #[serde(rename = "_valueInteger64")]
+    Integer64(types::Element),
+    #[serde(rename = "_valueMarkdown")]
+    Markdown(types::Element),
+    #[serde(rename = "_valueOid")]
+    Oid(types::Element),
+    #[serde(rename = "_valuePositiveInt")]
+    PositiveInt(types::Element),
+    #[serde(rename = "_valueString")]
+    String(types::Element),
+    #[serde(rename = "_valueTime")]
+    Time(types::Element),
+    #[serde(rename = "_valueUnsignedInt")]
+    UnsignedInt(types::Element),
+    #[serde(rename = "_valueUri")]
+    Uri(types::Element),
+    #[serde(rename = "_valueUrl")]
+    Url(types::Element),
+    #[serde(rename = "_valueUuid")]
+    Uuid(types::Element),
+}
+
+impl types::Choice for ExtensionValueElement {
+    const KEYS: &'static [&'static str] = &[
+        "_valueBase64Binary",
+        "_valueBoolean",
+        "_valueCanonical",
+        "_valueCode",
+        "_valueDate",
+        "_valueDateTime",
+        "_valueDecimal",
+        "_valueId",
+        "_valueInstant",
+        "_valueInteger",
+        "_valueInteger64",
+        "_valueMarkdown",
+        "_valueOid",
+        "_valuePositiveInt",
+        "_valueString",
+        "_valueTime",
+        "_valueUnsignedInt",
+        "_valueUri",
+        "_valueUrl",
+        "_valueUuid",
+    ];
+}
+
+impl types::Validate for ExtensionValueElement {
+    const PATH: &'static str = "Extension.value";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Base64Binary"),
+                    issues,
+                )
+            }
+            Self::Boolean(value) => {
+                types::Validate::validate_into(value, &format!("{path}Boolean"), issues)
+            }
+            Self::Canonical(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Canonical"),
+                    issues,
+                )
+            }
+            Self::Code(value) => {
+                types::Validate::validate_into(value, &format!("{path}Code"), issues)
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+            Self::Decimal(value) => {
+                types::Validate::validate_into(value, &format!("{path}Decimal"), issues)
+            }
+            Self::Id(value) => {
+                types::Validate::validate_into(value, &format!("{path}Id"), issues)
+            }
+            Self::Instant(value) => {
+                types::Validate::validate_into(value, &format!("{path}Instant"), issues)
+            }
+            Self::Integer(value) => {
+                types::Validate::validate_into(value, &format!("{path}Integer"), issues)
+            }
+            Self::Integer64(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Integer64"),
+                    issues,
+                )
+            }
+            Self::Markdown(value) => {
+                types::Validate::validate_into(value, &format!("{path}Markdown"), issues)
+            }
+            Self::Oid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Oid"), issues)
+            }
+            Self::PositiveInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}PositiveInt"),
+                    issues,
+                )
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::Time(value) => {
+                types::Validate::validate_into(value, &format!("{path}Time"), issues)
+            }
+            Self::UnsignedInt(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}UnsignedInt"),
+                    issues,
+                )
+            }
+            Self::Uri(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uri"), issues)
+            }
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::Uuid(value) => {
+                types::Validate::validate_into(value, &format!("{path}Uuid"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ExtensionValueElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => types::Walk::accept(value, &format!("{path}Id"), visitor),
+            Self::Instant(value) => {
+                types::Walk::accept(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Base64Binary(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Base64Binary"), visitor)
+            }
+            Self::Boolean(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Boolean"), visitor)
+            }
+            Self::Canonical(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Canonical"), visitor)
+            }
+            Self::Code(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Code"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+            Self::Decimal(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Decimal"), visitor)
+            }
+            Self::Id(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Id"), visitor)
+            }
+            Self::Instant(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Instant"), visitor)
+            }
+            Self::Integer(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer"), visitor)
+            }
+            Self::Integer64(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Integer64"), visitor)
+            }
+            Self::Markdown(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Markdown"), visitor)
+            }
+            Self::Oid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Oid"), visitor)
+            }
+            Self::PositiveInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}PositiveInt"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::Time(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Time"), visitor)
+            }
+            Self::UnsignedInt(value) => {
+                types::Walk::accept_mut(value, &format!("{path}UnsignedInt"), visitor)
+            }
+            Self::Uri(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uri"), visitor)
+            }
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::Uuid(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Uuid"), visitor)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Extension;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["url"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
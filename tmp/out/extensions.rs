@@ -0,0 +1,800 @@
+//! Extensions
+//!
+//! Find the extensions of a value by URL, and view the value of an
+//! extension as the Rust type of its `value[x]` type, such as
+//! `types::Coding` for `valueCoding`.
+//!
+//! A complex extension has no value, and has nested extensions, that
+//! `HasExtensions` finds the same way.
+
+/// Use the FHIR R5 datatypes for the extension values.
+use crate::r5::types;
+
+/// The Rust type of a `value[x]` type of an extension, such as
+/// `types::Coding` for `valueCoding`.
+pub trait ExtensionValueType: Sized {
+    /// The value, if the extension value has this type.
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self>;
+
+    /// The extension value of this value.
+    fn into_extension_value(self) -> types::extension::ExtensionValue;
+}
+
+impl ExtensionValueType for types::extension::ExtensionValue {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        Some(value)
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        self
+    }
+}
+
+impl ExtensionValueType for types::Base64Binary {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Base64Binary(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Base64Binary(self)
+    }
+}
+
+impl ExtensionValueType for types::Boolean {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Boolean(self)
+    }
+}
+
+impl ExtensionValueType for types::Canonical {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Canonical(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Canonical(self)
+    }
+}
+
+impl ExtensionValueType for types::Code {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Code(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Code(self)
+    }
+}
+
+impl ExtensionValueType for types::Date {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Date(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Date(self)
+    }
+}
+
+impl ExtensionValueType for types::DateTime {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::DateTime(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::DateTime(self)
+    }
+}
+
+impl ExtensionValueType for types::Decimal {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Decimal(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Decimal(self)
+    }
+}
+
+impl ExtensionValueType for types::Id {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Id(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Id(self)
+    }
+}
+
+impl ExtensionValueType for types::Instant {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Instant(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Instant(self)
+    }
+}
+
+impl ExtensionValueType for types::Integer {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Integer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Integer(self)
+    }
+}
+
+impl ExtensionValueType for types::Integer64 {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Integer64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Integer64(self)
+    }
+}
+
+impl ExtensionValueType for types::Markdown {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Markdown(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Markdown(self)
+    }
+}
+
+impl ExtensionValueType for types::Oid {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Oid(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Oid(self)
+    }
+}
+
+impl ExtensionValueType for types::PositiveInt {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::PositiveInt(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::PositiveInt(self)
+    }
+}
+
+impl ExtensionValueType for types::String {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::String(self)
+    }
+}
+
+impl ExtensionValueType for types::Time {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Time(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Time(self)
+    }
+}
+
+impl ExtensionValueType for types::UnsignedInt {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::UnsignedInt(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::UnsignedInt(self)
+    }
+}
+
+impl ExtensionValueType for types::Uri {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Uri(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Uri(self)
+    }
+}
+
+impl ExtensionValueType for types::Url {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Url(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Url(self)
+    }
+}
+
+impl ExtensionValueType for types::Uuid {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Uuid(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Uuid(self)
+    }
+}
+
+impl ExtensionValueType for types::Address {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Address(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Address(self)
+    }
+}
+
+impl ExtensionValueType for types::Age {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Age(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Age(self)
+    }
+}
+
+impl ExtensionValueType for types::Annotation {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Annotation(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Annotation(self)
+    }
+}
+
+impl ExtensionValueType for types::Attachment {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Attachment(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Attachment(self)
+    }
+}
+
+impl ExtensionValueType for types::CodeableConcept {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::CodeableConcept(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::CodeableConcept(self)
+    }
+}
+
+impl ExtensionValueType for types::CodeableReference {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::CodeableReference(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::CodeableReference(self)
+    }
+}
+
+impl ExtensionValueType for types::Coding {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Coding(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Coding(self)
+    }
+}
+
+impl ExtensionValueType for types::ContactPoint {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::ContactPoint(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::ContactPoint(self)
+    }
+}
+
+impl ExtensionValueType for types::Count {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Count(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Count(self)
+    }
+}
+
+impl ExtensionValueType for types::Distance {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Distance(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Distance(self)
+    }
+}
+
+impl ExtensionValueType for types::Duration {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Duration(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Duration(self)
+    }
+}
+
+impl ExtensionValueType for types::HumanName {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::HumanName(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::HumanName(self)
+    }
+}
+
+impl ExtensionValueType for types::Identifier {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Identifier(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Identifier(self)
+    }
+}
+
+impl ExtensionValueType for types::Money {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Money(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Money(self)
+    }
+}
+
+impl ExtensionValueType for types::Period {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Period(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Period(self)
+    }
+}
+
+impl ExtensionValueType for types::Quantity {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Quantity(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Quantity(self)
+    }
+}
+
+impl ExtensionValueType for types::Range {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Range(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Range(self)
+    }
+}
+
+impl ExtensionValueType for types::Ratio {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Ratio(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Ratio(self)
+    }
+}
+
+impl ExtensionValueType for types::RatioRange {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::RatioRange(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::RatioRange(self)
+    }
+}
+
+impl ExtensionValueType for types::Reference {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Reference(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Reference(self)
+    }
+}
+
+impl ExtensionValueType for types::SampledData {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::SampledData(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::SampledData(self)
+    }
+}
+
+impl ExtensionValueType for types::Signature {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Signature(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Signature(self)
+    }
+}
+
+impl ExtensionValueType for types::Timing {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Timing(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Timing(self)
+    }
+}
+
+impl ExtensionValueType for types::ContactDetail {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::ContactDetail(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::ContactDetail(self)
+    }
+}
+
+impl ExtensionValueType for types::DataRequirement {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::DataRequirement(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::DataRequirement(self)
+    }
+}
+
+impl ExtensionValueType for types::Expression {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Expression(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Expression(self)
+    }
+}
+
+impl ExtensionValueType for types::ParameterDefinition {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::ParameterDefinition(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::ParameterDefinition(self)
+    }
+}
+
+impl ExtensionValueType for types::RelatedArtifact {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::RelatedArtifact(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::RelatedArtifact(self)
+    }
+}
+
+impl ExtensionValueType for types::TriggerDefinition {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::TriggerDefinition(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::TriggerDefinition(self)
+    }
+}
+
+impl ExtensionValueType for types::UsageContext {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::UsageContext(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::UsageContext(self)
+    }
+}
+
+impl ExtensionValueType for types::Availability {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Availability(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Availability(self)
+    }
+}
+
+impl ExtensionValueType for types::ExtendedContactDetail {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::ExtendedContactDetail(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::ExtendedContactDetail(self)
+    }
+}
+
+impl ExtensionValueType for types::Dosage {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Dosage(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Dosage(self)
+    }
+}
+
+impl ExtensionValueType for types::Meta {
+    fn from_extension_value(value: &types::extension::ExtensionValue) -> Option<&Self> {
+        match value {
+            types::extension::ExtensionValue::Meta(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_extension_value(self) -> types::extension::ExtensionValue {
+        types::extension::ExtensionValue::Meta(self)
+    }
+}
+
+/// A value with extensions, such as an `Element` or a `DomainResource`.
+pub trait HasExtensions {
+    /// The extensions, in order.
+    fn extensions(&self) -> &[types::Extension];
+
+    /// The extensions, mutably.
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension>;
+
+    /// The first extension with the URL, if any.
+    fn get_extension(&self, url: &str) -> Option<&types::Extension> {
+        self.extensions().iter().find(|extension| extension.url == url)
+    }
+
+    /// Each extension with the URL, in order.
+    fn get_extensions(&self, url: &str) -> Vec<&types::Extension> {
+        self.extensions().iter().filter(|extension| extension.url == url).collect()
+    }
+
+    /// The value of the first extension with the URL, if it has the type.
+    fn get_extension_value<T: ExtensionValueType>(&self, url: &str) -> Option<&T> {
+        self.get_extension(url)?.value_as()
+    }
+
+    /// Add the extension, after the others.
+    fn add_extension(&mut self, extension: types::Extension) {
+        self.extensions_mut().push(extension);
+    }
+
+    /// Replace each extension with the URL by one simple extension with the
+    /// value.
+    fn set_extension_value<T: ExtensionValueType>(&mut self, url: &str, value: T) {
+        self.remove_extensions(url);
+        self.add_extension(types::Extension::from_value(url, value));
+    }
+
+    /// Remove each extension with the URL, and return them, in order.
+    fn remove_extensions(&mut self, url: &str) -> Vec<types::Extension> {
+        let (removed, kept) = std::mem::take(self.extensions_mut())
+            .into_iter()
+            .partition(|extension| extension.url == url);
+        *self.extensions_mut() = kept;
+        removed
+    }
+}
+
+impl types::Extension {
+    /// Create a simple extension, with the URL and the value.
+    pub fn from_value<T: ExtensionValueType>(url: impl Into<String>, value: T) -> Self {
+        Self {
+            url: url.into(),
+            value: Some(value.into_extension_value()),
+            ..Self::default()
+        }
+    }
+
+    /// The value, if it has the type.
+    pub fn value_as<T: ExtensionValueType>(&self) -> Option<&T> {
+        self.value.as_ref().and_then(T::from_extension_value)
+    }
+}
+
+/// The typed accessors of the core extensions, for each value with
+/// extensions.
+pub trait CoreExtensions: HasExtensions {}
+
+impl<T: HasExtensions + ?Sized> CoreExtensions for T {}
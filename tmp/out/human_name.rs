@@ -1,6 +1,6 @@
 //! HumanName
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/HumanName
+//! URL: <http://hl7.org/fhir/StructureDefinition/HumanName>
 //!
 //! Version: 5.0.0
 //!
@@ -10,78 +10,579 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Name of a human or other living entity - parts and usage
+///
+/// A name, normally of a human, that can be used for other living entities (e.g. animals but not organizations) that have been assigned names by a human and may need the use of name parts or the need for usage information.
+///
+/// Comment: Names may be changed, or repudiated, or people may have different names in different contexts. Names may be divided into parts of different type that have variable significance depending on context, though the division into parts does not always matter. With personal names, the different parts might or might not be imbued with some implicit meaning; various cultures associate different importance with the name parts and the degree to which systems must care about name parts around the world varies widely.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct HumanName {
-    /// Name of a human or other living entity - parts and usage
-    HumanName: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// usual | official | temp | nickname | anonymous | old | maiden
-    use: ? // code
+    ///
+    /// Identifies the purpose for this name.
+    ///
+    /// Comment: Applications can assume that a name is current unless it explicitly says that it is temporary or old.
+    ///
+    /// Requirements: Allows the appropriate name for a particular context of use to be selected from among a set of names.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/name-use|5.0.0`](http://hl7.org/fhir/ValueSet/name-use)
+    ///
+    /// The use of a human name.
+    ///
+    /// Modifier: This is labeled as "Is Modifier" because applications should not mistake a temporary or old name etc.for a current/permanent one
+    pub r#use: Option<types::Code>,
+
+    /// Id and extensions of `use`
+    #[serde(rename = "_use")]
+    pub use_element: Option<types::Element>,
 
     /// Text representation of the full name
-    text: ? // string
+    ///
+    /// Specifies the entire name as it should be displayed e.g. on an application UI. This may be provided instead of or as well as the specific parts.
+    ///
+    /// Comment: Can provide both a text representation and parts. Applications updating a name SHALL ensure that when both text and parts are present,  no content is included in the text that isn't found in a part.
+    ///
+    /// Requirements: A renderable, unencoded form.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub text: Option<types::String>,
+
+    /// Id and extensions of `text`
+    #[serde(rename = "_text")]
+    pub text_element: Option<types::Element>,
 
     /// Family name (often called 'Surname')
-    family: ? // string
+    ///
+    /// The part of a name that links to the genealogy. In some cultures (e.g. Eritrea) the family name of a son is the first name of his father.
+    ///
+    /// Comment: Family Name may be decomposed into specific parts using extensions (de, nl, es related cultures).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub family: Option<types::String>,
+
+    /// Id and extensions of `family`
+    #[serde(rename = "_family")]
+    pub family_element: Option<types::Element>,
 
     /// Given names (not always 'first'). Includes middle names
-    given: ? // string
+    ///
+    /// Given name.
+    ///
+    /// Comment: If only initials are recorded, they may be used in place of the full name parts. Initials may be separated into multiple given names but often aren't due to paractical limitations.  This element is not called "first name" since given names do not always come first.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `string`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub given: Vec<Option<types::String>>,
+
+    /// Id and extensions of `given`
+    #[serde(rename = "_given", default, skip_serializing_if = "Vec::is_empty")]
+    pub given_element: Vec<Option<types::Element>>,
 
     /// Parts that come before the name
-    prefix: ? // string
+    ///
+    /// Part of the name that is acquired as a title due to academic, legal, employment or nobility status, etc. and that appears at the start of the name.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `string`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prefix: Vec<Option<types::String>>,
+
+    /// Id and extensions of `prefix`
+    #[serde(rename = "_prefix", default, skip_serializing_if = "Vec::is_empty")]
+    pub prefix_element: Vec<Option<types::Element>>,
 
     /// Parts that come after the name
-    suffix: ? // string
+    ///
+    /// Part of the name that is acquired as a title due to academic, legal, employment or nobility status, etc. and that appears at the end of the name.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `string`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suffix: Vec<Option<types::String>>,
+
+    /// Id and extensions of `suffix`
+    #[serde(rename = "_suffix", default, skip_serializing_if = "Vec::is_empty")]
+    pub suffix_element: Vec<Option<types::Element>>,
 
     /// Time period when name was/is in use
-    period: ? // Period
+    ///
+    /// Indicates the period of time when this name was valid for the named person.
+    ///
+    /// Requirements: Allows names to be placed in historical context.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Period`
+    pub period: Option<types::Period>,
+}
+
+impl HumanName {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl HumanName {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> HumanNameBuilder {
+        HumanNameBuilder::default()
+    }
+}
+
+/// Build one `HumanName`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct HumanNameBuilder {
+    inner: HumanName,
+}
+
+impl HumanNameBuilder {
+    /// Set `HumanName.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `HumanName.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `HumanName.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `HumanName.use`.
+    pub fn r#use(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.r#use = Some(value.into());
+        self
+    }
+
+    /// Set `HumanName.text`.
+    pub fn text(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.text = Some(value.into());
+        self
+    }
+
+    /// Set `HumanName.family`.
+    pub fn family(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.family = Some(value.into());
+        self
+    }
+
+    /// Set every `HumanName.given`.
+    pub fn given(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::String>>,
+    ) -> Self {
+        self.inner.given = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `HumanName.given`.
+    pub fn push_given(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.given.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `HumanName.prefix`.
+    pub fn prefix(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::String>>,
+    ) -> Self {
+        self.inner.prefix = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `HumanName.prefix`.
+    pub fn push_prefix(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.prefix.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `HumanName.suffix`.
+    pub fn suffix(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::String>>,
+    ) -> Self {
+        self.inner.suffix = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `HumanName.suffix`.
+    pub fn push_suffix(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.suffix.push(Some(value.into()));
+        self
+    }
+
+    /// Set `HumanName.period`.
+    pub fn period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.period = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<HumanName, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for HumanName {
+    const PATH: &'static str = "HumanName";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#use, &format!("{path}.use"), issues);
+        types::Validate::validate_into(
+            &self.use_element,
+            &format!("{path}._use"),
+            issues,
+        );
+        types::Validate::validate_into(&self.text, &format!("{path}.text"), issues);
+        types::Validate::validate_into(
+            &self.text_element,
+            &format!("{path}._text"),
+            issues,
+        );
+        types::Validate::validate_into(&self.family, &format!("{path}.family"), issues);
+        types::Validate::validate_into(
+            &self.family_element,
+            &format!("{path}._family"),
+            issues,
+        );
+        types::Validate::validate_into(&self.given, &format!("{path}.given"), issues);
+        types::Validate::validate_into(
+            &self.given_element,
+            &format!("{path}._given"),
+            issues,
+        );
+        types::Validate::validate_into(&self.prefix, &format!("{path}.prefix"), issues);
+        types::Validate::validate_into(
+            &self.prefix_element,
+            &format!("{path}._prefix"),
+            issues,
+        );
+        types::Validate::validate_into(&self.suffix, &format!("{path}.suffix"), issues);
+        types::Validate::validate_into(
+            &self.suffix_element,
+            &format!("{path}._suffix"),
+            issues,
+        );
+        types::Validate::validate_into(&self.period, &format!("{path}.period"), issues);
+    }
+}
+
+impl types::Walk for HumanName {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_human_name(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept(&self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept(&self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept(&self.text_element, &format!("{path}._text"), visitor);
+        types::Walk::accept(&self.family, &format!("{path}.family"), visitor);
+        types::Walk::accept(&self.family_element, &format!("{path}._family"), visitor);
+        types::Walk::accept(&self.given, &format!("{path}.given"), visitor);
+        types::Walk::accept(&self.given_element, &format!("{path}._given"), visitor);
+        types::Walk::accept(&self.prefix, &format!("{path}.prefix"), visitor);
+        types::Walk::accept(&self.prefix_element, &format!("{path}._prefix"), visitor);
+        types::Walk::accept(&self.suffix, &format!("{path}.suffix"), visitor);
+        types::Walk::accept(&self.suffix_element, &format!("{path}._suffix"), visitor);
+        types::Walk::accept(&self.period, &format!("{path}.period"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_human_name_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept_mut(&mut self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept_mut(&mut self.text, &format!("{path}.text"), visitor);
+        types::Walk::accept_mut(
+            &mut self.text_element,
+            &format!("{path}._text"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.family, &format!("{path}.family"), visitor);
+        types::Walk::accept_mut(
+            &mut self.family_element,
+            &format!("{path}._family"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.given, &format!("{path}.given"), visitor);
+        types::Walk::accept_mut(
+            &mut self.given_element,
+            &format!("{path}._given"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.prefix, &format!("{path}.prefix"), visitor);
+        types::Walk::accept_mut(
+            &mut self.prefix_element,
+            &format!("{path}._prefix"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.suffix, &format!("{path}.suffix"), visitor);
+        types::Walk::accept_mut(
+            &mut self.suffix_element,
+            &format!("{path}._suffix"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.period, &format!("{path}.period"), visitor);
+    }
+}
+
+impl HumanName {
+    /// The metadata of each child element of `HumanName`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "use",
+            json_key: "use",
+            rust_field: "r#use",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/name-use|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "text",
+            json_key: "text",
+            rust_field: "text",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "family",
+            json_key: "family",
+            rust_field: "family",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "given",
+            json_key: "given",
+            rust_field: "given",
+            min: 0,
+            max: None,
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "prefix",
+            json_key: "prefix",
+            rust_field: "prefix",
+            min: 0,
+            max: None,
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "suffix",
+            json_key: "suffix",
+            rust_field: "suffix",
+            min: 0,
+            max: None,
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "period",
+            json_key: "period",
+            rust_field: "period",
+            min: 0,
+            max: Some(1),
+            types: &["Period"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for HumanName {
+    fn type_name(&self) -> &'static str {
+        "HumanName"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "use" => types::AsValueRef::as_value_ref(&self.r#use),
+            "text" => types::AsValueRef::as_value_ref(&self.text),
+            "family" => types::AsValueRef::as_value_ref(&self.family),
+            "given" => types::AsValueRef::as_value_ref(&self.given),
+            "prefix" => types::AsValueRef::as_value_ref(&self.prefix),
+            "suffix" => types::AsValueRef::as_value_ref(&self.suffix),
+            "period" => types::AsValueRef::as_value_ref(&self.period),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for HumanName {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
 
+impl types::HasExtensions for HumanName {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = HumanName;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! Id
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/id
+//! URL: <http://hl7.org/fhir/StructureDefinition/id>
 //!
 //! Version: 5.0.0
 //!
@@ -10,60 +10,108 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
-#[serde_with::skip_serializing_none]
+/// Wrap the value of the FHIR `id` primitive type.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+/// Serialize as the bare JSON value, without a wrapping object.
+#[serde(transparent)]
 pub struct Id {
-    /// Primitive Type id
-    id: ? // ?
+    pub value: std::string::String,
+}
 
-    /// xml:id (or equivalent in JSON)
-    id: ? // http://hl7.org/fhirpath/System.String
+impl Id {
+    /// Create with the value.
+    pub fn new(value: std::string::String) -> Self {
+        Self { value }
+    }
+}
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+impl From<std::string::String> for Id {
+    fn from(value: std::string::String) -> Self {
+        Self { value }
+    }
+}
+
+impl From<&str> for Id {
+    fn from(value: &str) -> Self {
+        Self { value: value.into() }
+    }
+}
 
-    /// Primitive value for id
-    value: ? // http://hl7.org/fhirpath/System.String
+impl types::Validate for Id {
+    const PATH: &'static str = "id";
 
+    fn validate_into(&self, _path: &str, _issues: &mut Vec<types::ValidationIssue>) {}
+}
+
+impl types::Walk for Id {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_id(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_id_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, _path: &str, _visitor: &mut V) {}
+}
+
+impl Id {
+    /// The metadata of each child element of `id`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[];
+}
+
+impl types::Reflect for Id {
+    fn type_name(&self) -> &'static str {
+        "id"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {
+        None
+    }
+}
+
+impl types::AsValueRef for Id {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        types::AsValueRef::as_value_ref(&self.value)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Id;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        assert_eq!(actual, ::serde_json::json!(""));
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! Identifier
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Identifier
+//! URL: <http://hl7.org/fhir/StructureDefinition/Identifier>
 //!
 //! Version: 5.0.0
 //!
@@ -10,75 +10,495 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// An identifier intended for computation
+///
+/// An identifier - identifies some entity uniquely and unambiguously. Typically this is used for business identifiers.
+///
+/// Comment: The rules of the identifier.type  determine if a check digit is part of the ID value or sent separately, such as through the checkDigit extension.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `ident-1`: Identifier with no value has limited utility.  If communicating that an identifier value has been suppressed or missing, the value element SHOULD be present with an extension indicating the missing semantic - e.g. data-absent-reason
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Identifier {
-    /// An identifier intended for computation
-    Identifier: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// usual | official | temp | secondary | old (If known)
-    use: ? // code
+    ///
+    /// The purpose of this identifier.
+    ///
+    /// Comment: Applications can assume that an identifier is permanent unless it explicitly says that it is temporary.
+    ///
+    /// Requirements: Allows the appropriate identifier for a particular context of use to be selected from among a set of identifiers.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/identifier-use|5.0.0`](http://hl7.org/fhir/ValueSet/identifier-use)
+    ///
+    /// Identifies the purpose for this identifier, if known .
+    ///
+    /// Modifier: This is labeled as "Is Modifier" because applications should not mistake a temporary id for a permanent one.
+    pub r#use: Option<types::Code>,
+
+    /// Id and extensions of `use`
+    #[serde(rename = "_use")]
+    pub use_element: Option<types::Element>,
 
     /// Description of identifier
-    type: ? // CodeableConcept
+    ///
+    /// A coded type for the identifier that can be used to determine which identifier to use for a specific purpose.
+    ///
+    /// Comment: This element deals only with general categories of identifiers.  It SHOULD not be used for codes that correspond 1..1 with the Identifier.system. Some identifiers may fall into multiple categories due to common usage.   Where the system is known, a type is unnecessary because the type is always part of the system definition. However systems often need to handle identifiers where the system is not known. There is not a 1:1 relationship between type and system, since many different systems have the same type.
+    ///
+    /// Requirements: Allows users to make use of identifiers when the identifier system is not known.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: extensible to [`http://hl7.org/fhir/ValueSet/identifier-type`](http://hl7.org/fhir/ValueSet/identifier-type)
+    ///
+    /// A coded type for an identifier that can be used to determine which identifier to use for a specific purpose.
+    pub r#type: Option<types::CodeableConcept>,
 
     /// The namespace for the identifier value
-    system: ? // uri
+    ///
+    /// Establishes the namespace for the value - that is, an absolute URL that describes a set values that are unique.
+    ///
+    /// Comment: Identifier.system is always case sensitive.
+    ///
+    /// Requirements: There are many sets  of identifiers.  To perform matching of two identifiers, we need to know what set we're dealing with. The system identifies a particular set of unique identifiers.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `uri`
+    pub system: Option<types::Uri>,
+
+    /// Id and extensions of `system`
+    #[serde(rename = "_system")]
+    pub system_element: Option<types::Element>,
 
     /// The value that is unique
-    value: ? // string
+    ///
+    /// The portion of the identifier typically relevant to the user and which is unique within the context of the system.
+    ///
+    /// Comment: If the value is a full URI, then the system SHALL be urn:ietf:rfc:3986.  The value's primary purpose is computational mapping.  As a result, it may be normalized for comparison purposes (e.g. removing non-significant whitespace, dashes, etc.)  A value formatted for human display can be conveyed using the [http://hl7.org/fhir/StructureDefinition/rendered-value](http://hl7.org/fhir/extensions/StructureDefinition-rendered-value.html)). Identifier.value is to be treated as case sensitive unless knowledge of the Identifier.system allows the processer to be confident that non-case-sensitive processing is safe.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub value: Option<types::String>,
+
+    /// Id and extensions of `value`
+    #[serde(rename = "_value")]
+    pub value_element: Option<types::Element>,
 
     /// Time period when id is/was valid for use
-    period: ? // Period
+    ///
+    /// Time period during which identifier is/was valid for use.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Period`
+    pub period: Option<types::Period>,
 
     /// Organization that issued id (may be just text)
-    assigner: ? // Reference
+    ///
+    /// Organization that issued/manages the identifier.
+    ///
+    /// Comment: The Identifier.assigner may omit the .reference element and only contain a .display element reflecting the name or other textual information about the assigning organization.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Reference(Organization)`
+    pub assigner: Option<Box<types::Reference>>,
+}
+
+impl Identifier {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
+impl Identifier {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> IdentifierBuilder {
+        IdentifierBuilder::default()
+    }
+}
+
+/// Build one `Identifier`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct IdentifierBuilder {
+    inner: Identifier,
+}
+
+impl IdentifierBuilder {
+    /// Set `Identifier.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Identifier.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Identifier.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Identifier.use`.
+    pub fn r#use(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.r#use = Some(value.into());
+        self
+    }
+
+    /// Set `Identifier.type`.
+    pub fn r#type(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `Identifier.system`.
+    pub fn system(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.system = Some(value.into());
+        self
+    }
+
+    /// Set `Identifier.value`.
+    pub fn value(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.value = Some(value.into());
+        self
+    }
+
+    /// Set `Identifier.period`.
+    pub fn period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.period = Some(value.into());
+        self
+    }
+
+    /// Set `Identifier.assigner`.
+    pub fn assigner(mut self, value: impl Into<Box<types::Reference>>) -> Self {
+        self.inner.assigner = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Identifier, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Identifier {
+    const PATH: &'static str = "Identifier";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#use, &format!("{path}.use"), issues);
+        types::Validate::validate_into(
+            &self.use_element,
+            &format!("{path}._use"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(&self.system, &format!("{path}.system"), issues);
+        types::Validate::validate_into(
+            &self.system_element,
+            &format!("{path}._system"),
+            issues,
+        );
+        types::Validate::validate_into(&self.value, &format!("{path}.value"), issues);
+        types::Validate::validate_into(
+            &self.value_element,
+            &format!("{path}._value"),
+            issues,
+        );
+        types::Validate::validate_into(&self.period, &format!("{path}.period"), issues);
+        types::Validate::validate_into(
+            &self.assigner,
+            &format!("{path}.assigner"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Identifier {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_identifier(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept(&self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.system, &format!("{path}.system"), visitor);
+        types::Walk::accept(&self.system_element, &format!("{path}._system"), visitor);
+        types::Walk::accept(&self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept(&self.value_element, &format!("{path}._value"), visitor);
+        types::Walk::accept(&self.period, &format!("{path}.period"), visitor);
+        types::Walk::accept(&self.assigner, &format!("{path}.assigner"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_identifier_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept_mut(&mut self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(&mut self.system, &format!("{path}.system"), visitor);
+        types::Walk::accept_mut(
+            &mut self.system_element,
+            &format!("{path}._system"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.value, &format!("{path}.value"), visitor);
+        types::Walk::accept_mut(
+            &mut self.value_element,
+            &format!("{path}._value"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.period, &format!("{path}.period"), visitor);
+        types::Walk::accept_mut(
+            &mut self.assigner,
+            &format!("{path}.assigner"),
+            visitor,
+        );
+    }
+}
+
+impl Identifier {
+    /// The metadata of each child element of `Identifier`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "use",
+            json_key: "use",
+            rust_field: "r#use",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/identifier-use|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "extensible",
+                value_set: Some("http://hl7.org/fhir/ValueSet/identifier-type"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "system",
+            json_key: "system",
+            rust_field: "system",
+            min: 0,
+            max: Some(1),
+            types: &["uri"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value",
+            json_key: "value",
+            rust_field: "value",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "period",
+            json_key: "period",
+            rust_field: "period",
+            min: 0,
+            max: Some(1),
+            types: &["Period"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "assigner",
+            json_key: "assigner",
+            rust_field: "assigner",
+            min: 0,
+            max: Some(1),
+            types: &["Reference"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Identifier {
+    fn type_name(&self) -> &'static str {
+        "Identifier"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "use" => types::AsValueRef::as_value_ref(&self.r#use),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "system" => types::AsValueRef::as_value_ref(&self.system),
+            "value" => types::AsValueRef::as_value_ref(&self.value),
+            "period" => types::AsValueRef::as_value_ref(&self.period),
+            "assigner" => types::AsValueRef::as_value_ref(&self.assigner),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Identifier {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Identifier {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Identifier;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
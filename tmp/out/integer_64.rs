@@ -1,6 +1,6 @@
 //! Integer64
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/integer64
+//! URL: <http://hl7.org/fhir/StructureDefinition/integer64>
 //!
 //! Version: 5.0.0
 //!
@@ -10,60 +10,113 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
-#[serde_with::skip_serializing_none]
+/// Wrap the value of the FHIR `integer64` primitive type.
+#[serde_with::serde_as]
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+/// Serialize as the bare JSON value, without a wrapping object.
+#[serde(transparent)]
 pub struct Integer64 {
-    /// Primitive Type integer64
-    integer64: ? // ?
+    #[serde_as(as = "::serde_with::DisplayFromStr")]
+    pub value: i64,
+}
+
+impl Integer64 {
+    /// Create with the value.
+    pub fn new(value: i64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<i64> for Integer64 {
+    fn from(value: i64) -> Self {
+        Self { value }
+    }
+}
 
-    /// xml:id (or equivalent in JSON)
-    id: ? // http://hl7.org/fhirpath/System.String
+impl types::Validate for Integer64 {
+    const PATH: &'static str = "integer64";
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        let value = self;
+        types::validate::check_range(
+            path,
+            i64::from(value.value),
+            Some(-9223372036854775808),
+            Some(9223372036854775807),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Integer64 {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_integer_64(path, self);
+    }
 
-    /// Primitive value for integer64
-    value: ? // http://hl7.org/fhirpath/System.Integer
+    fn walk<V: types::Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_integer_64_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, _path: &str, _visitor: &mut V) {}
+}
 
+impl Integer64 {
+    /// The metadata of each child element of `integer64`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[];
+}
+
+impl types::Reflect for Integer64 {
+    fn type_name(&self) -> &'static str {
+        "integer64"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {
+        None
+    }
+}
+
+impl types::AsValueRef for Integer64 {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        types::AsValueRef::as_value_ref(&self.value)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Integer64;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        assert_eq!(actual, ::serde_json::json!("0"));
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! MarketingStatus
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/MarketingStatus
+//! URL: <http://hl7.org/fhir/StructureDefinition/MarketingStatus>
 //!
 //! Version: 5.0.0
 //!
@@ -10,75 +10,515 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// The marketing status describes the date when a medicinal product is actually put on the market or the date as of which it is no longer available
+///
+/// The marketing status describes the date when a medicinal product is actually put on the market or the date as of which it is no longer available.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct MarketingStatus {
-    /// The marketing status describes the date when a medicinal product is actually put on the market or the date as of which it is no longer available
-    MarketingStatus: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Extensions that cannot be ignored even if unrecognized
-    modifierExtension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element and that modifies the understanding of the element in which it is contained and/or the understanding of the containing element's descendants. Usually modifier elements provide negation or qualification. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension. Applications processing a resource are required to check for modifier extensions.
+    ///
+    /// Modifier extensions SHALL NOT change the meaning of any elements on Resource or DomainResource (including cannot change the meaning of modifierExtension itself).
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Requirements: Modifier extensions allow for extensions that *cannot* be safely ignored to be clearly distinguished from the vast majority of extensions which can be safely ignored.  This promotes interoperability by eliminating the need for implementers to prohibit the presence of extensions. For further information, see the [definition of modifier extensions](https://build.fhir.org/extensibility.html#modifierExtension).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    ///
+    /// Modifier: Modifier extensions are expected to modify the meaning or interpretation of the element that contains them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modifier_extension: Vec<types::Extension>,
 
     /// The country in which the marketing authorization has been granted shall be specified It should be specified using the ISO 3166 ‑ 1 alpha-2 code elements
-    country: ? // CodeableConcept
+    ///
+    /// The country in which the marketing authorization has been granted shall be specified It should be specified using the ISO 3166 ‑ 1 alpha-2 code elements.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    pub country: Option<types::CodeableConcept>,
 
     /// Where a Medicines Regulatory Agency has granted a marketing authorization for which specific provisions within a jurisdiction apply, the jurisdiction can be specified using an appropriate controlled terminology The controlled term and the controlled term identifier shall be specified
-    jurisdiction: ? // CodeableConcept
+    ///
+    /// Where a Medicines Regulatory Agency has granted a marketing authorization for which specific provisions within a jurisdiction apply, the jurisdiction can be specified using an appropriate controlled terminology The controlled term and the controlled term identifier shall be specified.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    pub jurisdiction: Option<types::CodeableConcept>,
 
     /// This attribute provides information on the status of the marketing of the medicinal product See ISO/TS 20443 for more information and examples
-    status: ? // CodeableConcept
+    ///
+    /// This attribute provides information on the status of the marketing of the medicinal product See ISO/TS 20443 for more information and examples.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `CodeableConcept`
+    pub status: types::CodeableConcept,
 
     /// The date when the Medicinal Product is placed on the market by the Marketing Authorization Holder (or where applicable, the manufacturer/distributor) in a country and/or jurisdiction shall be provided A complete date consisting of day, month and year shall be specified using the ISO 8601 date format NOTE “Placed on the market” refers to the release of the Medicinal Product into the distribution chain
-    dateRange: ? // Period
+    ///
+    /// The date when the Medicinal Product is placed on the market by the Marketing Authorization Holder (or where applicable, the manufacturer/distributor) in a country and/or jurisdiction shall be provided A complete date consisting of day, month and year shall be specified using the ISO 8601 date format NOTE “Placed on the market” refers to the release of the Medicinal Product into the distribution chain.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Period`
+    pub date_range: Option<types::Period>,
 
     /// The date when the Medicinal Product is placed on the market by the Marketing Authorization Holder (or where applicable, the manufacturer/distributor) in a country and/or jurisdiction shall be provided A complete date consisting of day, month and year shall be specified using the ISO 8601 date format NOTE “Placed on the market” refers to the release of the Medicinal Product into the distribution chain
-    restoreDate: ? // dateTime
+    ///
+    /// The date when the Medicinal Product is placed on the market by the Marketing Authorization Holder (or where applicable, the manufacturer/distributor) in a country and/or jurisdiction shall be provided A complete date consisting of day, month and year shall be specified using the ISO 8601 date format NOTE “Placed on the market” refers to the release of the Medicinal Product into the distribution chain.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `dateTime`
+    pub restore_date: Option<types::DateTime>,
+
+    /// Id and extensions of `restoreDate`
+    #[serde(rename = "_restoreDate")]
+    pub restore_date_element: Option<types::Element>,
+}
+
+impl MarketingStatus {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(status: types::CodeableConcept) -> Self {
+        Self {
+            status,
+            ..Default::default()
+        }
+    }
+}
+
+impl MarketingStatus {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> MarketingStatusBuilder {
+        MarketingStatusBuilder::default()
+    }
+}
+
+/// Build one `MarketingStatus`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct MarketingStatusBuilder {
+    inner: MarketingStatus,
+    status: Option<types::CodeableConcept>,
+}
+
+impl MarketingStatusBuilder {
+    /// Set `MarketingStatus.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `MarketingStatus.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `MarketingStatus.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `MarketingStatus.modifierExtension`.
+    pub fn modifier_extension(
+        mut self,
+        value: impl Into<Vec<types::Extension>>,
+    ) -> Self {
+        self.inner.modifier_extension = value.into();
+        self
+    }
+
+    /// Add one `MarketingStatus.modifierExtension`.
+    pub fn push_modifier_extension(
+        mut self,
+        value: impl Into<types::Extension>,
+    ) -> Self {
+        self.inner.modifier_extension.push(value.into());
+        self
+    }
+
+    /// Set `MarketingStatus.country`.
+    pub fn country(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.country = Some(value.into());
+        self
+    }
+
+    /// Set `MarketingStatus.jurisdiction`.
+    pub fn jurisdiction(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.jurisdiction = Some(value.into());
+        self
+    }
+
+    /// Set `MarketingStatus.status`.
+    pub fn status(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Set `MarketingStatus.dateRange`.
+    pub fn date_range(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.date_range = Some(value.into());
+        self
+    }
+
+    /// Set `MarketingStatus.restoreDate`.
+    pub fn restore_date(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.restore_date = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<MarketingStatus, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.status {
+            Some(value) => inner.status = value,
+            None => missing.push("MarketingStatus.status"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for MarketingStatus {
+    const PATH: &'static str = "MarketingStatus";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.country,
+            &format!("{path}.country"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.jurisdiction,
+            &format!("{path}.jurisdiction"),
+            issues,
+        );
+        types::Validate::validate_into(&self.status, &format!("{path}.status"), issues);
+        types::Validate::validate_into(
+            &self.date_range,
+            &format!("{path}.dateRange"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.restore_date,
+            &format!("{path}.restoreDate"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.restore_date_element,
+            &format!("{path}._restoreDate"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for MarketingStatus {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_marketing_status(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept(&self.country, &format!("{path}.country"), visitor);
+        types::Walk::accept(
+            &self.jurisdiction,
+            &format!("{path}.jurisdiction"),
+            visitor,
+        );
+        types::Walk::accept(&self.status, &format!("{path}.status"), visitor);
+        types::Walk::accept(&self.date_range, &format!("{path}.dateRange"), visitor);
+        types::Walk::accept(&self.restore_date, &format!("{path}.restoreDate"), visitor);
+        types::Walk::accept(
+            &self.restore_date_element,
+            &format!("{path}._restoreDate"),
+            visitor,
+        );
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_marketing_status_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.country, &format!("{path}.country"), visitor);
+        types::Walk::accept_mut(
+            &mut self.jurisdiction,
+            &format!("{path}.jurisdiction"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.status, &format!("{path}.status"), visitor);
+        types::Walk::accept_mut(
+            &mut self.date_range,
+            &format!("{path}.dateRange"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.restore_date,
+            &format!("{path}.restoreDate"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.restore_date_element,
+            &format!("{path}._restoreDate"),
+            visitor,
+        );
+    }
+}
+
+impl MarketingStatus {
+    /// The metadata of each child element of `MarketingStatus`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "modifierExtension",
+            json_key: "modifierExtension",
+            rust_field: "modifier_extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "country",
+            json_key: "country",
+            rust_field: "country",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "jurisdiction",
+            json_key: "jurisdiction",
+            rust_field: "jurisdiction",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "status",
+            json_key: "status",
+            rust_field: "status",
+            min: 1,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "dateRange",
+            json_key: "dateRange",
+            rust_field: "date_range",
+            min: 0,
+            max: Some(1),
+            types: &["Period"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "restoreDate",
+            json_key: "restoreDate",
+            rust_field: "restore_date",
+            min: 0,
+            max: Some(1),
+            types: &["dateTime"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for MarketingStatus {
+    fn type_name(&self) -> &'static str {
+        "MarketingStatus"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "modifierExtension" => {
+                types::AsValueRef::as_value_ref(&self.modifier_extension)
+            }
+            "country" => types::AsValueRef::as_value_ref(&self.country),
+            "jurisdiction" => types::AsValueRef::as_value_ref(&self.jurisdiction),
+            "status" => types::AsValueRef::as_value_ref(&self.status),
+            "dateRange" => types::AsValueRef::as_value_ref(&self.date_range),
+            "restoreDate" => types::AsValueRef::as_value_ref(&self.restore_date),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for MarketingStatus {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for MarketingStatus {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
 
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = MarketingStatus;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["status"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! Meta
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Meta
+//! URL: <http://hl7.org/fhir/StructureDefinition/Meta>
 //!
 //! Version: 5.0.0
 //!
@@ -10,75 +10,556 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Metadata about a resource
+///
+/// The metadata about a resource. This is content in the resource that is maintained by the infrastructure. Changes to the content might not always be associated with version changes to the resource.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Meta {
-    /// Metadata about a resource
-    Meta: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Version specific identifier
-    versionId: ? // id
+    ///
+    /// The version specific identifier, as it appears in the version portion of the URL. This value changes when the resource is created, updated, or deleted.
+    ///
+    /// Comment: The server assigns this value, and ignores what the client specifies, except in the case that the server is imposing version integrity on updates/deletes.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `id`
+    pub version_id: Option<types::Id>,
+
+    /// Id and extensions of `versionId`
+    #[serde(rename = "_versionId")]
+    pub version_id_element: Option<types::Element>,
 
     /// When the resource version last changed
-    lastUpdated: ? // instant
+    ///
+    /// When the resource last changed - e.g. when the version changed.
+    ///
+    /// Comment: This element is generally omitted in instances submitted in a PUT or POST. Instead, it is populated in the response instance and when retrieving information using a GET. The server / resource manager sets this value; what a client provides is irrelevant. This is equivalent to the HTTP Last-Modified and SHOULD have the same value on a [read](https://build.fhir.org/http.html#read) interaction.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `instant`
+    pub last_updated: Option<types::Instant>,
+
+    /// Id and extensions of `lastUpdated`
+    #[serde(rename = "_lastUpdated")]
+    pub last_updated_element: Option<types::Element>,
 
     /// Identifies where the resource comes from
-    source: ? // uri
+    ///
+    /// A uri that identifies the source system of the resource. This provides a minimal amount of [Provenance](https://build.fhir.org/provenance.html#) information that can be used to track or differentiate the source of information in the resource. The source may identify another FHIR server, document, message, database, etc.
+    ///
+    /// Comment: The exact use of the source (and the possible implied Provenance.entity.role and agent.role) is left to implementer discretion. Only one nominated source is allowed; for additional provenance details, a full Provenance resource should be used. The source may correspond to Provenance.entity.what\[x\] or Provenance.agent.who\[x\], though it may be a more general or abstract reference.
+    ///
+    /// This element can be used to indicate where the current master source of a resource that has a canonical URL if the resource is no longer hosted at the canonical URL.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `uri`
+    pub source: Option<types::Uri>,
+
+    /// Id and extensions of `source`
+    #[serde(rename = "_source")]
+    pub source_element: Option<types::Element>,
 
     /// Profiles this resource claims to conform to
-    profile: ? // canonical
+    ///
+    /// A list of profiles (references to [StructureDefinition](https://build.fhir.org/structuredefinition.html#) resources) that this resource claims to conform to. The URL is a reference to [StructureDefinition.url](https://build.fhir.org/structuredefinition-definitions.html#StructureDefinition.url).
+    ///
+    /// Comment: It is up to the server and/or other infrastructure of policy to determine whether/how these claims are verified and/or updated over time.  The list of profile URLs is a set.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `canonical(StructureDefinition)`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profile: Vec<Option<types::Canonical>>,
+
+    /// Id and extensions of `profile`
+    #[serde(rename = "_profile", default, skip_serializing_if = "Vec::is_empty")]
+    pub profile_element: Vec<Option<types::Element>>,
 
     /// Security Labels applied to this resource
-    security: ? // Coding
+    ///
+    /// Security labels applied to this resource. These tags connect specific resources to the overall security policy and infrastructure.
+    ///
+    /// Comment: The security labels can be updated without changing the stated version of the resource. The list of security labels is a set. Uniqueness is based the system/code, and version and display are ignored.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Coding`
+    ///
+    /// Binding: extensible to [`http://hl7.org/fhir/ValueSet/security-labels`](http://hl7.org/fhir/ValueSet/security-labels)
+    ///
+    /// Security Labels from the Healthcare Privacy and Security Classification System.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security: Vec<types::Coding>,
 
     /// Tags applied to this resource
-    tag: ? // Coding
+    ///
+    /// Tags applied to this resource. Tags are intended to be used to identify and relate resources to process and workflow, and applications are not required to consider the tags when interpreting the meaning of a resource.
+    ///
+    /// Comment: The tags can be updated without changing the stated version of the resource. The list of tags is a set. Uniqueness is based the system/code, and version and display are ignored.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Coding`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/common-tags`](http://hl7.org/fhir/ValueSet/common-tags)
+    ///
+    /// Codes that represent various types of tags, commonly workflow-related; e.g. "Needs review by Dr. Jones".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tag: Vec<types::Coding>,
+}
+
+impl Meta {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Meta {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> MetaBuilder {
+        MetaBuilder::default()
+    }
+}
+
+/// Build one `Meta`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct MetaBuilder {
+    inner: Meta,
+}
+
+impl MetaBuilder {
+    /// Set `Meta.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Meta.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Meta.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Meta.versionId`.
+    pub fn version_id(mut self, value: impl Into<types::Id>) -> Self {
+        self.inner.version_id = Some(value.into());
+        self
+    }
+
+    /// Set `Meta.lastUpdated`.
+    pub fn last_updated(mut self, value: impl Into<types::Instant>) -> Self {
+        self.inner.last_updated = Some(value.into());
+        self
+    }
+
+    /// Set `Meta.source`.
+    pub fn source(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.source = Some(value.into());
+        self
+    }
+
+    /// Set every `Meta.profile`.
+    pub fn profile(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Canonical>>,
+    ) -> Self {
+        self.inner.profile = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `Meta.profile`.
+    pub fn push_profile(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.profile.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `Meta.security`.
+    pub fn security(mut self, value: impl Into<Vec<types::Coding>>) -> Self {
+        self.inner.security = value.into();
+        self
+    }
+
+    /// Add one `Meta.security`.
+    pub fn push_security(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.security.push(value.into());
+        self
+    }
+
+    /// Set every `Meta.tag`.
+    pub fn tag(mut self, value: impl Into<Vec<types::Coding>>) -> Self {
+        self.inner.tag = value.into();
+        self
+    }
+
+    /// Add one `Meta.tag`.
+    pub fn push_tag(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.tag.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Meta, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Meta {
+    const PATH: &'static str = "Meta";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.version_id,
+            &format!("{path}.versionId"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.version_id_element,
+            &format!("{path}._versionId"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.last_updated,
+            &format!("{path}.lastUpdated"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.last_updated_element,
+            &format!("{path}._lastUpdated"),
+            issues,
+        );
+        types::Validate::validate_into(&self.source, &format!("{path}.source"), issues);
+        types::Validate::validate_into(
+            &self.source_element,
+            &format!("{path}._source"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.profile,
+            &format!("{path}.profile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.profile_element,
+            &format!("{path}._profile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.security,
+            &format!("{path}.security"),
+            issues,
+        );
+        types::Validate::validate_into(&self.tag, &format!("{path}.tag"), issues);
+    }
+}
+
+impl types::Walk for Meta {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_meta(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.version_id, &format!("{path}.versionId"), visitor);
+        types::Walk::accept(
+            &self.version_id_element,
+            &format!("{path}._versionId"),
+            visitor,
+        );
+        types::Walk::accept(&self.last_updated, &format!("{path}.lastUpdated"), visitor);
+        types::Walk::accept(
+            &self.last_updated_element,
+            &format!("{path}._lastUpdated"),
+            visitor,
+        );
+        types::Walk::accept(&self.source, &format!("{path}.source"), visitor);
+        types::Walk::accept(&self.source_element, &format!("{path}._source"), visitor);
+        types::Walk::accept(&self.profile, &format!("{path}.profile"), visitor);
+        types::Walk::accept(&self.profile_element, &format!("{path}._profile"), visitor);
+        types::Walk::accept(&self.security, &format!("{path}.security"), visitor);
+        types::Walk::accept(&self.tag, &format!("{path}.tag"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_meta_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.version_id,
+            &format!("{path}.versionId"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.version_id_element,
+            &format!("{path}._versionId"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.last_updated,
+            &format!("{path}.lastUpdated"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.last_updated_element,
+            &format!("{path}._lastUpdated"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.source, &format!("{path}.source"), visitor);
+        types::Walk::accept_mut(
+            &mut self.source_element,
+            &format!("{path}._source"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.profile, &format!("{path}.profile"), visitor);
+        types::Walk::accept_mut(
+            &mut self.profile_element,
+            &format!("{path}._profile"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.security,
+            &format!("{path}.security"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.tag, &format!("{path}.tag"), visitor);
+    }
+}
+
+impl Meta {
+    /// The metadata of each child element of `Meta`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "versionId",
+            json_key: "versionId",
+            rust_field: "version_id",
+            min: 0,
+            max: Some(1),
+            types: &["id"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "lastUpdated",
+            json_key: "lastUpdated",
+            rust_field: "last_updated",
+            min: 0,
+            max: Some(1),
+            types: &["instant"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "source",
+            json_key: "source",
+            rust_field: "source",
+            min: 0,
+            max: Some(1),
+            types: &["uri"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "profile",
+            json_key: "profile",
+            rust_field: "profile",
+            min: 0,
+            max: None,
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "security",
+            json_key: "security",
+            rust_field: "security",
+            min: 0,
+            max: None,
+            types: &["Coding"],
+            binding: Some(types::BindingInfo {
+                strength: "extensible",
+                value_set: Some("http://hl7.org/fhir/ValueSet/security-labels"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "tag",
+            json_key: "tag",
+            rust_field: "tag",
+            min: 0,
+            max: None,
+            types: &["Coding"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some("http://hl7.org/fhir/ValueSet/common-tags"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
 
+impl types::Reflect for Meta {
+    fn type_name(&self) -> &'static str {
+        "Meta"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "versionId" => types::AsValueRef::as_value_ref(&self.version_id),
+            "lastUpdated" => types::AsValueRef::as_value_ref(&self.last_updated),
+            "source" => types::AsValueRef::as_value_ref(&self.source),
+            "profile" => types::AsValueRef::as_value_ref(&self.profile),
+            "security" => types::AsValueRef::as_value_ref(&self.security),
+            "tag" => types::AsValueRef::as_value_ref(&self.tag),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Meta {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Meta {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Meta;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -0,0 +1,238 @@
+//! FHIR R5 types, generated from the FHIR StructureDefinitions.
+
+pub mod address;
+pub use address::Address;
+
+pub mod age;
+pub use age::Age;
+
+pub mod annotation;
+pub use annotation::Annotation;
+
+pub mod attachment;
+pub use attachment::Attachment;
+
+pub mod availability;
+pub use availability::Availability;
+
+pub mod backbone_element;
+pub use backbone_element::BackboneElement;
+
+pub mod backbone_type;
+pub use backbone_type::BackboneType;
+
+pub mod base;
+pub use base::Base;
+
+pub mod base_64_binary;
+pub use base_64_binary::Base64Binary;
+
+pub mod boolean;
+pub use boolean::Boolean;
+
+pub mod canonical;
+pub use canonical::Canonical;
+
+pub mod code;
+pub use code::Code;
+
+pub mod codeable_concept;
+pub use codeable_concept::CodeableConcept;
+
+pub mod codeable_reference;
+pub use codeable_reference::CodeableReference;
+
+pub mod coding;
+pub use coding::Coding;
+
+pub mod contact_detail;
+pub use contact_detail::ContactDetail;
+
+pub mod contact_point;
+pub use contact_point::ContactPoint;
+
+pub mod contributor;
+pub use contributor::Contributor;
+
+pub mod count;
+pub use count::Count;
+
+pub mod data_requirement;
+pub use data_requirement::DataRequirement;
+
+pub mod data_type;
+pub use data_type::DataType;
+
+pub mod date;
+pub use date::Date;
+
+pub mod date_time;
+pub use date_time::DateTime;
+
+pub mod decimal;
+pub use decimal::Decimal;
+
+pub mod distance;
+pub use distance::Distance;
+
+pub mod dosage;
+pub use dosage::Dosage;
+
+pub mod duration;
+pub use duration::Duration;
+
+pub mod element;
+pub use element::Element;
+
+pub mod element_definition;
+pub use element_definition::ElementDefinition;
+
+pub mod expression;
+pub use expression::Expression;
+
+pub mod extended_contact_detail;
+pub use extended_contact_detail::ExtendedContactDetail;
+
+pub mod extension;
+pub use extension::Extension;
+
+pub mod human_name;
+pub use human_name::HumanName;
+
+pub mod id;
+pub use id::Id;
+
+pub mod identifier;
+pub use identifier::Identifier;
+
+pub mod instant;
+pub use instant::Instant;
+
+pub mod integer;
+pub use integer::Integer;
+
+pub mod integer_64;
+pub use integer_64::Integer64;
+
+pub mod markdown;
+pub use markdown::Markdown;
+
+pub mod marketing_status;
+pub use marketing_status::MarketingStatus;
+
+pub mod meta;
+pub use meta::Meta;
+
+pub mod monetary_component;
+pub use monetary_component::MonetaryComponent;
+
+pub mod money;
+pub use money::Money;
+
+pub mod money_quantity;
+pub use money_quantity::MoneyQuantity;
+
+pub mod narrative;
+pub use narrative::Narrative;
+
+pub mod oid;
+pub use oid::Oid;
+
+pub mod parameter_definition;
+pub use parameter_definition::ParameterDefinition;
+
+pub mod period;
+pub use period::Period;
+
+pub mod positive_int;
+pub use positive_int::PositiveInt;
+
+pub mod primitive_type;
+pub use primitive_type::PrimitiveType;
+
+pub mod product_shelf_life;
+pub use product_shelf_life::ProductShelfLife;
+
+pub mod quantity;
+pub use quantity::Quantity;
+
+pub mod range;
+pub use range::Range;
+
+pub mod ratio;
+pub use ratio::Ratio;
+
+pub mod ratio_range;
+pub use ratio_range::RatioRange;
+
+pub mod reference;
+pub use reference::Reference;
+
+pub mod related_artifact;
+pub use related_artifact::RelatedArtifact;
+
+pub mod sampled_data;
+pub use sampled_data::SampledData;
+
+pub mod signature;
+pub use signature::Signature;
+
+pub mod simple_quantity;
+pub use simple_quantity::SimpleQuantity;
+
+pub mod string;
+pub use string::String;
+
+pub mod time;
+pub use time::Time;
+
+pub mod timing;
+pub use timing::Timing;
+
+pub mod trigger_definition;
+pub use trigger_definition::TriggerDefinition;
+
+pub mod unsigned_int;
+pub use unsigned_int::UnsignedInt;
+
+pub mod uri;
+pub use uri::Uri;
+
+pub mod url;
+pub use url::Url;
+
+pub mod usage_context;
+pub use usage_context::UsageContext;
+
+pub mod uuid;
+pub use uuid::Uuid;
+
+pub mod virtual_service_detail;
+pub use virtual_service_detail::VirtualServiceDetail;
+
+pub mod xhtml;
+pub use xhtml::Xhtml;
+
+pub mod unknown_fields;
+pub use unknown_fields::DenyUnknownFields;
+
+pub mod choice;
+pub use choice::Choice;
+
+pub mod builder;
+pub use builder::MissingElements;
+
+pub mod validate;
+pub use validate::{Validate, ValidationIssue};
+
+pub mod visit;
+pub use visit::{Visit, VisitMut, Walk};
+
+pub mod reflect;
+pub use reflect::{AsValueRef, BindingInfo, ElementInfo, Reflect, ValueRef};
+
+pub mod extensions;
+pub use extensions::{CoreExtensions, ExtensionValueType, HasExtensions};
+
+pub mod profile;
+pub use profile::{ProfileIssue, ProfileIssues, ProfileRule};
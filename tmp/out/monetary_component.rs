@@ -1,6 +1,6 @@
 //! MonetaryComponent
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/MonetaryComponent
+//! URL: <http://hl7.org/fhir/StructureDefinition/MonetaryComponent>
 //!
 //! Version: 5.0.0
 //!
@@ -10,69 +10,409 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Availability data for an {item}
+///
+/// Availability data for an {item}.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct MonetaryComponent {
-    /// Availability data for an {item}
-    MonetaryComponent: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// base | surcharge | deduction | discount | tax | informational
-    type: ? // code
+    ///
+    /// base | surcharge | deduction | discount | tax | informational.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/price-component-type|5.0.0`](http://hl7.org/fhir/ValueSet/price-component-type)
+    ///
+    /// The purpose for which an extended contact detail should be used.
+    pub r#type: types::Code,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
 
     /// Codes may be used to differentiate between kinds of taxes, surcharges, discounts etc.
-    code: ? // CodeableConcept
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: example
+    ///
+    /// Codes may be used to differentiate between kinds of taxes, surcharges, discounts etc.
+    pub code: Option<types::CodeableConcept>,
 
     /// Factor used for calculating this component
-    factor: ? // decimal
+    ///
+    /// Factor used for calculating this component.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub factor: Option<types::Decimal>,
+
+    /// Id and extensions of `factor`
+    #[serde(rename = "_factor")]
+    pub factor_element: Option<types::Element>,
 
     /// Explicit value amount to be used
-    amount: ? // Money
+    ///
+    /// Explicit value amount to be used.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Money`
+    pub amount: Option<types::Money>,
+}
+
+impl MonetaryComponent {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(r#type: types::Code) -> Self {
+        Self {
+            r#type,
+            ..Default::default()
+        }
+    }
+}
+
+impl MonetaryComponent {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> MonetaryComponentBuilder {
+        MonetaryComponentBuilder::default()
+    }
+}
+
+/// Build one `MonetaryComponent`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct MonetaryComponentBuilder {
+    inner: MonetaryComponent,
+    r#type: Option<types::Code>,
+}
+
+impl MonetaryComponentBuilder {
+    /// Set `MonetaryComponent.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `MonetaryComponent.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `MonetaryComponent.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `MonetaryComponent.type`.
+    pub fn r#type(mut self, value: impl Into<types::Code>) -> Self {
+        self.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `MonetaryComponent.code`.
+    pub fn code(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.code = Some(value.into());
+        self
+    }
+
+    /// Set `MonetaryComponent.factor`.
+    pub fn factor(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.factor = Some(value.into());
+        self
+    }
+
+    /// Set `MonetaryComponent.amount`.
+    pub fn amount(mut self, value: impl Into<types::Money>) -> Self {
+        self.inner.amount = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<MonetaryComponent, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.r#type {
+            Some(value) => inner.r#type = value,
+            None => missing.push("MonetaryComponent.type"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
 
+impl types::Validate for MonetaryComponent {
+    const PATH: &'static str = "MonetaryComponent";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+        types::Validate::validate_into(&self.factor, &format!("{path}.factor"), issues);
+        types::Validate::validate_into(
+            &self.factor_element,
+            &format!("{path}._factor"),
+            issues,
+        );
+        types::Validate::validate_into(&self.amount, &format!("{path}.amount"), issues);
+    }
+}
+
+impl types::Walk for MonetaryComponent {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_monetary_component(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept(&self.factor, &format!("{path}.factor"), visitor);
+        types::Walk::accept(&self.factor_element, &format!("{path}._factor"), visitor);
+        types::Walk::accept(&self.amount, &format!("{path}.amount"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_monetary_component_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept_mut(&mut self.factor, &format!("{path}.factor"), visitor);
+        types::Walk::accept_mut(
+            &mut self.factor_element,
+            &format!("{path}._factor"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.amount, &format!("{path}.amount"), visitor);
+    }
+}
+
+impl MonetaryComponent {
+    /// The metadata of each child element of `MonetaryComponent`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/price-component-type|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: None,
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "factor",
+            json_key: "factor",
+            rust_field: "factor",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "amount",
+            json_key: "amount",
+            rust_field: "amount",
+            min: 0,
+            max: Some(1),
+            types: &["Money"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for MonetaryComponent {
+    fn type_name(&self) -> &'static str {
+        "MonetaryComponent"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            "factor" => types::AsValueRef::as_value_ref(&self.factor),
+            "amount" => types::AsValueRef::as_value_ref(&self.amount),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for MonetaryComponent {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for MonetaryComponent {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = MonetaryComponent;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["type"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
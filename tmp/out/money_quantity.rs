@@ -1,80 +1,251 @@
 //! MoneyQuantity
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/MoneyQuantity
+//! URL: <http://hl7.org/fhir/StructureDefinition/MoneyQuantity>
 //!
 //! Version: 5.0.0
 //!
-//! An amount of money. With regard to precision, see [Decimal Precision](datatypes.html#precision)
+//! An amount of money. With regard to precision, see [Decimal Precision](https://build.fhir.org/datatypes.html#precision)
 //!
 //! FHIR: <https://build.fhir.org/>
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
-#[serde_with::skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
-pub struct MoneyQuantity {
-    /// An amount of money. With regard to precision, see [Decimal Precision](datatypes.html#precision)
-    Quantity: ? // ?
+/// An amount of money. With regard to precision, see [Decimal Precision](https://build.fhir.org/datatypes.html#precision)
+///
+/// There SHALL be a code if there is a value and it SHALL be an expression of currency.  If system is present, it SHALL be ISO 4217 (system = "urn:iso:std:iso:4217" - currency).
+///
+/// Comment: The context of use may frequently define what kind of quantity this is and therefore what kind of units can be used. The context of use may also restrict the values for the comparator.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `mtqy-1`: There SHALL be a code if there is a value and it SHALL be an expression of currency.  If system is present, it SHALL be ISO 4217 (system = "urn:iso:std:iso:4217" - currency).
+/// - `qty-3`: If a code for the unit is present, the system SHALL also be present
+///
+/// A `Quantity` that satisfies the rules of the profile.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Check the rules when deserializing, and serialize as the base type.
+#[serde(try_from = "types::Quantity", into = "types::Quantity")]
+pub struct MoneyQuantity(types::Quantity);
+
+impl MoneyQuantity {
+    /// The canonical URL of the profile.
+    pub const URL: &'static str = "http://hl7.org/fhir/StructureDefinition/MoneyQuantity";
+
+    /// The rules of the profile, beyond the rules of `Quantity`.
+    pub const RULES: &'static [types::ProfileRule] = &[];
+
+    /// Borrow the base value.
+    pub fn as_inner(&self) -> &types::Quantity {
+        &self.0
+    }
+
+    /// Unwrap the base value.
+    pub fn into_inner(self) -> types::Quantity {
+        self.0
+    }
+}
+
+impl TryFrom<types::Quantity> for MoneyQuantity {
+    type Error = types::ProfileIssues;
+
+    /// Check each rule of the profile, and list each rule that fails.
+    fn try_from(value: types::Quantity) -> Result<Self, Self::Error> {
+        types::profile::check_profile_rules(Self::URL, &value, Self::RULES)?;
+        Ok(Self(value))
+    }
+}
+
+impl From<MoneyQuantity> for types::Quantity {
+    fn from(value: MoneyQuantity) -> Self {
+        value.0
+    }
+}
+
+impl std::ops::Deref for MoneyQuantity {
+    type Target = types::Quantity;
 
-    /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+impl types::Validate for MoneyQuantity {
+    const PATH: &'static str = "Quantity";
 
-    /// Numerical value (with implicit precision)
-    value: ? // decimal
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.0, path, issues);
+    }
+}
 
-    /// < | <= | >= | > | ad - how to understand the value
-    comparator: ? // code
+impl types::Walk for MoneyQuantity {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_money_quantity(path, self);
+    }
 
-    /// Unit representation
-    unit: ? // string
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.0, path, visitor);
+    }
 
-    /// System that defines coded unit form
-    system: ? // uri
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_money_quantity_mut(path, self);
+    }
 
-    /// Coded form of the unit
-    code: ? // code
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.0, path, visitor);
+    }
+}
 
+impl MoneyQuantity {
+    /// The metadata of each child element of `Quantity`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value",
+            json_key: "value",
+            rust_field: "value",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "comparator",
+            json_key: "comparator",
+            rust_field: "comparator",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/quantity-comparator|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "unit",
+            json_key: "unit",
+            rust_field: "unit",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "system",
+            json_key: "system",
+            rust_field: "system",
+            min: 0,
+            max: Some(1),
+            types: &["uri"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for MoneyQuantity {
+    fn type_name(&self) -> &'static str {
+        "Quantity"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        types::Reflect::child(&self.0, name)
+    }
+}
+
+impl types::AsValueRef for MoneyQuantity {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for MoneyQuantity {
+    fn extensions(&self) -> &[types::Extension] {
+        types::HasExtensions::extensions(&self.0)
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        types::HasExtensions::extensions_mut(&mut self.0)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = MoneyQuantity;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_try_from_default() {
+        let base = types::Quantity::default();
+        match T::try_from(base.clone()) {
+            Ok(actual) => assert_eq!(actual.into_inner(), base),
+            Err(actual) => assert!(! actual.issues.is_empty()),
+        }
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let Ok(expect) = T::try_from(types::Quantity::default()) else {
+                return;
+            };
+            let json = ::serde_json::to_value(&expect).expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
-            let expect: T = T::default();
-            assert_eq!(actual, expect);
-        }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
             assert_eq!(actual, expect);
         }
     }
@@ -1,6 +1,6 @@
 //! Narrative
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Narrative
+//! URL: <http://hl7.org/fhir/StructureDefinition/Narrative>
 //!
 //! Version: 5.0.0
 //!
@@ -10,63 +10,340 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Human-readable summary of the resource (essential clinical and business information)
+///
+/// A human-readable summary of the resource conveying the essential clinical and business information for the resource.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Narrative {
-    /// Human-readable summary of the resource (essential clinical and business information)
-    Narrative: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// generated | extensions | additional | empty
-    status: ? // code
+    ///
+    /// The status of the narrative - whether it's entirely generated (from just the defined data or the extensions too), or whether a human authored it and it may contain additional data.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/narrative-status|5.0.0`](http://hl7.org/fhir/ValueSet/narrative-status)
+    ///
+    /// The status of a resource narrative.
+    pub status: types::Code,
+
+    /// Id and extensions of `status`
+    #[serde(rename = "_status")]
+    pub status_element: Option<types::Element>,
 
     /// Limited xhtml content
-    div: ? // xhtml
+    ///
+    /// The actual narrative content, a stripped down version of XHTML.
+    ///
+    /// Comment: The contents of the html element are an XHTML fragment containing only the basic html formatting elements described in chapters 7-11 and 15 of the HTML 4.0 standard, &lt;a&gt; elements (either name or href), images and internally contained stylesheets. The XHTML content SHALL NOT contain a head, a body, external stylesheet references, scripts, forms, base/link/xlink, frames, iframes and objects.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `xhtml`
+    ///
+    /// Constraints:
+    ///
+    /// - `txt-1`: The narrative SHALL contain only the basic html formatting elements and attributes described in chapters 7-11 (except section 4 of chapter 9) and 15 of the HTML 4.0 standard, &lt;a&gt; elements (either name or href), images and internally contained style attributes
+    /// - `txt-2`: The narrative SHALL have some non-whitespace content
+    pub div: types::Xhtml,
+}
+
+impl Narrative {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(status: types::Code, div: types::Xhtml) -> Self {
+        Self {
+            status,
+            div,
+            ..Default::default()
+        }
+    }
+}
+
+impl Narrative {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> NarrativeBuilder {
+        NarrativeBuilder::default()
+    }
+}
+
+/// Build one `Narrative`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct NarrativeBuilder {
+    inner: Narrative,
+    status: Option<types::Code>,
+    div: Option<types::Xhtml>,
+}
+
+impl NarrativeBuilder {
+    /// Set `Narrative.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Narrative.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Narrative.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Narrative.status`.
+    pub fn status(mut self, value: impl Into<types::Code>) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Set `Narrative.div`.
+    pub fn div(mut self, value: impl Into<types::Xhtml>) -> Self {
+        self.div = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Narrative, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.status {
+            Some(value) => inner.status = value,
+            None => missing.push("Narrative.status"),
+        }
+        match self.div {
+            Some(value) => inner.div = value,
+            None => missing.push("Narrative.div"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for Narrative {
+    const PATH: &'static str = "Narrative";
 
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.status, &format!("{path}.status"), issues);
+        types::Validate::validate_into(
+            &self.status_element,
+            &format!("{path}._status"),
+            issues,
+        );
+        types::Validate::validate_into(&self.div, &format!("{path}.div"), issues);
+    }
+}
+
+impl types::Walk for Narrative {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_narrative(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.status, &format!("{path}.status"), visitor);
+        types::Walk::accept(&self.status_element, &format!("{path}._status"), visitor);
+        types::Walk::accept(&self.div, &format!("{path}.div"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_narrative_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.status, &format!("{path}.status"), visitor);
+        types::Walk::accept_mut(
+            &mut self.status_element,
+            &format!("{path}._status"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.div, &format!("{path}.div"), visitor);
+    }
+}
+
+impl Narrative {
+    /// The metadata of each child element of `Narrative`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "status",
+            json_key: "status",
+            rust_field: "status",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/narrative-status|5.0.0"),
+            }),
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "div",
+            json_key: "div",
+            rust_field: "div",
+            min: 1,
+            max: Some(1),
+            types: &["xhtml"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Narrative {
+    fn type_name(&self) -> &'static str {
+        "Narrative"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "status" => types::AsValueRef::as_value_ref(&self.status),
+            "div" => types::AsValueRef::as_value_ref(&self.div),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Narrative {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Narrative {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Narrative;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["div", "status"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
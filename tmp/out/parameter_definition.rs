@@ -1,6 +1,6 @@
 //! ParameterDefinition
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/ParameterDefinition
+//! URL: <http://hl7.org/fhir/StructureDefinition/ParameterDefinition>
 //!
 //! Version: 5.0.0
 //!
@@ -10,78 +10,590 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Definition of a parameter to a module
+///
+/// The parameters to the module. This collection specifies both the input and output parameters. Input parameters are provided by the caller as part of the $evaluate operation. Output parameters are included in the GuidanceResponse.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct ParameterDefinition {
-    /// Definition of a parameter to a module
-    ParameterDefinition: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Name used to access the parameter value
-    name: ? // code
+    ///
+    /// The name of the parameter used to allow access to the value of the parameter in evaluation contexts.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    pub name: Option<types::Code>,
+
+    /// Id and extensions of `name`
+    #[serde(rename = "_name")]
+    pub name_element: Option<types::Element>,
 
     /// in | out
-    use: ? // code
+    ///
+    /// Whether the parameter is input or output for the module.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/operation-parameter-use|5.0.0`](http://hl7.org/fhir/ValueSet/operation-parameter-use)
+    ///
+    /// Whether the parameter is input or output.
+    pub r#use: types::Code,
+
+    /// Id and extensions of `use`
+    #[serde(rename = "_use")]
+    pub use_element: Option<types::Element>,
 
     /// Minimum cardinality
-    min: ? // integer
+    ///
+    /// The minimum number of times this parameter SHALL appear in the request or response.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `integer`
+    pub min: Option<types::Integer>,
+
+    /// Id and extensions of `min`
+    #[serde(rename = "_min")]
+    pub min_element: Option<types::Element>,
 
     /// Maximum cardinality (a number of *)
-    max: ? // string
+    ///
+    /// The maximum number of times this element is permitted to appear in the request or response.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub max: Option<types::String>,
+
+    /// Id and extensions of `max`
+    #[serde(rename = "_max")]
+    pub max_element: Option<types::Element>,
 
     /// A brief description of the parameter
-    documentation: ? // string
+    ///
+    /// A brief discussion of what the parameter is for and how it is used by the module.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub documentation: Option<types::String>,
+
+    /// Id and extensions of `documentation`
+    #[serde(rename = "_documentation")]
+    pub documentation_element: Option<types::Element>,
 
     /// What type of value
-    type: ? // code
+    ///
+    /// The type of the parameter.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/fhir-types|5.0.0`](http://hl7.org/fhir/ValueSet/fhir-types)
+    ///
+    /// List of FHIR types (resources, data types).
+    pub r#type: types::Code,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
 
     /// What profile the value is expected to be
-    profile: ? // canonical
+    ///
+    /// If specified, this indicates a profile that the input data must conform to, or that the output data will conform to.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `canonical(StructureDefinition)`
+    pub profile: Option<types::Canonical>,
+
+    /// Id and extensions of `profile`
+    #[serde(rename = "_profile")]
+    pub profile_element: Option<types::Element>,
+}
 
+impl ParameterDefinition {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(r#use: types::Code, r#type: types::Code) -> Self {
+        Self {
+            r#use,
+            r#type,
+            ..Default::default()
+        }
+    }
+}
+
+impl ParameterDefinition {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ParameterDefinitionBuilder {
+        ParameterDefinitionBuilder::default()
+    }
+}
+
+/// Build one `ParameterDefinition`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ParameterDefinitionBuilder {
+    inner: ParameterDefinition,
+    r#use: Option<types::Code>,
+    r#type: Option<types::Code>,
+}
+
+impl ParameterDefinitionBuilder {
+    /// Set `ParameterDefinition.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ParameterDefinition.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ParameterDefinition.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `ParameterDefinition.name`.
+    pub fn name(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.name = Some(value.into());
+        self
+    }
+
+    /// Set `ParameterDefinition.use`.
+    pub fn r#use(mut self, value: impl Into<types::Code>) -> Self {
+        self.r#use = Some(value.into());
+        self
+    }
+
+    /// Set `ParameterDefinition.min`.
+    pub fn min(mut self, value: impl Into<types::Integer>) -> Self {
+        self.inner.min = Some(value.into());
+        self
+    }
+
+    /// Set `ParameterDefinition.max`.
+    pub fn max(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.max = Some(value.into());
+        self
+    }
+
+    /// Set `ParameterDefinition.documentation`.
+    pub fn documentation(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.documentation = Some(value.into());
+        self
+    }
+
+    /// Set `ParameterDefinition.type`.
+    pub fn r#type(mut self, value: impl Into<types::Code>) -> Self {
+        self.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `ParameterDefinition.profile`.
+    pub fn profile(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.profile = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ParameterDefinition, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.r#use {
+            Some(value) => inner.r#use = value,
+            None => missing.push("ParameterDefinition.use"),
+        }
+        match self.r#type {
+            Some(value) => inner.r#type = value,
+            None => missing.push("ParameterDefinition.type"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for ParameterDefinition {
+    const PATH: &'static str = "ParameterDefinition";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.name, &format!("{path}.name"), issues);
+        types::Validate::validate_into(
+            &self.name_element,
+            &format!("{path}._name"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#use, &format!("{path}.use"), issues);
+        types::Validate::validate_into(
+            &self.use_element,
+            &format!("{path}._use"),
+            issues,
+        );
+        types::Validate::validate_into(&self.min, &format!("{path}.min"), issues);
+        types::Validate::validate_into(
+            &self.min_element,
+            &format!("{path}._min"),
+            issues,
+        );
+        types::Validate::validate_into(&self.max, &format!("{path}.max"), issues);
+        types::Validate::validate_into(
+            &self.max_element,
+            &format!("{path}._max"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.documentation,
+            &format!("{path}.documentation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.documentation_element,
+            &format!("{path}._documentation"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.profile,
+            &format!("{path}.profile"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.profile_element,
+            &format!("{path}._profile"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ParameterDefinition {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_parameter_definition(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept(&self.name_element, &format!("{path}._name"), visitor);
+        types::Walk::accept(&self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept(&self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept(&self.min, &format!("{path}.min"), visitor);
+        types::Walk::accept(&self.min_element, &format!("{path}._min"), visitor);
+        types::Walk::accept(&self.max, &format!("{path}.max"), visitor);
+        types::Walk::accept(&self.max_element, &format!("{path}._max"), visitor);
+        types::Walk::accept(
+            &self.documentation,
+            &format!("{path}.documentation"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.documentation_element,
+            &format!("{path}._documentation"),
+            visitor,
+        );
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.profile, &format!("{path}.profile"), visitor);
+        types::Walk::accept(&self.profile_element, &format!("{path}._profile"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_parameter_definition_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept_mut(
+            &mut self.name_element,
+            &format!("{path}._name"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#use, &format!("{path}.use"), visitor);
+        types::Walk::accept_mut(&mut self.use_element, &format!("{path}._use"), visitor);
+        types::Walk::accept_mut(&mut self.min, &format!("{path}.min"), visitor);
+        types::Walk::accept_mut(&mut self.min_element, &format!("{path}._min"), visitor);
+        types::Walk::accept_mut(&mut self.max, &format!("{path}.max"), visitor);
+        types::Walk::accept_mut(&mut self.max_element, &format!("{path}._max"), visitor);
+        types::Walk::accept_mut(
+            &mut self.documentation,
+            &format!("{path}.documentation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.documentation_element,
+            &format!("{path}._documentation"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.profile, &format!("{path}.profile"), visitor);
+        types::Walk::accept_mut(
+            &mut self.profile_element,
+            &format!("{path}._profile"),
+            visitor,
+        );
+    }
+}
+
+impl ParameterDefinition {
+    /// The metadata of each child element of `ParameterDefinition`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "name",
+            json_key: "name",
+            rust_field: "name",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "use",
+            json_key: "use",
+            rust_field: "r#use",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/operation-parameter-use|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "min",
+            json_key: "min",
+            rust_field: "min",
+            min: 0,
+            max: Some(1),
+            types: &["integer"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "max",
+            json_key: "max",
+            rust_field: "max",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "documentation",
+            json_key: "documentation",
+            rust_field: "documentation",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/fhir-types|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "profile",
+            json_key: "profile",
+            rust_field: "profile",
+            min: 0,
+            max: Some(1),
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ParameterDefinition {
+    fn type_name(&self) -> &'static str {
+        "ParameterDefinition"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "name" => types::AsValueRef::as_value_ref(&self.name),
+            "use" => types::AsValueRef::as_value_ref(&self.r#use),
+            "min" => types::AsValueRef::as_value_ref(&self.min),
+            "max" => types::AsValueRef::as_value_ref(&self.max),
+            "documentation" => types::AsValueRef::as_value_ref(&self.documentation),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "profile" => types::AsValueRef::as_value_ref(&self.profile),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ParameterDefinition {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ParameterDefinition {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = ParameterDefinition;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["type", "use"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! Period
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Period
+//! URL: <http://hl7.org/fhir/StructureDefinition/Period>
 //!
 //! Version: 5.0.0
 //!
@@ -10,63 +10,326 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Time range defined by start and end date/time
+///
+/// A time period defined by a start and end date and optionally time.
+///
+/// Comment: A Period specifies a range of time; the context of use will specify whether the entire range applies (e.g. "the patient was an inpatient of the hospital for this time range") or one value from the range applies (e.g. "give to the patient between these two times").
+///
+/// Period is not used for a duration (a measure of elapsed time). See [Duration](https://build.fhir.org/datatypes.html#Duration).
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `per-1`: If present, start SHALL have a lower or equal value than end
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Period {
-    /// Time range defined by start and end date/time
-    Period: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Starting time with inclusive boundary
-    start: ? // dateTime
+    ///
+    /// The start of the period. The boundary is inclusive.
+    ///
+    /// Comment: If the low element is missing, the meaning is that the low boundary is not known.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `dateTime`
+    pub start: Option<types::DateTime>,
+
+    /// Id and extensions of `start`
+    #[serde(rename = "_start")]
+    pub start_element: Option<types::Element>,
 
     /// End time with inclusive boundary, if not ongoing
-    end: ? // dateTime
+    ///
+    /// The end of the period. If the end of the period is missing, it means no end was known or planned at the time the instance was created. The start may be in the past, and the end date in the future, which means that period is expected/planned to end at that time.
+    ///
+    /// Comment: The end value includes any matching date/time. i.e. 2012-02-03T10:00:00 is in a period that has an end value of 2012-02-03.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `dateTime`
+    pub end: Option<types::DateTime>,
+
+    /// Id and extensions of `end`
+    #[serde(rename = "_end")]
+    pub end_element: Option<types::Element>,
+}
+
+impl Period {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Period {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> PeriodBuilder {
+        PeriodBuilder::default()
+    }
+}
+
+/// Build one `Period`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct PeriodBuilder {
+    inner: Period,
+}
+
+impl PeriodBuilder {
+    /// Set `Period.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Period.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Period.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Period.start`.
+    pub fn start(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.start = Some(value.into());
+        self
+    }
+
+    /// Set `Period.end`.
+    pub fn end(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.end = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Period, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Period {
+    const PATH: &'static str = "Period";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.start, &format!("{path}.start"), issues);
+        types::Validate::validate_into(
+            &self.start_element,
+            &format!("{path}._start"),
+            issues,
+        );
+        types::Validate::validate_into(&self.end, &format!("{path}.end"), issues);
+        types::Validate::validate_into(
+            &self.end_element,
+            &format!("{path}._end"),
+            issues,
+        );
+    }
+}
 
+impl types::Walk for Period {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_period(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.start, &format!("{path}.start"), visitor);
+        types::Walk::accept(&self.start_element, &format!("{path}._start"), visitor);
+        types::Walk::accept(&self.end, &format!("{path}.end"), visitor);
+        types::Walk::accept(&self.end_element, &format!("{path}._end"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_period_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.start, &format!("{path}.start"), visitor);
+        types::Walk::accept_mut(
+            &mut self.start_element,
+            &format!("{path}._start"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.end, &format!("{path}.end"), visitor);
+        types::Walk::accept_mut(&mut self.end_element, &format!("{path}._end"), visitor);
+    }
+}
+
+impl Period {
+    /// The metadata of each child element of `Period`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "start",
+            json_key: "start",
+            rust_field: "start",
+            min: 0,
+            max: Some(1),
+            types: &["dateTime"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "end",
+            json_key: "end",
+            rust_field: "end",
+            min: 0,
+            max: Some(1),
+            types: &["dateTime"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Period {
+    fn type_name(&self) -> &'static str {
+        "Period"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "start" => types::AsValueRef::as_value_ref(&self.start),
+            "end" => types::AsValueRef::as_value_ref(&self.end),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Period {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Period {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Period;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
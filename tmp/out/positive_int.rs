@@ -1,69 +1,111 @@
 //! PositiveInt
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/positiveInt
+//! URL: <http://hl7.org/fhir/StructureDefinition/positiveInt>
 //!
 //! Version: 5.0.0
 //!
-//! positiveInt type: An integer with a value that is positive (e.g. >0)
+//! positiveInt type: An integer with a value that is positive (e.g. &gt;0)
 //!
 //! FHIR: <https://build.fhir.org/>
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
-#[serde_with::skip_serializing_none]
+/// Wrap the value of the FHIR `positiveInt` primitive type.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+/// Serialize as the bare JSON value, without a wrapping object.
+#[serde(transparent)]
 pub struct PositiveInt {
-    /// Primitive Type positiveInt
-    positiveInt: ? // ?
+    pub value: u32,
+}
+
+impl PositiveInt {
+    /// Create with the value.
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<u32> for PositiveInt {
+    fn from(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl types::Validate for PositiveInt {
+    const PATH: &'static str = "positiveInt";
+
+    fn validate_into(&self, _path: &str, _issues: &mut Vec<types::ValidationIssue>) {}
+}
+
+impl types::Walk for PositiveInt {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_positive_int(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_positive_int_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, _path: &str, _visitor: &mut V) {}
+}
 
-    /// xml:id (or equivalent in JSON)
-    id: ? // http://hl7.org/fhirpath/System.String
+impl PositiveInt {
+    /// The metadata of each child element of `positiveInt`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[];
+}
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+impl types::Reflect for PositiveInt {
+    fn type_name(&self) -> &'static str {
+        "positiveInt"
+    }
 
-    /// Primitive value for positiveInt
-    value: ? // http://hl7.org/fhirpath/System.String
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
 
+    fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {
+        None
+    }
+}
+
+impl types::AsValueRef for PositiveInt {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        types::AsValueRef::as_value_ref(&self.value)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = PositiveInt;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        assert_eq!(actual, ::serde_json::json!(0));
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
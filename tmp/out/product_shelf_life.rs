@@ -1,6 +1,6 @@
 //! ProductShelfLife
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/ProductShelfLife
+//! URL: <http://hl7.org/fhir/StructureDefinition/ProductShelfLife>
 //!
 //! Version: 5.0.0
 //!
@@ -10,69 +10,548 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// The shelf-life and storage information for a medicinal product item or container can be described using this class
+///
+/// The shelf-life and storage information for a medicinal product item or container can be described using this class.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
 pub struct ProductShelfLife {
-    /// The shelf-life and storage information for a medicinal product item or container can be described using this class
-    ProductShelfLife: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Extensions that cannot be ignored even if unrecognized
-    modifierExtension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element and that modifies the understanding of the element in which it is contained and/or the understanding of the containing element's descendants. Usually modifier elements provide negation or qualification. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension. Applications processing a resource are required to check for modifier extensions.
+    ///
+    /// Modifier extensions SHALL NOT change the meaning of any elements on Resource or DomainResource (including cannot change the meaning of modifierExtension itself).
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Requirements: Modifier extensions allow for extensions that *cannot* be safely ignored to be clearly distinguished from the vast majority of extensions which can be safely ignored.  This promotes interoperability by eliminating the need for implementers to prohibit the presence of extensions. For further information, see the [definition of modifier extensions](https://build.fhir.org/extensibility.html#modifierExtension).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    ///
+    /// Modifier: Modifier extensions are expected to modify the meaning or interpretation of the element that contains them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modifier_extension: Vec<types::Extension>,
 
     /// This describes the shelf life, taking into account various scenarios such as shelf life of the packaged Medicinal Product itself, shelf life after transformation where necessary and shelf life after the first opening of a bottle, etc. The shelf life type shall be specified using an appropriate controlled vocabulary The controlled term and the controlled term identifier shall be specified
-    type: ? // CodeableConcept
+    ///
+    /// This describes the shelf life, taking into account various scenarios such as shelf life of the packaged Medicinal Product itself, shelf life after transformation where necessary and shelf life after the first opening of a bottle, etc. The shelf life type shall be specified using an appropriate controlled vocabulary The controlled term and the controlled term identifier shall be specified.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    pub r#type: Option<types::CodeableConcept>,
 
     /// The shelf life time period can be specified using a numerical value for the period of time and its unit of time measurement The unit of measurement shall be specified in accordance with ISO 11240 and the resulting terminology The symbol and the symbol identifier shall be used
-    : ? // Duration
+    ///
+    /// The shelf life time period can be specified using a numerical value for the period of time and its unit of time measurement The unit of measurement shall be specified in accordance with ISO 11240 and the resulting terminology The symbol and the symbol identifier shall be used.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Duration`, `string`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub period: Option<ProductShelfLifePeriod>,
+
+    /// Id and extensions of `period[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub period_element: Option<ProductShelfLifePeriodElement>,
 
     /// Special precautions for storage, if any, can be specified using an appropriate controlled vocabulary The controlled term and the controlled term identifier shall be specified
-    specialPrecautionsForStorage: ? // CodeableConcept
+    ///
+    /// Special precautions for storage, if any, can be specified using an appropriate controlled vocabulary The controlled term and the controlled term identifier shall be specified.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `CodeableConcept`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub special_precautions_for_storage: Vec<types::CodeableConcept>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl ProductShelfLife {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ProductShelfLife {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ProductShelfLifeBuilder {
+        ProductShelfLifeBuilder::default()
+    }
+}
 
+/// Build one `ProductShelfLife`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ProductShelfLifeBuilder {
+    inner: ProductShelfLife,
+}
+
+impl ProductShelfLifeBuilder {
+    /// Set `ProductShelfLife.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `ProductShelfLife.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `ProductShelfLife.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `ProductShelfLife.modifierExtension`.
+    pub fn modifier_extension(
+        mut self,
+        value: impl Into<Vec<types::Extension>>,
+    ) -> Self {
+        self.inner.modifier_extension = value.into();
+        self
+    }
+
+    /// Add one `ProductShelfLife.modifierExtension`.
+    pub fn push_modifier_extension(
+        mut self,
+        value: impl Into<types::Extension>,
+    ) -> Self {
+        self.inner.modifier_extension.push(value.into());
+        self
+    }
+
+    /// Set `ProductShelfLife.type`.
+    pub fn r#type(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `ProductShelfLife.period[x]` to a `Duration`.
+    pub fn period_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.inner.period = Some(ProductShelfLifePeriod::Duration(value.into()));
+        self
+    }
+
+    /// Set `ProductShelfLife.period[x]` to a `string`.
+    pub fn period_string(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.period = Some(ProductShelfLifePeriod::String(value.into()));
+        self
+    }
+
+    /// Set every `ProductShelfLife.specialPrecautionsForStorage`.
+    pub fn special_precautions_for_storage(
+        mut self,
+        value: impl Into<Vec<types::CodeableConcept>>,
+    ) -> Self {
+        self.inner.special_precautions_for_storage = value.into();
+        self
+    }
+
+    /// Add one `ProductShelfLife.specialPrecautionsForStorage`.
+    pub fn push_special_precautions_for_storage(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.inner.special_precautions_for_storage.push(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<ProductShelfLife, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for ProductShelfLife {
+    const PATH: &'static str = "ProductShelfLife";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(&self.period, &format!("{path}.period"), issues);
+        types::Validate::validate_into(
+            &self.period_element,
+            &format!("{path}._period"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.special_precautions_for_storage,
+            &format!("{path}.specialPrecautionsForStorage"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for ProductShelfLife {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_product_shelf_life(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.period, &format!("{path}.period"), visitor);
+        types::Walk::accept(&self.period_element, &format!("{path}._period"), visitor);
+        types::Walk::accept(
+            &self.special_precautions_for_storage,
+            &format!("{path}.specialPrecautionsForStorage"),
+            visitor,
+        );
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_product_shelf_life_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(&mut self.period, &format!("{path}.period"), visitor);
+        types::Walk::accept_mut(
+            &mut self.period_element,
+            &format!("{path}._period"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.special_precautions_for_storage,
+            &format!("{path}.specialPrecautionsForStorage"),
+            visitor,
+        );
+    }
+}
+
+impl ProductShelfLife {
+    /// The metadata of each child element of `ProductShelfLife`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "modifierExtension",
+            json_key: "modifierExtension",
+            rust_field: "modifier_extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "period[x]",
+            json_key: "period",
+            rust_field: "period",
+            min: 0,
+            max: Some(1),
+            types: &["Duration", "string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "specialPrecautionsForStorage",
+            json_key: "specialPrecautionsForStorage",
+            rust_field: "special_precautions_for_storage",
+            min: 0,
+            max: None,
+            types: &["CodeableConcept"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for ProductShelfLife {
+    fn type_name(&self) -> &'static str {
+        "ProductShelfLife"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "modifierExtension" => {
+                types::AsValueRef::as_value_ref(&self.modifier_extension)
+            }
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "period[x]" | "period" => types::AsValueRef::as_value_ref(&self.period),
+            "specialPrecautionsForStorage" => {
+                types::AsValueRef::as_value_ref(&self.special_precautions_for_storage)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for ProductShelfLife {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for ProductShelfLife {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `ProductShelfLife.period[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProductShelfLifePeriod {
+    #[serde(rename = "periodDuration")]
+    Duration(types::Duration),
+    #[serde(rename = "periodString")]
+    String(types::String),
+}
+
+impl Default for ProductShelfLifePeriod {
+    fn default() -> Self {
+        Self::Duration(Default::default())
+    }
+}
+
+impl types::Choice for ProductShelfLifePeriod {
+    const KEYS: &'static [&'static str] = &["periodDuration", "periodString"];
+}
+
+impl types::Validate for ProductShelfLifePeriod {
+    const PATH: &'static str = "ProductShelfLife.period";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ProductShelfLifePeriod {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for ProductShelfLifePeriod {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+            Self::String(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `ProductShelfLife.period[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProductShelfLifePeriodElement {
+    #[serde(rename = "_periodString")]
+    String(types::Element),
+}
+
+impl types::Choice for ProductShelfLifePeriodElement {
+    const KEYS: &'static [&'static str] = &["_periodString"];
+}
+
+impl types::Validate for ProductShelfLifePeriodElement {
+    const PATH: &'static str = "ProductShelfLife.period";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for ProductShelfLifePeriodElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = ProductShelfLife;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -0,0 +1,224 @@
+//! Profile
+//!
+//! The rules of a constraint profile, checked on the JSON of a value of its
+//! base type.
+//!
+//! A rule path is a FHIR element path, such as `Quantity.comparator`. A
+//! choice element, such as `Extension.value[x]`, matches any of its JSON
+//! keys, such as `valueString`.
+
+use ::serde::Serialize;
+use ::serde_json::Value;
+
+/// One rule of a constraint profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileRule {
+    /// Each parent has between min and max values of the element, or at
+    /// least min when max is none.
+    Cardinality { path: &'static str, min: usize, max: Option<usize> },
+
+    /// Each value of the element equals the JSON value.
+    Fixed { path: &'static str, value: &'static str },
+
+    /// Each value of the element has every part of the JSON value.
+    Pattern { path: &'static str, value: &'static str },
+
+    /// Each parent has between min and max values of the element that
+    /// match the slice: at each discriminator path, such as `url`, a value
+    /// has every part of the JSON value.
+    Slice {
+        path: &'static str,
+        name: &'static str,
+        discriminators: &'static [(&'static str, &'static str)],
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+/// One rule that a value fails, such as `Quantity.comparator` with the
+/// message `has 1 values, but allows 0..0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileIssue {
+    pub path: String,
+    pub message: String,
+}
+
+/// Every rule of a profile that a value fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileIssues {
+    /// The canonical URL of the profile.
+    pub profile: &'static str,
+    pub issues: Vec<ProfileIssue>,
+}
+
+impl std::fmt::Display for ProfileIssues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "does not conform to profile {}: ", self.profile)?;
+        let issues: Vec<String> = self
+            .issues
+            .iter()
+            .map(|issue| format!("{} {}", issue.path, issue.message))
+            .collect();
+        write!(f, "{}", issues.join("; "))
+    }
+}
+
+impl std::error::Error for ProfileIssues {}
+
+/// Check every rule on the JSON of the value.
+pub fn check_profile_rules<T: Serialize>(
+    profile: &'static str,
+    value: &T,
+    rules: &[ProfileRule],
+) -> Result<(), ProfileIssues> {
+    let mut issues = vec![];
+    match ::serde_json::to_value(value) {
+        Ok(json) => {
+            for rule in rules {
+                check_rule(&json, rule, &mut issues);
+            }
+        }
+        Err(error) => {
+            issues
+                .push(ProfileIssue {
+                    path: String::new(),
+                    message: error.to_string(),
+                })
+        }
+    }
+    if issues.is_empty() { Ok(()) } else { Err(ProfileIssues { profile, issues }) }
+}
+
+/// Does the item match each discriminator of a slice?
+pub fn slice_matches<T: Serialize>(item: &T, discriminators: &[(&str, &str)]) -> bool {
+    ::serde_json::to_value(item)
+        .is_ok_and(|json| json_slice_matches(&json, discriminators))
+}
+
+fn check_rule(json: &Value, rule: &ProfileRule, issues: &mut Vec<ProfileIssue>) {
+    let mut issue = |path: &str, message: String| {
+        issues
+            .push(ProfileIssue {
+                path: String::from(path),
+                message,
+            })
+    };
+    match *rule {
+        ProfileRule::Cardinality { path, min, max } => {
+            for count in counts(json, path, |_| true) {
+                if count < min || max.is_some_and(|max| count > max) {
+                    issue(
+                        path,
+                        format!("has {count} values, but allows {}", range(min, max)),
+                    );
+                }
+            }
+        }
+        ProfileRule::Fixed { path, value } => {
+            let expect: Value = ::serde_json::from_str(value).unwrap_or_default();
+            if select(json, relative_path(path)).any(|x| *x != expect) {
+                issue(path, format!("is not the fixed value {value}"));
+            }
+        }
+        ProfileRule::Pattern { path, value } => {
+            let expect: Value = ::serde_json::from_str(value).unwrap_or_default();
+            if select(json, relative_path(path)).any(|x| !contains(x, &expect)) {
+                issue(path, format!("does not match the pattern {value}"));
+            }
+        }
+        ProfileRule::Slice { path, name, discriminators, min, max } => {
+            for count in counts(json, path, |x| json_slice_matches(x, discriminators)) {
+                if count < min || max.is_some_and(|max| count > max) {
+                    issue(
+                        path,
+                        format!(
+                            "has {count} values of slice {name}, but allows {}",
+                            range(min, max)
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn range(min: usize, max: Option<usize>) -> String {
+    match max {
+        Some(max) => format!("{min}..{max}"),
+        None => format!("{min}..*"),
+    }
+}
+
+/// Strip the type name of an element path, such as `Quantity.` of
+/// `Quantity.comparator`.
+fn relative_path(path: &str) -> &str {
+    path.split_once('.').map(|x| x.1).unwrap_or_default()
+}
+
+/// For each parent of the element, count its values that match.
+fn counts(json: &Value, path: &str, matches: impl Fn(&Value) -> bool) -> Vec<usize> {
+    let path = relative_path(path);
+    let (parent, key) = path.rsplit_once('.').unwrap_or(("", path));
+    select(json, parent)
+        .map(|parent| children(parent, key).filter(|x| matches(x)).count())
+        .collect()
+}
+
+fn json_slice_matches(json: &Value, discriminators: &[(&str, &str)]) -> bool {
+    discriminators
+        .iter()
+        .all(|(path, value)| {
+            let expect: Value = ::serde_json::from_str(value).unwrap_or_default();
+            select(json, path).any(|x| contains(x, &expect))
+        })
+}
+
+/// Select the values at a dotted path, such as `coding.code`, with each
+/// array flattened.
+fn select<'a>(
+    json: &'a Value,
+    path: &'a str,
+) -> Box<dyn Iterator<Item = &'a Value> + 'a> {
+    path.split('.')
+        .filter(|key| !key.is_empty() && *key != "$this")
+        .fold(
+            Box::new(std::iter::once(json)) as Box<dyn Iterator<Item = &'a Value> + 'a>,
+            |values, key| Box::new(values.flat_map(move |value| children(value, key))),
+        )
+}
+
+/// Select the values of one key, such as `code`, or of a choice key, such
+/// as `value[x]`, with an array flattened.
+fn children<'a>(json: &'a Value, key: &'a str) -> impl Iterator<Item = &'a Value> + 'a {
+    json.as_object()
+        .into_iter()
+        .flatten()
+        .filter(move |(name, _)| match key.strip_suffix("[x]") {
+            Some(prefix) => {
+                name.strip_prefix(prefix)
+                    .is_some_and(|rest| {
+                        rest.starts_with(|c: char| c.is_ascii_uppercase())
+                    })
+            }
+            None => *name == key,
+        })
+        .flat_map(|(_, value)| match value {
+            Value::Array(items) => items.iter().collect::<Vec<&Value>>(),
+            value => vec![value],
+        })
+}
+
+/// Does the value have every part of the pattern? Each key of a pattern
+/// object is in the value, and each item of a pattern array matches an item
+/// of the value.
+fn contains(value: &Value, pattern: &Value) -> bool {
+    match (value, pattern) {
+        (Value::Object(value), Value::Object(pattern)) => {
+            pattern.iter().all(|(key, x)| value.get(key).is_some_and(|y| contains(y, x)))
+        }
+        (Value::Array(value), Value::Array(pattern)) => {
+            pattern.iter().all(|x| value.iter().any(|y| contains(y, x)))
+        }
+        (value, pattern) => value == pattern,
+    }
+}
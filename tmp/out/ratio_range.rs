@@ -1,6 +1,6 @@
 //! RatioRange
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/RatioRange
+//! URL: <http://hl7.org/fhir/StructureDefinition/RatioRange>
 //!
 //! Version: 5.0.0
 //!
@@ -10,66 +10,361 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Range of ratio values
+///
+/// A range of ratios expressed as a low and high numerator and a denominator.
+///
+/// Comment: The stated low and high value are assumed to have arbitrarily high precision when it comes to determining which values are in the range. I.e. 1.99 is not in the range 2 -&gt; 3.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `ratrng-1`: One of lowNumerator or highNumerator and denominator SHALL be present, or all are absent. If all are absent, there SHALL be some extension present
+/// - `ratrng-2`: If present, lowNumerator SHALL have a lower value than highNumerator
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct RatioRange {
-    /// Range of ratio values
-    RatioRange: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Low Numerator limit
-    lowNumerator: ? // Quantity
+    ///
+    /// The value of the low limit numerator.
+    ///
+    /// Comment: If the low element is missing, the low boundary is not known.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Quantity`
+    pub low_numerator: Option<types::Quantity>,
 
     /// High Numerator limit
-    highNumerator: ? // Quantity
+    ///
+    /// The value of the high limit numerator.
+    ///
+    /// Comment: If the high element is missing, the high boundary is not known.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Quantity`
+    pub high_numerator: Option<types::Quantity>,
 
     /// Denominator value
-    denominator: ? // Quantity
+    ///
+    /// The value of the denominator.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Quantity`
+    pub denominator: Option<types::Quantity>,
+}
+
+impl RatioRange {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RatioRange {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> RatioRangeBuilder {
+        RatioRangeBuilder::default()
+    }
+}
+
+/// Build one `RatioRange`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct RatioRangeBuilder {
+    inner: RatioRange,
+}
+
+impl RatioRangeBuilder {
+    /// Set `RatioRange.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `RatioRange.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `RatioRange.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `RatioRange.lowNumerator`.
+    pub fn low_numerator(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.low_numerator = Some(value.into());
+        self
+    }
+
+    /// Set `RatioRange.highNumerator`.
+    pub fn high_numerator(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.high_numerator = Some(value.into());
+        self
+    }
+
+    /// Set `RatioRange.denominator`.
+    pub fn denominator(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.inner.denominator = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<RatioRange, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for RatioRange {
+    const PATH: &'static str = "RatioRange";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.low_numerator,
+            &format!("{path}.lowNumerator"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.high_numerator,
+            &format!("{path}.highNumerator"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.denominator,
+            &format!("{path}.denominator"),
+            issues,
+        );
+    }
+}
 
+impl types::Walk for RatioRange {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_ratio_range(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(
+            &self.low_numerator,
+            &format!("{path}.lowNumerator"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.high_numerator,
+            &format!("{path}.highNumerator"),
+            visitor,
+        );
+        types::Walk::accept(&self.denominator, &format!("{path}.denominator"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_ratio_range_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.low_numerator,
+            &format!("{path}.lowNumerator"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.high_numerator,
+            &format!("{path}.highNumerator"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.denominator,
+            &format!("{path}.denominator"),
+            visitor,
+        );
+    }
+}
+
+impl RatioRange {
+    /// The metadata of each child element of `RatioRange`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "lowNumerator",
+            json_key: "lowNumerator",
+            rust_field: "low_numerator",
+            min: 0,
+            max: Some(1),
+            types: &["Quantity"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "highNumerator",
+            json_key: "highNumerator",
+            rust_field: "high_numerator",
+            min: 0,
+            max: Some(1),
+            types: &["Quantity"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "denominator",
+            json_key: "denominator",
+            rust_field: "denominator",
+            min: 0,
+            max: Some(1),
+            types: &["Quantity"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for RatioRange {
+    fn type_name(&self) -> &'static str {
+        "RatioRange"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "lowNumerator" => types::AsValueRef::as_value_ref(&self.low_numerator),
+            "highNumerator" => types::AsValueRef::as_value_ref(&self.high_numerator),
+            "denominator" => types::AsValueRef::as_value_ref(&self.denominator),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for RatioRange {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for RatioRange {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = RatioRange;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
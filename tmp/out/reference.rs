@@ -1,6 +1,6 @@
 //! Reference
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Reference
+//! URL: <http://hl7.org/fhir/StructureDefinition/Reference>
 //!
 //! Version: 5.0.0
 //!
@@ -10,69 +10,449 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// A reference from one resource to another
+///
+/// A reference from one resource to another.
+///
+/// Comment: References SHALL be a reference to an actual FHIR resource, and SHALL be resolvable (allowing for access control, temporary unavailability, etc.). Resolution can be either by retrieval from the URL, or, where applicable by resource type, by treating an absolute reference as a canonical URL and looking it up in a local registry/repository.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `ref-1`: SHALL have a contained resource if a local reference is provided
+/// - `ref-2`: At least one of reference, identifier and display SHALL be present (unless an extension is provided).
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Reference {
-    /// A reference from one resource to another
-    Reference: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Literal reference, Relative, internal or absolute URL
-    reference: ? // string
+    ///
+    /// A reference to a location at which the other resource is found. The reference may be a relative reference, in which case it is relative to the service base URL, or an absolute URL that resolves to the location where the resource is found. The reference may be version specific or not. If the reference is not to a FHIR RESTful server, then it should be assumed to be version specific. Internal fragment references (start with '#') refer to contained resources.
+    ///
+    /// Comment: Using absolute URLs provides a stable scalable approach suitable for a cloud/web context, while using relative/logical references provides a flexible approach suitable for use when trading across closed eco-system boundaries.   Absolute URLs do not need to point to a FHIR RESTful server, though this is the preferred approach. If the URL conforms to the structure "\[type\]/\[id\]" then it should be assumed that the reference is to a FHIR RESTful server.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub reference: Option<types::String>,
+
+    /// Id and extensions of `reference`
+    #[serde(rename = "_reference")]
+    pub reference_element: Option<types::Element>,
 
     /// Type the reference refers to (e.g. "Patient") - must be a resource in resources
-    type: ? // uri
+    ///
+    /// The expected type of the target of the reference. If both Reference.type and Reference.reference are populated and Reference.reference is a FHIR URL, both SHALL be consistent.
+    ///
+    /// The type is the Canonical URL of Resource Definition that is the type this reference refers to. References are URLs that are relative to <http://hl7.org/fhir/StructureDefinition/> e.g. "Patient" is a reference to <http://hl7.org/fhir/StructureDefinition/Patient>. Absolute URLs are only allowed for logical models (and can only be used in references in logical models, not resources).
+    ///
+    /// Comment: This element is used to indicate the type of  the target of the reference. This may be used which ever of the other elements are populated (or not). In some cases, the type of the target may be determined by inspection of the reference (e.g. a known RESTful URL) or by resolving the target of the reference.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `uri`
+    ///
+    /// Binding: extensible to [`http://hl7.org/fhir/ValueSet/resource-types`](http://hl7.org/fhir/ValueSet/resource-types)
+    ///
+    /// Aa resource (or, for logical models, the URI of the logical model).
+    pub r#type: Option<types::Uri>,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
 
     /// Logical reference, when literal reference is not known
-    identifier: ? // Identifier
+    ///
+    /// An identifier for the target resource. This is used when there is no way to reference the other resource directly, either because the entity it represents is not available through a FHIR server, or because there is no way for the author of the resource to convert a known identifier to an actual location. There is no requirement that a Reference.identifier point to something that is actually exposed as a FHIR instance, but it SHALL point to a business concept that would be expected to be exposed as a FHIR instance, and that instance would need to be of a FHIR resource type allowed by the reference.
+    ///
+    /// Comment: When an identifier is provided in place of a reference, any system processing the reference will only be able to resolve the identifier to a reference if it understands the business context in which the identifier is used. Sometimes this is global (e.g. a national identifier) but often it is not. For this reason, none of the useful mechanisms described for working with references (e.g. chaining, includes) are possible, nor should servers be expected to be able resolve the reference. Servers may accept an identifier based reference untouched, resolve it, and/or reject it - see CapabilityStatement.rest.resource.referencePolicy.
+    ///
+    /// When both an identifier and a literal reference are provided, the literal reference is preferred. Applications processing the resource are allowed - but not required - to check that the identifier matches the literal reference
+    ///
+    /// Applications converting a logical reference to a literal reference may choose to leave the logical reference present, or remove it.
+    ///
+    /// Reference is intended to point to a structure that can potentially be expressed as a FHIR resource, though there is no need for it to exist as an actual FHIR resource instance - except in as much as an application wishes to actual find the target of the reference. The content referred to be the identifier must meet the logical constraints implied by any limitations on what resource types are permitted for the reference.  For example, it would not be legitimate to send the identifier for a drug prescription if the type were Reference(Observation|DiagnosticReport).  One of the use-cases for Reference.identifier is the situation where no FHIR representation exists (where the type is Reference (Any).
+    ///
+    /// This element only allows for a single identifier. In the case where additional identifers are required, use the [http://hl7.org/fhir/StructureDefinition/additionalIdentifier](http://hl7.org/fhir/extensions/StructureDefinition-additionalIdentifier.html) extension.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Identifier`
+    pub identifier: Option<Box<types::Identifier>>,
 
     /// Text alternative for the resource
-    display: ? // string
+    ///
+    /// Plain text narrative that identifies the resource in addition to the resource reference.
+    ///
+    /// Comment: This is generally not the same as the Resource.text of the referenced resource.  The purpose is to identify what's being referenced, not to fully describe it.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub display: Option<types::String>,
+
+    /// Id and extensions of `display`
+    #[serde(rename = "_display")]
+    pub display_element: Option<types::Element>,
+}
+
+impl Reference {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
+impl Reference {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> ReferenceBuilder {
+        ReferenceBuilder::default()
+    }
+}
+
+/// Build one `Reference`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct ReferenceBuilder {
+    inner: Reference,
+}
+
+impl ReferenceBuilder {
+    /// Set `Reference.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Reference.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Reference.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Reference.reference`.
+    pub fn reference(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.reference = Some(value.into());
+        self
+    }
+
+    /// Set `Reference.type`.
+    pub fn r#type(mut self, value: impl Into<types::Uri>) -> Self {
+        self.inner.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `Reference.identifier`.
+    pub fn identifier(mut self, value: impl Into<Box<types::Identifier>>) -> Self {
+        self.inner.identifier = Some(value.into());
+        self
+    }
+
+    /// Set `Reference.display`.
+    pub fn display(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.display = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Reference, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Reference {
+    const PATH: &'static str = "Reference";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.reference,
+            &format!("{path}.reference"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.reference_element,
+            &format!("{path}._reference"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.identifier,
+            &format!("{path}.identifier"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.display,
+            &format!("{path}.display"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.display_element,
+            &format!("{path}._display"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Reference {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_reference(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.reference, &format!("{path}.reference"), visitor);
+        types::Walk::accept(
+            &self.reference_element,
+            &format!("{path}._reference"),
+            visitor,
+        );
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.identifier, &format!("{path}.identifier"), visitor);
+        types::Walk::accept(&self.display, &format!("{path}.display"), visitor);
+        types::Walk::accept(&self.display_element, &format!("{path}._display"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_reference_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.reference,
+            &format!("{path}.reference"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.reference_element,
+            &format!("{path}._reference"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.identifier,
+            &format!("{path}.identifier"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.display, &format!("{path}.display"), visitor);
+        types::Walk::accept_mut(
+            &mut self.display_element,
+            &format!("{path}._display"),
+            visitor,
+        );
+    }
+}
+
+impl Reference {
+    /// The metadata of each child element of `Reference`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "reference",
+            json_key: "reference",
+            rust_field: "reference",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 0,
+            max: Some(1),
+            types: &["uri"],
+            binding: Some(types::BindingInfo {
+                strength: "extensible",
+                value_set: Some("http://hl7.org/fhir/ValueSet/resource-types"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "identifier",
+            json_key: "identifier",
+            rust_field: "identifier",
+            min: 0,
+            max: Some(1),
+            types: &["Identifier"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "display",
+            json_key: "display",
+            rust_field: "display",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Reference {
+    fn type_name(&self) -> &'static str {
+        "Reference"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "reference" => types::AsValueRef::as_value_ref(&self.reference),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "identifier" => types::AsValueRef::as_value_ref(&self.identifier),
+            "display" => types::AsValueRef::as_value_ref(&self.display),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Reference {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Reference {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Reference;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -0,0 +1,186 @@
+//! Reflect
+//!
+//! The metadata of each child element of a generated type, from its
+//! StructureDefinition, such as its cardinality and its types, and the value
+//! of each child element by its FHIR name, such as `birthDate`.
+
+/// The binding of a coded element to a ValueSet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BindingInfo {
+    /// The binding strength, such as `required` or `extensible`.
+    pub strength: &'static str,
+
+    /// The canonical URL of the ValueSet, if any.
+    pub value_set: Option<&'static str>,
+}
+
+/// The metadata of one child element of a generated type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ElementInfo {
+    /// The FHIR name, such as `birthDate` or `value[x]`.
+    pub name: &'static str,
+
+    /// The JSON key, such as `birthDate`. A choice element has the prefix of
+    /// the key of each type, such as `value` for `valueQuantity`.
+    pub json_key: &'static str,
+
+    /// The Rust field, such as `birth_date`.
+    pub rust_field: &'static str,
+
+    /// The minimum cardinality.
+    pub min: u32,
+
+    /// The maximum cardinality, or none for `*`.
+    pub max: Option<u32>,
+
+    /// The FHIR type codes, such as `Quantity` and `string`.
+    pub types: &'static [&'static str],
+
+    /// The binding to a ValueSet, if any.
+    pub binding: Option<BindingInfo>,
+
+    /// Is the element in the summary of the resource?
+    pub is_summary: bool,
+
+    /// Can the element change the meaning of the resource, such as
+    /// `Patient.active`?
+    pub is_modifier: bool,
+}
+
+impl ElementInfo {
+    /// Is the name the FHIR name or the JSON key of the element?
+    pub fn matches(&self, name: &str) -> bool {
+        name == self.name || name == self.json_key
+    }
+
+    /// Can the element repeat, such as `Patient.name`?
+    pub fn is_list(&self) -> bool {
+        !matches!(self.max, Some(0 | 1))
+    }
+}
+
+/// A borrowed view of one value, by its FHIRPath kind.
+#[derive(Debug, Clone)]
+pub enum ValueRef<'a> {
+    /// The value of a `boolean`.
+    Boolean(bool),
+
+    /// The value of an `integer`, `integer64`, `positiveInt` or
+    /// `unsignedInt`.
+    Integer(i64),
+
+    /// The value of a `decimal`, with its exact digits.
+    Decimal(&'a ::serde_json::Number),
+
+    /// The value of any other primitive, such as a `string`, `code` or
+    /// `dateTime`.
+    String(&'a str),
+
+    /// A JSON value without a generated type.
+    Json(&'a ::serde_json::Value),
+
+    /// A value of a generated type, such as a `Quantity` or a `Patient`.
+    Element(&'a dyn Reflect),
+
+    /// The values of a repeating element, such as `Patient.name`.
+    List(Vec<ValueRef<'a>>),
+}
+
+impl<'a> ValueRef<'a> {
+    /// The value of a generated type, if it is one.
+    pub fn as_element(&self) -> Option<&'a dyn Reflect> {
+        match self {
+            Self::Element(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value of a child element by its FHIR name, when the value is of
+    /// a generated type.
+    pub fn child(&self, name: &str) -> Option<ValueRef<'a>> {
+        self.as_element()?.child(name)
+    }
+}
+
+/// View a value as a `ValueRef`.
+pub trait AsValueRef {
+    /// The view of the value, or none when the value is absent, such as an
+    /// empty option or an empty list.
+    fn as_value_ref(&self) -> Option<ValueRef<'_>>;
+}
+
+/// Reflect on a value of a generated type.
+pub trait Reflect: std::fmt::Debug {
+    /// The FHIR type name, such as `Patient`, or the element path of an
+    /// inline type, such as `Patient.contact`.
+    fn type_name(&self) -> &'static str;
+
+    /// The metadata of each child element, in the order of the
+    /// StructureDefinition.
+    fn element_infos(&self) -> &'static [ElementInfo];
+
+    /// The value of a child element by its FHIR name or JSON key, such as
+    /// `value[x]` or `value`, or none when it is absent or unknown.
+    fn child(&self, name: &str) -> Option<ValueRef<'_>>;
+
+    /// The metadata of a child element by its FHIR name or JSON key.
+    fn element_info(&self, name: &str) -> Option<&'static ElementInfo> {
+        self.element_infos().iter().find(|info| info.matches(name))
+    }
+}
+
+impl<T: AsValueRef> AsValueRef for Option<T> {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        self.as_ref().and_then(AsValueRef::as_value_ref)
+    }
+}
+
+impl<T: AsValueRef> AsValueRef for Vec<T> {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        let values: Vec<ValueRef<'_>> = self
+            .iter()
+            .filter_map(AsValueRef::as_value_ref)
+            .collect();
+        (!values.is_empty()).then_some(ValueRef::List(values))
+    }
+}
+
+impl<T: AsValueRef> AsValueRef for Box<T> {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        (**self).as_value_ref()
+    }
+}
+
+impl AsValueRef for bool {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Boolean(*self))
+    }
+}
+
+/// Implement `AsValueRef` for the Rust type of an integer value.
+macro_rules! impl_as_value_ref_for_integer {
+    ($($t:ty),*) => {
+        $(impl AsValueRef for $t { fn as_value_ref(& self) -> Option < ValueRef <'_ >> {
+        Some(ValueRef::Integer(i64::from(* self))) } })*
+    };
+}
+
+impl_as_value_ref_for_integer!(i32, i64, u32);
+
+impl AsValueRef for String {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::String(self))
+    }
+}
+
+impl AsValueRef for ::serde_json::Number {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Decimal(self))
+    }
+}
+
+impl AsValueRef for ::serde_json::Value {
+    fn as_value_ref(&self) -> Option<ValueRef<'_>> {
+        Some(ValueRef::Json(self))
+    }
+}
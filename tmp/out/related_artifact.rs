@@ -1,6 +1,6 @@
 //! RelatedArtifact
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/RelatedArtifact
+//! URL: <http://hl7.org/fhir/StructureDefinition/RelatedArtifact>
 //!
 //! Version: 5.0.0
 //!
@@ -10,87 +10,784 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Related artifacts for a knowledge resource
+///
+/// Related artifacts such as additional documentation, justification, or bibliographic references.
+///
+/// Comment: Each related artifact is either an attachment, or a reference to another knowledge resource, but not both.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct RelatedArtifact {
-    /// Related artifacts for a knowledge resource
-    RelatedArtifact: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// documentation | justification | citation | predecessor | successor | derived-from | depends-on | composed-of | part-of | amends | amended-with | appends | appended-with | cites | cited-by | comments-on | comment-in | contains | contained-in | corrects | correction-in | replaces | replaced-with | retracts | retracted-by | signs | similar-to | supports | supported-with | transforms | transformed-into | transformed-with | documents | specification-of | created-with | cite-as
-    type: ? // code
+    ///
+    /// The type of relationship to the related artifact.
+    ///
+    /// Comment: The presence of both sides of a relationship type (e.g. successor and predecessor) is required to support use cases where one side of a relationship is not represented in FHIR. However, this feature SHALL NOT be used to create bi-directional resource links in FHIR instances. Specifically, following the methodology of "new points to old" and "many points to one", when using related artifact elements to describe and reference FHIR resources, the type element SHALL be drawn from the fhir-related-artifact-type ValueSet.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/related-artifact-type|5.0.0`](http://hl7.org/fhir/ValueSet/related-artifact-type)
+    ///
+    /// The type of relationship to the related artifact.
+    pub r#type: types::Code,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
 
     /// Additional classifiers
-    classifier: ? // CodeableConcept
+    ///
+    /// Provides additional classifiers of the related artifact.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/citation-artifact-classifier`](http://hl7.org/fhir/ValueSet/citation-artifact-classifier)
+    ///
+    /// Additional classifiers for the related artifact.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub classifier: Vec<types::CodeableConcept>,
 
     /// Short label
-    label: ? // string
+    ///
+    /// A short label that can be used to reference the citation from elsewhere in the containing artifact, such as a footnote index.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub label: Option<types::String>,
+
+    /// Id and extensions of `label`
+    #[serde(rename = "_label")]
+    pub label_element: Option<types::Element>,
 
     /// Brief description of the related artifact
-    display: ? // string
+    ///
+    /// A brief description of the document or knowledge resource being referenced, suitable for display to a consumer.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub display: Option<types::String>,
+
+    /// Id and extensions of `display`
+    #[serde(rename = "_display")]
+    pub display_element: Option<types::Element>,
 
     /// Bibliographic citation for the artifact
-    citation: ? // markdown
+    ///
+    /// A bibliographic citation for the related artifact. This text SHOULD be formatted according to an accepted citation format.
+    ///
+    /// Comment: Additional structured information about citations should be captured as extensions.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `markdown`
+    pub citation: Option<types::Markdown>,
+
+    /// Id and extensions of `citation`
+    #[serde(rename = "_citation")]
+    pub citation_element: Option<types::Element>,
 
     /// What document is being referenced
-    document: ? // Attachment
+    ///
+    /// The document being referenced, represented as an attachment. This is exclusive with the resource element.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Attachment`
+    pub document: Option<types::Attachment>,
 
     /// What artifact is being referenced
-    resource: ? // canonical
+    ///
+    /// The related artifact, such as a library, value set, profile, or other knowledge resource.
+    ///
+    /// Comment: If the type is predecessor, this is a reference to the succeeding knowledge resource. If the type is successor, this is a reference to the prior knowledge resource.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `canonical(Resource)`
+    pub resource: Option<types::Canonical>,
+
+    /// Id and extensions of `resource`
+    #[serde(rename = "_resource")]
+    pub resource_element: Option<types::Element>,
 
     /// What artifact, if not a conformance resource
-    resourceReference: ? // Reference
+    ///
+    /// The related artifact, if the artifact is not a canonical resource, or a resource reference to a canonical resource.
+    ///
+    /// Comment: If both resource and resourceReference are present, they SHOULD be consistent and reference the same resource. Although relatedArtifact is intended to support references to definitional resources, there are cases where non-definitional resources can be definitional (such as Location where the kind is mode). Related artifacts SHOULD be used to reference definitional resources, and profiles SHOULD be used to make that explicit for particular use cases.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Reference(Resource)`
+    pub resource_reference: Option<types::Reference>,
 
     /// draft | active | retired | unknown
-    publicationStatus: ? // code
+    ///
+    /// The publication status of the artifact being referred to.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/publication-status|5.0.0`](http://hl7.org/fhir/ValueSet/publication-status)
+    ///
+    /// Publication status of an artifact being referred to.
+    pub publication_status: Option<types::Code>,
+
+    /// Id and extensions of `publicationStatus`
+    #[serde(rename = "_publicationStatus")]
+    pub publication_status_element: Option<types::Element>,
 
     /// Date of publication of the artifact being referred to
-    publicationDate: ? // date
+    ///
+    /// The date of publication of the artifact being referred to.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `date`
+    pub publication_date: Option<types::Date>,
+
+    /// Id and extensions of `publicationDate`
+    #[serde(rename = "_publicationDate")]
+    pub publication_date_element: Option<types::Element>,
+}
+
+impl RelatedArtifact {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(r#type: types::Code) -> Self {
+        Self {
+            r#type,
+            ..Default::default()
+        }
+    }
+}
+
+impl RelatedArtifact {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> RelatedArtifactBuilder {
+        RelatedArtifactBuilder::default()
+    }
+}
+
+/// Build one `RelatedArtifact`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct RelatedArtifactBuilder {
+    inner: RelatedArtifact,
+    r#type: Option<types::Code>,
+}
+
+impl RelatedArtifactBuilder {
+    /// Set `RelatedArtifact.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `RelatedArtifact.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `RelatedArtifact.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `RelatedArtifact.type`.
+    pub fn r#type(mut self, value: impl Into<types::Code>) -> Self {
+        self.r#type = Some(value.into());
+        self
+    }
+
+    /// Set every `RelatedArtifact.classifier`.
+    pub fn classifier(mut self, value: impl Into<Vec<types::CodeableConcept>>) -> Self {
+        self.inner.classifier = value.into();
+        self
+    }
+
+    /// Add one `RelatedArtifact.classifier`.
+    pub fn push_classifier(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.classifier.push(value.into());
+        self
+    }
+
+    /// Set `RelatedArtifact.label`.
+    pub fn label(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.label = Some(value.into());
+        self
+    }
+
+    /// Set `RelatedArtifact.display`.
+    pub fn display(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.display = Some(value.into());
+        self
+    }
+
+    /// Set `RelatedArtifact.citation`.
+    pub fn citation(mut self, value: impl Into<types::Markdown>) -> Self {
+        self.inner.citation = Some(value.into());
+        self
+    }
+
+    /// Set `RelatedArtifact.document`.
+    pub fn document(mut self, value: impl Into<types::Attachment>) -> Self {
+        self.inner.document = Some(value.into());
+        self
+    }
+
+    /// Set `RelatedArtifact.resource`.
+    pub fn resource(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.resource = Some(value.into());
+        self
+    }
+
+    /// Set `RelatedArtifact.resourceReference`.
+    pub fn resource_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.resource_reference = Some(value.into());
+        self
+    }
 
+    /// Set `RelatedArtifact.publicationStatus`.
+    pub fn publication_status(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.publication_status = Some(value.into());
+        self
+    }
+
+    /// Set `RelatedArtifact.publicationDate`.
+    pub fn publication_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.inner.publication_date = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<RelatedArtifact, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.r#type {
+            Some(value) => inner.r#type = value,
+            None => missing.push("RelatedArtifact.type"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for RelatedArtifact {
+    const PATH: &'static str = "RelatedArtifact";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.classifier,
+            &format!("{path}.classifier"),
+            issues,
+        );
+        types::Validate::validate_into(&self.label, &format!("{path}.label"), issues);
+        types::Validate::validate_into(
+            &self.label_element,
+            &format!("{path}._label"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.display,
+            &format!("{path}.display"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.display_element,
+            &format!("{path}._display"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.citation,
+            &format!("{path}.citation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.citation_element,
+            &format!("{path}._citation"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.document,
+            &format!("{path}.document"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.resource,
+            &format!("{path}.resource"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.resource_element,
+            &format!("{path}._resource"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.resource_reference,
+            &format!("{path}.resourceReference"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.publication_status,
+            &format!("{path}.publicationStatus"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.publication_status_element,
+            &format!("{path}._publicationStatus"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.publication_date,
+            &format!("{path}.publicationDate"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.publication_date_element,
+            &format!("{path}._publicationDate"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for RelatedArtifact {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_related_artifact(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.classifier, &format!("{path}.classifier"), visitor);
+        types::Walk::accept(&self.label, &format!("{path}.label"), visitor);
+        types::Walk::accept(&self.label_element, &format!("{path}._label"), visitor);
+        types::Walk::accept(&self.display, &format!("{path}.display"), visitor);
+        types::Walk::accept(&self.display_element, &format!("{path}._display"), visitor);
+        types::Walk::accept(&self.citation, &format!("{path}.citation"), visitor);
+        types::Walk::accept(
+            &self.citation_element,
+            &format!("{path}._citation"),
+            visitor,
+        );
+        types::Walk::accept(&self.document, &format!("{path}.document"), visitor);
+        types::Walk::accept(&self.resource, &format!("{path}.resource"), visitor);
+        types::Walk::accept(
+            &self.resource_element,
+            &format!("{path}._resource"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.resource_reference,
+            &format!("{path}.resourceReference"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.publication_status,
+            &format!("{path}.publicationStatus"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.publication_status_element,
+            &format!("{path}._publicationStatus"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.publication_date,
+            &format!("{path}.publicationDate"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.publication_date_element,
+            &format!("{path}._publicationDate"),
+            visitor,
+        );
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_related_artifact_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.classifier,
+            &format!("{path}.classifier"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.label, &format!("{path}.label"), visitor);
+        types::Walk::accept_mut(
+            &mut self.label_element,
+            &format!("{path}._label"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.display, &format!("{path}.display"), visitor);
+        types::Walk::accept_mut(
+            &mut self.display_element,
+            &format!("{path}._display"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.citation,
+            &format!("{path}.citation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.citation_element,
+            &format!("{path}._citation"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.document,
+            &format!("{path}.document"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.resource,
+            &format!("{path}.resource"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.resource_element,
+            &format!("{path}._resource"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.resource_reference,
+            &format!("{path}.resourceReference"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.publication_status,
+            &format!("{path}.publicationStatus"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.publication_status_element,
+            &format!("{path}._publicationStatus"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.publication_date,
+            &format!("{path}.publicationDate"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.publication_date_element,
+            &format!("{path}._publicationDate"),
+            visitor,
+        );
+    }
+}
+
+impl RelatedArtifact {
+    /// The metadata of each child element of `RelatedArtifact`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/related-artifact-type|5.0.0",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "classifier",
+            json_key: "classifier",
+            rust_field: "classifier",
+            min: 0,
+            max: None,
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some(
+                    "http://hl7.org/fhir/ValueSet/citation-artifact-classifier",
+                ),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "label",
+            json_key: "label",
+            rust_field: "label",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "display",
+            json_key: "display",
+            rust_field: "display",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "citation",
+            json_key: "citation",
+            rust_field: "citation",
+            min: 0,
+            max: Some(1),
+            types: &["markdown"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "document",
+            json_key: "document",
+            rust_field: "document",
+            min: 0,
+            max: Some(1),
+            types: &["Attachment"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "resource",
+            json_key: "resource",
+            rust_field: "resource",
+            min: 0,
+            max: Some(1),
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "resourceReference",
+            json_key: "resourceReference",
+            rust_field: "resource_reference",
+            min: 0,
+            max: Some(1),
+            types: &["Reference"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "publicationStatus",
+            json_key: "publicationStatus",
+            rust_field: "publication_status",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/publication-status|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "publicationDate",
+            json_key: "publicationDate",
+            rust_field: "publication_date",
+            min: 0,
+            max: Some(1),
+            types: &["date"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for RelatedArtifact {
+    fn type_name(&self) -> &'static str {
+        "RelatedArtifact"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "classifier" => types::AsValueRef::as_value_ref(&self.classifier),
+            "label" => types::AsValueRef::as_value_ref(&self.label),
+            "display" => types::AsValueRef::as_value_ref(&self.display),
+            "citation" => types::AsValueRef::as_value_ref(&self.citation),
+            "document" => types::AsValueRef::as_value_ref(&self.document),
+            "resource" => types::AsValueRef::as_value_ref(&self.resource),
+            "resourceReference" => {
+                types::AsValueRef::as_value_ref(&self.resource_reference)
+            }
+            "publicationStatus" => {
+                types::AsValueRef::as_value_ref(&self.publication_status)
+            }
+            "publicationDate" => types::AsValueRef::as_value_ref(&self.publication_date),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for RelatedArtifact {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for RelatedArtifact {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = RelatedArtifact;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["type"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! SampledData
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/SampledData
+//! URL: <http://hl7.org/fhir/StructureDefinition/SampledData>
 //!
 //! Version: 5.0.0
 //!
@@ -10,87 +10,795 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// A series of measurements taken by a device
+///
+/// A series of measurements taken by a device, with upper and lower limits. There may be more than one dimension in the data.
+///
+/// Comment: The data is not interpretable without at least origin, period, and dimensions, but these are optional to allow a separation between the template of measurement and the actual measurement, such as between DeviceCapabilities and DeviceLog.  When providing a summary view (for example with Observation.value\[x\]) SampledData should be represented with a brief display text such as "Sampled Data".
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `sdd-1`: A SampledData SAHLL have either an interval and offsets but not both
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct SampledData {
-    /// A series of measurements taken by a device
-    SampledData: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Zero value and units
-    origin: ? // Quantity
+    ///
+    /// The base quantity that a measured value of zero represents. In addition, this provides the units of the entire measurement series.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `Quantity`
+    pub origin: types::Quantity,
 
     /// Number of intervalUnits between samples
-    interval: ? // decimal
+    ///
+    /// Amount of intervalUnits between samples, e.g. milliseconds for time-based sampling.
+    ///
+    /// Comment: This is usually a whole number.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub interval: Option<types::Decimal>,
+
+    /// Id and extensions of `interval`
+    #[serde(rename = "_interval")]
+    pub interval_element: Option<types::Element>,
 
     /// The measurement unit of the interval between samples
-    intervalUnit: ? // code
+    ///
+    /// The measurement unit in which the sample interval is expressed.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/ucum-units|5.0.0`](http://hl7.org/fhir/ValueSet/ucum-units)
+    ///
+    /// Units of measure allowed for an element.
+    pub interval_unit: types::Code,
+
+    /// Id and extensions of `intervalUnit`
+    #[serde(rename = "_intervalUnit")]
+    pub interval_unit_element: Option<types::Element>,
 
     /// Multiply data by this before adding to origin
-    factor: ? // decimal
+    ///
+    /// A correction factor that is applied to the sampled data points before they are added to the origin.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub factor: Option<types::Decimal>,
+
+    /// Id and extensions of `factor`
+    #[serde(rename = "_factor")]
+    pub factor_element: Option<types::Element>,
 
     /// Lower limit of detection
-    lowerLimit: ? // decimal
+    ///
+    /// The lower limit of detection of the measured points. This is needed if any of the data points have the value "L" (lower than detection limit).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub lower_limit: Option<types::Decimal>,
+
+    /// Id and extensions of `lowerLimit`
+    #[serde(rename = "_lowerLimit")]
+    pub lower_limit_element: Option<types::Element>,
 
     /// Upper limit of detection
-    upperLimit: ? // decimal
+    ///
+    /// The upper limit of detection of the measured points. This is needed if any of the data points have the value "U" (higher than detection limit).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub upper_limit: Option<types::Decimal>,
+
+    /// Id and extensions of `upperLimit`
+    #[serde(rename = "_upperLimit")]
+    pub upper_limit_element: Option<types::Element>,
 
     /// Number of sample points at each time point
-    dimensions: ? // positiveInt
+    ///
+    /// The number of sample points at each time point. If this value is greater than one, then the dimensions will be interlaced - all the sample points for a point in time will be recorded at once.
+    ///
+    /// Comment: If there is more than one dimension, the code for the type of data will define the meaning of the dimensions (typically ECG data).
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `positiveInt`
+    pub dimensions: types::PositiveInt,
+
+    /// Id and extensions of `dimensions`
+    #[serde(rename = "_dimensions")]
+    pub dimensions_element: Option<types::Element>,
 
     /// Defines the codes used in the data
-    codeMap: ? // canonical
+    ///
+    /// Reference to ConceptMap that defines the codes used in the data.
+    ///
+    /// Comment: The ConceptMap cannot define meanings for the codes 'E', 'U', or 'L' (nor 'e', 'u', or 'l').
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `canonical(ConceptMap)`
+    pub code_map: Option<types::Canonical>,
+
+    /// Id and extensions of `codeMap`
+    #[serde(rename = "_codeMap")]
+    pub code_map_element: Option<types::Element>,
 
     /// Offsets, typically in time, at which data values were taken
-    offsets: ? // string
+    ///
+    /// A series of data points which are decimal values separated by a single space (character u20).  The units in which the offsets are expressed are found in intervalUnit.  The absolute point at which the measurements begin SHALL be conveyed outside the scope of this datatype, e.g. Observation.effectiveDateTime for a timing offset.
+    ///
+    /// Comment: If offsets is present, the number of data points must be equal to the number of offsets mlutipled by the dimensions.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub offsets: Option<types::String>,
+
+    /// Id and extensions of `offsets`
+    #[serde(rename = "_offsets")]
+    pub offsets_element: Option<types::Element>,
 
     /// Decimal values with spaces, or "E" | "U" | "L", or another code
-    data: ? // string
+    ///
+    /// A series of data points which are decimal values or codes separated by a single space (character u20). The special codes "E" (error), "L" (below detection limit) and "U" (above detection limit) are also defined for used in place of decimal values.
+    ///
+    /// Comment: The data may be missing if it is omitted for summarization purposes. In general, data is required for any actual use of a SampledData.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub data: Option<types::String>,
+
+    /// Id and extensions of `data`
+    #[serde(rename = "_data")]
+    pub data_element: Option<types::Element>,
+}
+
+impl SampledData {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(
+        origin: types::Quantity,
+        interval_unit: types::Code,
+        dimensions: types::PositiveInt,
+    ) -> Self {
+        Self {
+            origin,
+            interval_unit,
+            dimensions,
+            ..Default::default()
+        }
+    }
+}
+
+impl SampledData {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> SampledDataBuilder {
+        SampledDataBuilder::default()
+    }
+}
+
+/// Build one `SampledData`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct SampledDataBuilder {
+    inner: SampledData,
+    origin: Option<types::Quantity>,
+    interval_unit: Option<types::Code>,
+    dimensions: Option<types::PositiveInt>,
+}
+
+impl SampledDataBuilder {
+    /// Set `SampledData.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `SampledData.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `SampledData.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `SampledData.origin`.
+    pub fn origin(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.origin = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.interval`.
+    pub fn interval(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.interval = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.intervalUnit`.
+    pub fn interval_unit(mut self, value: impl Into<types::Code>) -> Self {
+        self.interval_unit = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.factor`.
+    pub fn factor(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.factor = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.lowerLimit`.
+    pub fn lower_limit(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.lower_limit = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.upperLimit`.
+    pub fn upper_limit(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.upper_limit = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.dimensions`.
+    pub fn dimensions(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.dimensions = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.codeMap`.
+    pub fn code_map(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.code_map = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.offsets`.
+    pub fn offsets(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.offsets = Some(value.into());
+        self
+    }
+
+    /// Set `SampledData.data`.
+    pub fn data(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.data = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<SampledData, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.origin {
+            Some(value) => inner.origin = value,
+            None => missing.push("SampledData.origin"),
+        }
+        match self.interval_unit {
+            Some(value) => inner.interval_unit = value,
+            None => missing.push("SampledData.intervalUnit"),
+        }
+        match self.dimensions {
+            Some(value) => inner.dimensions = value,
+            None => missing.push("SampledData.dimensions"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for SampledData {
+    const PATH: &'static str = "SampledData";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.origin, &format!("{path}.origin"), issues);
+        types::Validate::validate_into(
+            &self.interval,
+            &format!("{path}.interval"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.interval_element,
+            &format!("{path}._interval"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.interval_unit,
+            &format!("{path}.intervalUnit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.interval_unit_element,
+            &format!("{path}._intervalUnit"),
+            issues,
+        );
+        types::Validate::validate_into(&self.factor, &format!("{path}.factor"), issues);
+        types::Validate::validate_into(
+            &self.factor_element,
+            &format!("{path}._factor"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.lower_limit,
+            &format!("{path}.lowerLimit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.lower_limit_element,
+            &format!("{path}._lowerLimit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.upper_limit,
+            &format!("{path}.upperLimit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.upper_limit_element,
+            &format!("{path}._upperLimit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.dimensions,
+            &format!("{path}.dimensions"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.dimensions_element,
+            &format!("{path}._dimensions"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.code_map,
+            &format!("{path}.codeMap"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.code_map_element,
+            &format!("{path}._codeMap"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.offsets,
+            &format!("{path}.offsets"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.offsets_element,
+            &format!("{path}._offsets"),
+            issues,
+        );
+        types::Validate::validate_into(&self.data, &format!("{path}.data"), issues);
+        types::Validate::validate_into(
+            &self.data_element,
+            &format!("{path}._data"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for SampledData {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_sampled_data(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.origin, &format!("{path}.origin"), visitor);
+        types::Walk::accept(&self.interval, &format!("{path}.interval"), visitor);
+        types::Walk::accept(
+            &self.interval_element,
+            &format!("{path}._interval"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.interval_unit,
+            &format!("{path}.intervalUnit"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.interval_unit_element,
+            &format!("{path}._intervalUnit"),
+            visitor,
+        );
+        types::Walk::accept(&self.factor, &format!("{path}.factor"), visitor);
+        types::Walk::accept(&self.factor_element, &format!("{path}._factor"), visitor);
+        types::Walk::accept(&self.lower_limit, &format!("{path}.lowerLimit"), visitor);
+        types::Walk::accept(
+            &self.lower_limit_element,
+            &format!("{path}._lowerLimit"),
+            visitor,
+        );
+        types::Walk::accept(&self.upper_limit, &format!("{path}.upperLimit"), visitor);
+        types::Walk::accept(
+            &self.upper_limit_element,
+            &format!("{path}._upperLimit"),
+            visitor,
+        );
+        types::Walk::accept(&self.dimensions, &format!("{path}.dimensions"), visitor);
+        types::Walk::accept(
+            &self.dimensions_element,
+            &format!("{path}._dimensions"),
+            visitor,
+        );
+        types::Walk::accept(&self.code_map, &format!("{path}.codeMap"), visitor);
+        types::Walk::accept(
+            &self.code_map_element,
+            &format!("{path}._codeMap"),
+            visitor,
+        );
+        types::Walk::accept(&self.offsets, &format!("{path}.offsets"), visitor);
+        types::Walk::accept(&self.offsets_element, &format!("{path}._offsets"), visitor);
+        types::Walk::accept(&self.data, &format!("{path}.data"), visitor);
+        types::Walk::accept(&self.data_element, &format!("{path}._data"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_sampled_data_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.origin, &format!("{path}.origin"), visitor);
+        types::Walk::accept_mut(
+            &mut self.interval,
+            &format!("{path}.interval"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.interval_element,
+            &format!("{path}._interval"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.interval_unit,
+            &format!("{path}.intervalUnit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.interval_unit_element,
+            &format!("{path}._intervalUnit"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.factor, &format!("{path}.factor"), visitor);
+        types::Walk::accept_mut(
+            &mut self.factor_element,
+            &format!("{path}._factor"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.lower_limit,
+            &format!("{path}.lowerLimit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.lower_limit_element,
+            &format!("{path}._lowerLimit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.upper_limit,
+            &format!("{path}.upperLimit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.upper_limit_element,
+            &format!("{path}._upperLimit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.dimensions,
+            &format!("{path}.dimensions"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.dimensions_element,
+            &format!("{path}._dimensions"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code_map, &format!("{path}.codeMap"), visitor);
+        types::Walk::accept_mut(
+            &mut self.code_map_element,
+            &format!("{path}._codeMap"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.offsets, &format!("{path}.offsets"), visitor);
+        types::Walk::accept_mut(
+            &mut self.offsets_element,
+            &format!("{path}._offsets"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.data, &format!("{path}.data"), visitor);
+        types::Walk::accept_mut(
+            &mut self.data_element,
+            &format!("{path}._data"),
+            visitor,
+        );
+    }
+}
+
+impl SampledData {
+    /// The metadata of each child element of `SampledData`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "origin",
+            json_key: "origin",
+            rust_field: "origin",
+            min: 1,
+            max: Some(1),
+            types: &["Quantity"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "interval",
+            json_key: "interval",
+            rust_field: "interval",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "intervalUnit",
+            json_key: "intervalUnit",
+            rust_field: "interval_unit",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/ucum-units|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "factor",
+            json_key: "factor",
+            rust_field: "factor",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "lowerLimit",
+            json_key: "lowerLimit",
+            rust_field: "lower_limit",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "upperLimit",
+            json_key: "upperLimit",
+            rust_field: "upper_limit",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "dimensions",
+            json_key: "dimensions",
+            rust_field: "dimensions",
+            min: 1,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "codeMap",
+            json_key: "codeMap",
+            rust_field: "code_map",
+            min: 0,
+            max: Some(1),
+            types: &["canonical"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "offsets",
+            json_key: "offsets",
+            rust_field: "offsets",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "data",
+            json_key: "data",
+            rust_field: "data",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for SampledData {
+    fn type_name(&self) -> &'static str {
+        "SampledData"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "origin" => types::AsValueRef::as_value_ref(&self.origin),
+            "interval" => types::AsValueRef::as_value_ref(&self.interval),
+            "intervalUnit" => types::AsValueRef::as_value_ref(&self.interval_unit),
+            "factor" => types::AsValueRef::as_value_ref(&self.factor),
+            "lowerLimit" => types::AsValueRef::as_value_ref(&self.lower_limit),
+            "upperLimit" => types::AsValueRef::as_value_ref(&self.upper_limit),
+            "dimensions" => types::AsValueRef::as_value_ref(&self.dimensions),
+            "codeMap" => types::AsValueRef::as_value_ref(&self.code_map),
+            "offsets" => types::AsValueRef::as_value_ref(&self.offsets),
+            "data" => types::AsValueRef::as_value_ref(&self.data),
+            _ => None,
+        }
+    }
+}
 
+impl types::AsValueRef for SampledData {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for SampledData {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = SampledData;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["dimensions", "intervalUnit", "origin"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
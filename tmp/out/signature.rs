@@ -1,6 +1,6 @@
 //! Signature
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Signature
+//! URL: <http://hl7.org/fhir/StructureDefinition/Signature>
 //!
 //! Version: 5.0.0
 //!
@@ -10,78 +10,578 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// A Signature - XML DigSig, JWS, Graphical image of signature, etc.
+///
+/// A signature along with supporting context. The signature may be a digital signature that is cryptographic in nature, or some other signature acceptable to the domain. This other signature may be as simple as a graphical image representing a hand-written signature, or a signature ceremony Different signature approaches have different utilities.
+///
+/// Comment: The elements of the Signature Resource are for ease of access of these elements. For digital signatures (Xml DigSig, JWS), the non-repudiation proof comes from the Signature  validation, which includes validation of the referenced objects (e.g. Resources) (a.k.a., Content) in the XML-Signature Detached form.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Signature {
-    /// A Signature - XML DigSig, JWS, Graphical image of signature, etc.
-    Signature: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Indication of the reason the entity signed the object(s)
-    type: ? // Coding
+    ///
+    /// An indication of the reason that the entity signed this document. This may be explicitly included as part of the signature information and can be used when determining accountability for various actions concerning the document.
+    ///
+    /// Comment: Examples include attesting to: authorship, correct transcription, and witness of specific event. Also known as a &quot;Commitment Type Indication&quot;.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Coding`
+    ///
+    /// Binding: preferred to [`http://hl7.org/fhir/ValueSet/signature-type`](http://hl7.org/fhir/ValueSet/signature-type)
+    ///
+    /// An indication of the reason that an entity signed the object.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub r#type: Vec<types::Coding>,
 
     /// When the signature was created
-    when: ? // instant
+    ///
+    /// When the digital signature was signed.
+    ///
+    /// Comment: This should agree with the information in the signature.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `instant`
+    pub when: Option<types::Instant>,
+
+    /// Id and extensions of `when`
+    #[serde(rename = "_when")]
+    pub when_element: Option<types::Element>,
 
     /// Who signed
-    who: ? // Reference
+    ///
+    /// A reference to an application-usable description of the identity that signed  (e.g. the signature used their private key).
+    ///
+    /// Comment: This should agree with the information in the signature.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Reference(Practitioner | PractitionerRole | RelatedPerson | Patient | Device | Organization)`
+    pub who: Option<types::Reference>,
 
     /// The party represented
-    onBehalfOf: ? // Reference
+    ///
+    /// A reference to an application-usable description of the identity that is represented by the signature.
+    ///
+    /// Comment: The party that can't sign. For example a child.
+    ///
+    /// Requirements: used when the signature is on behalf of a non-signer.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Reference(Practitioner | PractitionerRole | RelatedPerson | Patient | Device | Organization)`
+    pub on_behalf_of: Option<types::Reference>,
 
     /// The technical format of the signed resources
-    targetFormat: ? // code
+    ///
+    /// A mime type that indicates the technical format of the target resources signed by the signature.
+    ///
+    /// Comment: "xml", "json" and "ttl" are allowed, which describe the simple encodings described in the specification (and imply appropriate bundle support). Otherwise, mime types are legal here.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/mimetypes|5.0.0`](http://hl7.org/fhir/ValueSet/mimetypes)
+    ///
+    /// BCP 13 (RFCs 2045, 2046, 2047, 4288, 4289 and 2049)
+    pub target_format: Option<types::Code>,
+
+    /// Id and extensions of `targetFormat`
+    #[serde(rename = "_targetFormat")]
+    pub target_format_element: Option<types::Element>,
 
     /// The technical format of the signature
-    sigFormat: ? // code
+    ///
+    /// A mime type that indicates the technical format of the signature. Important mime types are application/signature+xml for X ML DigSig, application/jose for JWS, and image/* for a graphical image of a signature, etc.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/mimetypes|5.0.0`](http://hl7.org/fhir/ValueSet/mimetypes)
+    ///
+    /// BCP 13 (RFCs 2045, 2046, 2047, 4288, 4289 and 2049)
+    pub sig_format: Option<types::Code>,
+
+    /// Id and extensions of `sigFormat`
+    #[serde(rename = "_sigFormat")]
+    pub sig_format_element: Option<types::Element>,
 
     /// The actual signature content (XML DigSig. JWS, picture, etc.)
-    data: ? // base64Binary
+    ///
+    /// The base64 encoding of the Signature content. When signature is not recorded electronically this element would be empty.
+    ///
+    /// Comment: Where the signature type is an XML DigSig, the signed content is a FHIR Resource(s), the signature is of the XML form of the Resource(s) using  XML-Signature (XMLDIG) "Detached Signature" form.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `base64Binary`
+    pub data: Option<types::Base64Binary>,
+
+    /// Id and extensions of `data`
+    #[serde(rename = "_data")]
+    pub data_element: Option<types::Element>,
+}
+
+impl Signature {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Signature {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> SignatureBuilder {
+        SignatureBuilder::default()
+    }
+}
+
+/// Build one `Signature`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct SignatureBuilder {
+    inner: Signature,
+}
+
+impl SignatureBuilder {
+    /// Set `Signature.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Signature.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Signature.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `Signature.type`.
+    pub fn r#type(mut self, value: impl Into<Vec<types::Coding>>) -> Self {
+        self.inner.r#type = value.into();
+        self
+    }
+
+    /// Add one `Signature.type`.
+    pub fn push_type(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.r#type.push(value.into());
+        self
+    }
+
+    /// Set `Signature.when`.
+    pub fn when(mut self, value: impl Into<types::Instant>) -> Self {
+        self.inner.when = Some(value.into());
+        self
+    }
+
+    /// Set `Signature.who`.
+    pub fn who(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.who = Some(value.into());
+        self
+    }
+
+    /// Set `Signature.onBehalfOf`.
+    pub fn on_behalf_of(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.on_behalf_of = Some(value.into());
+        self
+    }
+
+    /// Set `Signature.targetFormat`.
+    pub fn target_format(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.target_format = Some(value.into());
+        self
+    }
+
+    /// Set `Signature.sigFormat`.
+    pub fn sig_format(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.sig_format = Some(value.into());
+        self
+    }
+
+    /// Set `Signature.data`.
+    pub fn data(mut self, value: impl Into<types::Base64Binary>) -> Self {
+        self.inner.data = Some(value.into());
+        self
+    }
 
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Signature, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Signature {
+    const PATH: &'static str = "Signature";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(&self.when, &format!("{path}.when"), issues);
+        types::Validate::validate_into(
+            &self.when_element,
+            &format!("{path}._when"),
+            issues,
+        );
+        types::Validate::validate_into(&self.who, &format!("{path}.who"), issues);
+        types::Validate::validate_into(
+            &self.on_behalf_of,
+            &format!("{path}.onBehalfOf"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.target_format,
+            &format!("{path}.targetFormat"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.target_format_element,
+            &format!("{path}._targetFormat"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.sig_format,
+            &format!("{path}.sigFormat"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.sig_format_element,
+            &format!("{path}._sigFormat"),
+            issues,
+        );
+        types::Validate::validate_into(&self.data, &format!("{path}.data"), issues);
+        types::Validate::validate_into(
+            &self.data_element,
+            &format!("{path}._data"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for Signature {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_signature(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.when, &format!("{path}.when"), visitor);
+        types::Walk::accept(&self.when_element, &format!("{path}._when"), visitor);
+        types::Walk::accept(&self.who, &format!("{path}.who"), visitor);
+        types::Walk::accept(&self.on_behalf_of, &format!("{path}.onBehalfOf"), visitor);
+        types::Walk::accept(
+            &self.target_format,
+            &format!("{path}.targetFormat"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.target_format_element,
+            &format!("{path}._targetFormat"),
+            visitor,
+        );
+        types::Walk::accept(&self.sig_format, &format!("{path}.sigFormat"), visitor);
+        types::Walk::accept(
+            &self.sig_format_element,
+            &format!("{path}._sigFormat"),
+            visitor,
+        );
+        types::Walk::accept(&self.data, &format!("{path}.data"), visitor);
+        types::Walk::accept(&self.data_element, &format!("{path}._data"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_signature_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(&mut self.when, &format!("{path}.when"), visitor);
+        types::Walk::accept_mut(
+            &mut self.when_element,
+            &format!("{path}._when"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.who, &format!("{path}.who"), visitor);
+        types::Walk::accept_mut(
+            &mut self.on_behalf_of,
+            &format!("{path}.onBehalfOf"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.target_format,
+            &format!("{path}.targetFormat"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.target_format_element,
+            &format!("{path}._targetFormat"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.sig_format,
+            &format!("{path}.sigFormat"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.sig_format_element,
+            &format!("{path}._sigFormat"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.data, &format!("{path}.data"), visitor);
+        types::Walk::accept_mut(
+            &mut self.data_element,
+            &format!("{path}._data"),
+            visitor,
+        );
+    }
+}
+
+impl Signature {
+    /// The metadata of each child element of `Signature`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 0,
+            max: None,
+            types: &["Coding"],
+            binding: Some(types::BindingInfo {
+                strength: "preferred",
+                value_set: Some("http://hl7.org/fhir/ValueSet/signature-type"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "when",
+            json_key: "when",
+            rust_field: "when",
+            min: 0,
+            max: Some(1),
+            types: &["instant"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "who",
+            json_key: "who",
+            rust_field: "who",
+            min: 0,
+            max: Some(1),
+            types: &["Reference"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "onBehalfOf",
+            json_key: "onBehalfOf",
+            rust_field: "on_behalf_of",
+            min: 0,
+            max: Some(1),
+            types: &["Reference"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "targetFormat",
+            json_key: "targetFormat",
+            rust_field: "target_format",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/mimetypes|5.0.0"),
+            }),
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "sigFormat",
+            json_key: "sigFormat",
+            rust_field: "sig_format",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/mimetypes|5.0.0"),
+            }),
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "data",
+            json_key: "data",
+            rust_field: "data",
+            min: 0,
+            max: Some(1),
+            types: &["base64Binary"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Signature {
+    fn type_name(&self) -> &'static str {
+        "Signature"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "when" => types::AsValueRef::as_value_ref(&self.when),
+            "who" => types::AsValueRef::as_value_ref(&self.who),
+            "onBehalfOf" => types::AsValueRef::as_value_ref(&self.on_behalf_of),
+            "targetFormat" => types::AsValueRef::as_value_ref(&self.target_format),
+            "sigFormat" => types::AsValueRef::as_value_ref(&self.sig_format),
+            "data" => types::AsValueRef::as_value_ref(&self.data),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Signature {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Signature {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Signature;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
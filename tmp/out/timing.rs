@@ -1,6 +1,6 @@
 //! Timing
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/Timing
+//! URL: <http://hl7.org/fhir/StructureDefinition/Timing>
 //!
 //! Version: 5.0.0
 //!
@@ -10,120 +10,1599 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// A timing schedule that specifies an event that may occur multiple times
+///
+/// Specifies an event that may occur multiple times. Timing schedules are used to record when things are planned, expected or requested to occur. The most common usage is in dosage instructions for medications. They are also used when planning care of various kinds, and may be used for reporting the schedule to which past regular activities were carried out.
+///
+/// Comment: Describes the occurrence of an event that may occur multiple times. Timing schedules are used for specifying when events are expected or requested to occur, and may also be used to represent the summary of a past or ongoing event.  For simplicity, the definitions of Timing components are expressed as 'future' events, but such components can also be used to describe historic or ongoing events.
+///
+/// A Timing schedule can be a list of events and/or criteria for when the event happens, which can be expressed in a structured form and/or as a code. When both event and a repeating specification are provided, the list of events should be understood as an interpretation of the information in the repeat structure.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
 pub struct Timing {
-    /// A timing schedule that specifies an event that may occur multiple times
-    Timing: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Extensions that cannot be ignored even if unrecognized
-    modifierExtension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element and that modifies the understanding of the element in which it is contained and/or the understanding of the containing element's descendants. Usually modifier elements provide negation or qualification. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension. Applications processing a resource are required to check for modifier extensions.
+    ///
+    /// Modifier extensions SHALL NOT change the meaning of any elements on Resource or DomainResource (including cannot change the meaning of modifierExtension itself).
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Requirements: Modifier extensions allow for extensions that *cannot* be safely ignored to be clearly distinguished from the vast majority of extensions which can be safely ignored.  This promotes interoperability by eliminating the need for implementers to prohibit the presence of extensions. For further information, see the [definition of modifier extensions](https://build.fhir.org/extensibility.html#modifierExtension).
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    ///
+    /// Modifier: Modifier extensions are expected to modify the meaning or interpretation of the element that contains them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modifier_extension: Vec<types::Extension>,
 
     /// When the event occurs
-    event: ? // dateTime
+    ///
+    /// Identifies specific times when the event occurs.
+    ///
+    /// Requirements: In a Medication Administration Record, for instance, you need to take a general specification, and turn it into a precise specification.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `dateTime`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub event: Vec<Option<types::DateTime>>,
+
+    /// Id and extensions of `event`
+    #[serde(rename = "_event", default, skip_serializing_if = "Vec::is_empty")]
+    pub event_element: Vec<Option<types::Element>>,
 
     /// When the event is to occur
-    repeat: ? // Element
+    ///
+    /// A set of rules that describe when the event is scheduled.
+    ///
+    /// Requirements: Many timing schedules are determined by regular repetitions.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Element`
+    ///
+    /// Constraints:
+    ///
+    /// - `tim-1`: if there's a duration, there needs to be duration units
+    /// - `tim-2`: if there's a period, there needs to be period units
+    /// - `tim-4`: duration SHALL be a non-negative value
+    /// - `tim-5`: period SHALL be a non-negative value
+    /// - `tim-6`: If there's a periodMax, there must be a period
+    /// - `tim-7`: If there's a durationMax, there must be a duration
+    /// - `tim-8`: If there's a countMax, there must be a count
+    /// - `tim-9`: If there's an offset, there must be a when (and not C, CM, CD, CV)
+    /// - `tim-10`: If there's a timeOfDay, there cannot be a when, or vice versa
+    pub repeat: Option<TimingRepeat>,
+
+    /// C | BID | TID | QID | AM | PM | QD | QOD | +
+    ///
+    /// A code for the timing schedule (or just text in code.text). Some codes such as BID are ubiquitous, but many institutions define their own additional codes. If a code is provided, the code is understood to be a complete statement of whatever is specified in the structured timing data, and either the code or the data may be used to interpret the Timing, with the exception that .repeat.bounds still applies over the code (and is not contained in the code).
+    ///
+    /// Comment: BID etc. are defined as 'at institutionally specified times'. For example, an institution may choose that BID is "always at 7am and 6pm".  If it is inappropriate for this choice to be made, the code BID should not be used. Instead, a distinct organization-specific code should be used in place of the HL7-defined BID code and/or a structured representation should be used (in this case, specifying the two event times).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    ///
+    /// Binding: preferred to [`http://hl7.org/fhir/ValueSet/timing-abbreviation`](http://hl7.org/fhir/ValueSet/timing-abbreviation)
+    ///
+    /// Code for a known / defined timing pattern.
+    pub code: Option<types::CodeableConcept>,
+}
+
+impl Timing {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Timing {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> TimingBuilder {
+        TimingBuilder::default()
+    }
+}
+
+/// Build one `Timing`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct TimingBuilder {
+    inner: Timing,
+}
+
+impl TimingBuilder {
+    /// Set `Timing.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Timing.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Timing.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set every `Timing.modifierExtension`.
+    pub fn modifier_extension(
+        mut self,
+        value: impl Into<Vec<types::Extension>>,
+    ) -> Self {
+        self.inner.modifier_extension = value.into();
+        self
+    }
+
+    /// Add one `Timing.modifierExtension`.
+    pub fn push_modifier_extension(
+        mut self,
+        value: impl Into<types::Extension>,
+    ) -> Self {
+        self.inner.modifier_extension.push(value.into());
+        self
+    }
+
+    /// Set every `Timing.event`.
+    pub fn event(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::DateTime>>,
+    ) -> Self {
+        self.inner.event = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `Timing.event`.
+    pub fn push_event(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.event.push(Some(value.into()));
+        self
+    }
+
+    /// Set `Timing.repeat`.
+    pub fn repeat(mut self, value: impl Into<TimingRepeat>) -> Self {
+        self.inner.repeat = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.code`.
+    pub fn code(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.code = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<Timing, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for Timing {
+    const PATH: &'static str = "Timing";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.event, &format!("{path}.event"), issues);
+        types::Validate::validate_into(
+            &self.event_element,
+            &format!("{path}._event"),
+            issues,
+        );
+        types::Validate::validate_into(&self.repeat, &format!("{path}.repeat"), issues);
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+    }
+}
+
+impl types::Walk for Timing {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_timing(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(
+            &self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept(&self.event, &format!("{path}.event"), visitor);
+        types::Walk::accept(&self.event_element, &format!("{path}._event"), visitor);
+        types::Walk::accept(&self.repeat, &format!("{path}.repeat"), visitor);
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_timing_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.modifier_extension,
+            &format!("{path}.modifierExtension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.event, &format!("{path}.event"), visitor);
+        types::Walk::accept_mut(
+            &mut self.event_element,
+            &format!("{path}._event"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.repeat, &format!("{path}.repeat"), visitor);
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+    }
+}
+
+impl Timing {
+    /// The metadata of each child element of `Timing`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "modifierExtension",
+            json_key: "modifierExtension",
+            rust_field: "modifier_extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: true,
+            is_modifier: true,
+        },
+        types::ElementInfo {
+            name: "event",
+            json_key: "event",
+            rust_field: "event",
+            min: 0,
+            max: None,
+            types: &["dateTime"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "repeat",
+            json_key: "repeat",
+            rust_field: "repeat",
+            min: 0,
+            max: Some(1),
+            types: &["Element"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: Some(types::BindingInfo {
+                strength: "preferred",
+                value_set: Some("http://hl7.org/fhir/ValueSet/timing-abbreviation"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for Timing {
+    fn type_name(&self) -> &'static str {
+        "Timing"
+    }
 
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "modifierExtension" => {
+                types::AsValueRef::as_value_ref(&self.modifier_extension)
+            }
+            "event" => types::AsValueRef::as_value_ref(&self.event),
+            "repeat" => types::AsValueRef::as_value_ref(&self.repeat),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for Timing {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for Timing {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// When the event is to occur
+///
+/// A set of rules that describe when the event is scheduled.
+///
+/// Requirements: Many timing schedules are determined by regular repetitions.
+///
+/// Cardinality: 0..1
+///
+/// Types: `Element`
+///
+/// Constraints:
+///
+/// - `tim-1`: if there's a duration, there needs to be duration units
+/// - `tim-2`: if there's a period, there needs to be period units
+/// - `tim-4`: duration SHALL be a non-negative value
+/// - `tim-5`: period SHALL be a non-negative value
+/// - `tim-6`: If there's a periodMax, there must be a period
+/// - `tim-7`: If there's a durationMax, there must be a duration
+/// - `tim-8`: If there's a countMax, there must be a count
+/// - `tim-9`: If there's an offset, there must be a when (and not C, CM, CD, CV)
+/// - `tim-10`: If there's a timeOfDay, there cannot be a when, or vice versa
+///
+/// Skip serializing each attributes that is an option and set to none.
+#[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
+#[serde(rename_all = "camelCase")]
+pub struct TimingRepeat {
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Length/Range of lengths, or (Start and/or end) limits
-    : ? // Duration
+    ///
+    /// Either a duration for the length of the timing schedule, a range of possible length, or outer bounds for start and/or end limits of the timing schedule.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Duration`, `Range`, `Period`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub bounds: Option<TimingRepeatBounds>,
 
     /// Number of times to repeat
-    count: ? // positiveInt
+    ///
+    /// A total count of the desired number of repetitions across the duration of the entire timing specification. If countMax is present, this element indicates the lower bound of the allowed range of count values.
+    ///
+    /// Comment: If you have both bounds and count, then this should be understood as within the bounds period, until count times happens.
+    ///
+    /// Requirements: Repetitions may be limited by end time or total occurrences.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub count: Option<types::PositiveInt>,
+
+    /// Id and extensions of `count`
+    #[serde(rename = "_count")]
+    pub count_element: Option<types::Element>,
 
     /// Maximum number of times to repeat
-    countMax: ? // positiveInt
+    ///
+    /// If present, indicates that the count is a range - so to perform the action between \[count\] and \[countMax\] times.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub count_max: Option<types::PositiveInt>,
+
+    /// Id and extensions of `countMax`
+    #[serde(rename = "_countMax")]
+    pub count_max_element: Option<types::Element>,
 
     /// How long when it happens
-    duration: ? // decimal
+    ///
+    /// How long this thing happens for when it happens. If durationMax is present, this element indicates the lower bound of the allowed range of the duration.
+    ///
+    /// Comment: For some events the duration is part of the definition of the event (e.g. IV infusions, where the duration is implicit in the specified quantity and rate). For others, it's part of the timing specification (e.g. exercise).
+    ///
+    /// Requirements: Some activities are not instantaneous and need to be maintained for a period of time.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub duration: Option<types::Decimal>,
+
+    /// Id and extensions of `duration`
+    #[serde(rename = "_duration")]
+    pub duration_element: Option<types::Element>,
 
     /// How long when it happens (Max)
-    durationMax: ? // decimal
+    ///
+    /// If present, indicates that the duration is a range - so to perform the action between \[duration\] and \[durationMax\] time length.
+    ///
+    /// Comment: For some events the duration is part of the definition of the event (e.g. IV infusions, where the duration is implicit in the specified quantity and rate). For others, it's part of the timing specification (e.g. exercise).
+    ///
+    /// Requirements: Some activities are not instantaneous and need to be maintained for a period of time.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub duration_max: Option<types::Decimal>,
+
+    /// Id and extensions of `durationMax`
+    #[serde(rename = "_durationMax")]
+    pub duration_max_element: Option<types::Element>,
 
     /// s | min | h | d | wk | mo | a - unit of time (UCUM)
-    durationUnit: ? // code
+    ///
+    /// The units of time for the duration, in UCUM units
+    /// Normal practice is to use the 'mo' code as a calendar month when calculating the next occurrence.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/units-of-time|5.0.0`](http://hl7.org/fhir/ValueSet/units-of-time)
+    ///
+    /// A unit of time (units from UCUM).
+    pub duration_unit: Option<types::Code>,
+
+    /// Id and extensions of `durationUnit`
+    #[serde(rename = "_durationUnit")]
+    pub duration_unit_element: Option<types::Element>,
 
     /// Indicates the number of repetitions that should occur within a period. I.e. Event occurs frequency times per period
-    frequency: ? // positiveInt
+    ///
+    /// The number of times to repeat the action within the specified period. If frequencyMax is present, this element indicates the lower bound of the allowed range of the frequency.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub frequency: Option<types::PositiveInt>,
+
+    /// Id and extensions of `frequency`
+    #[serde(rename = "_frequency")]
+    pub frequency_element: Option<types::Element>,
 
     /// Event occurs up to frequencyMax times per period
-    frequencyMax: ? // positiveInt
+    ///
+    /// If present, indicates that the frequency is a range - so to repeat between \[frequency\] and \[frequencyMax\] times within the period or period range.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub frequency_max: Option<types::PositiveInt>,
+
+    /// Id and extensions of `frequencyMax`
+    #[serde(rename = "_frequencyMax")]
+    pub frequency_max_element: Option<types::Element>,
 
     /// The duration to which the frequency applies. I.e. Event occurs frequency times per period
-    period: ? // decimal
+    ///
+    /// Indicates the duration of time over which repetitions are to occur; e.g. to express "3 times per day", 3 would be the frequency and "1 day" would be the period. If periodMax is present, this element indicates the lower bound of the allowed range of the period length.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub period: Option<types::Decimal>,
+
+    /// Id and extensions of `period`
+    #[serde(rename = "_period")]
+    pub period_element: Option<types::Element>,
 
     /// Upper limit of period (3-4 hours)
-    periodMax: ? // decimal
+    ///
+    /// If present, indicates that the period is a range from \[period\] to \[periodMax\], allowing expressing concepts such as "do this once every 3-5 days.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `decimal`
+    pub period_max: Option<types::Decimal>,
+
+    /// Id and extensions of `periodMax`
+    #[serde(rename = "_periodMax")]
+    pub period_max_element: Option<types::Element>,
 
     /// s | min | h | d | wk | mo | a - unit of time (UCUM)
-    periodUnit: ? // code
+    ///
+    /// The units of time for the period in UCUM units
+    /// Normal practice is to use the 'mo' code as a calendar month when calculating the next occurrence.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/units-of-time|5.0.0`](http://hl7.org/fhir/ValueSet/units-of-time)
+    ///
+    /// A unit of time (units from UCUM).
+    pub period_unit: Option<types::Code>,
+
+    /// Id and extensions of `periodUnit`
+    #[serde(rename = "_periodUnit")]
+    pub period_unit_element: Option<types::Element>,
 
     /// mon | tue | wed | thu | fri | sat | sun
-    dayOfWeek: ? // code
+    ///
+    /// If one or more days of week is provided, then the action happens only on the specified day(s).
+    ///
+    /// Comment: If no days are specified, the action is assumed to happen every day as otherwise specified.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/days-of-week|5.0.0`](http://hl7.org/fhir/ValueSet/days-of-week)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub day_of_week: Vec<Option<types::Code>>,
+
+    /// Id and extensions of `dayOfWeek`
+    #[serde(rename = "_dayOfWeek", default, skip_serializing_if = "Vec::is_empty")]
+    pub day_of_week_element: Vec<Option<types::Element>>,
 
     /// Time of day for action
-    timeOfDay: ? // time
+    ///
+    /// Specified time of day for action to take place.
+    ///
+    /// Comment: When time of day is specified, it is inferred that the action happens every day (as filtered by dayofWeek) on the specified times. If there's a timeOfDay, there cannot be a when, or vice versa.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `time`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub time_of_day: Vec<Option<types::Time>>,
+
+    /// Id and extensions of `timeOfDay`
+    #[serde(rename = "_timeOfDay", default, skip_serializing_if = "Vec::is_empty")]
+    pub time_of_day_element: Vec<Option<types::Element>>,
 
     /// Code for time period of occurrence
-    when: ? // code
+    ///
+    /// An approximate time period during the day, potentially linked to an event of daily living that indicates when the action should occur.
+    ///
+    /// Comment: When more than one event is listed, the event is tied to the union of the specified events.
+    ///
+    /// Requirements: Timings are frequently determined by occurrences such as waking, eating and sleep.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/event-timing|5.0.0`](http://hl7.org/fhir/ValueSet/event-timing)
+    ///
+    /// Real-world event relating to the schedule.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub when: Vec<Option<types::Code>>,
+
+    /// Id and extensions of `when`
+    #[serde(rename = "_when", default, skip_serializing_if = "Vec::is_empty")]
+    pub when_element: Vec<Option<types::Element>>,
 
     /// Minutes from event (before or after)
-    offset: ? // unsignedInt
+    ///
+    /// The number of minutes from the event. If the event code does not indicate whether the minutes is before or after the event, then the offset is assumed to be after the event.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `unsignedInt`
+    pub offset: Option<types::UnsignedInt>,
 
-    /// C | BID | TID | QID | AM | PM | QD | QOD | +
-    code: ? // CodeableConcept
+    /// Id and extensions of `offset`
+    #[serde(rename = "_offset")]
+    pub offset_element: Option<types::Element>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl TimingRepeat {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TimingRepeat {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> TimingRepeatBuilder {
+        TimingRepeatBuilder::default()
+    }
+}
 
+/// Build one `TimingRepeat`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct TimingRepeatBuilder {
+    inner: TimingRepeat,
+}
+
+impl TimingRepeatBuilder {
+    /// Set `Timing.repeat.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `Timing.repeat.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `Timing.repeat.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.bounds[x]` to a `Duration`.
+    pub fn bounds_duration(mut self, value: impl Into<types::Duration>) -> Self {
+        self.inner.bounds = Some(TimingRepeatBounds::Duration(value.into()));
+        self
+    }
+
+    /// Set `Timing.repeat.bounds[x]` to a `Range`.
+    pub fn bounds_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.inner.bounds = Some(TimingRepeatBounds::Range(value.into()));
+        self
+    }
+
+    /// Set `Timing.repeat.bounds[x]` to a `Period`.
+    pub fn bounds_period(mut self, value: impl Into<types::Period>) -> Self {
+        self.inner.bounds = Some(TimingRepeatBounds::Period(value.into()));
+        self
+    }
+
+    /// Set `Timing.repeat.count`.
+    pub fn count(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.count = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.countMax`.
+    pub fn count_max(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.count_max = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.duration`.
+    pub fn duration(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.duration = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.durationMax`.
+    pub fn duration_max(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.duration_max = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.durationUnit`.
+    pub fn duration_unit(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.duration_unit = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.frequency`.
+    pub fn frequency(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.frequency = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.frequencyMax`.
+    pub fn frequency_max(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.frequency_max = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.period`.
+    pub fn period(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.period = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.periodMax`.
+    pub fn period_max(mut self, value: impl Into<types::Decimal>) -> Self {
+        self.inner.period_max = Some(value.into());
+        self
+    }
+
+    /// Set `Timing.repeat.periodUnit`.
+    pub fn period_unit(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.period_unit = Some(value.into());
+        self
+    }
+
+    /// Set every `Timing.repeat.dayOfWeek`.
+    pub fn day_of_week(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Code>>,
+    ) -> Self {
+        self.inner.day_of_week = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `Timing.repeat.dayOfWeek`.
+    pub fn push_day_of_week(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.day_of_week.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `Timing.repeat.timeOfDay`.
+    pub fn time_of_day(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Time>>,
+    ) -> Self {
+        self.inner.time_of_day = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `Timing.repeat.timeOfDay`.
+    pub fn push_time_of_day(mut self, value: impl Into<types::Time>) -> Self {
+        self.inner.time_of_day.push(Some(value.into()));
+        self
+    }
+
+    /// Set every `Timing.repeat.when`.
+    pub fn when(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Code>>,
+    ) -> Self {
+        self.inner.when = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `Timing.repeat.when`.
+    pub fn push_when(mut self, value: impl Into<types::Code>) -> Self {
+        self.inner.when.push(Some(value.into()));
+        self
+    }
+
+    /// Set `Timing.repeat.offset`.
+    pub fn offset(mut self, value: impl Into<types::UnsignedInt>) -> Self {
+        self.inner.offset = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<TimingRepeat, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for TimingRepeat {
+    const PATH: &'static str = "Timing.repeat";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.bounds, &format!("{path}.bounds"), issues);
+        types::Validate::validate_into(&self.count, &format!("{path}.count"), issues);
+        types::Validate::validate_into(
+            &self.count_element,
+            &format!("{path}._count"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.count_max,
+            &format!("{path}.countMax"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.count_max_element,
+            &format!("{path}._countMax"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.duration,
+            &format!("{path}.duration"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.duration_element,
+            &format!("{path}._duration"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.duration_max,
+            &format!("{path}.durationMax"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.duration_max_element,
+            &format!("{path}._durationMax"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.duration_unit,
+            &format!("{path}.durationUnit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.duration_unit_element,
+            &format!("{path}._durationUnit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.frequency,
+            &format!("{path}.frequency"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.frequency_element,
+            &format!("{path}._frequency"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.frequency_max,
+            &format!("{path}.frequencyMax"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.frequency_max_element,
+            &format!("{path}._frequencyMax"),
+            issues,
+        );
+        types::Validate::validate_into(&self.period, &format!("{path}.period"), issues);
+        types::Validate::validate_into(
+            &self.period_element,
+            &format!("{path}._period"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.period_max,
+            &format!("{path}.periodMax"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.period_max_element,
+            &format!("{path}._periodMax"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.period_unit,
+            &format!("{path}.periodUnit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.period_unit_element,
+            &format!("{path}._periodUnit"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.day_of_week,
+            &format!("{path}.dayOfWeek"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.day_of_week_element,
+            &format!("{path}._dayOfWeek"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.time_of_day,
+            &format!("{path}.timeOfDay"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.time_of_day_element,
+            &format!("{path}._timeOfDay"),
+            issues,
+        );
+        types::Validate::validate_into(&self.when, &format!("{path}.when"), issues);
+        types::Validate::validate_into(
+            &self.when_element,
+            &format!("{path}._when"),
+            issues,
+        );
+        types::Validate::validate_into(&self.offset, &format!("{path}.offset"), issues);
+        types::Validate::validate_into(
+            &self.offset_element,
+            &format!("{path}._offset"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for TimingRepeat {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.bounds, &format!("{path}.bounds"), visitor);
+        types::Walk::accept(&self.count, &format!("{path}.count"), visitor);
+        types::Walk::accept(&self.count_element, &format!("{path}._count"), visitor);
+        types::Walk::accept(&self.count_max, &format!("{path}.countMax"), visitor);
+        types::Walk::accept(
+            &self.count_max_element,
+            &format!("{path}._countMax"),
+            visitor,
+        );
+        types::Walk::accept(&self.duration, &format!("{path}.duration"), visitor);
+        types::Walk::accept(
+            &self.duration_element,
+            &format!("{path}._duration"),
+            visitor,
+        );
+        types::Walk::accept(&self.duration_max, &format!("{path}.durationMax"), visitor);
+        types::Walk::accept(
+            &self.duration_max_element,
+            &format!("{path}._durationMax"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.duration_unit,
+            &format!("{path}.durationUnit"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.duration_unit_element,
+            &format!("{path}._durationUnit"),
+            visitor,
+        );
+        types::Walk::accept(&self.frequency, &format!("{path}.frequency"), visitor);
+        types::Walk::accept(
+            &self.frequency_element,
+            &format!("{path}._frequency"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.frequency_max,
+            &format!("{path}.frequencyMax"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.frequency_max_element,
+            &format!("{path}._frequencyMax"),
+            visitor,
+        );
+        types::Walk::accept(&self.period, &format!("{path}.period"), visitor);
+        types::Walk::accept(&self.period_element, &format!("{path}._period"), visitor);
+        types::Walk::accept(&self.period_max, &format!("{path}.periodMax"), visitor);
+        types::Walk::accept(
+            &self.period_max_element,
+            &format!("{path}._periodMax"),
+            visitor,
+        );
+        types::Walk::accept(&self.period_unit, &format!("{path}.periodUnit"), visitor);
+        types::Walk::accept(
+            &self.period_unit_element,
+            &format!("{path}._periodUnit"),
+            visitor,
+        );
+        types::Walk::accept(&self.day_of_week, &format!("{path}.dayOfWeek"), visitor);
+        types::Walk::accept(
+            &self.day_of_week_element,
+            &format!("{path}._dayOfWeek"),
+            visitor,
+        );
+        types::Walk::accept(&self.time_of_day, &format!("{path}.timeOfDay"), visitor);
+        types::Walk::accept(
+            &self.time_of_day_element,
+            &format!("{path}._timeOfDay"),
+            visitor,
+        );
+        types::Walk::accept(&self.when, &format!("{path}.when"), visitor);
+        types::Walk::accept(&self.when_element, &format!("{path}._when"), visitor);
+        types::Walk::accept(&self.offset, &format!("{path}.offset"), visitor);
+        types::Walk::accept(&self.offset_element, &format!("{path}._offset"), visitor);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.bounds, &format!("{path}.bounds"), visitor);
+        types::Walk::accept_mut(&mut self.count, &format!("{path}.count"), visitor);
+        types::Walk::accept_mut(
+            &mut self.count_element,
+            &format!("{path}._count"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.count_max,
+            &format!("{path}.countMax"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.count_max_element,
+            &format!("{path}._countMax"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.duration,
+            &format!("{path}.duration"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.duration_element,
+            &format!("{path}._duration"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.duration_max,
+            &format!("{path}.durationMax"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.duration_max_element,
+            &format!("{path}._durationMax"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.duration_unit,
+            &format!("{path}.durationUnit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.duration_unit_element,
+            &format!("{path}._durationUnit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.frequency,
+            &format!("{path}.frequency"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.frequency_element,
+            &format!("{path}._frequency"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.frequency_max,
+            &format!("{path}.frequencyMax"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.frequency_max_element,
+            &format!("{path}._frequencyMax"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.period, &format!("{path}.period"), visitor);
+        types::Walk::accept_mut(
+            &mut self.period_element,
+            &format!("{path}._period"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.period_max,
+            &format!("{path}.periodMax"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.period_max_element,
+            &format!("{path}._periodMax"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.period_unit,
+            &format!("{path}.periodUnit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.period_unit_element,
+            &format!("{path}._periodUnit"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.day_of_week,
+            &format!("{path}.dayOfWeek"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.day_of_week_element,
+            &format!("{path}._dayOfWeek"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.time_of_day,
+            &format!("{path}.timeOfDay"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.time_of_day_element,
+            &format!("{path}._timeOfDay"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.when, &format!("{path}.when"), visitor);
+        types::Walk::accept_mut(
+            &mut self.when_element,
+            &format!("{path}._when"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.offset, &format!("{path}.offset"), visitor);
+        types::Walk::accept_mut(
+            &mut self.offset_element,
+            &format!("{path}._offset"),
+            visitor,
+        );
+    }
+}
+
+impl TimingRepeat {
+    /// The metadata of each child element of `Timing.repeat`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "bounds[x]",
+            json_key: "bounds",
+            rust_field: "bounds",
+            min: 0,
+            max: Some(1),
+            types: &["Duration", "Range", "Period"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "count",
+            json_key: "count",
+            rust_field: "count",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "countMax",
+            json_key: "countMax",
+            rust_field: "count_max",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "duration",
+            json_key: "duration",
+            rust_field: "duration",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "durationMax",
+            json_key: "durationMax",
+            rust_field: "duration_max",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "durationUnit",
+            json_key: "durationUnit",
+            rust_field: "duration_unit",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/units-of-time|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "frequency",
+            json_key: "frequency",
+            rust_field: "frequency",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "frequencyMax",
+            json_key: "frequencyMax",
+            rust_field: "frequency_max",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "period",
+            json_key: "period",
+            rust_field: "period",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "periodMax",
+            json_key: "periodMax",
+            rust_field: "period_max",
+            min: 0,
+            max: Some(1),
+            types: &["decimal"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "periodUnit",
+            json_key: "periodUnit",
+            rust_field: "period_unit",
+            min: 0,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/units-of-time|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "dayOfWeek",
+            json_key: "dayOfWeek",
+            rust_field: "day_of_week",
+            min: 0,
+            max: None,
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/days-of-week|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "timeOfDay",
+            json_key: "timeOfDay",
+            rust_field: "time_of_day",
+            min: 0,
+            max: None,
+            types: &["time"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "when",
+            json_key: "when",
+            rust_field: "when",
+            min: 0,
+            max: None,
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/event-timing|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "offset",
+            json_key: "offset",
+            rust_field: "offset",
+            min: 0,
+            max: Some(1),
+            types: &["unsignedInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for TimingRepeat {
+    fn type_name(&self) -> &'static str {
+        "Timing.repeat"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "bounds[x]" | "bounds" => types::AsValueRef::as_value_ref(&self.bounds),
+            "count" => types::AsValueRef::as_value_ref(&self.count),
+            "countMax" => types::AsValueRef::as_value_ref(&self.count_max),
+            "duration" => types::AsValueRef::as_value_ref(&self.duration),
+            "durationMax" => types::AsValueRef::as_value_ref(&self.duration_max),
+            "durationUnit" => types::AsValueRef::as_value_ref(&self.duration_unit),
+            "frequency" => types::AsValueRef::as_value_ref(&self.frequency),
+            "frequencyMax" => types::AsValueRef::as_value_ref(&self.frequency_max),
+            "period" => types::AsValueRef::as_value_ref(&self.period),
+            "periodMax" => types::AsValueRef::as_value_ref(&self.period_max),
+            "periodUnit" => types::AsValueRef::as_value_ref(&self.period_unit),
+            "dayOfWeek" => types::AsValueRef::as_value_ref(&self.day_of_week),
+            "timeOfDay" => types::AsValueRef::as_value_ref(&self.time_of_day),
+            "when" => types::AsValueRef::as_value_ref(&self.when),
+            "offset" => types::AsValueRef::as_value_ref(&self.offset),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for TimingRepeat {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for TimingRepeat {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `Timing.repeat.bounds[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TimingRepeatBounds {
+    #[serde(rename = "boundsDuration")]
+    Duration(types::Duration),
+    #[serde(rename = "boundsRange")]
+    Range(types::Range),
+    #[serde(rename = "boundsPeriod")]
+    Period(types::Period),
+}
+
+impl Default for TimingRepeatBounds {
+    fn default() -> Self {
+        Self::Duration(Default::default())
+    }
+}
+
+impl types::Choice for TimingRepeatBounds {
+    const KEYS: &'static [&'static str] = &[
+        "boundsDuration",
+        "boundsRange",
+        "boundsPeriod",
+    ];
+}
+
+impl types::Validate for TimingRepeatBounds {
+    const PATH: &'static str = "Timing.repeat.bounds";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Duration(value) => {
+                types::Validate::validate_into(value, &format!("{path}Duration"), issues)
+            }
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Period(value) => {
+                types::Validate::validate_into(value, &format!("{path}Period"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for TimingRepeatBounds {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Duration(value) => {
+                types::Walk::accept(value, &format!("{path}Duration"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept(value, &format!("{path}Period"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Duration(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Duration"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Period(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Period"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for TimingRepeatBounds {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Duration(value) => types::AsValueRef::as_value_ref(value),
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Period(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = Timing;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -1,6 +1,6 @@
 //! TriggerDefinition
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/TriggerDefinition
+//! URL: <http://hl7.org/fhir/StructureDefinition/TriggerDefinition>
 //!
 //! Version: 5.0.0
 //!
@@ -10,78 +10,746 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Defines an expected trigger for a module
+///
+/// A description of a triggering event. Triggering events can be named events, data events, or periodic, as determined by the type element.
+///
+/// Comment: If an event is a named-event, it means the event is completely pre-coordinated, and no other information can be specified for the event. If the event is one of the data- events, the data and condition elements specify the triggering criteria. The data element specifies the structured component, and the condition element provides additional optional refinement of that structured component. If the event is periodic, the timing element defines when the event is triggered. For both data- and periodic events, a name can be provided as a shorthand for the formal semantics provided by the other elements.
+///
+/// Cardinality: 0..*
+///
+/// Constraints:
+///
+/// - `trd-1`: Either timing, or a data requirement, but not both
+/// - `trd-2`: A condition only if there is a data requirement
+/// - `trd-3`: A named event requires a name, a periodic event requires timing, and a data event requires data
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
 pub struct TriggerDefinition {
-    /// Defines an expected trigger for a module
-    TriggerDefinition: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// named-event | periodic | data-changed | data-added | data-modified | data-removed | data-accessed | data-access-ended
-    type: ? // code
+    ///
+    /// The type of triggering event.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `code`
+    ///
+    /// Binding: required to [`http://hl7.org/fhir/ValueSet/trigger-type|5.0.0`](http://hl7.org/fhir/ValueSet/trigger-type)
+    ///
+    /// The type of trigger.
+    pub r#type: types::Code,
+
+    /// Id and extensions of `type`
+    #[serde(rename = "_type")]
+    pub type_element: Option<types::Element>,
 
     /// Name or URI that identifies the event
-    name: ? // string
+    ///
+    /// A formal name for the event. This may be an absolute URI that identifies the event formally (e.g. from a trigger registry), or a simple relative URI that identifies the event in a local context.
+    ///
+    /// Comment: An event name can be provided for all event types, but is required for named events. If a name is provided for a type other than named events, it is considered to be a shorthand for the semantics described by the formal description of the event.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub name: Option<types::String>,
+
+    /// Id and extensions of `name`
+    #[serde(rename = "_name")]
+    pub name_element: Option<types::Element>,
 
     /// Coded definition of the event
-    code: ? // CodeableConcept
+    ///
+    /// A code that identifies the event.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `CodeableConcept`
+    pub code: Option<types::CodeableConcept>,
 
     /// What event
-    subscriptionTopic: ? // canonical
+    ///
+    /// A reference to a SubscriptionTopic resource that defines the event. If this element is provided, no other information about the trigger definition may be supplied.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `canonical(SubscriptionTopic)`
+    pub subscription_topic: Option<types::Canonical>,
+
+    /// Id and extensions of `subscriptionTopic`
+    #[serde(rename = "_subscriptionTopic")]
+    pub subscription_topic_element: Option<types::Element>,
 
     /// Timing of the event
-    : ? // Timing
+    ///
+    /// The timing of the event (if this is a periodic trigger).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Timing`, `Reference(Schedule)`, `date`, `dateTime`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub timing: Option<TriggerDefinitionTiming>,
+
+    /// Id and extensions of `timing[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub timing_element: Option<TriggerDefinitionTimingElement>,
 
     /// Triggering data of the event (multiple = 'and')
-    data: ? // DataRequirement
+    ///
+    /// The triggering data of the event (if this is a data trigger). If more than one data is requirement is specified, then all the data requirements must be true.
+    ///
+    /// Comment: This element shall be present for any data type trigger.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `DataRequirement`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data: Vec<types::DataRequirement>,
 
     /// Whether the event triggers (boolean expression)
-    condition: ? // Expression
+    ///
+    /// A boolean-valued expression that is evaluated in the context of the container of the trigger definition and returns whether or not the trigger fires.
+    ///
+    /// Comment: This element can be only be specified for data type triggers and provides additional semantics for the trigger. The context available within the condition is based on the type of data event. For all events, the current resource will be available as context. In addition, for modification events, the previous resource will also be available. The expression may be inlined, or may be a simple absolute URI, which is a reference to a named expression within a logic library referenced by a library element or extension within the containing resource. If the expression is a FHIR Path expression, it evaluates in the context of a resource of one of the type identified in the data requirement, and may also refer to the variable %previous for delta comparisons on events of type data-changed, data-modified, and data-deleted which will always have the same type.
+    ///
+    /// Requirements: Need to be able to formally describe the triggering criteria.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Expression`
+    pub condition: Option<types::Expression>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl TriggerDefinition {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(r#type: types::Code) -> Self {
+        Self {
+            r#type,
+            ..Default::default()
+        }
+    }
+}
+
+impl TriggerDefinition {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> TriggerDefinitionBuilder {
+        TriggerDefinitionBuilder::default()
+    }
+}
+
+/// Build one `TriggerDefinition`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct TriggerDefinitionBuilder {
+    inner: TriggerDefinition,
+    r#type: Option<types::Code>,
+}
+
+impl TriggerDefinitionBuilder {
+    /// Set `TriggerDefinition.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `TriggerDefinition.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `TriggerDefinition.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `TriggerDefinition.type`.
+    pub fn r#type(mut self, value: impl Into<types::Code>) -> Self {
+        self.r#type = Some(value.into());
+        self
+    }
+
+    /// Set `TriggerDefinition.name`.
+    pub fn name(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.name = Some(value.into());
+        self
+    }
+
+    /// Set `TriggerDefinition.code`.
+    pub fn code(mut self, value: impl Into<types::CodeableConcept>) -> Self {
+        self.inner.code = Some(value.into());
+        self
+    }
+
+    /// Set `TriggerDefinition.subscriptionTopic`.
+    pub fn subscription_topic(mut self, value: impl Into<types::Canonical>) -> Self {
+        self.inner.subscription_topic = Some(value.into());
+        self
+    }
 
+    /// Set `TriggerDefinition.timing[x]` to a `Timing`.
+    pub fn timing_timing(mut self, value: impl Into<types::Timing>) -> Self {
+        self.inner.timing = Some(TriggerDefinitionTiming::Timing(value.into()));
+        self
+    }
+
+    /// Set `TriggerDefinition.timing[x]` to a `Reference`.
+    pub fn timing_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.inner.timing = Some(TriggerDefinitionTiming::Reference(value.into()));
+        self
+    }
+
+    /// Set `TriggerDefinition.timing[x]` to a `date`.
+    pub fn timing_date(mut self, value: impl Into<types::Date>) -> Self {
+        self.inner.timing = Some(TriggerDefinitionTiming::Date(value.into()));
+        self
+    }
+
+    /// Set `TriggerDefinition.timing[x]` to a `dateTime`.
+    pub fn timing_date_time(mut self, value: impl Into<types::DateTime>) -> Self {
+        self.inner.timing = Some(TriggerDefinitionTiming::DateTime(value.into()));
+        self
+    }
+
+    /// Set every `TriggerDefinition.data`.
+    pub fn data(mut self, value: impl Into<Vec<types::DataRequirement>>) -> Self {
+        self.inner.data = value.into();
+        self
+    }
+
+    /// Add one `TriggerDefinition.data`.
+    pub fn push_data(mut self, value: impl Into<types::DataRequirement>) -> Self {
+        self.inner.data.push(value.into());
+        self
+    }
+
+    /// Set `TriggerDefinition.condition`.
+    pub fn condition(mut self, value: impl Into<types::Expression>) -> Self {
+        self.inner.condition = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<TriggerDefinition, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.r#type {
+            Some(value) => inner.r#type = value,
+            None => missing.push("TriggerDefinition.type"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for TriggerDefinition {
+    const PATH: &'static str = "TriggerDefinition";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.r#type, &format!("{path}.type"), issues);
+        types::Validate::validate_into(
+            &self.type_element,
+            &format!("{path}._type"),
+            issues,
+        );
+        types::Validate::validate_into(&self.name, &format!("{path}.name"), issues);
+        types::Validate::validate_into(
+            &self.name_element,
+            &format!("{path}._name"),
+            issues,
+        );
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+        types::Validate::validate_into(
+            &self.subscription_topic,
+            &format!("{path}.subscriptionTopic"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.subscription_topic_element,
+            &format!("{path}._subscriptionTopic"),
+            issues,
+        );
+        types::Validate::validate_into(&self.timing, &format!("{path}.timing"), issues);
+        types::Validate::validate_into(
+            &self.timing_element,
+            &format!("{path}._timing"),
+            issues,
+        );
+        types::Validate::validate_into(&self.data, &format!("{path}.data"), issues);
+        types::Validate::validate_into(
+            &self.condition,
+            &format!("{path}.condition"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for TriggerDefinition {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_trigger_definition(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept(&self.type_element, &format!("{path}._type"), visitor);
+        types::Walk::accept(&self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept(&self.name_element, &format!("{path}._name"), visitor);
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept(
+            &self.subscription_topic,
+            &format!("{path}.subscriptionTopic"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.subscription_topic_element,
+            &format!("{path}._subscriptionTopic"),
+            visitor,
+        );
+        types::Walk::accept(&self.timing, &format!("{path}.timing"), visitor);
+        types::Walk::accept(&self.timing_element, &format!("{path}._timing"), visitor);
+        types::Walk::accept(&self.data, &format!("{path}.data"), visitor);
+        types::Walk::accept(&self.condition, &format!("{path}.condition"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_trigger_definition_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.r#type, &format!("{path}.type"), visitor);
+        types::Walk::accept_mut(
+            &mut self.type_element,
+            &format!("{path}._type"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.name, &format!("{path}.name"), visitor);
+        types::Walk::accept_mut(
+            &mut self.name_element,
+            &format!("{path}._name"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept_mut(
+            &mut self.subscription_topic,
+            &format!("{path}.subscriptionTopic"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.subscription_topic_element,
+            &format!("{path}._subscriptionTopic"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.timing, &format!("{path}.timing"), visitor);
+        types::Walk::accept_mut(
+            &mut self.timing_element,
+            &format!("{path}._timing"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.data, &format!("{path}.data"), visitor);
+        types::Walk::accept_mut(
+            &mut self.condition,
+            &format!("{path}.condition"),
+            visitor,
+        );
+    }
+}
+
+impl TriggerDefinition {
+    /// The metadata of each child element of `TriggerDefinition`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "type",
+            json_key: "type",
+            rust_field: "r#type",
+            min: 1,
+            max: Some(1),
+            types: &["code"],
+            binding: Some(types::BindingInfo {
+                strength: "required",
+                value_set: Some("http://hl7.org/fhir/ValueSet/trigger-type|5.0.0"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "name",
+            json_key: "name",
+            rust_field: "name",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 0,
+            max: Some(1),
+            types: &["CodeableConcept"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "subscriptionTopic",
+            json_key: "subscriptionTopic",
+            rust_field: "subscription_topic",
+            min: 0,
+            max: Some(1),
+            types: &["canonical"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "timing[x]",
+            json_key: "timing",
+            rust_field: "timing",
+            min: 0,
+            max: Some(1),
+            types: &["Timing", "Reference", "date", "dateTime"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "data",
+            json_key: "data",
+            rust_field: "data",
+            min: 0,
+            max: None,
+            types: &["DataRequirement"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "condition",
+            json_key: "condition",
+            rust_field: "condition",
+            min: 0,
+            max: Some(1),
+            types: &["Expression"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for TriggerDefinition {
+    fn type_name(&self) -> &'static str {
+        "TriggerDefinition"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "type" => types::AsValueRef::as_value_ref(&self.r#type),
+            "name" => types::AsValueRef::as_value_ref(&self.name),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            "subscriptionTopic" => {
+                types::AsValueRef::as_value_ref(&self.subscription_topic)
+            }
+            "timing[x]" | "timing" => types::AsValueRef::as_value_ref(&self.timing),
+            "data" => types::AsValueRef::as_value_ref(&self.data),
+            "condition" => types::AsValueRef::as_value_ref(&self.condition),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for TriggerDefinition {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for TriggerDefinition {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `TriggerDefinition.timing[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TriggerDefinitionTiming {
+    #[serde(rename = "timingTiming")]
+    Timing(types::Timing),
+    #[serde(rename = "timingReference")]
+    Reference(types::Reference),
+    #[serde(rename = "timingDate")]
+    Date(types::Date),
+    #[serde(rename = "timingDateTime")]
+    DateTime(types::DateTime),
+}
+
+impl Default for TriggerDefinitionTiming {
+    fn default() -> Self {
+        Self::Timing(Default::default())
+    }
+}
+
+impl types::Choice for TriggerDefinitionTiming {
+    const KEYS: &'static [&'static str] = &[
+        "timingTiming",
+        "timingReference",
+        "timingDate",
+        "timingDateTime",
+    ];
+}
+
+impl types::Validate for TriggerDefinitionTiming {
+    const PATH: &'static str = "TriggerDefinition.timing";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Timing(value) => {
+                types::Validate::validate_into(value, &format!("{path}Timing"), issues)
+            }
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for TriggerDefinitionTiming {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Timing(value) => {
+                types::Walk::accept(value, &format!("{path}Timing"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Timing(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Timing"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for TriggerDefinitionTiming {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Timing(value) => types::AsValueRef::as_value_ref(value),
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+            Self::Date(value) => types::AsValueRef::as_value_ref(value),
+            Self::DateTime(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `TriggerDefinition.timing[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TriggerDefinitionTimingElement {
+    #[serde(rename = "_timingDate")]
+    Date(types::Element),
+    #[serde(rename = "_timingDateTime")]
+    DateTime(types::Element),
+}
+
+impl types::Choice for TriggerDefinitionTimingElement {
+    const KEYS: &'static [&'static str] = &["_timingDate", "_timingDateTime"];
+}
+
+impl types::Validate for TriggerDefinitionTimingElement {
+    const PATH: &'static str = "TriggerDefinition.timing";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Date(value) => {
+                types::Validate::validate_into(value, &format!("{path}Date"), issues)
+            }
+            Self::DateTime(value) => {
+                types::Validate::validate_into(value, &format!("{path}DateTime"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for TriggerDefinitionTimingElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept(value, &format!("{path}DateTime"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Date(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Date"), visitor)
+            }
+            Self::DateTime(value) => {
+                types::Walk::accept_mut(value, &format!("{path}DateTime"), visitor)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = TriggerDefinition;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["type"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
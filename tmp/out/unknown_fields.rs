@@ -0,0 +1,32 @@
+//! Unknown fields
+//!
+//! Serde ignores `#[serde(deny_unknown_fields)]` on a struct that flattens a
+//! field, such as a choice of types. Such a struct flattens this type last,
+//! which reads the keys that no other field reads, and rejects the first.
+
+use ::serde::de::{Error, IgnoredAny};
+use ::serde::ser::SerializeMap;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// Reject any key that no other field of the struct reads, and write none.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DenyUnknownFields;
+
+impl<'de> Deserialize<'de> for DenyUnknownFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let unknown: BTreeMap<String, IgnoredAny> = Deserialize::deserialize(
+            deserializer,
+        )?;
+        match unknown.into_keys().next() {
+            Some(key) => Err(D::Error::custom(format!("unknown field `{key}`"))),
+            None => Ok(Self),
+        }
+    }
+}
+
+impl Serialize for DenyUnknownFields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
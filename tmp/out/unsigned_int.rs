@@ -1,69 +1,111 @@
 //! UnsignedInt
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/unsignedInt
+//! URL: <http://hl7.org/fhir/StructureDefinition/unsignedInt>
 //!
 //! Version: 5.0.0
 //!
-//! unsignedInt type: An integer with a value that is not negative (e.g. >= 0)
+//! unsignedInt type: An integer with a value that is not negative (e.g. &gt;= 0)
 //!
 //! FHIR: <https://build.fhir.org/>
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
-#[serde_with::skip_serializing_none]
+/// Wrap the value of the FHIR `unsignedInt` primitive type.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+/// Serialize as the bare JSON value, without a wrapping object.
+#[serde(transparent)]
 pub struct UnsignedInt {
-    /// Primitive Type unsignedInt
-    unsignedInt: ? // ?
+    pub value: u32,
+}
+
+impl UnsignedInt {
+    /// Create with the value.
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<u32> for UnsignedInt {
+    fn from(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl types::Validate for UnsignedInt {
+    const PATH: &'static str = "unsignedInt";
+
+    fn validate_into(&self, _path: &str, _issues: &mut Vec<types::ValidationIssue>) {}
+}
+
+impl types::Walk for UnsignedInt {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_unsigned_int(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, _path: &str, _visitor: &mut V) {}
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_unsigned_int_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, _path: &str, _visitor: &mut V) {}
+}
 
-    /// xml:id (or equivalent in JSON)
-    id: ? // http://hl7.org/fhirpath/System.String
+impl UnsignedInt {
+    /// The metadata of each child element of `unsignedInt`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[];
+}
 
-    /// Additional content defined by implementations
-    extension: ? // Extension
+impl types::Reflect for UnsignedInt {
+    fn type_name(&self) -> &'static str {
+        "unsignedInt"
+    }
 
-    /// Primitive value for unsignedInt
-    value: ? // http://hl7.org/fhirpath/System.String
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
 
+    fn child(&self, _name: &str) -> Option<types::ValueRef<'_>> {
+        None
+    }
+}
+
+impl types::AsValueRef for UnsignedInt {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        types::AsValueRef::as_value_ref(&self.value)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = UnsignedInt;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        assert_eq!(actual, ::serde_json::json!(0));
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
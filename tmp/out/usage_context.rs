@@ -1,6 +1,6 @@
 //! UsageContext
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/UsageContext
+//! URL: <http://hl7.org/fhir/StructureDefinition/UsageContext>
 //!
 //! Version: 5.0.0
 //!
@@ -10,63 +10,459 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Describes the context of use for a conformance or knowledge resource
+///
+/// Specifies clinical/business/etc. metadata that can be used to retrieve, index and/or categorize an artifact. This metadata can either be specific to the applicable population (e.g., age category, DRG) or the specific context of care (e.g., venue, care setting, provider of care).
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
 pub struct UsageContext {
-    /// Describes the context of use for a conformance or knowledge resource
-    UsageContext: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Type of context being specified
-    code: ? // Coding
+    ///
+    /// A code that identifies the type of context being specified by this usage context.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `Coding`
+    ///
+    /// Binding: extensible to [`http://terminology.hl7.org/ValueSet/usage-context-type`](http://terminology.hl7.org/ValueSet/usage-context-type)
+    ///
+    /// A code that specifies a type of context being specified by a usage context.
+    pub code: types::Coding,
 
     /// Value that defines the context
-    : ? // CodeableConcept
+    ///
+    /// A value that defines the context specified in this context of use. The interpretation of the value is defined by the code.
+    ///
+    /// Cardinality: 1..1
+    ///
+    /// Types: `CodeableConcept`, `Quantity`, `Range`, `Reference(PlanDefinition | ResearchStudy | InsurancePlan | HealthcareService | Group | Location | Organization)`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/use-context`](http://hl7.org/fhir/ValueSet/use-context)
+    ///
+    /// A code that defines the specific value for the context being specified.
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_required")]
+    pub value: UsageContextValue,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl UsageContext {
+    /// Create with the required attributes, and every other attribute
+    /// set to its default.
+    pub fn new(code: types::Coding, value: UsageContextValue) -> Self {
+        Self {
+            code,
+            value,
+            ..Default::default()
+        }
+    }
+}
+
+impl UsageContext {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> UsageContextBuilder {
+        UsageContextBuilder::default()
+    }
+}
+
+/// Build one `UsageContext`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct UsageContextBuilder {
+    inner: UsageContext,
+    code: Option<types::Coding>,
+    value: Option<UsageContextValue>,
+}
+
+impl UsageContextBuilder {
+    /// Set `UsageContext.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `UsageContext.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `UsageContext.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `UsageContext.code`.
+    pub fn code(mut self, value: impl Into<types::Coding>) -> Self {
+        self.code = Some(value.into());
+        self
+    }
+
+    /// Set `UsageContext.value[x]` to a `CodeableConcept`.
+    pub fn value_codeable_concept(
+        mut self,
+        value: impl Into<types::CodeableConcept>,
+    ) -> Self {
+        self.value = Some(UsageContextValue::CodeableConcept(value.into()));
+        self
+    }
+
+    /// Set `UsageContext.value[x]` to a `Quantity`.
+    pub fn value_quantity(mut self, value: impl Into<types::Quantity>) -> Self {
+        self.value = Some(UsageContextValue::Quantity(value.into()));
+        self
+    }
+
+    /// Set `UsageContext.value[x]` to a `Range`.
+    pub fn value_range(mut self, value: impl Into<types::Range>) -> Self {
+        self.value = Some(UsageContextValue::Range(value.into()));
+        self
+    }
+
+    /// Set `UsageContext.value[x]` to a `Reference`.
+    pub fn value_reference(mut self, value: impl Into<types::Reference>) -> Self {
+        self.value = Some(UsageContextValue::Reference(value.into()));
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<UsageContext, types::MissingElements> {
+        let mut inner = self.inner;
+        let mut missing = vec![];
+        match self.code {
+            Some(value) => inner.code = value,
+            None => missing.push("UsageContext.code"),
+        }
+        match self.value {
+            Some(value) => inner.value = value,
+            None => missing.push("UsageContext.value[x]"),
+        }
+        if missing.is_empty() {
+            Ok(inner)
+        } else {
+            Err(types::MissingElements {
+                paths: missing,
+            })
+        }
+    }
+}
+
+impl types::Validate for UsageContext {
+    const PATH: &'static str = "UsageContext";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(&self.code, &format!("{path}.code"), issues);
+        types::Validate::validate_into(&self.value, &format!("{path}.value"), issues);
+    }
+}
+
+impl types::Walk for UsageContext {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_usage_context(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept(&self.value, &format!("{path}.value"), visitor);
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_usage_context_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.code, &format!("{path}.code"), visitor);
+        types::Walk::accept_mut(&mut self.value, &format!("{path}.value"), visitor);
+    }
+}
+
+impl UsageContext {
+    /// The metadata of each child element of `UsageContext`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "code",
+            json_key: "code",
+            rust_field: "code",
+            min: 1,
+            max: Some(1),
+            types: &["Coding"],
+            binding: Some(types::BindingInfo {
+                strength: "extensible",
+                value_set: Some("http://terminology.hl7.org/ValueSet/usage-context-type"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "value[x]",
+            json_key: "value",
+            rust_field: "value",
+            min: 1,
+            max: Some(1),
+            types: &["CodeableConcept", "Quantity", "Range", "Reference"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some("http://hl7.org/fhir/ValueSet/use-context"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
+
+impl types::Reflect for UsageContext {
+    fn type_name(&self) -> &'static str {
+        "UsageContext"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "code" => types::AsValueRef::as_value_ref(&self.code),
+            "value[x]" | "value" => types::AsValueRef::as_value_ref(&self.value),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for UsageContext {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for UsageContext {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `UsageContext.value[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum UsageContextValue {
+    #[serde(rename = "valueCodeableConcept")]
+    CodeableConcept(types::CodeableConcept),
+    #[serde(rename = "valueQuantity")]
+    Quantity(types::Quantity),
+    #[serde(rename = "valueRange")]
+    Range(types::Range),
+    #[serde(rename = "valueReference")]
+    Reference(types::Reference),
+}
+
+impl Default for UsageContextValue {
+    fn default() -> Self {
+        Self::CodeableConcept(Default::default())
+    }
+}
+
+impl types::Choice for UsageContextValue {
+    const KEYS: &'static [&'static str] = &[
+        "valueCodeableConcept",
+        "valueQuantity",
+        "valueRange",
+        "valueReference",
+    ];
+}
+
+impl types::Validate for UsageContextValue {
+    const PATH: &'static str = "UsageContext.value";
 
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::CodeableConcept(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    issues,
+                )
+            }
+            Self::Quantity(value) => {
+                types::Validate::validate_into(value, &format!("{path}Quantity"), issues)
+            }
+            Self::Range(value) => {
+                types::Validate::validate_into(value, &format!("{path}Range"), issues)
+            }
+            Self::Reference(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}Reference"),
+                    issues,
+                )
+            }
+        }
+    }
+}
+
+impl types::Walk for UsageContextValue {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::CodeableConcept(value) => {
+                types::Walk::accept(value, &format!("{path}CodeableConcept"), visitor)
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept(value, &format!("{path}Range"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept(value, &format!("{path}Reference"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::CodeableConcept(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}CodeableConcept"),
+                    visitor,
+                )
+            }
+            Self::Quantity(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Quantity"), visitor)
+            }
+            Self::Range(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Range"), visitor)
+            }
+            Self::Reference(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Reference"), visitor)
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for UsageContextValue {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::CodeableConcept(value) => types::AsValueRef::as_value_ref(value),
+            Self::Quantity(value) => types::AsValueRef::as_value_ref(value),
+            Self::Range(value) => types::AsValueRef::as_value_ref(value),
+            Self::Reference(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = UsageContext;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec!["code", "valueCodeableConcept"];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -0,0 +1,208 @@
+//! Validate
+//!
+//! Check the rules that the StructureDefinitions encode statically: the
+//! cardinality of repeated elements, `maxLength`, `minValue` and `maxValue`,
+//! fixed and pattern values, and required bindings.
+//!
+//! An issue path is an element path with the index of each repeated value,
+//! such as `Patient.name[0].given[1]`. A choice element has the JSON key of
+//! its type, such as `Observation.valueQuantity`.
+
+use ::serde::Serialize;
+use ::serde_json::Value;
+
+/// One rule that a value fails, such as `Patient.name[0].given[1]` with the
+/// message `has 0 values, but allows 1..*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.path, self.message)
+    }
+}
+
+/// Check the rules of a generated type on a value.
+pub trait Validate {
+    /// The element path of the type, such as `Patient`, that starts the path
+    /// of each issue of `validate()`.
+    const PATH: &'static str = "";
+
+    /// Check each rule, and push an issue for each rule that fails, with
+    /// the path of the value, such as `Patient.contact[0]`.
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>);
+
+    /// Check each rule, and list each rule that fails.
+    fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = vec![];
+        self.validate_into(Self::PATH, &mut issues);
+        issues
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    const PATH: &'static str = T::PATH;
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>) {
+        if let Some(value) = self {
+            value.validate_into(path, issues);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    const PATH: &'static str = T::PATH;
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>) {
+        for (index, value) in self.iter().enumerate() {
+            value.validate_into(&format!("{path}[{index}]"), issues);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Box<T> {
+    const PATH: &'static str = T::PATH;
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<ValidationIssue>) {
+        (**self).validate_into(path, issues);
+    }
+}
+
+/// Implement `Validate` for a type without rules, such as the Rust type of
+/// a FHIRPath system type.
+macro_rules! impl_validate_without_rules {
+    ($($t:ty),*) => {
+        $(impl Validate for $t { fn validate_into(& self, _path : & str, _issues : & mut
+        Vec < ValidationIssue >) {} })*
+    };
+}
+
+impl_validate_without_rules!(bool, i32, i64, u32, String, ::serde_json::Number, Value);
+
+/// Check that a repeated element has between min and max values, or at
+/// least min when max is none.
+pub fn check_count(
+    path: &str,
+    count: usize,
+    min: usize,
+    max: Option<usize>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if count < min || max.is_some_and(|max| count > max) {
+        let range = match max {
+            Some(max) => format!("{min}..{max}"),
+            None => format!("{min}..*"),
+        };
+        issues
+            .push(ValidationIssue {
+                path: String::from(path),
+                message: format!("has {count} values, but allows {range}"),
+            });
+    }
+}
+
+/// Check that a string value has at most max characters.
+pub fn check_max_length(
+    path: &str,
+    value: &str,
+    max: usize,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let count = value.chars().count();
+    if count > max {
+        issues
+            .push(ValidationIssue {
+                path: String::from(path),
+                message: format!("has {count} characters, but allows at most {max}"),
+            });
+    }
+}
+
+/// Check that an integer value is between min and max, where each is
+/// inclusive, and none is unbounded.
+pub fn check_range(
+    path: &str,
+    value: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max) {
+        let bound = |x: Option<i64>| x.map(|x| x.to_string()).unwrap_or_default();
+        issues
+            .push(ValidationIssue {
+                path: String::from(path),
+                message: format!("is {value}, but allows {}..{}", bound(min), bound(max)),
+            });
+    }
+}
+
+/// Check that the JSON of a value equals the fixed JSON value.
+pub fn check_fixed<T: Serialize>(
+    path: &str,
+    value: &T,
+    fixed: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let expect: Value = ::serde_json::from_str(fixed).unwrap_or_default();
+    if ::serde_json::to_value(value).ok().as_ref() != Some(&expect) {
+        issues
+            .push(ValidationIssue {
+                path: String::from(path),
+                message: format!("is not the fixed value {fixed}"),
+            });
+    }
+}
+
+/// Check that the JSON of a value has every part of the pattern JSON value.
+pub fn check_pattern<T: Serialize>(
+    path: &str,
+    value: &T,
+    pattern: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let expect: Value = ::serde_json::from_str(pattern).unwrap_or_default();
+    if !::serde_json::to_value(value).is_ok_and(|json| contains(&json, &expect)) {
+        issues
+            .push(ValidationIssue {
+                path: String::from(path),
+                message: format!("does not match the pattern {pattern}"),
+            });
+    }
+}
+
+/// Check that any of the codes of a value, such as the codes of each coding
+/// of a `CodeableConcept`, is a code of the required ValueSet, by the code
+/// enum of the ValueSet. A value without codes passes.
+pub fn check_codes<E: std::str::FromStr>(
+    path: &str,
+    codes: &[&str],
+    value_set: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if !codes.is_empty() && !codes.iter().any(|code| code.parse::<E>().is_ok()) {
+        issues
+            .push(ValidationIssue {
+                path: String::from(path),
+                message: format!("has no code of the required ValueSet {value_set}"),
+            });
+    }
+}
+
+/// Does the value have every part of the pattern? Each key of a pattern
+/// object is in the value, and each item of a pattern array matches an item
+/// of the value.
+fn contains(value: &Value, pattern: &Value) -> bool {
+    match (value, pattern) {
+        (Value::Object(value), Value::Object(pattern)) => {
+            pattern.iter().all(|(key, x)| value.get(key).is_some_and(|y| contains(y, x)))
+        }
+        (Value::Array(value), Value::Array(pattern)) => {
+            pattern.iter().all(|x| value.iter().any(|y| contains(y, x)))
+        }
+        (value, pattern) => value == pattern,
+    }
+}
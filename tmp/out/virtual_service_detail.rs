@@ -1,6 +1,6 @@
 //! VirtualServiceDetail
 //!
-//! URL: http://hl7.org/fhir/StructureDefinition/VirtualServiceDetail
+//! URL: <http://hl7.org/fhir/StructureDefinition/VirtualServiceDetail>
 //!
 //! Version: 5.0.0
 //!
@@ -10,72 +10,722 @@
 //!
 //! UML: <https://build.fhir.org/uml.html>
 
-// Allow unused crate::r5::types as types;
 #![allow(unused_imports)]
 
+/// Use the FHIR R5 datatypes for the attributes.
 use crate::r5::types;
+
+/// Use serde to serialize Rust into JSON and deserialize JSON to Rust.
 use ::serde::{Deserialize, Serialize};
 
+/// Virtual Service Contact Details
+///
+/// Virtual Service Contact Details.
+///
+/// Cardinality: 0..*
+///
+/// Skip serializing each attributes that is an option and set to none.
 #[serde_with::skip_serializing_none]
+/// Derive all our typical things for programming, serde, comparing, etc.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Rename all the snake case Rust attributes into camel case JSON keys.
 #[serde(rename_all = "camelCase")]
 pub struct VirtualServiceDetail {
-    /// Virtual Service Contact Details
-    VirtualServiceDetail: ? // ?
-
     /// Unique id for inter-element referencing
-    id: ? // http://hl7.org/fhirpath/System.String
+    ///
+    /// Unique id for the element within a resource (for internal references). This may be any string value that does not contain spaces.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `String`
+    pub id: Option<String>,
 
     /// Additional content defined by implementations
-    extension: ? // Extension
+    ///
+    /// May be used to represent additional information that is not part of the basic definition of the element. To make the use of extensions safe and managable, there is a strict set of governance applied to the definition and use of extensions. Though any implementer can define an extension, there is a set of requirements that SHALL be met as part of the definition of the extension.
+    ///
+    /// Comment: There can be no stigma associated with the use of extensions by any application, project, or standard - regardless of the institution or jurisdiction that uses or defines the extensions.  The use of extensions is what allows the FHIR specification to retain a core level of simplicity for everyone.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `Extension`
+    ///
+    /// Constraints:
+    ///
+    /// - `ext-1`: Must have either extensions or value\[x\], not both
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extension: Vec<types::Extension>,
 
     /// Channel Type
-    channelType: ? // Coding
+    ///
+    /// The type of virtual service to connect to (i.e. Teams, Zoom, Specific VMR technology, WhatsApp).
+    ///
+    /// Comment: As this type defines what the virtual service is, it then defines what address and additional information might be relevant.
+    ///
+    /// Some profiles could be used for specific types to define what the other values could/should be.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `Coding`
+    ///
+    /// Binding: example to [`http://hl7.org/fhir/ValueSet/virtual-service-type`](http://hl7.org/fhir/ValueSet/virtual-service-type)
+    ///
+    /// The purpose for which an extended contact detail should be used.
+    pub channel_type: Option<types::Coding>,
 
     /// Contact address/number
-    : ? // url
+    ///
+    /// What address or number needs to be used for a user to connect to the virtual service to join. The channelType informs as to which datatype is appropriate to use (requires knowledge of the specific type).
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `url`, `string`, `ContactPoint`, `ExtendedContactDetail`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub address: Option<VirtualServiceDetailAddress>,
+
+    /// Id and extensions of `address[x]`
+    #[serde(flatten, deserialize_with = "types::Choice::deserialize_option")]
+    pub address_element: Option<VirtualServiceDetailAddressElement>,
 
     /// Address to see alternative connection details
-    additionalInfo: ? // url
+    ///
+    /// Address to see alternative connection details.
+    ///
+    /// Comment: This web address can be used to provide additional details on the call, such as alternative/regional call in numbers, or other associated services.
+    ///
+    /// Cardinality: 0..*
+    ///
+    /// Types: `url`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_info: Vec<Option<types::Url>>,
+
+    /// Id and extensions of `additionalInfo`
+    #[serde(rename = "_additionalInfo", default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_info_element: Vec<Option<types::Element>>,
 
     /// Maximum number of participants supported by the virtual service
-    maxParticipants: ? // positiveInt
+    ///
+    /// Maximum number of participants supported by the virtual service.
+    ///
+    /// Comment: Some services have only a limitted number of participants permitted to connect at a time, this is typically used in "shared" virtual services that you might find on location resources.
+    ///
+    /// Typically on-demand services might not have these restrictions.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `positiveInt`
+    pub max_participants: Option<types::PositiveInt>,
+
+    /// Id and extensions of `maxParticipants`
+    #[serde(rename = "_maxParticipants")]
+    pub max_participants_element: Option<types::Element>,
 
     /// Session Key required by the virtual service
-    sessionKey: ? // string
+    ///
+    /// Session Key required by the virtual service.
+    ///
+    /// Comment: Some services require a session key to be able to access the service once connected.
+    ///
+    /// This could be in cases where a shared number is used, and a session key is added to put into a private line, or to identify the participant in a call.
+    ///
+    /// Cardinality: 0..1
+    ///
+    /// Types: `string`
+    pub session_key: Option<types::String>,
+
+    /// Id and extensions of `sessionKey`
+    #[serde(rename = "_sessionKey")]
+    pub session_key_element: Option<types::Element>,
+
+    /// Reject unknown fields, which serde can not deny in a struct that
+    /// flattens a choice.
+    #[serde(flatten)]
+    pub unknown_fields: types::DenyUnknownFields,
+}
+
+impl VirtualServiceDetail {
+    /// Create with every attribute set to its default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl VirtualServiceDetail {
+    /// Start a builder, with every attribute unset.
+    pub fn builder() -> VirtualServiceDetailBuilder {
+        VirtualServiceDetailBuilder::default()
+    }
+}
+
+/// Build one `VirtualServiceDetail`, one attribute at a time.
+#[derive(Debug, Default, Clone)]
+pub struct VirtualServiceDetailBuilder {
+    inner: VirtualServiceDetail,
+}
+
+impl VirtualServiceDetailBuilder {
+    /// Set `VirtualServiceDetail.id`.
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.inner.id = Some(value.into());
+        self
+    }
+
+    /// Set every `VirtualServiceDetail.extension`.
+    pub fn extension(mut self, value: impl Into<Vec<types::Extension>>) -> Self {
+        self.inner.extension = value.into();
+        self
+    }
+
+    /// Add one `VirtualServiceDetail.extension`.
+    pub fn push_extension(mut self, value: impl Into<types::Extension>) -> Self {
+        self.inner.extension.push(value.into());
+        self
+    }
+
+    /// Set `VirtualServiceDetail.channelType`.
+    pub fn channel_type(mut self, value: impl Into<types::Coding>) -> Self {
+        self.inner.channel_type = Some(value.into());
+        self
+    }
+
+    /// Set `VirtualServiceDetail.address[x]` to a `url`.
+    pub fn address_url(mut self, value: impl Into<types::Url>) -> Self {
+        self.inner.address = Some(VirtualServiceDetailAddress::Url(value.into()));
+        self
+    }
+
+    /// Set `VirtualServiceDetail.address[x]` to a `string`.
+    pub fn address_string(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.address = Some(VirtualServiceDetailAddress::String(value.into()));
+        self
+    }
+
+    /// Set `VirtualServiceDetail.address[x]` to a `ContactPoint`.
+    pub fn address_contact_point(
+        mut self,
+        value: impl Into<types::ContactPoint>,
+    ) -> Self {
+        self.inner.address = Some(
+            VirtualServiceDetailAddress::ContactPoint(value.into()),
+        );
+        self
+    }
+
+    /// Set `VirtualServiceDetail.address[x]` to a `ExtendedContactDetail`.
+    pub fn address_extended_contact_detail(
+        mut self,
+        value: impl Into<types::ExtendedContactDetail>,
+    ) -> Self {
+        self.inner.address = Some(
+            VirtualServiceDetailAddress::ExtendedContactDetail(value.into()),
+        );
+        self
+    }
+
+    /// Set every `VirtualServiceDetail.additionalInfo`.
+    pub fn additional_info(
+        mut self,
+        value: impl IntoIterator<Item = impl Into<types::Url>>,
+    ) -> Self {
+        self.inner.additional_info = value.into_iter().map(|x| Some(x.into())).collect();
+        self
+    }
+
+    /// Add one `VirtualServiceDetail.additionalInfo`.
+    pub fn push_additional_info(mut self, value: impl Into<types::Url>) -> Self {
+        self.inner.additional_info.push(Some(value.into()));
+        self
+    }
+
+    /// Set `VirtualServiceDetail.maxParticipants`.
+    pub fn max_participants(mut self, value: impl Into<types::PositiveInt>) -> Self {
+        self.inner.max_participants = Some(value.into());
+        self
+    }
+
+    /// Set `VirtualServiceDetail.sessionKey`.
+    pub fn session_key(mut self, value: impl Into<types::String>) -> Self {
+        self.inner.session_key = Some(value.into());
+        self
+    }
+
+    /// Build, or list the path of each required element that is not set.
+    pub fn build(self) -> Result<VirtualServiceDetail, types::MissingElements> {
+        Ok(self.inner)
+    }
+}
+
+impl types::Validate for VirtualServiceDetail {
+    const PATH: &'static str = "VirtualServiceDetail";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        types::Validate::validate_into(&self.id, &format!("{path}.id"), issues);
+        types::Validate::validate_into(
+            &self.extension,
+            &format!("{path}.extension"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.channel_type,
+            &format!("{path}.channelType"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.address,
+            &format!("{path}.address"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.address_element,
+            &format!("{path}._address"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.additional_info,
+            &format!("{path}.additionalInfo"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.additional_info_element,
+            &format!("{path}._additionalInfo"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_participants,
+            &format!("{path}.maxParticipants"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.max_participants_element,
+            &format!("{path}._maxParticipants"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.session_key,
+            &format!("{path}.sessionKey"),
+            issues,
+        );
+        types::Validate::validate_into(
+            &self.session_key_element,
+            &format!("{path}._sessionKey"),
+            issues,
+        );
+    }
+}
+
+impl types::Walk for VirtualServiceDetail {
+    fn accept<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        visitor.visit_virtual_service_detail(path, self);
+    }
+
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        types::Walk::accept(&self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept(&self.extension, &format!("{path}.extension"), visitor);
+        types::Walk::accept(&self.channel_type, &format!("{path}.channelType"), visitor);
+        types::Walk::accept(&self.address, &format!("{path}.address"), visitor);
+        types::Walk::accept(&self.address_element, &format!("{path}._address"), visitor);
+        types::Walk::accept(
+            &self.additional_info,
+            &format!("{path}.additionalInfo"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.additional_info_element,
+            &format!("{path}._additionalInfo"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.max_participants,
+            &format!("{path}.maxParticipants"),
+            visitor,
+        );
+        types::Walk::accept(
+            &self.max_participants_element,
+            &format!("{path}._maxParticipants"),
+            visitor,
+        );
+        types::Walk::accept(&self.session_key, &format!("{path}.sessionKey"), visitor);
+        types::Walk::accept(
+            &self.session_key_element,
+            &format!("{path}._sessionKey"),
+            visitor,
+        );
+    }
+
+    fn accept_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        visitor.visit_virtual_service_detail_mut(path, self);
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        types::Walk::accept_mut(&mut self.id, &format!("{path}.id"), visitor);
+        types::Walk::accept_mut(
+            &mut self.extension,
+            &format!("{path}.extension"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.channel_type,
+            &format!("{path}.channelType"),
+            visitor,
+        );
+        types::Walk::accept_mut(&mut self.address, &format!("{path}.address"), visitor);
+        types::Walk::accept_mut(
+            &mut self.address_element,
+            &format!("{path}._address"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.additional_info,
+            &format!("{path}.additionalInfo"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.additional_info_element,
+            &format!("{path}._additionalInfo"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_participants,
+            &format!("{path}.maxParticipants"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.max_participants_element,
+            &format!("{path}._maxParticipants"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.session_key,
+            &format!("{path}.sessionKey"),
+            visitor,
+        );
+        types::Walk::accept_mut(
+            &mut self.session_key_element,
+            &format!("{path}._sessionKey"),
+            visitor,
+        );
+    }
+}
+
+impl VirtualServiceDetail {
+    /// The metadata of each child element of `VirtualServiceDetail`.
+    pub const ELEMENTS: &'static [types::ElementInfo] = &[
+        types::ElementInfo {
+            name: "id",
+            json_key: "id",
+            rust_field: "id",
+            min: 0,
+            max: Some(1),
+            types: &["http://hl7.org/fhirpath/System.String"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "extension",
+            json_key: "extension",
+            rust_field: "extension",
+            min: 0,
+            max: None,
+            types: &["Extension"],
+            binding: None,
+            is_summary: false,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "channelType",
+            json_key: "channelType",
+            rust_field: "channel_type",
+            min: 0,
+            max: Some(1),
+            types: &["Coding"],
+            binding: Some(types::BindingInfo {
+                strength: "example",
+                value_set: Some("http://hl7.org/fhir/ValueSet/virtual-service-type"),
+            }),
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "address[x]",
+            json_key: "address",
+            rust_field: "address",
+            min: 0,
+            max: Some(1),
+            types: &["url", "string", "ContactPoint", "ExtendedContactDetail"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "additionalInfo",
+            json_key: "additionalInfo",
+            rust_field: "additional_info",
+            min: 0,
+            max: None,
+            types: &["url"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "maxParticipants",
+            json_key: "maxParticipants",
+            rust_field: "max_participants",
+            min: 0,
+            max: Some(1),
+            types: &["positiveInt"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+        types::ElementInfo {
+            name: "sessionKey",
+            json_key: "sessionKey",
+            rust_field: "session_key",
+            min: 0,
+            max: Some(1),
+            types: &["string"],
+            binding: None,
+            is_summary: true,
+            is_modifier: false,
+        },
+    ];
+}
 
+impl types::Reflect for VirtualServiceDetail {
+    fn type_name(&self) -> &'static str {
+        "VirtualServiceDetail"
+    }
+
+    fn element_infos(&self) -> &'static [types::ElementInfo] {
+        Self::ELEMENTS
+    }
+
+    fn child(&self, name: &str) -> Option<types::ValueRef<'_>> {
+        match name {
+            "id" => types::AsValueRef::as_value_ref(&self.id),
+            "extension" => types::AsValueRef::as_value_ref(&self.extension),
+            "channelType" => types::AsValueRef::as_value_ref(&self.channel_type),
+            "address[x]" | "address" => types::AsValueRef::as_value_ref(&self.address),
+            "additionalInfo" => types::AsValueRef::as_value_ref(&self.additional_info),
+            "maxParticipants" => types::AsValueRef::as_value_ref(&self.max_participants),
+            "sessionKey" => types::AsValueRef::as_value_ref(&self.session_key),
+            _ => None,
+        }
+    }
+}
+
+impl types::AsValueRef for VirtualServiceDetail {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        Some(types::ValueRef::Element(self))
+    }
+}
+
+impl types::HasExtensions for VirtualServiceDetail {
+    fn extensions(&self) -> &[types::Extension] {
+        &self.extension
+    }
+
+    fn extensions_mut(&mut self) -> &mut Vec<types::Extension> {
+        &mut self.extension
+    }
+}
+
+/// Choice of types for `VirtualServiceDetail.address[x]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VirtualServiceDetailAddress {
+    #[serde(rename = "addressUrl")]
+    Url(types::Url),
+    #[serde(rename = "addressString")]
+    String(types::String),
+    #[serde(rename = "addressContactPoint")]
+    ContactPoint(types::ContactPoint),
+    #[serde(rename = "addressExtendedContactDetail")]
+    ExtendedContactDetail(types::ExtendedContactDetail),
+}
+
+impl Default for VirtualServiceDetailAddress {
+    fn default() -> Self {
+        Self::Url(Default::default())
+    }
+}
+
+impl types::Choice for VirtualServiceDetailAddress {
+    const KEYS: &'static [&'static str] = &[
+        "addressUrl",
+        "addressString",
+        "addressContactPoint",
+        "addressExtendedContactDetail",
+    ];
+}
+
+impl types::Validate for VirtualServiceDetailAddress {
+    const PATH: &'static str = "VirtualServiceDetail.address";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+            Self::ContactPoint(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ContactPoint"),
+                    issues,
+                )
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Validate::validate_into(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    issues,
+                )
+            }
+        }
+    }
+}
+
+impl types::Walk for VirtualServiceDetailAddress {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+            Self::ContactPoint(value) => {
+                types::Walk::accept_mut(value, &format!("{path}ContactPoint"), visitor)
+            }
+            Self::ExtendedContactDetail(value) => {
+                types::Walk::accept_mut(
+                    value,
+                    &format!("{path}ExtendedContactDetail"),
+                    visitor,
+                )
+            }
+        }
+    }
+}
+
+impl types::AsValueRef for VirtualServiceDetailAddress {
+    fn as_value_ref(&self) -> Option<types::ValueRef<'_>> {
+        match self {
+            Self::Url(value) => types::AsValueRef::as_value_ref(value),
+            Self::String(value) => types::AsValueRef::as_value_ref(value),
+            Self::ContactPoint(value) => types::AsValueRef::as_value_ref(value),
+            Self::ExtendedContactDetail(value) => types::AsValueRef::as_value_ref(value),
+        }
+    }
+}
+
+/// Id and extensions of `VirtualServiceDetail.address[x]`, when its type is a primitive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VirtualServiceDetailAddressElement {
+    #[serde(rename = "_addressUrl")]
+    Url(types::Element),
+    #[serde(rename = "_addressString")]
+    String(types::Element),
+}
+
+impl types::Choice for VirtualServiceDetailAddressElement {
+    const KEYS: &'static [&'static str] = &["_addressUrl", "_addressString"];
+}
+
+impl types::Validate for VirtualServiceDetailAddressElement {
+    const PATH: &'static str = "VirtualServiceDetail.address";
+
+    fn validate_into(&self, path: &str, issues: &mut Vec<types::ValidationIssue>) {
+        match self {
+            Self::Url(value) => {
+                types::Validate::validate_into(value, &format!("{path}Url"), issues)
+            }
+            Self::String(value) => {
+                types::Validate::validate_into(value, &format!("{path}String"), issues)
+            }
+        }
+    }
+}
+
+impl types::Walk for VirtualServiceDetailAddressElement {
+    fn walk<V: types::Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Url(value) => {
+                types::Walk::accept(value, &format!("{path}Url"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
+
+    fn walk_mut<V: types::VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        match self {
+            Self::Url(value) => {
+                types::Walk::accept_mut(value, &format!("{path}Url"), visitor)
+            }
+            Self::String(value) => {
+                types::Walk::accept_mut(value, &format!("{path}String"), visitor)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
     type T = VirtualServiceDetail;
 
     #[test]
-    fn test_default() {
-        let actual = T::default();
-        let expect = T {};
-        assert_eq!(actual, expect);
+    fn test_default_into_json() {
+        let actual = ::serde_json::to_value(T::default()).expect("to_value");
+        let mut keys: Vec<&str> = actual
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let expect: Vec<&str> = vec![];
+        assert_eq!(keys, expect);
     }
 
     mod serde_json {
         use super::*;
-        use ::serde_json::json;
 
         #[test]
-        fn test_serde_json_from_value() {
-            let json = json!({});
+        fn test_serde_json_round_trip() {
+            let json: ::serde_json::Value = ::serde_json::to_value(T::default())
+                .expect("to_value");
             let actual: T = ::serde_json::from_value(json).expect("from_value");
             let expect: T = T::default();
             assert_eq!(actual, expect);
         }
-
-        #[test]
-        fn test_serde_json_to_value() {
-            let actual: ::serde_json::Value =
-                ::serde_json::to_value(T::default()).expect("to_value");
-            let expect: ::serde_json::Value = json!({});
-            assert_eq!(actual, expect);
-        }
     }
 }
@@ -0,0 +1,929 @@
+//! Visit
+//!
+//! Visit every value of a type inside a value, such as every `Reference`
+//! of a resource, with the FHIR element path of each value, such as
+//! `Patient.generalPractitioner[0]`.
+//!
+//! A visitor implements the visit method of each type that it needs,
+//! and calls `types::Walk::walk` in it to continue into the value.
+
+/// Use the FHIR R5 datatypes and resources for the visit methods.
+use crate::r5::types;
+
+/// Visit each value of a type, by reference.
+pub trait Visit {
+    /// Visit one `Address`, and by default walk it.
+    fn visit_address(&mut self, path: &str, value: &types::Address) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Age`, and by default walk it.
+    fn visit_age(&mut self, path: &str, value: &types::Age) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Annotation`, and by default walk it.
+    fn visit_annotation(&mut self, path: &str, value: &types::Annotation) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Attachment`, and by default walk it.
+    fn visit_attachment(&mut self, path: &str, value: &types::Attachment) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Availability`, and by default walk it.
+    fn visit_availability(&mut self, path: &str, value: &types::Availability) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `BackboneElement`, and by default walk it.
+    fn visit_backbone_element(&mut self, path: &str, value: &types::BackboneElement) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `BackboneType`, and by default walk it.
+    fn visit_backbone_type(&mut self, path: &str, value: &types::BackboneType) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Base`, and by default walk it.
+    fn visit_base(&mut self, path: &str, value: &types::Base) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Base64Binary`, and by default walk it.
+    fn visit_base_64_binary(&mut self, path: &str, value: &types::Base64Binary) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Boolean`, and by default walk it.
+    fn visit_boolean(&mut self, path: &str, value: &types::Boolean) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Canonical`, and by default walk it.
+    fn visit_canonical(&mut self, path: &str, value: &types::Canonical) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Code`, and by default walk it.
+    fn visit_code(&mut self, path: &str, value: &types::Code) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `CodeableConcept`, and by default walk it.
+    fn visit_codeable_concept(&mut self, path: &str, value: &types::CodeableConcept) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `CodeableReference`, and by default walk it.
+    fn visit_codeable_reference(
+        &mut self,
+        path: &str,
+        value: &types::CodeableReference,
+    ) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Coding`, and by default walk it.
+    fn visit_coding(&mut self, path: &str, value: &types::Coding) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `ContactDetail`, and by default walk it.
+    fn visit_contact_detail(&mut self, path: &str, value: &types::ContactDetail) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `ContactPoint`, and by default walk it.
+    fn visit_contact_point(&mut self, path: &str, value: &types::ContactPoint) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Contributor`, and by default walk it.
+    fn visit_contributor(&mut self, path: &str, value: &types::Contributor) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Count`, and by default walk it.
+    fn visit_count(&mut self, path: &str, value: &types::Count) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `DataRequirement`, and by default walk it.
+    fn visit_data_requirement(&mut self, path: &str, value: &types::DataRequirement) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `DataType`, and by default walk it.
+    fn visit_data_type(&mut self, path: &str, value: &types::DataType) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Date`, and by default walk it.
+    fn visit_date(&mut self, path: &str, value: &types::Date) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `DateTime`, and by default walk it.
+    fn visit_date_time(&mut self, path: &str, value: &types::DateTime) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Decimal`, and by default walk it.
+    fn visit_decimal(&mut self, path: &str, value: &types::Decimal) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Distance`, and by default walk it.
+    fn visit_distance(&mut self, path: &str, value: &types::Distance) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Dosage`, and by default walk it.
+    fn visit_dosage(&mut self, path: &str, value: &types::Dosage) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Duration`, and by default walk it.
+    fn visit_duration(&mut self, path: &str, value: &types::Duration) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Element`, and by default walk it.
+    fn visit_element(&mut self, path: &str, value: &types::Element) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `ElementDefinition`, and by default walk it.
+    fn visit_element_definition(
+        &mut self,
+        path: &str,
+        value: &types::ElementDefinition,
+    ) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Expression`, and by default walk it.
+    fn visit_expression(&mut self, path: &str, value: &types::Expression) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `ExtendedContactDetail`, and by default walk it.
+    fn visit_extended_contact_detail(
+        &mut self,
+        path: &str,
+        value: &types::ExtendedContactDetail,
+    ) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Extension`, and by default walk it.
+    fn visit_extension(&mut self, path: &str, value: &types::Extension) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `HumanName`, and by default walk it.
+    fn visit_human_name(&mut self, path: &str, value: &types::HumanName) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Id`, and by default walk it.
+    fn visit_id(&mut self, path: &str, value: &types::Id) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Identifier`, and by default walk it.
+    fn visit_identifier(&mut self, path: &str, value: &types::Identifier) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Instant`, and by default walk it.
+    fn visit_instant(&mut self, path: &str, value: &types::Instant) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Integer`, and by default walk it.
+    fn visit_integer(&mut self, path: &str, value: &types::Integer) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Integer64`, and by default walk it.
+    fn visit_integer_64(&mut self, path: &str, value: &types::Integer64) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Markdown`, and by default walk it.
+    fn visit_markdown(&mut self, path: &str, value: &types::Markdown) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `MarketingStatus`, and by default walk it.
+    fn visit_marketing_status(&mut self, path: &str, value: &types::MarketingStatus) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Meta`, and by default walk it.
+    fn visit_meta(&mut self, path: &str, value: &types::Meta) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `MonetaryComponent`, and by default walk it.
+    fn visit_monetary_component(
+        &mut self,
+        path: &str,
+        value: &types::MonetaryComponent,
+    ) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Money`, and by default walk it.
+    fn visit_money(&mut self, path: &str, value: &types::Money) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `MoneyQuantity`, and by default walk it.
+    fn visit_money_quantity(&mut self, path: &str, value: &types::MoneyQuantity) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Narrative`, and by default walk it.
+    fn visit_narrative(&mut self, path: &str, value: &types::Narrative) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Oid`, and by default walk it.
+    fn visit_oid(&mut self, path: &str, value: &types::Oid) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `ParameterDefinition`, and by default walk it.
+    fn visit_parameter_definition(
+        &mut self,
+        path: &str,
+        value: &types::ParameterDefinition,
+    ) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Period`, and by default walk it.
+    fn visit_period(&mut self, path: &str, value: &types::Period) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `PositiveInt`, and by default walk it.
+    fn visit_positive_int(&mut self, path: &str, value: &types::PositiveInt) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `PrimitiveType`, and by default walk it.
+    fn visit_primitive_type(&mut self, path: &str, value: &types::PrimitiveType) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `ProductShelfLife`, and by default walk it.
+    fn visit_product_shelf_life(&mut self, path: &str, value: &types::ProductShelfLife) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Quantity`, and by default walk it.
+    fn visit_quantity(&mut self, path: &str, value: &types::Quantity) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Range`, and by default walk it.
+    fn visit_range(&mut self, path: &str, value: &types::Range) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Ratio`, and by default walk it.
+    fn visit_ratio(&mut self, path: &str, value: &types::Ratio) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `RatioRange`, and by default walk it.
+    fn visit_ratio_range(&mut self, path: &str, value: &types::RatioRange) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Reference`, and by default walk it.
+    fn visit_reference(&mut self, path: &str, value: &types::Reference) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `RelatedArtifact`, and by default walk it.
+    fn visit_related_artifact(&mut self, path: &str, value: &types::RelatedArtifact) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `SampledData`, and by default walk it.
+    fn visit_sampled_data(&mut self, path: &str, value: &types::SampledData) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Signature`, and by default walk it.
+    fn visit_signature(&mut self, path: &str, value: &types::Signature) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `SimpleQuantity`, and by default walk it.
+    fn visit_simple_quantity(&mut self, path: &str, value: &types::SimpleQuantity) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `String`, and by default walk it.
+    fn visit_string(&mut self, path: &str, value: &types::String) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Time`, and by default walk it.
+    fn visit_time(&mut self, path: &str, value: &types::Time) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Timing`, and by default walk it.
+    fn visit_timing(&mut self, path: &str, value: &types::Timing) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `TriggerDefinition`, and by default walk it.
+    fn visit_trigger_definition(
+        &mut self,
+        path: &str,
+        value: &types::TriggerDefinition,
+    ) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `UnsignedInt`, and by default walk it.
+    fn visit_unsigned_int(&mut self, path: &str, value: &types::UnsignedInt) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Uri`, and by default walk it.
+    fn visit_uri(&mut self, path: &str, value: &types::Uri) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Url`, and by default walk it.
+    fn visit_url(&mut self, path: &str, value: &types::Url) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `UsageContext`, and by default walk it.
+    fn visit_usage_context(&mut self, path: &str, value: &types::UsageContext) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Uuid`, and by default walk it.
+    fn visit_uuid(&mut self, path: &str, value: &types::Uuid) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `VirtualServiceDetail`, and by default walk it.
+    fn visit_virtual_service_detail(
+        &mut self,
+        path: &str,
+        value: &types::VirtualServiceDetail,
+    ) {
+        types::Walk::walk(value, path, self);
+    }
+
+    /// Visit one `Xhtml`, and by default walk it.
+    fn visit_xhtml(&mut self, path: &str, value: &types::Xhtml) {
+        types::Walk::walk(value, path, self);
+    }
+}
+
+/// Visit each value of a type, by mutable reference.
+pub trait VisitMut {
+    /// Visit one `Address`, and by default walk it.
+    fn visit_address_mut(&mut self, path: &str, value: &mut types::Address) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Age`, and by default walk it.
+    fn visit_age_mut(&mut self, path: &str, value: &mut types::Age) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Annotation`, and by default walk it.
+    fn visit_annotation_mut(&mut self, path: &str, value: &mut types::Annotation) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Attachment`, and by default walk it.
+    fn visit_attachment_mut(&mut self, path: &str, value: &mut types::Attachment) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Availability`, and by default walk it.
+    fn visit_availability_mut(&mut self, path: &str, value: &mut types::Availability) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `BackboneElement`, and by default walk it.
+    fn visit_backbone_element_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::BackboneElement,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `BackboneType`, and by default walk it.
+    fn visit_backbone_type_mut(&mut self, path: &str, value: &mut types::BackboneType) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Base`, and by default walk it.
+    fn visit_base_mut(&mut self, path: &str, value: &mut types::Base) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Base64Binary`, and by default walk it.
+    fn visit_base_64_binary_mut(&mut self, path: &str, value: &mut types::Base64Binary) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Boolean`, and by default walk it.
+    fn visit_boolean_mut(&mut self, path: &str, value: &mut types::Boolean) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Canonical`, and by default walk it.
+    fn visit_canonical_mut(&mut self, path: &str, value: &mut types::Canonical) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Code`, and by default walk it.
+    fn visit_code_mut(&mut self, path: &str, value: &mut types::Code) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `CodeableConcept`, and by default walk it.
+    fn visit_codeable_concept_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::CodeableConcept,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `CodeableReference`, and by default walk it.
+    fn visit_codeable_reference_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::CodeableReference,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Coding`, and by default walk it.
+    fn visit_coding_mut(&mut self, path: &str, value: &mut types::Coding) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `ContactDetail`, and by default walk it.
+    fn visit_contact_detail_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::ContactDetail,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `ContactPoint`, and by default walk it.
+    fn visit_contact_point_mut(&mut self, path: &str, value: &mut types::ContactPoint) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Contributor`, and by default walk it.
+    fn visit_contributor_mut(&mut self, path: &str, value: &mut types::Contributor) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Count`, and by default walk it.
+    fn visit_count_mut(&mut self, path: &str, value: &mut types::Count) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `DataRequirement`, and by default walk it.
+    fn visit_data_requirement_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::DataRequirement,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `DataType`, and by default walk it.
+    fn visit_data_type_mut(&mut self, path: &str, value: &mut types::DataType) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Date`, and by default walk it.
+    fn visit_date_mut(&mut self, path: &str, value: &mut types::Date) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `DateTime`, and by default walk it.
+    fn visit_date_time_mut(&mut self, path: &str, value: &mut types::DateTime) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Decimal`, and by default walk it.
+    fn visit_decimal_mut(&mut self, path: &str, value: &mut types::Decimal) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Distance`, and by default walk it.
+    fn visit_distance_mut(&mut self, path: &str, value: &mut types::Distance) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Dosage`, and by default walk it.
+    fn visit_dosage_mut(&mut self, path: &str, value: &mut types::Dosage) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Duration`, and by default walk it.
+    fn visit_duration_mut(&mut self, path: &str, value: &mut types::Duration) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Element`, and by default walk it.
+    fn visit_element_mut(&mut self, path: &str, value: &mut types::Element) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `ElementDefinition`, and by default walk it.
+    fn visit_element_definition_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::ElementDefinition,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Expression`, and by default walk it.
+    fn visit_expression_mut(&mut self, path: &str, value: &mut types::Expression) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `ExtendedContactDetail`, and by default walk it.
+    fn visit_extended_contact_detail_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::ExtendedContactDetail,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Extension`, and by default walk it.
+    fn visit_extension_mut(&mut self, path: &str, value: &mut types::Extension) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `HumanName`, and by default walk it.
+    fn visit_human_name_mut(&mut self, path: &str, value: &mut types::HumanName) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Id`, and by default walk it.
+    fn visit_id_mut(&mut self, path: &str, value: &mut types::Id) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Identifier`, and by default walk it.
+    fn visit_identifier_mut(&mut self, path: &str, value: &mut types::Identifier) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Instant`, and by default walk it.
+    fn visit_instant_mut(&mut self, path: &str, value: &mut types::Instant) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Integer`, and by default walk it.
+    fn visit_integer_mut(&mut self, path: &str, value: &mut types::Integer) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Integer64`, and by default walk it.
+    fn visit_integer_64_mut(&mut self, path: &str, value: &mut types::Integer64) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Markdown`, and by default walk it.
+    fn visit_markdown_mut(&mut self, path: &str, value: &mut types::Markdown) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `MarketingStatus`, and by default walk it.
+    fn visit_marketing_status_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::MarketingStatus,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Meta`, and by default walk it.
+    fn visit_meta_mut(&mut self, path: &str, value: &mut types::Meta) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `MonetaryComponent`, and by default walk it.
+    fn visit_monetary_component_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::MonetaryComponent,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Money`, and by default walk it.
+    fn visit_money_mut(&mut self, path: &str, value: &mut types::Money) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `MoneyQuantity`, and by default walk it.
+    fn visit_money_quantity_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::MoneyQuantity,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Narrative`, and by default walk it.
+    fn visit_narrative_mut(&mut self, path: &str, value: &mut types::Narrative) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Oid`, and by default walk it.
+    fn visit_oid_mut(&mut self, path: &str, value: &mut types::Oid) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `ParameterDefinition`, and by default walk it.
+    fn visit_parameter_definition_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::ParameterDefinition,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Period`, and by default walk it.
+    fn visit_period_mut(&mut self, path: &str, value: &mut types::Period) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `PositiveInt`, and by default walk it.
+    fn visit_positive_int_mut(&mut self, path: &str, value: &mut types::PositiveInt) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `PrimitiveType`, and by default walk it.
+    fn visit_primitive_type_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::PrimitiveType,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `ProductShelfLife`, and by default walk it.
+    fn visit_product_shelf_life_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::ProductShelfLife,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Quantity`, and by default walk it.
+    fn visit_quantity_mut(&mut self, path: &str, value: &mut types::Quantity) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Range`, and by default walk it.
+    fn visit_range_mut(&mut self, path: &str, value: &mut types::Range) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Ratio`, and by default walk it.
+    fn visit_ratio_mut(&mut self, path: &str, value: &mut types::Ratio) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `RatioRange`, and by default walk it.
+    fn visit_ratio_range_mut(&mut self, path: &str, value: &mut types::RatioRange) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Reference`, and by default walk it.
+    fn visit_reference_mut(&mut self, path: &str, value: &mut types::Reference) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `RelatedArtifact`, and by default walk it.
+    fn visit_related_artifact_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::RelatedArtifact,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `SampledData`, and by default walk it.
+    fn visit_sampled_data_mut(&mut self, path: &str, value: &mut types::SampledData) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Signature`, and by default walk it.
+    fn visit_signature_mut(&mut self, path: &str, value: &mut types::Signature) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `SimpleQuantity`, and by default walk it.
+    fn visit_simple_quantity_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::SimpleQuantity,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `String`, and by default walk it.
+    fn visit_string_mut(&mut self, path: &str, value: &mut types::String) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Time`, and by default walk it.
+    fn visit_time_mut(&mut self, path: &str, value: &mut types::Time) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Timing`, and by default walk it.
+    fn visit_timing_mut(&mut self, path: &str, value: &mut types::Timing) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `TriggerDefinition`, and by default walk it.
+    fn visit_trigger_definition_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::TriggerDefinition,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `UnsignedInt`, and by default walk it.
+    fn visit_unsigned_int_mut(&mut self, path: &str, value: &mut types::UnsignedInt) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Uri`, and by default walk it.
+    fn visit_uri_mut(&mut self, path: &str, value: &mut types::Uri) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Url`, and by default walk it.
+    fn visit_url_mut(&mut self, path: &str, value: &mut types::Url) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `UsageContext`, and by default walk it.
+    fn visit_usage_context_mut(&mut self, path: &str, value: &mut types::UsageContext) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Uuid`, and by default walk it.
+    fn visit_uuid_mut(&mut self, path: &str, value: &mut types::Uuid) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `VirtualServiceDetail`, and by default walk it.
+    fn visit_virtual_service_detail_mut(
+        &mut self,
+        path: &str,
+        value: &mut types::VirtualServiceDetail,
+    ) {
+        types::Walk::walk_mut(value, path, self);
+    }
+
+    /// Visit one `Xhtml`, and by default walk it.
+    fn visit_xhtml_mut(&mut self, path: &str, value: &mut types::Xhtml) {
+        types::Walk::walk_mut(value, path, self);
+    }
+}
+
+/// Walk a value: accept the value of each of its fields, with its path.
+pub trait Walk {
+    /// Accept the value: call the visit method of its type, if any, else
+    /// walk it.
+    fn accept<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        self.walk(path, visitor);
+    }
+
+    /// Accept the value of each field, such as `Patient.name` with the
+    /// path of the value, such as `Patient.name[0]`.
+    fn walk<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V);
+
+    /// Accept the value mutably: call the mutable visit method of its
+    /// type, if any, else walk it mutably.
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        self.walk_mut(path, visitor);
+    }
+
+    /// Accept the value of each field mutably.
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V);
+}
+
+impl<T: Walk> Walk for Option<T> {
+    fn accept<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.accept(path, visitor);
+        }
+    }
+
+    fn walk<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.walk(path, visitor);
+        }
+    }
+
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.accept_mut(path, visitor);
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.walk_mut(path, visitor);
+        }
+    }
+}
+
+impl<T: Walk> Walk for Vec<T> {
+    fn accept<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        for (index, value) in self.iter().enumerate() {
+            value.accept(&format!("{path}[{index}]"), visitor);
+        }
+    }
+
+    fn walk<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        for (index, value) in self.iter().enumerate() {
+            value.walk(&format!("{path}[{index}]"), visitor);
+        }
+    }
+
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        for (index, value) in self.iter_mut().enumerate() {
+            value.accept_mut(&format!("{path}[{index}]"), visitor);
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        for (index, value) in self.iter_mut().enumerate() {
+            value.walk_mut(&format!("{path}[{index}]"), visitor);
+        }
+    }
+}
+
+impl<T: Walk> Walk for Box<T> {
+    fn accept<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        (**self).accept(path, visitor);
+    }
+
+    fn walk<V: Visit + ?Sized>(&self, path: &str, visitor: &mut V) {
+        (**self).walk(path, visitor);
+    }
+
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        (**self).accept_mut(path, visitor);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, path: &str, visitor: &mut V) {
+        (**self).walk_mut(path, visitor);
+    }
+}
+
+/// Implement `Walk` for a type without fields, such as the Rust type of a
+/// FHIRPath system type.
+macro_rules! impl_walk_without_fields {
+    ($($t:ty),*) => {
+        $(impl Walk for $t { fn walk < V : Visit + ? Sized > (& self, _path : & str,
+        _visitor : & mut V) {} fn walk_mut < V : VisitMut + ? Sized > (& mut self, _path
+        : & str, _visitor : & mut V) {} })*
+    };
+}
+
+impl_walk_without_fields!(
+    bool, i32, i64, u32, String, ::serde_json::Number, ::serde_json::Value
+);
@@ -0,0 +1,312 @@
+//! Account search parameters
+
+use super::*;
+
+/// `guarantor`: The parties ultimately responsible for balancing the Account
+pub const GUARANTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "guarantor",
+    url: "http://hl7.org/fhir/SearchParameter/Account-guarantor",
+    r#type: SearchParamType::Reference,
+    expression: Some("Account.guarantor.party"),
+    targets: &["Organization", "RelatedPerson", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Account number
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Account.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: Human-readable label
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/Account-name",
+    r#type: SearchParamType::String,
+    expression: Some("Account.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `owner`: Entity managing the Account
+pub const OWNER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "owner",
+    url: "http://hl7.org/fhir/SearchParameter/Account-owner",
+    r#type: SearchParamType::Reference,
+    expression: Some("Account.owner"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The entity that caused the expenses
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Account.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `period`: Transaction window
+pub const PERIOD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "period",
+    url: "http://hl7.org/fhir/SearchParameter/Account-period",
+    r#type: SearchParamType::Date,
+    expression: Some("Account.servicePeriod"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `relatedaccount`: Parent and other related accounts
+pub const RELATEDACCOUNT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "relatedaccount",
+    url: "http://hl7.org/fhir/SearchParameter/Account-relatedaccount",
+    r#type: SearchParamType::Reference,
+    expression: Some("Account.relatedAccount.account"),
+    targets: &["Account"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: active | inactive | entered-in-error | on-hold | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Account-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Account.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The entity that caused the expenses
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Account-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Account.subject"),
+    targets: &[
+        "HealthcareService",
+        "Device",
+        "Organization",
+        "PractitionerRole",
+        "Practitioner",
+        "Location",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: E.g. patient, expense, depreciation
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Account.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccountSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `guarantor`
+    Guarantor,
+
+    /// `identifier`
+    Identifier,
+
+    /// `name`
+    Name,
+
+    /// `owner`
+    Owner,
+
+    /// `patient`
+    Patient,
+
+    /// `period`
+    Period,
+
+    /// `relatedaccount`
+    Relatedaccount,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `type`
+    Type,
+}
+
+impl AccountSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Guarantor,
+        Self::Identifier,
+        Self::Name,
+        Self::Owner,
+        Self::Patient,
+        Self::Period,
+        Self::Relatedaccount,
+        Self::Status,
+        Self::Subject,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Guarantor => &GUARANTOR,
+            Self::Identifier => &IDENTIFIER,
+            Self::Name => &NAME,
+            Self::Owner => &OWNER,
+            Self::Patient => &PATIENT,
+            Self::Period => &PERIOD,
+            Self::Relatedaccount => &RELATEDACCOUNT,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for AccountSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = AccountSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
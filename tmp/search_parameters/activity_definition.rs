@@ -0,0 +1,1370 @@
+//! ActivityDefinition search parameters
+
+use super::*;
+
+/// `composed-of`: What resource is being referenced
+pub const COMPOSED_OF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "composed-of",
+    url: "http://hl7.org/fhir/SearchParameter/MetadataResource-composed-of",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ActivityDefinition.relatedArtifact.where(type='composed-of').resource",
+    ),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context`: A use context assigned to the activity definition
+pub const CONTEXT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context",
+    r#type: SearchParamType::Token,
+    expression: Some("(ActivityDefinition.useContext.value.ofType(CodeableConcept))"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-quantity`: A quantity- or range-valued use context assigned to the activity definition
+pub const CONTEXT_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some(
+        "(ActivityDefinition.useContext.value.ofType(Quantity)) | (ActivityDefinition.useContext.value.ofType(Range))",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-type`: A type of use context assigned to the activity definition
+pub const CONTEXT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+    r#type: SearchParamType::Token,
+    expression: Some("ActivityDefinition.useContext.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-type-quantity`: A use context type and quantity- or range-based value assigned to the activity definition
+pub const CONTEXT_TYPE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type-quantity",
+    r#type: SearchParamType::Composite,
+    expression: Some("ActivityDefinition.useContext"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-quantity",
+            expression: "value.ofType(Quantity) | value.ofType(Range)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `context-type-value`: A use context type and value assigned to the activity definition
+pub const CONTEXT_TYPE_VALUE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type-value",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type-value",
+    r#type: SearchParamType::Composite,
+    expression: Some("ActivityDefinition.useContext"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context",
+            expression: "value.ofType(CodeableConcept)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `date`: The activity definition publication date
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-date",
+    r#type: SearchParamType::Date,
+    expression: Some("ActivityDefinition.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `depends-on`: What resource is being referenced
+pub const DEPENDS_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "depends-on",
+    url: "http://hl7.org/fhir/SearchParameter/MetadataResource-depends-on",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ActivityDefinition.relatedArtifact.where(type='depends-on').resource | ActivityDefinition.library",
+    ),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `derived-from`: What resource is being referenced
+pub const DERIVED_FROM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "derived-from",
+    url: "http://hl7.org/fhir/SearchParameter/MetadataResource-derived-from",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ActivityDefinition.relatedArtifact.where(type='derived-from').resource",
+    ),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `description`: The description of the activity definition
+pub const DESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "description",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-description",
+    r#type: SearchParamType::String,
+    expression: Some("ActivityDefinition.description"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `effective`: The time during which the activity definition is intended to be in use
+pub const EFFECTIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "effective",
+    url: "http://hl7.org/fhir/SearchParameter/MetadataResource-effective",
+    r#type: SearchParamType::Date,
+    expression: Some("ActivityDefinition.effectivePeriod"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: External identifier for the activity definition
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ActivityDefinition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `jurisdiction`: Intended jurisdiction for the activity definition
+pub const JURISDICTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "jurisdiction",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-jurisdiction",
+    r#type: SearchParamType::Token,
+    expression: Some("ActivityDefinition.jurisdiction"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `kind`: The kind of activity definition
+pub const KIND: SearchParameterDefinition = SearchParameterDefinition {
+    code: "kind",
+    url: "http://hl7.org/fhir/SearchParameter/ActivityDefinition-kind",
+    r#type: SearchParamType::Token,
+    expression: Some("ActivityDefinition.kind"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: Computationally friendly name of the activity definition
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-name",
+    r#type: SearchParamType::String,
+    expression: Some("ActivityDefinition.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `predecessor`: What resource is being referenced
+pub const PREDECESSOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "predecessor",
+    url: "http://hl7.org/fhir/SearchParameter/MetadataResource-predecessor",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ActivityDefinition.relatedArtifact.where(type='predecessor').resource",
+    ),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `publisher`: Name of the publisher of the activity definition
+pub const PUBLISHER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "publisher",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-publisher",
+    r#type: SearchParamType::String,
+    expression: Some("ActivityDefinition.publisher"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The current status of the activity definition
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ActivityDefinition.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `successor`: What resource is being referenced
+pub const SUCCESSOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "successor",
+    url: "http://hl7.org/fhir/SearchParameter/MetadataResource-successor",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ActivityDefinition.relatedArtifact.where(type='successor').resource",
+    ),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `title`: The human-friendly name of the activity definition
+pub const TITLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "title",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-title",
+    r#type: SearchParamType::String,
+    expression: Some("ActivityDefinition.title"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `topic`: Topics associated with the module
+pub const TOPIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "topic",
+    url: "http://hl7.org/fhir/SearchParameter/MetadataResource-topic",
+    r#type: SearchParamType::Token,
+    expression: Some("ActivityDefinition.topic"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `url`: The uri that identifies the activity definition
+pub const URL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "url",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-url",
+    r#type: SearchParamType::Uri,
+    expression: Some("ActivityDefinition.url"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `version`: The business version of the activity definition
+pub const VERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "version",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-version",
+    r#type: SearchParamType::Token,
+    expression: Some("ActivityDefinition.version"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ActivityDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActivityDefinitionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `composed-of`
+    ComposedOf,
+
+    /// `context`
+    Context,
+
+    /// `context-quantity`
+    ContextQuantity,
+
+    /// `context-type`
+    ContextType,
+
+    /// `context-type-quantity`
+    ContextTypeQuantity,
+
+    /// `context-type-value`
+    ContextTypeValue,
+
+    /// `date`
+    Date,
+
+    /// `depends-on`
+    DependsOn,
+
+    /// `derived-from`
+    DerivedFrom,
+
+    /// `description`
+    Description,
+
+    /// `effective`
+    Effective,
+
+    /// `identifier`
+    Identifier,
+
+    /// `jurisdiction`
+    Jurisdiction,
+
+    /// `kind`
+    Kind,
+
+    /// `name`
+    Name,
+
+    /// `predecessor`
+    Predecessor,
+
+    /// `publisher`
+    Publisher,
+
+    /// `status`
+    Status,
+
+    /// `successor`
+    Successor,
+
+    /// `title`
+    Title,
+
+    /// `topic`
+    Topic,
+
+    /// `url`
+    Url,
+
+    /// `version`
+    Version,
+}
+
+impl ActivityDefinitionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::ComposedOf,
+        Self::Context,
+        Self::ContextQuantity,
+        Self::ContextType,
+        Self::ContextTypeQuantity,
+        Self::ContextTypeValue,
+        Self::Date,
+        Self::DependsOn,
+        Self::DerivedFrom,
+        Self::Description,
+        Self::Effective,
+        Self::Identifier,
+        Self::Jurisdiction,
+        Self::Kind,
+        Self::Name,
+        Self::Predecessor,
+        Self::Publisher,
+        Self::Status,
+        Self::Successor,
+        Self::Title,
+        Self::Topic,
+        Self::Url,
+        Self::Version,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::ComposedOf => &COMPOSED_OF,
+            Self::Context => &CONTEXT,
+            Self::ContextQuantity => &CONTEXT_QUANTITY,
+            Self::ContextType => &CONTEXT_TYPE,
+            Self::ContextTypeQuantity => &CONTEXT_TYPE_QUANTITY,
+            Self::ContextTypeValue => &CONTEXT_TYPE_VALUE,
+            Self::Date => &DATE,
+            Self::DependsOn => &DEPENDS_ON,
+            Self::DerivedFrom => &DERIVED_FROM,
+            Self::Description => &DESCRIPTION,
+            Self::Effective => &EFFECTIVE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Jurisdiction => &JURISDICTION,
+            Self::Kind => &KIND,
+            Self::Name => &NAME,
+            Self::Predecessor => &PREDECESSOR,
+            Self::Publisher => &PUBLISHER,
+            Self::Status => &STATUS,
+            Self::Successor => &SUCCESSOR,
+            Self::Title => &TITLE,
+            Self::Topic => &TOPIC,
+            Self::Url => &URL,
+            Self::Version => &VERSION,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ActivityDefinitionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ActivityDefinitionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! AdministrableProductDefinition search parameters
+
+use super::*;
+
+/// `device`: A device that is integral to the medicinal product, in effect being considered as an "ingredient" of the medicinal product. This is not intended for devices that are just co-packaged
+pub const DEVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "device",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-device",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdministrableProductDefinition.device"),
+    targets: &["DeviceDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `dose-form`: The administrable dose form, i.e. the dose form of the final product after necessary reconstitution or processing
+pub const DOSE_FORM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "dose-form",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-dose-form",
+    r#type: SearchParamType::Token,
+    expression: Some("AdministrableProductDefinition.administrableDoseForm"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `form-of`: The medicinal product that this is an administrable form of. This is not a reference to the item(s) that make up this administrable form - it is the whole product
+pub const FORM_OF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "form-of",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-form-of",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdministrableProductDefinition.formOf"),
+    targets: &["MedicinalProductDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: An identifier for the administrable product
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("AdministrableProductDefinition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `ingredient`: The ingredients of this administrable medicinal product
+pub const INGREDIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ingredient",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-ingredient",
+    r#type: SearchParamType::Token,
+    expression: Some("AdministrableProductDefinition.ingredient"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manufactured-item`: The manufactured item(s) that this administrable product is produced from. Either a single item, or several that are mixed before administration (e.g. a power item and a solution item). Note that these are not raw ingredients
+pub const MANUFACTURED_ITEM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manufactured-item",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-manufactured-item",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdministrableProductDefinition.producedFrom"),
+    targets: &["ManufacturedItemDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `route`: Coded expression for the route
+pub const ROUTE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "route",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-route",
+    r#type: SearchParamType::Token,
+    expression: Some("AdministrableProductDefinition.routeOfAdministration.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of this administrable product. Enables tracking the life-cycle of the content.
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-status",
+    r#type: SearchParamType::Token,
+    expression: Some("AdministrableProductDefinition.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `target-species`: Coded expression for the species
+pub const TARGET_SPECIES: SearchParameterDefinition = SearchParameterDefinition {
+    code: "target-species",
+    url: "http://hl7.org/fhir/SearchParameter/AdministrableProductDefinition-target-species",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "AdministrableProductDefinition.routeOfAdministration.targetSpecies.code",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of AdministrableProductDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AdministrableProductDefinitionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `device`
+    Device,
+
+    /// `dose-form`
+    DoseForm,
+
+    /// `form-of`
+    FormOf,
+
+    /// `identifier`
+    Identifier,
+
+    /// `ingredient`
+    Ingredient,
+
+    /// `manufactured-item`
+    ManufacturedItem,
+
+    /// `route`
+    Route,
+
+    /// `status`
+    Status,
+
+    /// `target-species`
+    TargetSpecies,
+}
+
+impl AdministrableProductDefinitionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Device,
+        Self::DoseForm,
+        Self::FormOf,
+        Self::Identifier,
+        Self::Ingredient,
+        Self::ManufacturedItem,
+        Self::Route,
+        Self::Status,
+        Self::TargetSpecies,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Device => &DEVICE,
+            Self::DoseForm => &DOSE_FORM,
+            Self::FormOf => &FORM_OF,
+            Self::Identifier => &IDENTIFIER,
+            Self::Ingredient => &INGREDIENT,
+            Self::ManufacturedItem => &MANUFACTURED_ITEM,
+            Self::Route => &ROUTE,
+            Self::Status => &STATUS,
+            Self::TargetSpecies => &TARGET_SPECIES,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for AdministrableProductDefinitionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = AdministrableProductDefinitionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
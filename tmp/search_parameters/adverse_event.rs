@@ -0,0 +1,390 @@
+//! AdverseEvent search parameters
+
+use super::*;
+
+/// `actuality`: actual | potential
+pub const ACTUALITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "actuality",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-actuality",
+    r#type: SearchParamType::Token,
+    expression: Some("AdverseEvent.actuality"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: wrong-patient | procedure-mishap | medication-mishap | device | unsafe-physical-environment | hospital-aquired-infection | wrong-body-site
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-category",
+    r#type: SearchParamType::Token,
+    expression: Some("AdverseEvent.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: Event or incident that occurred or was averted
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("AdverseEvent.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: When the event occurred
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "AdverseEvent.occurrence.ofType(dateTime) | AdverseEvent.occurrence.ofType(Period) | AdverseEvent.occurrence.ofType(Timing)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business identifier for the event
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("AdverseEvent.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: Location where adverse event occurred
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdverseEvent.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Subject impacted by event
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdverseEvent.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `recorder`: Who recorded the adverse event
+pub const RECORDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "recorder",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-recorder",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdverseEvent.recorder"),
+    targets: &[
+        "RelatedPerson",
+        "PractitionerRole",
+        "ResearchSubject",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `resultingeffect`: Effect on the subject due to this event
+pub const RESULTINGEFFECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "resultingeffect",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-resultingeffect",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdverseEvent.resultingEffect"),
+    targets: &["Observation", "Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `seriousness`: Seriousness or gravity of the event
+pub const SERIOUSNESS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "seriousness",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-seriousness",
+    r#type: SearchParamType::Token,
+    expression: Some("AdverseEvent.seriousness"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: in-progress | completed | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-status",
+    r#type: SearchParamType::Token,
+    expression: Some("AdverseEvent.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `study`: Research study that the subject is enrolled in
+pub const STUDY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "study",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-study",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdverseEvent.study"),
+    targets: &["ResearchStudy"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Subject impacted by event
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("AdverseEvent.subject"),
+    targets: &["RelatedPerson", "ResearchSubject", "Group", "Practitioner", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `substance`: Refers to the specific entity that caused the adverse event
+pub const SUBSTANCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "substance",
+    url: "http://hl7.org/fhir/SearchParameter/AdverseEvent-substance",
+    r#type: SearchParamType::Reference,
+    expression: Some("(AdverseEvent.suspectEntity.instance as Reference)"),
+    targets: &[
+        "Device",
+        "Procedure",
+        "MedicationStatement",
+        "ResearchStudy",
+        "MedicationAdministration",
+        "BiologicallyDerivedProduct",
+        "Immunization",
+        "Substance",
+        "Medication",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of AdverseEvent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AdverseEventSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `actuality`
+    Actuality,
+
+    /// `category`
+    Category,
+
+    /// `code`
+    Code,
+
+    /// `date`
+    Date,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `patient`
+    Patient,
+
+    /// `recorder`
+    Recorder,
+
+    /// `resultingeffect`
+    Resultingeffect,
+
+    /// `seriousness`
+    Seriousness,
+
+    /// `status`
+    Status,
+
+    /// `study`
+    Study,
+
+    /// `subject`
+    Subject,
+
+    /// `substance`
+    Substance,
+}
+
+impl AdverseEventSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Actuality,
+        Self::Category,
+        Self::Code,
+        Self::Date,
+        Self::Identifier,
+        Self::Location,
+        Self::Patient,
+        Self::Recorder,
+        Self::Resultingeffect,
+        Self::Seriousness,
+        Self::Status,
+        Self::Study,
+        Self::Subject,
+        Self::Substance,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Actuality => &ACTUALITY,
+            Self::Category => &CATEGORY,
+            Self::Code => &CODE,
+            Self::Date => &DATE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::Patient => &PATIENT,
+            Self::Recorder => &RECORDER,
+            Self::Resultingeffect => &RESULTINGEFFECT,
+            Self::Seriousness => &SERIOUSNESS,
+            Self::Status => &STATUS,
+            Self::Study => &STUDY,
+            Self::Subject => &SUBJECT,
+            Self::Substance => &SUBSTANCE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for AdverseEventSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = AdverseEventSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
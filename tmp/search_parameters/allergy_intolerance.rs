@@ -0,0 +1,407 @@
+//! AllergyIntolerance search parameters
+
+use super::*;
+
+/// `category`: food | medication | environment | biologic
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-category",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `clinical-status`: active | inactive | resolved
+pub const CLINICAL_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "clinical-status",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-clinical-status",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.clinicalStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: Code that identifies the allergy or intolerance
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.code | AllergyIntolerance.reaction.substance"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `criticality`: low | high | unable-to-assess
+pub const CRITICALITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "criticality",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-criticality",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.criticality"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: Date first version of the resource instance was recorded
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("AllergyIntolerance.recordedDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: External ids for this item
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `last-date`: Date(/time) of last known occurrence of a reaction
+pub const LAST_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "last-date",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-last-date",
+    r#type: SearchParamType::Date,
+    expression: Some("AllergyIntolerance.lastOccurrence"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manifestation-code`: Clinical symptoms/signs associated with the Event
+pub const MANIFESTATION_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manifestation-code",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-manifestation-code",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.reaction.manifestation.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manifestation-reference`: Clinical symptoms/signs associated with the Event
+pub const MANIFESTATION_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manifestation-reference",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-manifestation-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("AllergyIntolerance.reaction.manifestation.reference"),
+    targets: &["Observation"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `participant`: Who or what participated in the activities related to the allergy or intolerance
+pub const PARTICIPANT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "participant",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-participant",
+    r#type: SearchParamType::Reference,
+    expression: Some("AllergyIntolerance.participant.actor"),
+    targets: &[
+        "Device",
+        "Organization",
+        "CareTeam",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Who the sensitivity is for
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("AllergyIntolerance.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `route`: How the subject was exposed to the substance
+pub const ROUTE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "route",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-route",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.reaction.exposureRoute"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `severity`: mild | moderate | severe (of event as a whole)
+pub const SEVERITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "severity",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-severity",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.reaction.severity"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: allergy | intolerance - Underlying mechanism (if known)
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `verification-status`: unconfirmed | presumed | confirmed | refuted | entered-in-error
+pub const VERIFICATION_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "verification-status",
+    url: "http://hl7.org/fhir/SearchParameter/AllergyIntolerance-verification-status",
+    r#type: SearchParamType::Token,
+    expression: Some("AllergyIntolerance.verificationStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of AllergyIntolerance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AllergyIntoleranceSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `category`
+    Category,
+
+    /// `clinical-status`
+    ClinicalStatus,
+
+    /// `code`
+    Code,
+
+    /// `criticality`
+    Criticality,
+
+    /// `date`
+    Date,
+
+    /// `identifier`
+    Identifier,
+
+    /// `last-date`
+    LastDate,
+
+    /// `manifestation-code`
+    ManifestationCode,
+
+    /// `manifestation-reference`
+    ManifestationReference,
+
+    /// `participant`
+    Participant,
+
+    /// `patient`
+    Patient,
+
+    /// `route`
+    Route,
+
+    /// `severity`
+    Severity,
+
+    /// `type`
+    Type,
+
+    /// `verification-status`
+    VerificationStatus,
+}
+
+impl AllergyIntoleranceSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Category,
+        Self::ClinicalStatus,
+        Self::Code,
+        Self::Criticality,
+        Self::Date,
+        Self::Identifier,
+        Self::LastDate,
+        Self::ManifestationCode,
+        Self::ManifestationReference,
+        Self::Participant,
+        Self::Patient,
+        Self::Route,
+        Self::Severity,
+        Self::Type,
+        Self::VerificationStatus,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Category => &CATEGORY,
+            Self::ClinicalStatus => &CLINICAL_STATUS,
+            Self::Code => &CODE,
+            Self::Criticality => &CRITICALITY,
+            Self::Date => &DATE,
+            Self::Identifier => &IDENTIFIER,
+            Self::LastDate => &LAST_DATE,
+            Self::ManifestationCode => &MANIFESTATION_CODE,
+            Self::ManifestationReference => &MANIFESTATION_REFERENCE,
+            Self::Participant => &PARTICIPANT,
+            Self::Patient => &PATIENT,
+            Self::Route => &ROUTE,
+            Self::Severity => &SEVERITY,
+            Self::Type => &TYPE,
+            Self::VerificationStatus => &VERIFICATION_STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for AllergyIntoleranceSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = AllergyIntoleranceSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,676 @@
+//! Appointment search parameters
+
+use super::*;
+
+/// `actor`: Any one of the individuals participating in the appointment
+pub const ACTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "actor",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-actor",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.participant.actor"),
+    targets: &[
+        "HealthcareService",
+        "CareTeam",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Group",
+        "Practitioner",
+        "Location",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `appointment-type`: The style of appointment or patient that has been booked in the slot (not service type)
+pub const APPOINTMENT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "appointment-type",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-appointment-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Appointment.appointmentType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `based-on`: The service request this appointment is allocated to assess
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.basedOn"),
+    targets: &["DeviceRequest", "ServiceRequest", "CarePlan", "MedicationRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: Appointment date/time.
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "AdverseEvent.occurrence.ofType(dateTime) | AdverseEvent.occurrence.ofType(Period) | AdverseEvent.occurrence.ofType(Timing) | AllergyIntolerance.recordedDate | (start | requestedPeriod.start).first() | AuditEvent.recorded | CarePlan.period | ClinicalImpression.date | Composition.date | Consent.date | DiagnosticReport.effective.ofType(dateTime) | DiagnosticReport.effective.ofType(Period) | DocumentReference.date | Encounter.actualPeriod | EpisodeOfCare.period | FamilyMemberHistory.date | Flag.period | (Immunization.occurrence.ofType(dateTime)) | ImmunizationEvaluation.date | ImmunizationRecommendation.date | Invoice.date | List.date | MeasureReport.date | NutritionIntake.occurrence.ofType(dateTime) | NutritionIntake.occurrence.ofType(Period) | Observation.effective.ofType(dateTime) | Observation.effective.ofType(Period) | Observation.effective.ofType(Timing) | Observation.effective.ofType(instant) | Procedure.occurrence.ofType(dateTime) | Procedure.occurrence.ofType(Period) | Procedure.occurrence.ofType(Timing) | ResearchSubject.period | (RiskAssessment.occurrence.ofType(dateTime)) | SupplyRequest.authoredOn",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `group`: One of the individuals of the appointment is this patient
+pub const GROUP: SearchParameterDefinition = SearchParameterDefinition {
+    code: "group",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-group",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "Appointment.participant.actor.where(resolve() is Group) | Appointment.subject.where(resolve() is Group)",
+    ),
+    targets: &["Group"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: An Identifier of the Appointment
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Appointment.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: This location is listed in the participants of the appointment
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.participant.actor.where(resolve() is Location)"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `part-status`: The Participation status of the subject, or other participant on the appointment. Can be used to locate participants that have not responded to meeting requests.
+pub const PART_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "part-status",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-part-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Appointment.participant.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: One of the individuals of the appointment is this patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "Appointment.participant.actor.where(resolve() is Patient) | Appointment.subject.where(resolve() is Patient)",
+    ),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `practitioner`: One of the individuals of the appointment is this practitioner
+pub const PRACTITIONER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "practitioner",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-practitioner",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.participant.actor.where(resolve() is Practitioner)"),
+    targets: &["Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-code`: Reference to a concept (by class)
+pub const REASON_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-code",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-reason-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Appointment.reason.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-reference`: Reference to a resource (by instance)
+pub const REASON_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-reason-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.reason.reference"),
+    targets: &["Procedure", "Observation", "ImmunizationRecommendation", "Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requested-period`: During what period was the Appointment requested to take place
+pub const REQUESTED_PERIOD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requested-period",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-requested-period",
+    r#type: SearchParamType::Date,
+    expression: Some("requestedPeriod"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-category`: A broad categorization of the service that is to be performed during this appointment
+pub const SERVICE_CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-category",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-service-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Appointment.serviceCategory"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-type`: The specific service (by coding) that is to be performed during this appointment
+pub const SERVICE_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-type",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-service-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Appointment.serviceType.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-type-reference`: The specific service (by HealthcareService) that is to be performed during this appointment
+pub const SERVICE_TYPE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-type-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-service-type-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.serviceType.reference"),
+    targets: &["HealthcareService"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `slot`: The slots that this appointment is filling
+pub const SLOT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "slot",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-slot",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.slot"),
+    targets: &["Slot"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specialty`: The specialty of a practitioner that would be required to perform the service requested in this appointment
+pub const SPECIALTY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specialty",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-specialty",
+    r#type: SearchParamType::Token,
+    expression: Some("Appointment.specialty"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The overall status of the appointment
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Appointment.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: One of the individuals of the appointment is this patient
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `supporting-info`: Additional information to support the appointment
+pub const SUPPORTING_INFO: SearchParameterDefinition = SearchParameterDefinition {
+    code: "supporting-info",
+    url: "http://hl7.org/fhir/SearchParameter/Appointment-supporting-info",
+    r#type: SearchParamType::Reference,
+    expression: Some("Appointment.supportingInformation"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Appointment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AppointmentSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `actor`
+    Actor,
+
+    /// `appointment-type`
+    AppointmentType,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `date`
+    Date,
+
+    /// `group`
+    Group,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `part-status`
+    PartStatus,
+
+    /// `patient`
+    Patient,
+
+    /// `practitioner`
+    Practitioner,
+
+    /// `reason-code`
+    ReasonCode,
+
+    /// `reason-reference`
+    ReasonReference,
+
+    /// `requested-period`
+    RequestedPeriod,
+
+    /// `service-category`
+    ServiceCategory,
+
+    /// `service-type`
+    ServiceType,
+
+    /// `service-type-reference`
+    ServiceTypeReference,
+
+    /// `slot`
+    Slot,
+
+    /// `specialty`
+    Specialty,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `supporting-info`
+    SupportingInfo,
+}
+
+impl AppointmentSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Actor,
+        Self::AppointmentType,
+        Self::BasedOn,
+        Self::Date,
+        Self::Group,
+        Self::Identifier,
+        Self::Location,
+        Self::PartStatus,
+        Self::Patient,
+        Self::Practitioner,
+        Self::ReasonCode,
+        Self::ReasonReference,
+        Self::RequestedPeriod,
+        Self::ServiceCategory,
+        Self::ServiceType,
+        Self::ServiceTypeReference,
+        Self::Slot,
+        Self::Specialty,
+        Self::Status,
+        Self::Subject,
+        Self::SupportingInfo,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Actor => &ACTOR,
+            Self::AppointmentType => &APPOINTMENT_TYPE,
+            Self::BasedOn => &BASED_ON,
+            Self::Date => &DATE,
+            Self::Group => &GROUP,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::PartStatus => &PART_STATUS,
+            Self::Patient => &PATIENT,
+            Self::Practitioner => &PRACTITIONER,
+            Self::ReasonCode => &REASON_CODE,
+            Self::ReasonReference => &REASON_REFERENCE,
+            Self::RequestedPeriod => &REQUESTED_PERIOD,
+            Self::ServiceCategory => &SERVICE_CATEGORY,
+            Self::ServiceType => &SERVICE_TYPE,
+            Self::ServiceTypeReference => &SERVICE_TYPE_REFERENCE,
+            Self::Slot => &SLOT,
+            Self::Specialty => &SPECIALTY,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::SupportingInfo => &SUPPORTING_INFO,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for AppointmentSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = AppointmentSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! AppointmentResponse search parameters
+
+use super::*;
+
+/// `actor`: The Person, Location/HealthcareService or Device that this appointment response replies for
+pub const ACTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "actor",
+    url: "http://hl7.org/fhir/SearchParameter/AppointmentResponse-actor",
+    r#type: SearchParamType::Reference,
+    expression: Some("AppointmentResponse.actor"),
+    targets: &[
+        "HealthcareService",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Group",
+        "Practitioner",
+        "Location",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `appointment`: The appointment that the response is attached to
+pub const APPOINTMENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "appointment",
+    url: "http://hl7.org/fhir/SearchParameter/AppointmentResponse-appointment",
+    r#type: SearchParamType::Reference,
+    expression: Some("AppointmentResponse.appointment"),
+    targets: &["Appointment"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `group`: This Response is for this Group
+pub const GROUP: SearchParameterDefinition = SearchParameterDefinition {
+    code: "group",
+    url: "http://hl7.org/fhir/SearchParameter/AppointmentResponse-group",
+    r#type: SearchParamType::Reference,
+    expression: Some("AppointmentResponse.actor.where(resolve() is Group)"),
+    targets: &["Group"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: An Identifier in this appointment response
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("AppointmentResponse.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: This Response is for this Location
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/AppointmentResponse-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("AppointmentResponse.actor.where(resolve() is Location)"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `part-status`: The participants acceptance status for this appointment
+pub const PART_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "part-status",
+    url: "http://hl7.org/fhir/SearchParameter/AppointmentResponse-part-status",
+    r#type: SearchParamType::Token,
+    expression: Some("AppointmentResponse.participantStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: This Response is for this Patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("AppointmentResponse.actor.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `practitioner`: This Response is for this Practitioner
+pub const PRACTITIONER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "practitioner",
+    url: "http://hl7.org/fhir/SearchParameter/AppointmentResponse-practitioner",
+    r#type: SearchParamType::Reference,
+    expression: Some("AppointmentResponse.actor.where(resolve() is Practitioner)"),
+    targets: &["Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of AppointmentResponse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AppointmentResponseSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `actor`
+    Actor,
+
+    /// `appointment`
+    Appointment,
+
+    /// `group`
+    Group,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `part-status`
+    PartStatus,
+
+    /// `patient`
+    Patient,
+
+    /// `practitioner`
+    Practitioner,
+}
+
+impl AppointmentResponseSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Actor,
+        Self::Appointment,
+        Self::Group,
+        Self::Identifier,
+        Self::Location,
+        Self::PartStatus,
+        Self::Patient,
+        Self::Practitioner,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Actor => &ACTOR,
+            Self::Appointment => &APPOINTMENT,
+            Self::Group => &GROUP,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::PartStatus => &PART_STATUS,
+            Self::Patient => &PATIENT,
+            Self::Practitioner => &PRACTITIONER,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for AppointmentResponseSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = AppointmentResponseSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
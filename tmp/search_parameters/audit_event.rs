@@ -0,0 +1,572 @@
+//! AuditEvent search parameters
+
+use super::*;
+
+/// `action`: Type of action performed during the event
+pub const ACTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "action",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-action",
+    r#type: SearchParamType::Token,
+    expression: Some("AuditEvent.action"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `agent`: Identifier of who
+pub const AGENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "agent",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-agent",
+    r#type: SearchParamType::Reference,
+    expression: Some("AuditEvent.agent.who"),
+    targets: &[
+        "Organization",
+        "CareTeam",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `agent-role`: Agent role in the event
+pub const AGENT_ROLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "agent-role",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-agent-role",
+    r#type: SearchParamType::Token,
+    expression: Some("AuditEvent.agent.role"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `based-on`: Reference to the service request.
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("AuditEvent.basedOn"),
+    targets: &[
+        "DeviceRequest",
+        "ServiceRequest",
+        "CarePlan",
+        "Task",
+        "MedicationRequest",
+        "ImmunizationRecommendation",
+        "NutritionOrder",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Category of event
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-category",
+    r#type: SearchParamType::Token,
+    expression: Some("AuditEvent.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: More specific code for the event
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("AuditEvent.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: Time when the event was recorded
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("AuditEvent.recorded"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Encounter related to the activity recorded in the AuditEvent
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("AuditEvent.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `entity`: Specific instance of resource
+pub const ENTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "entity",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-entity",
+    r#type: SearchParamType::Reference,
+    expression: Some("AuditEvent.entity.what"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `entity-role`: What role the entity played
+pub const ENTITY_ROLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "entity-role",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-entity-role",
+    r#type: SearchParamType::Token,
+    expression: Some("AuditEvent.entity.role"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `outcome`: Whether the event succeeded or failed
+pub const OUTCOME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "outcome",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-outcome",
+    r#type: SearchParamType::Token,
+    expression: Some("AuditEvent.outcome.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Where the activity involved patient data
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("AuditEvent.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `policy`: Policy that authorized event
+pub const POLICY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "policy",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-policy",
+    r#type: SearchParamType::Uri,
+    expression: Some("AuditEvent.agent.policy"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `purpose`: The authorization (purposeOfUse) of the event
+pub const PURPOSE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "purpose",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-purpose",
+    r#type: SearchParamType::Token,
+    expression: Some("AuditEvent.authorization | AuditEvent.agent.authorization"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `source`: The identity of source detecting the event
+pub const SOURCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "source",
+    url: "http://hl7.org/fhir/SearchParameter/AuditEvent-source",
+    r#type: SearchParamType::Reference,
+    expression: Some("AuditEvent.source.observer"),
+    targets: &[
+        "Organization",
+        "CareTeam",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of AuditEvent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AuditEventSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `action`
+    Action,
+
+    /// `agent`
+    Agent,
+
+    /// `agent-role`
+    AgentRole,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `category`
+    Category,
+
+    /// `code`
+    Code,
+
+    /// `date`
+    Date,
+
+    /// `encounter`
+    Encounter,
+
+    /// `entity`
+    Entity,
+
+    /// `entity-role`
+    EntityRole,
+
+    /// `outcome`
+    Outcome,
+
+    /// `patient`
+    Patient,
+
+    /// `policy`
+    Policy,
+
+    /// `purpose`
+    Purpose,
+
+    /// `source`
+    Source,
+}
+
+impl AuditEventSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Action,
+        Self::Agent,
+        Self::AgentRole,
+        Self::BasedOn,
+        Self::Category,
+        Self::Code,
+        Self::Date,
+        Self::Encounter,
+        Self::Entity,
+        Self::EntityRole,
+        Self::Outcome,
+        Self::Patient,
+        Self::Policy,
+        Self::Purpose,
+        Self::Source,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Action => &ACTION,
+            Self::Agent => &AGENT,
+            Self::AgentRole => &AGENT_ROLE,
+            Self::BasedOn => &BASED_ON,
+            Self::Category => &CATEGORY,
+            Self::Code => &CODE,
+            Self::Date => &DATE,
+            Self::Encounter => &ENCOUNTER,
+            Self::Entity => &ENTITY,
+            Self::EntityRole => &ENTITY_ROLE,
+            Self::Outcome => &OUTCOME,
+            Self::Patient => &PATIENT,
+            Self::Policy => &POLICY,
+            Self::Purpose => &PURPOSE,
+            Self::Source => &SOURCE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for AuditEventSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = AuditEventSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! BiologicallyDerivedProduct search parameters
+
+use super::*;
+
+/// `biological-source-event`: The biological source for the biologically derived product
+pub const BIOLOGICAL_SOURCE_EVENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "biological-source-event",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProduct-biological-source-event",
+    r#type: SearchParamType::Token,
+    expression: Some("BiologicallyDerivedProduct.biologicalSourceEvent"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: A code that identifies the kind of this biologically derived product (SNOMED CT code).
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProduct-code",
+    r#type: SearchParamType::Token,
+    expression: Some("BiologicallyDerivedProduct.productCode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `collector`: Procedure request to obtain this biologically derived product.
+pub const COLLECTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "collector",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProduct-collector",
+    r#type: SearchParamType::Reference,
+    expression: Some("BiologicallyDerivedProduct.collection.collector"),
+    targets: &["PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProduct-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("BiologicallyDerivedProduct.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `product-category`: Broad category of this product.
+pub const PRODUCT_CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "product-category",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProduct-product-category",
+    r#type: SearchParamType::Token,
+    expression: Some("BiologicallyDerivedProduct.productCategory"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `product-status`: Whether the product is currently available.
+pub const PRODUCT_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "product-status",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProduct-product-status",
+    r#type: SearchParamType::Token,
+    expression: Some("BiologicallyDerivedProduct.productStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `request`: Procedure request to obtain this biologically derived product.
+pub const REQUEST: SearchParameterDefinition = SearchParameterDefinition {
+    code: "request",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProduct-request",
+    r#type: SearchParamType::Reference,
+    expression: Some("BiologicallyDerivedProduct.request"),
+    targets: &["ServiceRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `serial-number`: Identifier
+pub const SERIAL_NUMBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "serial-number",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProduct-serial-number",
+    r#type: SearchParamType::Token,
+    expression: Some("BiologicallyDerivedProduct.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of BiologicallyDerivedProduct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BiologicallyDerivedProductSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `biological-source-event`
+    BiologicalSourceEvent,
+
+    /// `code`
+    Code,
+
+    /// `collector`
+    Collector,
+
+    /// `identifier`
+    Identifier,
+
+    /// `product-category`
+    ProductCategory,
+
+    /// `product-status`
+    ProductStatus,
+
+    /// `request`
+    Request,
+
+    /// `serial-number`
+    SerialNumber,
+}
+
+impl BiologicallyDerivedProductSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::BiologicalSourceEvent,
+        Self::Code,
+        Self::Collector,
+        Self::Identifier,
+        Self::ProductCategory,
+        Self::ProductStatus,
+        Self::Request,
+        Self::SerialNumber,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::BiologicalSourceEvent => &BIOLOGICAL_SOURCE_EVENT,
+            Self::Code => &CODE,
+            Self::Collector => &COLLECTOR,
+            Self::Identifier => &IDENTIFIER,
+            Self::ProductCategory => &PRODUCT_CATEGORY,
+            Self::ProductStatus => &PRODUCT_STATUS,
+            Self::Request => &REQUEST,
+            Self::SerialNumber => &SERIAL_NUMBER,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for BiologicallyDerivedProductSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = BiologicallyDerivedProductSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
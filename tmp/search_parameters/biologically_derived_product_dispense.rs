@@ -0,0 +1,209 @@
+//! BiologicallyDerivedProductDispense search parameters
+
+use super::*;
+
+/// `identifier`: The identifier of the dispense
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProductDispense-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("BiologicallyDerivedProductDispense.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The identity of a patient for whom to list dispenses
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProductDispense-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("BiologicallyDerivedProductDispense.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: The identity of a patient for whom to list dispenses
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProductDispense-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("BiologicallyDerivedProductDispense.performer.actor"),
+    targets: &["Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `product`: Search for products that match this code
+pub const PRODUCT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "product",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProductDispense-product",
+    r#type: SearchParamType::Reference,
+    expression: Some("BiologicallyDerivedProductDispense.product"),
+    targets: &["BiologicallyDerivedProduct"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the dispense
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/BiologicallyDerivedProductDispense-status",
+    r#type: SearchParamType::Token,
+    expression: Some("BiologicallyDerivedProductDispense.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of BiologicallyDerivedProductDispense.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BiologicallyDerivedProductDispenseSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `identifier`
+    Identifier,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `product`
+    Product,
+
+    /// `status`
+    Status,
+}
+
+impl BiologicallyDerivedProductDispenseSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Identifier,
+        Self::Patient,
+        Self::Performer,
+        Self::Product,
+        Self::Status,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::Product => &PRODUCT,
+            Self::Status => &STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for BiologicallyDerivedProductDispenseSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = BiologicallyDerivedProductDispenseSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
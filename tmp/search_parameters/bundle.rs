@@ -0,0 +1,219 @@
+//! Bundle search parameters
+
+use super::*;
+
+/// `composition`: The first resource in the bundle, if the bundle type is "document" - this is a composition, and this parameter provides access to search its contents
+pub const COMPOSITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "composition",
+    url: "http://hl7.org/fhir/SearchParameter/Bundle-composition",
+    r#type: SearchParamType::Reference,
+    expression: Some("Bundle.entry[0].resource as Composition"),
+    targets: &["Composition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Persistent identifier for the bundle
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Bundle-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Bundle.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `message`: The first resource in the bundle, if the bundle type is "message" - this is a message header, and this parameter provides access to search its contents
+pub const MESSAGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "message",
+    url: "http://hl7.org/fhir/SearchParameter/Bundle-message",
+    r#type: SearchParamType::Reference,
+    expression: Some("Bundle.entry[0].resource as MessageHeader"),
+    targets: &["MessageHeader"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `timestamp`: When the bundle was assembled
+pub const TIMESTAMP: SearchParameterDefinition = SearchParameterDefinition {
+    code: "timestamp",
+    url: "http://hl7.org/fhir/SearchParameter/Bundle-timestamp",
+    r#type: SearchParamType::Date,
+    expression: Some("Bundle.timestamp"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: document | message | transaction | transaction-response | batch | batch-response | history | searchset | collection | subscription-notification
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/Bundle-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Bundle.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BundleSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `composition`
+    Composition,
+
+    /// `identifier`
+    Identifier,
+
+    /// `message`
+    Message,
+
+    /// `timestamp`
+    Timestamp,
+
+    /// `type`
+    Type,
+}
+
+impl BundleSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreText,
+        Self::UnderscoreType,
+        Self::Composition,
+        Self::Identifier,
+        Self::Message,
+        Self::Timestamp,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreText => &super::resource::UNDERSCORE_TEXT,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::Composition => &COMPOSITION,
+            Self::Identifier => &IDENTIFIER,
+            Self::Message => &MESSAGE,
+            Self::Timestamp => &TIMESTAMP,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for BundleSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = BundleSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
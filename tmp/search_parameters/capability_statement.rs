@@ -0,0 +1,572 @@
+//! CapabilityStatement search parameters
+
+use super::*;
+
+/// `context`: A use context assigned to the capability statement
+pub const CONTEXT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context",
+    r#type: SearchParamType::Token,
+    expression: Some("(CapabilityStatement.useContext.value.ofType(CodeableConcept))"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-quantity`: A quantity- or range-valued use context assigned to the capability statement
+pub const CONTEXT_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some(
+        "(CapabilityStatement.useContext.value.ofType(Quantity)) | (CapabilityStatement.useContext.value.ofType(Range))",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-type`: A type of use context assigned to the capability statement
+pub const CONTEXT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.useContext.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-type-quantity`: A use context type and quantity- or range-based value assigned to the capability statement
+pub const CONTEXT_TYPE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type-quantity",
+    r#type: SearchParamType::Composite,
+    expression: Some("CapabilityStatement.useContext"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-quantity",
+            expression: "value.ofType(Quantity) | value.ofType(Range)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `context-type-value`: A use context type and value assigned to the capability statement
+pub const CONTEXT_TYPE_VALUE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type-value",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type-value",
+    r#type: SearchParamType::Composite,
+    expression: Some("CapabilityStatement.useContext"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context",
+            expression: "value.ofType(CodeableConcept)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `date`: The capability statement publication date
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-date",
+    r#type: SearchParamType::Date,
+    expression: Some("CapabilityStatement.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `description`: The description of the capability statement
+pub const DESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "description",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-description",
+    r#type: SearchParamType::String,
+    expression: Some("CapabilityStatement.description"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `fhirversion`: The version of FHIR
+pub const FHIRVERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "fhirversion",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-fhirversion",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.fhirVersion"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `format`: formats supported (xml | json | ttl | mime type)
+pub const FORMAT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "format",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-format",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.format"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `guide`: Implementation guides supported
+pub const GUIDE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "guide",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-guide",
+    r#type: SearchParamType::Reference,
+    expression: Some("CapabilityStatement.implementationGuide"),
+    targets: &["ImplementationGuide"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: External identifier for the capability statement
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `jurisdiction`: Intended jurisdiction for the capability statement
+pub const JURISDICTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "jurisdiction",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-jurisdiction",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.jurisdiction"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `mode`: Mode - restful (server/client) or messaging (sender/receiver)
+pub const MODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "mode",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-mode",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.rest.mode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: Computationally friendly name of the capability statement
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-name",
+    r#type: SearchParamType::String,
+    expression: Some("CapabilityStatement.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `publisher`: Name of the publisher of the capability statement
+pub const PUBLISHER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "publisher",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-publisher",
+    r#type: SearchParamType::String,
+    expression: Some("CapabilityStatement.publisher"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `resource`: Name of a resource mentioned in a capability statement
+pub const RESOURCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "resource",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-resource",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.rest.resource.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `resource-profile`: A profile id invoked in a capability statement
+pub const RESOURCE_PROFILE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "resource-profile",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-resource-profile",
+    r#type: SearchParamType::Reference,
+    expression: Some("CapabilityStatement.rest.resource.profile"),
+    targets: &["StructureDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `security-service`: OAuth | SMART-on-FHIR | NTLM | Basic | Kerberos | Certificates
+pub const SECURITY_SERVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "security-service",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-security-service",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.rest.security.service"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `software`: Part of the name of a software application
+pub const SOFTWARE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "software",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-software",
+    r#type: SearchParamType::String,
+    expression: Some("CapabilityStatement.software.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The current status of the capability statement
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-status",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `supported-profile`: Profiles for use cases supported
+pub const SUPPORTED_PROFILE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "supported-profile",
+    url: "http://hl7.org/fhir/SearchParameter/CapabilityStatement-supported-profile",
+    r#type: SearchParamType::Reference,
+    expression: Some("CapabilityStatement.rest.resource.supportedProfile"),
+    targets: &["StructureDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `title`: The human-friendly name of the capability statement
+pub const TITLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "title",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-title",
+    r#type: SearchParamType::String,
+    expression: Some("CapabilityStatement.title"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `url`: The uri that identifies the capability statement
+pub const URL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "url",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-url",
+    r#type: SearchParamType::Uri,
+    expression: Some("CapabilityStatement.url"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `version`: The business version of the capability statement
+pub const VERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "version",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-version",
+    r#type: SearchParamType::Token,
+    expression: Some("CapabilityStatement.version"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of CapabilityStatement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CapabilityStatementSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `context`
+    Context,
+
+    /// `context-quantity`
+    ContextQuantity,
+
+    /// `context-type`
+    ContextType,
+
+    /// `context-type-quantity`
+    ContextTypeQuantity,
+
+    /// `context-type-value`
+    ContextTypeValue,
+
+    /// `date`
+    Date,
+
+    /// `description`
+    Description,
+
+    /// `fhirversion`
+    Fhirversion,
+
+    /// `format`
+    Format,
+
+    /// `guide`
+    Guide,
+
+    /// `identifier`
+    Identifier,
+
+    /// `jurisdiction`
+    Jurisdiction,
+
+    /// `mode`
+    Mode,
+
+    /// `name`
+    Name,
+
+    /// `publisher`
+    Publisher,
+
+    /// `resource`
+    Resource,
+
+    /// `resource-profile`
+    ResourceProfile,
+
+    /// `security-service`
+    SecurityService,
+
+    /// `software`
+    Software,
+
+    /// `status`
+    Status,
+
+    /// `supported-profile`
+    SupportedProfile,
+
+    /// `title`
+    Title,
+
+    /// `url`
+    Url,
+
+    /// `version`
+    Version,
+}
+
+impl CapabilityStatementSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Context,
+        Self::ContextQuantity,
+        Self::ContextType,
+        Self::ContextTypeQuantity,
+        Self::ContextTypeValue,
+        Self::Date,
+        Self::Description,
+        Self::Fhirversion,
+        Self::Format,
+        Self::Guide,
+        Self::Identifier,
+        Self::Jurisdiction,
+        Self::Mode,
+        Self::Name,
+        Self::Publisher,
+        Self::Resource,
+        Self::ResourceProfile,
+        Self::SecurityService,
+        Self::Software,
+        Self::Status,
+        Self::SupportedProfile,
+        Self::Title,
+        Self::Url,
+        Self::Version,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Context => &CONTEXT,
+            Self::ContextQuantity => &CONTEXT_QUANTITY,
+            Self::ContextType => &CONTEXT_TYPE,
+            Self::ContextTypeQuantity => &CONTEXT_TYPE_QUANTITY,
+            Self::ContextTypeValue => &CONTEXT_TYPE_VALUE,
+            Self::Date => &DATE,
+            Self::Description => &DESCRIPTION,
+            Self::Fhirversion => &FHIRVERSION,
+            Self::Format => &FORMAT,
+            Self::Guide => &GUIDE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Jurisdiction => &JURISDICTION,
+            Self::Mode => &MODE,
+            Self::Name => &NAME,
+            Self::Publisher => &PUBLISHER,
+            Self::Resource => &RESOURCE,
+            Self::ResourceProfile => &RESOURCE_PROFILE,
+            Self::SecurityService => &SECURITY_SERVICE,
+            Self::Software => &SOFTWARE,
+            Self::Status => &STATUS,
+            Self::SupportedProfile => &SUPPORTED_PROFILE,
+            Self::Title => &TITLE,
+            Self::Url => &URL,
+            Self::Version => &VERSION,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for CapabilityStatementSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = CapabilityStatementSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
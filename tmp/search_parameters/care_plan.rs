@@ -0,0 +1,466 @@
+//! CarePlan search parameters
+
+use super::*;
+
+/// `activity-reference`: Activity that is intended to be part of the care plan
+pub const ACTIVITY_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "activity-reference",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-activity-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.activity.plannedActivityReference"),
+    targets: &[
+        "Appointment",
+        "RequestOrchestration",
+        "DeviceRequest",
+        "ServiceRequest",
+        "CommunicationRequest",
+        "Task",
+        "MedicationRequest",
+        "SupplyRequest",
+        "ImmunizationRecommendation",
+        "NutritionOrder",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `based-on`: Fulfills CarePlan
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.basedOn"),
+    targets: &["RequestOrchestration", "ServiceRequest", "CarePlan", "NutritionOrder"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `care-team`: Who's involved in plan?
+pub const CARE_TEAM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "care-team",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-care-team",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.careTeam"),
+    targets: &["CareTeam"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Type of plan
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-category",
+    r#type: SearchParamType::Token,
+    expression: Some("CarePlan.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `condition`: Reference to a resource (by instance)
+pub const CONDITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "condition",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-condition",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.addresses.reference"),
+    targets: &["Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `custodian`: Who is the designated responsible party
+pub const CUSTODIAN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "custodian",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-custodian",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.custodian"),
+    targets: &[
+        "Device",
+        "Organization",
+        "CareTeam",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: Time period plan covers
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("CarePlan.period"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: The Encounter during which this CarePlan was created
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `goal`: Desired outcome of plan
+pub const GOAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "goal",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-goal",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.goal"),
+    targets: &["Goal"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: External Ids for this plan
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("CarePlan.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-canonical`: Instantiates FHIR protocol or definition
+pub const INSTANTIATES_CANONICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-canonical",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-instantiates-canonical",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.instantiatesCanonical"),
+    targets: &[
+        "Measure",
+        "OperationDefinition",
+        "ActivityDefinition",
+        "PlanDefinition",
+        "Questionnaire",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-uri`: Instantiates external protocol or definition
+pub const INSTANTIATES_URI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-uri",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-instantiates-uri",
+    r#type: SearchParamType::Uri,
+    expression: Some("CarePlan.instantiatesUri"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `intent`: proposal | plan | order | option | directive
+pub const INTENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "intent",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-intent",
+    r#type: SearchParamType::Token,
+    expression: Some("CarePlan.intent"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `part-of`: Part of referenced CarePlan
+pub const PART_OF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "part-of",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-part-of",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.partOf"),
+    targets: &["CarePlan"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Who the care plan is for
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `replaces`: CarePlan replaced by this CarePlan
+pub const REPLACES: SearchParameterDefinition = SearchParameterDefinition {
+    code: "replaces",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-replaces",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.replaces"),
+    targets: &["CarePlan"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: draft | active | on-hold | revoked | completed | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-status",
+    r#type: SearchParamType::Token,
+    expression: Some("CarePlan.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Who the care plan is for
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/CarePlan-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("CarePlan.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of CarePlan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CarePlanSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `activity-reference`
+    ActivityReference,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `care-team`
+    CareTeam,
+
+    /// `category`
+    Category,
+
+    /// `condition`
+    Condition,
+
+    /// `custodian`
+    Custodian,
+
+    /// `date`
+    Date,
+
+    /// `encounter`
+    Encounter,
+
+    /// `goal`
+    Goal,
+
+    /// `identifier`
+    Identifier,
+
+    /// `instantiates-canonical`
+    InstantiatesCanonical,
+
+    /// `instantiates-uri`
+    InstantiatesUri,
+
+    /// `intent`
+    Intent,
+
+    /// `part-of`
+    PartOf,
+
+    /// `patient`
+    Patient,
+
+    /// `replaces`
+    Replaces,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl CarePlanSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::ActivityReference,
+        Self::BasedOn,
+        Self::CareTeam,
+        Self::Category,
+        Self::Condition,
+        Self::Custodian,
+        Self::Date,
+        Self::Encounter,
+        Self::Goal,
+        Self::Identifier,
+        Self::InstantiatesCanonical,
+        Self::InstantiatesUri,
+        Self::Intent,
+        Self::PartOf,
+        Self::Patient,
+        Self::Replaces,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::ActivityReference => &ACTIVITY_REFERENCE,
+            Self::BasedOn => &BASED_ON,
+            Self::CareTeam => &CARE_TEAM,
+            Self::Category => &CATEGORY,
+            Self::Condition => &CONDITION,
+            Self::Custodian => &CUSTODIAN,
+            Self::Date => &DATE,
+            Self::Encounter => &ENCOUNTER,
+            Self::Goal => &GOAL,
+            Self::Identifier => &IDENTIFIER,
+            Self::InstantiatesCanonical => &INSTANTIATES_CANONICAL,
+            Self::InstantiatesUri => &INSTANTIATES_URI,
+            Self::Intent => &INTENT,
+            Self::PartOf => &PART_OF,
+            Self::Patient => &PATIENT,
+            Self::Replaces => &REPLACES,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for CarePlanSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = CarePlanSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
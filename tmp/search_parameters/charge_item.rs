@@ -0,0 +1,509 @@
+//! ChargeItem search parameters
+
+use super::*;
+
+/// `account`: Account to place this charge
+pub const ACCOUNT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "account",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-account",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.account"),
+    targets: &["Account"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: A code that identifies the charge, like a billing code
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("ChargeItem.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Encounter associated with event
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `entered-date`: Date the charge item was entered
+pub const ENTERED_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "entered-date",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-entered-date",
+    r#type: SearchParamType::Date,
+    expression: Some("ChargeItem.enteredDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `enterer`: Individual who was entering
+pub const ENTERER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "enterer",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-enterer",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.enterer"),
+    targets: &[
+        "Organization",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `factor-override`: Factor overriding the associated rules
+pub const FACTOR_OVERRIDE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "factor-override",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-factor-override",
+    r#type: SearchParamType::Number,
+    expression: Some("ChargeItem.totalPriceComponent.factor"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business Identifier for item
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ChargeItem.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `occurrence`: When the charged service was applied
+pub const OCCURRENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "occurrence",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-occurrence",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "ChargeItem.occurrence.ofType(dateTime) | ChargeItem.occurrence.ofType(Period) | ChargeItem.occurrence.ofType(Timing)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Individual service was done for/to
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer-actor`: Individual who was performing
+pub const PERFORMER_ACTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer-actor",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-performer-actor",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.performer.actor"),
+    targets: &[
+        "HealthcareService",
+        "Organization",
+        "CareTeam",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer-function`: What type of performance was done
+pub const PERFORMER_FUNCTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer-function",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-performer-function",
+    r#type: SearchParamType::Token,
+    expression: Some("ChargeItem.performer.function"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performing-organization`: Organization providing the charged service
+pub const PERFORMING_ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performing-organization",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-performing-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.performingOrganization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `price-override`: Price overriding the associated rules
+pub const PRICE_OVERRIDE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "price-override",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-price-override",
+    r#type: SearchParamType::Quantity,
+    expression: Some("ChargeItem.totalPriceComponent.amount"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `quantity`: Quantity of which the charge item has been serviced
+pub const QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "quantity",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some("ChargeItem.quantity"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requesting-organization`: Organization requesting the charged service
+pub const REQUESTING_ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requesting-organization",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-requesting-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.requestingOrganization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service`: Which rendered service is being charged?
+pub const SERVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-service",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.service.reference"),
+    targets: &[
+        "SupplyDelivery",
+        "ImagingStudy",
+        "Procedure",
+        "Observation",
+        "ServiceRequest",
+        "MedicationAdministration",
+        "DiagnosticReport",
+        "Immunization",
+        "MedicationRequest",
+        "MedicationDispense",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Is this charge item active
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ChargeItem.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Individual service was done for/to
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/ChargeItem-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("ChargeItem.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ChargeItem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChargeItemSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `account`
+    Account,
+
+    /// `code`
+    Code,
+
+    /// `encounter`
+    Encounter,
+
+    /// `entered-date`
+    EnteredDate,
+
+    /// `enterer`
+    Enterer,
+
+    /// `factor-override`
+    FactorOverride,
+
+    /// `identifier`
+    Identifier,
+
+    /// `occurrence`
+    Occurrence,
+
+    /// `patient`
+    Patient,
+
+    /// `performer-actor`
+    PerformerActor,
+
+    /// `performer-function`
+    PerformerFunction,
+
+    /// `performing-organization`
+    PerformingOrganization,
+
+    /// `price-override`
+    PriceOverride,
+
+    /// `quantity`
+    Quantity,
+
+    /// `requesting-organization`
+    RequestingOrganization,
+
+    /// `service`
+    Service,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl ChargeItemSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Account,
+        Self::Code,
+        Self::Encounter,
+        Self::EnteredDate,
+        Self::Enterer,
+        Self::FactorOverride,
+        Self::Identifier,
+        Self::Occurrence,
+        Self::Patient,
+        Self::PerformerActor,
+        Self::PerformerFunction,
+        Self::PerformingOrganization,
+        Self::PriceOverride,
+        Self::Quantity,
+        Self::RequestingOrganization,
+        Self::Service,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Account => &ACCOUNT,
+            Self::Code => &CODE,
+            Self::Encounter => &ENCOUNTER,
+            Self::EnteredDate => &ENTERED_DATE,
+            Self::Enterer => &ENTERER,
+            Self::FactorOverride => &FACTOR_OVERRIDE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Occurrence => &OCCURRENCE,
+            Self::Patient => &PATIENT,
+            Self::PerformerActor => &PERFORMER_ACTOR,
+            Self::PerformerFunction => &PERFORMER_FUNCTION,
+            Self::PerformingOrganization => &PERFORMING_ORGANIZATION,
+            Self::PriceOverride => &PRICE_OVERRIDE,
+            Self::Quantity => &QUANTITY,
+            Self::RequestingOrganization => &REQUESTING_ORGANIZATION,
+            Self::Service => &SERVICE,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ChargeItemSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ChargeItemSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,506 @@
+//! Citation search parameters
+
+use super::*;
+
+/// `classification`: A classification type and value assigned to the citation
+pub const CLASSIFICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "classification",
+    url: "http://hl7.org/fhir/SearchParameter/Citation-classification",
+    r#type: SearchParamType::Composite,
+    expression: Some("Citation.classification"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Citation-classification-type",
+            expression: "type",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Citation-classifier",
+            expression: "classifier",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `classification-type`: A type of classification assigned to the citation
+pub const CLASSIFICATION_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "classification-type",
+    url: "http://hl7.org/fhir/SearchParameter/Citation-classification-type",
+    r#type: SearchParamType::Token,
+    expression: Some("(Citation.classification.type)"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `classifier`: A classifier assigned to the citation
+pub const CLASSIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "classifier",
+    url: "http://hl7.org/fhir/SearchParameter/Citation-classifier",
+    r#type: SearchParamType::Token,
+    expression: Some("(Citation.classification.classifier)"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context`: A use context assigned to the citation
+pub const CONTEXT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context",
+    r#type: SearchParamType::Token,
+    expression: Some("(Citation.useContext.value.ofType(CodeableConcept))"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-quantity`: A quantity- or range-valued use context assigned to the citation
+pub const CONTEXT_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some(
+        "(Citation.useContext.value.ofType(Quantity)) | (Citation.useContext.value.ofType(Range))",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-type`: A type of use context assigned to the citation
+pub const CONTEXT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Citation.useContext.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-type-quantity`: A use context type and quantity- or range-based value assigned to the citation
+pub const CONTEXT_TYPE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type-quantity",
+    r#type: SearchParamType::Composite,
+    expression: Some("Citation.useContext"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-quantity",
+            expression: "value.ofType(Quantity) | value.ofType(Range)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `context-type-value`: A use context type and value assigned to the citation
+pub const CONTEXT_TYPE_VALUE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type-value",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type-value",
+    r#type: SearchParamType::Composite,
+    expression: Some("Citation.useContext"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context",
+            expression: "value.ofType(CodeableConcept)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `date`: The citation publication date
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Citation.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `description`: The description of the citation
+pub const DESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "description",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-description",
+    r#type: SearchParamType::String,
+    expression: Some("Citation.description"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `effective`: The time during which the citation is intended to be in use
+pub const EFFECTIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "effective",
+    url: "http://hl7.org/fhir/SearchParameter/MetadataResource-effective",
+    r#type: SearchParamType::Date,
+    expression: Some("Citation.effectivePeriod"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: External identifier for the citation
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Citation.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `jurisdiction`: Intended jurisdiction for the citation
+pub const JURISDICTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "jurisdiction",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-jurisdiction",
+    r#type: SearchParamType::Token,
+    expression: Some("Citation.jurisdiction"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: Computationally friendly name of the citation
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-name",
+    r#type: SearchParamType::String,
+    expression: Some("Citation.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `publisher`: Name of the publisher of the citation
+pub const PUBLISHER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "publisher",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-publisher",
+    r#type: SearchParamType::String,
+    expression: Some("Citation.publisher"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The current status of the citation
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Citation.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `title`: The human-friendly name of the citation
+pub const TITLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "title",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-title",
+    r#type: SearchParamType::String,
+    expression: Some("Citation.title"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `url`: The uri that identifies the citation
+pub const URL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "url",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-url",
+    r#type: SearchParamType::Uri,
+    expression: Some("Citation.url"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `version`: The business version of the citation
+pub const VERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "version",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-version",
+    r#type: SearchParamType::Token,
+    expression: Some("Citation.version"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Citation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CitationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `classification`
+    Classification,
+
+    /// `classification-type`
+    ClassificationType,
+
+    /// `classifier`
+    Classifier,
+
+    /// `context`
+    Context,
+
+    /// `context-quantity`
+    ContextQuantity,
+
+    /// `context-type`
+    ContextType,
+
+    /// `context-type-quantity`
+    ContextTypeQuantity,
+
+    /// `context-type-value`
+    ContextTypeValue,
+
+    /// `date`
+    Date,
+
+    /// `description`
+    Description,
+
+    /// `effective`
+    Effective,
+
+    /// `identifier`
+    Identifier,
+
+    /// `jurisdiction`
+    Jurisdiction,
+
+    /// `name`
+    Name,
+
+    /// `publisher`
+    Publisher,
+
+    /// `status`
+    Status,
+
+    /// `title`
+    Title,
+
+    /// `url`
+    Url,
+
+    /// `version`
+    Version,
+}
+
+impl CitationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Classification,
+        Self::ClassificationType,
+        Self::Classifier,
+        Self::Context,
+        Self::ContextQuantity,
+        Self::ContextType,
+        Self::ContextTypeQuantity,
+        Self::ContextTypeValue,
+        Self::Date,
+        Self::Description,
+        Self::Effective,
+        Self::Identifier,
+        Self::Jurisdiction,
+        Self::Name,
+        Self::Publisher,
+        Self::Status,
+        Self::Title,
+        Self::Url,
+        Self::Version,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Classification => &CLASSIFICATION,
+            Self::ClassificationType => &CLASSIFICATION_TYPE,
+            Self::Classifier => &CLASSIFIER,
+            Self::Context => &CONTEXT,
+            Self::ContextQuantity => &CONTEXT_QUANTITY,
+            Self::ContextType => &CONTEXT_TYPE,
+            Self::ContextTypeQuantity => &CONTEXT_TYPE_QUANTITY,
+            Self::ContextTypeValue => &CONTEXT_TYPE_VALUE,
+            Self::Date => &DATE,
+            Self::Description => &DESCRIPTION,
+            Self::Effective => &EFFECTIVE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Jurisdiction => &JURISDICTION,
+            Self::Name => &NAME,
+            Self::Publisher => &PUBLISHER,
+            Self::Status => &STATUS,
+            Self::Title => &TITLE,
+            Self::Url => &URL,
+            Self::Version => &VERSION,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for CitationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = CitationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,429 @@
+//! Claim search parameters
+
+use super::*;
+
+/// `care-team`: Member of the CareTeam
+pub const CARE_TEAM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "care-team",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-care-team",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.careTeam.provider"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `created`: The creation date for the Claim
+pub const CREATED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "created",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-created",
+    r#type: SearchParamType::Date,
+    expression: Some("Claim.created"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `detail-udi`: UDI associated with a line item, detail product or service
+pub const DETAIL_UDI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "detail-udi",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-detail-udi",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.item.detail.udi"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Encounters associated with a billed line item
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.item.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `enterer`: The party responsible for the entry of the Claim
+pub const ENTERER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "enterer",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-enterer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.enterer"),
+    targets: &["RelatedPerson", "PractitionerRole", "Practitioner", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `facility`: Facility where the products or services have been or will be provided
+pub const FACILITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "facility",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-facility",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.facility"),
+    targets: &["Organization", "Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The primary identifier of the financial resource
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Claim.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `insurer`: The target payor/insurer for the Claim
+pub const INSURER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "insurer",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-insurer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.insurer"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `item-udi`: UDI associated with a line item product or service
+pub const ITEM_UDI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "item-udi",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-item-udi",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.item.udi"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Patient receiving the products or services
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `payee`: The party receiving any payment for the Claim
+pub const PAYEE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "payee",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-payee",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.payee.party"),
+    targets: &[
+        "Organization",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `priority`: Processing priority requested
+pub const PRIORITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "priority",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-priority",
+    r#type: SearchParamType::Token,
+    expression: Some("Claim.priority"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `procedure-udi`: UDI associated with a procedure
+pub const PROCEDURE_UDI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "procedure-udi",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-procedure-udi",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.procedure.udi"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `provider`: Provider responsible for the Claim
+pub const PROVIDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "provider",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-provider",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.provider"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the Claim instance.
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Claim.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subdetail-udi`: UDI associated with a line item, detail, subdetail product or service
+pub const SUBDETAIL_UDI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subdetail-udi",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-subdetail-udi",
+    r#type: SearchParamType::Reference,
+    expression: Some("Claim.item.detail.subDetail.udi"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `use`: The kind of financial resource
+pub const USE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "use",
+    url: "http://hl7.org/fhir/SearchParameter/Claim-use",
+    r#type: SearchParamType::Token,
+    expression: Some("Claim.use"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Claim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClaimSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `care-team`
+    CareTeam,
+
+    /// `created`
+    Created,
+
+    /// `detail-udi`
+    DetailUdi,
+
+    /// `encounter`
+    Encounter,
+
+    /// `enterer`
+    Enterer,
+
+    /// `facility`
+    Facility,
+
+    /// `identifier`
+    Identifier,
+
+    /// `insurer`
+    Insurer,
+
+    /// `item-udi`
+    ItemUdi,
+
+    /// `patient`
+    Patient,
+
+    /// `payee`
+    Payee,
+
+    /// `priority`
+    Priority,
+
+    /// `procedure-udi`
+    ProcedureUdi,
+
+    /// `provider`
+    Provider,
+
+    /// `status`
+    Status,
+
+    /// `subdetail-udi`
+    SubdetailUdi,
+
+    /// `use`
+    Use,
+}
+
+impl ClaimSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::CareTeam,
+        Self::Created,
+        Self::DetailUdi,
+        Self::Encounter,
+        Self::Enterer,
+        Self::Facility,
+        Self::Identifier,
+        Self::Insurer,
+        Self::ItemUdi,
+        Self::Patient,
+        Self::Payee,
+        Self::Priority,
+        Self::ProcedureUdi,
+        Self::Provider,
+        Self::Status,
+        Self::SubdetailUdi,
+        Self::Use,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::CareTeam => &CARE_TEAM,
+            Self::Created => &CREATED,
+            Self::DetailUdi => &DETAIL_UDI,
+            Self::Encounter => &ENCOUNTER,
+            Self::Enterer => &ENTERER,
+            Self::Facility => &FACILITY,
+            Self::Identifier => &IDENTIFIER,
+            Self::Insurer => &INSURER,
+            Self::ItemUdi => &ITEM_UDI,
+            Self::Patient => &PATIENT,
+            Self::Payee => &PAYEE,
+            Self::Priority => &PRIORITY,
+            Self::ProcedureUdi => &PROCEDURE_UDI,
+            Self::Provider => &PROVIDER,
+            Self::Status => &STATUS,
+            Self::SubdetailUdi => &SUBDETAIL_UDI,
+            Self::Use => &USE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ClaimSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ClaimSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
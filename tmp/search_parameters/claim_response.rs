@@ -0,0 +1,331 @@
+//! ClaimResponse search parameters
+
+use super::*;
+
+/// `created`: The creation date
+pub const CREATED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "created",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-created",
+    r#type: SearchParamType::Date,
+    expression: Some("ClaimResponse.created"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `disposition`: The contents of the disposition message
+pub const DISPOSITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "disposition",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-disposition",
+    r#type: SearchParamType::String,
+    expression: Some("ClaimResponse.disposition"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The identity of the ClaimResponse
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ClaimResponse.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `insurer`: The organization which generated this resource
+pub const INSURER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "insurer",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-insurer",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClaimResponse.insurer"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `outcome`: The processing outcome
+pub const OUTCOME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "outcome",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-outcome",
+    r#type: SearchParamType::Token,
+    expression: Some("ClaimResponse.outcome"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The subject of care
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClaimResponse.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `payment-date`: The expected payment date
+pub const PAYMENT_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "payment-date",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-payment-date",
+    r#type: SearchParamType::Date,
+    expression: Some("ClaimResponse.payment.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `request`: The claim reference
+pub const REQUEST: SearchParameterDefinition = SearchParameterDefinition {
+    code: "request",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-request",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClaimResponse.request"),
+    targets: &["Claim"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requestor`: The Provider of the claim
+pub const REQUESTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requestor",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-requestor",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClaimResponse.requestor"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the ClaimResponse
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ClaimResponse.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `use`: The type of claim
+pub const USE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "use",
+    url: "http://hl7.org/fhir/SearchParameter/ClaimResponse-use",
+    r#type: SearchParamType::Token,
+    expression: Some("ClaimResponse.use"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ClaimResponse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClaimResponseSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `created`
+    Created,
+
+    /// `disposition`
+    Disposition,
+
+    /// `identifier`
+    Identifier,
+
+    /// `insurer`
+    Insurer,
+
+    /// `outcome`
+    Outcome,
+
+    /// `patient`
+    Patient,
+
+    /// `payment-date`
+    PaymentDate,
+
+    /// `request`
+    Request,
+
+    /// `requestor`
+    Requestor,
+
+    /// `status`
+    Status,
+
+    /// `use`
+    Use,
+}
+
+impl ClaimResponseSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Created,
+        Self::Disposition,
+        Self::Identifier,
+        Self::Insurer,
+        Self::Outcome,
+        Self::Patient,
+        Self::PaymentDate,
+        Self::Request,
+        Self::Requestor,
+        Self::Status,
+        Self::Use,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Created => &CREATED,
+            Self::Disposition => &DISPOSITION,
+            Self::Identifier => &IDENTIFIER,
+            Self::Insurer => &INSURER,
+            Self::Outcome => &OUTCOME,
+            Self::Patient => &PATIENT,
+            Self::PaymentDate => &PAYMENT_DATE,
+            Self::Request => &REQUEST,
+            Self::Requestor => &REQUESTOR,
+            Self::Status => &STATUS,
+            Self::Use => &USE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ClaimResponseSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ClaimResponseSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
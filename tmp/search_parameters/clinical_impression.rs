@@ -0,0 +1,497 @@
+//! ClinicalImpression search parameters
+
+use super::*;
+
+/// `date`: When the assessment was documented
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("ClinicalImpression.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: The Encounter during which this ClinicalImpression was created
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalImpression.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `finding-code`: Reference to a concept (by class)
+pub const FINDING_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "finding-code",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalImpression-finding-code",
+    r#type: SearchParamType::Token,
+    expression: Some("ClinicalImpression.finding.item.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `finding-ref`: Reference to a resource (by instance)
+pub const FINDING_REF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "finding-ref",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalImpression-finding-ref",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalImpression.finding.item.reference"),
+    targets: &["DocumentReference", "Observation", "Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ClinicalImpression.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Patient assessed
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalImpression.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: The clinician performing the assessment
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalImpression-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalImpression.performer"),
+    targets: &["PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `previous`: Reference to last assessment
+pub const PREVIOUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "previous",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalImpression-previous",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalImpression.previous"),
+    targets: &["ClinicalImpression"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `problem`: Relevant impressions of patient state
+pub const PROBLEM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "problem",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalImpression-problem",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalImpression.problem"),
+    targets: &["AllergyIntolerance", "Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: preparation | in-progress | not-done | on-hold | stopped | completed | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalImpression-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ClinicalImpression.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Patient or group assessed
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalImpression-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalImpression.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `supporting-info`: Information supporting the clinical impression
+pub const SUPPORTING_INFO: SearchParameterDefinition = SearchParameterDefinition {
+    code: "supporting-info",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalImpression-supporting-info",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalImpression.supportingInfo"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ClinicalImpression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClinicalImpressionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `date`
+    Date,
+
+    /// `encounter`
+    Encounter,
+
+    /// `finding-code`
+    FindingCode,
+
+    /// `finding-ref`
+    FindingRef,
+
+    /// `identifier`
+    Identifier,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `previous`
+    Previous,
+
+    /// `problem`
+    Problem,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `supporting-info`
+    SupportingInfo,
+}
+
+impl ClinicalImpressionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Date,
+        Self::Encounter,
+        Self::FindingCode,
+        Self::FindingRef,
+        Self::Identifier,
+        Self::Patient,
+        Self::Performer,
+        Self::Previous,
+        Self::Problem,
+        Self::Status,
+        Self::Subject,
+        Self::SupportingInfo,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Date => &DATE,
+            Self::Encounter => &ENCOUNTER,
+            Self::FindingCode => &FINDING_CODE,
+            Self::FindingRef => &FINDING_REF,
+            Self::Identifier => &IDENTIFIER,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::Previous => &PREVIOUS,
+            Self::Problem => &PROBLEM,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::SupportingInfo => &SUPPORTING_INFO,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ClinicalImpressionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ClinicalImpressionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
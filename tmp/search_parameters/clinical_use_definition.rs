@@ -0,0 +1,350 @@
+//! ClinicalUseDefinition search parameters
+
+use super::*;
+
+/// `contraindication`: The situation that is being documented as contraindicating against this item, as a code
+pub const CONTRAINDICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "contraindication",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-contraindication",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "ClinicalUseDefinition.contraindication.diseaseSymptomProcedure.concept",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `contraindication-reference`: The situation that is being documented as contraindicating against this item, as a reference
+pub const CONTRAINDICATION_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "contraindication-reference",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-contraindication-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ClinicalUseDefinition.contraindication.diseaseSymptomProcedure.reference",
+    ),
+    targets: &["ObservationDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `effect`: The situation in which the undesirable effect may manifest, as a code
+pub const EFFECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "effect",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-effect",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "ClinicalUseDefinition.undesirableEffect.symptomConditionEffect.concept",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `effect-reference`: The situation in which the undesirable effect may manifest, as a reference
+pub const EFFECT_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "effect-reference",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-effect-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ClinicalUseDefinition.undesirableEffect.symptomConditionEffect.reference",
+    ),
+    targets: &["ObservationDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business identifier for this issue
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ClinicalUseDefinition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `indication`: The situation that is being documented as an indicaton for this item, as a code
+pub const INDICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "indication",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-indication",
+    r#type: SearchParamType::Token,
+    expression: Some("ClinicalUseDefinition.indication.diseaseSymptomProcedure.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `indication-reference`: The situation that is being documented as an indicaton for this item, as a reference
+pub const INDICATION_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "indication-reference",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-indication-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ClinicalUseDefinition.indication.diseaseSymptomProcedure.reference",
+    ),
+    targets: &["ObservationDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `interaction`: The type of the interaction e.g. drug-drug interaction, drug-food interaction, drug-lab test interaction
+pub const INTERACTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "interaction",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-interaction",
+    r#type: SearchParamType::Token,
+    expression: Some("ClinicalUseDefinition.interaction.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `product`: The medicinal product for which this is a clinical usage issue
+pub const PRODUCT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "product",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-product",
+    r#type: SearchParamType::Reference,
+    expression: Some(
+        "ClinicalUseDefinition.subject.where(resolve() is MedicinalProductDefinition)",
+    ),
+    targets: &["MedicinalProductDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Whether this is a current issue or one that has been retired etc.
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ClinicalUseDefinition.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The resource for which this is a clinical usage issue
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("ClinicalUseDefinition.subject"),
+    targets: &[
+        "MedicinalProductDefinition",
+        "Device",
+        "DeviceDefinition",
+        "NutritionProduct",
+        "ActivityDefinition",
+        "PlanDefinition",
+        "BiologicallyDerivedProduct",
+        "Substance",
+        "Medication",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: indication | contraindication | interaction | undesirable-effect | warning
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/ClinicalUseDefinition-type",
+    r#type: SearchParamType::Token,
+    expression: Some("ClinicalUseDefinition.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ClinicalUseDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClinicalUseDefinitionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `contraindication`
+    Contraindication,
+
+    /// `contraindication-reference`
+    ContraindicationReference,
+
+    /// `effect`
+    Effect,
+
+    /// `effect-reference`
+    EffectReference,
+
+    /// `identifier`
+    Identifier,
+
+    /// `indication`
+    Indication,
+
+    /// `indication-reference`
+    IndicationReference,
+
+    /// `interaction`
+    Interaction,
+
+    /// `product`
+    Product,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `type`
+    Type,
+}
+
+impl ClinicalUseDefinitionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Contraindication,
+        Self::ContraindicationReference,
+        Self::Effect,
+        Self::EffectReference,
+        Self::Identifier,
+        Self::Indication,
+        Self::IndicationReference,
+        Self::Interaction,
+        Self::Product,
+        Self::Status,
+        Self::Subject,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Contraindication => &CONTRAINDICATION,
+            Self::ContraindicationReference => &CONTRAINDICATION_REFERENCE,
+            Self::Effect => &EFFECT,
+            Self::EffectReference => &EFFECT_REFERENCE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Indication => &INDICATION,
+            Self::IndicationReference => &INDICATION_REFERENCE,
+            Self::Interaction => &INTERACTION,
+            Self::Product => &PRODUCT,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ClinicalUseDefinitionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ClinicalUseDefinitionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,604 @@
+//! CommunicationRequest search parameters
+
+use super::*;
+
+/// `authored`: When request transitioned to being actionable
+pub const AUTHORED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "authored",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-authored",
+    r#type: SearchParamType::Date,
+    expression: Some("CommunicationRequest.authoredOn"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `based-on`: Fulfills plan or proposal
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("CommunicationRequest.basedOn"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Message category
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-category",
+    r#type: SearchParamType::Token,
+    expression: Some("CommunicationRequest.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: The Encounter during which this CommunicationRequest was created
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("CommunicationRequest.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `group-identifier`: Composite request this is part of
+pub const GROUP_IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "group-identifier",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-group-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("CommunicationRequest.groupIdentifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Unique identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("CommunicationRequest.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `information-provider`: Who should share the information
+pub const INFORMATION_PROVIDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "information-provider",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-information-provider",
+    r#type: SearchParamType::Reference,
+    expression: Some("CommunicationRequest.informationProvider"),
+    targets: &[
+        "HealthcareService",
+        "Device",
+        "Organization",
+        "Endpoint",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `medium`: A channel of communication
+pub const MEDIUM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "medium",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-medium",
+    r#type: SearchParamType::Token,
+    expression: Some("CommunicationRequest.medium"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `occurrence`: When scheduled
+pub const OCCURRENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "occurrence",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-occurrence",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "CommunicationRequest.occurrence.ofType(dateTime) | CommunicationRequest.occurrence.ofType(Period)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Focus of message
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("CommunicationRequest.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `priority`: routine | urgent | asap | stat
+pub const PRIORITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "priority",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-priority",
+    r#type: SearchParamType::Token,
+    expression: Some("CommunicationRequest.priority"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `recipient`: Who to share the information with
+pub const RECIPIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "recipient",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-recipient",
+    r#type: SearchParamType::Reference,
+    expression: Some("CommunicationRequest.recipient"),
+    targets: &[
+        "HealthcareService",
+        "Device",
+        "Organization",
+        "CareTeam",
+        "Endpoint",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Group",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `replaces`: Request(s) replaced by this request
+pub const REPLACES: SearchParameterDefinition = SearchParameterDefinition {
+    code: "replaces",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-replaces",
+    r#type: SearchParamType::Reference,
+    expression: Some("CommunicationRequest.replaces"),
+    targets: &["CommunicationRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requester`: Who asks for the information to be shared
+pub const REQUESTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requester",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-requester",
+    r#type: SearchParamType::Reference,
+    expression: Some("CommunicationRequest.requester"),
+    targets: &[
+        "Organization",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: draft | active | on-hold | revoked | completed | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-status",
+    r#type: SearchParamType::Token,
+    expression: Some("CommunicationRequest.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Focus of message
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/CommunicationRequest-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("CommunicationRequest.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of CommunicationRequest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CommunicationRequestSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `authored`
+    Authored,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `category`
+    Category,
+
+    /// `encounter`
+    Encounter,
+
+    /// `group-identifier`
+    GroupIdentifier,
+
+    /// `identifier`
+    Identifier,
+
+    /// `information-provider`
+    InformationProvider,
+
+    /// `medium`
+    Medium,
+
+    /// `occurrence`
+    Occurrence,
+
+    /// `patient`
+    Patient,
+
+    /// `priority`
+    Priority,
+
+    /// `recipient`
+    Recipient,
+
+    /// `replaces`
+    Replaces,
+
+    /// `requester`
+    Requester,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl CommunicationRequestSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Authored,
+        Self::BasedOn,
+        Self::Category,
+        Self::Encounter,
+        Self::GroupIdentifier,
+        Self::Identifier,
+        Self::InformationProvider,
+        Self::Medium,
+        Self::Occurrence,
+        Self::Patient,
+        Self::Priority,
+        Self::Recipient,
+        Self::Replaces,
+        Self::Requester,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Authored => &AUTHORED,
+            Self::BasedOn => &BASED_ON,
+            Self::Category => &CATEGORY,
+            Self::Encounter => &ENCOUNTER,
+            Self::GroupIdentifier => &GROUP_IDENTIFIER,
+            Self::Identifier => &IDENTIFIER,
+            Self::InformationProvider => &INFORMATION_PROVIDER,
+            Self::Medium => &MEDIUM,
+            Self::Occurrence => &OCCURRENCE,
+            Self::Patient => &PATIENT,
+            Self::Priority => &PRIORITY,
+            Self::Recipient => &RECIPIENT,
+            Self::Replaces => &REPLACES,
+            Self::Requester => &REQUESTER,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for CommunicationRequestSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = CommunicationRequestSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
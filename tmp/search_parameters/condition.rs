@@ -0,0 +1,721 @@
+//! Condition search parameters
+
+use super::*;
+
+/// `abatement-age`: Abatement as age or age range
+pub const ABATEMENT_AGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "abatement-age",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-abatement-age",
+    r#type: SearchParamType::Quantity,
+    expression: Some(
+        "Condition.abatement.ofType(Age) | Condition.abatement.ofType(Range)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `abatement-date`: Date-related abatements (dateTime and period)
+pub const ABATEMENT_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "abatement-date",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-abatement-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "Condition.abatement.ofType(dateTime) | Condition.abatement.ofType(Period)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `abatement-string`: Abatement as a string
+pub const ABATEMENT_STRING: SearchParameterDefinition = SearchParameterDefinition {
+    code: "abatement-string",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-abatement-string",
+    r#type: SearchParamType::String,
+    expression: Some("Condition.abatement.ofType(string)"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `body-site`: Anatomical location, if relevant
+pub const BODY_SITE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "body-site",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-body-site",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.bodySite"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: The category of the condition
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `clinical-status`: The clinical status of the condition
+pub const CLINICAL_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "clinical-status",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-clinical-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.clinicalStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: Code for the condition
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: The Encounter during which this Condition was created
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("Condition.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `evidence`: Manifestation/symptom
+pub const EVIDENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "evidence",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-evidence",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.evidence.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `evidence-detail`: Supporting information found elsewhere
+pub const EVIDENCE_DETAIL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "evidence-detail",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-evidence-detail",
+    r#type: SearchParamType::Reference,
+    expression: Some("Condition.evidence.reference"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A unique identifier of the condition record
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `onset-age`: Onsets as age or age range
+pub const ONSET_AGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "onset-age",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-onset-age",
+    r#type: SearchParamType::Quantity,
+    expression: Some("Condition.onset.ofType(Age) | Condition.onset.ofType(Range)"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `onset-date`: Date related onsets (dateTime and Period)
+pub const ONSET_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "onset-date",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-onset-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "Condition.onset.ofType(dateTime) | Condition.onset.ofType(Period)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `onset-info`: Onsets as a string
+pub const ONSET_INFO: SearchParameterDefinition = SearchParameterDefinition {
+    code: "onset-info",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-onset-info",
+    r#type: SearchParamType::String,
+    expression: Some("Condition.onset.ofType(string)"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `participant-actor`: Who or what participated in the activities related to the condition
+pub const PARTICIPANT_ACTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "participant-actor",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-participant-actor",
+    r#type: SearchParamType::Reference,
+    expression: Some("Condition.participant.actor"),
+    targets: &[
+        "Device",
+        "Organization",
+        "CareTeam",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `participant-function`: Type of involvement of the actor in the activities related to the condition
+pub const PARTICIPANT_FUNCTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "participant-function",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-participant-function",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.participant.function"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Who has the condition?
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Condition.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `recorded-date`: Date record was first recorded
+pub const RECORDED_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "recorded-date",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-recorded-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Condition.recordedDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `severity`: The severity of the condition
+pub const SEVERITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "severity",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-severity",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.severity"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `stage`: Simple summary (disease specific)
+pub const STAGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "stage",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-stage",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.stage.summary"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Who has the condition?
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Condition.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `verification-status`: unconfirmed | provisional | differential | confirmed | refuted | entered-in-error
+pub const VERIFICATION_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "verification-status",
+    url: "http://hl7.org/fhir/SearchParameter/Condition-verification-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Condition.verificationStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConditionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `abatement-age`
+    AbatementAge,
+
+    /// `abatement-date`
+    AbatementDate,
+
+    /// `abatement-string`
+    AbatementString,
+
+    /// `body-site`
+    BodySite,
+
+    /// `category`
+    Category,
+
+    /// `clinical-status`
+    ClinicalStatus,
+
+    /// `code`
+    Code,
+
+    /// `encounter`
+    Encounter,
+
+    /// `evidence`
+    Evidence,
+
+    /// `evidence-detail`
+    EvidenceDetail,
+
+    /// `identifier`
+    Identifier,
+
+    /// `onset-age`
+    OnsetAge,
+
+    /// `onset-date`
+    OnsetDate,
+
+    /// `onset-info`
+    OnsetInfo,
+
+    /// `participant-actor`
+    ParticipantActor,
+
+    /// `participant-function`
+    ParticipantFunction,
+
+    /// `patient`
+    Patient,
+
+    /// `recorded-date`
+    RecordedDate,
+
+    /// `severity`
+    Severity,
+
+    /// `stage`
+    Stage,
+
+    /// `subject`
+    Subject,
+
+    /// `verification-status`
+    VerificationStatus,
+}
+
+impl ConditionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::AbatementAge,
+        Self::AbatementDate,
+        Self::AbatementString,
+        Self::BodySite,
+        Self::Category,
+        Self::ClinicalStatus,
+        Self::Code,
+        Self::Encounter,
+        Self::Evidence,
+        Self::EvidenceDetail,
+        Self::Identifier,
+        Self::OnsetAge,
+        Self::OnsetDate,
+        Self::OnsetInfo,
+        Self::ParticipantActor,
+        Self::ParticipantFunction,
+        Self::Patient,
+        Self::RecordedDate,
+        Self::Severity,
+        Self::Stage,
+        Self::Subject,
+        Self::VerificationStatus,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::AbatementAge => &ABATEMENT_AGE,
+            Self::AbatementDate => &ABATEMENT_DATE,
+            Self::AbatementString => &ABATEMENT_STRING,
+            Self::BodySite => &BODY_SITE,
+            Self::Category => &CATEGORY,
+            Self::ClinicalStatus => &CLINICAL_STATUS,
+            Self::Code => &CODE,
+            Self::Encounter => &ENCOUNTER,
+            Self::Evidence => &EVIDENCE,
+            Self::EvidenceDetail => &EVIDENCE_DETAIL,
+            Self::Identifier => &IDENTIFIER,
+            Self::OnsetAge => &ONSET_AGE,
+            Self::OnsetDate => &ONSET_DATE,
+            Self::OnsetInfo => &ONSET_INFO,
+            Self::ParticipantActor => &PARTICIPANT_ACTOR,
+            Self::ParticipantFunction => &PARTICIPANT_FUNCTION,
+            Self::Patient => &PATIENT,
+            Self::RecordedDate => &RECORDED_DATE,
+            Self::Severity => &SEVERITY,
+            Self::Stage => &STAGE,
+            Self::Subject => &SUBJECT,
+            Self::VerificationStatus => &VERIFICATION_STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ConditionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ConditionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,636 @@
+//! Consent search parameters
+
+use super::*;
+
+/// `action`: Actions controlled by this rule
+pub const ACTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "action",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-action",
+    r#type: SearchParamType::Token,
+    expression: Some("Consent.provision.action"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `actor`: Resource for the actor (or group, by role)
+pub const ACTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "actor",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-actor",
+    r#type: SearchParamType::Reference,
+    expression: Some("Consent.provision.actor.reference"),
+    targets: &[
+        "Device",
+        "CareTeam",
+        "Organization",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Group",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Classification of the consent statement - for indexing/retrieval
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Consent.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `controller`: Consent Enforcer
+pub const CONTROLLER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "controller",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-controller",
+    r#type: SearchParamType::Reference,
+    expression: Some("Consent.controller"),
+    targets: &["HealthcareService", "Organization", "Practitioner", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `data`: The actual data reference
+pub const DATA: SearchParameterDefinition = SearchParameterDefinition {
+    code: "data",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-data",
+    r#type: SearchParamType::Reference,
+    expression: Some("Consent.provision.data.reference"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: When consent was agreed to
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Consent.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `grantee`: Who is agreeing to the policy and rules
+pub const GRANTEE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "grantee",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-grantee",
+    r#type: SearchParamType::Reference,
+    expression: Some("Consent.grantee"),
+    targets: &[
+        "HealthcareService",
+        "CareTeam",
+        "Organization",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Identifier for this record (external references)
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Consent.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manager`: Consent workflow management
+pub const MANAGER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manager",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-manager",
+    r#type: SearchParamType::Reference,
+    expression: Some("Consent.manager"),
+    targets: &["HealthcareService", "Organization", "Practitioner", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Who the consent applies to
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Consent.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `period`: Timeframe for this rule
+pub const PERIOD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "period",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-period",
+    r#type: SearchParamType::Date,
+    expression: Some("Consent.provision.period"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `purpose`: Context of activities covered by this rule
+pub const PURPOSE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "purpose",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-purpose",
+    r#type: SearchParamType::Token,
+    expression: Some("Consent.provision.purpose"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `security-label`: Security Labels that define affected resources
+pub const SECURITY_LABEL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "security-label",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-security-label",
+    r#type: SearchParamType::Token,
+    expression: Some("Consent.provision.securityLabel"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `source-reference`: Search by reference to a Consent, DocumentReference, Contract  or QuestionnaireResponse
+pub const SOURCE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "source-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-source-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Consent.sourceReference"),
+    targets: &["Consent", "DocumentReference", "Contract", "QuestionnaireResponse"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: draft | active | inactive | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Consent.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Who the consent applies to
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Consent.subject"),
+    targets: &["Practitioner", "Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `verified`: Has been verified
+pub const VERIFIED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "verified",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-verified",
+    r#type: SearchParamType::Token,
+    expression: Some("Consent.verification.verified"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `verified-date`: When consent verified
+pub const VERIFIED_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "verified-date",
+    url: "http://hl7.org/fhir/SearchParameter/Consent-verified-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Consent.verification.verificationDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Consent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConsentSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `action`
+    Action,
+
+    /// `actor`
+    Actor,
+
+    /// `category`
+    Category,
+
+    /// `controller`
+    Controller,
+
+    /// `data`
+    Data,
+
+    /// `date`
+    Date,
+
+    /// `grantee`
+    Grantee,
+
+    /// `identifier`
+    Identifier,
+
+    /// `manager`
+    Manager,
+
+    /// `patient`
+    Patient,
+
+    /// `period`
+    Period,
+
+    /// `purpose`
+    Purpose,
+
+    /// `security-label`
+    SecurityLabel,
+
+    /// `source-reference`
+    SourceReference,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `verified`
+    Verified,
+
+    /// `verified-date`
+    VerifiedDate,
+}
+
+impl ConsentSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Action,
+        Self::Actor,
+        Self::Category,
+        Self::Controller,
+        Self::Data,
+        Self::Date,
+        Self::Grantee,
+        Self::Identifier,
+        Self::Manager,
+        Self::Patient,
+        Self::Period,
+        Self::Purpose,
+        Self::SecurityLabel,
+        Self::SourceReference,
+        Self::Status,
+        Self::Subject,
+        Self::Verified,
+        Self::VerifiedDate,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Action => &ACTION,
+            Self::Actor => &ACTOR,
+            Self::Category => &CATEGORY,
+            Self::Controller => &CONTROLLER,
+            Self::Data => &DATA,
+            Self::Date => &DATE,
+            Self::Grantee => &GRANTEE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Manager => &MANAGER,
+            Self::Patient => &PATIENT,
+            Self::Period => &PERIOD,
+            Self::Purpose => &PURPOSE,
+            Self::SecurityLabel => &SECURITY_LABEL,
+            Self::SourceReference => &SOURCE_REFERENCE,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::Verified => &VERIFIED,
+            Self::VerifiedDate => &VERIFIED_DATE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ConsentSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ConsentSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
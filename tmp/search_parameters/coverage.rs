@@ -0,0 +1,345 @@
+//! Coverage search parameters
+
+use super::*;
+
+/// `beneficiary`: Covered party
+pub const BENEFICIARY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "beneficiary",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-beneficiary",
+    r#type: SearchParamType::Reference,
+    expression: Some("Coverage.beneficiary"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `class-type`: Coverage class (e.g. plan, group)
+pub const CLASS_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "class-type",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-class-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Coverage.class.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `class-value`: Value of the class (e.g. Plan number, group number)
+pub const CLASS_VALUE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "class-value",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-class-value",
+    r#type: SearchParamType::Token,
+    expression: Some("Coverage.class.value"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `dependent`: Dependent number
+pub const DEPENDENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "dependent",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-dependent",
+    r#type: SearchParamType::String,
+    expression: Some("Coverage.dependent"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The primary identifier of the insured and the coverage
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Coverage.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `insurer`: The identity of the insurer
+pub const INSURER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "insurer",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-insurer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Coverage.insurer"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Retrieve coverages for a patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Coverage.beneficiary"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `paymentby-party`: Parties who will pay for services
+pub const PAYMENTBY_PARTY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "paymentby-party",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-paymentby-party",
+    r#type: SearchParamType::Reference,
+    expression: Some("Coverage.paymentBy.party"),
+    targets: &["Organization", "RelatedPerson", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `policy-holder`: Reference to the policyholder
+pub const POLICY_HOLDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "policy-holder",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-policy-holder",
+    r#type: SearchParamType::Reference,
+    expression: Some("Coverage.policyHolder"),
+    targets: &["Organization", "RelatedPerson", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the Coverage
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Coverage.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subscriber`: Reference to the subscriber
+pub const SUBSCRIBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subscriber",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-subscriber",
+    r#type: SearchParamType::Reference,
+    expression: Some("Coverage.subscriber"),
+    targets: &["RelatedPerson", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subscriberid`: Identifier of the subscriber
+pub const SUBSCRIBERID: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subscriberid",
+    url: "http://hl7.org/fhir/SearchParameter/Coverage-subscriberid",
+    r#type: SearchParamType::Token,
+    expression: Some("Coverage.subscriberId"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: The kind of coverage (health plan, auto, Workers Compensation)
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Coverage.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Coverage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CoverageSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `beneficiary`
+    Beneficiary,
+
+    /// `class-type`
+    ClassType,
+
+    /// `class-value`
+    ClassValue,
+
+    /// `dependent`
+    Dependent,
+
+    /// `identifier`
+    Identifier,
+
+    /// `insurer`
+    Insurer,
+
+    /// `patient`
+    Patient,
+
+    /// `paymentby-party`
+    PaymentbyParty,
+
+    /// `policy-holder`
+    PolicyHolder,
+
+    /// `status`
+    Status,
+
+    /// `subscriber`
+    Subscriber,
+
+    /// `subscriberid`
+    Subscriberid,
+
+    /// `type`
+    Type,
+}
+
+impl CoverageSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Beneficiary,
+        Self::ClassType,
+        Self::ClassValue,
+        Self::Dependent,
+        Self::Identifier,
+        Self::Insurer,
+        Self::Patient,
+        Self::PaymentbyParty,
+        Self::PolicyHolder,
+        Self::Status,
+        Self::Subscriber,
+        Self::Subscriberid,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Beneficiary => &BENEFICIARY,
+            Self::ClassType => &CLASS_TYPE,
+            Self::ClassValue => &CLASS_VALUE,
+            Self::Dependent => &DEPENDENT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Insurer => &INSURER,
+            Self::Patient => &PATIENT,
+            Self::PaymentbyParty => &PAYMENTBY_PARTY,
+            Self::PolicyHolder => &POLICY_HOLDER,
+            Self::Status => &STATUS,
+            Self::Subscriber => &SUBSCRIBER,
+            Self::Subscriberid => &SUBSCRIBERID,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for CoverageSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = CoverageSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
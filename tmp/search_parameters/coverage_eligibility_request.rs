@@ -0,0 +1,253 @@
+//! CoverageEligibilityRequest search parameters
+
+use super::*;
+
+/// `created`: The creation date for the EOB
+pub const CREATED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "created",
+    url: "http://hl7.org/fhir/SearchParameter/CoverageEligibilityRequest-created",
+    r#type: SearchParamType::Date,
+    expression: Some("CoverageEligibilityRequest.created"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `enterer`: The party who is responsible for the request
+pub const ENTERER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "enterer",
+    url: "http://hl7.org/fhir/SearchParameter/CoverageEligibilityRequest-enterer",
+    r#type: SearchParamType::Reference,
+    expression: Some("CoverageEligibilityRequest.enterer"),
+    targets: &["PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `facility`: Facility responsible for the goods and services
+pub const FACILITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "facility",
+    url: "http://hl7.org/fhir/SearchParameter/CoverageEligibilityRequest-facility",
+    r#type: SearchParamType::Reference,
+    expression: Some("CoverageEligibilityRequest.facility"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The business identifier of the Eligibility
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("CoverageEligibilityRequest.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The reference to the patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("CoverageEligibilityRequest.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `provider`: The reference to the provider
+pub const PROVIDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "provider",
+    url: "http://hl7.org/fhir/SearchParameter/CoverageEligibilityRequest-provider",
+    r#type: SearchParamType::Reference,
+    expression: Some("CoverageEligibilityRequest.provider"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the EligibilityRequest
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CoverageEligibilityRequest-status",
+    r#type: SearchParamType::Token,
+    expression: Some("CoverageEligibilityRequest.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of CoverageEligibilityRequest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CoverageEligibilityRequestSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `created`
+    Created,
+
+    /// `enterer`
+    Enterer,
+
+    /// `facility`
+    Facility,
+
+    /// `identifier`
+    Identifier,
+
+    /// `patient`
+    Patient,
+
+    /// `provider`
+    Provider,
+
+    /// `status`
+    Status,
+}
+
+impl CoverageEligibilityRequestSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Created,
+        Self::Enterer,
+        Self::Facility,
+        Self::Identifier,
+        Self::Patient,
+        Self::Provider,
+        Self::Status,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Created => &CREATED,
+            Self::Enterer => &ENTERER,
+            Self::Facility => &FACILITY,
+            Self::Identifier => &IDENTIFIER,
+            Self::Patient => &PATIENT,
+            Self::Provider => &PROVIDER,
+            Self::Status => &STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for CoverageEligibilityRequestSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = CoverageEligibilityRequestSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,555 @@
+//! Device search parameters
+
+use super::*;
+
+/// `biological-source-event`: The biological source for the device
+pub const BIOLOGICAL_SOURCE_EVENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "biological-source-event",
+    url: "http://hl7.org/fhir/SearchParameter/Device-biological-source-event",
+    r#type: SearchParamType::Token,
+    expression: Some("Device.biologicalSourceEvent"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: The definition / type of the device (code)
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/Device-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Device.definition.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code-value-concept`: Code and value parameter pair
+pub const CODE_VALUE_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code-value-concept",
+    url: "http://hl7.org/fhir/SearchParameter/Device-code-value-concept",
+    r#type: SearchParamType::Composite,
+    expression: Some("Device"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Device-specification",
+            expression: "specification.ofType(CodeableConcept)",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Device-version",
+            expression: "version.ofType(string)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `definition`: The definition / type of the device
+pub const DEFINITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "definition",
+    url: "http://hl7.org/fhir/SearchParameter/Device-definition",
+    r#type: SearchParamType::Reference,
+    expression: Some("Device.definition.reference"),
+    targets: &["DeviceDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `device-name`: A server defined search that may match any of the string fields in Device.name or Device.type.
+pub const DEVICE_NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "device-name",
+    url: "http://hl7.org/fhir/SearchParameter/Device-device-name",
+    r#type: SearchParamType::String,
+    expression: Some(
+        "Device.name.value | Device.type.coding.display | Device.type.text",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `expiration-date`: The expiration date of the device
+pub const EXPIRATION_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "expiration-date",
+    url: "http://hl7.org/fhir/SearchParameter/Device-expiration-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Device.expirationDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Instance id from manufacturer, owner, and others
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Device-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Device.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: A location, where the resource is found
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/Device-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("Device.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `lot-number`: The lot number of the device
+pub const LOT_NUMBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "lot-number",
+    url: "http://hl7.org/fhir/SearchParameter/Device-lot-number",
+    r#type: SearchParamType::String,
+    expression: Some("Device.lotNumber"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manufacture-date`: The manufacture date of the device
+pub const MANUFACTURE_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manufacture-date",
+    url: "http://hl7.org/fhir/SearchParameter/Device-manufacture-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Device.manufactureDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manufacturer`: The manufacturer of the device
+pub const MANUFACTURER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manufacturer",
+    url: "http://hl7.org/fhir/SearchParameter/Device-manufacturer",
+    r#type: SearchParamType::String,
+    expression: Some("Device.manufacturer"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `model`: The model of the device
+pub const MODEL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "model",
+    url: "http://hl7.org/fhir/SearchParameter/Device-model",
+    r#type: SearchParamType::String,
+    expression: Some("Device.modelNumber"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `organization`: The organization responsible for the device
+pub const ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "organization",
+    url: "http://hl7.org/fhir/SearchParameter/Device-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("Device.owner"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `parent`: The parent device
+pub const PARENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "parent",
+    url: "http://hl7.org/fhir/SearchParameter/Device-parent",
+    r#type: SearchParamType::Reference,
+    expression: Some("Device.parent"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `serial-number`: The serial number of the device
+pub const SERIAL_NUMBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "serial-number",
+    url: "http://hl7.org/fhir/SearchParameter/Device-serial-number",
+    r#type: SearchParamType::String,
+    expression: Some("Device.serialNumber | Device.identifier.where(type='SNO')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specification`: The standards, specifications, or formal guidances.
+pub const SPECIFICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specification",
+    url: "http://hl7.org/fhir/SearchParameter/Device-specification",
+    r#type: SearchParamType::Token,
+    expression: Some("Device.conformsTo.specification"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specification-version`: A composite of both specification and version
+pub const SPECIFICATION_VERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specification-version",
+    url: "http://hl7.org/fhir/SearchParameter/Device-specification-version",
+    r#type: SearchParamType::Composite,
+    expression: Some("Device.conformsTo"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Device-specification",
+            expression: "specification",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Device-version",
+            expression: "version",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `status`: active | inactive | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Device-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Device.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: The type of the device
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/Device-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Device.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `udi-carrier`: UDI Barcode (RFID or other technology) string in *HRF* format.
+pub const UDI_CARRIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "udi-carrier",
+    url: "http://hl7.org/fhir/SearchParameter/Device-udi-carrier",
+    r#type: SearchParamType::String,
+    expression: Some("Device.udiCarrier.carrierHRF"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `udi-di`: The udi Device Identifier (DI)
+pub const UDI_DI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "udi-di",
+    url: "http://hl7.org/fhir/SearchParameter/Device-udi-di",
+    r#type: SearchParamType::String,
+    expression: Some("Device.udiCarrier.deviceIdentifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `url`: Network address to contact device
+pub const URL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "url",
+    url: "http://hl7.org/fhir/SearchParameter/Device-url",
+    r#type: SearchParamType::Uri,
+    expression: Some("Device.url"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `version`: The specific version of the device
+pub const VERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "version",
+    url: "http://hl7.org/fhir/SearchParameter/Device-version",
+    r#type: SearchParamType::String,
+    expression: Some("Device.version.value"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DeviceSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `biological-source-event`
+    BiologicalSourceEvent,
+
+    /// `code`
+    Code,
+
+    /// `code-value-concept`
+    CodeValueConcept,
+
+    /// `definition`
+    Definition,
+
+    /// `device-name`
+    DeviceName,
+
+    /// `expiration-date`
+    ExpirationDate,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `lot-number`
+    LotNumber,
+
+    /// `manufacture-date`
+    ManufactureDate,
+
+    /// `manufacturer`
+    Manufacturer,
+
+    /// `model`
+    Model,
+
+    /// `organization`
+    Organization,
+
+    /// `parent`
+    Parent,
+
+    /// `serial-number`
+    SerialNumber,
+
+    /// `specification`
+    Specification,
+
+    /// `specification-version`
+    SpecificationVersion,
+
+    /// `status`
+    Status,
+
+    /// `type`
+    Type,
+
+    /// `udi-carrier`
+    UdiCarrier,
+
+    /// `udi-di`
+    UdiDi,
+
+    /// `url`
+    Url,
+
+    /// `version`
+    Version,
+}
+
+impl DeviceSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::BiologicalSourceEvent,
+        Self::Code,
+        Self::CodeValueConcept,
+        Self::Definition,
+        Self::DeviceName,
+        Self::ExpirationDate,
+        Self::Identifier,
+        Self::Location,
+        Self::LotNumber,
+        Self::ManufactureDate,
+        Self::Manufacturer,
+        Self::Model,
+        Self::Organization,
+        Self::Parent,
+        Self::SerialNumber,
+        Self::Specification,
+        Self::SpecificationVersion,
+        Self::Status,
+        Self::Type,
+        Self::UdiCarrier,
+        Self::UdiDi,
+        Self::Url,
+        Self::Version,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::BiologicalSourceEvent => &BIOLOGICAL_SOURCE_EVENT,
+            Self::Code => &CODE,
+            Self::CodeValueConcept => &CODE_VALUE_CONCEPT,
+            Self::Definition => &DEFINITION,
+            Self::DeviceName => &DEVICE_NAME,
+            Self::ExpirationDate => &EXPIRATION_DATE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::LotNumber => &LOT_NUMBER,
+            Self::ManufactureDate => &MANUFACTURE_DATE,
+            Self::Manufacturer => &MANUFACTURER,
+            Self::Model => &MODEL,
+            Self::Organization => &ORGANIZATION,
+            Self::Parent => &PARENT,
+            Self::SerialNumber => &SERIAL_NUMBER,
+            Self::Specification => &SPECIFICATION,
+            Self::SpecificationVersion => &SPECIFICATION_VERSION,
+            Self::Status => &STATUS,
+            Self::Type => &TYPE,
+            Self::UdiCarrier => &UDI_CARRIER,
+            Self::UdiDi => &UDI_DI,
+            Self::Url => &URL,
+            Self::Version => &VERSION,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for DeviceSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = DeviceSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
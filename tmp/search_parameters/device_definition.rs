@@ -0,0 +1,254 @@
+//! DeviceDefinition search parameters
+
+use super::*;
+
+/// `device-name`: A server defined search that may match any of the string fields in DeviceDefinition.name or DeviceDefinition.classification.type - the latter to search for 'generic' devices.
+pub const DEVICE_NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "device-name",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-device-name",
+    r#type: SearchParamType::String,
+    expression: Some(
+        "DeviceDefinition.deviceName.name | DeviceDefinition.classification.type.coding.display | DeviceDefinition.classification.type.text",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The identifier of the component
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceDefinition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manufacturer`: The manufacturer of the device
+pub const MANUFACTURER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manufacturer",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-manufacturer",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceDefinition.manufacturer"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `organization`: The organization responsible for the device
+pub const ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "organization",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceDefinition.owner"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specification`: The specification that the device conforms to
+pub const SPECIFICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specification",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-specification",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceDefinition.conformsTo.specification"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specification-version`: A composite of both specification and version
+pub const SPECIFICATION_VERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specification-version",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-specification-version",
+    r#type: SearchParamType::Composite,
+    expression: Some("DeviceDefinition.conformsTo"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-specification",
+            expression: "specification",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-version",
+            expression: "version",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `type`: The device type
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceDefinition-type",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceDefinition.conformsTo.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of DeviceDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DeviceDefinitionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `device-name`
+    DeviceName,
+
+    /// `identifier`
+    Identifier,
+
+    /// `manufacturer`
+    Manufacturer,
+
+    /// `organization`
+    Organization,
+
+    /// `specification`
+    Specification,
+
+    /// `specification-version`
+    SpecificationVersion,
+
+    /// `type`
+    Type,
+}
+
+impl DeviceDefinitionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::DeviceName,
+        Self::Identifier,
+        Self::Manufacturer,
+        Self::Organization,
+        Self::Specification,
+        Self::SpecificationVersion,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::DeviceName => &DEVICE_NAME,
+            Self::Identifier => &IDENTIFIER,
+            Self::Manufacturer => &MANUFACTURER,
+            Self::Organization => &ORGANIZATION,
+            Self::Specification => &SPECIFICATION,
+            Self::SpecificationVersion => &SPECIFICATION_VERSION,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for DeviceDefinitionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = DeviceDefinitionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
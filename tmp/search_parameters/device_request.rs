@@ -0,0 +1,637 @@
+//! DeviceRequest search parameters
+
+use super::*;
+
+/// `authored-on`: When the request transitioned to being actionable
+pub const AUTHORED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "authored-on",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-authored-on",
+    r#type: SearchParamType::Date,
+    expression: Some("DeviceRequest.authoredOn"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `based-on`: Plan/proposal/order fulfilled by this request
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.basedOn"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: Code for what is being requested/ordered
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceRequest.code.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `device`: Reference to resource that is being requested/ordered
+pub const DEVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "device",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-device",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.code.reference"),
+    targets: &["Device", "DeviceDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Encounter during which request was created
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `event-date`: When service should occur
+pub const EVENT_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "event-date",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-event-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "(DeviceRequest.occurrence.ofType(dateTime)) | (DeviceRequest.occurrence.ofType(Period))",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `group-identifier`: Composite request this is part of
+pub const GROUP_IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "group-identifier",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-group-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceRequest.groupIdentifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business identifier for request/order
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceRequest.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-canonical`: Instantiates FHIR protocol or definition
+pub const INSTANTIATES_CANONICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-canonical",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-instantiates-canonical",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.instantiatesCanonical"),
+    targets: &["ActivityDefinition", "PlanDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-uri`: Instantiates external protocol or definition
+pub const INSTANTIATES_URI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-uri",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-instantiates-uri",
+    r#type: SearchParamType::Uri,
+    expression: Some("DeviceRequest.instantiatesUri"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `insurance`: Associated insurance coverage
+pub const INSURANCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "insurance",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-insurance",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.insurance"),
+    targets: &["ClaimResponse", "Coverage"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `intent`: proposal | plan | original-order |reflex-order
+pub const INTENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "intent",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-intent",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceRequest.intent"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Individual the service is ordered for
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: Desired performer for service
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.performer.reference"),
+    targets: &[
+        "HealthcareService",
+        "Organization",
+        "CareTeam",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer-code`: Desired performer for service
+pub const PERFORMER_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer-code",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-performer-code",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceRequest.performer.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `prior-request`: Request takes the place of referenced completed or terminated requests
+pub const PRIOR_REQUEST: SearchParameterDefinition = SearchParameterDefinition {
+    code: "prior-request",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-prior-request",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.replaces"),
+    targets: &["DeviceRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requester`: Who/what is requesting service
+pub const REQUESTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requester",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-requester",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.requester"),
+    targets: &["Device", "Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: entered-in-error | draft | active |suspended | completed
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-status",
+    r#type: SearchParamType::Token,
+    expression: Some("DeviceRequest.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Individual the service is ordered for
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/DeviceRequest-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("DeviceRequest.subject"),
+    targets: &["Device", "Group", "Location", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of DeviceRequest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DeviceRequestSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `authored-on`
+    AuthoredOn,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `code`
+    Code,
+
+    /// `device`
+    Device,
+
+    /// `encounter`
+    Encounter,
+
+    /// `event-date`
+    EventDate,
+
+    /// `group-identifier`
+    GroupIdentifier,
+
+    /// `identifier`
+    Identifier,
+
+    /// `instantiates-canonical`
+    InstantiatesCanonical,
+
+    /// `instantiates-uri`
+    InstantiatesUri,
+
+    /// `insurance`
+    Insurance,
+
+    /// `intent`
+    Intent,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `performer-code`
+    PerformerCode,
+
+    /// `prior-request`
+    PriorRequest,
+
+    /// `requester`
+    Requester,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl DeviceRequestSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::AuthoredOn,
+        Self::BasedOn,
+        Self::Code,
+        Self::Device,
+        Self::Encounter,
+        Self::EventDate,
+        Self::GroupIdentifier,
+        Self::Identifier,
+        Self::InstantiatesCanonical,
+        Self::InstantiatesUri,
+        Self::Insurance,
+        Self::Intent,
+        Self::Patient,
+        Self::Performer,
+        Self::PerformerCode,
+        Self::PriorRequest,
+        Self::Requester,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::AuthoredOn => &AUTHORED_ON,
+            Self::BasedOn => &BASED_ON,
+            Self::Code => &CODE,
+            Self::Device => &DEVICE,
+            Self::Encounter => &ENCOUNTER,
+            Self::EventDate => &EVENT_DATE,
+            Self::GroupIdentifier => &GROUP_IDENTIFIER,
+            Self::Identifier => &IDENTIFIER,
+            Self::InstantiatesCanonical => &INSTANTIATES_CANONICAL,
+            Self::InstantiatesUri => &INSTANTIATES_URI,
+            Self::Insurance => &INSURANCE,
+            Self::Intent => &INTENT,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::PerformerCode => &PERFORMER_CODE,
+            Self::PriorRequest => &PRIOR_REQUEST,
+            Self::Requester => &REQUESTER,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for DeviceRequestSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = DeviceRequestSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
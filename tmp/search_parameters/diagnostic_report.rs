@@ -0,0 +1,451 @@
+//! DiagnosticReport search parameters
+
+use super::*;
+
+/// `based-on`: Reference to the service request.
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.basedOn"),
+    targets: &[
+        "ServiceRequest",
+        "CarePlan",
+        "MedicationRequest",
+        "ImmunizationRecommendation",
+        "NutritionOrder",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Which diagnostic discipline/department created the report
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-category",
+    r#type: SearchParamType::Token,
+    expression: Some("DiagnosticReport.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: The code for the report, as opposed to codes for the atomic results, which are the names on the observation resource referred to from the result
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("DiagnosticReport.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `conclusion`: A coded conclusion (interpretation/impression) on the report
+pub const CONCLUSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "conclusion",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-conclusion",
+    r#type: SearchParamType::Token,
+    expression: Some("DiagnosticReport.conclusionCode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: The clinically relevant time of the report
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "DiagnosticReport.effective.ofType(dateTime) | DiagnosticReport.effective.ofType(Period)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: The Encounter when the order was made
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: An identifier for the report
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("DiagnosticReport.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `issued`: When the report was issued
+pub const ISSUED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "issued",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-issued",
+    r#type: SearchParamType::Date,
+    expression: Some("DiagnosticReport.issued"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `media`: A reference to the image source.
+pub const MEDIA: SearchParameterDefinition = SearchParameterDefinition {
+    code: "media",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-media",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.media.link"),
+    targets: &["DocumentReference"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The subject of the report if a patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: Who is responsible for the report
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.performer"),
+    targets: &["Organization", "CareTeam", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `result`: Link to an atomic result (observation resource)
+pub const RESULT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "result",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-result",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.result"),
+    targets: &["Observation"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `results-interpreter`: Who was the source of the report
+pub const RESULTS_INTERPRETER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "results-interpreter",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-results-interpreter",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.resultsInterpreter"),
+    targets: &["Organization", "CareTeam", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specimen`: The specimen details
+pub const SPECIMEN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specimen",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-specimen",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.specimen"),
+    targets: &["Specimen"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the report
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-status",
+    r#type: SearchParamType::Token,
+    expression: Some("DiagnosticReport.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `study`: Studies associated with the diagnostic report
+pub const STUDY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "study",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-study",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.study"),
+    targets: &["ImagingStudy", "GenomicStudy"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The subject of the report
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/DiagnosticReport-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("DiagnosticReport.subject"),
+    targets: &[
+        "Device",
+        "Organization",
+        "Group",
+        "Practitioner",
+        "BiologicallyDerivedProduct",
+        "Substance",
+        "Location",
+        "Patient",
+        "Medication",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of DiagnosticReport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DiagnosticReportSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `category`
+    Category,
+
+    /// `code`
+    Code,
+
+    /// `conclusion`
+    Conclusion,
+
+    /// `date`
+    Date,
+
+    /// `encounter`
+    Encounter,
+
+    /// `identifier`
+    Identifier,
+
+    /// `issued`
+    Issued,
+
+    /// `media`
+    Media,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `result`
+    Result,
+
+    /// `results-interpreter`
+    ResultsInterpreter,
+
+    /// `specimen`
+    Specimen,
+
+    /// `status`
+    Status,
+
+    /// `study`
+    Study,
+
+    /// `subject`
+    Subject,
+}
+
+impl DiagnosticReportSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::BasedOn,
+        Self::Category,
+        Self::Code,
+        Self::Conclusion,
+        Self::Date,
+        Self::Encounter,
+        Self::Identifier,
+        Self::Issued,
+        Self::Media,
+        Self::Patient,
+        Self::Performer,
+        Self::Result,
+        Self::ResultsInterpreter,
+        Self::Specimen,
+        Self::Status,
+        Self::Study,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::BasedOn => &BASED_ON,
+            Self::Category => &CATEGORY,
+            Self::Code => &CODE,
+            Self::Conclusion => &CONCLUSION,
+            Self::Date => &DATE,
+            Self::Encounter => &ENCOUNTER,
+            Self::Identifier => &IDENTIFIER,
+            Self::Issued => &ISSUED,
+            Self::Media => &MEDIA,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::Result => &RESULT,
+            Self::ResultsInterpreter => &RESULTS_INTERPRETER,
+            Self::Specimen => &SPECIMEN,
+            Self::Status => &STATUS,
+            Self::Study => &STUDY,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for DiagnosticReportSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = DiagnosticReportSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
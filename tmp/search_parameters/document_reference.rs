@@ -0,0 +1,1129 @@
+//! DocumentReference search parameters
+
+use super::*;
+
+/// `attester`: Who attested the document
+pub const ATTESTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "attester",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-attester",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.attester.party"),
+    targets: &[
+        "Organization",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `author`: Who and/or what authored the document
+pub const AUTHOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "author",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-author",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.author"),
+    targets: &[
+        "Organization",
+        "Device",
+        "CareTeam",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `based-on`: Procedure that caused this media to be created
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.basedOn"),
+    targets: &[
+        "Claim",
+        "RequestOrchestration",
+        "DeviceRequest",
+        "ServiceRequest",
+        "EnrollmentRequest",
+        "CarePlan",
+        "Contract",
+        "MedicationRequest",
+        "ImmunizationRecommendation",
+        "CoverageEligibilityRequest",
+        "VisionPrescription",
+        "Appointment",
+        "CommunicationRequest",
+        "AppointmentResponse",
+        "SupplyRequest",
+        "NutritionOrder",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `bodysite`: The body site studied
+pub const BODYSITE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "bodysite",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-bodysite",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.bodySite.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `bodysite-reference`: The body site studied
+pub const BODYSITE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "bodysite-reference",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-bodysite-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.bodySite.reference"),
+    targets: &["BodyStructure"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Categorization of document
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-category",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `contenttype`: Mime type of the content, with charset etc.
+pub const CONTENTTYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "contenttype",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-contenttype",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.content.attachment.contentType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context`: Context of the document content
+pub const CONTEXT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-context",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.context"),
+    targets: &["Appointment", "Encounter", "EpisodeOfCare"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `creation`: Date attachment was first created
+pub const CREATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "creation",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-creation",
+    r#type: SearchParamType::Date,
+    expression: Some("DocumentReference.content.attachment.creation"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `custodian`: Organization which maintains the document
+pub const CUSTODIAN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "custodian",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-custodian",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.custodian"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: When this document reference was created
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("DocumentReference.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `description`: Human-readable description
+pub const DESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "description",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-description",
+    r#type: SearchParamType::String,
+    expression: Some("DocumentReference.description"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `doc-status`: preliminary | final | amended | entered-in-error
+pub const DOC_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "doc-status",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-doc-status",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.docStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `event-code`: Main clinical acts documented
+pub const EVENT_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "event-code",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-event-code",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.event.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `event-reference`: Main clinical acts documented
+pub const EVENT_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "event-reference",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-event-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.event.reference"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `facility`: Kind of facility where patient was seen
+pub const FACILITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "facility",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-facility",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.facilityType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `format-canonical`: Profile canonical content rules for the document
+pub const FORMAT_CANONICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "format-canonical",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-format-canonical",
+    r#type: SearchParamType::Reference,
+    expression: Some("(DocumentReference.content.profile.value.ofType(canonical))"),
+    targets: &[
+        "ActivityDefinition",
+        "ActorDefinition",
+        "CapabilityStatement",
+        "ChargeItemDefinition",
+        "Citation",
+        "CodeSystem",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "ConditionDefinition",
+        "Contract",
+        "Device",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "GraphDefinition",
+        "ImplementationGuide",
+        "Library",
+        "Measure",
+        "MessageDefinition",
+        "NamingSystem",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "PlanDefinition",
+        "Questionnaire",
+        "Requirements",
+        "ResearchStudy",
+        "SearchParameter",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "SubscriptionTopic",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestScript",
+        "ValueSet",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `format-code`: Format code content rules for the document
+pub const FORMAT_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "format-code",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-format-code",
+    r#type: SearchParamType::Token,
+    expression: Some("(DocumentReference.content.profile.value.ofType(Coding))"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `format-uri`: Profile URI content rules for the document
+pub const FORMAT_URI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "format-uri",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-format-uri",
+    r#type: SearchParamType::Uri,
+    expression: Some("(DocumentReference.content.profile.value.ofType(uri))"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Identifier of the attachment binary
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `language`: Human language of the content (BCP-47)
+pub const LANGUAGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "language",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-language",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.content.attachment.language"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: Uri where the data can be found
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-location",
+    r#type: SearchParamType::Uri,
+    expression: Some("DocumentReference.content.attachment.url"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `modality`: The modality used
+pub const MODALITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "modality",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-modality",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.modality"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Who/what is the subject of the document
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `period`: Time of service that is being documented
+pub const PERIOD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "period",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-period",
+    r#type: SearchParamType::Date,
+    expression: Some("DocumentReference.period"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `relatesto`: Target of the relationship
+pub const RELATESTO: SearchParameterDefinition = SearchParameterDefinition {
+    code: "relatesto",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-relatesto",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.relatesTo.target"),
+    targets: &["DocumentReference"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `relation`: replaces | transforms | signs | appends
+pub const RELATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "relation",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-relation",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.relatesTo.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `relationship`: Combination of relation and relatesTo
+pub const RELATIONSHIP: SearchParameterDefinition = SearchParameterDefinition {
+    code: "relationship",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-relationship",
+    r#type: SearchParamType::Composite,
+    expression: Some("DocumentReference.relatesTo"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/DocumentReference-relatesto",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/DocumentReference-relation",
+            expression: "target",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `security-label`: Document security-tags
+pub const SECURITY_LABEL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "security-label",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-security-label",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.securityLabel"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `setting`: Additional details about where the content was created (e.g. clinical specialty)
+pub const SETTING: SearchParameterDefinition = SearchParameterDefinition {
+    code: "setting",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-setting",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.practiceSetting"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: current | superseded | entered-in-error
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-status",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Who/what is the subject of the document
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("DocumentReference.subject"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: Kind of document (LOINC if possible)
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("DocumentReference.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `version`: The business version identifier
+pub const VERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "version",
+    url: "http://hl7.org/fhir/SearchParameter/DocumentReference-version",
+    r#type: SearchParamType::String,
+    expression: Some("DocumentReference.version"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of DocumentReference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DocumentReferenceSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `attester`
+    Attester,
+
+    /// `author`
+    Author,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `bodysite`
+    Bodysite,
+
+    /// `bodysite-reference`
+    BodysiteReference,
+
+    /// `category`
+    Category,
+
+    /// `contenttype`
+    Contenttype,
+
+    /// `context`
+    Context,
+
+    /// `creation`
+    Creation,
+
+    /// `custodian`
+    Custodian,
+
+    /// `date`
+    Date,
+
+    /// `description`
+    Description,
+
+    /// `doc-status`
+    DocStatus,
+
+    /// `event-code`
+    EventCode,
+
+    /// `event-reference`
+    EventReference,
+
+    /// `facility`
+    Facility,
+
+    /// `format-canonical`
+    FormatCanonical,
+
+    /// `format-code`
+    FormatCode,
+
+    /// `format-uri`
+    FormatUri,
+
+    /// `identifier`
+    Identifier,
+
+    /// `language`
+    Language,
+
+    /// `location`
+    Location,
+
+    /// `modality`
+    Modality,
+
+    /// `patient`
+    Patient,
+
+    /// `period`
+    Period,
+
+    /// `relatesto`
+    Relatesto,
+
+    /// `relation`
+    Relation,
+
+    /// `relationship`
+    Relationship,
+
+    /// `security-label`
+    SecurityLabel,
+
+    /// `setting`
+    Setting,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `type`
+    Type,
+
+    /// `version`
+    Version,
+}
+
+impl DocumentReferenceSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Attester,
+        Self::Author,
+        Self::BasedOn,
+        Self::Bodysite,
+        Self::BodysiteReference,
+        Self::Category,
+        Self::Contenttype,
+        Self::Context,
+        Self::Creation,
+        Self::Custodian,
+        Self::Date,
+        Self::Description,
+        Self::DocStatus,
+        Self::EventCode,
+        Self::EventReference,
+        Self::Facility,
+        Self::FormatCanonical,
+        Self::FormatCode,
+        Self::FormatUri,
+        Self::Identifier,
+        Self::Language,
+        Self::Location,
+        Self::Modality,
+        Self::Patient,
+        Self::Period,
+        Self::Relatesto,
+        Self::Relation,
+        Self::Relationship,
+        Self::SecurityLabel,
+        Self::Setting,
+        Self::Status,
+        Self::Subject,
+        Self::Type,
+        Self::Version,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Attester => &ATTESTER,
+            Self::Author => &AUTHOR,
+            Self::BasedOn => &BASED_ON,
+            Self::Bodysite => &BODYSITE,
+            Self::BodysiteReference => &BODYSITE_REFERENCE,
+            Self::Category => &CATEGORY,
+            Self::Contenttype => &CONTENTTYPE,
+            Self::Context => &CONTEXT,
+            Self::Creation => &CREATION,
+            Self::Custodian => &CUSTODIAN,
+            Self::Date => &DATE,
+            Self::Description => &DESCRIPTION,
+            Self::DocStatus => &DOC_STATUS,
+            Self::EventCode => &EVENT_CODE,
+            Self::EventReference => &EVENT_REFERENCE,
+            Self::Facility => &FACILITY,
+            Self::FormatCanonical => &FORMAT_CANONICAL,
+            Self::FormatCode => &FORMAT_CODE,
+            Self::FormatUri => &FORMAT_URI,
+            Self::Identifier => &IDENTIFIER,
+            Self::Language => &LANGUAGE,
+            Self::Location => &LOCATION,
+            Self::Modality => &MODALITY,
+            Self::Patient => &PATIENT,
+            Self::Period => &PERIOD,
+            Self::Relatesto => &RELATESTO,
+            Self::Relation => &RELATION,
+            Self::Relationship => &RELATIONSHIP,
+            Self::SecurityLabel => &SECURITY_LABEL,
+            Self::Setting => &SETTING,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::Type => &TYPE,
+            Self::Version => &VERSION,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for DocumentReferenceSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = DocumentReferenceSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
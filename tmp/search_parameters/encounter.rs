@@ -0,0 +1,663 @@
+//! Encounter search parameters
+
+use super::*;
+
+/// `account`: The set of accounts that may be used for billing for this Encounter
+pub const ACCOUNT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "account",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-account",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.account"),
+    targets: &["Account"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `appointment`: The appointment that scheduled this encounter
+pub const APPOINTMENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "appointment",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-appointment",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.appointment"),
+    targets: &["Appointment"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `based-on`: The ServiceRequest that initiated this encounter
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.basedOn"),
+    targets: &["DeviceRequest", "ServiceRequest", "CarePlan", "MedicationRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `careteam`: Careteam allocated to participate in the encounter
+pub const CARETEAM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "careteam",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-careteam",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.careTeam"),
+    targets: &["CareTeam"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `class`: Classification of patient encounter
+pub const CLASS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "class",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-class",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.class"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: A date within the actualPeriod the Encounter lasted
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Encounter.actualPeriod"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date-start`: The actual start date of the Encounter
+pub const DATE_START: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date-start",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-date-start",
+    r#type: SearchParamType::Date,
+    expression: Some("Encounter.actualPeriod.start"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `diagnosis-code`: The diagnosis or procedure relevant to the encounter (coded)
+pub const DIAGNOSIS_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "diagnosis-code",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-diagnosis-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.diagnosis.condition.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `diagnosis-reference`: The diagnosis or procedure relevant to the encounter (resource reference)
+pub const DIAGNOSIS_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "diagnosis-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-diagnosis-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.diagnosis.condition.reference"),
+    targets: &["Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `end-date`: The actual end date of the Encounter
+pub const END_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "end-date",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-end-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Encounter.actualPeriod.end"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `episode-of-care`: Episode(s) of care that this encounter should be recorded against
+pub const EPISODE_OF_CARE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "episode-of-care",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-episode-of-care",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.episodeOfCare"),
+    targets: &["EpisodeOfCare"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Identifier(s) by which this encounter is known
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `length`: Length of encounter in days
+pub const LENGTH: SearchParameterDefinition = SearchParameterDefinition {
+    code: "length",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-length",
+    r#type: SearchParamType::Quantity,
+    expression: Some("Encounter.length"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: Location the encounter takes place
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.location.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location-period`: Time period during which the patient was present at the location
+pub const LOCATION_PERIOD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location-period",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-location-period",
+    r#type: SearchParamType::Composite,
+    expression: Some("Encounter.location"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Encounter-location",
+            expression: "location.reference",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Encounter-period",
+            expression: "period",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `part-of`: Another Encounter this encounter is part of
+pub const PART_OF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "part-of",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-part-of",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.partOf"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `participant`: Persons involved in the encounter other than the patient
+pub const PARTICIPANT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "participant",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-participant",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.participant.actor"),
+    targets: &[
+        "HealthcareService",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Group",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `participant-type`: Role of participant in encounter
+pub const PARTICIPANT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "participant-type",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-participant-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.participant.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The patient present at the encounter
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `practitioner`: Persons involved in the encounter other than the patient
+pub const PRACTITIONER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "practitioner",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-practitioner",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.participant.actor.where(resolve() is Practitioner)"),
+    targets: &["Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-code`: Reference to a concept (coded)
+pub const REASON_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-code",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-reason-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.reason.value.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-reference`: Reference to a resource (resource reference)
+pub const REASON_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-reason-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.reason.value.reference"),
+    targets: &[
+        "Procedure",
+        "Observation",
+        "DiagnosticReport",
+        "ImmunizationRecommendation",
+        "Condition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-provider`: The organization (facility) responsible for this encounter
+pub const SERVICE_PROVIDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-provider",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-service-provider",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.serviceProvider"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `special-arrangement`: Wheelchair, translator, stretcher, etc.
+pub const SPECIAL_ARRANGEMENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "special-arrangement",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-special-arrangement",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.specialArrangement"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: planned | in-progress | on-hold | completed | cancelled | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The patient or group present at the encounter
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Encounter.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject-status`: The current status of the subject in relation to the Encounter
+pub const SUBJECT_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject-status",
+    url: "http://hl7.org/fhir/SearchParameter/Encounter-subject-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.subjectStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: Specific type of encounter
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Encounter.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Encounter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EncounterSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `account`
+    Account,
+
+    /// `appointment`
+    Appointment,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `careteam`
+    Careteam,
+
+    /// `class`
+    Class,
+
+    /// `date`
+    Date,
+
+    /// `date-start`
+    DateStart,
+
+    /// `diagnosis-code`
+    DiagnosisCode,
+
+    /// `diagnosis-reference`
+    DiagnosisReference,
+
+    /// `end-date`
+    EndDate,
+
+    /// `episode-of-care`
+    EpisodeOfCare,
+
+    /// `identifier`
+    Identifier,
+
+    /// `length`
+    Length,
+
+    /// `location`
+    Location,
+
+    /// `location-period`
+    LocationPeriod,
+
+    /// `part-of`
+    PartOf,
+
+    /// `participant`
+    Participant,
+
+    /// `participant-type`
+    ParticipantType,
+
+    /// `patient`
+    Patient,
+
+    /// `practitioner`
+    Practitioner,
+
+    /// `reason-code`
+    ReasonCode,
+
+    /// `reason-reference`
+    ReasonReference,
+
+    /// `service-provider`
+    ServiceProvider,
+
+    /// `special-arrangement`
+    SpecialArrangement,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `subject-status`
+    SubjectStatus,
+
+    /// `type`
+    Type,
+}
+
+impl EncounterSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Account,
+        Self::Appointment,
+        Self::BasedOn,
+        Self::Careteam,
+        Self::Class,
+        Self::Date,
+        Self::DateStart,
+        Self::DiagnosisCode,
+        Self::DiagnosisReference,
+        Self::EndDate,
+        Self::EpisodeOfCare,
+        Self::Identifier,
+        Self::Length,
+        Self::Location,
+        Self::LocationPeriod,
+        Self::PartOf,
+        Self::Participant,
+        Self::ParticipantType,
+        Self::Patient,
+        Self::Practitioner,
+        Self::ReasonCode,
+        Self::ReasonReference,
+        Self::ServiceProvider,
+        Self::SpecialArrangement,
+        Self::Status,
+        Self::Subject,
+        Self::SubjectStatus,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Account => &ACCOUNT,
+            Self::Appointment => &APPOINTMENT,
+            Self::BasedOn => &BASED_ON,
+            Self::Careteam => &CARETEAM,
+            Self::Class => &CLASS,
+            Self::Date => &DATE,
+            Self::DateStart => &DATE_START,
+            Self::DiagnosisCode => &DIAGNOSIS_CODE,
+            Self::DiagnosisReference => &DIAGNOSIS_REFERENCE,
+            Self::EndDate => &END_DATE,
+            Self::EpisodeOfCare => &EPISODE_OF_CARE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Length => &LENGTH,
+            Self::Location => &LOCATION,
+            Self::LocationPeriod => &LOCATION_PERIOD,
+            Self::PartOf => &PART_OF,
+            Self::Participant => &PARTICIPANT,
+            Self::ParticipantType => &PARTICIPANT_TYPE,
+            Self::Patient => &PATIENT,
+            Self::Practitioner => &PRACTITIONER,
+            Self::ReasonCode => &REASON_CODE,
+            Self::ReasonReference => &REASON_REFERENCE,
+            Self::ServiceProvider => &SERVICE_PROVIDER,
+            Self::SpecialArrangement => &SPECIAL_ARRANGEMENT,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::SubjectStatus => &SUBJECT_STATUS,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for EncounterSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = EncounterSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! EpisodeOfCare search parameters
+
+use super::*;
+
+/// `care-manager`: Care manager/care coordinator for the patient
+pub const CARE_MANAGER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "care-manager",
+    url: "http://hl7.org/fhir/SearchParameter/EpisodeOfCare-care-manager",
+    r#type: SearchParamType::Reference,
+    expression: Some("EpisodeOfCare.careManager.where(resolve() is Practitioner)"),
+    targets: &["Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: The provided date search value falls within the episode of care's period
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("EpisodeOfCare.period"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `diagnosis-code`: Conditions/problems/diagnoses this episode of care is for (coded)
+pub const DIAGNOSIS_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "diagnosis-code",
+    url: "http://hl7.org/fhir/SearchParameter/EpisodeOfCare-diagnosis-code",
+    r#type: SearchParamType::Token,
+    expression: Some("EpisodeOfCare.diagnosis.condition.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `diagnosis-reference`: Conditions/problems/diagnoses this episode of care is for (resource reference)
+pub const DIAGNOSIS_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "diagnosis-reference",
+    url: "http://hl7.org/fhir/SearchParameter/EpisodeOfCare-diagnosis-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("EpisodeOfCare.diagnosis.condition.reference"),
+    targets: &["Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business Identifier(s) relevant for this EpisodeOfCare
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("EpisodeOfCare.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `incoming-referral`: Incoming Referral Request
+pub const INCOMING_REFERRAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "incoming-referral",
+    url: "http://hl7.org/fhir/SearchParameter/EpisodeOfCare-incoming-referral",
+    r#type: SearchParamType::Reference,
+    expression: Some("EpisodeOfCare.referralRequest"),
+    targets: &["ServiceRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `organization`: The organization that has assumed the specific responsibilities of this EpisodeOfCare
+pub const ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "organization",
+    url: "http://hl7.org/fhir/SearchParameter/EpisodeOfCare-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("EpisodeOfCare.managingOrganization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The patient who is the focus of this episode of care
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("EpisodeOfCare.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-code`: Reference to a concept (coded)
+pub const REASON_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-code",
+    url: "http://hl7.org/fhir/SearchParameter/EpisodeOfCare-reason-code",
+    r#type: SearchParamType::Token,
+    expression: Some("EpisodeOfCare.reason.value.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-reference`: Reference to a resource (resource reference)
+pub const REASON_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-reference",
+    url: "http://hl7.org/fhir/SearchParameter/EpisodeOfCare-reason-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("EpisodeOfCare.reason.value.reference"),
+    targets: &["HealthcareService", "Procedure", "Observation", "Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The current status of the Episode of Care as provided (does not check the status history collection)
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/EpisodeOfCare-status",
+    r#type: SearchParamType::Token,
+    expression: Some("EpisodeOfCare.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: Type/class  - e.g. specialist referral, disease management
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("EpisodeOfCare.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of EpisodeOfCare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EpisodeOfCareSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `care-manager`
+    CareManager,
+
+    /// `date`
+    Date,
+
+    /// `diagnosis-code`
+    DiagnosisCode,
+
+    /// `diagnosis-reference`
+    DiagnosisReference,
+
+    /// `identifier`
+    Identifier,
+
+    /// `incoming-referral`
+    IncomingReferral,
+
+    /// `organization`
+    Organization,
+
+    /// `patient`
+    Patient,
+
+    /// `reason-code`
+    ReasonCode,
+
+    /// `reason-reference`
+    ReasonReference,
+
+    /// `status`
+    Status,
+
+    /// `type`
+    Type,
+}
+
+impl EpisodeOfCareSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::CareManager,
+        Self::Date,
+        Self::DiagnosisCode,
+        Self::DiagnosisReference,
+        Self::Identifier,
+        Self::IncomingReferral,
+        Self::Organization,
+        Self::Patient,
+        Self::ReasonCode,
+        Self::ReasonReference,
+        Self::Status,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::CareManager => &CARE_MANAGER,
+            Self::Date => &DATE,
+            Self::DiagnosisCode => &DIAGNOSIS_CODE,
+            Self::DiagnosisReference => &DIAGNOSIS_REFERENCE,
+            Self::Identifier => &IDENTIFIER,
+            Self::IncomingReferral => &INCOMING_REFERRAL,
+            Self::Organization => &ORGANIZATION,
+            Self::Patient => &PATIENT,
+            Self::ReasonCode => &REASON_CODE,
+            Self::ReasonReference => &REASON_REFERENCE,
+            Self::Status => &STATUS,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for EpisodeOfCareSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = EpisodeOfCareSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
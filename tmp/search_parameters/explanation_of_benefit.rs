@@ -0,0 +1,429 @@
+//! ExplanationOfBenefit search parameters
+
+use super::*;
+
+/// `care-team`: Member of the CareTeam
+pub const CARE_TEAM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "care-team",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-care-team",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.careTeam.provider"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `claim`: The reference to the claim
+pub const CLAIM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "claim",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-claim",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.claim"),
+    targets: &["Claim"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `coverage`: The plan under which the claim was adjudicated
+pub const COVERAGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "coverage",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-coverage",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.insurance.coverage"),
+    targets: &["Coverage"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `created`: The creation date for the EOB
+pub const CREATED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "created",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-created",
+    r#type: SearchParamType::Date,
+    expression: Some("ExplanationOfBenefit.created"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `detail-udi`: UDI associated with a line item detail product or service
+pub const DETAIL_UDI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "detail-udi",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-detail-udi",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.item.detail.udi"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `disposition`: The contents of the disposition message
+pub const DISPOSITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "disposition",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-disposition",
+    r#type: SearchParamType::String,
+    expression: Some("ExplanationOfBenefit.disposition"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Encounters associated with a billed line item
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.item.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `enterer`: The party responsible for the entry of the Claim
+pub const ENTERER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "enterer",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-enterer",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.enterer"),
+    targets: &["RelatedPerson", "PractitionerRole", "Practitioner", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `facility`: Facility responsible for the goods and services
+pub const FACILITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "facility",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-facility",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.facility"),
+    targets: &["Organization", "Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The business identifier of the Explanation of Benefit
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ExplanationOfBenefit.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `item-udi`: UDI associated with a line item product or service
+pub const ITEM_UDI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "item-udi",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-item-udi",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.item.udi"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The reference to the patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `payee`: The party receiving any payment for the Claim
+pub const PAYEE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "payee",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-payee",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.payee.party"),
+    targets: &[
+        "Organization",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `procedure-udi`: UDI associated with a procedure
+pub const PROCEDURE_UDI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "procedure-udi",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-procedure-udi",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.procedure.udi"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `provider`: The reference to the provider
+pub const PROVIDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "provider",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-provider",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.provider"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Status of the instance
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ExplanationOfBenefit.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subdetail-udi`: UDI associated with a line item detail subdetail product or service
+pub const SUBDETAIL_UDI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subdetail-udi",
+    url: "http://hl7.org/fhir/SearchParameter/ExplanationOfBenefit-subdetail-udi",
+    r#type: SearchParamType::Reference,
+    expression: Some("ExplanationOfBenefit.item.detail.subDetail.udi"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ExplanationOfBenefit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExplanationOfBenefitSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `care-team`
+    CareTeam,
+
+    /// `claim`
+    Claim,
+
+    /// `coverage`
+    Coverage,
+
+    /// `created`
+    Created,
+
+    /// `detail-udi`
+    DetailUdi,
+
+    /// `disposition`
+    Disposition,
+
+    /// `encounter`
+    Encounter,
+
+    /// `enterer`
+    Enterer,
+
+    /// `facility`
+    Facility,
+
+    /// `identifier`
+    Identifier,
+
+    /// `item-udi`
+    ItemUdi,
+
+    /// `patient`
+    Patient,
+
+    /// `payee`
+    Payee,
+
+    /// `procedure-udi`
+    ProcedureUdi,
+
+    /// `provider`
+    Provider,
+
+    /// `status`
+    Status,
+
+    /// `subdetail-udi`
+    SubdetailUdi,
+}
+
+impl ExplanationOfBenefitSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::CareTeam,
+        Self::Claim,
+        Self::Coverage,
+        Self::Created,
+        Self::DetailUdi,
+        Self::Disposition,
+        Self::Encounter,
+        Self::Enterer,
+        Self::Facility,
+        Self::Identifier,
+        Self::ItemUdi,
+        Self::Patient,
+        Self::Payee,
+        Self::ProcedureUdi,
+        Self::Provider,
+        Self::Status,
+        Self::SubdetailUdi,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::CareTeam => &CARE_TEAM,
+            Self::Claim => &CLAIM,
+            Self::Coverage => &COVERAGE,
+            Self::Created => &CREATED,
+            Self::DetailUdi => &DETAIL_UDI,
+            Self::Disposition => &DISPOSITION,
+            Self::Encounter => &ENCOUNTER,
+            Self::Enterer => &ENTERER,
+            Self::Facility => &FACILITY,
+            Self::Identifier => &IDENTIFIER,
+            Self::ItemUdi => &ITEM_UDI,
+            Self::Patient => &PATIENT,
+            Self::Payee => &PAYEE,
+            Self::ProcedureUdi => &PROCEDURE_UDI,
+            Self::Provider => &PROVIDER,
+            Self::Status => &STATUS,
+            Self::SubdetailUdi => &SUBDETAIL_UDI,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ExplanationOfBenefitSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ExplanationOfBenefitSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! FamilyMemberHistory search parameters
+
+use super::*;
+
+/// `code`: A search by a condition code
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("FamilyMemberHistory.condition.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: When history was recorded or last updated
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("FamilyMemberHistory.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A search by a record identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("FamilyMemberHistory.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-canonical`: Instantiates FHIR protocol or definition
+pub const INSTANTIATES_CANONICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-canonical",
+    url: "http://hl7.org/fhir/SearchParameter/FamilyMemberHistory-instantiates-canonical",
+    r#type: SearchParamType::Reference,
+    expression: Some("FamilyMemberHistory.instantiatesCanonical"),
+    targets: &[
+        "Measure",
+        "OperationDefinition",
+        "ActivityDefinition",
+        "PlanDefinition",
+        "Questionnaire",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-uri`: Instantiates external protocol or definition
+pub const INSTANTIATES_URI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-uri",
+    url: "http://hl7.org/fhir/SearchParameter/FamilyMemberHistory-instantiates-uri",
+    r#type: SearchParamType::Uri,
+    expression: Some("FamilyMemberHistory.instantiatesUri"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The identity of a subject to list family member history items for
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("FamilyMemberHistory.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `relationship`: A search by a relationship type
+pub const RELATIONSHIP: SearchParameterDefinition = SearchParameterDefinition {
+    code: "relationship",
+    url: "http://hl7.org/fhir/SearchParameter/FamilyMemberHistory-relationship",
+    r#type: SearchParamType::Token,
+    expression: Some("FamilyMemberHistory.relationship"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `sex`: A search by a sex code of a family member
+pub const SEX: SearchParameterDefinition = SearchParameterDefinition {
+    code: "sex",
+    url: "http://hl7.org/fhir/SearchParameter/FamilyMemberHistory-sex",
+    r#type: SearchParamType::Token,
+    expression: Some("FamilyMemberHistory.sex"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: partial | completed | entered-in-error | health-unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/FamilyMemberHistory-status",
+    r#type: SearchParamType::Token,
+    expression: Some("FamilyMemberHistory.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of FamilyMemberHistory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FamilyMemberHistorySearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `code`
+    Code,
+
+    /// `date`
+    Date,
+
+    /// `identifier`
+    Identifier,
+
+    /// `instantiates-canonical`
+    InstantiatesCanonical,
+
+    /// `instantiates-uri`
+    InstantiatesUri,
+
+    /// `patient`
+    Patient,
+
+    /// `relationship`
+    Relationship,
+
+    /// `sex`
+    Sex,
+
+    /// `status`
+    Status,
+}
+
+impl FamilyMemberHistorySearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Code,
+        Self::Date,
+        Self::Identifier,
+        Self::InstantiatesCanonical,
+        Self::InstantiatesUri,
+        Self::Patient,
+        Self::Relationship,
+        Self::Sex,
+        Self::Status,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Code => &CODE,
+            Self::Date => &DATE,
+            Self::Identifier => &IDENTIFIER,
+            Self::InstantiatesCanonical => &INSTANTIATES_CANONICAL,
+            Self::InstantiatesUri => &INSTANTIATES_URI,
+            Self::Patient => &PATIENT,
+            Self::Relationship => &RELATIONSHIP,
+            Self::Sex => &SEX,
+            Self::Status => &STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for FamilyMemberHistorySearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = FamilyMemberHistorySearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Goal search parameters
+
+use super::*;
+
+/// `achievement-status`: in-progress | improving | worsening | no-change | achieved | sustaining | not-achieved | no-progress | not-attainable
+pub const ACHIEVEMENT_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "achievement-status",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-achievement-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Goal.achievementStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `addresses`: Issues addressed by this goal
+pub const ADDRESSES: SearchParameterDefinition = SearchParameterDefinition {
+    code: "addresses",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-addresses",
+    r#type: SearchParamType::Reference,
+    expression: Some("Goal.addresses"),
+    targets: &[
+        "RiskAssessment",
+        "Procedure",
+        "Observation",
+        "MedicationStatement",
+        "ServiceRequest",
+        "MedicationRequest",
+        "Condition",
+        "NutritionOrder",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: E.g. Treatment, dietary, behavioral, etc.
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Goal.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `description`: Code or text describing goal
+pub const DESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "description",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-description",
+    r#type: SearchParamType::Token,
+    expression: Some("Goal.description"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: External Ids for this goal
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Goal.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `lifecycle-status`: proposed | planned | accepted | active | on-hold | completed | cancelled | entered-in-error | rejected
+pub const LIFECYCLE_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "lifecycle-status",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-lifecycle-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Goal.lifecycleStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Who this goal is intended for
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Goal.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `start-date`: When goal pursuit begins
+pub const START_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "start-date",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-start-date",
+    r#type: SearchParamType::Date,
+    expression: Some("(Goal.start.ofType(date))"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Who this goal is intended for
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Goal.subject"),
+    targets: &["Organization", "Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `target-date`: Reach goal on or before
+pub const TARGET_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "target-date",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-target-date",
+    r#type: SearchParamType::Date,
+    expression: Some("(Goal.target.due.ofType(date))"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `target-measure`: The parameter whose value is being tracked
+pub const TARGET_MEASURE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "target-measure",
+    url: "http://hl7.org/fhir/SearchParameter/Goal-target-measure",
+    r#type: SearchParamType::Token,
+    expression: Some("Goal.target.measure"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Goal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GoalSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `achievement-status`
+    AchievementStatus,
+
+    /// `addresses`
+    Addresses,
+
+    /// `category`
+    Category,
+
+    /// `description`
+    Description,
+
+    /// `identifier`
+    Identifier,
+
+    /// `lifecycle-status`
+    LifecycleStatus,
+
+    /// `patient`
+    Patient,
+
+    /// `start-date`
+    StartDate,
+
+    /// `subject`
+    Subject,
+
+    /// `target-date`
+    TargetDate,
+
+    /// `target-measure`
+    TargetMeasure,
+}
+
+impl GoalSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::AchievementStatus,
+        Self::Addresses,
+        Self::Category,
+        Self::Description,
+        Self::Identifier,
+        Self::LifecycleStatus,
+        Self::Patient,
+        Self::StartDate,
+        Self::Subject,
+        Self::TargetDate,
+        Self::TargetMeasure,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::AchievementStatus => &ACHIEVEMENT_STATUS,
+            Self::Addresses => &ADDRESSES,
+            Self::Category => &CATEGORY,
+            Self::Description => &DESCRIPTION,
+            Self::Identifier => &IDENTIFIER,
+            Self::LifecycleStatus => &LIFECYCLE_STATUS,
+            Self::Patient => &PATIENT,
+            Self::StartDate => &START_DATE,
+            Self::Subject => &SUBJECT,
+            Self::TargetDate => &TARGET_DATE,
+            Self::TargetMeasure => &TARGET_MEASURE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for GoalSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = GoalSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,510 @@
+//! Group search parameters
+
+use super::*;
+
+/// `characteristic`: Kind of characteristic
+pub const CHARACTERISTIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "characteristic",
+    url: "http://hl7.org/fhir/SearchParameter/Group-characteristic",
+    r#type: SearchParamType::Token,
+    expression: Some("Group.characteristic.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `characteristic-reference`: An entity referenced in a characteristic
+pub const CHARACTERISTIC_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "characteristic-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Group-characteristic-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("(Group.characteristic.value.ofType(Reference))"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `characteristic-value`: A composite of both characteristic and value
+pub const CHARACTERISTIC_VALUE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "characteristic-value",
+    url: "http://hl7.org/fhir/SearchParameter/Group-characteristic-value",
+    r#type: SearchParamType::Composite,
+    expression: Some("Group.characteristic"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Group-characteristic",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Group-value",
+            expression: "(value.ofType(CodeableConcept)) | (value.ofType(boolean))",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `code`: The kind of resources contained
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/Group-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Group.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `exclude`: Group includes or excludes
+pub const EXCLUDE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "exclude",
+    url: "http://hl7.org/fhir/SearchParameter/Group-exclude",
+    r#type: SearchParamType::Token,
+    expression: Some("Group.characteristic.exclude"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Unique id
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Group-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Group.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `managing-entity`: Entity that is the custodian of the Group's definition
+pub const MANAGING_ENTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "managing-entity",
+    url: "http://hl7.org/fhir/SearchParameter/Group-managing-entity",
+    r#type: SearchParamType::Reference,
+    expression: Some("Group.managingEntity"),
+    targets: &["Organization", "RelatedPerson", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `member`: Reference to the group member
+pub const MEMBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "member",
+    url: "http://hl7.org/fhir/SearchParameter/Group-member",
+    r#type: SearchParamType::Reference,
+    expression: Some("Group.member.entity"),
+    targets: &[
+        "HealthcareService",
+        "CareTeam",
+        "Device",
+        "Organization",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Group",
+        "Practitioner",
+        "Specimen",
+        "Location",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `membership`: Definitional or enumerated group
+pub const MEMBERSHIP: SearchParameterDefinition = SearchParameterDefinition {
+    code: "membership",
+    url: "http://hl7.org/fhir/SearchParameter/Group-membership",
+    r#type: SearchParamType::Token,
+    expression: Some("Group.membership"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A portion of the Group's name
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/Group-name",
+    r#type: SearchParamType::String,
+    expression: Some("Group.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: The type of resources the group contains
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/Group-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Group.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `value`: Value held by characteristic
+pub const VALUE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "value",
+    url: "http://hl7.org/fhir/SearchParameter/Group-value",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "(Group.characteristic.value.ofType(CodeableConcept)) | (Group.characteristic.value.ofType(boolean))",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GroupSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `characteristic`
+    Characteristic,
+
+    /// `characteristic-reference`
+    CharacteristicReference,
+
+    /// `characteristic-value`
+    CharacteristicValue,
+
+    /// `code`
+    Code,
+
+    /// `exclude`
+    Exclude,
+
+    /// `identifier`
+    Identifier,
+
+    /// `managing-entity`
+    ManagingEntity,
+
+    /// `member`
+    Member,
+
+    /// `membership`
+    Membership,
+
+    /// `name`
+    Name,
+
+    /// `type`
+    Type,
+
+    /// `value`
+    Value,
+}
+
+impl GroupSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Characteristic,
+        Self::CharacteristicReference,
+        Self::CharacteristicValue,
+        Self::Code,
+        Self::Exclude,
+        Self::Identifier,
+        Self::ManagingEntity,
+        Self::Member,
+        Self::Membership,
+        Self::Name,
+        Self::Type,
+        Self::Value,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Characteristic => &CHARACTERISTIC,
+            Self::CharacteristicReference => &CHARACTERISTIC_REFERENCE,
+            Self::CharacteristicValue => &CHARACTERISTIC_VALUE,
+            Self::Code => &CODE,
+            Self::Exclude => &EXCLUDE,
+            Self::Identifier => &IDENTIFIER,
+            Self::ManagingEntity => &MANAGING_ENTITY,
+            Self::Member => &MEMBER,
+            Self::Membership => &MEMBERSHIP,
+            Self::Name => &NAME,
+            Self::Type => &TYPE,
+            Self::Value => &VALUE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for GroupSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = GroupSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
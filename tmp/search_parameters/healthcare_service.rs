@@ -0,0 +1,379 @@
+//! HealthcareService search parameters
+
+use super::*;
+
+/// `active`: The Healthcare Service is currently marked as active
+pub const ACTIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "active",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-active",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.active"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `characteristic`: One of the HealthcareService's characteristics
+pub const CHARACTERISTIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "characteristic",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-characteristic",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.characteristic"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `communication`: Languages that are available at this service
+pub const COMMUNICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "communication",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-communication",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.communication"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `coverage-area`: Location(s) service is intended for/available to
+pub const COVERAGE_AREA: SearchParameterDefinition = SearchParameterDefinition {
+    code: "coverage-area",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-coverage-area",
+    r#type: SearchParamType::Reference,
+    expression: Some("HealthcareService.coverageArea"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `eligibility`: One of the HealthcareService's eligibility requirements
+pub const ELIGIBILITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "eligibility",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-eligibility",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.eligibility.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `endpoint`: Technical endpoints providing access to electronic services operated for the healthcare service
+pub const ENDPOINT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "endpoint",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-endpoint",
+    r#type: SearchParamType::Reference,
+    expression: Some("HealthcareService.endpoint"),
+    targets: &["Endpoint"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: External identifiers for this item
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: The location of the Healthcare Service
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("HealthcareService.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A portion of the Healthcare service name
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-name",
+    r#type: SearchParamType::String,
+    expression: Some("HealthcareService.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `offered-in`: The service within which this service is offered
+pub const OFFERED_IN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "offered-in",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-offered-in",
+    r#type: SearchParamType::Reference,
+    expression: Some("HealthcareService.offeredIn"),
+    targets: &["HealthcareService"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `organization`: The organization that provides this Healthcare Service
+pub const ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "organization",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("HealthcareService.providedBy"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `program`: One of the Programs supported by this HealthcareService
+pub const PROGRAM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "program",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-program",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.program"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-category`: Service Category of the Healthcare Service
+pub const SERVICE_CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-category",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-service-category",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-type`: The type of service provided by this healthcare service
+pub const SERVICE_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-type",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-service-type",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specialty`: The specialty of the service provided by this healthcare service
+pub const SPECIALTY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specialty",
+    url: "http://hl7.org/fhir/SearchParameter/HealthcareService-specialty",
+    r#type: SearchParamType::Token,
+    expression: Some("HealthcareService.specialty"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of HealthcareService.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HealthcareServiceSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `active`
+    Active,
+
+    /// `characteristic`
+    Characteristic,
+
+    /// `communication`
+    Communication,
+
+    /// `coverage-area`
+    CoverageArea,
+
+    /// `eligibility`
+    Eligibility,
+
+    /// `endpoint`
+    Endpoint,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `name`
+    Name,
+
+    /// `offered-in`
+    OfferedIn,
+
+    /// `organization`
+    Organization,
+
+    /// `program`
+    Program,
+
+    /// `service-category`
+    ServiceCategory,
+
+    /// `service-type`
+    ServiceType,
+
+    /// `specialty`
+    Specialty,
+}
+
+impl HealthcareServiceSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Active,
+        Self::Characteristic,
+        Self::Communication,
+        Self::CoverageArea,
+        Self::Eligibility,
+        Self::Endpoint,
+        Self::Identifier,
+        Self::Location,
+        Self::Name,
+        Self::OfferedIn,
+        Self::Organization,
+        Self::Program,
+        Self::ServiceCategory,
+        Self::ServiceType,
+        Self::Specialty,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Active => &ACTIVE,
+            Self::Characteristic => &CHARACTERISTIC,
+            Self::Communication => &COMMUNICATION,
+            Self::CoverageArea => &COVERAGE_AREA,
+            Self::Eligibility => &ELIGIBILITY,
+            Self::Endpoint => &ENDPOINT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::Name => &NAME,
+            Self::OfferedIn => &OFFERED_IN,
+            Self::Organization => &ORGANIZATION,
+            Self::Program => &PROGRAM,
+            Self::ServiceCategory => &SERVICE_CATEGORY,
+            Self::ServiceType => &SERVICE_TYPE,
+            Self::Specialty => &SPECIALTY,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for HealthcareServiceSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = HealthcareServiceSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! ImagingSelection search parameters
+
+use super::*;
+
+/// `based-on`: The request associated with an imaging selection
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingSelection-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingSelection.basedOn"),
+    targets: &[
+        "Appointment",
+        "ServiceRequest",
+        "CarePlan",
+        "Task",
+        "AppointmentResponse",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `body-site`: The body site code associated with the imaging selection
+pub const BODY_SITE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "body-site",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingSelection-body-site",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingSelection.bodySite.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `body-structure`: The body structure associated with the imaging selection
+pub const BODY_STRUCTURE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "body-structure",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingSelection-body-structure",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingSelection.bodySite.reference"),
+    targets: &["BodyStructure"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: The imaging selection status
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingSelection.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `derived-from`: The imaging study from which the imaging selection was derived
+pub const DERIVED_FROM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "derived-from",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingSelection-derived-from",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingSelection.derivedFrom"),
+    targets: &["ImagingStudy", "DocumentReference"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Identifiers for the imaging selection
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingSelection.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `issued`: The date / time the imaging selection was created
+pub const ISSUED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "issued",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingSelection-issued",
+    r#type: SearchParamType::Date,
+    expression: Some("ImagingSelection.issued"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Who the study is about
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingSelection.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the imaging selection
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingSelection-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingSelection.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `study-uid`: The DICOM Study Instance UID from which the images were selected
+pub const STUDY_UID: SearchParameterDefinition = SearchParameterDefinition {
+    code: "study-uid",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingSelection-study-uid",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingSelection.studyUid"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The subject of the Imaging Selection, such as the associated Patient
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingSelection-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingSelection.subject"),
+    targets: &[
+        "Device",
+        "Organization",
+        "Procedure",
+        "Group",
+        "Practitioner",
+        "Specimen",
+        "Substance",
+        "Location",
+        "Patient",
+        "Medication",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ImagingSelection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImagingSelectionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `body-site`
+    BodySite,
+
+    /// `body-structure`
+    BodyStructure,
+
+    /// `code`
+    Code,
+
+    /// `derived-from`
+    DerivedFrom,
+
+    /// `identifier`
+    Identifier,
+
+    /// `issued`
+    Issued,
+
+    /// `patient`
+    Patient,
+
+    /// `status`
+    Status,
+
+    /// `study-uid`
+    StudyUid,
+
+    /// `subject`
+    Subject,
+}
+
+impl ImagingSelectionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::BasedOn,
+        Self::BodySite,
+        Self::BodyStructure,
+        Self::Code,
+        Self::DerivedFrom,
+        Self::Identifier,
+        Self::Issued,
+        Self::Patient,
+        Self::Status,
+        Self::StudyUid,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::BasedOn => &BASED_ON,
+            Self::BodySite => &BODY_SITE,
+            Self::BodyStructure => &BODY_STRUCTURE,
+            Self::Code => &CODE,
+            Self::DerivedFrom => &DERIVED_FROM,
+            Self::Identifier => &IDENTIFIER,
+            Self::Issued => &ISSUED,
+            Self::Patient => &PATIENT,
+            Self::Status => &STATUS,
+            Self::StudyUid => &STUDY_UID,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ImagingSelectionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ImagingSelectionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,438 @@
+//! ImagingStudy search parameters
+
+use super::*;
+
+/// `based-on`: The order for the image, such as Accession Number associated with a ServiceRequest
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingStudy.basedOn"),
+    targets: &[
+        "Appointment",
+        "ServiceRequest",
+        "CarePlan",
+        "Task",
+        "AppointmentResponse",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `body-site`: The body site code studied
+pub const BODY_SITE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "body-site",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-body-site",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingStudy.series.bodySite.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `body-structure`: The body structure resource associated with the ImagingStudy
+pub const BODY_STRUCTURE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "body-structure",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-body-structure",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingStudy.series.bodySite.reference"),
+    targets: &["BodyStructure"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `dicom-class`: The type of the instance
+pub const DICOM_CLASS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "dicom-class",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-dicom-class",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingStudy.series.instance.sopClass"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: The context of the study
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingStudy.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `endpoint`: The endpoint for the study or series
+pub const ENDPOINT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "endpoint",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-endpoint",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingStudy.endpoint | ImagingStudy.series.endpoint"),
+    targets: &["Endpoint"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Identifiers for the Study, such as DICOM Study Instance UID
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingStudy.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instance`: SOP Instance UID for an instance
+pub const INSTANCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instance",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-instance",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingStudy.series.instance.uid"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `modality`: The modality of the series
+pub const MODALITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "modality",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-modality",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingStudy.series.modality"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Who the study is about
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingStudy.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: The person who performed the study
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingStudy.series.performer.actor"),
+    targets: &[
+        "HealthcareService",
+        "Organization",
+        "CareTeam",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason`: The reason for the study
+pub const REASON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-reason",
+    r#type: SearchParamType::Token,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `referrer`: The referring physician
+pub const REFERRER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "referrer",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-referrer",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingStudy.referrer"),
+    targets: &["PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `series`: DICOM Series Instance UID for a series
+pub const SERIES: SearchParameterDefinition = SearchParameterDefinition {
+    code: "series",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-series",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingStudy.series.uid"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `started`: When the study was started
+pub const STARTED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "started",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-started",
+    r#type: SearchParamType::Date,
+    expression: Some("ImagingStudy.started"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the study
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ImagingStudy.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Who the study is about
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/ImagingStudy-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("ImagingStudy.subject"),
+    targets: &["Device", "Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ImagingStudy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImagingStudySearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `body-site`
+    BodySite,
+
+    /// `body-structure`
+    BodyStructure,
+
+    /// `dicom-class`
+    DicomClass,
+
+    /// `encounter`
+    Encounter,
+
+    /// `endpoint`
+    Endpoint,
+
+    /// `identifier`
+    Identifier,
+
+    /// `instance`
+    Instance,
+
+    /// `modality`
+    Modality,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `reason`
+    Reason,
+
+    /// `referrer`
+    Referrer,
+
+    /// `series`
+    Series,
+
+    /// `started`
+    Started,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl ImagingStudySearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::BasedOn,
+        Self::BodySite,
+        Self::BodyStructure,
+        Self::DicomClass,
+        Self::Encounter,
+        Self::Endpoint,
+        Self::Identifier,
+        Self::Instance,
+        Self::Modality,
+        Self::Patient,
+        Self::Performer,
+        Self::Reason,
+        Self::Referrer,
+        Self::Series,
+        Self::Started,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::BasedOn => &BASED_ON,
+            Self::BodySite => &BODY_SITE,
+            Self::BodyStructure => &BODY_STRUCTURE,
+            Self::DicomClass => &DICOM_CLASS,
+            Self::Encounter => &ENCOUNTER,
+            Self::Endpoint => &ENDPOINT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Instance => &INSTANCE,
+            Self::Modality => &MODALITY,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::Reason => &REASON,
+            Self::Referrer => &REFERRER,
+            Self::Series => &SERIES,
+            Self::Started => &STARTED,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ImagingStudySearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ImagingStudySearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,422 @@
+//! Immunization search parameters
+
+use super::*;
+
+/// `date`: Vaccination  (non)-Administration Date
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("(Immunization.occurrence.ofType(dateTime))"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Immunization.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: The service delivery location or facility in which the vaccine was / was to be administered
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("Immunization.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `lot-number`: Vaccine Lot Number
+pub const LOT_NUMBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "lot-number",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-lot-number",
+    r#type: SearchParamType::String,
+    expression: Some("Immunization.lotNumber"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manufacturer`: Vaccine Manufacturer
+pub const MANUFACTURER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manufacturer",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-manufacturer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Immunization.manufacturer.reference"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The patient for the vaccination record
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Immunization.patient"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: The practitioner, individual or organization who played a role in the vaccination
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Immunization.performer.actor"),
+    targets: &[
+        "Organization",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reaction`: Additional information on reaction
+pub const REACTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reaction",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-reaction",
+    r#type: SearchParamType::Reference,
+    expression: Some("Immunization.reaction.manifestation.reference"),
+    targets: &["Observation"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reaction-date`: When reaction started
+pub const REACTION_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reaction-date",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-reaction-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Immunization.reaction.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-code`: Reason why the vaccine was administered
+pub const REASON_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-code",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-reason-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Immunization.reason.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-reference`: Reference to a resource (by instance)
+pub const REASON_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-reason-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Immunization.reason.reference"),
+    targets: &["Observation", "DiagnosticReport", "Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `series`: The series being followed by the provider
+pub const SERIES: SearchParameterDefinition = SearchParameterDefinition {
+    code: "series",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-series",
+    r#type: SearchParamType::String,
+    expression: Some("Immunization.protocolApplied.series"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Immunization event status
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Immunization.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status-reason`: Reason why the vaccine was not administered
+pub const STATUS_REASON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status-reason",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-status-reason",
+    r#type: SearchParamType::Token,
+    expression: Some("Immunization.statusReason"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `target-disease`: The target disease the dose is being administered against
+pub const TARGET_DISEASE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "target-disease",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-target-disease",
+    r#type: SearchParamType::Token,
+    expression: Some("Immunization.protocolApplied.targetDisease"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `vaccine-code`: Vaccine Product Administered
+pub const VACCINE_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "vaccine-code",
+    url: "http://hl7.org/fhir/SearchParameter/Immunization-vaccine-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Immunization.vaccineCode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Immunization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImmunizationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `date`
+    Date,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `lot-number`
+    LotNumber,
+
+    /// `manufacturer`
+    Manufacturer,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `reaction`
+    Reaction,
+
+    /// `reaction-date`
+    ReactionDate,
+
+    /// `reason-code`
+    ReasonCode,
+
+    /// `reason-reference`
+    ReasonReference,
+
+    /// `series`
+    Series,
+
+    /// `status`
+    Status,
+
+    /// `status-reason`
+    StatusReason,
+
+    /// `target-disease`
+    TargetDisease,
+
+    /// `vaccine-code`
+    VaccineCode,
+}
+
+impl ImmunizationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Date,
+        Self::Identifier,
+        Self::Location,
+        Self::LotNumber,
+        Self::Manufacturer,
+        Self::Patient,
+        Self::Performer,
+        Self::Reaction,
+        Self::ReactionDate,
+        Self::ReasonCode,
+        Self::ReasonReference,
+        Self::Series,
+        Self::Status,
+        Self::StatusReason,
+        Self::TargetDisease,
+        Self::VaccineCode,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Date => &DATE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::LotNumber => &LOT_NUMBER,
+            Self::Manufacturer => &MANUFACTURER,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::Reaction => &REACTION,
+            Self::ReactionDate => &REACTION_DATE,
+            Self::ReasonCode => &REASON_CODE,
+            Self::ReasonReference => &REASON_REFERENCE,
+            Self::Series => &SERIES,
+            Self::Status => &STATUS,
+            Self::StatusReason => &STATUS_REASON,
+            Self::TargetDisease => &TARGET_DISEASE,
+            Self::VaccineCode => &VACCINE_CODE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ImmunizationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ImmunizationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
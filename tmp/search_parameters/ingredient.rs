@@ -0,0 +1,387 @@
+//! Ingredient search parameters
+
+use super::*;
+
+/// `for`: The product which this ingredient is a constituent part of
+pub const FOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "for",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-for",
+    r#type: SearchParamType::Reference,
+    expression: Some("Ingredient.for"),
+    targets: &[
+        "MedicinalProductDefinition",
+        "AdministrableProductDefinition",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `function`: A classification of the ingredient identifying its precise purpose(s) in the drug product. This extends the Ingredient.role to add more detail. Example: Antioxidant, Alkalizing Agent
+pub const FUNCTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "function",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-function",
+    r#type: SearchParamType::Token,
+    expression: Some("Ingredient.function"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: An identifier or code by which the ingredient can be referenced
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Ingredient.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manufacturer`: The organization that manufactures this ingredient
+pub const MANUFACTURER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manufacturer",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-manufacturer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Ingredient.manufacturer.manufacturer"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `role`: A classification of the ingredient identifying its purpose within the product, e.g. active, inactive
+pub const ROLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "role",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-role",
+    r#type: SearchParamType::Token,
+    expression: Some("Ingredient.role"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of this ingredient. Enables tracking the life-cycle of the content
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Ingredient.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `strength-concentration-quantity`: Ingredient concentration strength as quantity
+pub const STRENGTH_CONCENTRATION_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "strength-concentration-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-strength-concentration-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some("Ingredient.substance.strength.concentration.ofType(Quantity)"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `strength-concentration-ratio`: Ingredient concentration strength as ratio, numerator and denominator parameter pair
+pub const STRENGTH_CONCENTRATION_RATIO: SearchParameterDefinition = SearchParameterDefinition {
+    code: "strength-concentration-ratio",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-strength-concentration-ratio",
+    r#type: SearchParamType::Composite,
+    expression: Some("Ingredient.substance.strength.concentration.ofType(Ratio)"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Ingredient-numerator",
+            expression: "numerator",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Ingredient-denominator",
+            expression: "denominator",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `strength-presentation-quantity`: Ingredient presentation strength as quantity
+pub const STRENGTH_PRESENTATION_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "strength-presentation-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-strength-presentation-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some("Ingredient.substance.strength.presentation.ofType(Quantity)"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `strength-presentation-ratio`: Ingredient presentation strength as ratio, numerator and denominator parameter pair
+pub const STRENGTH_PRESENTATION_RATIO: SearchParameterDefinition = SearchParameterDefinition {
+    code: "strength-presentation-ratio",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-strength-presentation-ratio",
+    r#type: SearchParamType::Composite,
+    expression: Some("Ingredient.substance.strength.presentation.ofType(Ratio)"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Ingredient-numerator",
+            expression: "numerator",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Ingredient-denominator",
+            expression: "denominator",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `substance`: Reference to a resource (by instance)
+pub const SUBSTANCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "substance",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-substance",
+    r#type: SearchParamType::Reference,
+    expression: Some("Ingredient.substance.code.reference"),
+    targets: &["SubstanceDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `substance-code`: Reference to a concept (by class)
+pub const SUBSTANCE_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "substance-code",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-substance-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Ingredient.substance.code.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `substance-definition`: Reference to a resource (by instance)
+pub const SUBSTANCE_DEFINITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "substance-definition",
+    url: "http://hl7.org/fhir/SearchParameter/Ingredient-substance-definition",
+    r#type: SearchParamType::Reference,
+    expression: Some("Ingredient.substance.code.reference"),
+    targets: &["SubstanceDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Ingredient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IngredientSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `for`
+    For,
+
+    /// `function`
+    Function,
+
+    /// `identifier`
+    Identifier,
+
+    /// `manufacturer`
+    Manufacturer,
+
+    /// `role`
+    Role,
+
+    /// `status`
+    Status,
+
+    /// `strength-concentration-quantity`
+    StrengthConcentrationQuantity,
+
+    /// `strength-concentration-ratio`
+    StrengthConcentrationRatio,
+
+    /// `strength-presentation-quantity`
+    StrengthPresentationQuantity,
+
+    /// `strength-presentation-ratio`
+    StrengthPresentationRatio,
+
+    /// `substance`
+    Substance,
+
+    /// `substance-code`
+    SubstanceCode,
+
+    /// `substance-definition`
+    SubstanceDefinition,
+}
+
+impl IngredientSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::For,
+        Self::Function,
+        Self::Identifier,
+        Self::Manufacturer,
+        Self::Role,
+        Self::Status,
+        Self::StrengthConcentrationQuantity,
+        Self::StrengthConcentrationRatio,
+        Self::StrengthPresentationQuantity,
+        Self::StrengthPresentationRatio,
+        Self::Substance,
+        Self::SubstanceCode,
+        Self::SubstanceDefinition,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::For => &FOR,
+            Self::Function => &FUNCTION,
+            Self::Identifier => &IDENTIFIER,
+            Self::Manufacturer => &MANUFACTURER,
+            Self::Role => &ROLE,
+            Self::Status => &STATUS,
+            Self::StrengthConcentrationQuantity => &STRENGTH_CONCENTRATION_QUANTITY,
+            Self::StrengthConcentrationRatio => &STRENGTH_CONCENTRATION_RATIO,
+            Self::StrengthPresentationQuantity => &STRENGTH_PRESENTATION_QUANTITY,
+            Self::StrengthPresentationRatio => &STRENGTH_PRESENTATION_RATIO,
+            Self::Substance => &SUBSTANCE,
+            Self::SubstanceCode => &SUBSTANCE_CODE,
+            Self::SubstanceDefinition => &SUBSTANCE_DEFINITION,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for IngredientSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = IngredientSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,362 @@
+//! InsurancePlan search parameters
+
+use super::*;
+
+/// `address`: A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text
+pub const ADDRESS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-address",
+    r#type: SearchParamType::String,
+    expression: Some("InsurancePlan.contact.address"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-city`: A city specified in an address
+pub const ADDRESS_CITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-city",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-address-city",
+    r#type: SearchParamType::String,
+    expression: Some("InsurancePlan.contact.address.city"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-country`: A country specified in an address
+pub const ADDRESS_COUNTRY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-country",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-address-country",
+    r#type: SearchParamType::String,
+    expression: Some("InsurancePlan.contact.address.country"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-postalcode`: A postal code specified in an address
+pub const ADDRESS_POSTALCODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-postalcode",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-address-postalcode",
+    r#type: SearchParamType::String,
+    expression: Some("InsurancePlan.contact.address.postalCode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-state`: A state specified in an address
+pub const ADDRESS_STATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-state",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-address-state",
+    r#type: SearchParamType::String,
+    expression: Some("InsurancePlan.contact.address.state"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-use`: A use code specified in an address
+pub const ADDRESS_USE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-use",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-address-use",
+    r#type: SearchParamType::Token,
+    expression: Some("InsurancePlan.contact.address.use"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `administered-by`: Product administrator
+pub const ADMINISTERED_BY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "administered-by",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-administered-by",
+    r#type: SearchParamType::Reference,
+    expression: Some("InsurancePlan.administeredBy"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `endpoint`: Technical endpoint
+pub const ENDPOINT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "endpoint",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-endpoint",
+    r#type: SearchParamType::Reference,
+    expression: Some("InsurancePlan.endpoint"),
+    targets: &["Endpoint"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Any identifier for the organization (not the accreditation issuer's identifier)
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("InsurancePlan.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A portion of the organization's name or alias
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-name",
+    r#type: SearchParamType::String,
+    expression: Some("InsurancePlan.name | InsurancePlan.alias"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `owned-by`: An organization of which this organization forms a part
+pub const OWNED_BY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "owned-by",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-owned-by",
+    r#type: SearchParamType::Reference,
+    expression: Some("InsurancePlan.ownedBy"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phonetic`: A portion of the organization's name using some kind of phonetic matching algorithm
+pub const PHONETIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phonetic",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-phonetic",
+    r#type: SearchParamType::String,
+    expression: Some("InsurancePlan.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Is the Organization record active
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-status",
+    r#type: SearchParamType::Token,
+    expression: Some("InsurancePlan.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: A code for the type of organization
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/InsurancePlan-type",
+    r#type: SearchParamType::Token,
+    expression: Some("InsurancePlan.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of InsurancePlan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InsurancePlanSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `address`
+    Address,
+
+    /// `address-city`
+    AddressCity,
+
+    /// `address-country`
+    AddressCountry,
+
+    /// `address-postalcode`
+    AddressPostalcode,
+
+    /// `address-state`
+    AddressState,
+
+    /// `address-use`
+    AddressUse,
+
+    /// `administered-by`
+    AdministeredBy,
+
+    /// `endpoint`
+    Endpoint,
+
+    /// `identifier`
+    Identifier,
+
+    /// `name`
+    Name,
+
+    /// `owned-by`
+    OwnedBy,
+
+    /// `phonetic`
+    Phonetic,
+
+    /// `status`
+    Status,
+
+    /// `type`
+    Type,
+}
+
+impl InsurancePlanSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Address,
+        Self::AddressCity,
+        Self::AddressCountry,
+        Self::AddressPostalcode,
+        Self::AddressState,
+        Self::AddressUse,
+        Self::AdministeredBy,
+        Self::Endpoint,
+        Self::Identifier,
+        Self::Name,
+        Self::OwnedBy,
+        Self::Phonetic,
+        Self::Status,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Address => &ADDRESS,
+            Self::AddressCity => &ADDRESS_CITY,
+            Self::AddressCountry => &ADDRESS_COUNTRY,
+            Self::AddressPostalcode => &ADDRESS_POSTALCODE,
+            Self::AddressState => &ADDRESS_STATE,
+            Self::AddressUse => &ADDRESS_USE,
+            Self::AdministeredBy => &ADMINISTERED_BY,
+            Self::Endpoint => &ENDPOINT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Name => &NAME,
+            Self::OwnedBy => &OWNED_BY,
+            Self::Phonetic => &PHONETIC,
+            Self::Status => &STATUS,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for InsurancePlanSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = InsurancePlanSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
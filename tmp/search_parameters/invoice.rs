@@ -0,0 +1,382 @@
+//! Invoice search parameters
+
+use super::*;
+
+/// `account`: Account that is being balanced
+pub const ACCOUNT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "account",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-account",
+    r#type: SearchParamType::Reference,
+    expression: Some("Invoice.account"),
+    targets: &["Account"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: Invoice date / posting date
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Invoice.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business Identifier for item
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Invoice.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `issuer`: Issuing Organization of Invoice
+pub const ISSUER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "issuer",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-issuer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Invoice.issuer"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `participant`: Individual who was involved
+pub const PARTICIPANT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "participant",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-participant",
+    r#type: SearchParamType::Reference,
+    expression: Some("Invoice.participant.actor"),
+    targets: &[
+        "Organization",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `participant-role`: Type of involvement in creation of this Invoice
+pub const PARTICIPANT_ROLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "participant-role",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-participant-role",
+    r#type: SearchParamType::Token,
+    expression: Some("Invoice.participant.role"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Recipient(s) of goods and services
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Invoice.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `recipient`: Recipient of this invoice
+pub const RECIPIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "recipient",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-recipient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Invoice.recipient"),
+    targets: &["Organization", "RelatedPerson", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: draft | issued | balanced | cancelled | entered-in-error
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Invoice.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Recipient(s) of goods and services
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Invoice.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `totalgross`: Gross total of this Invoice
+pub const TOTALGROSS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "totalgross",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-totalgross",
+    r#type: SearchParamType::Quantity,
+    expression: Some("Invoice.totalGross"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `totalnet`: Net total of this Invoice
+pub const TOTALNET: SearchParameterDefinition = SearchParameterDefinition {
+    code: "totalnet",
+    url: "http://hl7.org/fhir/SearchParameter/Invoice-totalnet",
+    r#type: SearchParamType::Quantity,
+    expression: Some("Invoice.totalNet"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: Type of Invoice
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Invoice.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Invoice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InvoiceSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `account`
+    Account,
+
+    /// `date`
+    Date,
+
+    /// `identifier`
+    Identifier,
+
+    /// `issuer`
+    Issuer,
+
+    /// `participant`
+    Participant,
+
+    /// `participant-role`
+    ParticipantRole,
+
+    /// `patient`
+    Patient,
+
+    /// `recipient`
+    Recipient,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `totalgross`
+    Totalgross,
+
+    /// `totalnet`
+    Totalnet,
+
+    /// `type`
+    Type,
+}
+
+impl InvoiceSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Account,
+        Self::Date,
+        Self::Identifier,
+        Self::Issuer,
+        Self::Participant,
+        Self::ParticipantRole,
+        Self::Patient,
+        Self::Recipient,
+        Self::Status,
+        Self::Subject,
+        Self::Totalgross,
+        Self::Totalnet,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Account => &ACCOUNT,
+            Self::Date => &DATE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Issuer => &ISSUER,
+            Self::Participant => &PARTICIPANT,
+            Self::ParticipantRole => &PARTICIPANT_ROLE,
+            Self::Patient => &PATIENT,
+            Self::Recipient => &RECIPIENT,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::Totalgross => &TOTALGROSS,
+            Self::Totalnet => &TOTALNET,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for InvoiceSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = InvoiceSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,415 @@
+//! Location search parameters
+
+use super::*;
+
+/// `address`: A (part of the) address of the location
+pub const ADDRESS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address",
+    url: "http://hl7.org/fhir/SearchParameter/Location-address",
+    r#type: SearchParamType::String,
+    expression: Some("Location.address"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-city`: A city specified in an address
+pub const ADDRESS_CITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-city",
+    url: "http://hl7.org/fhir/SearchParameter/Location-address-city",
+    r#type: SearchParamType::String,
+    expression: Some("Location.address.city"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-country`: A country specified in an address
+pub const ADDRESS_COUNTRY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-country",
+    url: "http://hl7.org/fhir/SearchParameter/Location-address-country",
+    r#type: SearchParamType::String,
+    expression: Some("Location.address.country"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-postalcode`: A postal code specified in an address
+pub const ADDRESS_POSTALCODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-postalcode",
+    url: "http://hl7.org/fhir/SearchParameter/Location-address-postalcode",
+    r#type: SearchParamType::String,
+    expression: Some("Location.address.postalCode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-state`: A state specified in an address
+pub const ADDRESS_STATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-state",
+    url: "http://hl7.org/fhir/SearchParameter/Location-address-state",
+    r#type: SearchParamType::String,
+    expression: Some("Location.address.state"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-use`: A use code specified in an address
+pub const ADDRESS_USE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-use",
+    url: "http://hl7.org/fhir/SearchParameter/Location-address-use",
+    r#type: SearchParamType::Token,
+    expression: Some("Location.address.use"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `characteristic`: One of the Location's characteristics
+pub const CHARACTERISTIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "characteristic",
+    url: "http://hl7.org/fhir/SearchParameter/Location-characteristic",
+    r#type: SearchParamType::Token,
+    expression: Some("Location.characteristic"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `contains`: Select locations that contain the specified co-ordinates
+pub const CONTAINS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "contains",
+    url: "http://hl7.org/fhir/SearchParameter/Location-contains",
+    r#type: SearchParamType::Special,
+    expression: Some(
+        "Location.extension('http://hl7.org/fhir/StructureDefinition/location-boundary-geojson').value",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `endpoint`: Technical endpoints providing access to services operated for the location
+pub const ENDPOINT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "endpoint",
+    url: "http://hl7.org/fhir/SearchParameter/Location-endpoint",
+    r#type: SearchParamType::Reference,
+    expression: Some("Location.endpoint"),
+    targets: &["Endpoint"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: An identifier for the location
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Location-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Location.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A portion of the location's name or alias
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/Location-name",
+    r#type: SearchParamType::String,
+    expression: Some("Location.name | Location.alias"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `near`: Search for locations where the location.position is near to, or within a specified distance of, the provided coordinates expressed as \[latitude\]|\[longitude\]|\[distance\]|\[units\] (using the WGS84 datum, see notes).
+pub const NEAR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "near",
+    url: "http://hl7.org/fhir/SearchParameter/Location-near",
+    r#type: SearchParamType::Special,
+    expression: Some("Location.position"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `operational-status`: Searches for locations (typically bed/room) that have an operational status (e.g. contaminated, housekeeping)
+pub const OPERATIONAL_STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "operational-status",
+    url: "http://hl7.org/fhir/SearchParameter/Location-operational-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Location.operationalStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `organization`: Searches for locations that are managed by the provided organization
+pub const ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "organization",
+    url: "http://hl7.org/fhir/SearchParameter/Location-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("Location.managingOrganization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `partof`: A location of which this location is a part
+pub const PARTOF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "partof",
+    url: "http://hl7.org/fhir/SearchParameter/Location-partof",
+    r#type: SearchParamType::Reference,
+    expression: Some("Location.partOf"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Searches for locations with a specific kind of status
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Location-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Location.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: A code for the type of location
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/Location-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Location.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LocationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `address`
+    Address,
+
+    /// `address-city`
+    AddressCity,
+
+    /// `address-country`
+    AddressCountry,
+
+    /// `address-postalcode`
+    AddressPostalcode,
+
+    /// `address-state`
+    AddressState,
+
+    /// `address-use`
+    AddressUse,
+
+    /// `characteristic`
+    Characteristic,
+
+    /// `contains`
+    Contains,
+
+    /// `endpoint`
+    Endpoint,
+
+    /// `identifier`
+    Identifier,
+
+    /// `name`
+    Name,
+
+    /// `near`
+    Near,
+
+    /// `operational-status`
+    OperationalStatus,
+
+    /// `organization`
+    Organization,
+
+    /// `partof`
+    Partof,
+
+    /// `status`
+    Status,
+
+    /// `type`
+    Type,
+}
+
+impl LocationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Address,
+        Self::AddressCity,
+        Self::AddressCountry,
+        Self::AddressPostalcode,
+        Self::AddressState,
+        Self::AddressUse,
+        Self::Characteristic,
+        Self::Contains,
+        Self::Endpoint,
+        Self::Identifier,
+        Self::Name,
+        Self::Near,
+        Self::OperationalStatus,
+        Self::Organization,
+        Self::Partof,
+        Self::Status,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Address => &ADDRESS,
+            Self::AddressCity => &ADDRESS_CITY,
+            Self::AddressCountry => &ADDRESS_COUNTRY,
+            Self::AddressPostalcode => &ADDRESS_POSTALCODE,
+            Self::AddressState => &ADDRESS_STATE,
+            Self::AddressUse => &ADDRESS_USE,
+            Self::Characteristic => &CHARACTERISTIC,
+            Self::Contains => &CONTAINS,
+            Self::Endpoint => &ENDPOINT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Name => &NAME,
+            Self::Near => &NEAR,
+            Self::OperationalStatus => &OPERATIONAL_STATUS,
+            Self::Organization => &ORGANIZATION,
+            Self::Partof => &PARTOF,
+            Self::Status => &STATUS,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for LocationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = LocationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
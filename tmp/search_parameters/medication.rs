@@ -0,0 +1,304 @@
+//! Medication search parameters
+
+use super::*;
+
+/// `code`: Returns medications for a specific code
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Medication.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `expiration-date`: Returns medications in a batch with this expiration date
+pub const EXPIRATION_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "expiration-date",
+    url: "http://hl7.org/fhir/SearchParameter/Medication-expiration-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Medication.batch.expirationDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `form`: Returns medications for a specific dose form
+pub const FORM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "form",
+    url: "http://hl7.org/fhir/SearchParameter/Medication-form",
+    r#type: SearchParamType::Token,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Returns medications with this external identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Medication.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `ingredient`: Returns medications for this ingredient reference
+pub const INGREDIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ingredient",
+    url: "http://hl7.org/fhir/SearchParameter/Medication-ingredient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Medication.ingredient.item.reference"),
+    targets: &["Substance", "Medication"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `ingredient-code`: Returns medications for this ingredient code
+pub const INGREDIENT_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ingredient-code",
+    url: "http://hl7.org/fhir/SearchParameter/Medication-ingredient-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Medication.ingredient.item.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `lot-number`: Returns medications in a batch with this lot number
+pub const LOT_NUMBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "lot-number",
+    url: "http://hl7.org/fhir/SearchParameter/Medication-lot-number",
+    r#type: SearchParamType::Token,
+    expression: Some("Medication.batch.lotNumber"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `marketingauthorizationholder`: Returns medications made or sold for this marketing authorization holder
+pub const MARKETINGAUTHORIZATIONHOLDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "marketingauthorizationholder",
+    url: "http://hl7.org/fhir/SearchParameter/Medication-marketingauthorizationholder",
+    r#type: SearchParamType::Reference,
+    expression: Some("Medication.marketingAuthorizationHolder"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `serial-number`: Returns medications in a batch with this lot number
+pub const SERIAL_NUMBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "serial-number",
+    url: "http://hl7.org/fhir/SearchParameter/Medication-serial-number",
+    r#type: SearchParamType::Token,
+    expression: Some("Medication.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Returns medications for this status
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Medication-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Medication.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Medication.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MedicationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `code`
+    Code,
+
+    /// `expiration-date`
+    ExpirationDate,
+
+    /// `form`
+    Form,
+
+    /// `identifier`
+    Identifier,
+
+    /// `ingredient`
+    Ingredient,
+
+    /// `ingredient-code`
+    IngredientCode,
+
+    /// `lot-number`
+    LotNumber,
+
+    /// `marketingauthorizationholder`
+    Marketingauthorizationholder,
+
+    /// `serial-number`
+    SerialNumber,
+
+    /// `status`
+    Status,
+}
+
+impl MedicationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Code,
+        Self::ExpirationDate,
+        Self::Form,
+        Self::Identifier,
+        Self::Ingredient,
+        Self::IngredientCode,
+        Self::LotNumber,
+        Self::Marketingauthorizationholder,
+        Self::SerialNumber,
+        Self::Status,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Code => &CODE,
+            Self::ExpirationDate => &EXPIRATION_DATE,
+            Self::Form => &FORM,
+            Self::Identifier => &IDENTIFIER,
+            Self::Ingredient => &INGREDIENT,
+            Self::IngredientCode => &INGREDIENT_CODE,
+            Self::LotNumber => &LOT_NUMBER,
+            Self::Marketingauthorizationholder => &MARKETINGAUTHORIZATIONHOLDER,
+            Self::SerialNumber => &SERIAL_NUMBER,
+            Self::Status => &STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for MedicationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = MedicationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,391 @@
+//! MedicationAdministration search parameters
+
+use super::*;
+
+/// `code`: Return administrations of this medication code
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationAdministration.medication.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: Date administration happened (or did not happen)
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/medications-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "MedicationAdministration.occurence.ofType(dateTime) | MedicationAdministration.occurence.ofType(Period)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `device`: Return administrations with this administration device identity
+pub const DEVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "device",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationAdministration-device",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationAdministration.device.reference"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Return administrations that share this encounter
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/medications-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationAdministration.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Return administrations with this external identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationAdministration.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `medication`: Return administrations of this medication reference
+pub const MEDICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "medication",
+    url: "http://hl7.org/fhir/SearchParameter/medications-medication",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationAdministration.medication.reference"),
+    targets: &["Medication"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The identity of a patient to list administrations  for
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationAdministration.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: The identity of the individual who administered the medication
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationAdministration-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationAdministration.performer.actor.reference"),
+    targets: &["Device", "RelatedPerson", "PractitionerRole", "Practitioner", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer-device-code`: The identity of the individual who administered the medication
+pub const PERFORMER_DEVICE_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer-device-code",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationAdministration-performer-device-code",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationAdministration.performer.actor.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-given`: Reference to a resource (by instance)
+pub const REASON_GIVEN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-given",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationAdministration-reason-given",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationAdministration.reason.reference"),
+    targets: &["Observation", "DiagnosticReport", "Condition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-given-code`: Reasons for administering the medication
+pub const REASON_GIVEN_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-given-code",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationAdministration-reason-given-code",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationAdministration.reason.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-not-given`: Reasons for not administering the medication
+pub const REASON_NOT_GIVEN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-not-given",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationAdministration-reason-not-given",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationAdministration.statusReason"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `request`: The identity of a request to list administrations from
+pub const REQUEST: SearchParameterDefinition = SearchParameterDefinition {
+    code: "request",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationAdministration-request",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationAdministration.request"),
+    targets: &["MedicationRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: MedicationAdministration event status (for example one of active/paused/completed/nullified)
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/medications-status",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationAdministration.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The identity of the individual or group to list administrations for
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationAdministration-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationAdministration.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of MedicationAdministration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MedicationAdministrationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `code`
+    Code,
+
+    /// `date`
+    Date,
+
+    /// `device`
+    Device,
+
+    /// `encounter`
+    Encounter,
+
+    /// `identifier`
+    Identifier,
+
+    /// `medication`
+    Medication,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `performer-device-code`
+    PerformerDeviceCode,
+
+    /// `reason-given`
+    ReasonGiven,
+
+    /// `reason-given-code`
+    ReasonGivenCode,
+
+    /// `reason-not-given`
+    ReasonNotGiven,
+
+    /// `request`
+    Request,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl MedicationAdministrationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Code,
+        Self::Date,
+        Self::Device,
+        Self::Encounter,
+        Self::Identifier,
+        Self::Medication,
+        Self::Patient,
+        Self::Performer,
+        Self::PerformerDeviceCode,
+        Self::ReasonGiven,
+        Self::ReasonGivenCode,
+        Self::ReasonNotGiven,
+        Self::Request,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Code => &CODE,
+            Self::Date => &DATE,
+            Self::Device => &DEVICE,
+            Self::Encounter => &ENCOUNTER,
+            Self::Identifier => &IDENTIFIER,
+            Self::Medication => &MEDICATION,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::PerformerDeviceCode => &PERFORMER_DEVICE_CODE,
+            Self::ReasonGiven => &REASON_GIVEN,
+            Self::ReasonGivenCode => &REASON_GIVEN_CODE,
+            Self::ReasonNotGiven => &REASON_NOT_GIVEN,
+            Self::Request => &REQUEST,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for MedicationAdministrationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = MedicationAdministrationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,457 @@
+//! MedicationDispense search parameters
+
+use super::*;
+
+/// `code`: Returns dispenses of this medicine code
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationDispense.medication.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `destination`: Returns dispenses that should be sent to a specific destination
+pub const DESTINATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "destination",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-destination",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.destination"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Returns dispenses with a specific encounter
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Returns dispenses with this external identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationDispense.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: Returns dispense for a given location
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `medication`: Returns dispenses of this medicine resource
+pub const MEDICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "medication",
+    url: "http://hl7.org/fhir/SearchParameter/medications-medication",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.medication.reference"),
+    targets: &["Medication"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The identity of a patient to list dispenses  for
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: Returns dispenses performed by a specific individual
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.performer.actor"),
+    targets: &[
+        "Organization",
+        "Device",
+        "CareTeam",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `prescription`: The identity of a prescription to list dispenses from
+pub const PRESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "prescription",
+    url: "http://hl7.org/fhir/SearchParameter/medications-prescription",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.authorizingPrescription"),
+    targets: &["MedicationRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `receiver`: The identity of a receiver to list dispenses for
+pub const RECEIVER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "receiver",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-receiver",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.receiver"),
+    targets: &[
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Location",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `recorded`: Returns dispenses where dispensing activity began on this date
+pub const RECORDED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "recorded",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-recorded",
+    r#type: SearchParamType::Date,
+    expression: Some("MedicationDispense.recorded"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `responsibleparty`: Returns dispenses with the specified responsible party
+pub const RESPONSIBLEPARTY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "responsibleparty",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-responsibleparty",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.substitution.responsibleParty"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Returns dispenses with a specified dispense status
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/medications-status",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationDispense.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The identity of a patient for whom to list dispenses
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationDispense.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: Returns dispenses of a specific type
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationDispense.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `whenhandedover`: Returns dispenses handed over on this date
+pub const WHENHANDEDOVER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "whenhandedover",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-whenhandedover",
+    r#type: SearchParamType::Date,
+    expression: Some("MedicationDispense.whenHandedOver"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `whenprepared`: Returns dispenses prepared on this date
+pub const WHENPREPARED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "whenprepared",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationDispense-whenprepared",
+    r#type: SearchParamType::Date,
+    expression: Some("MedicationDispense.whenPrepared"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of MedicationDispense.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MedicationDispenseSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `code`
+    Code,
+
+    /// `destination`
+    Destination,
+
+    /// `encounter`
+    Encounter,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `medication`
+    Medication,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `prescription`
+    Prescription,
+
+    /// `receiver`
+    Receiver,
+
+    /// `recorded`
+    Recorded,
+
+    /// `responsibleparty`
+    Responsibleparty,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `type`
+    Type,
+
+    /// `whenhandedover`
+    Whenhandedover,
+
+    /// `whenprepared`
+    Whenprepared,
+}
+
+impl MedicationDispenseSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Code,
+        Self::Destination,
+        Self::Encounter,
+        Self::Identifier,
+        Self::Location,
+        Self::Medication,
+        Self::Patient,
+        Self::Performer,
+        Self::Prescription,
+        Self::Receiver,
+        Self::Recorded,
+        Self::Responsibleparty,
+        Self::Status,
+        Self::Subject,
+        Self::Type,
+        Self::Whenhandedover,
+        Self::Whenprepared,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Code => &CODE,
+            Self::Destination => &DESTINATION,
+            Self::Encounter => &ENCOUNTER,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::Medication => &MEDICATION,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::Prescription => &PRESCRIPTION,
+            Self::Receiver => &RECEIVER,
+            Self::Recorded => &RECORDED,
+            Self::Responsibleparty => &RESPONSIBLEPARTY,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::Type => &TYPE,
+            Self::Whenhandedover => &WHENHANDEDOVER,
+            Self::Whenprepared => &WHENPREPARED,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for MedicationDispenseSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = MedicationDispenseSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,406 @@
+//! MedicationKnowledge search parameters
+
+use super::*;
+
+/// `classification`: Specific category assigned to the medication
+pub const CLASSIFICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "classification",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-classification",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.medicineClassification.classification"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `classification-type`: The type of category for the medication (for example, therapeutic classification, therapeutic sub-classification)
+pub const CLASSIFICATION_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "classification-type",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-classification-type",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.medicineClassification.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: Code that identifies this medication
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-code",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `doseform`: powder | tablets | capsule +
+pub const DOSEFORM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "doseform",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-doseform",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.definitional.doseForm"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business identifier for this medication
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `ingredient`: Reference to a resource (by instance)
+pub const INGREDIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ingredient",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-ingredient",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationKnowledge.definitional.ingredient.item.reference"),
+    targets: &["Substance"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `ingredient-code`: Reference to a concept (by class)
+pub const INGREDIENT_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ingredient-code",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-ingredient-code",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.definitional.ingredient.item.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `monitoring-program-name`: Name of the reviewing program
+pub const MONITORING_PROGRAM_NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "monitoring-program-name",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-monitoring-program-name",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.monitoringProgram.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `monitoring-program-type`: Type of program under which the medication is monitored
+pub const MONITORING_PROGRAM_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "monitoring-program-type",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-monitoring-program-type",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.monitoringProgram.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `monograph`: Associated documentation about the medication
+pub const MONOGRAPH: SearchParameterDefinition = SearchParameterDefinition {
+    code: "monograph",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-monograph",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationKnowledge.monograph.source"),
+    targets: &["DocumentReference"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `monograph-type`: The category of medication document
+pub const MONOGRAPH_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "monograph-type",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-monograph-type",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.monograph.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `packaging-cost`: The cost of the packaged medication, if the cost is Money
+pub const PACKAGING_COST: SearchParameterDefinition = SearchParameterDefinition {
+    code: "packaging-cost",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-packaging-cost",
+    r#type: SearchParamType::Quantity,
+    expression: None,
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `packaging-cost-concept`: The cost of the packaged medication, if the cost is a CodeableConcept
+pub const PACKAGING_COST_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "packaging-cost-concept",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-packaging-cost-concept",
+    r#type: SearchParamType::Token,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `product-type`: Category of the medication or product
+pub const PRODUCT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "product-type",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-product-type",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.productType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `source-cost`: The source or owner for the price information
+pub const SOURCE_COST: SearchParameterDefinition = SearchParameterDefinition {
+    code: "source-cost",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationKnowledge-source-cost",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.cost.source"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: active | inactive | entered-in-error
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-status",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationKnowledge.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of MedicationKnowledge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MedicationKnowledgeSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `classification`
+    Classification,
+
+    /// `classification-type`
+    ClassificationType,
+
+    /// `code`
+    Code,
+
+    /// `doseform`
+    Doseform,
+
+    /// `identifier`
+    Identifier,
+
+    /// `ingredient`
+    Ingredient,
+
+    /// `ingredient-code`
+    IngredientCode,
+
+    /// `monitoring-program-name`
+    MonitoringProgramName,
+
+    /// `monitoring-program-type`
+    MonitoringProgramType,
+
+    /// `monograph`
+    Monograph,
+
+    /// `monograph-type`
+    MonographType,
+
+    /// `packaging-cost`
+    PackagingCost,
+
+    /// `packaging-cost-concept`
+    PackagingCostConcept,
+
+    /// `product-type`
+    ProductType,
+
+    /// `source-cost`
+    SourceCost,
+
+    /// `status`
+    Status,
+}
+
+impl MedicationKnowledgeSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Classification,
+        Self::ClassificationType,
+        Self::Code,
+        Self::Doseform,
+        Self::Identifier,
+        Self::Ingredient,
+        Self::IngredientCode,
+        Self::MonitoringProgramName,
+        Self::MonitoringProgramType,
+        Self::Monograph,
+        Self::MonographType,
+        Self::PackagingCost,
+        Self::PackagingCostConcept,
+        Self::ProductType,
+        Self::SourceCost,
+        Self::Status,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Classification => &CLASSIFICATION,
+            Self::ClassificationType => &CLASSIFICATION_TYPE,
+            Self::Code => &CODE,
+            Self::Doseform => &DOSEFORM,
+            Self::Identifier => &IDENTIFIER,
+            Self::Ingredient => &INGREDIENT,
+            Self::IngredientCode => &INGREDIENT_CODE,
+            Self::MonitoringProgramName => &MONITORING_PROGRAM_NAME,
+            Self::MonitoringProgramType => &MONITORING_PROGRAM_TYPE,
+            Self::Monograph => &MONOGRAPH,
+            Self::MonographType => &MONOGRAPH_TYPE,
+            Self::PackagingCost => &PACKAGING_COST,
+            Self::PackagingCostConcept => &PACKAGING_COST_CONCEPT,
+            Self::ProductType => &PRODUCT_TYPE,
+            Self::SourceCost => &SOURCE_COST,
+            Self::Status => &STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for MedicationKnowledgeSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = MedicationKnowledgeSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,451 @@
+//! MedicationRequest search parameters
+
+use super::*;
+
+/// `authoredon`: Return prescriptions written on this date
+pub const AUTHOREDON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "authoredon",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-authoredon",
+    r#type: SearchParamType::Date,
+    expression: Some("MedicationRequest.authoredOn"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Returns prescriptions with different categories
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-category",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationRequest.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: Return prescriptions of this medication code
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationRequest.medication.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `combo-date`: Returns medication request to be administered on a specific date or within a date range
+pub const COMBO_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "combo-date",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-combo-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "MedicationRequest.dosageInstruction.timing.event | (MedicationRequest.dosageInstruction.timing.repeat.bounds.ofType(Period))",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Return prescriptions with this encounter identifier
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/medications-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationRequest.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `group-identifier`: Composite request this is part of
+pub const GROUP_IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "group-identifier",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-group-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationRequest.groupIdentifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Return prescriptions with this external identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationRequest.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `intended-dispenser`: Returns prescriptions intended to be dispensed by this Organization
+pub const INTENDED_DISPENSER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "intended-dispenser",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-intended-dispenser",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationRequest.dispenseRequest.dispenser"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `intended-performer`: Returns the intended performer of the administration of the medication request
+pub const INTENDED_PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "intended-performer",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-intended-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationRequest.performer"),
+    targets: &[
+        "HealthcareService",
+        "Organization",
+        "CareTeam",
+        "RelatedPerson",
+        "PractitionerRole",
+        "DeviceDefinition",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `intended-performertype`: Returns requests for a specific type of performer
+pub const INTENDED_PERFORMERTYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "intended-performertype",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-intended-performertype",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationRequest.performerType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `intent`: Returns prescriptions with different intents
+pub const INTENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "intent",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-intent",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationRequest.intent"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `medication`: Return prescriptions for this medication reference
+pub const MEDICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "medication",
+    url: "http://hl7.org/fhir/SearchParameter/medications-medication",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationRequest.medication.reference"),
+    targets: &["Medication"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Returns prescriptions for a specific patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationRequest.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `priority`: Returns prescriptions with different priorities
+pub const PRIORITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "priority",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-priority",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationRequest.priority"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requester`: Returns prescriptions prescribed by this prescriber
+pub const REQUESTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requester",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-requester",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationRequest.requester"),
+    targets: &[
+        "Organization",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Status of the prescription
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/medications-status",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicationRequest.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The identity of a patient to list orders  for
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/MedicationRequest-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicationRequest.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of MedicationRequest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MedicationRequestSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `authoredon`
+    Authoredon,
+
+    /// `category`
+    Category,
+
+    /// `code`
+    Code,
+
+    /// `combo-date`
+    ComboDate,
+
+    /// `encounter`
+    Encounter,
+
+    /// `group-identifier`
+    GroupIdentifier,
+
+    /// `identifier`
+    Identifier,
+
+    /// `intended-dispenser`
+    IntendedDispenser,
+
+    /// `intended-performer`
+    IntendedPerformer,
+
+    /// `intended-performertype`
+    IntendedPerformertype,
+
+    /// `intent`
+    Intent,
+
+    /// `medication`
+    Medication,
+
+    /// `patient`
+    Patient,
+
+    /// `priority`
+    Priority,
+
+    /// `requester`
+    Requester,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl MedicationRequestSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Authoredon,
+        Self::Category,
+        Self::Code,
+        Self::ComboDate,
+        Self::Encounter,
+        Self::GroupIdentifier,
+        Self::Identifier,
+        Self::IntendedDispenser,
+        Self::IntendedPerformer,
+        Self::IntendedPerformertype,
+        Self::Intent,
+        Self::Medication,
+        Self::Patient,
+        Self::Priority,
+        Self::Requester,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Authoredon => &AUTHOREDON,
+            Self::Category => &CATEGORY,
+            Self::Code => &CODE,
+            Self::ComboDate => &COMBO_DATE,
+            Self::Encounter => &ENCOUNTER,
+            Self::GroupIdentifier => &GROUP_IDENTIFIER,
+            Self::Identifier => &IDENTIFIER,
+            Self::IntendedDispenser => &INTENDED_DISPENSER,
+            Self::IntendedPerformer => &INTENDED_PERFORMER,
+            Self::IntendedPerformertype => &INTENDED_PERFORMERTYPE,
+            Self::Intent => &INTENT,
+            Self::Medication => &MEDICATION,
+            Self::Patient => &PATIENT,
+            Self::Priority => &PRIORITY,
+            Self::Requester => &REQUESTER,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for MedicationRequestSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = MedicationRequestSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
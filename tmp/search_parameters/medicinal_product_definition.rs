@@ -0,0 +1,330 @@
+//! MedicinalProductDefinition search parameters
+
+use super::*;
+
+/// `characteristic`: Allows the key product features to be recorded, such as "sugar free", "modified release", "parallel import"
+pub const CHARACTERISTIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "characteristic",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-characteristic",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "MedicinalProductDefinition.characteristic.value.ofType(Quantity) | MedicinalProductDefinition.characteristic.value.ofType(CodeableConcept)",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `characteristic-type`: A category for the characteristic
+pub const CHARACTERISTIC_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "characteristic-type",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-characteristic-type",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicinalProductDefinition.characteristic.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `contact`: A product specific contact, person (in a role), or an organization
+pub const CONTACT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "contact",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-contact",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicinalProductDefinition.contact.contact"),
+    targets: &["Organization", "PractitionerRole"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `domain`: If this medicine applies to human or veterinary uses
+pub const DOMAIN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "domain",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-domain",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicinalProductDefinition.domain"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business identifier for this product. Could be an MPID
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicinalProductDefinition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `ingredient`: An ingredient of this product
+pub const INGREDIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ingredient",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-ingredient",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicinalProductDefinition.ingredient"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `master-file`: A master file for to the medicinal product (e.g. Pharmacovigilance System Master File)
+pub const MASTER_FILE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "master-file",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-master-file",
+    r#type: SearchParamType::Reference,
+    expression: Some("MedicinalProductDefinition.masterFile"),
+    targets: &["DocumentReference"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: The full product name
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-name",
+    r#type: SearchParamType::String,
+    expression: Some("MedicinalProductDefinition.name.productName"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name-language`: Language code for this name
+pub const NAME_LANGUAGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name-language",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-name-language",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicinalProductDefinition.name.usage.language"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `product-classification`: Allows the product to be classified by various systems
+pub const PRODUCT_CLASSIFICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "product-classification",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-product-classification",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicinalProductDefinition.classification"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status within the lifecycle of this product record. A high-level status, this is not intended to duplicate details carried elsewhere such as legal status, or authorization status
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-status",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicinalProductDefinition.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: Regulatory type, e.g. Investigational or Authorized
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/MedicinalProductDefinition-type",
+    r#type: SearchParamType::Token,
+    expression: Some("MedicinalProductDefinition.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of MedicinalProductDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MedicinalProductDefinitionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `characteristic`
+    Characteristic,
+
+    /// `characteristic-type`
+    CharacteristicType,
+
+    /// `contact`
+    Contact,
+
+    /// `domain`
+    Domain,
+
+    /// `identifier`
+    Identifier,
+
+    /// `ingredient`
+    Ingredient,
+
+    /// `master-file`
+    MasterFile,
+
+    /// `name`
+    Name,
+
+    /// `name-language`
+    NameLanguage,
+
+    /// `product-classification`
+    ProductClassification,
+
+    /// `status`
+    Status,
+
+    /// `type`
+    Type,
+}
+
+impl MedicinalProductDefinitionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Characteristic,
+        Self::CharacteristicType,
+        Self::Contact,
+        Self::Domain,
+        Self::Identifier,
+        Self::Ingredient,
+        Self::MasterFile,
+        Self::Name,
+        Self::NameLanguage,
+        Self::ProductClassification,
+        Self::Status,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Characteristic => &CHARACTERISTIC,
+            Self::CharacteristicType => &CHARACTERISTIC_TYPE,
+            Self::Contact => &CONTACT,
+            Self::Domain => &DOMAIN,
+            Self::Identifier => &IDENTIFIER,
+            Self::Ingredient => &INGREDIENT,
+            Self::MasterFile => &MASTER_FILE,
+            Self::Name => &NAME,
+            Self::NameLanguage => &NAME_LANGUAGE,
+            Self::ProductClassification => &PRODUCT_CLASSIFICATION,
+            Self::Status => &STATUS,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for MedicinalProductDefinitionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = MedicinalProductDefinitionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
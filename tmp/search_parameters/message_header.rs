@@ -0,0 +1,472 @@
+//! MessageHeader search parameters
+
+use super::*;
+
+/// `author`: The source of the decision
+pub const AUTHOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "author",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-author",
+    r#type: SearchParamType::Reference,
+    expression: Some("MessageHeader.author"),
+    targets: &["Device", "Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: ok | transient-error | fatal-error
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-code",
+    r#type: SearchParamType::Token,
+    expression: Some("MessageHeader.response.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `destination`: Name of system
+pub const DESTINATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "destination",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-destination",
+    r#type: SearchParamType::String,
+    expression: Some("MessageHeader.destination.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `event`: Code for the event this message represents or link to event definition
+pub const EVENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "event",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-event",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "MessageHeader.event.ofType(Coding) | MessageHeader.event.ofType(canonical)",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `focus`: The actual content of the message
+pub const FOCUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "focus",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-focus",
+    r#type: SearchParamType::Reference,
+    expression: Some("MessageHeader.focus"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `receiver`: Intended "real-world" recipient for the data
+pub const RECEIVER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "receiver",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-receiver",
+    r#type: SearchParamType::Reference,
+    expression: Some("MessageHeader.destination.receiver"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `response-id`: Id of original message
+pub const RESPONSE_ID: SearchParameterDefinition = SearchParameterDefinition {
+    code: "response-id",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-response-id",
+    r#type: SearchParamType::Token,
+    expression: Some("MessageHeader.response.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `responsible`: Final responsibility for event
+pub const RESPONSIBLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "responsible",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-responsible",
+    r#type: SearchParamType::Reference,
+    expression: Some("MessageHeader.responsible"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `sender`: Real world sender of the message
+pub const SENDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "sender",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-sender",
+    r#type: SearchParamType::Reference,
+    expression: Some("MessageHeader.sender"),
+    targets: &["Device", "Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `source`: Name of system
+pub const SOURCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "source",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-source",
+    r#type: SearchParamType::String,
+    expression: Some("MessageHeader.source.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `target`: Particular delivery destination within the destination
+pub const TARGET: SearchParameterDefinition = SearchParameterDefinition {
+    code: "target",
+    url: "http://hl7.org/fhir/SearchParameter/MessageHeader-target",
+    r#type: SearchParamType::Reference,
+    expression: Some("MessageHeader.destination.target"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of MessageHeader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MessageHeaderSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `author`
+    Author,
+
+    /// `code`
+    Code,
+
+    /// `destination`
+    Destination,
+
+    /// `event`
+    Event,
+
+    /// `focus`
+    Focus,
+
+    /// `receiver`
+    Receiver,
+
+    /// `response-id`
+    ResponseId,
+
+    /// `responsible`
+    Responsible,
+
+    /// `sender`
+    Sender,
+
+    /// `source`
+    Source,
+
+    /// `target`
+    Target,
+}
+
+impl MessageHeaderSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Author,
+        Self::Code,
+        Self::Destination,
+        Self::Event,
+        Self::Focus,
+        Self::Receiver,
+        Self::ResponseId,
+        Self::Responsible,
+        Self::Sender,
+        Self::Source,
+        Self::Target,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Author => &AUTHOR,
+            Self::Code => &CODE,
+            Self::Destination => &DESTINATION,
+            Self::Event => &EVENT,
+            Self::Focus => &FOCUS,
+            Self::Receiver => &RECEIVER,
+            Self::ResponseId => &RESPONSE_ID,
+            Self::Responsible => &RESPONSIBLE,
+            Self::Sender => &SENDER,
+            Self::Source => &SOURCE,
+            Self::Target => &TARGET,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for MessageHeaderSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = MessageHeaderSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,628 @@
+//! Search parameters
+//!
+//! The search parameters of each resource type, including the search
+//! parameters that it inherits from `Resource` and `DomainResource`.
+
+/// The type of a search parameter, which defines how it matches values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchParamType {
+    Number,
+    Date,
+    String,
+    Token,
+    Reference,
+    Composite,
+    Quantity,
+    Uri,
+    Special,
+}
+
+impl SearchParamType {
+    /// The code, such as `token`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Number => "number",
+            Self::Date => "date",
+            Self::String => "string",
+            Self::Token => "token",
+            Self::Reference => "reference",
+            Self::Composite => "composite",
+            Self::Quantity => "quantity",
+            Self::Uri => "uri",
+            Self::Special => "special",
+        }
+    }
+
+    /// The modifiers that a search parameter of this type allows.
+    pub fn modifiers(self) -> &'static [SearchModifier] {
+        use SearchModifier::*;
+        match self {
+            Self::Number | Self::Date | Self::Quantity => &[Missing],
+            Self::String => &[Contains, Exact, Missing, Text],
+            Self::Token => {
+                &[
+                    Above,
+                    Below,
+                    CodeText,
+                    In,
+                    Missing,
+                    Not,
+                    NotIn,
+                    OfType,
+                    Text,
+                    TextAdvanced,
+                ]
+            }
+            Self::Reference => {
+                &[Above, Below, CodeText, Identifier, Missing, Text, TextAdvanced, Type]
+            }
+            Self::Uri => &[Above, Below, Contains, Missing],
+            Self::Composite | Self::Special => &[],
+        }
+    }
+}
+
+/// A search modifier, such as `:missing` in `gender:missing=true`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchModifier {
+    Above,
+    Below,
+    CodeText,
+    Contains,
+    Exact,
+    Identifier,
+    In,
+    Missing,
+    Not,
+    NotIn,
+    OfType,
+    Text,
+    TextAdvanced,
+
+    /// A resource type, such as `:Patient` in `subject:Patient=123`.
+    Type,
+}
+
+impl SearchModifier {
+    /// The code, such as `missing`, or `[type]` for a resource type.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Above => "above",
+            Self::Below => "below",
+            Self::CodeText => "code-text",
+            Self::Contains => "contains",
+            Self::Exact => "exact",
+            Self::Identifier => "identifier",
+            Self::In => "in",
+            Self::Missing => "missing",
+            Self::Not => "not",
+            Self::NotIn => "not-in",
+            Self::OfType => "of-type",
+            Self::Text => "text",
+            Self::TextAdvanced => "text-advanced",
+            Self::Type => "[type]",
+        }
+    }
+}
+
+/// A search comparator, such as `ge` in `date=ge2020-01-01`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchComparator {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+    Sa,
+    Eb,
+    Ap,
+}
+
+impl SearchComparator {
+    /// The code, such as `ge`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Eq => "eq",
+            Self::Ne => "ne",
+            Self::Gt => "gt",
+            Self::Lt => "lt",
+            Self::Ge => "ge",
+            Self::Le => "le",
+            Self::Sa => "sa",
+            Self::Eb => "eb",
+            Self::Ap => "ap",
+        }
+    }
+}
+
+/// One component of a composite search parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SearchParameterComponent {
+    /// The URL of the component's search parameter definition.
+    pub definition: &'static str,
+
+    /// The FHIRPath expression of the component, relative to the composite.
+    pub expression: &'static str,
+}
+
+/// The definition of one search parameter of one resource type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SearchParameterDefinition {
+    /// The code in a search URL, such as `_id` or `code`.
+    pub code: &'static str,
+
+    /// The canonical URL of the SearchParameter.
+    pub url: &'static str,
+    pub r#type: SearchParamType,
+
+    /// The FHIRPath expression of the values, for this resource type, if
+    /// any; a special search parameter may have none.
+    pub expression: Option<&'static str>,
+
+    /// The resource types that a reference search parameter may refer to.
+    pub targets: &'static [&'static str],
+    pub comparators: &'static [SearchComparator],
+    pub components: &'static [SearchParameterComponent],
+    pub multiple_or: Option<bool>,
+}
+
+impl SearchParameterDefinition {
+    /// The modifiers that this search parameter allows, by its type.
+    pub fn modifiers(&self) -> &'static [SearchModifier] {
+        self.r#type.modifiers()
+    }
+}
+
+pub mod account;
+
+pub mod activity_definition;
+
+pub mod actor_definition;
+
+pub mod administrable_product_definition;
+
+pub mod adverse_event;
+
+pub mod allergy_intolerance;
+
+pub mod appointment;
+
+pub mod appointment_response;
+
+pub mod artifact_assessment;
+
+pub mod audit_event;
+
+pub mod basic;
+
+pub mod biologically_derived_product;
+
+pub mod biologically_derived_product_dispense;
+
+pub mod body_structure;
+
+pub mod bundle;
+
+pub mod capability_statement;
+
+pub mod care_plan;
+
+pub mod care_team;
+
+pub mod charge_item;
+
+pub mod charge_item_definition;
+
+pub mod citation;
+
+pub mod claim;
+
+pub mod claim_response;
+
+pub mod clinical_impression;
+
+pub mod clinical_use_definition;
+
+pub mod code_system;
+
+pub mod communication;
+
+pub mod communication_request;
+
+pub mod compartment_definition;
+
+pub mod composition;
+
+pub mod concept_map;
+
+pub mod condition;
+
+pub mod condition_definition;
+
+pub mod consent;
+
+pub mod contract;
+
+pub mod coverage;
+
+pub mod coverage_eligibility_request;
+
+pub mod coverage_eligibility_response;
+
+pub mod detected_issue;
+
+pub mod device;
+
+pub mod device_association;
+
+pub mod device_definition;
+
+pub mod device_dispense;
+
+pub mod device_metric;
+
+pub mod device_request;
+
+pub mod device_usage;
+
+pub mod diagnostic_report;
+
+pub mod document_reference;
+
+pub mod domain_resource;
+
+pub mod encounter;
+
+pub mod encounter_history;
+
+pub mod endpoint;
+
+pub mod enrollment_request;
+
+pub mod enrollment_response;
+
+pub mod episode_of_care;
+
+pub mod event_definition;
+
+pub mod evidence;
+
+pub mod evidence_report;
+
+pub mod evidence_variable;
+
+pub mod example_scenario;
+
+pub mod explanation_of_benefit;
+
+pub mod family_member_history;
+
+pub mod flag;
+
+pub mod formulary_item;
+
+pub mod genomic_study;
+
+pub mod goal;
+
+pub mod graph_definition;
+
+pub mod group;
+
+pub mod guidance_response;
+
+pub mod healthcare_service;
+
+pub mod imaging_selection;
+
+pub mod imaging_study;
+
+pub mod immunization;
+
+pub mod immunization_evaluation;
+
+pub mod immunization_recommendation;
+
+pub mod implementation_guide;
+
+pub mod ingredient;
+
+pub mod insurance_plan;
+
+pub mod inventory_item;
+
+pub mod inventory_report;
+
+pub mod invoice;
+
+pub mod library;
+
+pub mod linkage;
+
+pub mod list;
+
+pub mod location;
+
+pub mod manufactured_item_definition;
+
+pub mod measure;
+
+pub mod measure_report;
+
+pub mod medication;
+
+pub mod medication_administration;
+
+pub mod medication_dispense;
+
+pub mod medication_knowledge;
+
+pub mod medication_request;
+
+pub mod medication_statement;
+
+pub mod medicinal_product_definition;
+
+pub mod message_definition;
+
+pub mod message_header;
+
+pub mod molecular_sequence;
+
+pub mod naming_system;
+
+pub mod nutrition_intake;
+
+pub mod nutrition_order;
+
+pub mod nutrition_product;
+
+pub mod observation;
+
+pub mod observation_definition;
+
+pub mod operation_definition;
+
+pub mod organization;
+
+pub mod organization_affiliation;
+
+pub mod packaged_product_definition;
+
+pub mod patient;
+
+pub mod payment_notice;
+
+pub mod payment_reconciliation;
+
+pub mod permission;
+
+pub mod person;
+
+pub mod plan_definition;
+
+pub mod practitioner;
+
+pub mod practitioner_role;
+
+pub mod procedure;
+
+pub mod provenance;
+
+pub mod questionnaire;
+
+pub mod questionnaire_response;
+
+pub mod regulated_authorization;
+
+pub mod related_person;
+
+pub mod request_orchestration;
+
+pub mod requirements;
+
+pub mod research_study;
+
+pub mod research_subject;
+
+pub mod resource;
+
+pub mod risk_assessment;
+
+pub mod schedule;
+
+pub mod search_parameter;
+
+pub mod service_request;
+
+pub mod slot;
+
+pub mod specimen;
+
+pub mod specimen_definition;
+
+pub mod structure_definition;
+
+pub mod structure_map;
+
+pub mod subscription;
+
+pub mod subscription_topic;
+
+pub mod substance;
+
+pub mod substance_definition;
+
+pub mod supply_delivery;
+
+pub mod supply_request;
+
+pub mod task;
+
+pub mod terminology_capabilities;
+
+pub mod test_plan;
+
+pub mod test_report;
+
+pub mod test_script;
+
+pub mod transport;
+
+pub mod value_set;
+
+pub mod verification_result;
+
+pub mod vision_prescription;
+pub use account::AccountSearchParameter;
+pub use activity_definition::ActivityDefinitionSearchParameter;
+pub use actor_definition::ActorDefinitionSearchParameter;
+pub use administrable_product_definition::AdministrableProductDefinitionSearchParameter;
+pub use adverse_event::AdverseEventSearchParameter;
+pub use allergy_intolerance::AllergyIntoleranceSearchParameter;
+pub use appointment::AppointmentSearchParameter;
+pub use appointment_response::AppointmentResponseSearchParameter;
+pub use artifact_assessment::ArtifactAssessmentSearchParameter;
+pub use audit_event::AuditEventSearchParameter;
+pub use basic::BasicSearchParameter;
+pub use biologically_derived_product::BiologicallyDerivedProductSearchParameter;
+pub use biologically_derived_product_dispense::BiologicallyDerivedProductDispenseSearchParameter;
+pub use body_structure::BodyStructureSearchParameter;
+pub use bundle::BundleSearchParameter;
+pub use capability_statement::CapabilityStatementSearchParameter;
+pub use care_plan::CarePlanSearchParameter;
+pub use care_team::CareTeamSearchParameter;
+pub use charge_item::ChargeItemSearchParameter;
+pub use charge_item_definition::ChargeItemDefinitionSearchParameter;
+pub use citation::CitationSearchParameter;
+pub use claim::ClaimSearchParameter;
+pub use claim_response::ClaimResponseSearchParameter;
+pub use clinical_impression::ClinicalImpressionSearchParameter;
+pub use clinical_use_definition::ClinicalUseDefinitionSearchParameter;
+pub use code_system::CodeSystemSearchParameter;
+pub use communication::CommunicationSearchParameter;
+pub use communication_request::CommunicationRequestSearchParameter;
+pub use compartment_definition::CompartmentDefinitionSearchParameter;
+pub use composition::CompositionSearchParameter;
+pub use concept_map::ConceptMapSearchParameter;
+pub use condition::ConditionSearchParameter;
+pub use condition_definition::ConditionDefinitionSearchParameter;
+pub use consent::ConsentSearchParameter;
+pub use contract::ContractSearchParameter;
+pub use coverage::CoverageSearchParameter;
+pub use coverage_eligibility_request::CoverageEligibilityRequestSearchParameter;
+pub use coverage_eligibility_response::CoverageEligibilityResponseSearchParameter;
+pub use detected_issue::DetectedIssueSearchParameter;
+pub use device::DeviceSearchParameter;
+pub use device_association::DeviceAssociationSearchParameter;
+pub use device_definition::DeviceDefinitionSearchParameter;
+pub use device_dispense::DeviceDispenseSearchParameter;
+pub use device_metric::DeviceMetricSearchParameter;
+pub use device_request::DeviceRequestSearchParameter;
+pub use device_usage::DeviceUsageSearchParameter;
+pub use diagnostic_report::DiagnosticReportSearchParameter;
+pub use document_reference::DocumentReferenceSearchParameter;
+pub use domain_resource::DomainResourceSearchParameter;
+pub use encounter::EncounterSearchParameter;
+pub use encounter_history::EncounterHistorySearchParameter;
+pub use endpoint::EndpointSearchParameter;
+pub use enrollment_request::EnrollmentRequestSearchParameter;
+pub use enrollment_response::EnrollmentResponseSearchParameter;
+pub use episode_of_care::EpisodeOfCareSearchParameter;
+pub use event_definition::EventDefinitionSearchParameter;
+pub use evidence::EvidenceSearchParameter;
+pub use evidence_report::EvidenceReportSearchParameter;
+pub use evidence_variable::EvidenceVariableSearchParameter;
+pub use example_scenario::ExampleScenarioSearchParameter;
+pub use explanation_of_benefit::ExplanationOfBenefitSearchParameter;
+pub use family_member_history::FamilyMemberHistorySearchParameter;
+pub use flag::FlagSearchParameter;
+pub use formulary_item::FormularyItemSearchParameter;
+pub use genomic_study::GenomicStudySearchParameter;
+pub use goal::GoalSearchParameter;
+pub use graph_definition::GraphDefinitionSearchParameter;
+pub use group::GroupSearchParameter;
+pub use guidance_response::GuidanceResponseSearchParameter;
+pub use healthcare_service::HealthcareServiceSearchParameter;
+pub use imaging_selection::ImagingSelectionSearchParameter;
+pub use imaging_study::ImagingStudySearchParameter;
+pub use immunization::ImmunizationSearchParameter;
+pub use immunization_evaluation::ImmunizationEvaluationSearchParameter;
+pub use immunization_recommendation::ImmunizationRecommendationSearchParameter;
+pub use implementation_guide::ImplementationGuideSearchParameter;
+pub use ingredient::IngredientSearchParameter;
+pub use insurance_plan::InsurancePlanSearchParameter;
+pub use inventory_item::InventoryItemSearchParameter;
+pub use inventory_report::InventoryReportSearchParameter;
+pub use invoice::InvoiceSearchParameter;
+pub use library::LibrarySearchParameter;
+pub use linkage::LinkageSearchParameter;
+pub use list::ListSearchParameter;
+pub use location::LocationSearchParameter;
+pub use manufactured_item_definition::ManufacturedItemDefinitionSearchParameter;
+pub use measure::MeasureSearchParameter;
+pub use measure_report::MeasureReportSearchParameter;
+pub use medication::MedicationSearchParameter;
+pub use medication_administration::MedicationAdministrationSearchParameter;
+pub use medication_dispense::MedicationDispenseSearchParameter;
+pub use medication_knowledge::MedicationKnowledgeSearchParameter;
+pub use medication_request::MedicationRequestSearchParameter;
+pub use medication_statement::MedicationStatementSearchParameter;
+pub use medicinal_product_definition::MedicinalProductDefinitionSearchParameter;
+pub use message_definition::MessageDefinitionSearchParameter;
+pub use message_header::MessageHeaderSearchParameter;
+pub use molecular_sequence::MolecularSequenceSearchParameter;
+pub use naming_system::NamingSystemSearchParameter;
+pub use nutrition_intake::NutritionIntakeSearchParameter;
+pub use nutrition_order::NutritionOrderSearchParameter;
+pub use nutrition_product::NutritionProductSearchParameter;
+pub use observation::ObservationSearchParameter;
+pub use observation_definition::ObservationDefinitionSearchParameter;
+pub use operation_definition::OperationDefinitionSearchParameter;
+pub use organization::OrganizationSearchParameter;
+pub use organization_affiliation::OrganizationAffiliationSearchParameter;
+pub use packaged_product_definition::PackagedProductDefinitionSearchParameter;
+pub use patient::PatientSearchParameter;
+pub use payment_notice::PaymentNoticeSearchParameter;
+pub use payment_reconciliation::PaymentReconciliationSearchParameter;
+pub use permission::PermissionSearchParameter;
+pub use person::PersonSearchParameter;
+pub use plan_definition::PlanDefinitionSearchParameter;
+pub use practitioner::PractitionerSearchParameter;
+pub use practitioner_role::PractitionerRoleSearchParameter;
+pub use procedure::ProcedureSearchParameter;
+pub use provenance::ProvenanceSearchParameter;
+pub use questionnaire::QuestionnaireSearchParameter;
+pub use questionnaire_response::QuestionnaireResponseSearchParameter;
+pub use regulated_authorization::RegulatedAuthorizationSearchParameter;
+pub use related_person::RelatedPersonSearchParameter;
+pub use request_orchestration::RequestOrchestrationSearchParameter;
+pub use requirements::RequirementsSearchParameter;
+pub use research_study::ResearchStudySearchParameter;
+pub use research_subject::ResearchSubjectSearchParameter;
+pub use resource::ResourceSearchParameter;
+pub use risk_assessment::RiskAssessmentSearchParameter;
+pub use schedule::ScheduleSearchParameter;
+pub use search_parameter::SearchParameterSearchParameter;
+pub use service_request::ServiceRequestSearchParameter;
+pub use slot::SlotSearchParameter;
+pub use specimen::SpecimenSearchParameter;
+pub use specimen_definition::SpecimenDefinitionSearchParameter;
+pub use structure_definition::StructureDefinitionSearchParameter;
+pub use structure_map::StructureMapSearchParameter;
+pub use subscription::SubscriptionSearchParameter;
+pub use subscription_topic::SubscriptionTopicSearchParameter;
+pub use substance::SubstanceSearchParameter;
+pub use substance_definition::SubstanceDefinitionSearchParameter;
+pub use supply_delivery::SupplyDeliverySearchParameter;
+pub use supply_request::SupplyRequestSearchParameter;
+pub use task::TaskSearchParameter;
+pub use terminology_capabilities::TerminologyCapabilitiesSearchParameter;
+pub use test_plan::TestPlanSearchParameter;
+pub use test_report::TestReportSearchParameter;
+pub use test_script::TestScriptSearchParameter;
+pub use transport::TransportSearchParameter;
+pub use value_set::ValueSetSearchParameter;
+pub use verification_result::VerificationResultSearchParameter;
+pub use vision_prescription::VisionPrescriptionSearchParameter;
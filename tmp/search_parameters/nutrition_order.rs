@@ -0,0 +1,338 @@
+//! NutritionOrder search parameters
+
+use super::*;
+
+/// `additive`: Type of module component to add to the feeding
+pub const ADDITIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "additive",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-additive",
+    r#type: SearchParamType::Token,
+    expression: Some("NutritionOrder.enteralFormula.additive.type.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `datetime`: Return nutrition orders requested on this date
+pub const DATETIME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "datetime",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-datetime",
+    r#type: SearchParamType::Date,
+    expression: Some("NutritionOrder.dateTime"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Return nutrition orders with this encounter identifier
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("NutritionOrder.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `formula`: Type of enteral or infant formula
+pub const FORMULA: SearchParameterDefinition = SearchParameterDefinition {
+    code: "formula",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-formula",
+    r#type: SearchParamType::Token,
+    expression: Some("NutritionOrder.enteralFormula.baseFormulaType.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `group-identifier`: Composite Request ID
+pub const GROUP_IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "group-identifier",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-group-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("NutritionOrder.groupIdentifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Return nutrition orders with this external identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("NutritionOrder.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `oraldiet`: Type of diet that can be consumed orally (i.e., take via the mouth).
+pub const ORALDIET: SearchParameterDefinition = SearchParameterDefinition {
+    code: "oraldiet",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-oraldiet",
+    r#type: SearchParamType::Token,
+    expression: Some("NutritionOrder.oralDiet.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The identity of the individual or set of individuals who requires the diet, formula or nutritional supplement
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("NutritionOrder.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `provider`: The identity of the provider who placed the nutrition order
+pub const PROVIDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "provider",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-provider",
+    r#type: SearchParamType::Reference,
+    expression: Some("NutritionOrder.orderer"),
+    targets: &["PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Status of the nutrition order.
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-status",
+    r#type: SearchParamType::Token,
+    expression: Some("NutritionOrder.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The identity of the individual or set of individuals who requires the diet, formula or nutritional supplement
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("NutritionOrder.subject"),
+    targets: &["Group", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `supplement`: Type of supplement product requested
+pub const SUPPLEMENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "supplement",
+    url: "http://hl7.org/fhir/SearchParameter/NutritionOrder-supplement",
+    r#type: SearchParamType::Token,
+    expression: Some("NutritionOrder.supplement.type.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of NutritionOrder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NutritionOrderSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `additive`
+    Additive,
+
+    /// `datetime`
+    Datetime,
+
+    /// `encounter`
+    Encounter,
+
+    /// `formula`
+    Formula,
+
+    /// `group-identifier`
+    GroupIdentifier,
+
+    /// `identifier`
+    Identifier,
+
+    /// `oraldiet`
+    Oraldiet,
+
+    /// `patient`
+    Patient,
+
+    /// `provider`
+    Provider,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `supplement`
+    Supplement,
+}
+
+impl NutritionOrderSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Additive,
+        Self::Datetime,
+        Self::Encounter,
+        Self::Formula,
+        Self::GroupIdentifier,
+        Self::Identifier,
+        Self::Oraldiet,
+        Self::Patient,
+        Self::Provider,
+        Self::Status,
+        Self::Subject,
+        Self::Supplement,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Additive => &ADDITIVE,
+            Self::Datetime => &DATETIME,
+            Self::Encounter => &ENCOUNTER,
+            Self::Formula => &FORMULA,
+            Self::GroupIdentifier => &GROUP_IDENTIFIER,
+            Self::Identifier => &IDENTIFIER,
+            Self::Oraldiet => &ORALDIET,
+            Self::Patient => &PATIENT,
+            Self::Provider => &PROVIDER,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::Supplement => &SUPPLEMENT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for NutritionOrderSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = NutritionOrderSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
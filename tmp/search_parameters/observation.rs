@@ -0,0 +1,1177 @@
+//! Observation search parameters
+
+use super::*;
+
+/// `based-on`: Reference to the service request.
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.basedOn"),
+    targets: &[
+        "DeviceRequest",
+        "ServiceRequest",
+        "CarePlan",
+        "MedicationRequest",
+        "ImmunizationRecommendation",
+        "NutritionOrder",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: The classification of the type of observation
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: The code of the observation type
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code-value-concept`: Code and coded value parameter pair
+pub const CODE_VALUE_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code-value-concept",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-code-value-concept",
+    r#type: SearchParamType::Composite,
+    expression: Some("Observation"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/clinical-code",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-value-concept",
+            expression: "value.ofType(CodeableConcept)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `code-value-date`: Code and date/time value parameter pair
+pub const CODE_VALUE_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code-value-date",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-code-value-date",
+    r#type: SearchParamType::Composite,
+    expression: Some("Observation"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/clinical-code",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-value-date",
+            expression: "value.ofType(dateTime) | value.ofType(Period)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `code-value-quantity`: Code and quantity value parameter pair
+pub const CODE_VALUE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code-value-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-code-value-quantity",
+    r#type: SearchParamType::Composite,
+    expression: Some("Observation"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/clinical-code",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-value-quantity",
+            expression: "value.ofType(Quantity)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `code-value-string`: Code and string value parameter pair
+pub const CODE_VALUE_STRING: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code-value-string",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-code-value-string",
+    r#type: SearchParamType::Composite,
+    expression: Some("Observation"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/clinical-code",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-value-string",
+            expression: "value.ofType(string)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `combo-code`: The code of the observation type or component type
+pub const COMBO_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "combo-code",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-combo-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.code | Observation.component.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `combo-code-value-concept`: Code and coded value parameter pair, including in components
+pub const COMBO_CODE_VALUE_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "combo-code-value-concept",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-combo-code-value-concept",
+    r#type: SearchParamType::Composite,
+    expression: Some("Observation | Observation.component"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-combo-code",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-combo-value-concept",
+            expression: "value.ofType(CodeableConcept)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `combo-code-value-quantity`: Code and quantity value parameter pair, including in components
+pub const COMBO_CODE_VALUE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "combo-code-value-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-combo-code-value-quantity",
+    r#type: SearchParamType::Composite,
+    expression: Some("Observation | Observation.component"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-combo-code",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-combo-value-quantity",
+            expression: "value.ofType(Quantity)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `combo-data-absent-reason`: The reason why the expected value in the element Observation.value\[x\] or Observation.component.value\[x\] is missing.
+pub const COMBO_DATA_ABSENT_REASON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "combo-data-absent-reason",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-combo-data-absent-reason",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "Observation.dataAbsentReason | Observation.component.dataAbsentReason",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `combo-value-concept`: The value or component value of the observation, if the value is a CodeableConcept
+pub const COMBO_VALUE_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "combo-value-concept",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-combo-value-concept",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "Observation.value.ofType(CodeableConcept) | Observation.component.value.ofType(CodeableConcept)",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `combo-value-quantity`: The value or component value of the observation, if the value is a Quantity, or a SampledData (just search on the bounds of the values in sampled data)
+pub const COMBO_VALUE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "combo-value-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-combo-value-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some(
+        "Observation.value.ofType(Quantity) | Observation.value.ofType(SampledData) | Observation.component.value.ofType(Quantity) | Observation.component.value.ofType(SampledData)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `component-code`: The component code of the observation type
+pub const COMPONENT_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "component-code",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-component-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.component.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `component-code-value-concept`: Component code and component coded value parameter pair
+pub const COMPONENT_CODE_VALUE_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "component-code-value-concept",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-component-code-value-concept",
+    r#type: SearchParamType::Composite,
+    expression: Some("Observation.component"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-component-code",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-component-value-concept",
+            expression: "value.ofType(CodeableConcept)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `component-code-value-quantity`: Component code and component quantity value parameter pair
+pub const COMPONENT_CODE_VALUE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "component-code-value-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-component-code-value-quantity",
+    r#type: SearchParamType::Composite,
+    expression: Some("Observation.component"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-component-code",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/Observation-component-value-quantity",
+            expression: "value.ofType(Quantity)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `component-data-absent-reason`: The reason why the expected value in the element Observation.component.value\[x\] is missing.
+pub const COMPONENT_DATA_ABSENT_REASON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "component-data-absent-reason",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-component-data-absent-reason",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.component.dataAbsentReason"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `component-value-canonical`: URL contained in valueCanonical.
+pub const COMPONENT_VALUE_CANONICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "component-value-canonical",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-component-value-canonical",
+    r#type: SearchParamType::Uri,
+    expression: Some("Observation.component.value.ofType(canonical)"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `component-value-concept`: The value of the component observation, if the value is a CodeableConcept
+pub const COMPONENT_VALUE_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "component-value-concept",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-component-value-concept",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.component.value.ofType(CodeableConcept)"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `component-value-quantity`: The value of the component observation, if the value is a Quantity, or a SampledData (just search on the bounds of the values in sampled data)
+pub const COMPONENT_VALUE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "component-value-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-component-value-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some(
+        "Observation.component.value.ofType(Quantity) | Observation.component.value.ofType(SampledData)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `component-value-reference`: Reference contained in valueReference.
+pub const COMPONENT_VALUE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "component-value-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-component-value-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.component.value.ofType(Reference)"),
+    targets: &["MolecularSequence"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `data-absent-reason`: The reason why the expected value in the element Observation.value\[x\] is missing.
+pub const DATA_ABSENT_REASON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "data-absent-reason",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-data-absent-reason",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.dataAbsentReason"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: Clinically relevant time/time-period for observation
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "Observation.effective.ofType(dateTime) | Observation.effective.ofType(Period) | Observation.effective.ofType(Timing) | Observation.effective.ofType(instant)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `derived-from`: Related measurements the observation is made from
+pub const DERIVED_FROM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "derived-from",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-derived-from",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.derivedFrom"),
+    targets: &[
+        "ImagingStudy",
+        "DocumentReference",
+        "Observation",
+        "MolecularSequence",
+        "GenomicStudy",
+        "ImagingSelection",
+        "QuestionnaireResponse",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `device`: The Device that generated the observation data.
+pub const DEVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "device",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-device",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.device"),
+    targets: &["Device", "DeviceMetric"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: Encounter related to the observation
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `focus`: The focus of an observation when the focus is not the patient of record.
+pub const FOCUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "focus",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-focus",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.focus"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `has-member`: Related resource that belongs to the Observation group
+pub const HAS_MEMBER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "has-member",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-has-member",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.hasMember"),
+    targets: &["Observation", "MolecularSequence", "QuestionnaireResponse"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The unique id for a particular observation
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `method`: The method used for the observation
+pub const METHOD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "method",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-method",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.method"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `part-of`: Part of referenced event
+pub const PART_OF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "part-of",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-part-of",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.partOf"),
+    targets: &[
+        "ImagingStudy",
+        "Procedure",
+        "MedicationStatement",
+        "MedicationAdministration",
+        "GenomicStudy",
+        "Immunization",
+        "MedicationDispense",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The subject that the observation is about (if patient)
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: Who performed the observation
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.performer"),
+    targets: &[
+        "Organization",
+        "CareTeam",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specimen`: Specimen used for this observation
+pub const SPECIMEN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specimen",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-specimen",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.specimen"),
+    targets: &["Group", "Specimen"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the observation
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The subject that the observation is about
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.subject"),
+    targets: &[
+        "Device",
+        "Organization",
+        "Procedure",
+        "NutritionProduct",
+        "Group",
+        "Practitioner",
+        "BiologicallyDerivedProduct",
+        "Substance",
+        "Location",
+        "Patient",
+        "Medication",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `value-canonical`: URL contained in valueCanonical.
+pub const VALUE_CANONICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "value-canonical",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-value-canonical",
+    r#type: SearchParamType::Uri,
+    expression: Some("Observation.value.ofType(canonical)"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `value-concept`: The value of the observation, if the value is a CodeableConcept
+pub const VALUE_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "value-concept",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-value-concept",
+    r#type: SearchParamType::Token,
+    expression: Some("Observation.value.ofType(CodeableConcept)"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `value-date`: The value of the observation, if the value is a date or period of time
+pub const VALUE_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "value-date",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-value-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "Observation.value.ofType(dateTime) | Observation.value.ofType(Period)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `value-markdown`: The value of the observation, if the value is a string, and also searches in CodeableConcept.text
+pub const VALUE_MARKDOWN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "value-markdown",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-value-markdown",
+    r#type: SearchParamType::String,
+    expression: Some(
+        "Observation.value.ofType(markdown) | Observation.value.ofType(CodeableConcept).text",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `value-quantity`: The value of the observation, if the value is a Quantity, or a SampledData (just search on the bounds of the values in sampled data)
+pub const VALUE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "value-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-value-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some(
+        "Observation.value.ofType(Quantity) | Observation.value.ofType(SampledData)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `value-reference`: Reference contained in valueReference.
+pub const VALUE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "value-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Observation-value-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Observation.value.ofType(Reference)"),
+    targets: &["MolecularSequence"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Observation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ObservationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `category`
+    Category,
+
+    /// `code`
+    Code,
+
+    /// `code-value-concept`
+    CodeValueConcept,
+
+    /// `code-value-date`
+    CodeValueDate,
+
+    /// `code-value-quantity`
+    CodeValueQuantity,
+
+    /// `code-value-string`
+    CodeValueString,
+
+    /// `combo-code`
+    ComboCode,
+
+    /// `combo-code-value-concept`
+    ComboCodeValueConcept,
+
+    /// `combo-code-value-quantity`
+    ComboCodeValueQuantity,
+
+    /// `combo-data-absent-reason`
+    ComboDataAbsentReason,
+
+    /// `combo-value-concept`
+    ComboValueConcept,
+
+    /// `combo-value-quantity`
+    ComboValueQuantity,
+
+    /// `component-code`
+    ComponentCode,
+
+    /// `component-code-value-concept`
+    ComponentCodeValueConcept,
+
+    /// `component-code-value-quantity`
+    ComponentCodeValueQuantity,
+
+    /// `component-data-absent-reason`
+    ComponentDataAbsentReason,
+
+    /// `component-value-canonical`
+    ComponentValueCanonical,
+
+    /// `component-value-concept`
+    ComponentValueConcept,
+
+    /// `component-value-quantity`
+    ComponentValueQuantity,
+
+    /// `component-value-reference`
+    ComponentValueReference,
+
+    /// `data-absent-reason`
+    DataAbsentReason,
+
+    /// `date`
+    Date,
+
+    /// `derived-from`
+    DerivedFrom,
+
+    /// `device`
+    Device,
+
+    /// `encounter`
+    Encounter,
+
+    /// `focus`
+    Focus,
+
+    /// `has-member`
+    HasMember,
+
+    /// `identifier`
+    Identifier,
+
+    /// `method`
+    Method,
+
+    /// `part-of`
+    PartOf,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `specimen`
+    Specimen,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `value-canonical`
+    ValueCanonical,
+
+    /// `value-concept`
+    ValueConcept,
+
+    /// `value-date`
+    ValueDate,
+
+    /// `value-markdown`
+    ValueMarkdown,
+
+    /// `value-quantity`
+    ValueQuantity,
+
+    /// `value-reference`
+    ValueReference,
+}
+
+impl ObservationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::BasedOn,
+        Self::Category,
+        Self::Code,
+        Self::CodeValueConcept,
+        Self::CodeValueDate,
+        Self::CodeValueQuantity,
+        Self::CodeValueString,
+        Self::ComboCode,
+        Self::ComboCodeValueConcept,
+        Self::ComboCodeValueQuantity,
+        Self::ComboDataAbsentReason,
+        Self::ComboValueConcept,
+        Self::ComboValueQuantity,
+        Self::ComponentCode,
+        Self::ComponentCodeValueConcept,
+        Self::ComponentCodeValueQuantity,
+        Self::ComponentDataAbsentReason,
+        Self::ComponentValueCanonical,
+        Self::ComponentValueConcept,
+        Self::ComponentValueQuantity,
+        Self::ComponentValueReference,
+        Self::DataAbsentReason,
+        Self::Date,
+        Self::DerivedFrom,
+        Self::Device,
+        Self::Encounter,
+        Self::Focus,
+        Self::HasMember,
+        Self::Identifier,
+        Self::Method,
+        Self::PartOf,
+        Self::Patient,
+        Self::Performer,
+        Self::Specimen,
+        Self::Status,
+        Self::Subject,
+        Self::ValueCanonical,
+        Self::ValueConcept,
+        Self::ValueDate,
+        Self::ValueMarkdown,
+        Self::ValueQuantity,
+        Self::ValueReference,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::BasedOn => &BASED_ON,
+            Self::Category => &CATEGORY,
+            Self::Code => &CODE,
+            Self::CodeValueConcept => &CODE_VALUE_CONCEPT,
+            Self::CodeValueDate => &CODE_VALUE_DATE,
+            Self::CodeValueQuantity => &CODE_VALUE_QUANTITY,
+            Self::CodeValueString => &CODE_VALUE_STRING,
+            Self::ComboCode => &COMBO_CODE,
+            Self::ComboCodeValueConcept => &COMBO_CODE_VALUE_CONCEPT,
+            Self::ComboCodeValueQuantity => &COMBO_CODE_VALUE_QUANTITY,
+            Self::ComboDataAbsentReason => &COMBO_DATA_ABSENT_REASON,
+            Self::ComboValueConcept => &COMBO_VALUE_CONCEPT,
+            Self::ComboValueQuantity => &COMBO_VALUE_QUANTITY,
+            Self::ComponentCode => &COMPONENT_CODE,
+            Self::ComponentCodeValueConcept => &COMPONENT_CODE_VALUE_CONCEPT,
+            Self::ComponentCodeValueQuantity => &COMPONENT_CODE_VALUE_QUANTITY,
+            Self::ComponentDataAbsentReason => &COMPONENT_DATA_ABSENT_REASON,
+            Self::ComponentValueCanonical => &COMPONENT_VALUE_CANONICAL,
+            Self::ComponentValueConcept => &COMPONENT_VALUE_CONCEPT,
+            Self::ComponentValueQuantity => &COMPONENT_VALUE_QUANTITY,
+            Self::ComponentValueReference => &COMPONENT_VALUE_REFERENCE,
+            Self::DataAbsentReason => &DATA_ABSENT_REASON,
+            Self::Date => &DATE,
+            Self::DerivedFrom => &DERIVED_FROM,
+            Self::Device => &DEVICE,
+            Self::Encounter => &ENCOUNTER,
+            Self::Focus => &FOCUS,
+            Self::HasMember => &HAS_MEMBER,
+            Self::Identifier => &IDENTIFIER,
+            Self::Method => &METHOD,
+            Self::PartOf => &PART_OF,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::Specimen => &SPECIMEN,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::ValueCanonical => &VALUE_CANONICAL,
+            Self::ValueConcept => &VALUE_CONCEPT,
+            Self::ValueDate => &VALUE_DATE,
+            Self::ValueMarkdown => &VALUE_MARKDOWN,
+            Self::ValueQuantity => &VALUE_QUANTITY,
+            Self::ValueReference => &VALUE_REFERENCE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ObservationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ObservationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
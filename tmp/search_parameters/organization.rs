@@ -0,0 +1,345 @@
+//! Organization search parameters
+
+use super::*;
+
+/// `active`: Is the Organization record active
+pub const ACTIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "active",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-active",
+    r#type: SearchParamType::Token,
+    expression: Some("Organization.active"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address`: A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text
+pub const ADDRESS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-address",
+    r#type: SearchParamType::String,
+    expression: Some("Organization.contact.address"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-city`: A city specified in an address
+pub const ADDRESS_CITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-city",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-address-city",
+    r#type: SearchParamType::String,
+    expression: Some("Organization.contact.address.city"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-country`: A country specified in an address
+pub const ADDRESS_COUNTRY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-country",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-address-country",
+    r#type: SearchParamType::String,
+    expression: Some("Organization.contact.address.country"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-postalcode`: A postal code specified in an address
+pub const ADDRESS_POSTALCODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-postalcode",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-address-postalcode",
+    r#type: SearchParamType::String,
+    expression: Some("Organization.contact.address.postalCode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-state`: A state specified in an address
+pub const ADDRESS_STATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-state",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-address-state",
+    r#type: SearchParamType::String,
+    expression: Some("Organization.contact.address.state"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-use`: A use code specified in an address
+pub const ADDRESS_USE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-use",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-address-use",
+    r#type: SearchParamType::Token,
+    expression: Some("Organization.contact.address.use"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `endpoint`: Technical endpoints providing access to services operated for the organization
+pub const ENDPOINT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "endpoint",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-endpoint",
+    r#type: SearchParamType::Reference,
+    expression: Some("Organization.endpoint"),
+    targets: &["Endpoint"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Any identifier for the organization (not the accreditation issuer's identifier)
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Organization.identifier | Organization.qualification.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A portion of the organization's name or alias
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-name",
+    r#type: SearchParamType::String,
+    expression: Some("Organization.name | Organization.alias"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `partof`: An organization of which this organization forms a part
+pub const PARTOF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "partof",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-partof",
+    r#type: SearchParamType::Reference,
+    expression: Some("Organization.partOf"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phonetic`: A portion of the organization's name using some kind of phonetic matching algorithm
+pub const PHONETIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phonetic",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-phonetic",
+    r#type: SearchParamType::String,
+    expression: Some("Organization.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: A code for the type of organization
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/Organization-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Organization.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Organization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OrganizationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `active`
+    Active,
+
+    /// `address`
+    Address,
+
+    /// `address-city`
+    AddressCity,
+
+    /// `address-country`
+    AddressCountry,
+
+    /// `address-postalcode`
+    AddressPostalcode,
+
+    /// `address-state`
+    AddressState,
+
+    /// `address-use`
+    AddressUse,
+
+    /// `endpoint`
+    Endpoint,
+
+    /// `identifier`
+    Identifier,
+
+    /// `name`
+    Name,
+
+    /// `partof`
+    Partof,
+
+    /// `phonetic`
+    Phonetic,
+
+    /// `type`
+    Type,
+}
+
+impl OrganizationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Active,
+        Self::Address,
+        Self::AddressCity,
+        Self::AddressCountry,
+        Self::AddressPostalcode,
+        Self::AddressState,
+        Self::AddressUse,
+        Self::Endpoint,
+        Self::Identifier,
+        Self::Name,
+        Self::Partof,
+        Self::Phonetic,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Active => &ACTIVE,
+            Self::Address => &ADDRESS,
+            Self::AddressCity => &ADDRESS_CITY,
+            Self::AddressCountry => &ADDRESS_COUNTRY,
+            Self::AddressPostalcode => &ADDRESS_POSTALCODE,
+            Self::AddressState => &ADDRESS_STATE,
+            Self::AddressUse => &ADDRESS_USE,
+            Self::Endpoint => &ENDPOINT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Name => &NAME,
+            Self::Partof => &PARTOF,
+            Self::Phonetic => &PHONETIC,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for OrganizationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = OrganizationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,372 @@
+//! OrganizationAffiliation search parameters
+
+use super::*;
+
+/// `active`: Whether this organization affiliation record is in active use
+pub const ACTIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "active",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-active",
+    r#type: SearchParamType::Token,
+    expression: Some("OrganizationAffiliation.active"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: The period during which the participatingOrganization is affiliated with the primary organization
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-date",
+    r#type: SearchParamType::Date,
+    expression: Some("OrganizationAffiliation.period"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `email`: A value in an email contact
+pub const EMAIL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "email",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-email",
+    r#type: SearchParamType::Token,
+    expression: Some("OrganizationAffiliation.contact.telecom.where(system='email')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `endpoint`: Technical endpoints providing access to services operated for this role
+pub const ENDPOINT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "endpoint",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-endpoint",
+    r#type: SearchParamType::Reference,
+    expression: Some("OrganizationAffiliation.endpoint"),
+    targets: &["Endpoint"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: An organization affiliation's Identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("OrganizationAffiliation.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: The location(s) at which the role occurs
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("OrganizationAffiliation.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `network`: Health insurance provider network in which the participatingOrganization provides the role's services (if defined) at the indicated locations (if defined)
+pub const NETWORK: SearchParameterDefinition = SearchParameterDefinition {
+    code: "network",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-network",
+    r#type: SearchParamType::Reference,
+    expression: Some("OrganizationAffiliation.network"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `participating-organization`: The organization that provides services to the primary organization
+pub const PARTICIPATING_ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "participating-organization",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-participating-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("OrganizationAffiliation.participatingOrganization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phone`: A value in a phone contact
+pub const PHONE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phone",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-phone",
+    r#type: SearchParamType::Token,
+    expression: Some("OrganizationAffiliation.contact.telecom.where(system='phone')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `primary-organization`: The organization that receives the services from the participating organization
+pub const PRIMARY_ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "primary-organization",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-primary-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("OrganizationAffiliation.organization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `role`: Definition of the role the participatingOrganization plays
+pub const ROLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "role",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-role",
+    r#type: SearchParamType::Token,
+    expression: Some("OrganizationAffiliation.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service`: Healthcare services provided through the role
+pub const SERVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-service",
+    r#type: SearchParamType::Reference,
+    expression: Some("OrganizationAffiliation.healthcareService"),
+    targets: &["HealthcareService"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specialty`: Specific specialty of the participatingOrganization in the context of the role
+pub const SPECIALTY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specialty",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-specialty",
+    r#type: SearchParamType::Token,
+    expression: Some("OrganizationAffiliation.specialty"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `telecom`: The value in any kind of contact
+pub const TELECOM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "telecom",
+    url: "http://hl7.org/fhir/SearchParameter/OrganizationAffiliation-telecom",
+    r#type: SearchParamType::Token,
+    expression: Some("OrganizationAffiliation.contact.telecom"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of OrganizationAffiliation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OrganizationAffiliationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `active`
+    Active,
+
+    /// `date`
+    Date,
+
+    /// `email`
+    Email,
+
+    /// `endpoint`
+    Endpoint,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `network`
+    Network,
+
+    /// `participating-organization`
+    ParticipatingOrganization,
+
+    /// `phone`
+    Phone,
+
+    /// `primary-organization`
+    PrimaryOrganization,
+
+    /// `role`
+    Role,
+
+    /// `service`
+    Service,
+
+    /// `specialty`
+    Specialty,
+
+    /// `telecom`
+    Telecom,
+}
+
+impl OrganizationAffiliationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Active,
+        Self::Date,
+        Self::Email,
+        Self::Endpoint,
+        Self::Identifier,
+        Self::Location,
+        Self::Network,
+        Self::ParticipatingOrganization,
+        Self::Phone,
+        Self::PrimaryOrganization,
+        Self::Role,
+        Self::Service,
+        Self::Specialty,
+        Self::Telecom,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Active => &ACTIVE,
+            Self::Date => &DATE,
+            Self::Email => &EMAIL,
+            Self::Endpoint => &ENDPOINT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::Network => &NETWORK,
+            Self::ParticipatingOrganization => &PARTICIPATING_ORGANIZATION,
+            Self::Phone => &PHONE,
+            Self::PrimaryOrganization => &PRIMARY_ORGANIZATION,
+            Self::Role => &ROLE,
+            Self::Service => &SERVICE,
+            Self::Specialty => &SPECIALTY,
+            Self::Telecom => &TELECOM,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for OrganizationAffiliationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = OrganizationAffiliationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,353 @@
+//! PackagedProductDefinition search parameters
+
+use super::*;
+
+/// `biological`: A biologically derived product within this packaged product
+pub const BIOLOGICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "biological",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-biological",
+    r#type: SearchParamType::Reference,
+    expression: Some("PackagedProductDefinition.packaging.containedItem.item.reference"),
+    targets: &[
+        "DeviceDefinition",
+        "NutritionProduct",
+        "BiologicallyDerivedProduct",
+        "PackagedProductDefinition",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `contained-item`: Any of the contained items within this packaged product
+pub const CONTAINED_ITEM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "contained-item",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-contained-item",
+    r#type: SearchParamType::Reference,
+    expression: Some("PackagedProductDefinition.packaging.containedItem.item.reference"),
+    targets: &[
+        "DeviceDefinition",
+        "NutritionProduct",
+        "BiologicallyDerivedProduct",
+        "PackagedProductDefinition",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `device`: A device within this packaged product
+pub const DEVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "device",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-device",
+    r#type: SearchParamType::Reference,
+    expression: Some("PackagedProductDefinition.packaging.containedItem.item.reference"),
+    targets: &[
+        "DeviceDefinition",
+        "NutritionProduct",
+        "BiologicallyDerivedProduct",
+        "PackagedProductDefinition",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Unique identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("PackagedProductDefinition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `manufactured-item`: A manufactured item of medication within this packaged product
+pub const MANUFACTURED_ITEM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "manufactured-item",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-manufactured-item",
+    r#type: SearchParamType::Reference,
+    expression: Some("PackagedProductDefinition.packaging.containedItem.item.reference"),
+    targets: &[
+        "DeviceDefinition",
+        "NutritionProduct",
+        "BiologicallyDerivedProduct",
+        "PackagedProductDefinition",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `medication`: A manufactured item of medication within this packaged product
+pub const MEDICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "medication",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-medication",
+    r#type: SearchParamType::Reference,
+    expression: Some("PackagedProductDefinition.packaging.containedItem.item.reference"),
+    targets: &[
+        "DeviceDefinition",
+        "NutritionProduct",
+        "BiologicallyDerivedProduct",
+        "PackagedProductDefinition",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A name for this package. Typically what it would be listed as in a drug formulary or catalogue, inventory etc.
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-name",
+    r#type: SearchParamType::Token,
+    expression: Some("PackagedProductDefinition.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `nutrition`: A nutrition product within this packaged product
+pub const NUTRITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "nutrition",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-nutrition",
+    r#type: SearchParamType::Reference,
+    expression: Some("PackagedProductDefinition.packaging.containedItem.item.reference"),
+    targets: &[
+        "DeviceDefinition",
+        "NutritionProduct",
+        "BiologicallyDerivedProduct",
+        "PackagedProductDefinition",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `package`: A complete packaged product within this packaged product
+pub const PACKAGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "package",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-package",
+    r#type: SearchParamType::Reference,
+    expression: Some("PackagedProductDefinition.packaging.containedItem.item.reference"),
+    targets: &[
+        "DeviceDefinition",
+        "NutritionProduct",
+        "BiologicallyDerivedProduct",
+        "PackagedProductDefinition",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `package-for`: The product that this is a pack for
+pub const PACKAGE_FOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "package-for",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-package-for",
+    r#type: SearchParamType::Reference,
+    expression: Some("PackagedProductDefinition.packageFor"),
+    targets: &["MedicinalProductDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status within the lifecycle of this item. A high level status, this is not intended to duplicate details carried elsewhere such as legal status, or authorization or marketing status
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/PackagedProductDefinition-status",
+    r#type: SearchParamType::Token,
+    expression: Some("PackagedProductDefinition.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of PackagedProductDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PackagedProductDefinitionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `biological`
+    Biological,
+
+    /// `contained-item`
+    ContainedItem,
+
+    /// `device`
+    Device,
+
+    /// `identifier`
+    Identifier,
+
+    /// `manufactured-item`
+    ManufacturedItem,
+
+    /// `medication`
+    Medication,
+
+    /// `name`
+    Name,
+
+    /// `nutrition`
+    Nutrition,
+
+    /// `package`
+    Package,
+
+    /// `package-for`
+    PackageFor,
+
+    /// `status`
+    Status,
+}
+
+impl PackagedProductDefinitionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Biological,
+        Self::ContainedItem,
+        Self::Device,
+        Self::Identifier,
+        Self::ManufacturedItem,
+        Self::Medication,
+        Self::Name,
+        Self::Nutrition,
+        Self::Package,
+        Self::PackageFor,
+        Self::Status,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Biological => &BIOLOGICAL,
+            Self::ContainedItem => &CONTAINED_ITEM,
+            Self::Device => &DEVICE,
+            Self::Identifier => &IDENTIFIER,
+            Self::ManufacturedItem => &MANUFACTURED_ITEM,
+            Self::Medication => &MEDICATION,
+            Self::Name => &NAME,
+            Self::Nutrition => &NUTRITION,
+            Self::Package => &PACKAGE,
+            Self::PackageFor => &PACKAGE_FOR,
+            Self::Status => &STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for PackagedProductDefinitionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = PackagedProductDefinitionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,535 @@
+//! Patient search parameters
+
+use super::*;
+
+/// `active`: Whether the patient record is active
+pub const ACTIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "active",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-active",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.active"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address`: A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text
+pub const ADDRESS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.address"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-city`: A city specified in an address
+pub const ADDRESS_CITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-city",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-city",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.address.city"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-country`: A country specified in an address
+pub const ADDRESS_COUNTRY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-country",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-country",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.address.country"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-postalcode`: A postalCode specified in an address
+pub const ADDRESS_POSTALCODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-postalcode",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-postalcode",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.address.postalCode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-state`: A state specified in an address
+pub const ADDRESS_STATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-state",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-state",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.address.state"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-use`: A use code specified in an address
+pub const ADDRESS_USE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-use",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-use",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.address.use"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `birthdate`: The patient's date of birth
+pub const BIRTHDATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "birthdate",
+    url: "http://hl7.org/fhir/SearchParameter/individual-birthdate",
+    r#type: SearchParamType::Date,
+    expression: Some("Patient.birthDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `death-date`: The date of death has been provided and satisfies this search value
+pub const DEATH_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "death-date",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-death-date",
+    r#type: SearchParamType::Date,
+    expression: Some("(Patient.deceased.ofType(dateTime))"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `deceased`: This patient has been marked as deceased, or has a death date entered
+pub const DECEASED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "deceased",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-deceased",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.deceased.exists() and Patient.deceased != false"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `email`: A value in an email contact
+pub const EMAIL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "email",
+    url: "http://hl7.org/fhir/SearchParameter/individual-email",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.telecom.where(system='email')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `family`: A portion of the family name of the patient
+pub const FAMILY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "family",
+    url: "http://hl7.org/fhir/SearchParameter/individual-family",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.name.family"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `gender`: Gender of the patient
+pub const GENDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "gender",
+    url: "http://hl7.org/fhir/SearchParameter/individual-gender",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.gender"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `general-practitioner`: Patient's nominated general practitioner, not the organization that manages the record
+pub const GENERAL_PRACTITIONER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "general-practitioner",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-general-practitioner",
+    r#type: SearchParamType::Reference,
+    expression: Some("Patient.generalPractitioner"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `given`: A portion of the given name of the patient
+pub const GIVEN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "given",
+    url: "http://hl7.org/fhir/SearchParameter/individual-given",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.name.given"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A patient identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `language`: Language code (irrespective of use value)
+pub const LANGUAGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "language",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-language",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.communication.language"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `link`: All patients/related persons linked to the given patient
+pub const LINK: SearchParameterDefinition = SearchParameterDefinition {
+    code: "link",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-link",
+    r#type: SearchParamType::Reference,
+    expression: Some("Patient.link.other"),
+    targets: &["RelatedPerson", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A server defined search that may match any of the string fields in the HumanName, including family, given, prefix, suffix, and/or text
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-name",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `organization`: The organization that is the custodian of the patient record
+pub const ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "organization",
+    url: "http://hl7.org/fhir/SearchParameter/Patient-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("Patient.managingOrganization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phone`: A value in a phone contact
+pub const PHONE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phone",
+    url: "http://hl7.org/fhir/SearchParameter/individual-phone",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.telecom.where(system='phone')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phonetic`: A portion of either family or given name using some kind of phonetic matching algorithm
+pub const PHONETIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phonetic",
+    url: "http://hl7.org/fhir/SearchParameter/individual-phonetic",
+    r#type: SearchParamType::String,
+    expression: Some("Patient.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `telecom`: The value in any kind of telecom details of the patient
+pub const TELECOM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "telecom",
+    url: "http://hl7.org/fhir/SearchParameter/individual-telecom",
+    r#type: SearchParamType::Token,
+    expression: Some("Patient.telecom"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Patient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PatientSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `active`
+    Active,
+
+    /// `address`
+    Address,
+
+    /// `address-city`
+    AddressCity,
+
+    /// `address-country`
+    AddressCountry,
+
+    /// `address-postalcode`
+    AddressPostalcode,
+
+    /// `address-state`
+    AddressState,
+
+    /// `address-use`
+    AddressUse,
+
+    /// `birthdate`
+    Birthdate,
+
+    /// `death-date`
+    DeathDate,
+
+    /// `deceased`
+    Deceased,
+
+    /// `email`
+    Email,
+
+    /// `family`
+    Family,
+
+    /// `gender`
+    Gender,
+
+    /// `general-practitioner`
+    GeneralPractitioner,
+
+    /// `given`
+    Given,
+
+    /// `identifier`
+    Identifier,
+
+    /// `language`
+    Language,
+
+    /// `link`
+    Link,
+
+    /// `name`
+    Name,
+
+    /// `organization`
+    Organization,
+
+    /// `phone`
+    Phone,
+
+    /// `phonetic`
+    Phonetic,
+
+    /// `telecom`
+    Telecom,
+}
+
+impl PatientSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Active,
+        Self::Address,
+        Self::AddressCity,
+        Self::AddressCountry,
+        Self::AddressPostalcode,
+        Self::AddressState,
+        Self::AddressUse,
+        Self::Birthdate,
+        Self::DeathDate,
+        Self::Deceased,
+        Self::Email,
+        Self::Family,
+        Self::Gender,
+        Self::GeneralPractitioner,
+        Self::Given,
+        Self::Identifier,
+        Self::Language,
+        Self::Link,
+        Self::Name,
+        Self::Organization,
+        Self::Phone,
+        Self::Phonetic,
+        Self::Telecom,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Active => &ACTIVE,
+            Self::Address => &ADDRESS,
+            Self::AddressCity => &ADDRESS_CITY,
+            Self::AddressCountry => &ADDRESS_COUNTRY,
+            Self::AddressPostalcode => &ADDRESS_POSTALCODE,
+            Self::AddressState => &ADDRESS_STATE,
+            Self::AddressUse => &ADDRESS_USE,
+            Self::Birthdate => &BIRTHDATE,
+            Self::DeathDate => &DEATH_DATE,
+            Self::Deceased => &DECEASED,
+            Self::Email => &EMAIL,
+            Self::Family => &FAMILY,
+            Self::Gender => &GENDER,
+            Self::GeneralPractitioner => &GENERAL_PRACTITIONER,
+            Self::Given => &GIVEN,
+            Self::Identifier => &IDENTIFIER,
+            Self::Language => &LANGUAGE,
+            Self::Link => &LINK,
+            Self::Name => &NAME,
+            Self::Organization => &ORGANIZATION,
+            Self::Phone => &PHONE,
+            Self::Phonetic => &PHONETIC,
+            Self::Telecom => &TELECOM,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for PatientSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = PatientSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! PaymentReconciliation search parameters
+
+use super::*;
+
+/// `allocation-account`: The account to which payment or adjustment was applied.
+pub const ALLOCATION_ACCOUNT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "allocation-account",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-allocation-account",
+    r#type: SearchParamType::Reference,
+    expression: Some("PaymentReconciliation.allocation.account"),
+    targets: &["Account"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `allocation-encounter`: The encounter to which payment or adjustment was applied.
+pub const ALLOCATION_ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "allocation-encounter",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-allocation-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("PaymentReconciliation.allocation.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `created`: The creation date
+pub const CREATED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "created",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-created",
+    r#type: SearchParamType::Date,
+    expression: Some("PaymentReconciliation.created"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `disposition`: The contents of the disposition message
+pub const DISPOSITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "disposition",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-disposition",
+    r#type: SearchParamType::String,
+    expression: Some("PaymentReconciliation.disposition"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The business identifier of the ExplanationOfBenefit
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("PaymentReconciliation.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `outcome`: The processing outcome
+pub const OUTCOME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "outcome",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-outcome",
+    r#type: SearchParamType::Token,
+    expression: Some("PaymentReconciliation.outcome"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `payment-issuer`: The organization which generated this resource
+pub const PAYMENT_ISSUER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "payment-issuer",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-payment-issuer",
+    r#type: SearchParamType::Reference,
+    expression: Some("PaymentReconciliation.paymentIssuer"),
+    targets: &["Organization", "RelatedPerson", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `request`: The reference to the claim
+pub const REQUEST: SearchParameterDefinition = SearchParameterDefinition {
+    code: "request",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-request",
+    r#type: SearchParamType::Reference,
+    expression: Some("PaymentReconciliation.request"),
+    targets: &["Task"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requestor`: The reference to the provider who submitted the claim
+pub const REQUESTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requestor",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-requestor",
+    r#type: SearchParamType::Reference,
+    expression: Some("PaymentReconciliation.requestor"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status of the payment reconciliation
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/PaymentReconciliation-status",
+    r#type: SearchParamType::Token,
+    expression: Some("PaymentReconciliation.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of PaymentReconciliation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PaymentReconciliationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `allocation-account`
+    AllocationAccount,
+
+    /// `allocation-encounter`
+    AllocationEncounter,
+
+    /// `created`
+    Created,
+
+    /// `disposition`
+    Disposition,
+
+    /// `identifier`
+    Identifier,
+
+    /// `outcome`
+    Outcome,
+
+    /// `payment-issuer`
+    PaymentIssuer,
+
+    /// `request`
+    Request,
+
+    /// `requestor`
+    Requestor,
+
+    /// `status`
+    Status,
+}
+
+impl PaymentReconciliationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::AllocationAccount,
+        Self::AllocationEncounter,
+        Self::Created,
+        Self::Disposition,
+        Self::Identifier,
+        Self::Outcome,
+        Self::PaymentIssuer,
+        Self::Request,
+        Self::Requestor,
+        Self::Status,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::AllocationAccount => &ALLOCATION_ACCOUNT,
+            Self::AllocationEncounter => &ALLOCATION_ENCOUNTER,
+            Self::Created => &CREATED,
+            Self::Disposition => &DISPOSITION,
+            Self::Identifier => &IDENTIFIER,
+            Self::Outcome => &OUTCOME,
+            Self::PaymentIssuer => &PAYMENT_ISSUER,
+            Self::Request => &REQUEST,
+            Self::Requestor => &REQUESTOR,
+            Self::Status => &STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for PaymentReconciliationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = PaymentReconciliationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,535 @@
+//! Person search parameters
+
+use super::*;
+
+/// `address`: A server defined search that may match any of the string fields in the Address, including line, city, district, state, country, postalCode, and/or text
+pub const ADDRESS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address",
+    r#type: SearchParamType::String,
+    expression: Some("Person.address"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-city`: A city specified in an address
+pub const ADDRESS_CITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-city",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-city",
+    r#type: SearchParamType::String,
+    expression: Some("Person.address.city"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-country`: A country specified in an address
+pub const ADDRESS_COUNTRY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-country",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-country",
+    r#type: SearchParamType::String,
+    expression: Some("Person.address.country"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-postalcode`: A postal code specified in an address
+pub const ADDRESS_POSTALCODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-postalcode",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-postalcode",
+    r#type: SearchParamType::String,
+    expression: Some("Person.address.postalCode"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-state`: A state specified in an address
+pub const ADDRESS_STATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-state",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-state",
+    r#type: SearchParamType::String,
+    expression: Some("Person.address.state"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `address-use`: A use code specified in an address
+pub const ADDRESS_USE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "address-use",
+    url: "http://hl7.org/fhir/SearchParameter/individual-address-use",
+    r#type: SearchParamType::Token,
+    expression: Some("Person.address.use"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `birthdate`: The person's date of birth
+pub const BIRTHDATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "birthdate",
+    url: "http://hl7.org/fhir/SearchParameter/individual-birthdate",
+    r#type: SearchParamType::Date,
+    expression: Some("Person.birthDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `death-date`: The date of death has been provided and satisfies this search value
+pub const DEATH_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "death-date",
+    url: "http://hl7.org/fhir/SearchParameter/Person-death-date",
+    r#type: SearchParamType::Date,
+    expression: Some("(Person.deceased.ofType(dateTime))"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `deceased`: This person has been marked as deceased, or has a death date entered
+pub const DECEASED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "deceased",
+    url: "http://hl7.org/fhir/SearchParameter/Person-deceased",
+    r#type: SearchParamType::Token,
+    expression: Some("Person.deceased.exists() and Person.deceased != false"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `email`: A value in an email contact
+pub const EMAIL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "email",
+    url: "http://hl7.org/fhir/SearchParameter/individual-email",
+    r#type: SearchParamType::Token,
+    expression: Some("Person.telecom.where(system='email')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `family`: A portion of the family name of the person
+pub const FAMILY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "family",
+    url: "http://hl7.org/fhir/SearchParameter/Person-family",
+    r#type: SearchParamType::String,
+    expression: Some("Person.name.family"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `gender`: The gender of the person
+pub const GENDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "gender",
+    url: "http://hl7.org/fhir/SearchParameter/individual-gender",
+    r#type: SearchParamType::Token,
+    expression: Some("Person.gender"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `given`: A portion of the given name of the person
+pub const GIVEN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "given",
+    url: "http://hl7.org/fhir/SearchParameter/Person-given",
+    r#type: SearchParamType::String,
+    expression: Some("Person.name.given"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A person Identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Person.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `link`: Any link has this Patient, Person, RelatedPerson or Practitioner reference
+pub const LINK: SearchParameterDefinition = SearchParameterDefinition {
+    code: "link",
+    url: "http://hl7.org/fhir/SearchParameter/Person-link",
+    r#type: SearchParamType::Reference,
+    expression: Some("Person.link.target"),
+    targets: &["RelatedPerson", "Practitioner", "Person", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A server defined search that may match any of the string fields in the HumanName, including family, give, prefix, suffix, suffix, and/or text
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/Person-name",
+    r#type: SearchParamType::String,
+    expression: Some("Person.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `organization`: The organization at which this person record is being managed
+pub const ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "organization",
+    url: "http://hl7.org/fhir/SearchParameter/Person-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("Person.managingOrganization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The Person links to this Patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Person.link.target.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phone`: A value in a phone contact
+pub const PHONE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phone",
+    url: "http://hl7.org/fhir/SearchParameter/individual-phone",
+    r#type: SearchParamType::Token,
+    expression: Some("Person.telecom.where(system='phone')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phonetic`: A portion of name using some kind of phonetic matching algorithm
+pub const PHONETIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phonetic",
+    url: "http://hl7.org/fhir/SearchParameter/individual-phonetic",
+    r#type: SearchParamType::String,
+    expression: Some("Person.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `practitioner`: The Person links to this Practitioner
+pub const PRACTITIONER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "practitioner",
+    url: "http://hl7.org/fhir/SearchParameter/Person-practitioner",
+    r#type: SearchParamType::Reference,
+    expression: Some("Person.link.target.where(resolve() is Practitioner)"),
+    targets: &["Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `relatedperson`: The Person links to this RelatedPerson
+pub const RELATEDPERSON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "relatedperson",
+    url: "http://hl7.org/fhir/SearchParameter/Person-relatedperson",
+    r#type: SearchParamType::Reference,
+    expression: Some("Person.link.target.where(resolve() is RelatedPerson)"),
+    targets: &["RelatedPerson"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `telecom`: The value in any kind of contact
+pub const TELECOM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "telecom",
+    url: "http://hl7.org/fhir/SearchParameter/individual-telecom",
+    r#type: SearchParamType::Token,
+    expression: Some("Person.telecom"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PersonSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `address`
+    Address,
+
+    /// `address-city`
+    AddressCity,
+
+    /// `address-country`
+    AddressCountry,
+
+    /// `address-postalcode`
+    AddressPostalcode,
+
+    /// `address-state`
+    AddressState,
+
+    /// `address-use`
+    AddressUse,
+
+    /// `birthdate`
+    Birthdate,
+
+    /// `death-date`
+    DeathDate,
+
+    /// `deceased`
+    Deceased,
+
+    /// `email`
+    Email,
+
+    /// `family`
+    Family,
+
+    /// `gender`
+    Gender,
+
+    /// `given`
+    Given,
+
+    /// `identifier`
+    Identifier,
+
+    /// `link`
+    Link,
+
+    /// `name`
+    Name,
+
+    /// `organization`
+    Organization,
+
+    /// `patient`
+    Patient,
+
+    /// `phone`
+    Phone,
+
+    /// `phonetic`
+    Phonetic,
+
+    /// `practitioner`
+    Practitioner,
+
+    /// `relatedperson`
+    Relatedperson,
+
+    /// `telecom`
+    Telecom,
+}
+
+impl PersonSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Address,
+        Self::AddressCity,
+        Self::AddressCountry,
+        Self::AddressPostalcode,
+        Self::AddressState,
+        Self::AddressUse,
+        Self::Birthdate,
+        Self::DeathDate,
+        Self::Deceased,
+        Self::Email,
+        Self::Family,
+        Self::Gender,
+        Self::Given,
+        Self::Identifier,
+        Self::Link,
+        Self::Name,
+        Self::Organization,
+        Self::Patient,
+        Self::Phone,
+        Self::Phonetic,
+        Self::Practitioner,
+        Self::Relatedperson,
+        Self::Telecom,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Address => &ADDRESS,
+            Self::AddressCity => &ADDRESS_CITY,
+            Self::AddressCountry => &ADDRESS_COUNTRY,
+            Self::AddressPostalcode => &ADDRESS_POSTALCODE,
+            Self::AddressState => &ADDRESS_STATE,
+            Self::AddressUse => &ADDRESS_USE,
+            Self::Birthdate => &BIRTHDATE,
+            Self::DeathDate => &DEATH_DATE,
+            Self::Deceased => &DECEASED,
+            Self::Email => &EMAIL,
+            Self::Family => &FAMILY,
+            Self::Gender => &GENDER,
+            Self::Given => &GIVEN,
+            Self::Identifier => &IDENTIFIER,
+            Self::Link => &LINK,
+            Self::Name => &NAME,
+            Self::Organization => &ORGANIZATION,
+            Self::Patient => &PATIENT,
+            Self::Phone => &PHONE,
+            Self::Phonetic => &PHONETIC,
+            Self::Practitioner => &PRACTITIONER,
+            Self::Relatedperson => &RELATEDPERSON,
+            Self::Telecom => &TELECOM,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for PersonSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = PersonSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
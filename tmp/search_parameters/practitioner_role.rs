@@ -0,0 +1,389 @@
+//! PractitionerRole search parameters
+
+use super::*;
+
+/// `active`: Whether this practitioner role record is in active use
+pub const ACTIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "active",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-active",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.active"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `characteristic`: One of the PractitionerRole's characteristics
+pub const CHARACTERISTIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "characteristic",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-characteristic",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.characteristic"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `communication`: One of the languages that the practitioner can communicate with
+pub const COMMUNICATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "communication",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-communication",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.communication"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: The period during which the practitioner is authorized to perform in these role(s)
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-date",
+    r#type: SearchParamType::Date,
+    expression: Some("PractitionerRole.period"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `email`: A value in an email contact
+pub const EMAIL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "email",
+    url: "http://hl7.org/fhir/SearchParameter/individual-email",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.contact.telecom.where(system='email')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `endpoint`: Technical endpoints providing access to services operated for the practitioner with this role
+pub const ENDPOINT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "endpoint",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-endpoint",
+    r#type: SearchParamType::Reference,
+    expression: Some("PractitionerRole.endpoint"),
+    targets: &["Endpoint"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A practitioner's Identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: One of the locations at which this practitioner provides care
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("PractitionerRole.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `organization`: The identity of the organization the practitioner represents / acts on behalf of
+pub const ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "organization",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("PractitionerRole.organization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phone`: A value in a phone contact
+pub const PHONE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phone",
+    url: "http://hl7.org/fhir/SearchParameter/individual-phone",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.contact.telecom.where(system='phone')"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `practitioner`: Practitioner that is able to provide the defined services for the organization
+pub const PRACTITIONER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "practitioner",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-practitioner",
+    r#type: SearchParamType::Reference,
+    expression: Some("PractitionerRole.practitioner"),
+    targets: &["Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `role`: The practitioner can perform this role at for the organization
+pub const ROLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "role",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-role",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service`: The list of healthcare services that this worker provides for this role's Organization/Location(s)
+pub const SERVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-service",
+    r#type: SearchParamType::Reference,
+    expression: Some("PractitionerRole.healthcareService"),
+    targets: &["HealthcareService"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specialty`: The practitioner has this specialty at an organization
+pub const SPECIALTY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specialty",
+    url: "http://hl7.org/fhir/SearchParameter/PractitionerRole-specialty",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.specialty"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `telecom`: The value in any kind of contact
+pub const TELECOM: SearchParameterDefinition = SearchParameterDefinition {
+    code: "telecom",
+    url: "http://hl7.org/fhir/SearchParameter/individual-telecom",
+    r#type: SearchParamType::Token,
+    expression: Some("PractitionerRole.contact.telecom"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of PractitionerRole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PractitionerRoleSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `active`
+    Active,
+
+    /// `characteristic`
+    Characteristic,
+
+    /// `communication`
+    Communication,
+
+    /// `date`
+    Date,
+
+    /// `email`
+    Email,
+
+    /// `endpoint`
+    Endpoint,
+
+    /// `identifier`
+    Identifier,
+
+    /// `location`
+    Location,
+
+    /// `organization`
+    Organization,
+
+    /// `phone`
+    Phone,
+
+    /// `practitioner`
+    Practitioner,
+
+    /// `role`
+    Role,
+
+    /// `service`
+    Service,
+
+    /// `specialty`
+    Specialty,
+
+    /// `telecom`
+    Telecom,
+}
+
+impl PractitionerRoleSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Active,
+        Self::Characteristic,
+        Self::Communication,
+        Self::Date,
+        Self::Email,
+        Self::Endpoint,
+        Self::Identifier,
+        Self::Location,
+        Self::Organization,
+        Self::Phone,
+        Self::Practitioner,
+        Self::Role,
+        Self::Service,
+        Self::Specialty,
+        Self::Telecom,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Active => &ACTIVE,
+            Self::Characteristic => &CHARACTERISTIC,
+            Self::Communication => &COMMUNICATION,
+            Self::Date => &DATE,
+            Self::Email => &EMAIL,
+            Self::Endpoint => &ENDPOINT,
+            Self::Identifier => &IDENTIFIER,
+            Self::Location => &LOCATION,
+            Self::Organization => &ORGANIZATION,
+            Self::Phone => &PHONE,
+            Self::Practitioner => &PRACTITIONER,
+            Self::Role => &ROLE,
+            Self::Service => &SERVICE,
+            Self::Specialty => &SPECIALTY,
+            Self::Telecom => &TELECOM,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for PractitionerRoleSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = PractitionerRoleSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
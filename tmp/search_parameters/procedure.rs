@@ -0,0 +1,446 @@
+//! Procedure search parameters
+
+use super::*;
+
+/// `based-on`: A request for this procedure
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.basedOn"),
+    targets: &["ServiceRequest", "CarePlan"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Classification of the procedure
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Procedure.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: A code to identify a  procedure
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Procedure.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: When the procedure occurred or is occurring
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-date",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "Procedure.occurrence.ofType(dateTime) | Procedure.occurrence.ofType(Period) | Procedure.occurrence.ofType(Timing)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: The Encounter during which this Procedure was created
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A unique identifier for a procedure
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Procedure.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-canonical`: Instantiates FHIR protocol or definition
+pub const INSTANTIATES_CANONICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-canonical",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-instantiates-canonical",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.instantiatesCanonical"),
+    targets: &[
+        "Measure",
+        "OperationDefinition",
+        "ActivityDefinition",
+        "PlanDefinition",
+        "Questionnaire",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-uri`: Instantiates external protocol or definition
+pub const INSTANTIATES_URI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-uri",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-instantiates-uri",
+    r#type: SearchParamType::Uri,
+    expression: Some("Procedure.instantiatesUri"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `location`: Where the procedure happened
+pub const LOCATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "location",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-location",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.location"),
+    targets: &["Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `part-of`: Part of referenced event
+pub const PART_OF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "part-of",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-part-of",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.partOf"),
+    targets: &["Procedure", "Observation", "MedicationAdministration"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Search by subject - a patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: Who performed the procedure
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.performer.actor"),
+    targets: &[
+        "HealthcareService",
+        "Organization",
+        "Device",
+        "CareTeam",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-code`: Reference to a concept (by class)
+pub const REASON_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-code",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-reason-code",
+    r#type: SearchParamType::Token,
+    expression: Some("Procedure.reason.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `reason-reference`: Reference to a resource (by instance)
+pub const REASON_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "reason-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-reason-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.reason.reference"),
+    targets: &[
+        "Procedure",
+        "DocumentReference",
+        "Observation",
+        "DiagnosticReport",
+        "Condition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `report`: Any report resulting from the procedure
+pub const REPORT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "report",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-report",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.report"),
+    targets: &["Composition", "DocumentReference", "DiagnosticReport"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: preparation | in-progress | not-done | on-hold | stopped | completed | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Procedure.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Search by subject
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Procedure-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Procedure.subject"),
+    targets: &["Device", "Organization", "Group", "Practitioner", "Location", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Procedure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProcedureSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `category`
+    Category,
+
+    /// `code`
+    Code,
+
+    /// `date`
+    Date,
+
+    /// `encounter`
+    Encounter,
+
+    /// `identifier`
+    Identifier,
+
+    /// `instantiates-canonical`
+    InstantiatesCanonical,
+
+    /// `instantiates-uri`
+    InstantiatesUri,
+
+    /// `location`
+    Location,
+
+    /// `part-of`
+    PartOf,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `reason-code`
+    ReasonCode,
+
+    /// `reason-reference`
+    ReasonReference,
+
+    /// `report`
+    Report,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl ProcedureSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::BasedOn,
+        Self::Category,
+        Self::Code,
+        Self::Date,
+        Self::Encounter,
+        Self::Identifier,
+        Self::InstantiatesCanonical,
+        Self::InstantiatesUri,
+        Self::Location,
+        Self::PartOf,
+        Self::Patient,
+        Self::Performer,
+        Self::ReasonCode,
+        Self::ReasonReference,
+        Self::Report,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::BasedOn => &BASED_ON,
+            Self::Category => &CATEGORY,
+            Self::Code => &CODE,
+            Self::Date => &DATE,
+            Self::Encounter => &ENCOUNTER,
+            Self::Identifier => &IDENTIFIER,
+            Self::InstantiatesCanonical => &INSTANTIATES_CANONICAL,
+            Self::InstantiatesUri => &INSTANTIATES_URI,
+            Self::Location => &LOCATION,
+            Self::PartOf => &PART_OF,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::ReasonCode => &REASON_CODE,
+            Self::ReasonReference => &REASON_REFERENCE,
+            Self::Report => &REPORT,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ProcedureSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ProcedureSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
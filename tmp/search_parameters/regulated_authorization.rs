@@ -0,0 +1,259 @@
+//! RegulatedAuthorization search parameters
+
+use super::*;
+
+/// `case`: The case or procedure number
+pub const CASE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "case",
+    url: "http://hl7.org/fhir/SearchParameter/RegulatedAuthorization-case",
+    r#type: SearchParamType::Token,
+    expression: Some("RegulatedAuthorization.case.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `case-type`: The defining type of case
+pub const CASE_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "case-type",
+    url: "http://hl7.org/fhir/SearchParameter/RegulatedAuthorization-case-type",
+    r#type: SearchParamType::Token,
+    expression: Some("RegulatedAuthorization.case.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `holder`: The organization that holds the granted authorization
+pub const HOLDER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "holder",
+    url: "http://hl7.org/fhir/SearchParameter/RegulatedAuthorization-holder",
+    r#type: SearchParamType::Reference,
+    expression: Some("RegulatedAuthorization.holder"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business identifier for the authorization, typically assigned by the authorizing body
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/RegulatedAuthorization-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("RegulatedAuthorization.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `region`: The territory (e.g., country, jurisdiction etc.) in which the authorization has been granted
+pub const REGION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "region",
+    url: "http://hl7.org/fhir/SearchParameter/RegulatedAuthorization-region",
+    r#type: SearchParamType::Token,
+    expression: Some("RegulatedAuthorization.region"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The status that is authorised e.g. approved. Intermediate states can be tracked with cases and applications
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/RegulatedAuthorization-status",
+    r#type: SearchParamType::Token,
+    expression: Some("RegulatedAuthorization.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The type of regulated product, treatment, facility or activity that is being authorized
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/RegulatedAuthorization-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("RegulatedAuthorization.subject"),
+    targets: &[
+        "Ingredient",
+        "Organization",
+        "ObservationDefinition",
+        "ActivityDefinition",
+        "PlanDefinition",
+        "BiologicallyDerivedProduct",
+        "Practitioner",
+        "PackagedProductDefinition",
+        "SubstanceDefinition",
+        "Location",
+        "MedicinalProductDefinition",
+        "NutritionProduct",
+        "DeviceDefinition",
+        "ResearchStudy",
+        "ManufacturedItemDefinition",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of RegulatedAuthorization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RegulatedAuthorizationSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `case`
+    Case,
+
+    /// `case-type`
+    CaseType,
+
+    /// `holder`
+    Holder,
+
+    /// `identifier`
+    Identifier,
+
+    /// `region`
+    Region,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl RegulatedAuthorizationSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Case,
+        Self::CaseType,
+        Self::Holder,
+        Self::Identifier,
+        Self::Region,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Case => &CASE,
+            Self::CaseType => &CASE_TYPE,
+            Self::Holder => &HOLDER,
+            Self::Identifier => &IDENTIFIER,
+            Self::Region => &REGION,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for RegulatedAuthorizationSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = RegulatedAuthorizationSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,615 @@
+//! ResearchStudy search parameters
+
+use super::*;
+
+/// `classifier`: Classification for the study
+pub const CLASSIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "classifier",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-classifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.classifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `condition`: Condition being studied
+pub const CONDITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "condition",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-condition",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.condition"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: When the study began and ended
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-date",
+    r#type: SearchParamType::Date,
+    expression: Some("ResearchStudy.period"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `description`: Detailed narrative of the study
+pub const DESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "description",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-description",
+    r#type: SearchParamType::String,
+    expression: Some("ResearchStudy.description"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `eligibility`: Inclusion and exclusion criteria
+pub const ELIGIBILITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "eligibility",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-eligibility",
+    r#type: SearchParamType::Reference,
+    expression: Some("ResearchStudy.recruitment.eligibility"),
+    targets: &["Group", "EvidenceVariable"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `focus-code`: Drugs, devices, etc. under study, as a code
+pub const FOCUS_CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "focus-code",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-focus-code",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.focus.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `focus-reference`: Drugs, devices, etc. under study, as a reference
+pub const FOCUS_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "focus-reference",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-focus-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("ResearchStudy.focus.reference"),
+    targets: &[
+        "MedicinalProductDefinition",
+        "EvidenceVariable",
+        "SubstanceDefinition",
+        "Medication",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Business Identifier for study
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `keyword`: Used to search for the study
+pub const KEYWORD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "keyword",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-keyword",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.keyword"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: Name for this study
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-name",
+    r#type: SearchParamType::String,
+    expression: Some("ResearchStudy.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `objective-description`: Free text description of the objective of the study
+pub const OBJECTIVE_DESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "objective-description",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-objective-description",
+    r#type: SearchParamType::String,
+    expression: Some("ResearchStudy.objective.description"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `objective-type`: The kind of study objective
+pub const OBJECTIVE_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "objective-type",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-objective-type",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.objective.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `part-of`: Part of larger study
+pub const PART_OF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "part-of",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-part-of",
+    r#type: SearchParamType::Reference,
+    expression: Some("ResearchStudy.partOf"),
+    targets: &["ResearchStudy"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `phase`: The stage in the progression of a study
+pub const PHASE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "phase",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-phase",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.phase"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `progress-status-state-actual`: Status of study by state and actual
+pub const PROGRESS_STATUS_STATE_ACTUAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "progress-status-state-actual",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-progress-status-state-actual",
+    r#type: SearchParamType::Composite,
+    expression: Some("ResearchStudy.progressStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/ResearchStudy-state",
+            expression: "state",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/ResearchStudy-actual",
+            expression: "actual",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `progress-status-state-period`: Status of study by state and period
+pub const PROGRESS_STATUS_STATE_PERIOD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "progress-status-state-period",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-progress-status-state-period",
+    r#type: SearchParamType::Composite,
+    expression: Some("ResearchStudy.progressStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/ResearchStudy-state",
+            expression: "state",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/ResearchStudy-period",
+            expression: "period",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `progress-status-state-period-actual`: Status of study by state, period and actual
+pub const PROGRESS_STATUS_STATE_PERIOD_ACTUAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "progress-status-state-period-actual",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-progress-status-state-period-actual",
+    r#type: SearchParamType::Composite,
+    expression: Some("ResearchStudy.progressStatus"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/ResearchStudy-state",
+            expression: "state",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/ResearchStudy-period",
+            expression: "period",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/ResearchStudy-actual",
+            expression: "actual",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `protocol`: Steps followed in executing study
+pub const PROTOCOL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "protocol",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-protocol",
+    r#type: SearchParamType::Reference,
+    expression: Some("ResearchStudy.protocol"),
+    targets: &["PlanDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `recruitment-actual`: Actual number of participants enrolled in study across all groups
+pub const RECRUITMENT_ACTUAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "recruitment-actual",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-recruitment-actual",
+    r#type: SearchParamType::Number,
+    expression: Some("ResearchStudy.recruitment.actualNumber"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `recruitment-target`: Target number of participants enrolled in study across all groups
+pub const RECRUITMENT_TARGET: SearchParameterDefinition = SearchParameterDefinition {
+    code: "recruitment-target",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-recruitment-target",
+    r#type: SearchParamType::Number,
+    expression: Some("ResearchStudy.recruitment.targetNumber"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `region`: Geographic area for the study
+pub const REGION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "region",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-region",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.region"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `site`: Facility where study activities are conducted
+pub const SITE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "site",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-site",
+    r#type: SearchParamType::Reference,
+    expression: Some("ResearchStudy.site"),
+    targets: &["Organization", "ResearchStudy", "Location"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: active | active-but-not-recruiting | administratively-completed | approved | closed-to-accrual | closed-to-accrual-and-intervention | completed | disapproved | enrolling-by-invitation | in-review | not-yet-recruiting | recruiting | temporarily-closed-to-accrual | temporarily-closed-to-accrual-and-intervention | terminated | withdrawn
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `study-design`: Classifications of the study design characteristics
+pub const STUDY_DESIGN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "study-design",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-study-design",
+    r#type: SearchParamType::Token,
+    expression: Some("ResearchStudy.studyDesign"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `title`: The human readable name of the research study
+pub const TITLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "title",
+    url: "http://hl7.org/fhir/SearchParameter/ResearchStudy-title",
+    r#type: SearchParamType::String,
+    expression: Some("ResearchStudy.title"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ResearchStudy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResearchStudySearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `classifier`
+    Classifier,
+
+    /// `condition`
+    Condition,
+
+    /// `date`
+    Date,
+
+    /// `description`
+    Description,
+
+    /// `eligibility`
+    Eligibility,
+
+    /// `focus-code`
+    FocusCode,
+
+    /// `focus-reference`
+    FocusReference,
+
+    /// `identifier`
+    Identifier,
+
+    /// `keyword`
+    Keyword,
+
+    /// `name`
+    Name,
+
+    /// `objective-description`
+    ObjectiveDescription,
+
+    /// `objective-type`
+    ObjectiveType,
+
+    /// `part-of`
+    PartOf,
+
+    /// `phase`
+    Phase,
+
+    /// `progress-status-state-actual`
+    ProgressStatusStateActual,
+
+    /// `progress-status-state-period`
+    ProgressStatusStatePeriod,
+
+    /// `progress-status-state-period-actual`
+    ProgressStatusStatePeriodActual,
+
+    /// `protocol`
+    Protocol,
+
+    /// `recruitment-actual`
+    RecruitmentActual,
+
+    /// `recruitment-target`
+    RecruitmentTarget,
+
+    /// `region`
+    Region,
+
+    /// `site`
+    Site,
+
+    /// `status`
+    Status,
+
+    /// `study-design`
+    StudyDesign,
+
+    /// `title`
+    Title,
+}
+
+impl ResearchStudySearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Classifier,
+        Self::Condition,
+        Self::Date,
+        Self::Description,
+        Self::Eligibility,
+        Self::FocusCode,
+        Self::FocusReference,
+        Self::Identifier,
+        Self::Keyword,
+        Self::Name,
+        Self::ObjectiveDescription,
+        Self::ObjectiveType,
+        Self::PartOf,
+        Self::Phase,
+        Self::ProgressStatusStateActual,
+        Self::ProgressStatusStatePeriod,
+        Self::ProgressStatusStatePeriodActual,
+        Self::Protocol,
+        Self::RecruitmentActual,
+        Self::RecruitmentTarget,
+        Self::Region,
+        Self::Site,
+        Self::Status,
+        Self::StudyDesign,
+        Self::Title,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Classifier => &CLASSIFIER,
+            Self::Condition => &CONDITION,
+            Self::Date => &DATE,
+            Self::Description => &DESCRIPTION,
+            Self::Eligibility => &ELIGIBILITY,
+            Self::FocusCode => &FOCUS_CODE,
+            Self::FocusReference => &FOCUS_REFERENCE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Keyword => &KEYWORD,
+            Self::Name => &NAME,
+            Self::ObjectiveDescription => &OBJECTIVE_DESCRIPTION,
+            Self::ObjectiveType => &OBJECTIVE_TYPE,
+            Self::PartOf => &PART_OF,
+            Self::Phase => &PHASE,
+            Self::ProgressStatusStateActual => &PROGRESS_STATUS_STATE_ACTUAL,
+            Self::ProgressStatusStatePeriod => &PROGRESS_STATUS_STATE_PERIOD,
+            Self::ProgressStatusStatePeriodActual => &PROGRESS_STATUS_STATE_PERIOD_ACTUAL,
+            Self::Protocol => &PROTOCOL,
+            Self::RecruitmentActual => &RECRUITMENT_ACTUAL,
+            Self::RecruitmentTarget => &RECRUITMENT_TARGET,
+            Self::Region => &REGION,
+            Self::Site => &SITE,
+            Self::Status => &STATUS,
+            Self::StudyDesign => &STUDY_DESIGN,
+            Self::Title => &TITLE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ResearchStudySearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ResearchStudySearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
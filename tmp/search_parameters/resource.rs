@@ -0,0 +1,314 @@
+//! Resource search parameters
+
+use super::*;
+
+/// `_content`: Search on the entire content of the resource
+pub const UNDERSCORE_CONTENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_content",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-content",
+    r#type: SearchParamType::Special,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_filter`: Filter search parameter which supports a more sophisticated grammar for searching. See [documentation](https://build.fhir.org/search_filter.html) for further details
+pub const UNDERSCORE_FILTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_filter",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-filter",
+    r#type: SearchParamType::Special,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_has`: Provides limited support for reverse chaining - that is, selecting resources based on the properties of resources that refer to them (instead of chaining where resources can be selected based on the properties of resources that they refer to). See the FHIR search page for further documentation
+pub const UNDERSCORE_HAS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_has",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-has",
+    r#type: SearchParamType::Special,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_id`: Logical id of this artifact
+pub const UNDERSCORE_ID: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_id",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-id",
+    r#type: SearchParamType::Token,
+    expression: Some("Resource.id"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_in`: Allows for the retrieval of resources that are active members of a CareTeam, Group, or List
+pub const UNDERSCORE_IN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_in",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-in",
+    r#type: SearchParamType::Reference,
+    expression: Some("Resource.id"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_language`: Language of the resource content
+pub const UNDERSCORE_LANGUAGE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_language",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-language",
+    r#type: SearchParamType::Token,
+    expression: Some("Resource.language"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_lastUpdated`: When the resource version last changed
+pub const UNDERSCORE_LAST_UPDATED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_lastUpdated",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-lastUpdated",
+    r#type: SearchParamType::Date,
+    expression: Some("Resource.meta.lastUpdated"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_list`: Allows for the retrieval of resources that are referenced by a List resource or by one of the pre-defined functional lists
+pub const UNDERSCORE_LIST: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_list",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-list",
+    r#type: SearchParamType::Special,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_profile`: Profiles this resource claims to conform to
+pub const UNDERSCORE_PROFILE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_profile",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-profile",
+    r#type: SearchParamType::Reference,
+    expression: Some("Resource.meta.profile"),
+    targets: &["StructureDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_query`: A custom search profile that describes a specific defined query operation
+pub const UNDERSCORE_QUERY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_query",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-query",
+    r#type: SearchParamType::Special,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_security`: Security Labels applied to this resource
+pub const UNDERSCORE_SECURITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_security",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-security",
+    r#type: SearchParamType::Token,
+    expression: Some("Resource.meta.security"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_source`: Identifies where the resource comes from
+pub const UNDERSCORE_SOURCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_source",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-source",
+    r#type: SearchParamType::Uri,
+    expression: Some("Resource.meta.source"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_tag`: Tags applied to this resource
+pub const UNDERSCORE_TAG: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_tag",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-tag",
+    r#type: SearchParamType::Token,
+    expression: Some("Resource.meta.tag"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_text`: Text search against the narrative
+pub const UNDERSCORE_TEXT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_text",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-text",
+    r#type: SearchParamType::String,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `_type`: A resource type filter
+pub const UNDERSCORE_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "_type",
+    url: "http://hl7.org/fhir/SearchParameter/Resource-type",
+    r#type: SearchParamType::Special,
+    expression: None,
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResourceSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `_type`
+    UnderscoreType,
+}
+
+impl ResourceSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreText,
+        Self::UnderscoreType,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &UNDERSCORE_HAS,
+            Self::UnderscoreId => &UNDERSCORE_ID,
+            Self::UnderscoreIn => &UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &UNDERSCORE_TAG,
+            Self::UnderscoreText => &UNDERSCORE_TEXT,
+            Self::UnderscoreType => &UNDERSCORE_TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ResourceSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ResourceSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,296 @@
+//! Schedule search parameters
+
+use super::*;
+
+/// `active`: Is the schedule in active use
+pub const ACTIVE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "active",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-active",
+    r#type: SearchParamType::Token,
+    expression: Some("Schedule.active"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `actor`: The individual(HealthcareService, Practitioner, Location, ...) to find a Schedule for
+pub const ACTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "actor",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-actor",
+    r#type: SearchParamType::Reference,
+    expression: Some("Schedule.actor"),
+    targets: &[
+        "HealthcareService",
+        "CareTeam",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Location",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `date`: Search for Schedule resources that have a period that contains this date specified
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-date",
+    r#type: SearchParamType::Date,
+    expression: Some("Schedule.planningHorizon"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A Schedule Identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Schedule.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A portion of the Schedule name
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-name",
+    r#type: SearchParamType::String,
+    expression: Some("Schedule.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-category`: High-level category
+pub const SERVICE_CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-category",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-service-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Schedule.serviceCategory"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-type`: The type (by coding) of appointments that can be booked into associated slot(s)
+pub const SERVICE_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-type",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-service-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Schedule.serviceType.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-type-reference`: The type (by HealthcareService) of appointments that can be booked into associated slot(s)
+pub const SERVICE_TYPE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-type-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-service-type-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Schedule.serviceType.reference"),
+    targets: &["HealthcareService"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specialty`: Type of specialty needed
+pub const SPECIALTY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specialty",
+    url: "http://hl7.org/fhir/SearchParameter/Schedule-specialty",
+    r#type: SearchParamType::Token,
+    expression: Some("Schedule.specialty"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScheduleSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `active`
+    Active,
+
+    /// `actor`
+    Actor,
+
+    /// `date`
+    Date,
+
+    /// `identifier`
+    Identifier,
+
+    /// `name`
+    Name,
+
+    /// `service-category`
+    ServiceCategory,
+
+    /// `service-type`
+    ServiceType,
+
+    /// `service-type-reference`
+    ServiceTypeReference,
+
+    /// `specialty`
+    Specialty,
+}
+
+impl ScheduleSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Active,
+        Self::Actor,
+        Self::Date,
+        Self::Identifier,
+        Self::Name,
+        Self::ServiceCategory,
+        Self::ServiceType,
+        Self::ServiceTypeReference,
+        Self::Specialty,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Active => &ACTIVE,
+            Self::Actor => &ACTOR,
+            Self::Date => &DATE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Name => &NAME,
+            Self::ServiceCategory => &SERVICE_CATEGORY,
+            Self::ServiceType => &SERVICE_TYPE,
+            Self::ServiceTypeReference => &SERVICE_TYPE_REFERENCE,
+            Self::Specialty => &SPECIALTY,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ScheduleSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ScheduleSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
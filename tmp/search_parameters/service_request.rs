@@ -0,0 +1,553 @@
+//! ServiceRequest search parameters
+
+use super::*;
+
+/// `authored`: Date request signed
+pub const AUTHORED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "authored",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-authored",
+    r#type: SearchParamType::Date,
+    expression: Some("ServiceRequest.authoredOn"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `based-on`: What request fulfills
+pub const BASED_ON: SearchParameterDefinition = SearchParameterDefinition {
+    code: "based-on",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-based-on",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.basedOn"),
+    targets: &["ServiceRequest", "CarePlan", "MedicationRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `body-site`: Where procedure is going to be done
+pub const BODY_SITE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "body-site",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-body-site",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.bodySite"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `body-structure`: Body structure Where procedure is going to be done
+pub const BODY_STRUCTURE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "body-structure",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-body-structure",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.bodyStructure"),
+    targets: &["BodyStructure"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `category`: Classification of service
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-category",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code-concept`: What is being requested/ordered
+pub const CODE_CONCEPT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code-concept",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-code-concept",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.code.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code-reference`: What is being requested/ordered
+pub const CODE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code-reference",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-code-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.code.reference"),
+    targets: &["ActivityDefinition", "PlanDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `encounter`: An encounter in which this request is made
+pub const ENCOUNTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "encounter",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-encounter",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.encounter"),
+    targets: &["Encounter"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Identifiers assigned to this order
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-canonical`: Instantiates FHIR protocol or definition
+pub const INSTANTIATES_CANONICAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-canonical",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-instantiates-canonical",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.instantiatesCanonical"),
+    targets: &["ActivityDefinition", "PlanDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `instantiates-uri`: Instantiates external protocol or definition
+pub const INSTANTIATES_URI: SearchParameterDefinition = SearchParameterDefinition {
+    code: "instantiates-uri",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-instantiates-uri",
+    r#type: SearchParamType::Uri,
+    expression: Some("ServiceRequest.instantiatesUri"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `intent`: proposal | plan | directive | order +
+pub const INTENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "intent",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-intent",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.intent"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `occurrence`: When service should occur
+pub const OCCURRENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "occurrence",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-occurrence",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "ServiceRequest.occurrence.ofType(dateTime) | ServiceRequest.occurrence.ofType(Period) | ServiceRequest.occurrence.ofType(Timing)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: Search by subject - a patient
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer`: Requested performer
+pub const PERFORMER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-performer",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.performer"),
+    targets: &[
+        "HealthcareService",
+        "Organization",
+        "CareTeam",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `performer-type`: Performer role
+pub const PERFORMER_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "performer-type",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-performer-type",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.performerType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `priority`: routine | urgent | asap | stat
+pub const PRIORITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "priority",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-priority",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.priority"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `replaces`: What request replaces
+pub const REPLACES: SearchParameterDefinition = SearchParameterDefinition {
+    code: "replaces",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-replaces",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.replaces"),
+    targets: &["ServiceRequest"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requester`: Who/what is requesting service
+pub const REQUESTER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requester",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-requester",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.requester"),
+    targets: &[
+        "Organization",
+        "Device",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `requisition`: Composite Request ID
+pub const REQUISITION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "requisition",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-requisition",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.requisition"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specimen`: Specimen to be tested
+pub const SPECIMEN: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specimen",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-specimen",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.specimen"),
+    targets: &["Specimen"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: draft | active | on-hold | revoked | completed | entered-in-error | unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-status",
+    r#type: SearchParamType::Token,
+    expression: Some("ServiceRequest.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: Search by subject
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/ServiceRequest-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("ServiceRequest.subject"),
+    targets: &["Device", "Group", "Location", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of ServiceRequest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ServiceRequestSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `authored`
+    Authored,
+
+    /// `based-on`
+    BasedOn,
+
+    /// `body-site`
+    BodySite,
+
+    /// `body-structure`
+    BodyStructure,
+
+    /// `category`
+    Category,
+
+    /// `code-concept`
+    CodeConcept,
+
+    /// `code-reference`
+    CodeReference,
+
+    /// `encounter`
+    Encounter,
+
+    /// `identifier`
+    Identifier,
+
+    /// `instantiates-canonical`
+    InstantiatesCanonical,
+
+    /// `instantiates-uri`
+    InstantiatesUri,
+
+    /// `intent`
+    Intent,
+
+    /// `occurrence`
+    Occurrence,
+
+    /// `patient`
+    Patient,
+
+    /// `performer`
+    Performer,
+
+    /// `performer-type`
+    PerformerType,
+
+    /// `priority`
+    Priority,
+
+    /// `replaces`
+    Replaces,
+
+    /// `requester`
+    Requester,
+
+    /// `requisition`
+    Requisition,
+
+    /// `specimen`
+    Specimen,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+}
+
+impl ServiceRequestSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Authored,
+        Self::BasedOn,
+        Self::BodySite,
+        Self::BodyStructure,
+        Self::Category,
+        Self::CodeConcept,
+        Self::CodeReference,
+        Self::Encounter,
+        Self::Identifier,
+        Self::InstantiatesCanonical,
+        Self::InstantiatesUri,
+        Self::Intent,
+        Self::Occurrence,
+        Self::Patient,
+        Self::Performer,
+        Self::PerformerType,
+        Self::Priority,
+        Self::Replaces,
+        Self::Requester,
+        Self::Requisition,
+        Self::Specimen,
+        Self::Status,
+        Self::Subject,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Authored => &AUTHORED,
+            Self::BasedOn => &BASED_ON,
+            Self::BodySite => &BODY_SITE,
+            Self::BodyStructure => &BODY_STRUCTURE,
+            Self::Category => &CATEGORY,
+            Self::CodeConcept => &CODE_CONCEPT,
+            Self::CodeReference => &CODE_REFERENCE,
+            Self::Encounter => &ENCOUNTER,
+            Self::Identifier => &IDENTIFIER,
+            Self::InstantiatesCanonical => &INSTANTIATES_CANONICAL,
+            Self::InstantiatesUri => &INSTANTIATES_URI,
+            Self::Intent => &INTENT,
+            Self::Occurrence => &OCCURRENCE,
+            Self::Patient => &PATIENT,
+            Self::Performer => &PERFORMER,
+            Self::PerformerType => &PERFORMER_TYPE,
+            Self::Priority => &PRIORITY,
+            Self::Replaces => &REPLACES,
+            Self::Requester => &REQUESTER,
+            Self::Requisition => &REQUISITION,
+            Self::Specimen => &SPECIMEN,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for ServiceRequestSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = ServiceRequestSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
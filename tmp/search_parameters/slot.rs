@@ -0,0 +1,287 @@
+//! Slot search parameters
+
+use super::*;
+
+/// `appointment-type`: The style of appointment or patient that may be booked in the slot (not service type)
+pub const APPOINTMENT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "appointment-type",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-appointment-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Slot.appointmentType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A Slot Identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Slot.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `schedule`: The Schedule Resource that we are seeking a slot within
+pub const SCHEDULE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "schedule",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-schedule",
+    r#type: SearchParamType::Reference,
+    expression: Some("Slot.schedule"),
+    targets: &["Schedule"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-category`: A broad categorization of the service that is to be performed during this appointment
+pub const SERVICE_CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-category",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-service-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Slot.serviceCategory"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-type`: The type (by coding) of appointments that can be booked into the slot
+pub const SERVICE_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-type",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-service-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Slot.serviceType.concept"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `service-type-reference`: The type (by HealthcareService) of appointments that can be booked into the slot
+pub const SERVICE_TYPE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "service-type-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-service-type-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Slot.serviceType.reference"),
+    targets: &["HealthcareService"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `specialty`: The specialty of a practitioner that would be required to perform the service requested in this appointment
+pub const SPECIALTY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "specialty",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-specialty",
+    r#type: SearchParamType::Token,
+    expression: Some("Slot.specialty"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `start`: Appointment date/time.
+pub const START: SearchParameterDefinition = SearchParameterDefinition {
+    code: "start",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-start",
+    r#type: SearchParamType::Date,
+    expression: Some("Slot.start"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The free/busy status of the appointment
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Slot-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Slot.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SlotSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `appointment-type`
+    AppointmentType,
+
+    /// `identifier`
+    Identifier,
+
+    /// `schedule`
+    Schedule,
+
+    /// `service-category`
+    ServiceCategory,
+
+    /// `service-type`
+    ServiceType,
+
+    /// `service-type-reference`
+    ServiceTypeReference,
+
+    /// `specialty`
+    Specialty,
+
+    /// `start`
+    Start,
+
+    /// `status`
+    Status,
+}
+
+impl SlotSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::AppointmentType,
+        Self::Identifier,
+        Self::Schedule,
+        Self::ServiceCategory,
+        Self::ServiceType,
+        Self::ServiceTypeReference,
+        Self::Specialty,
+        Self::Start,
+        Self::Status,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::AppointmentType => &APPOINTMENT_TYPE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Schedule => &SCHEDULE,
+            Self::ServiceCategory => &SERVICE_CATEGORY,
+            Self::ServiceType => &SERVICE_TYPE,
+            Self::ServiceTypeReference => &SERVICE_TYPE_REFERENCE,
+            Self::Specialty => &SPECIALTY,
+            Self::Start => &START,
+            Self::Status => &STATUS,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for SlotSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = SlotSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
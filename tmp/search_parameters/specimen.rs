@@ -0,0 +1,347 @@
+//! Specimen search parameters
+
+use super::*;
+
+/// `accession`: The accession number associated with the specimen
+pub const ACCESSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "accession",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-accession",
+    r#type: SearchParamType::Token,
+    expression: Some("Specimen.accessionIdentifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `bodysite`: Reference to a resource (by instance)
+pub const BODYSITE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "bodysite",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-bodysite",
+    r#type: SearchParamType::Reference,
+    expression: Some("Specimen.collection.bodySite.reference"),
+    targets: &["BodyStructure"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `collected`: The date the specimen was collected
+pub const COLLECTED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "collected",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-collected",
+    r#type: SearchParamType::Date,
+    expression: Some(
+        "Specimen.collection.collected.ofType(dateTime) | Specimen.collection.collected.ofType(Period)",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `collector`: Who collected the specimen
+pub const COLLECTOR: SearchParameterDefinition = SearchParameterDefinition {
+    code: "collector",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-collector",
+    r#type: SearchParamType::Reference,
+    expression: Some("Specimen.collection.collector"),
+    targets: &["RelatedPerson", "PractitionerRole", "Practitioner", "Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `container-device`: The unique identifier associated with the specimen container
+pub const CONTAINER_DEVICE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "container-device",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-container-device",
+    r#type: SearchParamType::Reference,
+    expression: Some("Specimen.container.device.where(resolve() is Device)"),
+    targets: &["Device"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The unique identifier associated with the specimen
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Specimen.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `parent`: The parent of the specimen
+pub const PARENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "parent",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-parent",
+    r#type: SearchParamType::Reference,
+    expression: Some("Specimen.parent"),
+    targets: &["Specimen"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `patient`: The patient the specimen comes from
+pub const PATIENT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "patient",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-patient",
+    r#type: SearchParamType::Reference,
+    expression: Some("Specimen.subject.where(resolve() is Patient)"),
+    targets: &["Patient"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `procedure`: The procedure that collected the specimen
+pub const PROCEDURE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "procedure",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-procedure",
+    r#type: SearchParamType::Reference,
+    expression: Some("Specimen.collection.procedure"),
+    targets: &["Procedure"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: available | unavailable | unsatisfactory | entered-in-error
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Specimen.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `subject`: The subject of the specimen
+pub const SUBJECT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "subject",
+    url: "http://hl7.org/fhir/SearchParameter/Specimen-subject",
+    r#type: SearchParamType::Reference,
+    expression: Some("Specimen.subject"),
+    targets: &[
+        "Device",
+        "Group",
+        "BiologicallyDerivedProduct",
+        "Substance",
+        "Location",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: The specimen type
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/clinical-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Specimen.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Specimen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpecimenSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `accession`
+    Accession,
+
+    /// `bodysite`
+    Bodysite,
+
+    /// `collected`
+    Collected,
+
+    /// `collector`
+    Collector,
+
+    /// `container-device`
+    ContainerDevice,
+
+    /// `identifier`
+    Identifier,
+
+    /// `parent`
+    Parent,
+
+    /// `patient`
+    Patient,
+
+    /// `procedure`
+    Procedure,
+
+    /// `status`
+    Status,
+
+    /// `subject`
+    Subject,
+
+    /// `type`
+    Type,
+}
+
+impl SpecimenSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Accession,
+        Self::Bodysite,
+        Self::Collected,
+        Self::Collector,
+        Self::ContainerDevice,
+        Self::Identifier,
+        Self::Parent,
+        Self::Patient,
+        Self::Procedure,
+        Self::Status,
+        Self::Subject,
+        Self::Type,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Accession => &ACCESSION,
+            Self::Bodysite => &BODYSITE,
+            Self::Collected => &COLLECTED,
+            Self::Collector => &COLLECTOR,
+            Self::ContainerDevice => &CONTAINER_DEVICE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Parent => &PARENT,
+            Self::Patient => &PATIENT,
+            Self::Procedure => &PROCEDURE,
+            Self::Status => &STATUS,
+            Self::Subject => &SUBJECT,
+            Self::Type => &TYPE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for SpecimenSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = SpecimenSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! SpecimenDefinition search parameters
+
+use super::*;
+
+/// `container`: The type of specimen conditioned in container expected by the lab
+pub const CONTAINER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "container",
+    url: "http://hl7.org/fhir/SearchParameter/SpecimenDefinition-container",
+    r#type: SearchParamType::Token,
+    expression: Some("SpecimenDefinition.typeTested.container.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `experimental`: Not for genuine usage (true)
+pub const EXPERIMENTAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "experimental",
+    url: "http://hl7.org/fhir/SearchParameter/SpecimenDefinition-experimental",
+    r#type: SearchParamType::Token,
+    expression: Some("SpecimenDefinition.experimental"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: The unique identifier associated with the SpecimenDefinition
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("SpecimenDefinition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `is-derived`: Primary specimen (false) or derived specimen (true)
+pub const IS_DERIVED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "is-derived",
+    url: "http://hl7.org/fhir/SearchParameter/SpecimenDefinition-is-derived",
+    r#type: SearchParamType::Token,
+    expression: Some("SpecimenDefinition.typeTested.isDerived"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Publication status of the SpecimenDefinition: draft, active, retired, unknown
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-status",
+    r#type: SearchParamType::Token,
+    expression: Some("SpecimenDefinition.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `title`: Human-friendly name of the SpecimenDefinition
+pub const TITLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "title",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-title",
+    r#type: SearchParamType::String,
+    expression: Some("SpecimenDefinition.title"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: The type of collected specimen
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/SpecimenDefinition-type",
+    r#type: SearchParamType::Token,
+    expression: Some("SpecimenDefinition.typeCollected"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type-tested`: The type of specimen conditioned for testing
+pub const TYPE_TESTED: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type-tested",
+    url: "http://hl7.org/fhir/SearchParameter/SpecimenDefinition-type-tested",
+    r#type: SearchParamType::Token,
+    expression: Some("SpecimenDefinition.typeTested.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `url`: The uri that identifies the specimen definition
+pub const URL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "url",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-url",
+    r#type: SearchParamType::Uri,
+    expression: Some("SpecimenDefinition.url"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of SpecimenDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpecimenDefinitionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `container`
+    Container,
+
+    /// `experimental`
+    Experimental,
+
+    /// `identifier`
+    Identifier,
+
+    /// `is-derived`
+    IsDerived,
+
+    /// `status`
+    Status,
+
+    /// `title`
+    Title,
+
+    /// `type`
+    Type,
+
+    /// `type-tested`
+    TypeTested,
+
+    /// `url`
+    Url,
+}
+
+impl SpecimenDefinitionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Container,
+        Self::Experimental,
+        Self::Identifier,
+        Self::IsDerived,
+        Self::Status,
+        Self::Title,
+        Self::Type,
+        Self::TypeTested,
+        Self::Url,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Container => &CONTAINER,
+            Self::Experimental => &EXPERIMENTAL,
+            Self::Identifier => &IDENTIFIER,
+            Self::IsDerived => &IS_DERIVED,
+            Self::Status => &STATUS,
+            Self::Title => &TITLE,
+            Self::Type => &TYPE,
+            Self::TypeTested => &TYPE_TESTED,
+            Self::Url => &URL,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for SpecimenDefinitionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = SpecimenDefinitionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
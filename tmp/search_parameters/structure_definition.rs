@@ -0,0 +1,653 @@
+//! StructureDefinition search parameters
+
+use super::*;
+
+/// `abstract`: Whether the structure is abstract
+pub const ABSTRACT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "abstract",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-abstract",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.abstract"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `base`: Definition that this type is constrained/specialized from
+pub const BASE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "base",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-base",
+    r#type: SearchParamType::Reference,
+    expression: Some("StructureDefinition.baseDefinition"),
+    targets: &["StructureDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `base-path`: Path that identifies the base element
+pub const BASE_PATH: SearchParameterDefinition = SearchParameterDefinition {
+    code: "base-path",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-base-path",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "StructureDefinition.snapshot.element.base.path | StructureDefinition.differential.element.base.path",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context`: A use context assigned to the structure definition
+pub const CONTEXT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context",
+    r#type: SearchParamType::Token,
+    expression: Some("(StructureDefinition.useContext.value.ofType(CodeableConcept))"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-quantity`: A quantity- or range-valued use context assigned to the structure definition
+pub const CONTEXT_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some(
+        "(StructureDefinition.useContext.value.ofType(Quantity)) | (StructureDefinition.useContext.value.ofType(Range))",
+    ),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-type`: A type of use context assigned to the structure definition
+pub const CONTEXT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.useContext.code"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `context-type-quantity`: A use context type and quantity- or range-based value assigned to the structure definition
+pub const CONTEXT_TYPE_QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type-quantity",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type-quantity",
+    r#type: SearchParamType::Composite,
+    expression: Some("StructureDefinition.useContext"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-quantity",
+            expression: "value.ofType(Quantity) | value.ofType(Range)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `context-type-value`: A use context type and value assigned to the structure definition
+pub const CONTEXT_TYPE_VALUE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "context-type-value",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type-value",
+    r#type: SearchParamType::Composite,
+    expression: Some("StructureDefinition.useContext"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context-type",
+            expression: "code",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/CanonicalResource-context",
+            expression: "value.ofType(CodeableConcept)",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `date`: The structure definition publication date
+pub const DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "date",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-date",
+    r#type: SearchParamType::Date,
+    expression: Some("StructureDefinition.date"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `derivation`: specialization | constraint - How relates to base definition
+pub const DERIVATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "derivation",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-derivation",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.derivation"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `description`: The description of the structure definition
+pub const DESCRIPTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "description",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-description",
+    r#type: SearchParamType::String,
+    expression: Some("StructureDefinition.description"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `experimental`: For testing purposes, not real usage
+pub const EXPERIMENTAL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "experimental",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-experimental",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.experimental"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `ext-context`: An extension context assigned to the structure definition
+pub const EXT_CONTEXT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ext-context",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-ext-context",
+    r#type: SearchParamType::Composite,
+    expression: Some("StructureDefinition.context"),
+    targets: &[],
+    comparators: &[],
+    components: &[
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/StructureDefinition-ext-context-type",
+            expression: "type",
+        },
+        SearchParameterComponent {
+            definition: "http://hl7.org/fhir/SearchParameter/StructureDefinition-ext-context-expression",
+            expression: "expression",
+        },
+    ],
+    multiple_or: Some(false),
+};
+
+/// `ext-context-expression`: An expression of extension context assigned to the structure definition
+pub const EXT_CONTEXT_EXPRESSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ext-context-expression",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-ext-context-expression",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.context.expression"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `ext-context-type`: A type of extension context assigned to the structure definition
+pub const EXT_CONTEXT_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "ext-context-type",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-ext-context-type",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.context.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: External identifier for the structure definition
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `jurisdiction`: Intended jurisdiction for the structure definition
+pub const JURISDICTION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "jurisdiction",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-jurisdiction",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.jurisdiction"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `keyword`: A code for the StructureDefinition
+pub const KEYWORD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "keyword",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-keyword",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.keyword"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `kind`: primitive-type | complex-type | resource | logical
+pub const KIND: SearchParameterDefinition = SearchParameterDefinition {
+    code: "kind",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-kind",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.kind"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: Computationally friendly name of the structure definition
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-name",
+    r#type: SearchParamType::String,
+    expression: Some("StructureDefinition.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `path`: A path that is constrained in the StructureDefinition
+pub const PATH: SearchParameterDefinition = SearchParameterDefinition {
+    code: "path",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-path",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "StructureDefinition.snapshot.element.path | StructureDefinition.differential.element.path",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `publisher`: Name of the publisher of the structure definition
+pub const PUBLISHER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "publisher",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-publisher",
+    r#type: SearchParamType::String,
+    expression: Some("StructureDefinition.publisher"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The current status of the structure definition
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-status",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `title`: The human-friendly name of the structure definition
+pub const TITLE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "title",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-title",
+    r#type: SearchParamType::String,
+    expression: Some("StructureDefinition.title"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: Type defined or constrained by this structure
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-type",
+    r#type: SearchParamType::Uri,
+    expression: Some("StructureDefinition.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `url`: The uri that identifies the structure definition
+pub const URL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "url",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-url",
+    r#type: SearchParamType::Uri,
+    expression: Some("StructureDefinition.url"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `valueset`: A vocabulary binding reference
+pub const VALUESET: SearchParameterDefinition = SearchParameterDefinition {
+    code: "valueset",
+    url: "http://hl7.org/fhir/SearchParameter/StructureDefinition-valueset",
+    r#type: SearchParamType::Reference,
+    expression: Some("StructureDefinition.snapshot.element.binding.valueSet"),
+    targets: &["ValueSet"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `version`: The business version of the structure definition
+pub const VERSION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "version",
+    url: "http://hl7.org/fhir/SearchParameter/CanonicalResource-version",
+    r#type: SearchParamType::Token,
+    expression: Some("StructureDefinition.version"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of StructureDefinition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StructureDefinitionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `abstract`
+    Abstract,
+
+    /// `base`
+    Base,
+
+    /// `base-path`
+    BasePath,
+
+    /// `context`
+    Context,
+
+    /// `context-quantity`
+    ContextQuantity,
+
+    /// `context-type`
+    ContextType,
+
+    /// `context-type-quantity`
+    ContextTypeQuantity,
+
+    /// `context-type-value`
+    ContextTypeValue,
+
+    /// `date`
+    Date,
+
+    /// `derivation`
+    Derivation,
+
+    /// `description`
+    Description,
+
+    /// `experimental`
+    Experimental,
+
+    /// `ext-context`
+    ExtContext,
+
+    /// `ext-context-expression`
+    ExtContextExpression,
+
+    /// `ext-context-type`
+    ExtContextType,
+
+    /// `identifier`
+    Identifier,
+
+    /// `jurisdiction`
+    Jurisdiction,
+
+    /// `keyword`
+    Keyword,
+
+    /// `kind`
+    Kind,
+
+    /// `name`
+    Name,
+
+    /// `path`
+    Path,
+
+    /// `publisher`
+    Publisher,
+
+    /// `status`
+    Status,
+
+    /// `title`
+    Title,
+
+    /// `type`
+    Type,
+
+    /// `url`
+    Url,
+
+    /// `valueset`
+    Valueset,
+
+    /// `version`
+    Version,
+}
+
+impl StructureDefinitionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Abstract,
+        Self::Base,
+        Self::BasePath,
+        Self::Context,
+        Self::ContextQuantity,
+        Self::ContextType,
+        Self::ContextTypeQuantity,
+        Self::ContextTypeValue,
+        Self::Date,
+        Self::Derivation,
+        Self::Description,
+        Self::Experimental,
+        Self::ExtContext,
+        Self::ExtContextExpression,
+        Self::ExtContextType,
+        Self::Identifier,
+        Self::Jurisdiction,
+        Self::Keyword,
+        Self::Kind,
+        Self::Name,
+        Self::Path,
+        Self::Publisher,
+        Self::Status,
+        Self::Title,
+        Self::Type,
+        Self::Url,
+        Self::Valueset,
+        Self::Version,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Abstract => &ABSTRACT,
+            Self::Base => &BASE,
+            Self::BasePath => &BASE_PATH,
+            Self::Context => &CONTEXT,
+            Self::ContextQuantity => &CONTEXT_QUANTITY,
+            Self::ContextType => &CONTEXT_TYPE,
+            Self::ContextTypeQuantity => &CONTEXT_TYPE_QUANTITY,
+            Self::ContextTypeValue => &CONTEXT_TYPE_VALUE,
+            Self::Date => &DATE,
+            Self::Derivation => &DERIVATION,
+            Self::Description => &DESCRIPTION,
+            Self::Experimental => &EXPERIMENTAL,
+            Self::ExtContext => &EXT_CONTEXT,
+            Self::ExtContextExpression => &EXT_CONTEXT_EXPRESSION,
+            Self::ExtContextType => &EXT_CONTEXT_TYPE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Jurisdiction => &JURISDICTION,
+            Self::Keyword => &KEYWORD,
+            Self::Kind => &KIND,
+            Self::Name => &NAME,
+            Self::Path => &PATH,
+            Self::Publisher => &PUBLISHER,
+            Self::Status => &STATUS,
+            Self::Title => &TITLE,
+            Self::Type => &TYPE,
+            Self::Url => &URL,
+            Self::Valueset => &VALUESET,
+            Self::Version => &VERSION,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for StructureDefinitionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = StructureDefinitionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Subscription search parameters
+
+use super::*;
+
+/// `contact`: Contact details for the subscription
+pub const CONTACT: SearchParameterDefinition = SearchParameterDefinition {
+    code: "contact",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-contact",
+    r#type: SearchParamType::Token,
+    expression: Some("Subscription.contact"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `content-level`: Content level included in notifications
+pub const CONTENT_LEVEL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "content-level",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-content-level",
+    r#type: SearchParamType::Token,
+    expression: Some("Subscription.content"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `filter-value`: Filter value used to narrow notifications
+pub const FILTER_VALUE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "filter-value",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-filter-value",
+    r#type: SearchParamType::String,
+    expression: Some("Subscription.filterBy.value"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: A subscription identifier
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Subscription.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `name`: A human-readable name
+pub const NAME: SearchParameterDefinition = SearchParameterDefinition {
+    code: "name",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-name",
+    r#type: SearchParamType::String,
+    expression: Some("Subscription.name"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `owner`: The managing entity
+pub const OWNER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "owner",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-owner",
+    r#type: SearchParamType::Reference,
+    expression: Some("Subscription.managingEntity"),
+    targets: &[
+        "HealthcareService",
+        "CareTeam",
+        "Organization",
+        "RelatedPerson",
+        "PractitionerRole",
+        "Practitioner",
+        "Patient",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `payload`: The mime-type of notifications
+pub const PAYLOAD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "payload",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-payload",
+    r#type: SearchParamType::Token,
+    expression: Some("Subscription.contentType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: The current state of the subscription
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Subscription.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `topic`: The canonical topic url that triggers notifications
+pub const TOPIC: SearchParameterDefinition = SearchParameterDefinition {
+    code: "topic",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-topic",
+    r#type: SearchParamType::Uri,
+    expression: Some("Subscription.topic"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `type`: The type of channel for the sent notifications
+pub const TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "type",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-type",
+    r#type: SearchParamType::Token,
+    expression: Some("Subscription.channelType"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `url`: The uri that will receive the notifications
+pub const URL: SearchParameterDefinition = SearchParameterDefinition {
+    code: "url",
+    url: "http://hl7.org/fhir/SearchParameter/Subscription-url",
+    r#type: SearchParamType::Uri,
+    expression: Some("Subscription.endpoint"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubscriptionSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `contact`
+    Contact,
+
+    /// `content-level`
+    ContentLevel,
+
+    /// `filter-value`
+    FilterValue,
+
+    /// `identifier`
+    Identifier,
+
+    /// `name`
+    Name,
+
+    /// `owner`
+    Owner,
+
+    /// `payload`
+    Payload,
+
+    /// `status`
+    Status,
+
+    /// `topic`
+    Topic,
+
+    /// `type`
+    Type,
+
+    /// `url`
+    Url,
+}
+
+impl SubscriptionSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Contact,
+        Self::ContentLevel,
+        Self::FilterValue,
+        Self::Identifier,
+        Self::Name,
+        Self::Owner,
+        Self::Payload,
+        Self::Status,
+        Self::Topic,
+        Self::Type,
+        Self::Url,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Contact => &CONTACT,
+            Self::ContentLevel => &CONTENT_LEVEL,
+            Self::FilterValue => &FILTER_VALUE,
+            Self::Identifier => &IDENTIFIER,
+            Self::Name => &NAME,
+            Self::Owner => &OWNER,
+            Self::Payload => &PAYLOAD,
+            Self::Status => &STATUS,
+            Self::Topic => &TOPIC,
+            Self::Type => &TYPE,
+            Self::Url => &URL,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for SubscriptionSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = SubscriptionSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
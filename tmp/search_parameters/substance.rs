@@ -0,0 +1,282 @@
+//! Substance search parameters
+
+use super::*;
+
+/// `category`: The category of the substance
+pub const CATEGORY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "category",
+    url: "http://hl7.org/fhir/SearchParameter/Substance-category",
+    r#type: SearchParamType::Token,
+    expression: Some("Substance.category"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code`: The code of the substance or ingredient
+pub const CODE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code",
+    url: "http://hl7.org/fhir/SearchParameter/Substance-code",
+    r#type: SearchParamType::Token,
+    expression: Some(
+        "Substance.code.concept | (Substance.ingredient.substance.ofType(CodeableConcept))",
+    ),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `code-reference`: A reference to the defining substance
+pub const CODE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "code-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Substance-code-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("Substance.code.reference"),
+    targets: &["SubstanceDefinition"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `expiry`: Expiry date of package or container of substance
+pub const EXPIRY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "expiry",
+    url: "http://hl7.org/fhir/SearchParameter/Substance-expiry",
+    r#type: SearchParamType::Date,
+    expression: Some("Substance.expiry"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `identifier`: Unique identifier for the substance
+pub const IDENTIFIER: SearchParameterDefinition = SearchParameterDefinition {
+    code: "identifier",
+    url: "http://hl7.org/fhir/SearchParameter/Substance-identifier",
+    r#type: SearchParamType::Token,
+    expression: Some("Substance.identifier"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `quantity`: Amount of substance in the package
+pub const QUANTITY: SearchParameterDefinition = SearchParameterDefinition {
+    code: "quantity",
+    url: "http://hl7.org/fhir/SearchParameter/Substance-quantity",
+    r#type: SearchParamType::Quantity,
+    expression: Some("Substance.quantity"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: active | inactive | entered-in-error
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/Substance-status",
+    r#type: SearchParamType::Token,
+    expression: Some("Substance.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `substance-reference`: A component of the substance
+pub const SUBSTANCE_REFERENCE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "substance-reference",
+    url: "http://hl7.org/fhir/SearchParameter/Substance-substance-reference",
+    r#type: SearchParamType::Reference,
+    expression: Some("(Substance.ingredient.substance.ofType(Reference))"),
+    targets: &["Substance"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of Substance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubstanceSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `category`
+    Category,
+
+    /// `code`
+    Code,
+
+    /// `code-reference`
+    CodeReference,
+
+    /// `expiry`
+    Expiry,
+
+    /// `identifier`
+    Identifier,
+
+    /// `quantity`
+    Quantity,
+
+    /// `status`
+    Status,
+
+    /// `substance-reference`
+    SubstanceReference,
+}
+
+impl SubstanceSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::Category,
+        Self::Code,
+        Self::CodeReference,
+        Self::Expiry,
+        Self::Identifier,
+        Self::Quantity,
+        Self::Status,
+        Self::SubstanceReference,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::Category => &CATEGORY,
+            Self::Code => &CODE,
+            Self::CodeReference => &CODE_REFERENCE,
+            Self::Expiry => &EXPIRY,
+            Self::Identifier => &IDENTIFIER,
+            Self::Quantity => &QUANTITY,
+            Self::Status => &STATUS,
+            Self::SubstanceReference => &SUBSTANCE_REFERENCE,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for SubstanceSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = SubstanceSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}
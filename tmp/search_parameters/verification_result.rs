@@ -0,0 +1,473 @@
+//! VerificationResult search parameters
+
+use super::*;
+
+/// `attestation-method`: Select validation information for data that was attested to using the specified method
+pub const ATTESTATION_METHOD: SearchParameterDefinition = SearchParameterDefinition {
+    code: "attestation-method",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-attestation-method",
+    r#type: SearchParamType::Token,
+    expression: Some("VerificationResult.attestation.communicationMethod"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `attestation-onbehalfof`: Select validation information for data that was attested to on behalf of the specified source
+pub const ATTESTATION_ONBEHALFOF: SearchParameterDefinition = SearchParameterDefinition {
+    code: "attestation-onbehalfof",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-attestation-onbehalfof",
+    r#type: SearchParamType::Reference,
+    expression: Some("VerificationResult.attestation.onBehalfOf"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `attestation-who`: Select validation information for data that was attested to by the specified source
+pub const ATTESTATION_WHO: SearchParameterDefinition = SearchParameterDefinition {
+    code: "attestation-who",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-attestation-who",
+    r#type: SearchParamType::Reference,
+    expression: Some("VerificationResult.attestation.who"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `primarysource-date`: Select validation information for data that was validated against a primary source on the specified date
+pub const PRIMARYSOURCE_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "primarysource-date",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-primarysource-date",
+    r#type: SearchParamType::Date,
+    expression: Some("VerificationResult.primarySource.validationDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `primarysource-type`: Select validation information for data that was validated against the specified type of primary source organization
+pub const PRIMARYSOURCE_TYPE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "primarysource-type",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-primarysource-type",
+    r#type: SearchParamType::Token,
+    expression: Some("VerificationResult.primarySource.type"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `primarysource-who`: Select validation information for data that was validated against the specified primary source
+pub const PRIMARYSOURCE_WHO: SearchParameterDefinition = SearchParameterDefinition {
+    code: "primarysource-who",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-primarysource-who",
+    r#type: SearchParamType::Reference,
+    expression: Some("VerificationResult.primarySource.who"),
+    targets: &["Organization", "PractitionerRole", "Practitioner"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status`: Select validation information with the specified status
+pub const STATUS: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-status",
+    r#type: SearchParamType::Token,
+    expression: Some("VerificationResult.status"),
+    targets: &[],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `status-date`: Select validation information with a validation status that occurred at the specified date/time
+pub const STATUS_DATE: SearchParameterDefinition = SearchParameterDefinition {
+    code: "status-date",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-status-date",
+    r#type: SearchParamType::Date,
+    expression: Some("VerificationResult.statusDate"),
+    targets: &[],
+    comparators: &[
+        SearchComparator::Eq,
+        SearchComparator::Ne,
+        SearchComparator::Gt,
+        SearchComparator::Ge,
+        SearchComparator::Lt,
+        SearchComparator::Le,
+        SearchComparator::Sa,
+        SearchComparator::Eb,
+        SearchComparator::Ap,
+    ],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `target`: A resource that was validated
+pub const TARGET: SearchParameterDefinition = SearchParameterDefinition {
+    code: "target",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-target",
+    r#type: SearchParamType::Reference,
+    expression: Some("VerificationResult.target"),
+    targets: &[
+        "Account",
+        "ActivityDefinition",
+        "ActorDefinition",
+        "AdministrableProductDefinition",
+        "AdverseEvent",
+        "AllergyIntolerance",
+        "Appointment",
+        "AppointmentResponse",
+        "ArtifactAssessment",
+        "AuditEvent",
+        "Basic",
+        "Binary",
+        "BiologicallyDerivedProduct",
+        "BiologicallyDerivedProductDispense",
+        "BodyStructure",
+        "Bundle",
+        "CapabilityStatement",
+        "CarePlan",
+        "CareTeam",
+        "ChargeItem",
+        "ChargeItemDefinition",
+        "Citation",
+        "Claim",
+        "ClaimResponse",
+        "ClinicalImpression",
+        "ClinicalUseDefinition",
+        "CodeSystem",
+        "Communication",
+        "CommunicationRequest",
+        "CompartmentDefinition",
+        "Composition",
+        "ConceptMap",
+        "Condition",
+        "ConditionDefinition",
+        "Consent",
+        "Contract",
+        "Coverage",
+        "CoverageEligibilityRequest",
+        "CoverageEligibilityResponse",
+        "DetectedIssue",
+        "Device",
+        "DeviceAssociation",
+        "DeviceDefinition",
+        "DeviceDispense",
+        "DeviceMetric",
+        "DeviceRequest",
+        "DeviceUsage",
+        "DiagnosticReport",
+        "DocumentReference",
+        "Encounter",
+        "EncounterHistory",
+        "Endpoint",
+        "EnrollmentRequest",
+        "EnrollmentResponse",
+        "EpisodeOfCare",
+        "EventDefinition",
+        "Evidence",
+        "EvidenceReport",
+        "EvidenceVariable",
+        "ExampleScenario",
+        "ExplanationOfBenefit",
+        "FamilyMemberHistory",
+        "Flag",
+        "FormularyItem",
+        "GenomicStudy",
+        "Goal",
+        "GraphDefinition",
+        "Group",
+        "GuidanceResponse",
+        "HealthcareService",
+        "ImagingSelection",
+        "ImagingStudy",
+        "Immunization",
+        "ImmunizationEvaluation",
+        "ImmunizationRecommendation",
+        "ImplementationGuide",
+        "Ingredient",
+        "InsurancePlan",
+        "InventoryItem",
+        "InventoryReport",
+        "Invoice",
+        "Library",
+        "Linkage",
+        "List",
+        "Location",
+        "ManufacturedItemDefinition",
+        "Measure",
+        "MeasureReport",
+        "Medication",
+        "MedicationAdministration",
+        "MedicationDispense",
+        "MedicationKnowledge",
+        "MedicationRequest",
+        "MedicationStatement",
+        "MedicinalProductDefinition",
+        "MessageDefinition",
+        "MessageHeader",
+        "MolecularSequence",
+        "NamingSystem",
+        "NutritionIntake",
+        "NutritionOrder",
+        "NutritionProduct",
+        "Observation",
+        "ObservationDefinition",
+        "OperationDefinition",
+        "OperationOutcome",
+        "Organization",
+        "OrganizationAffiliation",
+        "PackagedProductDefinition",
+        "Parameters",
+        "Patient",
+        "PaymentNotice",
+        "PaymentReconciliation",
+        "Permission",
+        "Person",
+        "PlanDefinition",
+        "Practitioner",
+        "PractitionerRole",
+        "Procedure",
+        "Provenance",
+        "Questionnaire",
+        "QuestionnaireResponse",
+        "RegulatedAuthorization",
+        "RelatedPerson",
+        "RequestOrchestration",
+        "Requirements",
+        "ResearchStudy",
+        "ResearchSubject",
+        "RiskAssessment",
+        "Schedule",
+        "SearchParameter",
+        "ServiceRequest",
+        "Slot",
+        "Specimen",
+        "SpecimenDefinition",
+        "StructureDefinition",
+        "StructureMap",
+        "Subscription",
+        "SubscriptionStatus",
+        "SubscriptionTopic",
+        "Substance",
+        "SubstanceDefinition",
+        "SubstanceNucleicAcid",
+        "SubstancePolymer",
+        "SubstanceProtein",
+        "SubstanceReferenceInformation",
+        "SubstanceSourceMaterial",
+        "SupplyDelivery",
+        "SupplyRequest",
+        "Task",
+        "TerminologyCapabilities",
+        "TestPlan",
+        "TestReport",
+        "TestScript",
+        "Transport",
+        "ValueSet",
+        "VerificationResult",
+        "VisionPrescription",
+    ],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// `validator-organization`: Select validation information for data that was validated by the specified organization
+pub const VALIDATOR_ORGANIZATION: SearchParameterDefinition = SearchParameterDefinition {
+    code: "validator-organization",
+    url: "http://hl7.org/fhir/SearchParameter/VerificationResult-validator-organization",
+    r#type: SearchParamType::Reference,
+    expression: Some("VerificationResult.validator.organization"),
+    targets: &["Organization"],
+    comparators: &[],
+    components: &[],
+    multiple_or: None,
+};
+
+/// The search parameters of VerificationResult.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VerificationResultSearchParameter {
+    /// `_content`
+    UnderscoreContent,
+
+    /// `_filter`
+    UnderscoreFilter,
+
+    /// `_has`
+    UnderscoreHas,
+
+    /// `_id`
+    UnderscoreId,
+
+    /// `_in`
+    UnderscoreIn,
+
+    /// `_language`
+    UnderscoreLanguage,
+
+    /// `_lastUpdated`
+    UnderscoreLastUpdated,
+
+    /// `_list`
+    UnderscoreList,
+
+    /// `_profile`
+    UnderscoreProfile,
+
+    /// `_query`
+    UnderscoreQuery,
+
+    /// `_security`
+    UnderscoreSecurity,
+
+    /// `_source`
+    UnderscoreSource,
+
+    /// `_tag`
+    UnderscoreTag,
+
+    /// `_type`
+    UnderscoreType,
+
+    /// `_text`
+    UnderscoreText,
+
+    /// `attestation-method`
+    AttestationMethod,
+
+    /// `attestation-onbehalfof`
+    AttestationOnbehalfof,
+
+    /// `attestation-who`
+    AttestationWho,
+
+    /// `primarysource-date`
+    PrimarysourceDate,
+
+    /// `primarysource-type`
+    PrimarysourceType,
+
+    /// `primarysource-who`
+    PrimarysourceWho,
+
+    /// `status`
+    Status,
+
+    /// `status-date`
+    StatusDate,
+
+    /// `target`
+    Target,
+
+    /// `validator-organization`
+    ValidatorOrganization,
+}
+
+impl VerificationResultSearchParameter {
+    /// Every search parameter, inherited ones first.
+    pub const ALL: &'static [Self] = &[
+        Self::UnderscoreContent,
+        Self::UnderscoreFilter,
+        Self::UnderscoreHas,
+        Self::UnderscoreId,
+        Self::UnderscoreIn,
+        Self::UnderscoreLanguage,
+        Self::UnderscoreLastUpdated,
+        Self::UnderscoreList,
+        Self::UnderscoreProfile,
+        Self::UnderscoreQuery,
+        Self::UnderscoreSecurity,
+        Self::UnderscoreSource,
+        Self::UnderscoreTag,
+        Self::UnderscoreType,
+        Self::UnderscoreText,
+        Self::AttestationMethod,
+        Self::AttestationOnbehalfof,
+        Self::AttestationWho,
+        Self::PrimarysourceDate,
+        Self::PrimarysourceType,
+        Self::PrimarysourceWho,
+        Self::Status,
+        Self::StatusDate,
+        Self::Target,
+        Self::ValidatorOrganization,
+    ];
+
+    /// The definition.
+    pub fn definition(self) -> &'static SearchParameterDefinition {
+        match self {
+            Self::UnderscoreContent => &super::resource::UNDERSCORE_CONTENT,
+            Self::UnderscoreFilter => &super::resource::UNDERSCORE_FILTER,
+            Self::UnderscoreHas => &super::resource::UNDERSCORE_HAS,
+            Self::UnderscoreId => &super::resource::UNDERSCORE_ID,
+            Self::UnderscoreIn => &super::resource::UNDERSCORE_IN,
+            Self::UnderscoreLanguage => &super::resource::UNDERSCORE_LANGUAGE,
+            Self::UnderscoreLastUpdated => &super::resource::UNDERSCORE_LAST_UPDATED,
+            Self::UnderscoreList => &super::resource::UNDERSCORE_LIST,
+            Self::UnderscoreProfile => &super::resource::UNDERSCORE_PROFILE,
+            Self::UnderscoreQuery => &super::resource::UNDERSCORE_QUERY,
+            Self::UnderscoreSecurity => &super::resource::UNDERSCORE_SECURITY,
+            Self::UnderscoreSource => &super::resource::UNDERSCORE_SOURCE,
+            Self::UnderscoreTag => &super::resource::UNDERSCORE_TAG,
+            Self::UnderscoreType => &super::resource::UNDERSCORE_TYPE,
+            Self::UnderscoreText => &super::domain_resource::UNDERSCORE_TEXT,
+            Self::AttestationMethod => &ATTESTATION_METHOD,
+            Self::AttestationOnbehalfof => &ATTESTATION_ONBEHALFOF,
+            Self::AttestationWho => &ATTESTATION_WHO,
+            Self::PrimarysourceDate => &PRIMARYSOURCE_DATE,
+            Self::PrimarysourceType => &PRIMARYSOURCE_TYPE,
+            Self::PrimarysourceWho => &PRIMARYSOURCE_WHO,
+            Self::Status => &STATUS,
+            Self::StatusDate => &STATUS_DATE,
+            Self::Target => &TARGET,
+            Self::ValidatorOrganization => &VALIDATOR_ORGANIZATION,
+        }
+    }
+
+    /// The code in a search URL, such as `_id`.
+    pub fn code(self) -> &'static str {
+        self.definition().code
+    }
+
+    /// The search parameter of the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|x| x.code() == code)
+    }
+}
+
+impl std::fmt::Display for VerificationResultSearchParameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type T = VerificationResultSearchParameter;
+
+    #[test]
+    fn test_from_code() {
+        for x in T::ALL {
+            assert_eq!(T::from_code(x.code()), Some(* x));
+        }
+    }
+}